@@ -0,0 +1,384 @@
+//! Measure how long it takes to lay out, draw, tessellate, and render some
+//! widget trees with every renderer backend.
+//!
+//! Every scene is built from fixed inputs and rendered at a fixed size, so
+//! results are comparable between runs. Run them with:
+//!
+//! ```text
+//! cargo bench --features canvas,tiny_skia
+//! ```
+//!
+//! The `wgpu` benchmarks are skipped if no graphics adapter is available.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use iced::futures::executor;
+use iced::mouse;
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::widget::{button, column, container, row, scrollable, text};
+use iced::{
+    Color, Element, Font, Length, Pixels, Rectangle, Renderer, Size, Theme,
+    Vector,
+};
+use iced_core::renderer::Style;
+use iced_runtime::user_interface::{self, UserInterface};
+use iced_wgpu::graphics::{color, Viewport};
+use iced_wgpu::wgpu;
+
+/// The physical size of the viewport every scene is rendered at.
+const SIZE: Size<u32> = Size::new(1024, 768);
+
+/// The amount of shapes drawn by the canvas scene.
+const SHAPES: usize = 2_000;
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
+
+fn benchmark(c: &mut Criterion) {
+    match Target::wgpu() {
+        Some((renderer, target)) => run(c, "wgpu", renderer, target),
+        None => eprintln!("No wgpu adapter available. Skipping..."),
+    }
+
+    let (renderer, target) = Target::tiny_skia();
+
+    run(c, "tiny_skia", renderer, target);
+}
+
+fn run(
+    c: &mut Criterion,
+    backend: &str,
+    mut renderer: Renderer,
+    mut target: Target,
+) {
+    let viewport = Viewport::with_physical_size(SIZE, 1.0);
+    let bounds = viewport.logical_size();
+
+    let mut group = c.benchmark_group(backend);
+
+    for (name, view) in SCENES {
+        let _ = group.bench_function(BenchmarkId::new("layout", name), |b| {
+            b.iter(|| {
+                UserInterface::build(
+                    view(),
+                    bounds,
+                    user_interface::Cache::default(),
+                    &mut renderer,
+                )
+            });
+        });
+
+        let mut user_interface = UserInterface::build(
+            view(),
+            bounds,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        let _ = group.bench_function(BenchmarkId::new("draw", name), |b| {
+            b.iter(|| draw(&mut user_interface, &mut renderer));
+        });
+
+        let _ = draw(&mut user_interface, &mut renderer);
+
+        let _ = group.bench_function(BenchmarkId::new("render", name), |b| {
+            b.iter(|| target.render(&mut renderer, &viewport));
+        });
+    }
+
+    let _ = group.bench_function("tessellate", |b| {
+        b.iter(|| shapes(&renderer, bounds));
+    });
+
+    group.finish();
+}
+
+fn draw(
+    user_interface: &mut UserInterface<'_, (), Renderer>,
+    renderer: &mut Renderer,
+) -> mouse::Interaction {
+    user_interface.draw(
+        renderer,
+        &Theme::default(),
+        &Style {
+            text_color: Color::BLACK,
+        },
+        mouse::Cursor::Unavailable,
+    )
+}
+
+/// The scenes measured, with their names.
+const SCENES: [(&str, fn() -> Element<'static, ()>); 3] = [
+    ("widgets", widgets),
+    ("list", list),
+    ("canvas", shapes_canvas),
+];
+
+/// A grid of buttons with labels.
+fn widgets() -> Element<'static, ()> {
+    column(
+        (0..30)
+            .map(|i| {
+                row((0..10)
+                    .map(|j| {
+                        button(text(format!("{i} × {j}")))
+                            .on_press(())
+                            .width(Length::Fill)
+                            .into()
+                    })
+                    .collect())
+                .spacing(5)
+                .into()
+            })
+            .collect(),
+    )
+    .spacing(5)
+    .padding(10)
+    .into()
+}
+
+/// A long list of text in a scrollable.
+fn list() -> Element<'static, ()> {
+    scrollable(
+        column(
+            (0..1_000)
+                .map(|i| {
+                    container(text(format!("Item number {i}")))
+                        .padding(5)
+                        .width(Length::Fill)
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(2),
+    )
+    .height(Length::Fill)
+    .into()
+}
+
+/// A canvas filled with shapes.
+fn shapes_canvas() -> Element<'static, ()> {
+    Canvas::new(Shapes)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+struct Shapes;
+
+impl canvas::Program<()> for Shapes {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        vec![shapes(renderer, bounds.size())]
+    }
+}
+
+/// Draws circles along a spiral, joined by lines, without any caching.
+fn shapes(renderer: &Renderer, size: Size) -> Geometry {
+    let mut frame = Frame::new(renderer, size);
+    let center = frame.center();
+    let max_radius = size.width.min(size.height) / 2.0;
+
+    let point = |i: usize| {
+        // The golden angle spreads the points evenly
+        let angle = i as f32 * 2.399_963;
+        let distance = max_radius * (i as f32 / SHAPES as f32).sqrt();
+
+        center + Vector::new(angle.cos(), angle.sin()) * distance
+    };
+
+    for i in 0..SHAPES {
+        let color = Color::from_rgb(
+            (i % 7) as f32 / 6.0,
+            (i % 11) as f32 / 10.0,
+            (i % 13) as f32 / 12.0,
+        );
+
+        frame.fill(&Path::circle(point(i), 3.0 + (i % 5) as f32), color);
+    }
+
+    let spiral = Path::new(|path| {
+        path.move_to(point(0));
+
+        for i in 1..SHAPES {
+            path.line_to(point(i));
+        }
+    });
+
+    frame.stroke(
+        &spiral,
+        Stroke::default().with_color(Color::BLACK).with_width(1.0),
+    );
+
+    frame.into_geometry()
+}
+
+/// An offscreen surface to render to.
+enum Target {
+    Wgpu {
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        view: wgpu::TextureView,
+    },
+    TinySkia {
+        pixmap: tiny_skia::Pixmap,
+        clip_mask: tiny_skia::Mask,
+    },
+}
+
+impl Target {
+    /// Creates a [`Target`] and a [`Renderer`] using the first available
+    /// graphics adapter, if any.
+    fn wgpu() -> Option<(Renderer, Self)> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+
+        let adapter = executor::block_on(instance.request_adapter(
+            &wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            },
+        ))?;
+
+        let (device, queue) = executor::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("iced benchmark device"),
+                features: wgpu::Features::empty(),
+                limits: wgpu::Limits {
+                    max_bind_groups: 2,
+                    ..wgpu::Limits::default()
+                },
+            },
+            None,
+        ))
+        .ok()?;
+
+        let format = if color::GAMMA_CORRECTION {
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        } else {
+            wgpu::TextureFormat::Rgba8Unorm
+        };
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced benchmark target"),
+            size: wgpu::Extent3d {
+                width: SIZE.width,
+                height: SIZE.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let backend = iced_wgpu::Backend::new(
+            &device,
+            &queue,
+            iced_wgpu::Settings::default(),
+            format,
+        );
+
+        let renderer = Renderer::Wgpu(iced_wgpu::Renderer::new(
+            backend,
+            Font::default(),
+            Pixels(16.0),
+        ));
+
+        Some((
+            renderer,
+            Self::Wgpu {
+                device,
+                queue,
+                view,
+            },
+        ))
+    }
+
+    /// Creates a [`Target`] and a [`Renderer`] drawing on the CPU.
+    fn tiny_skia() -> (Renderer, Self) {
+        let renderer = Renderer::TinySkia(iced_tiny_skia::Renderer::new(
+            iced_tiny_skia::Backend::new(iced_tiny_skia::Settings::default()),
+            Font::default(),
+            Pixels(16.0),
+        ));
+
+        let pixmap = tiny_skia::Pixmap::new(SIZE.width, SIZE.height)
+            .expect("Create pixel map");
+
+        let clip_mask = tiny_skia::Mask::new(SIZE.width, SIZE.height)
+            .expect("Create clip mask");
+
+        (renderer, Self::TinySkia { pixmap, clip_mask })
+    }
+
+    /// Renders the primitives recorded by the [`Renderer`], waiting until
+    /// they are done.
+    fn render(&mut self, renderer: &mut Renderer, viewport: &Viewport) {
+        match (self, renderer) {
+            (
+                Self::Wgpu {
+                    device,
+                    queue,
+                    view,
+                },
+                Renderer::Wgpu(renderer),
+            ) => {
+                let mut encoder = device.create_command_encoder(
+                    &wgpu::CommandEncoderDescriptor {
+                        label: Some("iced benchmark encoder"),
+                    },
+                );
+
+                renderer.with_primitives(|backend, primitives| {
+                    backend.present::<&str>(
+                        device,
+                        queue,
+                        &mut encoder,
+                        Some(Color::WHITE),
+                        view,
+                        primitives,
+                        viewport,
+                        &[],
+                    );
+                });
+
+                let _ = queue.submit(Some(encoder.finish()));
+                let _ = device.poll(wgpu::Maintain::Wait);
+            }
+            (
+                Self::TinySkia { pixmap, clip_mask },
+                Renderer::TinySkia(renderer),
+            ) => {
+                let damage = Rectangle::with_size(Size::new(
+                    SIZE.width as f32,
+                    SIZE.height as f32,
+                ));
+
+                renderer.with_primitives(|backend, primitives| {
+                    backend.draw::<&str>(
+                        &mut pixmap.as_mut(),
+                        clip_mask,
+                        primitives,
+                        viewport,
+                        &[damage],
+                        Color::WHITE,
+                        &[],
+                    );
+                });
+            }
+            _ => unreachable!("Target and renderer backends must match"),
+        }
+    }
+}
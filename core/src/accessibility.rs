@@ -0,0 +1,141 @@
+//! Describe widgets to assistive technologies.
+//!
+//! Widgets report an accessibility [`Node`] for themselves while operating
+//! (see [`Operation::accessible`]). A shell can then collect these nodes to
+//! build the accessibility tree of a window, so screen readers can present
+//! and interact with the user interface.
+//!
+//! [`Operation::accessible`]: crate::widget::Operation::accessible
+use std::ops::RangeInclusive;
+
+/// The role of a widget, describing what it is to assistive technologies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A push button.
+    Button,
+    /// A checkbox.
+    CheckBox,
+    /// A drop-down list of options.
+    ComboBox,
+    /// A group of related widgets.
+    Group,
+    /// An image.
+    Image,
+    /// Some static text.
+    Label,
+    /// An indicator of the progress of some task.
+    ProgressIndicator,
+    /// A radio button.
+    RadioButton,
+    /// A scrollable area.
+    ScrollView,
+    /// A slider.
+    Slider,
+    /// An on/off switch.
+    Switch,
+    /// An editable text field.
+    TextInput,
+}
+
+/// A numeric value of a [`Node`], like the value of a slider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Numeric {
+    /// The current value.
+    pub value: f64,
+    /// The range of possible values.
+    pub range: RangeInclusive<f64>,
+    /// The step between two consecutive values, if any.
+    pub step: Option<f64>,
+}
+
+/// The description of a widget for assistive technologies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+    /// The name of the widget, if any.
+    ///
+    /// When missing, assistive technologies may compute it from the labels
+    /// contained in the widget.
+    pub label: Option<String>,
+    /// The textual value of the widget, if any.
+    pub value: Option<String>,
+    /// The [`Numeric`] value of the widget, if any.
+    pub numeric: Option<Numeric>,
+    /// Whether the widget is toggled, for widgets that can be checked.
+    pub is_toggled: Option<bool>,
+    /// Whether the widget currently has focus.
+    pub is_focused: bool,
+    /// Whether the widget is disabled.
+    pub is_disabled: bool,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            numeric: None,
+            is_toggled: None,
+            is_focused: false,
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the textual value of the [`Node`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`Numeric`] value of the [`Node`].
+    pub fn numeric(
+        mut self,
+        value: f64,
+        range: RangeInclusive<f64>,
+        step: Option<f64>,
+    ) -> Self {
+        self.numeric = Some(Numeric { value, range, step });
+        self
+    }
+
+    /// Sets whether the [`Node`] is toggled.
+    pub fn toggled(mut self, is_toggled: bool) -> Self {
+        self.is_toggled = Some(is_toggled);
+        self
+    }
+
+    /// Sets whether the [`Node`] has focus.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Returns whether the widget described by the [`Node`] can be
+    /// activated with a click.
+    pub fn is_clickable(&self) -> bool {
+        !self.is_disabled
+            && matches!(
+                self.role,
+                Role::Button
+                    | Role::CheckBox
+                    | Role::ComboBox
+                    | Role::RadioButton
+                    | Role::Switch
+            )
+    }
+}
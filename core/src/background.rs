@@ -6,7 +6,7 @@ use crate::Color;
 pub enum Background {
     /// A solid color.
     Color(Color),
-    /// Linearly interpolate between several colors.
+    /// Interpolate between several colors.
     Gradient(Gradient),
     // TODO: Add image variant
 }
@@ -28,3 +28,15 @@ impl From<gradient::Linear> for Background {
         Background::Gradient(Gradient::Linear(gradient))
     }
 }
+
+impl From<gradient::Radial> for Background {
+    fn from(gradient: gradient::Radial) -> Self {
+        Background::Gradient(Gradient::Radial(gradient))
+    }
+}
+
+impl From<gradient::Conic> for Background {
+    fn from(gradient: gradient::Conic) -> Self {
+        Background::Gradient(Gradient::Conic(gradient))
+    }
+}
@@ -3,6 +3,61 @@
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct BorderRadius([f32; 4]);
 
+impl BorderRadius {
+    /// Creates a new [`BorderRadius`] with the same radius for every corner.
+    pub const fn new(radius: f32) -> Self {
+        Self([radius; 4])
+    }
+
+    /// Sets the radius of the top-left corner of the [`BorderRadius`].
+    pub const fn top_left(self, radius: f32) -> Self {
+        let [_, top_right, bottom_right, bottom_left] = self.0;
+
+        Self([radius, top_right, bottom_right, bottom_left])
+    }
+
+    /// Sets the radius of the top-right corner of the [`BorderRadius`].
+    pub const fn top_right(self, radius: f32) -> Self {
+        let [top_left, _, bottom_right, bottom_left] = self.0;
+
+        Self([top_left, radius, bottom_right, bottom_left])
+    }
+
+    /// Sets the radius of the bottom-right corner of the [`BorderRadius`].
+    pub const fn bottom_right(self, radius: f32) -> Self {
+        let [top_left, top_right, _, bottom_left] = self.0;
+
+        Self([top_left, top_right, radius, bottom_left])
+    }
+
+    /// Sets the radius of the bottom-left corner of the [`BorderRadius`].
+    pub const fn bottom_left(self, radius: f32) -> Self {
+        let [top_left, top_right, bottom_right, _] = self.0;
+
+        Self([top_left, top_right, bottom_right, radius])
+    }
+
+    /// Sets the radius of both top corners of the [`BorderRadius`].
+    pub const fn top(self, radius: f32) -> Self {
+        self.top_left(radius).top_right(radius)
+    }
+
+    /// Sets the radius of both bottom corners of the [`BorderRadius`].
+    pub const fn bottom(self, radius: f32) -> Self {
+        self.bottom_right(radius).bottom_left(radius)
+    }
+
+    /// Sets the radius of both left corners of the [`BorderRadius`].
+    pub const fn left(self, radius: f32) -> Self {
+        self.top_left(radius).bottom_left(radius)
+    }
+
+    /// Sets the radius of both right corners of the [`BorderRadius`].
+    pub const fn right(self, radius: f32) -> Self {
+        self.top_right(radius).bottom_right(radius)
+    }
+}
+
 impl From<f32> for BorderRadius {
     fn from(w: f32) -> Self {
         Self([w; 4])
@@ -0,0 +1,71 @@
+//! Inherit style overrides down a widget tree.
+//!
+//! Some style properties change how widgets are laid out, so they cannot be
+//! passed down when drawing like the text color. Instead, a widget can
+//! [`scope`] the layout of its children with some [`Overrides`], which every
+//! widget that does not set the property explicitly picks up.
+use std::any::Any;
+use std::cell::RefCell;
+
+thread_local! {
+    static STACK: RefCell<Vec<Overrides>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Some style properties inherited by all the descendants of a widget.
+#[derive(Debug, Default)]
+pub struct Overrides {
+    font: Option<Box<dyn Any>>,
+    spacing: Option<f32>,
+}
+
+impl Overrides {
+    /// Creates some empty [`Overrides`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the font of the text of the descendants.
+    pub fn font<Font: Copy + 'static>(mut self, font: Option<Font>) -> Self {
+        self.font = font.map(|font| Box::new(font) as Box<dyn Any>);
+        self
+    }
+
+    /// Sets the spacing between the children of the descendants.
+    pub fn spacing(mut self, spacing: Option<f32>) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+/// Calls the given function with the given [`Overrides`] in place.
+///
+/// Overrides of an outer scope stay in place unless replaced.
+pub fn scope<T>(overrides: Overrides, f: impl FnOnce() -> T) -> T {
+    STACK.with(|stack| stack.borrow_mut().push(overrides));
+
+    let result = f();
+
+    let _ = STACK.with(|stack| stack.borrow_mut().pop());
+
+    result
+}
+
+/// Returns the inherited font of the current [`scope`], if any.
+pub fn font<Font: Copy + 'static>() -> Option<Font> {
+    STACK.with(|stack| {
+        stack.borrow().iter().rev().find_map(|overrides| {
+            overrides.font.as_ref()?.downcast_ref::<Font>().copied()
+        })
+    })
+}
+
+/// Returns the inherited spacing of the current [`scope`], if any.
+pub fn spacing() -> Option<f32> {
+    STACK.with(|stack| {
+        stack
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|overrides| overrides.spacing)
+    })
+}
@@ -120,6 +120,31 @@ impl Color {
         ]
     }
 
+    /// Returns the relative luminance of the [`Color`], as defined by the
+    /// [WCAG], from `0.0` for black to `1.0` for white.
+    ///
+    /// [WCAG]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(self) -> f32 {
+        let [r, g, b, _] = self.into_linear();
+
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Returns the contrast ratio between the [`Color`] and another one, as
+    /// defined by the [WCAG], from `1.0` to `21.0`.
+    ///
+    /// Text needs a contrast ratio of at least `4.5` with its background to
+    /// be readable (level AA), or `7.0` for the enhanced level (AAA). The
+    /// alpha channel is ignored.
+    ///
+    /// [WCAG]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
     /// Inverts the [`Color`] in-place.
     pub fn invert(&mut self) {
         self.r = 1.0f32 - self.r;
@@ -229,6 +254,33 @@ mod tests {
         assert_eq!(c, r);
     }
 
+    #[test]
+    fn contrast_ratio() {
+        use approx::assert_relative_eq;
+
+        assert_relative_eq!(Color::BLACK.contrast_ratio(Color::WHITE), 21.0);
+        assert_relative_eq!(Color::WHITE.contrast_ratio(Color::BLACK), 21.0);
+        assert_relative_eq!(Color::WHITE.contrast_ratio(Color::WHITE), 1.0);
+
+        // Reference ratios of the WCAG formula, rounded to two decimals like
+        // most contrast checkers do; hence the tolerance of half a hundredth
+        let references = [
+            ([0x76, 0x76, 0x76], [0xff, 0xff, 0xff], 4.54),
+            ([0x77, 0x77, 0x77], [0xff, 0xff, 0xff], 4.48),
+            ([0x59, 0x59, 0x59], [0xff, 0xff, 0xff], 7.0),
+            ([0xff, 0x00, 0x00], [0xff, 0xff, 0xff], 4.0),
+            ([0x00, 0x00, 0xff], [0xff, 0xff, 0x00], 8.0),
+        ];
+
+        for ([r1, g1, b1], [r2, g2, b2], ratio) in references {
+            let a = Color::from_rgb8(r1, g1, b1);
+            let b = Color::from_rgb8(r2, g2, b2);
+
+            assert_relative_eq!(a.contrast_ratio(b), ratio, epsilon = 0.005);
+            assert_relative_eq!(b.contrast_ratio(a), ratio, epsilon = 0.005);
+        }
+    }
+
     #[test]
     fn color_manipulation() {
         use approx::assert_relative_eq;
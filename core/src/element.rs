@@ -1,3 +1,4 @@
+use crate::accessibility;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -6,7 +7,7 @@ use crate::renderer;
 use crate::widget;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Clipboard, Color, Layout, Length, Rectangle, Shell, Vector, Widget,
+    Clipboard, Color, Layout, Length, Rectangle, Shadow, Shell, Vector, Widget,
 };
 
 use std::any::Any;
@@ -236,6 +237,21 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
             widget: Box::new(Explain::new(self, color.into())),
         }
     }
+
+    /// Tags the [`Element`] with the given [`widget::Id`].
+    ///
+    /// A tagged [`Element`] can be found by its [`widget::Id`] in the live
+    /// widget tree—even if its widget does not support ids—which is useful
+    /// for end-to-end automation and accessibility tooling.
+    pub fn tag(self, id: widget::Id) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: crate::Renderer + 'a,
+    {
+        Element {
+            widget: Box::new(Tagged { element: self, id }),
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Borrow<dyn Widget<Message, Renderer> + 'a>
@@ -338,6 +354,21 @@ where
                 });
             }
 
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &accessibility::Node,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation
+                    .accessible(id, bounds, node, &mut |operation| {
+                        operate_on_children(&mut MapOperation { operation });
+                    });
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -548,6 +579,7 @@ where
                     border_color: color,
                     border_width: 1.0,
                     border_radius: 0.0.into(),
+                    shadow: Shadow::default(),
                 },
                 Color::TRANSPARENT,
             );
@@ -586,3 +618,118 @@ where
         self.element.widget.overlay(state, layout, renderer)
     }
 }
+
+struct Tagged<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    id: widget::Id,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tagged<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        self.element.widget.tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.element.widget.state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.element.widget.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.element.widget.diff(tree);
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.widget.layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        operation.container(
+            Some(&self.id),
+            layout.bounds(),
+            &mut |operation| {
+                self.element
+                    .widget
+                    .operate(state, layout, renderer, operation);
+            },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.element.widget.on_event(
+            state, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.element
+            .widget
+            .draw(state, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.element
+            .widget
+            .mouse_interaction(state, layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.element.widget.overlay(state, layout, renderer)
+    }
+}
@@ -1,6 +1,7 @@
 //! Handle events of a user interface.
 use crate::keyboard;
 use crate::mouse;
+use crate::pen;
 use crate::touch;
 use crate::window;
 
@@ -24,6 +25,12 @@ pub enum Event {
     /// A touch event
     Touch(touch::Event),
 
+    /// A pen event
+    Pen(pen::Event),
+
+    /// A touch gesture, recognized from the touch events that precede it
+    Gesture(touch::Gesture),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
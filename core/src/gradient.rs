@@ -1,25 +1,40 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians, Rectangle};
 
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from its center.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around its center.
+    Conic(Conic),
 }
 
 impl Gradient {
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        }
+    }
+
     /// Adjust the opacity of the gradient by a multiplier applied to each color stop.
     pub fn mul_alpha(mut self, alpha_multiplier: f32) -> Self {
-        match &mut self {
-            Gradient::Linear(linear) => {
-                for stop in linear.stops.iter_mut().flatten() {
-                    stop.color.a *= alpha_multiplier;
-                }
-            }
+        let stops = match &mut self {
+            Gradient::Linear(linear) => &mut linear.stops,
+            Gradient::Radial(radial) => &mut radial.stops,
+            Gradient::Conic(conic) => &mut conic.stops,
+        };
+
+        for stop in stops.iter_mut().flatten() {
+            stop.color.a *= alpha_multiplier;
         }
 
         self
@@ -32,6 +47,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -70,20 +97,144 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The center of the gradient, relative to its bounds.
+    ///
+    /// `(0.0, 0.0)` is the top left corner and `(1.0, 1.0)` is the bottom
+    /// right one.
+    pub center: Point,
+    /// The radius of the gradient, relative to the distance from its
+    /// center to the farthest corner of its bounds.
+    pub radius: f32,
+    /// [`ColorStop`]s from the center of the gradient outwards.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient centered in its bounds with the
+    /// given relative radius.
+    pub fn new(radius: f32) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            radius,
+            stops: [None; 8],
+        }
+    }
+
+    /// Sets the relative center of the [`Radial`] gradient.
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Calculates the absolute center and radius of the [`Radial`] gradient
+    /// inside the given `bounds`.
+    pub fn to_circle(&self, bounds: &Rectangle) -> (Point, f32) {
+        let center = absolute(self.center, bounds);
+
+        let farthest = [
+            Point::new(bounds.x, bounds.y),
+            Point::new(bounds.x + bounds.width, bounds.y),
+            Point::new(bounds.x, bounds.y + bounds.height),
+            Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        ]
+        .into_iter()
+        .map(|corner| center.distance(corner))
+        .fold(0.0, f32::max);
+
+        (center, self.radius * farthest)
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The center of the gradient, relative to its bounds.
+    ///
+    /// `(0.0, 0.0)` is the top left corner and `(1.0, 1.0)` is the bottom
+    /// right one.
+    pub center: Point,
+    /// The angle where the gradient starts, clockwise from the top.
+    pub angle: Radians,
+    /// [`ColorStop`]s clockwise around the center of the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
 
+impl Conic {
+    /// Creates a new [`Conic`] gradient centered in its bounds that starts at
+    /// the given angle in [`Radians`].
+    pub fn new(angle: impl Into<Radians>) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Sets the relative center of the [`Conic`] gradient.
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Calculates the absolute center of the [`Conic`] gradient inside the
+    /// given `bounds`.
+    pub fn to_center(&self, bounds: &Rectangle) -> Point {
+        absolute(self.center, bounds)
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
         self
     }
 
@@ -101,3 +252,26 @@ impl Linear {
         self
     }
 }
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+            });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn absolute(point: Point, bounds: &Rectangle) -> Point {
+    Point::new(
+        bounds.x + point.x * bounds.width,
+        bounds.y + point.y * bounds.height,
+    )
+}
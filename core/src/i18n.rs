@@ -0,0 +1,520 @@
+//! Format numbers and dates for the language of the user.
+//!
+//! The current [`Locale`] is shared by the whole application, so the
+//! built-in widgets can format their contents without being configured.
+use crate::layout::Direction;
+
+use std::fmt;
+use std::sync::RwLock;
+
+static CURRENT: RwLock<Option<Locale>> = RwLock::new(None);
+
+/// Returns the current [`Locale`] of the application.
+///
+/// It is `en-US` until changed with [`set_locale`].
+pub fn locale() -> Locale {
+    CURRENT
+        .read()
+        .ok()
+        .and_then(|current| current.clone())
+        .unwrap_or_default()
+}
+
+/// Calls the given function with the current [`Locale`], without cloning it.
+pub(crate) fn with_locale<T>(f: impl FnOnce(&Locale) -> T) -> T {
+    let current = CURRENT.read().ok();
+
+    match current.as_ref().and_then(|current| current.as_ref()) {
+        Some(locale) => f(locale),
+        None => f(&Locale::default()),
+    }
+}
+
+/// Changes the current [`Locale`] of the application.
+///
+/// Widgets only pick up the new [`Locale`] once the application is viewed
+/// again.
+pub fn set_locale(locale: Locale) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = Some(locale);
+    }
+}
+
+/// A language, optionally specific to a region.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale(String);
+
+impl Locale {
+    /// Creates a [`Locale`] from its language tag, like `en-US` or `fr`.
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self(tag.into().replace('_', "-"))
+    }
+
+    /// Returns the language tag of the [`Locale`].
+    pub fn tag(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the language of the [`Locale`], like `en`.
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or_default()
+    }
+
+    /// Returns the region of the [`Locale`], like `US`, if any.
+    pub fn region(&self) -> Option<&str> {
+        self.0.split('-').skip(1).find(|subtag| {
+            subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+                || subtag.len() == 3
+                    && subtag.chars().all(|c| c.is_ascii_digit())
+        })
+    }
+
+    /// Returns true if the [`Locale`] is written from right to left.
+    pub fn is_rtl(&self) -> bool {
+        let mut subtags = self.0.split('-');
+        let language = subtags.next().unwrap_or_default();
+
+        // A script subtag overrides the usual script of the language
+        if let Some(script) = subtags.find(|subtag| {
+            subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+        }) {
+            return matches!(
+                script.to_ascii_lowercase().as_str(),
+                "arab" | "hebr" | "thaa" | "syrc" | "nkoo" | "adlm" | "rohg"
+            );
+        }
+
+        matches!(
+            language.to_ascii_lowercase().as_str(),
+            "ar" | "he"
+                | "iw"
+                | "fa"
+                | "ur"
+                | "ps"
+                | "sd"
+                | "yi"
+                | "dv"
+                | "ug"
+                | "ckb"
+        )
+    }
+
+    /// Returns the layout [`Direction`] of the [`Locale`].
+    pub fn direction(&self) -> Direction {
+        if self.is_rtl() {
+            Direction::RightToLeft
+        } else {
+            Direction::LeftToRight
+        }
+    }
+
+    /// Formats the given number with the given amount of decimals, using the
+    /// separators of the [`Locale`].
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        let (decimal, group) = self.separators();
+
+        let formatted = format!("{:.decimals$}", value.abs());
+        let (integer, fraction) = formatted
+            .split_once('.')
+            .map_or((formatted.as_str(), None), |(integer, fraction)| {
+                (integer, Some(fraction))
+            });
+
+        let mut result = String::new();
+
+        if value.is_sign_negative()
+            && formatted.chars().any(|c| c != '0' && c != '.')
+        {
+            result.push('-');
+        }
+
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                result.push(group);
+            }
+
+            result.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            result.push(decimal);
+            result.push_str(fraction);
+        }
+
+        result
+    }
+
+    /// Formats the given date in the numeric format of the [`Locale`].
+    pub fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        match self.language() {
+            "ja" | "zh" => format!("{year}/{month:02}/{day:02}"),
+            "ko" | "hu" => format!("{year}. {month:02}. {day:02}."),
+            "sv" | "lt" => format!("{year}-{month:02}-{day:02}"),
+            "de" | "ru" | "pl" | "cs" | "sk" | "fi" | "nb" | "da" | "tr"
+            | "uk" | "ro" => format!("{day:02}.{month:02}.{year}"),
+            "nl" => format!("{day:02}-{month:02}-{year}"),
+            "en" if matches!(self.region(), None | Some("US")) => {
+                format!("{month}/{day}/{year}")
+            }
+            _ => format!("{day:02}/{month:02}/{year}"),
+        }
+    }
+
+    /// Returns the name of the given month, from `1` to `12`, in the
+    /// language of the [`Locale`].
+    ///
+    /// Languages without translated names use English.
+    pub fn month_name(&self, month: u32, is_abbreviated: bool) -> &'static str {
+        let index = (month.clamp(1, 12) - 1) as usize;
+
+        let names = match (self.language(), is_abbreviated) {
+            ("de", false) => &MONTHS_DE,
+            ("de", true) => &MONTHS_DE_SHORT,
+            ("es", false) => &MONTHS_ES,
+            ("es", true) => &MONTHS_ES_SHORT,
+            ("fr", false) => &MONTHS_FR,
+            ("fr", true) => &MONTHS_FR_SHORT,
+            ("it", false) => &MONTHS_IT,
+            ("it", true) => &MONTHS_IT_SHORT,
+            ("nl", false) => &MONTHS_NL,
+            ("nl", true) => &MONTHS_NL_SHORT,
+            ("pt", false) => &MONTHS_PT,
+            ("pt", true) => &MONTHS_PT_SHORT,
+            (_, false) => &MONTHS_EN,
+            (_, true) => &MONTHS_EN_SHORT,
+        };
+
+        names[index]
+    }
+
+    /// Returns the name of the given day of the week, from `0` (Sunday) to
+    /// `6` (Saturday), in the language of the [`Locale`].
+    ///
+    /// Languages without translated names use English.
+    pub fn weekday_name(
+        &self,
+        weekday: u32,
+        is_abbreviated: bool,
+    ) -> &'static str {
+        let index = (weekday % 7) as usize;
+
+        let names = match (self.language(), is_abbreviated) {
+            ("de", false) => &WEEKDAYS_DE,
+            ("de", true) => &WEEKDAYS_DE_SHORT,
+            ("es", false) => &WEEKDAYS_ES,
+            ("es", true) => &WEEKDAYS_ES_SHORT,
+            ("fr", false) => &WEEKDAYS_FR,
+            ("fr", true) => &WEEKDAYS_FR_SHORT,
+            ("it", false) => &WEEKDAYS_IT,
+            ("it", true) => &WEEKDAYS_IT_SHORT,
+            ("nl", false) => &WEEKDAYS_NL,
+            ("nl", true) => &WEEKDAYS_NL_SHORT,
+            ("pt", false) => &WEEKDAYS_PT,
+            ("pt", true) => &WEEKDAYS_PT_SHORT,
+            (_, false) => &WEEKDAYS_EN,
+            (_, true) => &WEEKDAYS_EN_SHORT,
+        };
+
+        names[index]
+    }
+
+    /// Returns the decimal and group separators of the [`Locale`].
+    fn separators(&self) -> (char, char) {
+        match self.language() {
+            "de" | "es" | "it" | "pt" | "nl" | "da" | "id" | "tr" | "el"
+            | "ro" | "hr" | "sl" => (',', '.'),
+            "fr" => (',', '\u{202F}'),
+            "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "hu"
+            | "lt" => (',', '\u{A0}'),
+            _ => ('.', ','),
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new("en-US")
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(tag: &str) -> Self {
+        Self::new(tag)
+    }
+}
+
+impl From<String> for Locale {
+    fn from(tag: String) -> Self {
+        Self::new(tag)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+const MONTHS_EN: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const MONTHS_EN_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct",
+    "Nov", "Dec",
+];
+
+const MONTHS_DE: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+const MONTHS_DE_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt",
+    "Nov", "Dez",
+];
+
+const MONTHS_ES: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+const MONTHS_ES_SHORT: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct",
+    "nov", "dic",
+];
+
+const MONTHS_FR: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+const MONTHS_FR_SHORT: [&str; 12] = [
+    "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.",
+    "oct.", "nov.", "déc.",
+];
+
+const MONTHS_IT: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+
+const MONTHS_IT_SHORT: [&str; 12] = [
+    "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott",
+    "nov", "dic",
+];
+
+const MONTHS_NL: [&str; 12] = [
+    "januari",
+    "februari",
+    "maart",
+    "april",
+    "mei",
+    "juni",
+    "juli",
+    "augustus",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+const MONTHS_NL_SHORT: [&str; 12] = [
+    "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt",
+    "nov", "dec",
+];
+
+const MONTHS_PT: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+const MONTHS_PT_SHORT: [&str; 12] = [
+    "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out",
+    "nov", "dez",
+];
+
+const WEEKDAYS_EN: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const WEEKDAYS_EN_SHORT: [&str; 7] =
+    ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const WEEKDAYS_DE: [&str; 7] = [
+    "Sonntag",
+    "Montag",
+    "Dienstag",
+    "Mittwoch",
+    "Donnerstag",
+    "Freitag",
+    "Samstag",
+];
+
+const WEEKDAYS_DE_SHORT: [&str; 7] = ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"];
+
+const WEEKDAYS_ES: [&str; 7] = [
+    "domingo",
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+];
+
+const WEEKDAYS_ES_SHORT: [&str; 7] =
+    ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"];
+
+const WEEKDAYS_FR: [&str; 7] = [
+    "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+];
+
+const WEEKDAYS_FR_SHORT: [&str; 7] =
+    ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."];
+
+const WEEKDAYS_IT: [&str; 7] = [
+    "domenica",
+    "lunedì",
+    "martedì",
+    "mercoledì",
+    "giovedì",
+    "venerdì",
+    "sabato",
+];
+
+const WEEKDAYS_IT_SHORT: [&str; 7] =
+    ["dom", "lun", "mar", "mer", "gio", "ven", "sab"];
+
+const WEEKDAYS_NL: [&str; 7] = [
+    "zondag",
+    "maandag",
+    "dinsdag",
+    "woensdag",
+    "donderdag",
+    "vrijdag",
+    "zaterdag",
+];
+
+const WEEKDAYS_NL_SHORT: [&str; 7] = ["zo", "ma", "di", "wo", "do", "vr", "za"];
+
+const WEEKDAYS_PT: [&str; 7] = [
+    "domingo",
+    "segunda-feira",
+    "terça-feira",
+    "quarta-feira",
+    "quinta-feira",
+    "sexta-feira",
+    "sábado",
+];
+
+const WEEKDAYS_PT_SHORT: [&str; 7] =
+    ["dom", "seg", "ter", "qua", "qui", "sex", "sáb"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_use_the_separators_of_the_locale() {
+        let english = Locale::new("en-US");
+        let german = Locale::new("de-DE");
+
+        assert_eq!(english.format_number(1234567.891, 2), "1,234,567.89");
+        assert_eq!(german.format_number(1234567.891, 2), "1.234.567,89");
+        assert_eq!(german.format_number(-999.0, 0), "-999");
+        assert_eq!(english.format_number(-0.001, 1), "0.0");
+    }
+
+    #[test]
+    fn dates_use_the_order_of_the_locale() {
+        assert_eq!(Locale::new("en").format_date(2023, 7, 4), "7/4/2023");
+        assert_eq!(Locale::new("en-GB").format_date(2023, 7, 4), "04/07/2023");
+        assert_eq!(Locale::new("ja_JP").format_date(2023, 7, 4), "2023/07/04");
+    }
+
+    #[test]
+    fn locales_are_split_into_language_and_region() {
+        let locale = Locale::new("zh_Hant_TW");
+
+        assert_eq!(locale.tag(), "zh-Hant-TW");
+        assert_eq!(locale.language(), "zh");
+        assert_eq!(locale.region(), Some("TW"));
+        assert_eq!(Locale::new("fr").region(), None);
+    }
+
+    #[test]
+    fn scripts_decide_the_direction() {
+        assert!(Locale::new("ar-EG").is_rtl());
+        assert!(Locale::new("az-Arab").is_rtl());
+        assert!(!Locale::new("ug-Latn").is_rtl());
+        assert!(!Locale::new("en-US").is_rtl());
+    }
+}
@@ -1,15 +1,18 @@
 //! Load and draw raster graphics.
-use crate::{Hasher, Rectangle, Size};
+use crate::{Color, Hasher, Padding, Point, Rectangle, Size};
 
+use std::any::Any;
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// A handle of some image data.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Handle {
     id: u64,
     data: Data,
+    respects_exif: bool,
+    tone_mapping: ToneMapping,
 }
 
 impl Handle {
@@ -37,6 +40,120 @@ impl Handle {
         })
     }
 
+    /// Creates an image [`Handle`] containing a single frame of a stream of
+    /// RGBA pixels; like the frames of a video.
+    ///
+    /// Unlike [`Handle::from_pixels`], the pixels are not hashed to produce
+    /// the identifier of the [`Handle`]. Instead, every frame gets a new
+    /// unique identifier; which is considerably cheaper when the pixels
+    /// change constantly.
+    pub fn from_frame(
+        width: u32,
+        height: u32,
+        pixels: impl AsRef<[u8]> + Send + Sync + 'static,
+    ) -> Handle {
+        use std::sync::atomic::{self, AtomicU64};
+
+        static NEXT_FRAME: AtomicU64 = AtomicU64::new(0);
+
+        let mut hasher = Hasher::default();
+        "frame".hash(&mut hasher);
+        NEXT_FRAME
+            .fetch_add(1, atomic::Ordering::Relaxed)
+            .hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Data::Rgba {
+                width,
+                height,
+                pixels: Bytes::new(pixels),
+            },
+            respects_exif: true,
+            tone_mapping: ToneMapping::default(),
+        }
+    }
+
+    /// Creates an image [`Handle`] of the given size pointing to a texture
+    /// that already lives in the GPU; like the frames of a video decoder or a
+    /// camera.
+    ///
+    /// The texture is copied by the GPU when drawn, so the pixels never go
+    /// through the CPU. Which textures are supported depends on the renderer;
+    /// renderers that do not support them draw nothing.
+    ///
+    /// The `wgpu` renderer supports a `wgpu::Texture`—or an `Arc` of it—
+    /// created with its same device, with an `Rgba8UnormSrgb` format and a
+    /// `COPY_SRC` usage. Platform buffers, like DMA-BUFs or IOSurfaces, can be
+    /// imported as such a texture with the HAL of `wgpu`.
+    ///
+    /// Just like [`Handle::from_frame`], every [`Handle`] gets a new unique
+    /// identifier; so create a new one whenever the texture changes.
+    pub fn from_texture(
+        width: u32,
+        height: u32,
+        texture: impl Any + Send + Sync,
+    ) -> Handle {
+        use std::sync::atomic::{self, AtomicU64};
+
+        static NEXT_TEXTURE: AtomicU64 = AtomicU64::new(0);
+
+        let mut hasher = Hasher::default();
+        "texture".hash(&mut hasher);
+        NEXT_TEXTURE
+            .fetch_add(1, atomic::Ordering::Relaxed)
+            .hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Data::Texture {
+                width,
+                height,
+                texture: Texture(Arc::new(texture)),
+            },
+            respects_exif: true,
+            tone_mapping: ToneMapping::default(),
+        }
+    }
+
+    /// Creates an image [`Handle`] containing a raw video frame of the given
+    /// size, made of the given [`Plane`] of pixels in the given [`Format`].
+    ///
+    /// Frames straight out of a video decoder can be drawn this way, without
+    /// converting them to RGBA first. Renderers convert them as they see
+    /// fit; the `wgpu` renderer converts them in the GPU.
+    ///
+    /// Just like [`Handle::from_frame`], every [`Handle`] gets a new unique
+    /// identifier; so create a new one for every frame.
+    pub fn from_raw_parts(
+        width: u32,
+        height: u32,
+        format: Format,
+        planes: impl IntoIterator<Item = Plane>,
+    ) -> Handle {
+        use std::sync::atomic::{self, AtomicU64};
+
+        static NEXT_RAW: AtomicU64 = AtomicU64::new(0);
+
+        let mut hasher = Hasher::default();
+        "raw".hash(&mut hasher);
+        NEXT_RAW
+            .fetch_add(1, atomic::Ordering::Relaxed)
+            .hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Data::Raw {
+                width,
+                height,
+                format,
+                planes: planes.into_iter().collect(),
+            },
+            respects_exif: true,
+            tone_mapping: ToneMapping::default(),
+        }
+    }
+
     /// Creates an image [`Handle`] containing the image data directly.
     ///
     /// Makes an educated guess about the image format by examining the given data.
@@ -56,9 +173,51 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             data,
+            respects_exif: true,
+            tone_mapping: ToneMapping::default(),
         }
     }
 
+    /// Ignores the EXIF metadata of the image of the [`Handle`].
+    ///
+    /// By default, images are rotated and mirrored as their EXIF metadata
+    /// says when decoded; which is how photos taken with a phone end up
+    /// upright. Ignoring it displays the pixels as they are stored.
+    pub fn ignore_exif(mut self) -> Self {
+        let mut hasher = Hasher::default();
+        self.id.hash(&mut hasher);
+        "ignore_exif".hash(&mut hasher);
+
+        self.id = hasher.finish();
+        self.respects_exif = false;
+        self
+    }
+
+    /// Returns true if the EXIF metadata of the image of the [`Handle`] is
+    /// applied when decoding it.
+    pub fn respects_exif(&self) -> bool {
+        self.respects_exif
+    }
+
+    /// Sets the [`ToneMapping`] of the image of the [`Handle`].
+    ///
+    /// It is only applied to high dynamic range images—like Radiance HDR or
+    /// OpenEXR files—when they are drawn on a standard dynamic range surface.
+    pub fn with_tone_mapping(mut self, tone_mapping: ToneMapping) -> Self {
+        let mut hasher = Hasher::default();
+        self.id.hash(&mut hasher);
+        tone_mapping.hash(&mut hasher);
+
+        self.id = hasher.finish();
+        self.tone_mapping = tone_mapping;
+        self
+    }
+
+    /// Returns the [`ToneMapping`] of the image of the [`Handle`].
+    pub fn tone_mapping(&self) -> ToneMapping {
+        self.tone_mapping
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> u64 {
         self.id
@@ -124,6 +283,40 @@ impl AsRef<[u8]> for Bytes {
     }
 }
 
+/// A texture living in the GPU, owned by a renderer-specific type.
+///
+/// Two [`Texture`] are only equal if they share the same owner.
+#[derive(Clone)]
+pub struct Texture(Arc<dyn Any + Send + Sync>);
+
+impl Texture {
+    /// Returns a reference to the owner of the [`Texture`], if it is of the
+    /// given type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for Texture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Texture(...)")
+    }
+}
+
+impl std::hash::Hash for Texture {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+impl PartialEq for Texture {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Texture {}
+
 impl std::ops::Deref for Bytes {
     type Target = [u8];
 
@@ -141,6 +334,9 @@ pub enum Data {
     /// In-memory data
     Bytes(Bytes),
 
+    /// In-memory data that has only partially arrived; see [`Stream`].
+    Partial(Bytes),
+
     /// Decoded image pixels in RGBA format.
     Rgba {
         /// The width of the image.
@@ -150,6 +346,28 @@ pub enum Data {
         /// The pixels.
         pixels: Bytes,
     },
+
+    /// A texture living in the GPU.
+    Texture {
+        /// The width of the texture.
+        width: u32,
+        /// The height of the texture.
+        height: u32,
+        /// The texture.
+        texture: Texture,
+    },
+
+    /// A raw video frame.
+    Raw {
+        /// The width of the frame.
+        width: u32,
+        /// The height of the frame.
+        height: u32,
+        /// The [`Format`] of the pixels.
+        format: Format,
+        /// The [`Plane`] of pixels, as many as the [`Format`] needs.
+        planes: Arc<[Plane]>,
+    },
 }
 
 impl std::fmt::Debug for Data {
@@ -157,9 +375,599 @@ impl std::fmt::Debug for Data {
         match self {
             Data::Path(path) => write!(f, "Path({path:?})"),
             Data::Bytes(_) => write!(f, "Bytes(...)"),
+            Data::Partial(bytes) => write!(f, "Partial({} bytes)", bytes.len()),
             Data::Rgba { width, height, .. } => {
                 write!(f, "Pixels({width} * {height})")
             }
+            Data::Texture { width, height, .. } => {
+                write!(f, "Texture({width} * {height})")
+            }
+            Data::Raw {
+                width,
+                height,
+                format,
+                ..
+            } => {
+                write!(f, "Raw({format:?}, {width} * {height})")
+            }
+        }
+    }
+}
+
+/// The encoded data of an image that arrives over time; like an image that
+/// is being downloaded.
+///
+/// A [`Stream`] produces a new [`Handle`] every time more data arrives, so
+/// the image is displayed incrementally instead of popping in at the end.
+/// How much of a partial image can be displayed depends on the renderer and
+/// the image format; progressive JPEG images get sharper with every scan.
+///
+/// Cloning a [`Stream`] is cheap, since its data is shared; so one clone
+/// can receive the data in the background while another one is displayed.
+#[derive(Clone)]
+pub struct Stream {
+    id: u64,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    bytes: Vec<u8>,
+    is_finished: bool,
+    handle: Option<Handle>,
+}
+
+impl Stream {
+    /// Creates a new empty [`Stream`].
+    pub fn new() -> Self {
+        use std::sync::atomic::{self, AtomicU64};
+
+        static NEXT_STREAM: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_STREAM.fetch_add(1, atomic::Ordering::Relaxed),
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// Appends the given bytes to the data of the [`Stream`].
+    pub fn push(&self, bytes: &[u8]) {
+        let mut state = self.state();
+
+        if state.is_finished || bytes.is_empty() {
+            return;
+        }
+
+        state.bytes.extend_from_slice(bytes);
+        state.handle = None;
+    }
+
+    /// Marks the data of the [`Stream`] as complete.
+    pub fn finish(&self) {
+        let mut state = self.state();
+
+        if !state.is_finished {
+            state.is_finished = true;
+            state.handle = None;
+        }
+    }
+
+    /// Returns true if the data of the [`Stream`] is complete.
+    pub fn is_finished(&self) -> bool {
+        self.state().is_finished
+    }
+
+    /// Returns the amount of bytes received by the [`Stream`] so far.
+    pub fn received(&self) -> usize {
+        self.state().bytes.len()
+    }
+
+    /// Returns the [`Handle`] of the data received by the [`Stream`] so far,
+    /// if any.
+    ///
+    /// The [`Handle`] stays the same until more data arrives.
+    pub fn handle(&self) -> Option<Handle> {
+        let mut state = self.state();
+
+        if state.bytes.is_empty() {
+            return None;
+        }
+
+        if state.handle.is_none() {
+            let mut hasher = Hasher::default();
+            "stream".hash(&mut hasher);
+            self.id.hash(&mut hasher);
+            state.bytes.len().hash(&mut hasher);
+            state.is_finished.hash(&mut hasher);
+
+            let bytes = Bytes::new(state.bytes.clone());
+
+            state.handle = Some(Handle {
+                id: hasher.finish(),
+                data: if state.is_finished {
+                    Data::Bytes(bytes)
+                } else {
+                    Data::Partial(bytes)
+                },
+                respects_exif: true,
+                tone_mapping: ToneMapping::default(),
+            });
+        }
+
+        state.handle.clone()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for Stream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state();
+
+        f.debug_struct("Stream")
+            .field("id", &self.id)
+            .field("received", &state.bytes.len())
+            .field("is_finished", &state.is_finished)
+            .finish()
+    }
+}
+
+impl Default for Stream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The format of the pixels of a raw video frame.
+///
+/// The chroma planes of the YUV formats are subsampled by 2 in both axes,
+/// and their colors are converted to RGB with the limited range BT.709
+/// coefficients; like most HD video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// A single plane of 8-bit blue, green, red, and alpha channels.
+    Bgra,
+
+    /// A plane of 8-bit luma samples, followed by a plane of interleaved
+    /// 8-bit blue and red chroma samples.
+    Nv12,
+
+    /// A plane of 8-bit luma samples, followed by a plane of 8-bit blue
+    /// chroma samples and a plane of 8-bit red chroma samples.
+    I420,
+}
+
+impl Format {
+    /// Returns the amount of planes of a frame in this [`Format`].
+    pub fn planes(self) -> usize {
+        match self {
+            Format::Bgra => 1,
+            Format::Nv12 => 2,
+            Format::I420 => 3,
+        }
+    }
+
+    /// Returns the amount of bytes of every sample of the given plane.
+    pub fn bytes_per_sample(self, plane: usize) -> u32 {
+        match (self, plane) {
+            (Format::Bgra, _) => 4,
+            (Format::Nv12, 1) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the size, in samples, of the given plane of a frame of the
+    /// given size.
+    pub fn plane_size(self, plane: usize, size: Size<u32>) -> Size<u32> {
+        if plane == 0 {
+            size
+        } else {
+            Size::new(size.width.div_ceil(2), size.height.div_ceil(2))
+        }
+    }
+
+    /// Converts the given [`Plane`] of a frame of the given size in this
+    /// [`Format`] to RGBA pixels in the CPU.
+    ///
+    /// Returns `None` if some plane is missing or too short.
+    pub fn to_rgba(self, size: Size<u32>, planes: &[Plane]) -> Option<Vec<u8>> {
+        if planes.len() < self.planes() {
+            return None;
+        }
+
+        let mut rows = Vec::with_capacity(self.planes());
+
+        let mut pixels =
+            Vec::with_capacity(size.width as usize * size.height as usize * 4);
+
+        for y in 0..size.height {
+            rows.clear();
+
+            for (i, plane) in planes.iter().take(self.planes()).enumerate() {
+                let plane_size = self.plane_size(i, size);
+                let row = if i == 0 { y } else { y / 2 };
+
+                rows.push(
+                    plane.row(
+                        row,
+                        plane_size.width * self.bytes_per_sample(i),
+                    )?,
+                );
+            }
+
+            for x in 0..size.width as usize {
+                let (luma, cb, cr) = match self {
+                    Format::Bgra => {
+                        let [b, g, r, a] = [0, 1, 2, 3]
+                            .map(|channel| rows[0][x * 4 + channel]);
+
+                        pixels.extend([r, g, b, a]);
+                        continue;
+                    }
+                    Format::Nv12 => {
+                        (rows[0][x], rows[1][x / 2 * 2], rows[1][x / 2 * 2 + 1])
+                    }
+                    Format::I420 => {
+                        (rows[0][x], rows[1][x / 2], rows[2][x / 2])
+                    }
+                };
+
+                let luma = (f32::from(luma) - 16.0) * 1.164_383;
+                let cb = f32::from(cb) - 128.0;
+                let cr = f32::from(cr) - 128.0;
+
+                pixels.extend(
+                    [
+                        luma + 1.792_741 * cr,
+                        luma - 0.213_249 * cb - 0.532_909 * cr,
+                        luma + 2.112_402 * cb,
+                        255.0,
+                    ]
+                    .map(|channel| channel.round().clamp(0.0, 255.0) as u8),
+                );
+            }
+        }
+
+        Some(pixels)
+    }
+}
+
+/// A plane of pixels of a raw video frame.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Plane {
+    /// The bytes of the plane.
+    pub bytes: Bytes,
+    /// The amount of bytes between the start of two consecutive rows, which
+    /// may include some padding.
+    pub stride: u32,
+}
+
+impl Plane {
+    /// Creates a new [`Plane`] with the given bytes and stride.
+    pub fn new(
+        bytes: impl AsRef<[u8]> + Send + Sync + 'static,
+        stride: u32,
+    ) -> Self {
+        Self {
+            bytes: Bytes::new(bytes),
+            stride,
+        }
+    }
+
+    /// Returns the bytes of the given row of the [`Plane`], which holds the
+    /// given amount of bytes, if it is complete.
+    pub fn row(&self, row: u32, bytes: u32) -> Option<&[u8]> {
+        let start = row as usize * self.stride as usize;
+
+        self.bytes.get(start..start + bytes as usize)
+    }
+}
+
+/// A clockwise rotation of an image, in quarter turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rotation {
+    /// No rotation.
+    #[default]
+    None,
+    /// A quarter turn clockwise.
+    Clockwise90,
+    /// A half turn.
+    Half,
+    /// A quarter turn counterclockwise.
+    Counterclockwise90,
+}
+
+impl Rotation {
+    /// Returns the [`Rotation`] resulting from rotating by an additional
+    /// quarter turn clockwise.
+    pub fn clockwise(self) -> Self {
+        match self {
+            Self::None => Self::Clockwise90,
+            Self::Clockwise90 => Self::Half,
+            Self::Half => Self::Counterclockwise90,
+            Self::Counterclockwise90 => Self::None,
+        }
+    }
+
+    /// Returns the [`Rotation`] resulting from rotating by an additional
+    /// quarter turn counterclockwise.
+    pub fn counterclockwise(self) -> Self {
+        match self {
+            Self::None => Self::Counterclockwise90,
+            Self::Clockwise90 => Self::None,
+            Self::Half => Self::Clockwise90,
+            Self::Counterclockwise90 => Self::Half,
+        }
+    }
+
+    /// Returns the number of quarter turns clockwise of the [`Rotation`].
+    pub fn quarter_turns(self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Clockwise90 => 1,
+            Self::Half => 2,
+            Self::Counterclockwise90 => 3,
+        }
+    }
+}
+
+/// The strategy used to fit the colors of a high dynamic range image into
+/// the range of a standard dynamic range surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ToneMapping {
+    /// Clips the colors brighter than white.
+    Clamp,
+
+    /// Compresses the highlights smoothly, using the Reinhard operator.
+    Reinhard,
+
+    /// Compresses the highlights with a filmic curve, approximating the
+    /// ACES reference rendering transform.
+    #[default]
+    Aces,
+}
+
+impl ToneMapping {
+    /// Maps a linear color channel of any brightness to the `[0, 1]` range.
+    pub fn apply(self, channel: f32) -> f32 {
+        let channel = channel.max(0.0);
+
+        let mapped = match self {
+            Self::Clamp => channel,
+            Self::Reinhard => channel / (1.0 + channel),
+            Self::Aces => {
+                (channel * (2.51 * channel + 0.03))
+                    / (channel * (2.43 * channel + 0.59) + 0.14)
+            }
+        };
+
+        mapped.clamp(0.0, 1.0)
+    }
+}
+
+/// The filtering method used when an image is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilterMethod {
+    /// Takes the nearest pixel; which keeps pixel art crisp.
+    Nearest,
+
+    /// Interpolates the nearest pixels linearly.
+    #[default]
+    Linear,
+
+    /// Interpolates linearly between the pixels of the two nearest
+    /// mipmaps; which avoids aliasing when an image is heavily scaled down.
+    Trilinear,
+
+    /// Like [`FilterMethod::Trilinear`], but it also takes more samples
+    /// along the axis an image is stretched the most; which keeps skewed
+    /// and very thin images sharp.
+    Anisotropic,
+}
+
+/// The orientation an image is drawn with.
+///
+/// The [`Rotation`] is applied first and the flips afterwards, along the
+/// axes of the rotated image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Orientation {
+    /// The [`Rotation`] of the image.
+    pub rotation: Rotation,
+    /// Whether the image is mirrored horizontally.
+    pub flip_horizontal: bool,
+    /// Whether the image is mirrored vertically.
+    pub flip_vertical: bool,
+}
+
+impl Orientation {
+    /// Returns true if the width and height of an image are swapped when it
+    /// is drawn with this [`Orientation`].
+    pub fn is_transposed(self) -> bool {
+        matches!(
+            self.rotation,
+            Rotation::Clockwise90 | Rotation::Counterclockwise90
+        )
+    }
+
+    /// Returns the size of an image of the given size once drawn with this
+    /// [`Orientation`].
+    pub fn apply_to_size<T>(self, size: Size<T>) -> Size<T> {
+        if self.is_transposed() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+
+    /// Maps a point of an image, normalized to the `[0, 1]` range, to its
+    /// normalized position once the image is drawn with this [`Orientation`].
+    pub fn apply(self, point: Point) -> Point {
+        let Point { x, y } = point;
+
+        let (x, y) = match self.rotation {
+            Rotation::None => (x, y),
+            Rotation::Clockwise90 => (1.0 - y, x),
+            Rotation::Half => (1.0 - x, 1.0 - y),
+            Rotation::Counterclockwise90 => (y, 1.0 - x),
+        };
+
+        Point::new(
+            if self.flip_horizontal { 1.0 - x } else { x },
+            if self.flip_vertical { 1.0 - y } else { y },
+        )
+    }
+}
+
+/// The color effects an image is drawn with.
+///
+/// The effects are applied in the order of the fields: first the grayscale,
+/// then the sepia, the brightness, the contrast, the tint, and finally the
+/// opacity. The default [`Effects`] leave an image untouched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Effects {
+    /// The amount of grayscale, from `0.0` to `1.0`.
+    pub grayscale: f32,
+    /// The amount of sepia, from `0.0` to `1.0`.
+    pub sepia: f32,
+    /// The brightness multiplier; `1.0` keeps the brightness of the image.
+    pub brightness: f32,
+    /// The contrast multiplier; `1.0` keeps the contrast of the image.
+    pub contrast: f32,
+    /// The [`Color`] every pixel is multiplied by, if any.
+    pub tint: Option<Color>,
+    /// The opacity, from `0.0` to `1.0`.
+    pub opacity: f32,
+}
+
+impl Effects {
+    /// Returns true if the [`Effects`] leave an image untouched.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the [`Effects`] as a single color matrix.
+    ///
+    /// The matrix has 4 rows—one for each of the red, green, blue, and alpha
+    /// channels of the result—of 5 columns: the factors of the red, green,
+    /// blue, and alpha channels of a pixel—with straight alpha—and an
+    /// offset.
+    pub fn color_matrix(&self) -> [f32; 20] {
+        let grayscale = {
+            let s = 1.0 - self.grayscale.clamp(0.0, 1.0);
+
+            [
+                [
+                    0.2126 + 0.7874 * s,
+                    0.7152 - 0.7152 * s,
+                    0.0722 - 0.0722 * s,
+                ],
+                [
+                    0.2126 - 0.2126 * s,
+                    0.7152 + 0.2848 * s,
+                    0.0722 - 0.0722 * s,
+                ],
+                [
+                    0.2126 - 0.2126 * s,
+                    0.7152 - 0.7152 * s,
+                    0.0722 + 0.9278 * s,
+                ],
+            ]
+        };
+
+        let sepia = {
+            let s = 1.0 - self.sepia.clamp(0.0, 1.0);
+
+            [
+                [0.393 + 0.607 * s, 0.769 - 0.769 * s, 0.189 - 0.189 * s],
+                [0.349 - 0.349 * s, 0.686 + 0.314 * s, 0.168 - 0.168 * s],
+                [0.272 - 0.272 * s, 0.534 - 0.534 * s, 0.131 + 0.869 * s],
+            ]
+        };
+
+        let brightness = self.brightness.max(0.0);
+        let contrast = self.contrast.max(0.0);
+        let tint = self.tint.unwrap_or(Color::WHITE);
+        let tint = [tint.r, tint.g, tint.b];
+
+        let mut matrix = [0.0; 20];
+
+        for row in 0..3 {
+            // The sepia is applied to the grayscale result
+            let mut factors = [0.0; 3];
+
+            for (column, factor) in factors.iter_mut().enumerate() {
+                *factor = (0..3)
+                    .map(|i| sepia[row][i] * grayscale[i][column])
+                    .sum::<f32>();
+            }
+
+            let scale = brightness * contrast * tint[row];
+
+            for column in 0..3 {
+                matrix[row * 5 + column] = factors[column] * scale;
+            }
+
+            matrix[row * 5 + 4] = (0.5 - 0.5 * contrast) * tint[row];
+        }
+
+        matrix[18] =
+            self.tint.map_or(1.0, |tint| tint.a) * self.opacity.clamp(0.0, 1.0);
+
+        matrix
+    }
+}
+
+impl Default for Effects {
+    fn default() -> Self {
+        Self {
+            grayscale: 0.0,
+            sepia: 0.0,
+            brightness: 1.0,
+            contrast: 1.0,
+            tint: None,
+            opacity: 1.0,
+        }
+    }
+}
+
+/// An image to be drawn by a [`Renderer`].
+///
+/// Create one with [`Image::new`] and change the rest of its fields as
+/// needed; they all default to drawing the whole image untouched.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Image<H = Handle> {
+    /// The handle of the image.
+    pub handle: H,
+    /// The [`FilterMethod`] used when the image is scaled.
+    pub filter_method: FilterMethod,
+    /// The [`Orientation`] of the image.
+    pub orientation: Orientation,
+    /// The [`Effects`] applied to the colors of the image.
+    pub effects: Effects,
+    /// The rectangle of the image to draw, in pixels, or the whole image if
+    /// `None`; like a sprite of a sprite sheet.
+    ///
+    /// It is cut before the [`Orientation`] is applied.
+    pub region: Option<Rectangle>,
+    /// The bounds of the image once cut and oriented.
+    pub bounds: Rectangle,
+}
+
+impl<H> Image<H> {
+    /// Creates a new [`Image`] with the given handle, filling the given
+    /// bounds.
+    pub fn new(handle: H, bounds: Rectangle) -> Self {
+        Self {
+            handle,
+            filter_method: FilterMethod::default(),
+            orientation: Orientation::default(),
+            effects: Effects::default(),
+            region: None,
+            bounds,
         }
     }
 }
@@ -176,7 +984,93 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an image for the given [`Handle`].
     fn dimensions(&self, handle: &Self::Handle) -> Size<u32>;
 
-    /// Draws an image with the given [`Handle`] and inside the provided
-    /// `bounds`.
-    fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+    /// Draws the given [`Image`].
+    fn draw(&mut self, image: Image<Self::Handle>);
+
+    /// Draws the given [`Image`] using nine-slice scaling.
+    ///
+    /// The `insets`, in pixels of the image, cut it into nine slices. The
+    /// corners keep their size, the edges are stretched along one axis, and
+    /// the center is stretched along both; see [`nine_slice`]. The region
+    /// and the orientation of the [`Image`] are ignored.
+    ///
+    /// By default, the whole image is stretched to fit its bounds.
+    fn draw_nine_slice(
+        &mut self,
+        image: Image<Self::Handle>,
+        _insets: Padding,
+    ) {
+        self.draw(Image {
+            region: None,
+            orientation: Orientation::default(),
+            ..image
+        });
+    }
+}
+
+/// Cuts an image of the given size into nine slices with the given `insets`
+/// and lays them out inside the provided `bounds`.
+///
+/// Returns the region of the image and the bounds of every slice that is not
+/// empty. The corners keep the size of their region, unless the `bounds` are
+/// too small to fit them; in which case they are shrunk.
+pub fn nine_slice(
+    size: Size<u32>,
+    insets: Padding,
+    bounds: Rectangle,
+) -> Vec<(Rectangle, Rectangle)> {
+    let width = size.width as f32;
+    let height = size.height as f32;
+
+    let left = insets.left.clamp(0.0, width);
+    let right = insets.right.clamp(0.0, width - left);
+    let top = insets.top.clamp(0.0, height);
+    let bottom = insets.bottom.clamp(0.0, height - top);
+
+    let scale_x = (bounds.width / (left + right)).min(1.0);
+    let scale_y = (bounds.height / (top + bottom)).min(1.0);
+
+    let columns = [
+        (0.0, bounds.x),
+        (left, bounds.x + left * scale_x),
+        (width - right, bounds.x + bounds.width - right * scale_x),
+        (width, bounds.x + bounds.width),
+    ];
+
+    let rows = [
+        (0.0, bounds.y),
+        (top, bounds.y + top * scale_y),
+        (height - bottom, bounds.y + bounds.height - bottom * scale_y),
+        (height, bounds.y + bounds.height),
+    ];
+
+    let mut slices = Vec::with_capacity(9);
+
+    for row in rows.windows(2) {
+        for column in columns.windows(2) {
+            let region = Rectangle {
+                x: column[0].0,
+                y: row[0].0,
+                width: column[1].0 - column[0].0,
+                height: row[1].0 - row[0].0,
+            };
+
+            let bounds = Rectangle {
+                x: column[0].1,
+                y: row[0].1,
+                width: column[1].1 - column[0].1,
+                height: row[1].1 - row[0].1,
+            };
+
+            if region.width > 0.0
+                && region.height > 0.0
+                && bounds.width > 0.0
+                && bounds.height > 0.0
+            {
+                slices.push((region, bounds));
+            }
+        }
+    }
+
+    slices
 }
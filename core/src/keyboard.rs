@@ -2,7 +2,9 @@
 mod event;
 mod key_code;
 mod modifiers;
+mod repeat;
 
 pub use event::Event;
 pub use key_code::KeyCode;
 pub use modifiers::Modifiers;
+pub use repeat::Repeat;
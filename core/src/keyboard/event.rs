@@ -15,6 +15,15 @@ pub enum Event {
 
         /// The state of the modifier keys
         modifiers: Modifiers,
+
+        /// The platform-specific code of the physical key, independent of
+        /// the keyboard layout.
+        ///
+        /// It is `None` if the key press was not produced by a keyboard.
+        scancode: Option<u32>,
+
+        /// Whether the key press was produced by holding the key down.
+        is_repeat: bool,
     },
 
     /// A keyboard key was released.
@@ -24,6 +33,12 @@ pub enum Event {
 
         /// The state of the modifier keys
         modifiers: Modifiers,
+
+        /// The platform-specific code of the physical key, independent of
+        /// the keyboard layout.
+        ///
+        /// It is `None` if the key release was not produced by a keyboard.
+        scancode: Option<u32>,
     },
 
     /// A unicode character was received.
@@ -0,0 +1,22 @@
+use crate::time::Duration;
+
+/// How keys that are held down repeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Repeat {
+    /// Keys repeat with the delay and rate configured in the system.
+    #[default]
+    System,
+
+    /// Keys never repeat.
+    Disabled,
+
+    /// Keys repeat with the given delay and interval, ignoring the settings
+    /// of the system.
+    Custom {
+        /// The time a key needs to be held before it starts repeating.
+        delay: Duration,
+
+        /// The time between two repeats.
+        interval: Duration,
+    },
+}
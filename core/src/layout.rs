@@ -1,9 +1,11 @@
 //! Position your widgets properly.
+mod direction;
 mod limits;
 mod node;
 
 pub mod flex;
 
+pub use direction::Direction;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -65,6 +67,8 @@ impl<'a> Layout<'a> {
 }
 
 /// Produces a [`Node`] with two children nodes one right next to each other.
+///
+/// The nodes are swapped when the current [`Direction`] is right to left.
 pub fn next_to_each_other(
     limits: &Limits,
     spacing: f32,
@@ -88,11 +92,15 @@ pub fn next_to_each_other(
     left_node.move_to(Point::new(0.0, left_y));
     right_node.move_to(Point::new(left_size.width + spacing, right_y));
 
-    Node::with_children(
-        Size::new(
-            left_size.width + spacing + right_size.width,
-            left_size.height.max(right_size.height),
-        ),
-        vec![left_node, right_node],
-    )
+    let size = Size::new(
+        left_size.width + spacing + right_size.width,
+        left_size.height.max(right_size.height),
+    );
+
+    if Direction::current().is_rtl() {
+        left_node.mirror(size.width);
+        right_node.mirror(size.width);
+    }
+
+    Node::with_children(size, vec![left_node, right_node])
 }
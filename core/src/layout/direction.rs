@@ -0,0 +1,25 @@
+use crate::i18n;
+
+/// The horizontal direction in which content flows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Content flows from left to right.
+    #[default]
+    LeftToRight,
+    /// Content flows from right to left, mirroring the layout.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns the [`Direction`] of the current [`Locale`].
+    ///
+    /// [`Locale`]: i18n::Locale
+    pub fn current() -> Self {
+        i18n::with_locale(i18n::Locale::direction)
+    }
+
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+}
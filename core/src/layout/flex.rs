@@ -18,7 +18,7 @@
 // limitations under the License.
 use crate::Element;
 
-use crate::layout::{Limits, Node};
+use crate::layout::{Direction, Limits, Node};
 use crate::widget;
 use crate::{Alignment, Padding, Point, Size};
 
@@ -58,6 +58,10 @@ impl Axis {
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
+/// The items are mirrored when the current [`Direction`] is right to left,
+/// which reverses their order in a row and their alignment and padding in a
+/// column.
+///
 /// It returns a new layout [`Node`].
 pub fn resolve<Message, Renderer>(
     axis: Axis,
@@ -179,7 +183,13 @@ where
     }
 
     let (width, height) = axis.pack(main - pad.0, cross);
-    let size = limits.resolve(Size::new(width, height));
+    let size = limits.resolve(Size::new(width, height)).pad(padding);
+
+    if Direction::current().is_rtl() {
+        for node in &mut nodes {
+            node.mirror(size.width);
+        }
+    }
 
-    Node::with_children(size.pad(padding), nodes)
+    Node::with_children(size, nodes)
 }
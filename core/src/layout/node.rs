@@ -75,6 +75,12 @@ impl Node {
         self.bounds.y = position.y;
     }
 
+    /// Mirrors the horizontal position of the [`Node`] inside a parent of the
+    /// given width.
+    pub fn mirror(&mut self, width: f32) {
+        self.bounds.x = width - self.bounds.x - self.bounds.width;
+    }
+
     /// Translates the [`Node`] by the given translation.
     pub fn translate(self, translation: Vector) -> Self {
         Self {
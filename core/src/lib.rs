@@ -16,20 +16,25 @@
     unused_results,
     rustdoc::broken_intra_doc_links
 )]
+pub mod accessibility;
 pub mod alignment;
+pub mod cascade;
 pub mod clipboard;
 pub mod event;
 pub mod font;
 pub mod gradient;
+pub mod i18n;
 pub mod image;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
 pub mod overlay;
+pub mod pen;
 pub mod renderer;
 pub mod svg;
 pub mod text;
 pub mod time;
+pub mod token;
 pub mod touch;
 pub mod widget;
 pub mod window;
@@ -46,6 +51,7 @@ mod padding;
 mod pixels;
 mod point;
 mod rectangle;
+mod shadow;
 mod shell;
 mod size;
 mod vector;
@@ -70,6 +76,7 @@ pub use pixels::Pixels;
 pub use point::Point;
 pub use rectangle::Rectangle;
 pub use renderer::Renderer;
+pub use shadow::Shadow;
 pub use shell::Shell;
 pub use size::Size;
 pub use text::Text;
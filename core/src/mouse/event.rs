@@ -1,4 +1,4 @@
-use crate::Point;
+use crate::{Point, Vector};
 
 use super::Button;
 
@@ -22,6 +22,18 @@ pub enum Event {
         position: Point,
     },
 
+    /// The mouse was moved, no matter where the cursor is.
+    ///
+    /// Unlike [`Event::CursorMoved`], it keeps being reported while the
+    /// cursor is grabbed or at the edge of the screen.
+    RelativeMotion {
+        /// The movement of the mouse, in raw device units.
+        ///
+        /// It is affected by neither the scale factor nor the acceleration
+        /// of the cursor.
+        delta: Vector,
+    },
+
     /// A mouse button was pressed.
     ButtonPressed(Button),
 
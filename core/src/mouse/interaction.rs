@@ -12,5 +12,9 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    ResizingDiagonallyUp,
+    ResizingDiagonallyDown,
     NotAllowed,
+    /// The cursor is not shown.
+    Hidden,
 }
@@ -1,5 +1,6 @@
 pub use crate::Overlay;
 
+use crate::accessibility;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -182,6 +183,21 @@ where
                 });
             }
 
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &accessibility::Node,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation
+                    .accessible(id, bounds, node, &mut |operation| {
+                        operate_on_children(&mut MapOperation { operation });
+                    });
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
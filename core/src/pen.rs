@@ -0,0 +1,70 @@
+//! Handle pen and stylus input.
+use crate::Point;
+
+/// A pen interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub enum Event {
+    /// The pen touched the surface.
+    Pressed { position: Point, state: State },
+
+    /// The pen moved while touching the surface.
+    Moved { position: Point, state: State },
+
+    /// The pen was lifted from the surface.
+    Lifted { position: Point, state: State },
+
+    /// The pen interaction was canceled.
+    Lost { position: Point },
+}
+
+/// The state of a pen at the time of an [`Event`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    /// The pressure of the pen, from `0.0` to `1.0`.
+    pub pressure: f32,
+
+    /// The [`Tilt`] of the pen.
+    pub tilt: Tilt,
+
+    /// The [`Tool`] touching the surface.
+    pub tool: Tool,
+
+    /// Whether the barrel button of the pen is pressed.
+    pub is_barrel_pressed: bool,
+}
+
+/// The angles of a pen relative to the normal of the surface, in degrees.
+///
+/// Both angles are `0.0` when the pen is perpendicular to the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tilt {
+    /// The angle towards the right of the surface, from `-90.0` to `90.0`.
+    pub x: f32,
+
+    /// The angle towards the bottom of the surface, from `-90.0` to `90.0`.
+    pub y: f32,
+}
+
+/// The end of a pen touching the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tool {
+    /// The tip of the pen.
+    #[default]
+    Pen,
+
+    /// The eraser of the pen.
+    Eraser,
+}
+
+impl Event {
+    /// Returns the position of the pen.
+    pub fn position(&self) -> Point {
+        match self {
+            Self::Pressed { position, .. }
+            | Self::Moved { position, .. }
+            | Self::Lifted { position, .. }
+            | Self::Lost { position } => *position,
+        }
+    }
+}
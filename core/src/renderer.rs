@@ -5,7 +5,7 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
-use crate::{Background, BorderRadius, Color, Rectangle, Vector};
+use crate::{Background, BorderRadius, Color, Rectangle, Shadow, Vector};
 
 /// A component that can be used by widgets to draw themselves on a screen.
 pub trait Renderer: Sized {
@@ -24,6 +24,41 @@ pub trait Renderer: Sized {
         f: impl FnOnce(&mut Self),
     );
 
+    /// Applies a `scale` to the primitives recorded in the given closure.
+    ///
+    /// The primitives are scaled around the origin, before any translation
+    /// applied outside of the closure.
+    fn with_scale(&mut self, scale: f32, f: impl FnOnce(&mut Self));
+
+    /// Draws the primitives recorded in the given closure on top of a blurred
+    /// copy of whatever was drawn behind the given `bounds`.
+    ///
+    /// The `radius` of the blur is in logical pixels. A [`Renderer`] that
+    /// cannot blur draws the primitives as usual.
+    fn with_backdrop_blur(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        radius: f32,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let _ = (bounds, border_radius, radius);
+
+        f(self);
+    }
+
+    /// Draws the primitives recorded in the given closure as a single group
+    /// with the given opacity, from `0.0` (invisible) to `1.0` (opaque).
+    ///
+    /// The primitives are drawn together before applying the opacity, so
+    /// the ones below do not show through the ones on top. A [`Renderer`]
+    /// that cannot group primitives draws them as usual.
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let _ = opacity;
+
+        f(self);
+    }
+
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
@@ -45,6 +80,9 @@ pub struct Quad {
 
     /// The border color of the [`Quad`].
     pub border_color: Color,
+
+    /// The [`Shadow`] cast by the [`Quad`].
+    pub shadow: Shadow,
 }
 
 /// The styling attributes of a [`Renderer`].
@@ -0,0 +1,37 @@
+use crate::{Color, Rectangle, Vector};
+
+/// A shadow cast by a quad, giving it some depth.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Shadow {
+    /// The color of the [`Shadow`].
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] from the quad casting it.
+    pub offset: Vector,
+
+    /// The blur radius of the [`Shadow`].
+    pub blur_radius: f32,
+
+    /// How much the [`Shadow`] grows on every side before it is blurred.
+    pub spread_radius: f32,
+}
+
+impl Shadow {
+    /// Returns true if the [`Shadow`] is not visible.
+    pub fn is_invisible(&self) -> bool {
+        self.color.a <= 0.0
+    }
+
+    /// Returns the bounds covered by the [`Shadow`] of a quad with the given
+    /// bounds.
+    pub fn bounds(&self, quad: Rectangle) -> Rectangle {
+        let extent = self.spread_radius + self.blur_radius;
+
+        Rectangle {
+            x: quad.x + self.offset.x - extent,
+            y: quad.y + self.offset.y - extent,
+            width: quad.width + extent * 2.0,
+            height: quad.height + extent * 2.0,
+        }
+    }
+}
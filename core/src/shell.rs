@@ -12,6 +12,7 @@ pub struct Shell<'a, Message> {
     redraw_request: Option<window::RedrawRequest>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    is_pointer_captured: bool,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -22,6 +23,7 @@ impl<'a, Message> Shell<'a, Message> {
             redraw_request: None,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            is_pointer_captured: false,
         }
     }
 
@@ -88,6 +90,21 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Returns whether the pointer has been captured.
+    pub fn is_pointer_captured(&self) -> bool {
+        self.is_pointer_captured
+    }
+
+    /// Captures the pointer until every mouse button is released.
+    ///
+    /// While captured, the widget keeps receiving the mouse events of the
+    /// ongoing drag, even when the cursor leaves its bounds or the window.
+    /// If the window loses focus first, the shell reports the release of the
+    /// pressed buttons instead.
+    pub fn capture_pointer(&mut self) {
+        self.is_pointer_captured = true;
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -104,5 +121,8 @@ impl<'a, Message> Shell<'a, Message> {
 
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        self.is_pointer_captured =
+            self.is_pointer_captured || other.is_pointer_captured;
     }
 }
@@ -11,6 +11,7 @@ use std::sync::Arc;
 pub struct Handle {
     id: u64,
     data: Arc<Data>,
+    style: Option<Arc<str>>,
 }
 
 impl Handle {
@@ -36,9 +37,47 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             data: Arc::new(data),
+            style: None,
         }
     }
 
+    /// Adds the given CSS to the SVG of the [`Handle`], which is injected
+    /// into the document before it is rasterized.
+    ///
+    /// The rules can select elements by id or class, among others; so the
+    /// different parts of a multi-color icon can be styled independently.
+    ///
+    /// The resulting [`Handle`] is a different image, so it is cached
+    /// separately from the original one.
+    pub fn with_style(self, css: impl AsRef<str>) -> Handle {
+        let style = match &self.style {
+            Some(style) => format!("{style}\n{}", css.as_ref()),
+            None => css.as_ref().to_owned(),
+        };
+
+        let mut hasher = Hasher::default();
+        self.data.hash(&mut hasher);
+        style.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: self.data,
+            style: Some(Arc::from(style)),
+        }
+    }
+
+    /// Overrides the fill [`Color`] of the elements matching the given CSS
+    /// selector—like `#id` or `.class`.
+    pub fn fill(self, selector: impl AsRef<str>, color: Color) -> Handle {
+        self.with_style(rule(selector.as_ref(), "fill", color))
+    }
+
+    /// Overrides the stroke [`Color`] of the elements matching the given CSS
+    /// selector—like `#id` or `.class`.
+    pub fn stroke(self, selector: impl AsRef<str>, color: Color) -> Handle {
+        self.with_style(rule(selector.as_ref(), "stroke", color))
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> u64 {
         self.id
@@ -48,6 +87,76 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the CSS injected into the SVG of the [`Handle`], if any.
+    pub fn style(&self) -> Option<&str> {
+        self.style.as_deref()
+    }
+}
+
+/// Returns a CSS rule overriding the given color property, and its opacity,
+/// of the elements matching the given selector.
+fn rule(selector: &str, property: &str, color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+
+    format!(
+        "{selector} {{ {property}: #{r:02x}{g:02x}{b:02x} !important; \
+        {property}-opacity: {} !important; }}",
+        color.a
+    )
+}
+
+/// Injects the given CSS into the source of an SVG document, as a `<style>`
+/// element at the start of its root `<svg>` element.
+///
+/// The source is returned untouched if it has no root element that can
+/// contain children.
+pub fn inject_style(source: &str, css: &str) -> String {
+    let Some(end) = root_start_tag_end(source) else {
+        return source.to_owned();
+    };
+
+    let (head, tail) = source.split_at(end);
+
+    format!(
+        "{head}<style><![CDATA[{}]]></style>{tail}",
+        css.replace("]]>", "]]]]><![CDATA[>")
+    )
+}
+
+/// Returns the position right after the start tag of the root `<svg>`
+/// element in the given source, if it is not self-closing.
+fn root_start_tag_end(source: &str) -> Option<usize> {
+    let mut offset = 0;
+
+    let start = loop {
+        let position = offset + source[offset..].find("<svg")?;
+        let next = source[position + 4..].chars().next()?;
+
+        if next.is_whitespace() || next == '>' || next == '/' {
+            break position + 4;
+        }
+
+        offset = position + 4;
+    };
+
+    let mut quote = None;
+
+    for (i, c) in source[start..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => {
+                let end = start + i;
+
+                return (!source[..end].ends_with('/')).then_some(end + 1);
+            }
+            _ => {}
+        }
+    }
+
+    None
 }
 
 impl Hash for Handle {
@@ -87,3 +196,38 @@ pub trait Renderer: crate::Renderer {
     /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter, and inside the provided `bounds`.
     fn draw(&mut self, handle: Handle, color: Option<Color>, bounds: Rectangle);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inject_style_in_root() {
+        let source = r#"<?xml version="1.0"?><svg data-x="a>b"><g/></svg>"#;
+
+        let expected = concat!(
+            r#"<?xml version="1.0"?><svg data-x="a>b">"#,
+            "<style><![CDATA[g { fill: red; }]]></style>",
+            "<g/></svg>",
+        );
+
+        assert_eq!(inject_style(source, "g { fill: red; }"), expected);
+    }
+
+    #[test]
+    fn inject_style_without_children() {
+        let source = r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#;
+
+        assert_eq!(inject_style(source, "* { fill: red; }"), source);
+        assert_eq!(inject_style("<svgz>", "* { fill: red; }"), "<svgz>");
+    }
+
+    #[test]
+    fn styles_change_the_id() {
+        let handle = Handle::from_memory(b"<svg></svg>".as_slice());
+        let styled = handle.clone().fill("#a", Color::BLACK);
+
+        assert_ne!(handle.id(), styled.id());
+        assert_eq!(styled.id(), handle.fill("#a", Color::BLACK).id());
+    }
+}
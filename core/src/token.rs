@@ -0,0 +1,151 @@
+//! Share the spacing, radii, and text sizes of an application.
+//!
+//! The built-in widgets take their default padding and spacing from the
+//! current [`Tokens`], so the density of a whole application can be changed
+//! in one place. The current [`Tokens`] are normally set by the shell from
+//! the theme of the application.
+use std::sync::RwLock;
+
+static CURRENT: RwLock<Tokens> = RwLock::new(Tokens::COMFORTABLE);
+
+/// Returns the current [`Tokens`] of the application.
+///
+/// They are [`Tokens::COMFORTABLE`] until changed with [`set`].
+pub fn current() -> Tokens {
+    CURRENT
+        .read()
+        .map(|current| *current)
+        .unwrap_or(Tokens::COMFORTABLE)
+}
+
+/// Changes the current [`Tokens`] of the application.
+///
+/// Widgets only pick up the new [`Tokens`] once the application is viewed
+/// again.
+pub fn set(tokens: Tokens) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = tokens;
+    }
+}
+
+/// Returns the spacing of the given [`Step`] of the current [`Tokens`].
+pub fn spacing(step: Step) -> f32 {
+    current().spacing.get(step)
+}
+
+/// Returns the radius of the given [`Step`] of the current [`Tokens`].
+pub fn radius(step: Step) -> f32 {
+    current().radius.get(step)
+}
+
+/// Returns the text size of the given [`Step`] of the current [`Tokens`].
+pub fn text_size(step: Step) -> f32 {
+    current().text_size.get(step)
+}
+
+/// The design tokens of an application.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tokens {
+    /// The [`Scale`] of the padding and the spacing of widgets.
+    pub spacing: Scale,
+    /// The [`Scale`] of the border radii of widgets.
+    pub radius: Scale,
+    /// The [`Scale`] of the text sizes, in logical pixels.
+    pub text_size: Scale,
+}
+
+impl Tokens {
+    /// The default [`Tokens`].
+    pub const COMFORTABLE: Self = Self {
+        spacing: Scale {
+            xs: 2.0,
+            sm: 5.0,
+            md: 10.0,
+            lg: 15.0,
+            xl: 20.0,
+        },
+        radius: Scale {
+            xs: 1.0,
+            sm: 2.0,
+            md: 4.0,
+            lg: 8.0,
+            xl: 16.0,
+        },
+        text_size: Scale {
+            xs: 12.0,
+            sm: 14.0,
+            md: 16.0,
+            lg: 20.0,
+            xl: 28.0,
+        },
+    };
+
+    /// Some [`Tokens`] with less room between widgets, for applications
+    /// showing a lot of information at once.
+    pub const COMPACT: Self = Self {
+        spacing: Scale {
+            xs: 1.0,
+            sm: 3.0,
+            md: 6.0,
+            lg: 9.0,
+            xl: 12.0,
+        },
+        radius: Self::COMFORTABLE.radius,
+        text_size: Scale {
+            xs: 11.0,
+            sm: 12.0,
+            md: 14.0,
+            lg: 18.0,
+            xl: 24.0,
+        },
+    };
+}
+
+impl Default for Tokens {
+    fn default() -> Self {
+        Self::COMFORTABLE
+    }
+}
+
+/// A scale of values, from the smallest to the largest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale {
+    /// The extra small value.
+    pub xs: f32,
+    /// The small value.
+    pub sm: f32,
+    /// The medium value.
+    pub md: f32,
+    /// The large value.
+    pub lg: f32,
+    /// The extra large value.
+    pub xl: f32,
+}
+
+impl Scale {
+    /// Returns the value of the given [`Step`] of the [`Scale`].
+    pub fn get(&self, step: Step) -> f32 {
+        match step {
+            Step::Xs => self.xs,
+            Step::Sm => self.sm,
+            Step::Md => self.md,
+            Step::Lg => self.lg,
+            Step::Xl => self.xl,
+        }
+    }
+}
+
+/// A step of a [`Scale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    /// The extra small step.
+    Xs,
+    /// The small step.
+    Sm,
+    /// The medium step.
+    Md,
+    /// The large step.
+    Lg,
+    /// The extra large step.
+    Xl,
+}
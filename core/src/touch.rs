@@ -1,4 +1,8 @@
 //! Build touch events.
+pub mod gesture;
+
+pub use gesture::Gesture;
+
 use crate::Point;
 
 /// A touch interaction.
@@ -0,0 +1,420 @@
+//! Recognize gestures from touch events.
+use crate::time::{Duration, Instant};
+use crate::touch::{Event, Finger};
+use crate::{Point, Vector};
+
+/// The distance a finger can move before a press stops being a tap.
+const TAP_SLOP: f32 = 10.0;
+
+/// The longest press that is still considered a tap.
+const TAP_DURATION: Duration = Duration::from_millis(300);
+
+/// The longest time between two taps of a double tap.
+const DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The longest distance between two taps of a double tap.
+const DOUBLE_TAP_DISTANCE: f32 = 30.0;
+
+/// The time a finger needs to be held still to produce a long press.
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// The time window used to compute the velocity of a swipe.
+const SWIPE_WINDOW: Duration = Duration::from_millis(100);
+
+/// The minimum speed of a swipe, in logical pixels per second.
+const SWIPE_SPEED: f32 = 500.0;
+
+/// A high-level touch interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A finger was pressed and lifted quickly without moving.
+    Tap {
+        /// The position of the tap.
+        position: Point,
+    },
+
+    /// A finger tapped twice in quick succession.
+    ///
+    /// It is produced right after the [`Gesture::Tap`] of the second tap.
+    DoubleTap {
+        /// The position of the second tap.
+        position: Point,
+    },
+
+    /// A finger was pressed and held still.
+    LongPress {
+        /// The position of the finger.
+        position: Point,
+    },
+
+    /// Two fingers moved closer or further apart.
+    Pinch {
+        /// The point between both fingers.
+        center: Point,
+        /// The factor by which the distance between both fingers changed
+        /// since the last [`Gesture::Pinch`].
+        scale: f32,
+    },
+
+    /// Two fingers moved together.
+    Pan {
+        /// The point between both fingers.
+        center: Point,
+        /// The translation of the center since the last [`Gesture::Pan`].
+        translation: Vector,
+    },
+
+    /// A finger was lifted while moving quickly.
+    Swipe {
+        /// The position where the finger was lifted.
+        position: Point,
+        /// The velocity of the finger, in logical pixels per second.
+        velocity: Vector,
+    },
+}
+
+/// Turns touch [`Event`]s into [`Gesture`]s.
+///
+/// Shells feed every touch [`Event`] to a [`Recognizer`] and call
+/// [`Recognizer::tick`] once the [`Recognizer::deadline`] is reached.
+#[derive(Debug, Default)]
+pub struct Recognizer {
+    fingers: Vec<(Finger, Point)>,
+    press: Option<Press>,
+    last_tap: Option<(Instant, Point)>,
+    pair: Option<Pair>,
+}
+
+/// A single finger that may become a tap, a long press, or a swipe.
+#[derive(Debug)]
+struct Press {
+    finger: Finger,
+    origin: Point,
+    started_at: Instant,
+    samples: Vec<(Instant, Point)>,
+    has_moved: bool,
+    is_long: bool,
+}
+
+/// The last known center and spread of two fingers.
+#[derive(Debug, Clone, Copy)]
+struct Pair {
+    center: Point,
+    distance: f32,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a touch [`Event`] that happened at the given time, calling
+    /// the given function with every [`Gesture`] it completes.
+    pub fn update(
+        &mut self,
+        event: &Event,
+        now: Instant,
+        mut on_gesture: impl FnMut(Gesture),
+    ) {
+        match *event {
+            Event::FingerPressed { id, position } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+                self.fingers.push((id, position));
+
+                if self.fingers.len() == 1 {
+                    self.press = Some(Press {
+                        finger: id,
+                        origin: position,
+                        started_at: now,
+                        samples: vec![(now, position)],
+                        has_moved: false,
+                        is_long: false,
+                    });
+                } else {
+                    // More fingers cancel any single finger gesture
+                    self.press = None;
+                    self.pair = self.pair();
+                }
+            }
+            Event::FingerMoved { id, position } => {
+                if let Some((_, last)) =
+                    self.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                {
+                    *last = position;
+                }
+
+                if let Some(press) =
+                    self.press.as_mut().filter(|press| press.finger == id)
+                {
+                    if press.origin.distance(position) > TAP_SLOP {
+                        press.has_moved = true;
+                    }
+
+                    press.samples.retain(|(at, _)| {
+                        now.duration_since(*at) <= SWIPE_WINDOW
+                    });
+                    press.samples.push((now, position));
+                }
+
+                if let (Some(previous), Some(current)) =
+                    (self.pair, self.pair())
+                {
+                    if previous.distance > 0.0
+                        && current.distance != previous.distance
+                    {
+                        on_gesture(Gesture::Pinch {
+                            center: current.center,
+                            scale: current.distance / previous.distance,
+                        });
+                    }
+
+                    if current.center != previous.center {
+                        on_gesture(Gesture::Pan {
+                            center: current.center,
+                            translation: current.center - previous.center,
+                        });
+                    }
+
+                    self.pair = Some(current);
+                }
+            }
+            Event::FingerLifted { id, position } => {
+                if let Some(press) =
+                    self.press.take().filter(|press| press.finger == id)
+                {
+                    self.lift(press, position, now, &mut on_gesture);
+                }
+
+                self.remove(id);
+            }
+            Event::FingerLost { id, .. } => {
+                if self.press.as_ref().is_some_and(|press| press.finger == id) {
+                    self.press = None;
+                }
+
+                self.remove(id);
+            }
+        }
+    }
+
+    /// Produces a [`Gesture::LongPress`] if a finger has been held still
+    /// long enough at the given time.
+    pub fn tick(&mut self, now: Instant) -> Option<Gesture> {
+        let press = self.press.as_mut()?;
+
+        if press.has_moved
+            || press.is_long
+            || now.duration_since(press.started_at) < LONG_PRESS_DURATION
+        {
+            return None;
+        }
+
+        press.is_long = true;
+
+        Some(Gesture::LongPress {
+            position: press.origin,
+        })
+    }
+
+    /// Returns the time at which [`Recognizer::tick`] may produce a
+    /// [`Gesture`], if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.press
+            .as_ref()
+            .filter(|press| !press.has_moved && !press.is_long)
+            .map(|press| press.started_at + LONG_PRESS_DURATION)
+    }
+
+    fn lift(
+        &mut self,
+        press: Press,
+        position: Point,
+        now: Instant,
+        on_gesture: &mut impl FnMut(Gesture),
+    ) {
+        if press.is_long {
+            return;
+        }
+
+        if !press.has_moved {
+            if now.duration_since(press.started_at) > TAP_DURATION {
+                return;
+            }
+
+            on_gesture(Gesture::Tap { position });
+
+            let is_double = self.last_tap.is_some_and(|(at, last)| {
+                now.duration_since(at) <= DOUBLE_TAP_INTERVAL
+                    && last.distance(position) <= DOUBLE_TAP_DISTANCE
+            });
+
+            if is_double {
+                on_gesture(Gesture::DoubleTap { position });
+
+                self.last_tap = None;
+            } else {
+                self.last_tap = Some((now, position));
+            }
+
+            return;
+        }
+
+        let Some((started_at, start)) = press
+            .samples
+            .iter()
+            .copied()
+            .find(|(at, _)| now.duration_since(*at) <= SWIPE_WINDOW)
+        else {
+            return;
+        };
+
+        let elapsed = now.duration_since(started_at).as_secs_f32();
+
+        if elapsed <= 0.0 {
+            return;
+        }
+
+        let distance = position - start;
+        let velocity = Vector::new(distance.x / elapsed, distance.y / elapsed);
+
+        if velocity.x.hypot(velocity.y) >= SWIPE_SPEED {
+            on_gesture(Gesture::Swipe { position, velocity });
+        }
+    }
+
+    fn remove(&mut self, id: Finger) {
+        self.fingers.retain(|(finger, _)| *finger != id);
+
+        if self.fingers.len() < 2 {
+            self.pair = None;
+        }
+    }
+
+    fn pair(&self) -> Option<Pair> {
+        let [(_, a), (_, b)] = self.fingers.get(..2)? else {
+            return None;
+        };
+
+        Some(Pair {
+            center: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+            distance: a.distance(*b),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(
+        recognizer: &mut Recognizer,
+        events: &[(u64, Event)],
+    ) -> Vec<Gesture> {
+        let start = Instant::now();
+        let mut gestures = Vec::new();
+
+        for (millis, event) in events {
+            recognizer.update(
+                event,
+                start + Duration::from_millis(*millis),
+                |gesture| gestures.push(gesture),
+            );
+        }
+
+        gestures
+    }
+
+    #[test]
+    fn two_quick_taps_are_a_double_tap() {
+        let id = Finger(0);
+        let position = Point::new(10.0, 10.0);
+
+        let gestures = events(
+            &mut Recognizer::new(),
+            &[
+                (0, Event::FingerPressed { id, position }),
+                (50, Event::FingerLifted { id, position }),
+                (150, Event::FingerPressed { id, position }),
+                (200, Event::FingerLifted { id, position }),
+            ],
+        );
+
+        assert_eq!(
+            gestures,
+            vec![
+                Gesture::Tap { position },
+                Gesture::Tap { position },
+                Gesture::DoubleTap { position },
+            ]
+        );
+    }
+
+    #[test]
+    fn spreading_two_fingers_is_a_pinch() {
+        let (a, b) = (Finger(0), Finger(1));
+
+        let gestures = events(
+            &mut Recognizer::new(),
+            &[
+                (
+                    0,
+                    Event::FingerPressed {
+                        id: a,
+                        position: Point::new(40.0, 50.0),
+                    },
+                ),
+                (
+                    0,
+                    Event::FingerPressed {
+                        id: b,
+                        position: Point::new(60.0, 50.0),
+                    },
+                ),
+                (
+                    10,
+                    Event::FingerMoved {
+                        id: b,
+                        position: Point::new(80.0, 50.0),
+                    },
+                ),
+            ],
+        );
+
+        assert_eq!(
+            gestures,
+            vec![
+                Gesture::Pinch {
+                    center: Point::new(60.0, 50.0),
+                    scale: 2.0,
+                },
+                Gesture::Pan {
+                    center: Point::new(60.0, 50.0),
+                    translation: Vector::new(10.0, 0.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn holding_still_is_a_long_press() {
+        let id = Finger(0);
+        let position = Point::new(10.0, 10.0);
+        let start = Instant::now();
+
+        let mut recognizer = Recognizer::new();
+        recognizer.update(
+            &Event::FingerPressed { id, position },
+            start,
+            |_| {},
+        );
+
+        assert_eq!(recognizer.deadline(), Some(start + LONG_PRESS_DURATION));
+        assert_eq!(recognizer.tick(start), None);
+        assert_eq!(
+            recognizer.tick(start + LONG_PRESS_DURATION),
+            Some(Gesture::LongPress { position })
+        );
+        assert_eq!(recognizer.deadline(), None);
+    }
+}
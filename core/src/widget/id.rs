@@ -21,6 +21,14 @@ impl Id {
 
         Self(Internal::Unique(id))
     }
+
+    /// Returns the name of the [`Id`], if it is a custom one.
+    pub fn name(&self) -> Option<&str> {
+        match &self.0 {
+            Internal::Custom(name) => Some(name.as_ref()),
+            Internal::Unique(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
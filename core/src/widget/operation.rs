@@ -7,6 +7,7 @@ pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
+use crate::accessibility;
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
 
@@ -28,6 +29,23 @@ pub trait Operation<T> {
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
     );
 
+    /// Operates on a widget described to assistive technologies.
+    ///
+    /// The `operate_on_children` function can be called to return control to
+    /// the widget tree and keep traversing the contents of the widget. By
+    /// default, the widget is treated as a [`container`].
+    ///
+    /// [`container`]: Self::container
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _node: &accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.container(id, bounds, operate_on_children);
+    }
+
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
@@ -93,6 +111,61 @@ where
         f: Rc<dyn Fn(A) -> B>,
     }
 
+    struct MapRef<'a, A> {
+        operation: &'a mut dyn Operation<A>,
+    }
+
+    impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            let Self { operation, .. } = self;
+
+            operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut MapRef { operation });
+            });
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            let Self { operation, .. } = self;
+
+            operation.accessible(id, bounds, node, &mut |operation| {
+                operate_on_children(&mut MapRef { operation });
+            });
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.operation.scrollable(state, id, bounds, translation);
+        }
+
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            self.operation.focusable(state, id);
+        }
+
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            self.operation.text_input(state, id);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            self.operation.custom(state, id);
+        }
+    }
+
     impl<A, B> Operation<B> for Map<A, B>
     where
         A: 'static,
@@ -104,61 +177,27 @@ where
             bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
         ) {
-            struct MapRef<'a, A> {
-                operation: &'a mut dyn Operation<A>,
-            }
-
-            impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-                fn container(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-                ) {
-                    let Self { operation, .. } = self;
-
-                    operation.container(id, bounds, &mut |operation| {
-                        operate_on_children(&mut MapRef { operation });
-                    });
-                }
-
-                fn scrollable(
-                    &mut self,
-                    state: &mut dyn Scrollable,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    translation: Vector,
-                ) {
-                    self.operation.scrollable(state, id, bounds, translation);
-                }
-
-                fn focusable(
-                    &mut self,
-                    state: &mut dyn Focusable,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.focusable(state, id);
-                }
-
-                fn text_input(
-                    &mut self,
-                    state: &mut dyn TextInput,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.text_input(state, id);
-                }
+            let Self { operation, .. } = self;
 
-                fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                    self.operation.custom(state, id);
-                }
+            MapRef {
+                operation: operation.as_mut(),
             }
+            .container(id, bounds, operate_on_children);
+        }
 
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
             let Self { operation, .. } = self;
 
             MapRef {
                 operation: operation.as_mut(),
             }
-            .container(id, bounds, operate_on_children);
+            .accessible(id, bounds, node, operate_on_children);
         }
 
         fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
@@ -13,6 +13,48 @@ pub trait Focusable {
 
     /// Unfocuses the widget.
     fn unfocus(&mut self);
+
+    /// Returns the position of the widget in the tab order.
+    ///
+    /// Widgets with a positive index are visited first, in increasing order.
+    /// Then, widgets with an index of `0` are visited in layout order.
+    /// Widgets with a negative index can only be focused programmatically.
+    fn tab_index(&self) -> i32 {
+        0
+    }
+}
+
+/// Wraps a [`Focusable`] to give it an explicit position in the tab order.
+///
+/// See [`Focusable::tab_index`].
+pub fn with_tab_index(
+    state: &mut dyn Focusable,
+    tab_index: i32,
+) -> impl Focusable + '_ {
+    struct WithTabIndex<'a> {
+        state: &'a mut dyn Focusable,
+        tab_index: i32,
+    }
+
+    impl<'a> Focusable for WithTabIndex<'a> {
+        fn is_focused(&self) -> bool {
+            self.state.is_focused()
+        }
+
+        fn focus(&mut self) {
+            self.state.focus();
+        }
+
+        fn unfocus(&mut self) {
+            self.state.unfocus();
+        }
+
+        fn tab_index(&self) -> i32 {
+            self.tab_index
+        }
+    }
+
+    WithTabIndex { state, tab_index }
 }
 
 /// A summary of the focusable widgets present on a widget tree.
@@ -99,31 +141,53 @@ where
     }
 }
 
-/// Produces an [`Operation`] that searches for the current focused widget, and
-/// - if found, focuses the previous focusable widget.
-/// - if not found, focuses the last focusable widget.
-pub fn focus_previous<T>() -> impl Operation<T> {
-    struct FocusPrevious {
-        count: Count,
-        current: usize,
+/// The tab order of the focusable widgets present on a widget tree.
+#[derive(Debug, Clone, Default)]
+struct Order {
+    tab_indices: Vec<i32>,
+    focused: Option<usize>,
+}
+
+impl Order {
+    /// Returns the focusable widgets reachable with the keyboard, in
+    /// tab order.
+    fn sequence(&self) -> Vec<usize> {
+        let mut sequence: Vec<usize> = (0..self.tab_indices.len())
+            .filter(|i| self.tab_indices[*i] >= 0)
+            .collect();
+
+        // The sort is stable, so layout order is kept among equal indices
+        sequence.sort_by_key(|i| match self.tab_indices[*i] {
+            0 => i32::MAX,
+            tab_index => tab_index,
+        });
+
+        sequence
     }
+}
 
-    impl<T> Operation<T> for FocusPrevious {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
-            if self.count.total == 0 {
-                return;
-            }
+/// Produces an [`Operation`] that computes the [`Order`] of the focusable
+/// widgets and chains it with the provided function to build a new
+/// [`Operation`].
+fn order<T, O>(f: fn(Order) -> O) -> impl Operation<T>
+where
+    O: Operation<T> + 'static,
+{
+    struct ComputeOrder<O> {
+        order: Order,
+        next: fn(Order) -> O,
+    }
 
-            match self.count.focused {
-                None if self.current == self.count.total - 1 => state.focus(),
-                Some(0) if self.current == 0 => state.unfocus(),
-                Some(0) => {}
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused - 1 == self.current => state.focus(),
-                _ => {}
+    impl<T, O> Operation<T> for ComputeOrder<O>
+    where
+        O: Operation<T> + 'static,
+    {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            if state.is_focused() {
+                self.order.focused = Some(self.order.tab_indices.len());
             }
 
-            self.current += 1;
+            self.order.tab_indices.push(state.tab_index());
         }
 
         fn container(
@@ -134,43 +198,89 @@ pub fn focus_previous<T>() -> impl Operation<T> {
         ) {
             operate_on_children(self);
         }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Chain(Box::new((self.next)(self.order.clone())))
+        }
     }
 
-    count(|count| FocusPrevious { count, current: 0 })
+    ComputeOrder {
+        order: Order::default(),
+        next: f,
+    }
 }
 
-/// Produces an [`Operation`] that searches for the current focused widget, and
-/// - if found, focuses the next focusable widget.
-/// - if not found, focuses the first focusable widget.
-pub fn focus_next<T>() -> impl Operation<T> {
-    struct FocusNext {
-        count: Count,
-        current: usize,
+/// An [`Operation`] that focuses the focusable widget at the given position
+/// in layout order, unfocusing any other.
+struct FocusAt {
+    target: Option<usize>,
+    current: usize,
+}
+
+impl<T> Operation<T> for FocusAt {
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.target == Some(self.current) {
+            state.focus();
+        } else if state.is_focused() {
+            state.unfocus();
+        }
+
+        self.current += 1;
     }
 
-    impl<T> Operation<T> for FocusNext {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
-            match self.count.focused {
-                None if self.current == 0 => state.focus(),
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused + 1 == self.current => state.focus(),
-                _ => {}
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the previous focusable widget in tab order.
+/// - if not found, focuses the last focusable widget in tab order.
+pub fn focus_previous<T>() -> impl Operation<T> {
+    order(|order| {
+        let sequence = order.sequence();
+
+        let target = match order.focused {
+            Some(focused) => {
+                match sequence.iter().position(|i| *i == focused) {
+                    Some(0) => None,
+                    Some(position) => Some(sequence[position - 1]),
+                    None => {
+                        sequence.iter().rev().find(|i| **i < focused).copied()
+                    }
+                }
             }
+            None => sequence.last().copied(),
+        };
 
-            self.current += 1;
-        }
+        FocusAt { target, current: 0 }
+    })
+}
 
-        fn container(
-            &mut self,
-            _id: Option<&Id>,
-            _bounds: Rectangle,
-            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
-        ) {
-            operate_on_children(self);
-        }
-    }
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the next focusable widget in tab order.
+/// - if not found, focuses the first focusable widget in tab order.
+pub fn focus_next<T>() -> impl Operation<T> {
+    order(|order| {
+        let sequence = order.sequence();
+
+        let target = match order.focused {
+            Some(focused) => {
+                match sequence.iter().position(|i| *i == focused) {
+                    Some(position) => sequence.get(position + 1).copied(),
+                    None => sequence.iter().find(|i| **i > focused).copied(),
+                }
+            }
+            None => sequence.first().copied(),
+        };
 
-    count(|count| FocusNext { count, current: 0 })
+        FocusAt { target, current: 0 }
+    })
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget
@@ -1,10 +1,13 @@
 //! Write some text for your users to read.
+use crate::accessibility;
 use crate::alignment;
+use crate::cascade;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{Color, Element, Layout, Length, Pixels, Point, Rectangle, Widget};
 
 use std::borrow::Cow;
@@ -164,6 +167,19 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let node = accessibility::Node::new(accessibility::Role::Label)
+            .label(self.content.to_string());
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -208,7 +224,9 @@ where
     let bounds = limits.max();
 
     let size = size.unwrap_or_else(|| renderer.default_size());
-    let font = font.unwrap_or_else(|| renderer.default_font());
+    let font = font
+        .or_else(cascade::font)
+        .unwrap_or_else(|| renderer.default_font());
 
     let State(ref mut paragraph) = state;
 
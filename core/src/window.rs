@@ -1,15 +1,25 @@
 //! Build window-based GUI applications.
 pub mod icon;
 
+mod cursor_grab;
+mod direction;
 mod event;
 mod level;
 mod mode;
+mod monitor;
+mod progress;
 mod redraw_request;
 mod user_attention;
+mod video_mode;
 
+pub use cursor_grab::CursorGrab;
+pub use direction::Direction;
 pub use event::Event;
 pub use icon::Icon;
 pub use level::Level;
 pub use mode::Mode;
+pub use monitor::Monitor;
+pub use progress::Progress;
 pub use redraw_request::RedrawRequest;
 pub use user_attention::UserAttention;
+pub use video_mode::VideoMode;
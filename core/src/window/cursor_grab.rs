@@ -0,0 +1,15 @@
+/// How the cursor is held inside of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorGrab {
+    /// The cursor moves freely.
+    #[default]
+    None,
+
+    /// The cursor is kept inside of the window.
+    Confined,
+
+    /// The cursor is locked in place.
+    ///
+    /// Combine it with relative mouse motion to look around in 3D viewports.
+    Locked,
+}
@@ -0,0 +1,39 @@
+use crate::mouse;
+
+/// The edge or corner of a window that is dragged to resize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The top edge.
+    North,
+    /// The bottom edge.
+    South,
+    /// The right edge.
+    East,
+    /// The left edge.
+    West,
+    /// The top-right corner.
+    NorthEast,
+    /// The top-left corner.
+    NorthWest,
+    /// The bottom-right corner.
+    SouthEast,
+    /// The bottom-left corner.
+    SouthWest,
+}
+
+impl Direction {
+    /// Returns the [`mouse::Interaction`] that hints at resizing the window
+    /// in this [`Direction`].
+    pub fn mouse_interaction(self) -> mouse::Interaction {
+        match self {
+            Self::North | Self::South => mouse::Interaction::ResizingVertically,
+            Self::East | Self::West => mouse::Interaction::ResizingHorizontally,
+            Self::NorthEast | Self::SouthWest => {
+                mouse::Interaction::ResizingDiagonallyUp
+            }
+            Self::NorthWest | Self::SouthEast => {
+                mouse::Interaction::ResizingDiagonallyDown
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::Mode;
 
 use std::path::PathBuf;
 
@@ -21,6 +22,41 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window stopped being resized.
+    ///
+    /// It is produced once the size of the window has not changed for a
+    /// short while, like when the user releases the border of the window or
+    /// the window is snapped to a side of the screen.
+    ResizeEnded {
+        /// The final logical width of the window
+        width: u32,
+        /// The final logical height of the window
+        height: u32,
+    },
+
+    /// A window was maximized.
+    Maximized,
+
+    /// A window was minimized.
+    Minimized,
+
+    /// A window was restored after being maximized or minimized.
+    Restored,
+
+    /// A window was moved to a different monitor.
+    MonitorChanged {
+        /// The name of the new monitor, if known
+        name: Option<String>,
+    },
+
+    /// The [`Mode`] of a window changed.
+    ModeChanged(Mode),
+
+    /// The zoom of a window changed, in percent.
+    ///
+    /// It is applied on top of the scale factor of the window.
+    ZoomChanged(u32),
+
     /// A window redraw was requested.
     ///
     /// The [`Instant`] contains the current time.
@@ -50,6 +86,20 @@ pub enum Event {
     /// for each file separately.
     FileDropped(PathBuf),
 
+    /// The files hovering the window were moved.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** Reported while the files are dragged and right before
+    ///   they are dropped.
+    /// - **Other platforms:** Unsupported.
+    FilesHoveredMoved {
+        /// The new logical x position of the files, relative to the window
+        x: i32,
+        /// The new logical y position of the files, relative to the window
+        y: i32,
+    },
+
     /// A file was hovered, but has exited the window.
     ///
     /// There will be a single `FilesHoveredLeft` event triggered even if
@@ -1,3 +1,5 @@
+use crate::window::VideoMode;
+
 /// The mode of a window-based application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
@@ -7,6 +9,14 @@ pub enum Mode {
     /// The application takes the whole screen of its current monitor.
     Fullscreen,
 
+    /// The application takes exclusive control of its current monitor,
+    /// switching it to the closest supported [`VideoMode`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / Web:** Unsupported.
+    ExclusiveFullscreen(VideoMode),
+
     /// The application is hidden
     Hidden,
 }
@@ -0,0 +1,55 @@
+use crate::window::VideoMode;
+use crate::Size;
+
+/// A display connected to the system.
+///
+/// Its geometry is given in physical pixels, in the coordinate space shared
+/// by every monitor of the desktop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The name of the [`Monitor`], if known.
+    pub name: Option<String>,
+
+    /// The physical x position of the top-left corner of the [`Monitor`].
+    pub x: i32,
+
+    /// The physical y position of the top-left corner of the [`Monitor`].
+    pub y: i32,
+
+    /// The physical [`Size`] of the [`Monitor`].
+    pub size: Size<u32>,
+
+    /// The scale factor of the [`Monitor`].
+    pub scale_factor: f64,
+
+    /// Whether the [`Monitor`] is the primary one of the system.
+    pub is_primary: bool,
+
+    /// The video modes supported by the [`Monitor`], for
+    /// [`Mode::ExclusiveFullscreen`].
+    ///
+    /// [`Mode::ExclusiveFullscreen`]: crate::window::Mode::ExclusiveFullscreen
+    pub video_modes: Vec<VideoMode>,
+}
+
+impl Monitor {
+    /// Returns the logical [`Size`] of the [`Monitor`].
+    pub fn logical_size(&self) -> Size {
+        Size::new(
+            (f64::from(self.size.width) / self.scale_factor) as f32,
+            (f64::from(self.size.height) / self.scale_factor) as f32,
+        )
+    }
+
+    /// Returns true if the given physical position is inside the
+    /// [`Monitor`].
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let (x, y) = (i64::from(x), i64::from(y));
+        let (left, top) = (i64::from(self.x), i64::from(self.y));
+
+        x >= left
+            && y >= top
+            && x < left + i64::from(self.size.width)
+            && y < top + i64::from(self.size.height)
+    }
+}
@@ -0,0 +1,21 @@
+/// The progress of a long-running task, shown in the taskbar entry of a
+/// window.
+///
+/// ## Platform-specific
+///
+/// - **Windows:** Fills the taskbar button of the window.
+/// - **macOS / Linux / Web:** Unsupported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Progress {
+    /// The task is running, but its progress is unknown.
+    Indeterminate,
+    /// The task is running, with the given progress in the `0.0..=1.0`
+    /// range.
+    Normal(f32),
+    /// The task is paused, with the given progress in the `0.0..=1.0`
+    /// range.
+    Paused(f32),
+    /// The task has failed, with the given progress in the `0.0..=1.0`
+    /// range.
+    Error(f32),
+}
@@ -0,0 +1,16 @@
+use crate::Size;
+
+/// A resolution and refresh rate supported by a [`Monitor`].
+///
+/// [`Monitor`]: super::Monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// The physical [`Size`] of the [`VideoMode`].
+    pub size: Size<u32>,
+
+    /// The number of bits per pixel of the [`VideoMode`].
+    pub bit_depth: u16,
+
+    /// The refresh rate of the [`VideoMode`], in millihertz.
+    pub refresh_rate_millihertz: u32,
+}
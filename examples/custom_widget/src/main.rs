@@ -13,7 +13,7 @@ mod circle {
     use iced::advanced::renderer;
     use iced::advanced::widget::{self, Widget};
     use iced::mouse;
-    use iced::{Color, Element, Length, Rectangle, Size};
+    use iced::{Color, Element, Length, Rectangle, Shadow, Size};
 
     pub struct Circle {
         radius: f32,
@@ -66,6 +66,7 @@ mod circle {
                     border_radius: self.radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Color::BLACK,
             );
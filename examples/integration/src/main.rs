@@ -4,18 +4,14 @@ mod scene;
 use controls::Controls;
 use scene::Scene;
 
-use iced_wgpu::graphics::Viewport;
 use iced_wgpu::{wgpu, Backend, Renderer, Settings};
-use iced_winit::core::mouse;
 use iced_winit::core::renderer;
-use iced_winit::core::{Color, Font, Pixels, Size};
-use iced_winit::runtime::program;
-use iced_winit::runtime::Debug;
+use iced_winit::core::{Color, Font, Pixels};
 use iced_winit::style::Theme;
-use iced_winit::{conversion, futures, winit, Clipboard};
+use iced_winit::{futures, winit, Embedded};
 
 use winit::{
-    event::{Event, ModifiersState, WindowEvent},
+    event::{Event, WindowEvent},
     event_loop::{ControlFlow, EventLoop},
 };
 
@@ -55,13 +51,6 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     let window = winit::window::Window::new(&event_loop)?;
 
     let physical_size = window.inner_size();
-    let mut viewport = Viewport::with_physical_size(
-        Size::new(physical_size.width, physical_size.height),
-        window.scale_factor(),
-    );
-    let mut cursor_position = None;
-    let mut modifiers = ModifiersState::default();
-    let mut clipboard = Clipboard::connect(&window);
 
     // Initialize wgpu
     #[cfg(target_arch = "wasm32")]
@@ -141,19 +130,13 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     let controls = Controls::new();
 
     // Initialize iced
-    let mut debug = Debug::new();
     let mut renderer = Renderer::new(
         Backend::new(&device, &queue, Settings::default(), format),
         Font::default(),
         Pixels(16.0),
     );
 
-    let mut state = program::State::new(
-        controls,
-        viewport.logical_size(),
-        &mut renderer,
-        &mut debug,
-    );
+    let mut embedded = Embedded::new(controls, &window, &mut renderer);
 
     // Run event loop
     event_loop.run(move |event, _, control_flow| {
@@ -163,13 +146,8 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         match event {
             Event::WindowEvent { event, .. } => {
                 match event {
-                    WindowEvent::CursorMoved { position, .. } => {
-                        cursor_position = Some(position);
-                    }
-                    WindowEvent::ModifiersChanged(new_modifiers) => {
-                        modifiers = new_modifiers;
-                    }
-                    WindowEvent::Resized(_) => {
+                    WindowEvent::Resized(_)
+                    | WindowEvent::ScaleFactorChanged { .. } => {
                         resized = true;
                     }
                     WindowEvent::CloseRequested => {
@@ -178,37 +156,20 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                     _ => {}
                 }
 
-                // Map window event to iced event
-                if let Some(event) = iced_winit::conversion::window_event(
-                    &event,
-                    window.scale_factor(),
-                    modifiers,
-                ) {
-                    state.queue_event(event);
-                }
+                // Let iced handle the window event
+                embedded.handle_event(&window, &event);
             }
             Event::MainEventsCleared => {
                 // If there are events pending
-                if !state.is_queue_empty() {
-                    // We update iced
-                    let _ = state.update(
-                        viewport.logical_size(),
-                        cursor_position
-                            .map(|p| {
-                                conversion::cursor_position(
-                                    p,
-                                    viewport.scale_factor(),
-                                )
-                            })
-                            .map(mouse::Cursor::Available)
-                            .unwrap_or(mouse::Cursor::Unavailable),
+                if embedded.has_pending_events() {
+                    // We update iced, ignoring the events it does not
+                    // capture; a game would handle them instead
+                    let _ = embedded.update(
                         &mut renderer,
                         &Theme::Dark,
                         &renderer::Style {
                             text_color: Color::WHITE,
                         },
-                        &mut clipboard,
-                        &mut debug,
                     );
 
                     // and request a redraw
@@ -219,11 +180,6 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if resized {
                     let size = window.inner_size();
 
-                    viewport = Viewport::with_physical_size(
-                        Size::new(size.width, size.height),
-                        window.scale_factor(),
-                    );
-
                     surface.configure(
                         &device,
                         &wgpu::SurfaceConfiguration {
@@ -246,7 +202,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                             &wgpu::CommandEncoderDescriptor { label: None },
                         );
 
-                        let program = state.program();
+                        let program = embedded.program();
 
                         let view = frame.texture.create_view(
                             &wgpu::TextureViewDescriptor::default(),
@@ -273,8 +229,8 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 None,
                                 &view,
                                 primitive,
-                                &viewport,
-                                &debug.overlay(),
+                                embedded.viewport(),
+                                &embedded.debug().overlay(),
                             );
                         });
 
@@ -283,11 +239,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
                         frame.present();
 
                         // Update the mouse cursor
-                        window.set_cursor_icon(
-                            iced_winit::conversion::mouse_interaction(
-                                state.mouse_interaction(),
-                            ),
-                        );
+                        embedded.sync_cursor(&window);
                     }
                     Err(error) => match error {
                         wgpu::SurfaceError::OutOfMemory => {
@@ -7,7 +7,9 @@ use iced::event;
 use iced::mouse;
 use iced::time::Instant;
 use iced::window::{self, RedrawRequest};
-use iced::{Background, Color, Element, Event, Length, Rectangle, Size};
+use iced::{
+    Background, Color, Element, Event, Length, Rectangle, Shadow, Size,
+};
 
 use super::easing::{self, Easing};
 
@@ -236,6 +238,7 @@ where
                 border_radius: 0.0.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
             },
             Background::Color(custom_style.track_color),
         );
@@ -252,6 +255,7 @@ where
                     border_radius: 0.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Background::Color(custom_style.bar_color),
             ),
@@ -269,6 +273,7 @@ where
                     border_radius: 0.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Background::Color(custom_style.bar_color),
             ),
@@ -84,16 +84,6 @@ impl Application for App {
                 Command::none()
             }
             Message::Event(event) => match event {
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key_code: keyboard::KeyCode::Tab,
-                    modifiers,
-                }) => {
-                    if modifiers.shift() {
-                        widget::focus_previous()
-                    } else {
-                        widget::focus_next()
-                    }
-                }
                 Event::Keyboard(keyboard::Event::KeyPressed {
                     key_code: keyboard::KeyCode::Escape,
                     ..
@@ -229,7 +219,8 @@ mod modal {
     use iced::event;
     use iced::mouse;
     use iced::{
-        BorderRadius, Color, Element, Event, Length, Point, Rectangle, Size,
+        BorderRadius, Color, Element, Event, Length, Point, Rectangle, Shadow,
+        Size,
     };
 
     /// A widget that centers a modal element over some base element
@@ -479,6 +470,7 @@ mod modal {
                     border_radius: BorderRadius::default(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 Color {
                     a: 0.80,
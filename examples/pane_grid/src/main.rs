@@ -1,6 +1,6 @@
 use iced::alignment::{self, Alignment};
 use iced::executor;
-use iced::keyboard;
+use iced::keymap::{Keymap, Shortcut};
 use iced::theme::{self, Theme};
 use iced::widget::pane_grid::{self, PaneGrid};
 use iced::widget::{
@@ -18,6 +18,7 @@ struct Example {
     panes: pane_grid::State<Pane>,
     panes_created: usize,
     focus: Option<pane_grid::Pane>,
+    keymap: Keymap<Message>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -49,6 +50,7 @@ impl Application for Example {
                 panes,
                 panes_created: 1,
                 focus: None,
+                keymap: keymap(),
             },
             Command::none(),
         )
@@ -137,13 +139,7 @@ impl Application for Example {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        keyboard::on_key_press(|key_code, modifiers| {
-            if !modifiers.command() {
-                return None;
-            }
-
-            handle_hotkey(key_code)
-        })
+        self.keymap.subscription()
     }
 
     fn view(&self) -> Element<Message> {
@@ -220,24 +216,44 @@ const PANE_ID_COLOR_FOCUSED: Color = Color::from_rgb(
     0x47 as f32 / 255.0,
 );
 
-fn handle_hotkey(key_code: keyboard::KeyCode) -> Option<Message> {
-    use keyboard::KeyCode;
+fn keymap() -> Keymap<Message> {
     use pane_grid::{Axis, Direction};
 
-    let direction = match key_code {
-        KeyCode::Up => Some(Direction::Up),
-        KeyCode::Down => Some(Direction::Down),
-        KeyCode::Left => Some(Direction::Left),
-        KeyCode::Right => Some(Direction::Right),
-        _ => None,
-    };
+    let shortcut =
+        |shortcut: &str| shortcut.parse::<Shortcut>().expect("Parse shortcut");
 
-    match key_code {
-        KeyCode::V => Some(Message::SplitFocused(Axis::Vertical)),
-        KeyCode::H => Some(Message::SplitFocused(Axis::Horizontal)),
-        KeyCode::W => Some(Message::CloseFocused),
-        _ => direction.map(Message::FocusAdjacent),
-    }
+    Keymap::new()
+        .bind(
+            "split_vertically",
+            shortcut("Cmd+V"),
+            Message::SplitFocused(Axis::Vertical),
+        )
+        .bind(
+            "split_horizontally",
+            shortcut("Cmd+H"),
+            Message::SplitFocused(Axis::Horizontal),
+        )
+        .bind("close", shortcut("Cmd+W"), Message::CloseFocused)
+        .bind(
+            "focus_up",
+            shortcut("Cmd+Up"),
+            Message::FocusAdjacent(Direction::Up),
+        )
+        .bind(
+            "focus_down",
+            shortcut("Cmd+Down"),
+            Message::FocusAdjacent(Direction::Down),
+        )
+        .bind(
+            "focus_left",
+            shortcut("Cmd+Left"),
+            Message::FocusAdjacent(Direction::Left),
+        )
+        .bind(
+            "focus_right",
+            shortcut("Cmd+Right"),
+            Message::FocusAdjacent(Direction::Right),
+        )
 }
 
 #[derive(Clone, Copy)]
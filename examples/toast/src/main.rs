@@ -1,12 +1,8 @@
-use iced::event::{self, Event};
 use iced::executor;
-use iced::keyboard;
 use iced::widget::{
-    self, button, column, container, pick_list, row, slider, text, text_input,
-};
-use iced::{
-    Alignment, Application, Command, Element, Length, Settings, Subscription,
+    button, column, container, pick_list, row, slider, text, text_input,
 };
+use iced::{Alignment, Application, Command, Element, Length, Settings};
 
 use toast::{Status, Toast};
 
@@ -30,7 +26,6 @@ enum Message {
     Body(String),
     Status(Status),
     Timeout(f64),
-    Event(Event),
 }
 
 impl Application for App {
@@ -58,10 +53,6 @@ impl Application for App {
         String::from("Toast - Iced")
     }
 
-    fn subscription(&self) -> Subscription<Self::Message> {
-        event::listen().map(Message::Event)
-    }
-
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::Add => {
@@ -92,15 +83,6 @@ impl Application for App {
                 self.timeout_secs = timeout as u64;
                 Command::none()
             }
-            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
-                key_code: keyboard::KeyCode::Tab,
-                modifiers,
-            })) if modifiers.shift() => widget::focus_previous(),
-            Message::Event(Event::Keyboard(keyboard::Event::KeyPressed {
-                key_code: keyboard::KeyCode::Tab,
-                ..
-            })) => widget::focus_next(),
-            Message::Event(_) => Command::none(),
         }
     }
 
@@ -3,8 +3,8 @@ use iced::font::{self, Font};
 use iced::keyboard;
 use iced::theme::{self, Theme};
 use iced::widget::{
-    self, button, checkbox, column, container, keyed_column, row, scrollable,
-    text, text_input, Text,
+    button, checkbox, column, container, keyed_column, row, scrollable, text,
+    text_input, Text,
 };
 use iced::window;
 use iced::{Application, Element};
@@ -53,7 +53,6 @@ enum Message {
     CreateTask,
     FilterChanged(Filter),
     TaskMessage(usize, TaskMessage),
-    TabPressed { shift: bool },
     ChangeWindowMode(window::Mode),
 }
 
@@ -158,13 +157,6 @@ impl Application for Todos {
 
                         Command::none()
                     }
-                    Message::TabPressed { shift } => {
-                        if shift {
-                            widget::focus_previous()
-                        } else {
-                            widget::focus_next()
-                        }
-                    }
                     Message::ChangeWindowMode(mode) => {
                         window::change_mode(mode)
                     }
@@ -268,9 +260,6 @@ impl Application for Todos {
     fn subscription(&self) -> Subscription<Message> {
         keyboard::on_key_press(|key_code, modifiers| {
             match (key_code, modifiers) {
-                (keyboard::KeyCode::Tab, _) => Some(Message::TabPressed {
-                    shift: modifiers.shift(),
-                }),
                 (keyboard::KeyCode::Up, keyboard::Modifiers::SHIFT) => {
                     Some(Message::ChangeWindowMode(window::Mode::Fullscreen))
                 }
@@ -3,6 +3,8 @@ use iced::widget::tooltip::Position;
 use iced::widget::{button, container, tooltip};
 use iced::{Element, Length, Sandbox, Settings};
 
+use std::time::Duration;
+
 pub fn main() -> iced::Result {
     Example::run(Settings::default())
 }
@@ -53,6 +55,8 @@ impl Sandbox for Example {
             self.position,
         )
         .gap(10)
+        .arrow(6)
+        .delay(Duration::from_millis(300))
         .style(theme::Container::Box);
 
         container(tooltip)
@@ -587,7 +587,10 @@ impl<'a> Step {
         Self::container("Text input")
             .push("Use a text input to ask for different kinds of information.")
             .push(if is_secure {
-                text_input.password()
+                text_input
+                    .password()
+                    .revealable(true)
+                    .strength(|value| value.chars().count() as f32 / 12.0)
             } else {
                 text_input
             })
@@ -55,4 +55,49 @@ pub mod time {
                 .boxed()
         }
     }
+
+    /// Returns a future that completes after the given `duration`.
+    pub(crate) fn sleep(duration: std::time::Duration) -> crate::BoxFuture<()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+}
+
+pub mod idle {
+    //! Detect when the user stops interacting with the application.
+    use crate::core::Hasher;
+    use crate::idle::Event;
+    use crate::subscription::{self, Subscription};
+
+    /// Returns a [`Subscription`] that produces an [`Event::Idle`] when
+    /// no input has been received for the given `duration`, and an
+    /// [`Event::Resumed`] when input is received again.
+    ///
+    /// This is useful to enter screensaver modes or to lock an application
+    /// automatically.
+    pub fn after(duration: std::time::Duration) -> Subscription<Event> {
+        Subscription::from_recipe(After(duration))
+    }
+
+    #[derive(Debug)]
+    struct After(std::time::Duration);
+
+    impl subscription::Recipe for After {
+        type Output = Event;
+
+        fn hash(&self, state: &mut Hasher) {
+            use std::hash::Hash;
+
+            std::any::TypeId::of::<Self>().hash(state);
+            self.0.hash(state);
+        }
+
+        fn stream(
+            self: Box<Self>,
+            input: subscription::EventStream,
+        ) -> futures::stream::BoxStream<'static, Self::Output> {
+            use futures::stream::StreamExt;
+
+            crate::idle::watch(self.0, input, super::time::sleep).boxed()
+        }
+    }
 }
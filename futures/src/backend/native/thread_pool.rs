@@ -9,6 +9,18 @@ impl crate::Executor for Executor {
         futures::executor::ThreadPool::new()
     }
 
+    fn with_settings(
+        settings: &crate::executor::Settings,
+    ) -> Result<Self, futures::io::Error> {
+        let mut builder = futures::executor::ThreadPool::builder();
+
+        if let Some(threads) = settings.threads {
+            let _ = builder.pool_size(threads.max(1));
+        }
+
+        builder.create()
+    }
+
     fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
         self.spawn_ok(future);
     }
@@ -17,3 +29,7 @@ impl crate::Executor for Executor {
 pub mod time {
     //! Listen and react to time.
 }
+
+pub mod idle {
+    //! Detect when the user stops interacting with the application.
+}
@@ -20,3 +20,7 @@ impl crate::Executor for Executor {
 pub mod time {
     //! Listen and react to time.
 }
+
+pub mod idle {
+    //! Detect when the user stops interacting with the application.
+}
@@ -9,6 +9,18 @@ pub trait Executor: Sized {
     where
         Self: Sized;
 
+    /// Creates a new [`Executor`] with the given [`Settings`].
+    ///
+    /// Executors that cannot be configured ignore the [`Settings`].
+    fn with_settings(settings: &Settings) -> Result<Self, futures::io::Error>
+    where
+        Self: Sized,
+    {
+        let _ = settings;
+
+        Self::new()
+    }
+
     /// Spawns a future in the [`Executor`].
     fn spawn(&self, future: impl Future<Output = ()> + MaybeSend + 'static);
 
@@ -22,3 +34,50 @@ pub trait Executor: Sized {
         f()
     }
 }
+
+/// The configuration of an [`Executor`] and the runtime using it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// The amount of threads running futures.
+    ///
+    /// By default, the [`Executor`] decides—usually one per core.
+    pub threads: Option<usize>,
+
+    /// The maximum amount of threads of the dedicated pool running blocking
+    /// work, for the executors that have one.
+    ///
+    /// By default, the [`Executor`] decides.
+    pub blocking_threads: Option<usize>,
+
+    /// The maximum amount of futures with [`Priority::Low`] running at once.
+    ///
+    /// By default, it is one less than the available parallelism, so heavy
+    /// background work always leaves a thread free for other futures.
+    pub low_priority_limit: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            threads: None,
+            blocking_threads: None,
+            low_priority_limit: std::thread::available_parallelism()
+                .map_or(1, |parallelism| parallelism.get().saturating_sub(1))
+                .max(1),
+        }
+    }
+}
+
+/// The priority of a future spawned in a runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// The future does background work that can wait.
+    ///
+    /// Only [`Settings::low_priority_limit`] of these futures run at once;
+    /// the rest wait for their turn.
+    Low,
+
+    /// The future runs right away.
+    #[default]
+    Normal,
+}
@@ -0,0 +1,113 @@
+//! Detect when the user stops interacting with the application.
+use crate::core;
+use crate::core::time::{Duration, Instant};
+use crate::subscription;
+
+use futures::future::{self, Either};
+use futures::stream::{self, Stream, StreamExt};
+use futures::Future;
+
+/// A change in the activity of the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// No input has been received for the watched duration.
+    Idle,
+
+    /// Input has been received after being [`Event::Idle`].
+    Resumed,
+}
+
+/// Returns whether the given [`core::Event`] was produced by the user
+/// interacting with the application.
+pub fn is_activity(event: &core::Event) -> bool {
+    matches!(
+        event,
+        core::Event::Keyboard(_)
+            | core::Event::Mouse(_)
+            | core::Event::Touch(_)
+            | core::Event::Pen(_)
+            | core::Event::Gesture(_)
+    )
+}
+
+/// Watches the given input for inactivity, using the given `sleep` function
+/// of a backend to wait.
+#[cfg_attr(
+    not(any(
+        target_arch = "wasm32",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn watch<F>(
+    duration: Duration,
+    input: subscription::EventStream,
+    sleep: fn(Duration) -> F,
+) -> impl Stream<Item = Event>
+where
+    F: Future<Output = ()>,
+{
+    struct State<F> {
+        input: subscription::EventStream,
+        timer: std::pin::Pin<Box<F>>,
+        last_activity: Instant,
+        is_idle: bool,
+    }
+
+    let state = State {
+        input,
+        timer: Box::pin(sleep(duration)),
+        last_activity: Instant::now(),
+        is_idle: false,
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if state.is_idle {
+                // There is nothing to time until the user comes back
+                let (event, _status) = state.input.next().await?;
+
+                if is_activity(&event) {
+                    state.is_idle = false;
+                    state.last_activity = Instant::now();
+                    state.timer = Box::pin(sleep(duration));
+
+                    return Some((Event::Resumed, state));
+                }
+
+                continue;
+            }
+
+            let is_timer_done =
+                match future::select(state.input.next(), state.timer.as_mut())
+                    .await
+                {
+                    Either::Left((Some((event, _status)), _)) => {
+                        if is_activity(&event) {
+                            state.last_activity = Instant::now();
+                        }
+
+                        false
+                    }
+                    Either::Left((None, _)) => return None,
+                    Either::Right(((), _)) => true,
+                };
+
+            if is_timer_done {
+                let elapsed = state.last_activity.elapsed();
+
+                if elapsed >= duration {
+                    state.is_idle = true;
+
+                    return Some((Event::Idle, state));
+                }
+
+                // Activity was received in the meantime, so the timer is
+                // rearmed for the remaining time instead of on every event
+                state.timer = Box::pin(sleep(duration - elapsed));
+            }
+        }
+    })
+}
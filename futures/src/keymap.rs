@@ -0,0 +1,810 @@
+//! Declare keyboard shortcuts and map them to messages.
+//!
+//! A [`Keymap`] holds the shortcuts of an application by name. Each
+//! shortcut is made of one or more [`Chord`]s pressed in sequence, and it
+//! can be bound globally or only while a scope is active. The [`Keymap`]
+//! can then produce a [`Subscription`] that listens to the keyboard and
+//! publishes the message of every shortcut that is pressed.
+//!
+//! ```
+//! use iced_futures::keymap::{Keymap, Shortcut};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//!     SaveAll,
+//!     CloseTab,
+//! }
+//!
+//! let keymap = Keymap::new()
+//!     .bind("save", "Cmd+S".parse().unwrap(), Message::Save)
+//!     .bind("save_all", "Cmd+K S".parse().unwrap(), Message::SaveAll)
+//!     .bind_in("tabs", "close_tab", "Cmd+W".parse().unwrap(), Message::CloseTab);
+//!
+//! assert!(keymap.conflicts().is_empty());
+//! ```
+use crate::core;
+use crate::core::keyboard::{Event, KeyCode, Modifiers};
+use crate::core::Hasher;
+use crate::futures::future;
+use crate::futures::stream::StreamExt;
+use crate::subscription::{EventStream, Recipe, Subscription};
+use crate::{BoxStream, MaybeSend};
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A key pressed together with some [`Modifiers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// The [`Modifiers`] held down.
+    pub modifiers: Modifiers,
+    /// The [`KeyCode`] pressed.
+    pub key_code: KeyCode,
+}
+
+impl Chord {
+    /// Creates a new [`Chord`].
+    pub fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+}
+
+impl From<KeyCode> for Chord {
+    fn from(key_code: KeyCode) -> Self {
+        Self::new(Modifiers::default(), key_code)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::default();
+        let mut parts = s.split('+').map(str::trim).peekable();
+
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                let key_code =
+                    key_code(part).ok_or_else(|| ParseError::UnknownKey {
+                        name: part.to_owned(),
+                    })?;
+
+                return Ok(Self::new(modifiers, key_code));
+            }
+
+            modifiers |=
+                modifier(part).ok_or_else(|| ParseError::UnknownModifier {
+                    name: part.to_owned(),
+                })?;
+        }
+
+        Err(ParseError::Empty)
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in MODIFIERS {
+            if self.modifiers.contains(*modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match key_name(self.key_code) {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{:?}", self.key_code),
+        }
+    }
+}
+
+/// A sequence of [`Chord`]s that triggers a binding of a [`Keymap`].
+///
+/// Most shortcuts are a single [`Chord`], like `Ctrl+S`. Longer sequences,
+/// like `Ctrl+K Ctrl+S`, must be pressed one [`Chord`] after the other.
+///
+/// A [`Shortcut`] can be parsed from a string, where chords are separated
+/// by spaces and the keys of a chord by `+`. The `Cmd` modifier stands for
+/// [`Modifiers::COMMAND`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    chords: Vec<Chord>,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] made of a single [`Chord`].
+    pub fn new(chord: impl Into<Chord>) -> Self {
+        Self {
+            chords: vec![chord.into()],
+        }
+    }
+
+    /// Appends a [`Chord`] that must be pressed after the current ones.
+    pub fn then(mut self, chord: impl Into<Chord>) -> Self {
+        self.chords.push(chord.into());
+        self
+    }
+
+    /// Returns the [`Chord`]s of the [`Shortcut`], in order.
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.chords.starts_with(&other.chords)
+            || other.chords.starts_with(&self.chords)
+    }
+}
+
+impl From<Chord> for Shortcut {
+    fn from(chord: Chord) -> Self {
+        Self::new(chord)
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chords.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(Self { chords })
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.chords.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{chord}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error produced when parsing a [`Shortcut`] or a [`Chord`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// The string did not contain any key.
+    #[error("the shortcut is empty")]
+    Empty,
+    /// A modifier was not recognized.
+    #[error("unknown modifier: {name}")]
+    UnknownModifier {
+        /// The name of the modifier.
+        name: String,
+    },
+    /// A key was not recognized.
+    #[error("unknown key: {name}")]
+    UnknownKey {
+        /// The name of the key.
+        name: String,
+    },
+}
+
+/// A set of named keyboard shortcuts mapped to messages.
+#[derive(Debug, Clone)]
+pub struct Keymap<Message> {
+    bindings: Vec<Binding<Message>>,
+    active_scopes: BTreeSet<&'static str>,
+}
+
+/// A [`Shortcut`] of a [`Keymap`] and the message it produces.
+#[derive(Debug, Clone)]
+pub struct Binding<Message> {
+    /// The unique name of the [`Binding`].
+    pub name: &'static str,
+    /// The [`Shortcut`] that triggers the [`Binding`].
+    pub shortcut: Shortcut,
+    /// The scope where the [`Binding`] applies, if it is not global.
+    pub scope: Option<&'static str>,
+    /// The message produced when the [`Binding`] is triggered.
+    pub message: Message,
+}
+
+/// Two bindings of a [`Keymap`] that cannot be told apart.
+///
+/// It happens when both bindings apply in the same scope and the
+/// [`Shortcut`] of one of them is the same as, or starts with, the
+/// [`Shortcut`] of the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// The name of the first binding.
+    pub first: &'static str,
+    /// The name of the second binding.
+    pub second: &'static str,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" conflicts with \"{}\"", self.first, self.second)
+    }
+}
+
+/// An error produced when rebinding a shortcut of a [`Keymap`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RebindError {
+    /// No binding has the given name.
+    #[error("unknown binding: {name}")]
+    Unknown {
+        /// The name of the binding.
+        name: String,
+    },
+    /// The new [`Shortcut`] conflicts with another binding.
+    #[error("{0}")]
+    Conflict(Conflict),
+}
+
+impl<Message> Keymap<Message> {
+    /// Creates an empty [`Keymap`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            active_scopes: BTreeSet::new(),
+        }
+    }
+
+    /// Binds a global [`Shortcut`] to the given message.
+    ///
+    /// Any previous binding with the same name is replaced.
+    pub fn bind(
+        self,
+        name: &'static str,
+        shortcut: Shortcut,
+        message: Message,
+    ) -> Self {
+        self.push(Binding {
+            name,
+            shortcut,
+            scope: None,
+            message,
+        })
+    }
+
+    /// Binds a [`Shortcut`] to the given message only while the given scope
+    /// is active.
+    ///
+    /// Scoped bindings take precedence over global ones.
+    ///
+    /// Any previous binding with the same name is replaced.
+    pub fn bind_in(
+        self,
+        scope: &'static str,
+        name: &'static str,
+        shortcut: Shortcut,
+        message: Message,
+    ) -> Self {
+        self.push(Binding {
+            name,
+            shortcut,
+            scope: Some(scope),
+            message,
+        })
+    }
+
+    /// Removes the binding with the given name, if any.
+    pub fn unbind(&mut self, name: &str) -> Option<Binding<Message>> {
+        let index = self.position(name)?;
+
+        Some(self.bindings.remove(index))
+    }
+
+    /// Changes the [`Shortcut`] of the binding with the given name.
+    ///
+    /// The [`Keymap`] is left untouched if the new [`Shortcut`] would
+    /// conflict with another binding.
+    pub fn rebind(
+        &mut self,
+        name: &str,
+        shortcut: Shortcut,
+    ) -> Result<(), RebindError> {
+        let index =
+            self.position(name).ok_or_else(|| RebindError::Unknown {
+                name: name.to_owned(),
+            })?;
+
+        let binding = &self.bindings[index];
+
+        if let Some(other) = self.bindings.iter().find(|other| {
+            other.name != binding.name
+                && other.scope == binding.scope
+                && other.shortcut.overlaps(&shortcut)
+        }) {
+            return Err(RebindError::Conflict(Conflict {
+                first: binding.name,
+                second: other.name,
+            }));
+        }
+
+        self.bindings[index].shortcut = shortcut;
+
+        Ok(())
+    }
+
+    /// Returns the [`Shortcut`] of the binding with the given name, if any.
+    pub fn shortcut(&self, name: &str) -> Option<&Shortcut> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.name == name)
+            .map(|binding| &binding.shortcut)
+    }
+
+    /// Returns all the bindings of the [`Keymap`].
+    pub fn bindings(&self) -> impl Iterator<Item = &Binding<Message>> {
+        self.bindings.iter()
+    }
+
+    /// Returns all the [`Conflict`]s between the bindings of the [`Keymap`].
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+
+        for (i, first) in self.bindings.iter().enumerate() {
+            for second in &self.bindings[i + 1..] {
+                if first.scope == second.scope
+                    && first.shortcut.overlaps(&second.shortcut)
+                {
+                    conflicts.push(Conflict {
+                        first: first.name,
+                        second: second.name,
+                    });
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Activates the given scope, enabling its bindings.
+    pub fn activate(&mut self, scope: &'static str) {
+        let _ = self.active_scopes.insert(scope);
+    }
+
+    /// Deactivates the given scope, disabling its bindings.
+    pub fn deactivate(&mut self, scope: &str) {
+        let _ = self.active_scopes.remove(scope);
+    }
+
+    /// Returns whether the given scope is active.
+    pub fn is_active(&self, scope: &str) -> bool {
+        self.active_scopes.contains(scope)
+    }
+
+    fn push(mut self, binding: Binding<Message>) -> Self {
+        match self.position(binding.name) {
+            Some(index) => self.bindings[index] = binding,
+            None => self.bindings.push(binding),
+        }
+
+        self
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.bindings
+            .iter()
+            .position(|binding| binding.name == name)
+    }
+}
+
+impl<Message> Keymap<Message>
+where
+    Message: Clone + MaybeSend + 'static,
+{
+    /// Returns a [`Subscription`] that produces the message of every
+    /// binding triggered by the keyboard.
+    ///
+    /// Only key presses that were not captured by a widget are considered,
+    /// and only the bindings of active scopes apply.
+    ///
+    /// The [`Subscription`] is restarted whenever a [`Shortcut`] or the
+    /// active scopes change. Changing only the message of a binding does
+    /// not restart it; rename the binding in that case.
+    pub fn subscription(&self) -> Subscription<Message> {
+        let bindings = self
+            .bindings
+            .iter()
+            .filter(|binding| {
+                binding
+                    .scope
+                    .is_none_or(|scope| self.active_scopes.contains(scope))
+            })
+            .cloned()
+            .collect();
+
+        Subscription::from_recipe(Listener { bindings })
+    }
+}
+
+impl<Message> Default for Keymap<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Listener<Message> {
+    bindings: Vec<Binding<Message>>,
+}
+
+impl<Message> Recipe for Listener<Message>
+where
+    Message: Clone + MaybeSend + 'static,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+
+        for binding in &self.bindings {
+            binding.name.hash(state);
+            binding.shortcut.hash(state);
+            binding.scope.hash(state);
+        }
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Message> {
+        let mut matcher = Matcher {
+            bindings: self.bindings,
+            pending: Vec::new(),
+        };
+
+        crate::boxed_stream(input.filter_map(move |(event, status)| {
+            future::ready(match (event, status) {
+                (
+                    core::Event::Keyboard(Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                        ..
+                    }),
+                    core::event::Status::Ignored,
+                ) if !is_modifier(key_code) => {
+                    matcher.press(Chord::new(modifiers, key_code))
+                }
+                _ => None,
+            })
+        }))
+    }
+}
+
+struct Matcher<Message> {
+    bindings: Vec<Binding<Message>>,
+    pending: Vec<Chord>,
+}
+
+impl<Message: Clone> Matcher<Message> {
+    fn press(&mut self, chord: Chord) -> Option<Message> {
+        self.pending.push(chord);
+
+        match self.find() {
+            Match::Exact(message) => {
+                self.pending.clear();
+
+                Some(message)
+            }
+            Match::Prefix => None,
+            Match::None if self.pending.len() > 1 => {
+                // The sequence was broken; the last chord may start a new one
+                self.pending.clear();
+                self.press(chord)
+            }
+            Match::None => {
+                self.pending.clear();
+
+                None
+            }
+        }
+    }
+
+    fn find(&self) -> Match<Message> {
+        let mut result = Match::None;
+
+        for binding in &self.bindings {
+            let chords = binding.shortcut.chords();
+
+            if !chords.starts_with(&self.pending) {
+                continue;
+            }
+
+            if chords.len() > self.pending.len() {
+                if matches!(result, Match::None) {
+                    result = Match::Prefix;
+                }
+
+                continue;
+            }
+
+            if binding.scope.is_some() {
+                return Match::Exact(binding.message.clone());
+            }
+
+            if !matches!(result, Match::Exact(_)) {
+                result = Match::Exact(binding.message.clone());
+            }
+        }
+
+        result
+    }
+}
+
+enum Match<Message> {
+    None,
+    Prefix,
+    Exact(Message),
+}
+
+fn is_modifier(key_code: KeyCode) -> bool {
+    matches!(
+        key_code,
+        KeyCode::LShift
+            | KeyCode::RShift
+            | KeyCode::LControl
+            | KeyCode::RControl
+            | KeyCode::LAlt
+            | KeyCode::RAlt
+            | KeyCode::LWin
+            | KeyCode::RWin
+    )
+}
+
+const MODIFIERS: &[(Modifiers, &str)] = &[
+    (Modifiers::CTRL, "Ctrl"),
+    (Modifiers::ALT, "Alt"),
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::LOGO, "Logo"),
+];
+
+fn modifier(name: &str) -> Option<Modifiers> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Modifiers::CTRL,
+        "alt" | "option" => Modifiers::ALT,
+        "shift" => Modifiers::SHIFT,
+        "logo" | "super" | "win" | "meta" => Modifiers::LOGO,
+        "cmd" | "command" | "mod" => Modifiers::COMMAND,
+        _ => return None,
+    })
+}
+
+/// The names of the keys that can be used in a [`Shortcut`].
+///
+/// The first name of a key is the one used to display it.
+const KEYS: &[(&str, KeyCode)] = &[
+    ("A", KeyCode::A),
+    ("B", KeyCode::B),
+    ("C", KeyCode::C),
+    ("D", KeyCode::D),
+    ("E", KeyCode::E),
+    ("F", KeyCode::F),
+    ("G", KeyCode::G),
+    ("H", KeyCode::H),
+    ("I", KeyCode::I),
+    ("J", KeyCode::J),
+    ("K", KeyCode::K),
+    ("L", KeyCode::L),
+    ("M", KeyCode::M),
+    ("N", KeyCode::N),
+    ("O", KeyCode::O),
+    ("P", KeyCode::P),
+    ("Q", KeyCode::Q),
+    ("R", KeyCode::R),
+    ("S", KeyCode::S),
+    ("T", KeyCode::T),
+    ("U", KeyCode::U),
+    ("V", KeyCode::V),
+    ("W", KeyCode::W),
+    ("X", KeyCode::X),
+    ("Y", KeyCode::Y),
+    ("Z", KeyCode::Z),
+    ("0", KeyCode::Key0),
+    ("1", KeyCode::Key1),
+    ("2", KeyCode::Key2),
+    ("3", KeyCode::Key3),
+    ("4", KeyCode::Key4),
+    ("5", KeyCode::Key5),
+    ("6", KeyCode::Key6),
+    ("7", KeyCode::Key7),
+    ("8", KeyCode::Key8),
+    ("9", KeyCode::Key9),
+    ("F1", KeyCode::F1),
+    ("F2", KeyCode::F2),
+    ("F3", KeyCode::F3),
+    ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5),
+    ("F6", KeyCode::F6),
+    ("F7", KeyCode::F7),
+    ("F8", KeyCode::F8),
+    ("F9", KeyCode::F9),
+    ("F10", KeyCode::F10),
+    ("F11", KeyCode::F11),
+    ("F12", KeyCode::F12),
+    ("F13", KeyCode::F13),
+    ("F14", KeyCode::F14),
+    ("F15", KeyCode::F15),
+    ("F16", KeyCode::F16),
+    ("F17", KeyCode::F17),
+    ("F18", KeyCode::F18),
+    ("F19", KeyCode::F19),
+    ("F20", KeyCode::F20),
+    ("F21", KeyCode::F21),
+    ("F22", KeyCode::F22),
+    ("F23", KeyCode::F23),
+    ("F24", KeyCode::F24),
+    ("Escape", KeyCode::Escape),
+    ("Esc", KeyCode::Escape),
+    ("Enter", KeyCode::Enter),
+    ("Return", KeyCode::Enter),
+    ("Space", KeyCode::Space),
+    ("Tab", KeyCode::Tab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Del", KeyCode::Delete),
+    ("Insert", KeyCode::Insert),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("-", KeyCode::Minus),
+    ("Minus", KeyCode::Minus),
+    ("=", KeyCode::Equals),
+    ("Equals", KeyCode::Equals),
+    ("Plus", KeyCode::Plus),
+    (",", KeyCode::Comma),
+    ("Comma", KeyCode::Comma),
+    (".", KeyCode::Period),
+    ("Period", KeyCode::Period),
+    ("/", KeyCode::Slash),
+    ("Slash", KeyCode::Slash),
+    ("\\", KeyCode::Backslash),
+    ("Backslash", KeyCode::Backslash),
+    (";", KeyCode::Semicolon),
+    ("Semicolon", KeyCode::Semicolon),
+    ("'", KeyCode::Apostrophe),
+    ("Apostrophe", KeyCode::Apostrophe),
+    ("`", KeyCode::Grave),
+    ("Grave", KeyCode::Grave),
+    ("[", KeyCode::LBracket),
+    ("]", KeyCode::RBracket),
+];
+
+fn key_code(name: &str) -> Option<KeyCode> {
+    KEYS.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, key_code)| *key_code)
+}
+
+fn key_name(key_code: KeyCode) -> Option<&'static str> {
+    KEYS.iter()
+        .find(|(_, key)| *key == key_code)
+        .map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Save,
+        SaveAll,
+        Close,
+    }
+
+    #[test]
+    fn parse_shortcut() {
+        let shortcut: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+
+        assert_eq!(
+            shortcut.chords(),
+            &[Chord::new(Modifiers::CTRL | Modifiers::SHIFT, KeyCode::S)]
+        );
+
+        let shortcut: Shortcut = "ctrl+k  ctrl+s".parse().unwrap();
+
+        assert_eq!(
+            shortcut,
+            Shortcut::new(Chord::new(Modifiers::CTRL, KeyCode::K))
+                .then(Chord::new(Modifiers::CTRL, KeyCode::S))
+        );
+        assert_eq!(shortcut.to_string(), "Ctrl+K Ctrl+S");
+
+        assert_eq!("".parse::<Shortcut>(), Err(ParseError::Empty));
+        assert_eq!(
+            "Hyper+S".parse::<Shortcut>(),
+            Err(ParseError::UnknownModifier {
+                name: String::from("Hyper")
+            })
+        );
+        assert_eq!(
+            "Ctrl+Foo".parse::<Shortcut>(),
+            Err(ParseError::UnknownKey {
+                name: String::from("Foo")
+            })
+        );
+    }
+
+    #[test]
+    fn detect_conflicts() {
+        let mut keymap = Keymap::new()
+            .bind("save", "Ctrl+S".parse().unwrap(), Message::Save)
+            .bind("save_all", "Ctrl+K S".parse().unwrap(), Message::SaveAll)
+            .bind_in(
+                "tabs",
+                "close",
+                "Ctrl+S".parse().unwrap(),
+                Message::Close,
+            );
+
+        assert!(keymap.conflicts().is_empty());
+
+        assert_eq!(
+            keymap.rebind("save_all", "Ctrl+S Ctrl+A".parse().unwrap()),
+            Err(RebindError::Conflict(Conflict {
+                first: "save_all",
+                second: "save",
+            }))
+        );
+        assert_eq!(
+            keymap.shortcut("save_all"),
+            Some(&"Ctrl+K S".parse().unwrap())
+        );
+
+        keymap =
+            keymap.bind("close", "Ctrl+K".parse().unwrap(), Message::Close);
+
+        assert_eq!(
+            keymap.conflicts(),
+            vec![Conflict {
+                first: "save_all",
+                second: "close",
+            }]
+        );
+    }
+
+    #[test]
+    fn match_sequences() {
+        let ctrl = |key_code| Chord::new(Modifiers::CTRL, key_code);
+
+        let mut matcher = Matcher {
+            bindings: Keymap::new()
+                .bind("save", "Ctrl+S".parse().unwrap(), Message::Save)
+                .bind("save_all", "Ctrl+K S".parse().unwrap(), Message::SaveAll)
+                .bind_in(
+                    "tabs",
+                    "close",
+                    "Ctrl+S".parse().unwrap(),
+                    Message::Close,
+                )
+                .bindings,
+            pending: Vec::new(),
+        };
+
+        assert_eq!(matcher.press(ctrl(KeyCode::K)), None);
+        assert_eq!(matcher.press(KeyCode::S.into()), Some(Message::SaveAll));
+
+        // Scoped bindings take precedence
+        assert_eq!(matcher.press(ctrl(KeyCode::S)), Some(Message::Close));
+
+        // A broken sequence starts over with the last chord
+        matcher.bindings.retain(|binding| binding.scope.is_none());
+
+        assert_eq!(matcher.press(ctrl(KeyCode::K)), None);
+        assert_eq!(matcher.press(ctrl(KeyCode::S)), Some(Message::Save));
+    }
+}
@@ -21,7 +21,10 @@ mod runtime;
 pub mod backend;
 pub mod event;
 pub mod executor;
+pub mod idle;
 pub mod keyboard;
+pub mod keymap;
+pub mod mouse;
 pub mod subscription;
 
 pub use executor::Executor;
@@ -0,0 +1,28 @@
+//! Listen to mouse events.
+use crate::core;
+use crate::core::mouse::Event;
+use crate::core::Vector;
+use crate::subscription::{self, Subscription};
+use crate::MaybeSend;
+
+/// Listens to the relative motion of the mouse and calls the given function
+/// to map it into actual messages.
+///
+/// The motion keeps being reported while the cursor is grabbed, which makes
+/// it suitable for looking around in 3D viewports.
+///
+/// If the function returns `None`, the motion will be simply ignored.
+pub fn on_motion<Message>(
+    f: fn(Vector) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: MaybeSend + 'static,
+{
+    #[derive(Hash)]
+    struct OnMotion;
+
+    subscription::filter_map((OnMotion, f), move |event, _status| match event {
+        core::Event::Mouse(Event::RelativeMotion { delta }) => f(delta),
+        _ => None,
+    })
+}
@@ -1,10 +1,13 @@
 //! Run commands and keep track of subscriptions.
 use crate::core::event::{self, Event};
+use crate::executor::{self, Priority};
 use crate::subscription;
 use crate::{BoxFuture, Executor, MaybeSend};
 
+use futures::lock::Mutex;
 use futures::{channel::mpsc, Sink};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// A batteries-included runtime of commands and subscriptions.
 ///
@@ -17,6 +20,7 @@ pub struct Runtime<Executor, Sender, Message> {
     executor: Executor,
     sender: Sender,
     subscriptions: subscription::Tracker,
+    low_priority: Permits,
     _message: PhantomData<Message>,
 }
 
@@ -40,10 +44,20 @@ where
             executor,
             sender,
             subscriptions: subscription::Tracker::new(),
+            low_priority: Permits::new(
+                executor::Settings::default().low_priority_limit,
+            ),
             _message: PhantomData,
         }
     }
 
+    /// Sets the maximum amount of futures with [`Priority::Low`] running at
+    /// once in the [`Runtime`].
+    pub fn low_priority_limit(mut self, limit: usize) -> Self {
+        self.low_priority = Permits::new(limit);
+        self
+    }
+
     /// Runs the given closure inside the [`Executor`] of the [`Runtime`].
     ///
     /// See [`Executor::enter`] to learn more.
@@ -69,6 +83,31 @@ where
         self.executor.spawn(future);
     }
 
+    /// Spawns a [`Future`] in the [`Runtime`] with the given [`Priority`].
+    ///
+    /// Futures with [`Priority::Low`] wait for their turn if too many of them
+    /// are already running, so they cannot starve the rest.
+    ///
+    /// [`Future`]: BoxFuture
+    pub fn spawn_with_priority(
+        &mut self,
+        future: BoxFuture<Message>,
+        priority: Priority,
+    ) {
+        match priority {
+            Priority::Low => {
+                let permits = self.low_priority.clone();
+
+                self.spawn(Box::pin(async move {
+                    let _permit = permits.acquire().await;
+
+                    future.await
+                }));
+            }
+            Priority::Normal => self.spawn(future),
+        }
+    }
+
     /// Tracks a [`Subscription`] in the [`Runtime`].
     ///
     /// It will spawn new streams or close old ones as necessary! See
@@ -108,3 +147,49 @@ where
         self.subscriptions.broadcast(event, status);
     }
 }
+
+/// A limited amount of permits to run futures, shared by all of them.
+#[derive(Debug, Clone)]
+struct Permits {
+    sender: mpsc::UnboundedSender<()>,
+    receiver: Arc<Mutex<mpsc::UnboundedReceiver<()>>>,
+}
+
+impl Permits {
+    fn new(amount: usize) -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+
+        for _ in 0..amount.max(1) {
+            let _ = sender.unbounded_send(());
+        }
+
+        Self {
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+        }
+    }
+
+    /// Waits until a permit is available and takes it.
+    ///
+    /// The permit is given back when the returned [`Permit`] is dropped.
+    async fn acquire(&self) -> Permit {
+        use futures::StreamExt;
+
+        // Waiters take turns through the lock, in order
+        let _ = self.receiver.lock().await.next().await;
+
+        Permit {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+struct Permit {
+    sender: mpsc::UnboundedSender<()>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let _ = self.sender.unbounded_send(());
+    }
+}
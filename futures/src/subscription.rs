@@ -1,6 +1,14 @@
 //! Listen to external events in your application.
 mod tracker;
 
+#[cfg(any(
+    target_arch = "wasm32",
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol"
+))]
+mod rate;
+
 pub use tracker::Tracker;
 
 use crate::core::event::{self, Event};
@@ -103,6 +111,99 @@ impl<Message> Subscription<Message> {
                 .collect(),
         }
     }
+
+    /// Only produces the latest output of the [`Subscription`] once it has
+    /// not produced anything for the given `duration`.
+    ///
+    /// This is useful to search as the user types, without searching on
+    /// every keystroke.
+    #[cfg(any(
+        target_arch = "wasm32",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))]
+    pub fn debounce(self, duration: std::time::Duration) -> Self
+    where
+        Message: MaybeSend + 'static,
+    {
+        self.limit(rate::Kind::Debounce, duration)
+    }
+
+    /// Produces at most one output of the [`Subscription`] per `duration`.
+    ///
+    /// The first output is produced right away, and the latest one of each
+    /// period at its end. This is useful to react to window resizes or to
+    /// high-frequency sensors.
+    #[cfg(any(
+        target_arch = "wasm32",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))]
+    pub fn throttle(self, duration: std::time::Duration) -> Self
+    where
+        Message: MaybeSend + 'static,
+    {
+        self.limit(rate::Kind::Throttle, duration)
+    }
+
+    /// Groups the outputs of the [`Subscription`] produced within `duration`
+    /// of the first one of each group.
+    #[cfg(any(
+        target_arch = "wasm32",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))]
+    pub fn buffer(
+        mut self,
+        duration: std::time::Duration,
+    ) -> Subscription<Vec<Message>>
+    where
+        Message: MaybeSend + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(rate::Buffer::new(
+                        recipe,
+                        duration,
+                        crate::backend::default::time::sleep,
+                    ))
+                        as Box<dyn Recipe<Output = Vec<Message>>>
+                })
+                .collect(),
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "wasm32",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))]
+    fn limit(mut self, kind: rate::Kind, duration: std::time::Duration) -> Self
+    where
+        Message: MaybeSend + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(rate::Limit::new(
+                        recipe,
+                        kind,
+                        duration,
+                        crate::backend::default::time::sleep,
+                    )) as Box<dyn Recipe<Output = Message>>
+                })
+                .collect(),
+        }
+    }
 }
 
 impl<Message> std::fmt::Debug for Subscription<Message> {
@@ -0,0 +1,386 @@
+//! Limit the rate of the output of a [`Subscription`].
+//!
+//! [`Subscription`]: crate::Subscription
+use crate::core::time::Duration;
+use crate::core::Hasher;
+use crate::subscription::{EventStream, Recipe};
+use crate::{BoxFuture, BoxStream, MaybeSend};
+
+use futures::future::{self, Either};
+use futures::stream::{self, StreamExt};
+use std::hash::Hash;
+
+/// A function of a backend that waits for the given duration.
+pub(crate) type Sleep = fn(Duration) -> BoxFuture<()>;
+
+/// The way a [`Limit`] lets values through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Kind {
+    /// Only the latest value is produced, once no values have been
+    /// produced for a while.
+    Debounce,
+    /// At most one value is produced per period, keeping the latest one of
+    /// the period to produce it at its end.
+    Throttle,
+}
+
+/// A [`Recipe`] that limits the rate of the values of another [`Recipe`].
+pub(crate) struct Limit<T> {
+    recipe: Box<dyn Recipe<Output = T>>,
+    kind: Kind,
+    duration: Duration,
+    sleep: Sleep,
+}
+
+impl<T> Limit<T> {
+    pub(crate) fn new(
+        recipe: Box<dyn Recipe<Output = T>>,
+        kind: Kind,
+        duration: Duration,
+        sleep: Sleep,
+    ) -> Self {
+        Self {
+            recipe,
+            kind,
+            duration,
+            sleep,
+        }
+    }
+}
+
+impl<T> Recipe for Limit<T>
+where
+    T: MaybeSend + 'static,
+{
+    type Output = T;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.kind.hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        let Limit {
+            recipe,
+            kind,
+            duration,
+            sleep,
+        } = *self;
+
+        let state = State {
+            stream: recipe.stream(input),
+            timer: None,
+            pending: None,
+            is_done: false,
+        };
+
+        crate::boxed_stream(stream::unfold(
+            state,
+            move |mut state| async move {
+                loop {
+                    if state.is_done {
+                        return None;
+                    }
+
+                    match next(&mut state.stream, &mut state.timer).await {
+                        Step::Value(value) => match kind {
+                            Kind::Debounce => {
+                                state.pending = Some(value);
+                                state.timer = Some(sleep(duration));
+                            }
+                            Kind::Throttle if state.timer.is_none() => {
+                                state.timer = Some(sleep(duration));
+
+                                return Some((value, state));
+                            }
+                            Kind::Throttle => {
+                                state.pending = Some(value);
+                            }
+                        },
+                        Step::Timer => {
+                            if let Some(value) = state.pending.take() {
+                                if kind == Kind::Throttle {
+                                    // The value starts a new period
+                                    state.timer = Some(sleep(duration));
+                                }
+
+                                return Some((value, state));
+                            }
+                        }
+                        Step::Done => {
+                            state.is_done = true;
+
+                            // The latest value is never lost
+                            let value = state.pending.take()?;
+
+                            return Some((value, state));
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
+/// A [`Recipe`] that groups the values of another [`Recipe`] produced
+/// within a period.
+pub(crate) struct Buffer<T> {
+    recipe: Box<dyn Recipe<Output = T>>,
+    duration: Duration,
+    sleep: Sleep,
+}
+
+impl<T> Buffer<T> {
+    pub(crate) fn new(
+        recipe: Box<dyn Recipe<Output = T>>,
+        duration: Duration,
+        sleep: Sleep,
+    ) -> Self {
+        Self {
+            recipe,
+            duration,
+            sleep,
+        }
+    }
+}
+
+impl<T> Recipe for Buffer<T>
+where
+    T: MaybeSend + 'static,
+{
+    type Output = Vec<T>;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        let Buffer {
+            recipe,
+            duration,
+            sleep,
+        } = *self;
+
+        let state = State {
+            stream: recipe.stream(input),
+            timer: None,
+            pending: Vec::new(),
+            is_done: false,
+        };
+
+        crate::boxed_stream(stream::unfold(
+            state,
+            move |mut state| async move {
+                loop {
+                    if state.is_done {
+                        return None;
+                    }
+
+                    match next(&mut state.stream, &mut state.timer).await {
+                        Step::Value(value) => {
+                            // The period starts with its first value, so
+                            // nothing is timed while the subscription is
+                            // quiet
+                            if state.pending.is_empty() {
+                                state.timer = Some(sleep(duration));
+                            }
+
+                            state.pending.push(value);
+                        }
+                        Step::Timer => {
+                            return Some((
+                                std::mem::take(&mut state.pending),
+                                state,
+                            ));
+                        }
+                        Step::Done => {
+                            state.is_done = true;
+
+                            if state.pending.is_empty() {
+                                return None;
+                            }
+
+                            return Some((
+                                std::mem::take(&mut state.pending),
+                                state,
+                            ));
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
+struct State<T, P> {
+    stream: BoxStream<T>,
+    timer: Option<BoxFuture<()>>,
+    pending: P,
+    is_done: bool,
+}
+
+enum Step<T> {
+    Value(T),
+    Timer,
+    Done,
+}
+
+/// Waits for the next value of the stream or for the timer, whichever comes
+/// first.
+async fn next<T>(
+    stream: &mut BoxStream<T>,
+    timer: &mut Option<BoxFuture<()>>,
+) -> Step<T> {
+    let Some(sleep) = timer else {
+        return stream.next().await.map_or(Step::Done, Step::Value);
+    };
+
+    match future::select(stream.next(), sleep.as_mut()).await {
+        Either::Left((Some(value), _)) => Step::Value(value),
+        Either::Left((None, _)) => Step::Done,
+        Either::Right(((), _)) => {
+            *timer = None;
+
+            Step::Timer
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::channel::{mpsc, oneshot};
+    use futures::executor;
+    use futures::FutureExt;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static TIMERS: RefCell<Vec<oneshot::Sender<()>>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    /// A timer that only fires when [`fire`] is called.
+    fn sleep(_duration: Duration) -> BoxFuture<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        TIMERS.with(|timers| timers.borrow_mut().push(sender));
+
+        Box::pin(async move {
+            let _ = receiver.await;
+        })
+    }
+
+    fn fire() {
+        for timer in TIMERS.with(|timers| timers.take()) {
+            let _ = timer.send(());
+        }
+    }
+
+    struct Source(mpsc::UnboundedReceiver<u32>);
+
+    impl Recipe for Source {
+        type Output = u32;
+
+        fn hash(&self, state: &mut Hasher) {
+            std::any::TypeId::of::<Self>().hash(state);
+        }
+
+        fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<u32> {
+            self.0.boxed()
+        }
+    }
+
+    fn limit(kind: Kind) -> (mpsc::UnboundedSender<u32>, BoxStream<u32>) {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let limit = Box::new(Limit::new(
+            Box::new(Source(receiver)),
+            kind,
+            Duration::from_secs(1),
+            sleep,
+        ));
+
+        (sender, limit.stream(stream::empty().boxed()))
+    }
+
+    #[test]
+    fn debounce_produces_the_latest_value_once_quiet() {
+        let (sender, mut output) = limit(Kind::Debounce);
+
+        sender.unbounded_send(1).unwrap();
+        sender.unbounded_send(2).unwrap();
+        assert_eq!(output.next().now_or_never(), None);
+
+        fire();
+        assert_eq!(output.next().now_or_never(), Some(Some(2)));
+
+        sender.unbounded_send(3).unwrap();
+        assert_eq!(output.next().now_or_never(), None);
+
+        // The pending value is flushed when the subscription ends
+        drop(sender);
+        assert_eq!(executor::block_on(output.next()), Some(3));
+        assert_eq!(executor::block_on(output.next()), None);
+    }
+
+    #[test]
+    fn throttle_produces_one_value_per_period() {
+        let (sender, mut output) = limit(Kind::Throttle);
+
+        sender.unbounded_send(1).unwrap();
+        assert_eq!(output.next().now_or_never(), Some(Some(1)));
+
+        sender.unbounded_send(2).unwrap();
+        sender.unbounded_send(3).unwrap();
+        assert_eq!(output.next().now_or_never(), None);
+
+        fire();
+        assert_eq!(output.next().now_or_never(), Some(Some(3)));
+
+        // A quiet period lets the next value through right away
+        fire();
+        assert_eq!(output.next().now_or_never(), None);
+
+        sender.unbounded_send(4).unwrap();
+        assert_eq!(output.next().now_or_never(), Some(Some(4)));
+
+        sender.unbounded_send(5).unwrap();
+        drop(sender);
+        assert_eq!(executor::block_on(output.next()), Some(5));
+        assert_eq!(executor::block_on(output.next()), None);
+    }
+
+    #[test]
+    fn buffer_groups_the_values_of_a_period() {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let buffer = Box::new(Buffer::new(
+            Box::new(Source(receiver)),
+            Duration::from_secs(1),
+            sleep,
+        ));
+
+        let mut output = buffer.stream(stream::empty().boxed());
+
+        sender.unbounded_send(1).unwrap();
+        sender.unbounded_send(2).unwrap();
+        assert_eq!(output.next().now_or_never(), None);
+
+        fire();
+        assert_eq!(output.next().now_or_never(), Some(Some(vec![1, 2])));
+
+        // Nothing is produced for an empty period
+        fire();
+        assert_eq!(output.next().now_or_never(), None);
+
+        sender.unbounded_send(3).unwrap();
+        drop(sender);
+        assert_eq!(executor::block_on(output.next()), Some(vec![3]));
+        assert_eq!(executor::block_on(output.next()), None);
+    }
+}
@@ -1,4 +1,5 @@
 //! Write a graphics backend.
+use crate::compositor::ImageCache;
 use crate::core::image;
 use crate::core::svg;
 use crate::core::Size;
@@ -12,6 +13,18 @@ use std::borrow::Cow;
 pub trait Backend {
     /// The custom kind of primitives this [`Backend`] supports.
     type Primitive;
+
+    /// Returns the fraction of the texture atlas of the [`Backend`] in use,
+    /// if it has one.
+    fn atlas_occupancy(&self) -> Option<f32> {
+        None
+    }
+
+    /// Returns the memory usage of the cache of raster images of the
+    /// [`Backend`], if it has one.
+    fn image_cache(&self) -> Option<ImageCache> {
+        None
+    }
 }
 
 /// A graphics backend that supports text rendering.
@@ -1,6 +1,6 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
-use crate::{Error, Viewport};
+use crate::{Error, Primitive, Viewport};
 
 use iced_core::Color;
 
@@ -72,6 +72,14 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Vec<u8>;
+
+    /// Returns the [`Statistics`] of the primitives recorded by the given
+    /// [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn statistics(&self, _renderer: &Self::Renderer) -> Statistics {
+        Statistics::default()
+    }
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
@@ -103,3 +111,79 @@ pub struct Information {
     /// Contains the graphics backend.
     pub backend: String,
 }
+
+/// Some statistics of the primitives of a frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Statistics {
+    /// The amount of quads.
+    pub quads: usize,
+    /// The amount of texts and paragraphs.
+    pub texts: usize,
+    /// The amount of meshes and other backend-specific primitives.
+    pub meshes: usize,
+    /// The amount of raster and vector images.
+    pub images: usize,
+    /// The fraction of the texture atlas in use, if the backend has one.
+    pub atlas_occupancy: Option<f32>,
+    /// The memory usage of the cache of raster images, if the backend has
+    /// one.
+    pub image_cache: Option<ImageCache>,
+}
+
+/// The memory usage of the cache of raster images of a backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImageCache {
+    /// The amount of cached images.
+    pub images: usize,
+    /// The amount of bytes taken by the cached images.
+    pub bytes: usize,
+    /// The memory budget of the cache, in bytes, if any.
+    pub budget: Option<usize>,
+    /// The amount of images evicted from the cache so far.
+    pub evictions: usize,
+}
+
+impl Statistics {
+    /// Counts the given primitives, including the ones nested in groups,
+    /// clips, translations, and caches.
+    pub fn count<T>(primitives: &[Primitive<T>]) -> Self {
+        let mut statistics = Self::default();
+
+        for primitive in primitives {
+            statistics.visit(primitive);
+        }
+
+        statistics
+    }
+
+    fn visit<T>(&mut self, primitive: &Primitive<T>) {
+        match primitive {
+            Primitive::Text { .. } | Primitive::Paragraph { .. } => {
+                self.texts += 1;
+            }
+            Primitive::Quad { .. } => {
+                self.quads += 1;
+            }
+            Primitive::Image { .. } | Primitive::Svg { .. } => {
+                self.images += 1;
+            }
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.visit(primitive);
+                }
+            }
+            Primitive::Clip { content, .. }
+            | Primitive::Backdrop { content, .. }
+            | Primitive::Opacity { content, .. }
+            | Primitive::Translate { content, .. } => {
+                self.visit(content);
+            }
+            Primitive::Cache { content } => {
+                self.visit(content);
+            }
+            Primitive::Custom(_) => {
+                self.meshes += 1;
+            }
+        }
+    }
+}
@@ -66,10 +66,16 @@ impl<T: Damage> Damage for Primitive<T> {
 
                 bounds.expand(1.5)
             }
+            Self::Quad { bounds, shadow, .. } if !shadow.is_invisible() => {
+                bounds.union(&shadow.bounds(*bounds)).expand(1.0)
+            }
             Self::Quad { bounds, .. }
             | Self::Image { bounds, .. }
             | Self::Svg { bounds, .. } => bounds.expand(1.0),
             Self::Clip { bounds, .. } => bounds.expand(1.0),
+            Self::Backdrop {
+                bounds, content, ..
+            } => bounds.expand(1.0).union(&content.bounds()),
             Self::Group { primitives } => primitives
                 .iter()
                 .map(Self::bounds)
@@ -80,6 +86,8 @@ impl<T: Damage> Damage for Primitive<T> {
                 translation,
                 content,
             } => content.bounds() + *translation,
+            Self::Scale { scale, content } => content.bounds() * *scale,
+            Self::Opacity { content, .. } => content.bounds(),
             Self::Cache { content } => content.bounds(),
             Self::Custom(custom) => custom.bounds(),
         }
@@ -134,6 +142,23 @@ fn regions<T: Damage>(a: &Primitive<T>, b: &Primitive<T>) -> Vec<Rectangle> {
                     .collect();
             }
         }
+        (
+            Primitive::Scale {
+                scale: scale_a,
+                content: content_a,
+            },
+            Primitive::Scale {
+                scale: scale_b,
+                content: content_b,
+            },
+        ) => {
+            if scale_a == scale_b {
+                return regions(content_a, content_b)
+                    .into_iter()
+                    .map(|r| r * *scale_a)
+                    .collect();
+            }
+        }
         (
             Primitive::Cache { content: content_a },
             Primitive::Cache { content: content_b },
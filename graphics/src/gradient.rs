@@ -126,6 +126,7 @@ impl Linear {
             colors,
             offsets,
             direction,
+            kind: 0,
         }
     }
 }
@@ -138,47 +139,62 @@ pub struct Packed {
     colors: [[u32; 2]; 8],
     // 8 offsets, 8x 16 bit floats packed into 4 u32s
     offsets: [u32; 4],
+    // The start and end points of a linear gradient, the center and radius
+    // of a radial one, or the center and angle of a conic one
     direction: [f32; 4],
+    // 0 = linear, 1 = radial, 2 = conic
+    kind: u32,
 }
 
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
-    match gradient {
-        core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
+    let mut colors = [[0u32; 2]; 8];
+    let mut offsets = [f16::from(0u8); 8];
 
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color))
-                        .components();
+    for (index, stop) in gradient.stops().iter().enumerate() {
+        let [r, g, b, a] =
+            color::pack(stop.map_or(Color::default(), |s| s.color))
+                .components();
 
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
+        colors[index] = [
+            pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
+            pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
+        ];
 
-                offsets[index] = stop
-                    .map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
+        offsets[index] =
+            stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+    }
 
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
+    let offsets = [
+        pack_f16s([offsets[0], offsets[1]]),
+        pack_f16s([offsets[2], offsets[3]]),
+        pack_f16s([offsets[4], offsets[5]]),
+        pack_f16s([offsets[6], offsets[7]]),
+    ];
 
+    let (direction, kind) = match gradient {
+        core::Gradient::Linear(linear) => {
             let (start, end) = linear.angle.to_distance(&bounds);
 
-            let direction = [start.x, start.y, end.x, end.y];
+            ([start.x, start.y, end.x, end.y], 0)
+        }
+        core::Gradient::Radial(radial) => {
+            let (center, radius) = radial.to_circle(&bounds);
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
+            ([center.x, center.y, radius, 0.0], 1)
         }
+        core::Gradient::Conic(conic) => {
+            let center = conic.to_center(&bounds);
+
+            ([center.x, center.y, conic.angle.0, 0.0], 2)
+        }
+    };
+
+    Packed {
+        colors,
+        offsets,
+        direction,
+        kind,
     }
 }
 
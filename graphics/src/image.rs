@@ -1,16 +1,45 @@
 //! Load and operate on images.
-use crate::core::image::{Data, Handle};
+pub mod cache;
+
+pub use cache::Cache;
+
+use crate::core::image::{Data, Handle, ToneMapping};
+use crate::core::Size;
 
 use bitflags::bitflags;
 
+use std::time::Duration;
+
 pub use ::image as image_rs;
 
 /// Tries to load an image by its [`Handle`].
+///
+/// The image is rotated and mirrored as its EXIF metadata says, unless the
+/// [`Handle`] ignores it.
+///
+/// High dynamic range images—like Radiance HDR or OpenEXR files—are tone
+/// mapped to 8-bit sRGB colors with the [`ToneMapping`] of the [`Handle`].
+/// Images with 16 bits per channel are converted to 8 bits.
 pub fn load(handle: &Handle) -> image_rs::ImageResult<image_rs::DynamicImage> {
+    Ok(tone_map(load_hdr(handle)?, handle.tone_mapping()))
+}
+
+/// Tries to load an image by its [`Handle`], just like [`load`], but keeping
+/// the linear colors of high dynamic range images untouched.
+///
+/// This is what a renderer drawing to a high dynamic range surface needs to
+/// pass the colors through.
+pub fn load_hdr(
+    handle: &Handle,
+) -> image_rs::ImageResult<image_rs::DynamicImage> {
     match handle.data() {
         Data::Path(path) => {
             let image = ::image::open(path)?;
 
+            if !handle.respects_exif() {
+                return Ok(image);
+            }
+
             let operation = std::fs::File::open(path)
                 .ok()
                 .map(std::io::BufReader::new)
@@ -19,8 +48,17 @@ pub fn load(handle: &Handle) -> image_rs::ImageResult<image_rs::DynamicImage> {
 
             Ok(operation.perform(image))
         }
-        Data::Bytes(bytes) => {
-            let image = ::image::load_from_memory(bytes)?;
+        Data::Bytes(bytes) | Data::Partial(bytes) => {
+            let image = if matches!(handle.data(), Data::Partial(_)) {
+                load_partial(bytes)?
+            } else {
+                ::image::load_from_memory(bytes)?
+            };
+
+            if !handle.respects_exif() {
+                return Ok(image);
+            }
+
             let operation =
                 Operation::from_exif(&mut std::io::Cursor::new(bytes))
                     .ok()
@@ -47,9 +85,175 @@ pub fn load(handle: &Handle) -> image_rs::ImageResult<image_rs::DynamicImage> {
                 ))
             }
         }
+        Data::Raw {
+            width,
+            height,
+            format,
+            planes,
+        } => {
+            if let Some(image) = format
+                .to_rgba(Size::new(*width, *height), planes)
+                .and_then(|pixels| {
+                    image_rs::ImageBuffer::from_vec(*width, *height, pixels)
+                })
+            {
+                Ok(image_rs::DynamicImage::ImageRgba8(image))
+            } else {
+                Err(image_rs::error::ImageError::Limits(
+                    image_rs::error::LimitError::from_kind(
+                        image_rs::error::LimitErrorKind::DimensionError,
+                    ),
+                ))
+            }
+        }
+        // Textures live in the GPU, so they cannot be decoded
+        Data::Texture { .. } => Err(image_rs::error::ImageError::Unsupported(
+            image_rs::error::UnsupportedError::from_format_and_kind(
+                image_rs::error::ImageFormatHint::Unknown,
+                image_rs::error::UnsupportedErrorKind::GenericFeature(
+                    String::from("GPU textures"),
+                ),
+            ),
+        )),
     }
 }
 
+/// Tries to load all the frames of an image by its [`Handle`], together with
+/// how long each of them is displayed.
+///
+/// Animated GIF, APNG, and WebP images produce all of their frames. Any
+/// other image produces a single frame that is displayed forever.
+pub fn load_frames(
+    handle: &Handle,
+) -> image_rs::ImageResult<Vec<(image_rs::RgbaImage, Duration)>> {
+    use image_rs::codecs::{gif, png, webp};
+    use image_rs::AnimationDecoder;
+    use std::io::Cursor;
+
+    let bytes = match handle.data() {
+        Data::Path(path) => std::fs::read(path)?,
+        Data::Bytes(bytes) => bytes.to_vec(),
+        Data::Partial(_)
+        | Data::Rgba { .. }
+        | Data::Texture { .. }
+        | Data::Raw { .. } => return single_frame(handle),
+    };
+
+    let frames = match image_rs::guess_format(&bytes)? {
+        image_rs::ImageFormat::Gif => {
+            gif::GifDecoder::new(Cursor::new(&bytes))?.into_frames()
+        }
+        image_rs::ImageFormat::Png => {
+            let decoder = png::PngDecoder::new(Cursor::new(&bytes))?;
+
+            if !decoder.is_apng() {
+                return single_frame(handle);
+            }
+
+            decoder.apng().into_frames()
+        }
+        image_rs::ImageFormat::WebP => {
+            let decoder = webp::WebPDecoder::new(Cursor::new(&bytes))?;
+
+            if !decoder.has_animation() {
+                return single_frame(handle);
+            }
+
+            decoder.into_frames()
+        }
+        _ => return single_frame(handle),
+    };
+
+    frames
+        .map(|frame| {
+            let frame = frame?;
+            let delay = Duration::from(frame.delay());
+
+            Ok((frame.into_buffer(), delay))
+        })
+        .collect()
+}
+
+/// Tries to decode the given bytes of an image that has only partially
+/// arrived.
+///
+/// JPEG images are decoded up to the data available, which displays the
+/// scans of progressive JPEG images that have arrived. Other formats fail
+/// to decode until they are complete.
+fn load_partial(bytes: &[u8]) -> image_rs::ImageResult<image_rs::DynamicImage> {
+    let format = image_rs::guess_format(bytes)?;
+
+    if format != image_rs::ImageFormat::Jpeg {
+        return image_rs::load_from_memory_with_format(bytes, format);
+    }
+
+    // Ending the data with an EOI marker makes the decoder finish the scan
+    // so far, instead of failing
+    let mut data = Vec::with_capacity(bytes.len() + 2);
+    data.extend_from_slice(bytes);
+
+    if !data.ends_with(&[0xFF, 0xD9]) {
+        data.extend_from_slice(&[0xFF, 0xD9]);
+    }
+
+    image_rs::load_from_memory_with_format(&data, format)
+}
+
+/// Returns true if the given image has high dynamic range; that is, linear
+/// colors which may be brighter than white.
+pub fn is_hdr(image: &image_rs::DynamicImage) -> bool {
+    matches!(
+        image,
+        image_rs::DynamicImage::ImageRgb32F(_)
+            | image_rs::DynamicImage::ImageRgba32F(_)
+    )
+}
+
+/// Tone maps the given image to 8-bit sRGB colors, if it has high dynamic
+/// range.
+pub fn tone_map(
+    image: image_rs::DynamicImage,
+    tone_mapping: ToneMapping,
+) -> image_rs::DynamicImage {
+    if !is_hdr(&image) {
+        return image;
+    }
+
+    let encode = |channel: f32| {
+        let channel = tone_mapping.apply(channel);
+
+        let srgb = if channel <= 0.003_130_8 {
+            channel * 12.92
+        } else {
+            1.055 * channel.powf(1.0 / 2.4) - 0.055
+        };
+
+        (srgb * 255.0).round() as u8
+    };
+
+    let hdr = image.into_rgba32f();
+
+    let sdr =
+        image_rs::RgbaImage::from_fn(hdr.width(), hdr.height(), |x, y| {
+            let [r, g, b, a] = hdr.get_pixel(x, y).0;
+
+            image_rs::Rgba([
+                encode(r),
+                encode(g),
+                encode(b),
+                (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+            ])
+        });
+
+    image_rs::DynamicImage::ImageRgba8(sdr)
+}
+
+fn single_frame(
+    handle: &Handle,
+) -> image_rs::ImageResult<Vec<(image_rs::RgbaImage, Duration)>> {
+    Ok(vec![(load(handle)?.into_rgba8(), Duration::MAX)])
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
@@ -78,8 +282,10 @@ impl Operation {
     fn perform(self, mut image: image::DynamicImage) -> image::DynamicImage {
         use image::imageops;
 
+        // Transposing is a clockwise rotation followed by a horizontal flip
         if self.contains(Self::FLIP_DIAGONALLY) {
-            imageops::flip_vertical_in_place(&mut image);
+            image = image.rotate90();
+            imageops::flip_horizontal_in_place(&mut image);
         }
 
         if self.contains(Self::ROTATE_180) {
@@ -0,0 +1,210 @@
+//! Cache decoded images within a memory budget.
+use crate::compositor::ImageCache;
+use crate::core::image::Handle;
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+
+/// The amount of times any image has been invalidated.
+static EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// The epoch in which every invalidated image was last invalidated.
+static INVALIDATED: Mutex<BTreeMap<u64, u64>> = Mutex::new(BTreeMap::new());
+
+/// Invalidates the image of the given [`Handle`] in every [`Cache`], so that
+/// it is loaded again the next time it is drawn.
+///
+/// This is useful when the file of an image changes on disk, since the
+/// [`Handle`] of a path stays the same.
+pub fn invalidate(handle: &Handle) {
+    let mut invalidated = INVALIDATED
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+
+    let epoch = EPOCH.fetch_add(1, atomic::Ordering::SeqCst) + 1;
+    let _ = invalidated.insert(handle.id(), epoch);
+}
+
+/// A cache of decoded images, keyed by [`Handle`].
+///
+/// Without a budget, the images that are not drawn during a frame are
+/// evicted at the end of it. With a budget, they are kept around until the
+/// memory of the cache exceeds the budget; then, the least recently drawn
+/// images are evicted first. The images drawn during the current frame are
+/// never evicted.
+#[derive(Debug)]
+pub struct Cache<T> {
+    entries: HashMap<u64, Entry<T>>,
+    budget: Option<usize>,
+    bytes: usize,
+    frame: u64,
+    epoch: u64,
+    evictions: usize,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    bytes: usize,
+    last_used: u64,
+    epoch: u64,
+}
+
+impl<T> Cache<T> {
+    /// Creates a new [`Cache`] with the given memory budget, in bytes.
+    pub fn new(budget: Option<usize>) -> Self {
+        Self {
+            entries: HashMap::new(),
+            budget,
+            bytes: 0,
+            frame: 0,
+            epoch: EPOCH.load(atomic::Ordering::SeqCst),
+            evictions: 0,
+        }
+    }
+
+    /// Returns true if the image of the given [`Handle`] is in the [`Cache`].
+    pub fn contains(&self, handle: &Handle) -> bool {
+        self.entries.contains_key(&handle.id())
+    }
+
+    /// Returns the image of the given [`Handle`], marking it as used during
+    /// the current frame.
+    pub fn get(&mut self, handle: &Handle) -> Option<&mut T> {
+        let entry = self.entries.get_mut(&handle.id())?;
+        entry.last_used = self.frame;
+
+        Some(&mut entry.value)
+    }
+
+    /// Stores the image of the given [`Handle`], which takes the given amount
+    /// of bytes, and marks it as used during the current frame.
+    pub fn insert(
+        &mut self,
+        handle: &Handle,
+        value: T,
+        bytes: usize,
+    ) -> &mut T {
+        let entry = Entry {
+            value,
+            bytes,
+            last_used: self.frame,
+            epoch: EPOCH.load(atomic::Ordering::SeqCst),
+        };
+
+        self.bytes += bytes;
+
+        if let Some(previous) = self.entries.insert(handle.id(), entry) {
+            self.bytes -= previous.bytes;
+        }
+
+        &mut self.entries.get_mut(&handle.id()).unwrap().value
+    }
+
+    /// Removes the image of the given [`Handle`] from the [`Cache`].
+    pub fn remove(&mut self, handle: &Handle) -> Option<T> {
+        let entry = self.entries.remove(&handle.id())?;
+        self.bytes -= entry.bytes;
+
+        Some(entry.value)
+    }
+
+    /// Ends the current frame, evicting the invalidated images and the ones
+    /// that do not fit in the budget.
+    ///
+    /// The given closure is called with every evicted image, so that its
+    /// resources can be released.
+    pub fn trim(&mut self, mut evict: impl FnMut(T)) {
+        let epoch = EPOCH.load(atomic::Ordering::SeqCst);
+
+        if epoch != self.epoch {
+            let invalidated = INVALIDATED
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+
+            let ids: Vec<u64> = self
+                .entries
+                .iter()
+                .filter(|(id, entry)| {
+                    matches!(
+                        invalidated.get(id),
+                        Some(invalidation) if *invalidation > entry.epoch
+                    )
+                })
+                .map(|(id, _)| *id)
+                .collect();
+
+            drop(invalidated);
+
+            for id in ids {
+                self.evict(id, &mut evict);
+            }
+
+            self.epoch = epoch;
+        }
+
+        let frame = self.frame;
+
+        match self.budget {
+            None => {
+                let ids: Vec<u64> = self
+                    .entries
+                    .iter()
+                    .filter(|(_, entry)| entry.last_used != frame)
+                    .map(|(id, _)| *id)
+                    .collect();
+
+                for id in ids {
+                    self.evict(id, &mut evict);
+                }
+            }
+            Some(budget) if self.bytes > budget => {
+                let mut unused: Vec<(u64, u64)> = self
+                    .entries
+                    .iter()
+                    .filter(|(_, entry)| entry.last_used != frame)
+                    .map(|(id, entry)| (entry.last_used, *id))
+                    .collect();
+
+                unused.sort_unstable();
+
+                for (_, id) in unused {
+                    if self.bytes <= budget {
+                        break;
+                    }
+
+                    self.evict(id, &mut evict);
+                }
+            }
+            Some(_) => {}
+        }
+
+        self.frame += 1;
+    }
+
+    /// Returns the memory usage of the [`Cache`].
+    pub fn usage(&self) -> ImageCache {
+        ImageCache {
+            images: self.entries.len(),
+            bytes: self.bytes,
+            budget: self.budget,
+            evictions: self.evictions,
+        }
+    }
+
+    fn evict(&mut self, id: u64, evict: &mut impl FnMut(T)) {
+        if let Some(entry) = self.entries.remove(&id) {
+            self.bytes -= entry.bytes;
+            self.evictions += 1;
+
+            evict(entry.value);
+        }
+    }
+}
+
+impl<T> Default for Cache<T> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
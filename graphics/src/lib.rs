@@ -29,6 +29,7 @@ pub mod damage;
 pub mod gradient;
 pub mod mesh;
 pub mod renderer;
+pub mod svg;
 pub mod text;
 
 #[cfg(feature = "geometry")]
@@ -3,7 +3,9 @@ use crate::core::alignment;
 use crate::core::image;
 use crate::core::svg;
 use crate::core::text;
-use crate::core::{Background, Color, Font, Pixels, Point, Rectangle, Vector};
+use crate::core::{
+    Background, Color, Font, Pixels, Point, Rectangle, Shadow, Vector,
+};
 use crate::text::paragraph;
 
 use std::sync::Arc;
@@ -53,11 +55,23 @@ pub enum Primitive<T> {
         border_width: f32,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow of the quad
+        shadow: Shadow,
     },
     /// An image primitive
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The filter method of the image
+        filter_method: image::FilterMethod,
+        /// The orientation of the image
+        orientation: image::Orientation,
+        /// The color effects of the image
+        effects: image::Effects,
+        /// The region of the image to draw, in pixels, or the whole image
+        ///
+        /// The region is cut before the orientation is applied.
+        region: Option<Rectangle>,
         /// The bounds of the image
         bounds: Rectangle,
     },
@@ -84,6 +98,24 @@ pub enum Primitive<T> {
         /// The content of the clip
         content: Box<Primitive<T>>,
     },
+    /// A primitive drawn on top of a blurred copy of what is behind it
+    Backdrop {
+        /// The bounds of the blurred area
+        bounds: Rectangle,
+        /// The border radii of the blurred area
+        border_radius: [f32; 4],
+        /// The radius of the blur, in logical pixels
+        radius: f32,
+        /// The content drawn on top of the blurred area
+        content: Box<Primitive<T>>,
+    },
+    /// A group of primitives composited together with some opacity
+    Opacity {
+        /// The opacity of the group, from `0.0` to `1.0`
+        opacity: f32,
+        /// The primitives of the group
+        content: Box<Primitive<T>>,
+    },
     /// A primitive that applies a translation
     Translate {
         /// The translation vector
@@ -92,6 +124,14 @@ pub enum Primitive<T> {
         /// The primitive to translate
         content: Box<Primitive<T>>,
     },
+    /// A primitive that applies a scale
+    Scale {
+        /// The scale factor
+        scale: f32,
+
+        /// The primitive to scale
+        content: Box<Primitive<T>>,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
@@ -125,4 +165,12 @@ impl<T> Primitive<T> {
             content: Box::new(self),
         }
     }
+
+    /// Creates a [`Primitive::Scale`].
+    pub fn scale(self, scale: f32) -> Self {
+        Self::Scale {
+            scale,
+            content: Box::new(self),
+        }
+    }
 }
@@ -1,12 +1,14 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
+use crate::compositor::Statistics;
 use crate::core;
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
 use crate::core::text::Text;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+    Background, BorderRadius, Color, Font, Padding, Pixels, Point, Rectangle,
+    Size, Vector,
 };
 use crate::text;
 use crate::Primitive;
@@ -59,6 +61,16 @@ impl<B: Backend, T> Renderer<B, T> {
         f(&mut self.backend, &self.primitives)
     }
 
+    /// Returns the [`Statistics`] of the primitives recorded by the
+    /// [`Renderer`].
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            atlas_occupancy: self.backend.atlas_occupancy(),
+            image_cache: self.backend.image_cache(),
+            ..Statistics::count(&self.primitives)
+        }
+    }
+
     /// Starts recording a new layer.
     pub fn start_layer(&mut self) -> Vec<Primitive<B::Primitive>> {
         std::mem::take(&mut self.primitives)
@@ -75,6 +87,48 @@ impl<B: Backend, T> Renderer<B, T> {
         self.primitives.push(Primitive::group(layer).clip(bounds));
     }
 
+    /// Starts recording the content of a backdrop blur.
+    pub fn start_backdrop(&mut self) -> Vec<Primitive<B::Primitive>> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    /// Ends the recording of the content of a backdrop blur.
+    pub fn end_backdrop(
+        &mut self,
+        primitives: Vec<Primitive<B::Primitive>>,
+        bounds: Rectangle,
+        border_radius: [f32; 4],
+        radius: f32,
+    ) {
+        let content = std::mem::replace(&mut self.primitives, primitives);
+
+        self.primitives.push(Primitive::Backdrop {
+            bounds,
+            border_radius,
+            radius,
+            content: Box::new(Primitive::group(content)),
+        });
+    }
+
+    /// Starts recording a group of primitives with some opacity.
+    pub fn start_opacity(&mut self) -> Vec<Primitive<B::Primitive>> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    /// Ends the recording of a group of primitives with some opacity.
+    pub fn end_opacity(
+        &mut self,
+        primitives: Vec<Primitive<B::Primitive>>,
+        opacity: f32,
+    ) {
+        let content = std::mem::replace(&mut self.primitives, primitives);
+
+        self.primitives.push(Primitive::Opacity {
+            opacity: opacity.clamp(0.0, 1.0),
+            content: Box::new(Primitive::group(content)),
+        });
+    }
+
     /// Starts recording a translation.
     pub fn start_translation(&mut self) -> Vec<Primitive<B::Primitive>> {
         std::mem::take(&mut self.primitives)
@@ -91,6 +145,22 @@ impl<B: Backend, T> Renderer<B, T> {
         self.primitives
             .push(Primitive::group(layer).translate(translation));
     }
+
+    /// Starts recording a scale.
+    pub fn start_scale(&mut self) -> Vec<Primitive<B::Primitive>> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    /// Ends the recording of a scale.
+    pub fn end_scale(
+        &mut self,
+        primitives: Vec<Primitive<B::Primitive>>,
+        scale: f32,
+    ) {
+        let layer = std::mem::replace(&mut self.primitives, primitives);
+
+        self.primitives.push(Primitive::group(layer).scale(scale));
+    }
 }
 
 impl<B: Backend, T> iced_core::Renderer for Renderer<B, T> {
@@ -116,6 +186,36 @@ impl<B: Backend, T> iced_core::Renderer for Renderer<B, T> {
         self.end_translation(current, translation);
     }
 
+    fn with_scale(&mut self, scale: f32, f: impl FnOnce(&mut Self)) {
+        let current = self.start_scale();
+
+        f(self);
+
+        self.end_scale(current, scale);
+    }
+
+    fn with_backdrop_blur(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        radius: f32,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current = self.start_backdrop();
+
+        f(self);
+
+        self.end_backdrop(current, bounds, border_radius.into(), radius);
+    }
+
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let current = self.start_opacity();
+
+        f(self);
+
+        self.end_opacity(current, opacity);
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -127,6 +227,7 @@ impl<B: Backend, T> iced_core::Renderer for Renderer<B, T> {
             border_radius: quad.border_radius.into(),
             border_width: quad.border_width,
             border_color: quad.border_color,
+            shadow: quad.shadow,
         });
     }
 
@@ -236,8 +337,39 @@ where
         self.backend().dimensions(handle)
     }
 
-    fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.primitives.push(Primitive::Image { handle, bounds });
+    fn draw(&mut self, image: image::Image) {
+        let image::Image {
+            handle,
+            filter_method,
+            orientation,
+            effects,
+            region,
+            bounds,
+        } = image;
+
+        self.primitives.push(Primitive::Image {
+            handle,
+            filter_method,
+            orientation,
+            effects,
+            region,
+            bounds,
+        });
+    }
+
+    fn draw_nine_slice(&mut self, image: image::Image, insets: Padding) {
+        let size = self.dimensions(&image.handle);
+
+        for (region, bounds) in image::nine_slice(size, insets, image.bounds) {
+            self.primitives.push(Primitive::Image {
+                handle: image.handle.clone(),
+                filter_method: image.filter_method,
+                orientation: image::Orientation::default(),
+                effects: image.effects,
+                region: Some(region),
+                bounds,
+            });
+        }
     }
 }
 
@@ -0,0 +1,139 @@
+//! Control the rasterization of vector graphics.
+//!
+//! Rasterizing an SVG is expensive, so renderers keep the rasters of the
+//! SVGs drawn during a frame. When a window is resized, every SVG needs to
+//! be rasterized again at its new size; which can cause hitches in
+//! applications with lots of icons.
+//!
+//! Renderers can round the sizes of the rasters up to a step—or bucket—so
+//! they are reused between similar sizes. Besides, an SVG can be rasterized
+//! ahead of time at any size with [`prerender`], and its rasters can be
+//! dropped with [`evict`].
+use crate::core::svg::Handle;
+use crate::core::{Color, Size};
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+
+/// The amount of times the rasters have been controlled.
+static EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// The rasters requested so far.
+static REQUESTS: Mutex<Requests> = Mutex::new(Requests {
+    prerendered: Vec::new(),
+    evicted: BTreeMap::new(),
+});
+
+#[derive(Debug)]
+struct Requests {
+    prerendered: Vec<Prerender>,
+    evicted: BTreeMap<u64, u64>,
+}
+
+fn requests() -> std::sync::MutexGuard<'static, Requests> {
+    REQUESTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// An SVG that is rasterized ahead of time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prerender {
+    /// The [`Handle`] of the SVG.
+    pub handle: Handle,
+    /// The size of the raster, in physical pixels.
+    pub size: Size<u32>,
+    /// The [`Color`] filter of the raster, if any.
+    pub color: Option<Color>,
+}
+
+/// Rasterizes the SVG of the given [`Handle`] at the given size—in physical
+/// pixels—and [`Color`] filter in every renderer, the next time it draws.
+///
+/// The raster is kept until the SVG is evicted, even if it is not drawn.
+pub fn prerender(handle: &Handle, size: Size<u32>, color: Option<Color>) {
+    let prerender = Prerender {
+        handle: handle.clone(),
+        size,
+        color,
+    };
+
+    let mut requests = requests();
+
+    if !requests.prerendered.contains(&prerender) {
+        requests.prerendered.push(prerender);
+        let _ = EPOCH.fetch_add(1, atomic::Ordering::SeqCst);
+    }
+}
+
+/// Drops every raster of the SVG of the given [`Handle`] in every renderer,
+/// including the ones produced by [`prerender`].
+///
+/// The SVG is parsed and rasterized again the next time it is drawn; so this
+/// is also useful when its file changes on disk.
+pub fn evict(handle: &Handle) {
+    let mut requests = requests();
+
+    let epoch = EPOCH.fetch_add(1, atomic::Ordering::SeqCst) + 1;
+
+    requests
+        .prerendered
+        .retain(|prerender| prerender.handle.id() != handle.id());
+
+    let _ = requests.evicted.insert(handle.id(), epoch);
+}
+
+/// Rounds the given size up to the given bucket, if any.
+pub fn bucket(size: Size<u32>, bucket: Option<u32>) -> Size<u32> {
+    match bucket {
+        Some(bucket) if bucket > 1 => Size::new(
+            (size.width + bucket - 1) / bucket * bucket,
+            (size.height + bucket - 1) / bucket * bucket,
+        ),
+        _ => size,
+    }
+}
+
+/// Keeps the cache of a renderer up to date with the calls to [`prerender`]
+/// and [`evict`].
+#[derive(Debug, Default)]
+pub struct Tracker {
+    epoch: u64,
+}
+
+/// The changes to a cache of SVG rasters since the last time.
+#[derive(Debug)]
+pub struct Changes {
+    /// The ids of the handles whose rasters must be dropped.
+    pub evicted: Vec<u64>,
+    /// The SVGs that must be rasterized and kept.
+    pub prerendered: Vec<Prerender>,
+}
+
+impl Tracker {
+    /// Returns the [`Changes`] since the last time, if any.
+    pub fn changes(&mut self) -> Option<Changes> {
+        let epoch = EPOCH.load(atomic::Ordering::SeqCst);
+
+        if epoch == self.epoch {
+            return None;
+        }
+
+        let requests = requests();
+
+        let evicted = requests
+            .evicted
+            .iter()
+            .filter(|(_, eviction)| **eviction > self.epoch)
+            .map(|(id, _)| *id)
+            .collect();
+
+        self.epoch = epoch;
+
+        Some(Changes {
+            evicted,
+            prerendered: requests.prerendered.clone(),
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::core::Color;
-use crate::graphics::compositor::{Information, SurfaceError};
+use crate::graphics::compositor::{Information, Statistics, SurfaceError};
 use crate::graphics::{Error, Viewport};
 use crate::{Renderer, Settings};
 
@@ -184,6 +184,14 @@ impl<Theme> crate::graphics::Compositor for Compositor<Theme> {
             ),
         }
     }
+
+    fn statistics(&self, renderer: &Self::Renderer) -> Statistics {
+        match renderer {
+            #[cfg(feature = "tiny_skia")]
+            Renderer::TinySkia(renderer) => renderer.statistics(),
+            Renderer::Wgpu(renderer) => renderer.statistics(),
+        }
+    }
 }
 
 enum Candidate {
@@ -225,7 +233,15 @@ impl Candidate {
             #[cfg(feature = "tiny_skia")]
             Self::TinySkia => {
                 let (compositor, backend) =
-                    iced_tiny_skia::window::compositor::new();
+                    iced_tiny_skia::window::compositor::new(
+                        iced_tiny_skia::Settings {
+                            default_font: settings.default_font,
+                            default_text_size: settings.default_text_size,
+                            image_cache_budget: settings.image_cache_budget,
+                            svg_size_bucket: settings.svg_size_bucket,
+                            svg_size_bucket: settings.svg_size_bucket,
+                        },
+                    );
 
                 Ok((
                     Compositor::TinySkia(compositor),
@@ -242,6 +258,7 @@ impl Candidate {
                         default_font: settings.default_font,
                         default_text_size: settings.default_text_size,
                         antialiasing: settings.antialiasing,
+                        image_cache_budget: settings.image_cache_budget,
                         ..iced_wgpu::Settings::from_env()
                     },
                     _compatible_window,
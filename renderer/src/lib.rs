@@ -20,7 +20,8 @@ pub use geometry::Geometry;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+    Background, BorderRadius, Color, Font, Pixels, Point, Rectangle, Size,
+    Vector,
 };
 use crate::graphics::text::Paragraph;
 use crate::graphics::Mesh;
@@ -131,6 +132,117 @@ impl<T> core::Renderer for Renderer<T> {
         }
     }
 
+    fn with_scale(&mut self, scale: f32, f: impl FnOnce(&mut Self)) {
+        match self {
+            #[cfg(feature = "tiny_skia")]
+            Self::TinySkia(renderer) => {
+                let primitives = renderer.start_scale();
+
+                f(self);
+
+                match self {
+                    Self::TinySkia(renderer) => {
+                        renderer.end_scale(primitives, scale);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            Self::Wgpu(renderer) => {
+                let primitives = renderer.start_scale();
+
+                f(self);
+
+                match self {
+                    Self::Wgpu(renderer) => {
+                        renderer.end_scale(primitives, scale);
+                    }
+                    #[cfg(feature = "tiny_skia")]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    fn with_backdrop_blur(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        radius: f32,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let border_radius = border_radius.into();
+
+        match self {
+            #[cfg(feature = "tiny_skia")]
+            Self::TinySkia(renderer) => {
+                let primitives = renderer.start_backdrop();
+
+                f(self);
+
+                match self {
+                    Self::TinySkia(renderer) => {
+                        renderer.end_backdrop(
+                            primitives,
+                            bounds,
+                            border_radius,
+                            radius,
+                        );
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            Self::Wgpu(renderer) => {
+                let primitives = renderer.start_backdrop();
+
+                f(self);
+
+                match self {
+                    Self::Wgpu(renderer) => {
+                        renderer.end_backdrop(
+                            primitives,
+                            bounds,
+                            border_radius,
+                            radius,
+                        );
+                    }
+                    #[cfg(feature = "tiny_skia")]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        match self {
+            #[cfg(feature = "tiny_skia")]
+            Self::TinySkia(renderer) => {
+                let primitives = renderer.start_opacity();
+
+                f(self);
+
+                match self {
+                    Self::TinySkia(renderer) => {
+                        renderer.end_opacity(primitives, opacity);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            Self::Wgpu(renderer) => {
+                let primitives = renderer.start_opacity();
+
+                f(self);
+
+                match self {
+                    Self::Wgpu(renderer) => {
+                        renderer.end_opacity(primitives, opacity);
+                    }
+                    #[cfg(feature = "tiny_skia")]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -211,8 +323,16 @@ impl<T> crate::core::image::Renderer for Renderer<T> {
         delegate!(self, renderer, renderer.dimensions(handle))
     }
 
-    fn draw(&mut self, handle: crate::core::image::Handle, bounds: Rectangle) {
-        delegate!(self, renderer, renderer.draw(handle, bounds));
+    fn draw(&mut self, image: crate::core::image::Image) {
+        delegate!(self, renderer, renderer.draw(image));
+    }
+
+    fn draw_nine_slice(
+        &mut self,
+        image: crate::core::image::Image,
+        insets: crate::core::Padding,
+    ) {
+        delegate!(self, renderer, renderer.draw_nine_slice(image, insets));
     }
 }
 
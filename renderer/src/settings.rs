@@ -16,6 +16,16 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The memory budget of the cache of raster images, in bytes.
+    ///
+    /// By default, it is `None`.
+    pub image_cache_budget: Option<usize>,
+
+    /// The size step of the rasters of vector graphics, in physical pixels.
+    ///
+    /// By default, it is `None`.
+    pub svg_size_bucket: Option<u32>,
 }
 
 impl Default for Settings {
@@ -24,6 +34,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_cache_budget: None,
+            svg_size_bucket: None,
         }
     }
 }
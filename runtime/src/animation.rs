@@ -0,0 +1,475 @@
+//! Animate values smoothly over time.
+//!
+//! An [`Animation`] tracks a value moving toward a target. Whenever the
+//! target changes, the value starts to travel from wherever it currently is,
+//! following an [`Easing`] curve during a fixed [`Duration`].
+//!
+//! [`Keyframes`] describe a fixed timeline of values instead, which can be
+//! played once or repeated forever.
+//!
+//! A [`Spring`] follows its target using physics rather than a fixed curve,
+//! which feels natural for gestures like releasing a drag.
+mod spring;
+
+pub use spring::Spring;
+
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::{Color, Point, Shell, Size, Vector};
+
+/// A curve describing how an animation progresses over time.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly and accelerates.
+    EaseIn,
+    /// Starts quickly and decelerates.
+    #[default]
+    EaseOut,
+    /// Accelerates until halfway, then decelerates.
+    EaseInOut,
+    /// A steeper [`Easing::EaseIn`].
+    EaseInCubic,
+    /// A steeper [`Easing::EaseOut`].
+    EaseOutCubic,
+    /// A steeper [`Easing::EaseInOut`].
+    EaseInOutCubic,
+    /// A custom curve.
+    ///
+    /// It receives the progress of the animation in the `0.0..=1.0` range and
+    /// must return `0.0` at the start and `1.0` at the end.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given progress, in the
+    /// `0.0..=1.0` range.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// A value that can be interpolated between two states.
+pub trait Interpolate: Clone {
+    /// Returns the value found at `t` between `self` and `other`, where
+    /// `0.0` is `self` and `1.0` is `other`.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, t),
+            g: self.g.interpolate(&other.g, t),
+            b: self.b.interpolate(&other.b, t),
+            a: self.a.interpolate(&other.a, t),
+        }
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
+
+/// A value that can be simulated as a point in space.
+///
+/// It is necessary for a value to be driven by a [`Spring`].
+pub trait Spatial: Interpolate {
+    /// Returns the zero value.
+    fn zero() -> Self;
+
+    /// Returns `self + other * scale`.
+    fn add_scaled(&self, other: &Self, scale: f32) -> Self;
+
+    /// Returns the magnitude of the value, seen as a vector.
+    fn magnitude(&self) -> f32;
+}
+
+impl Spatial for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn add_scaled(&self, other: &Self, scale: f32) -> Self {
+        self + other * scale
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.abs()
+    }
+}
+
+impl Spatial for Color {
+    fn zero() -> Self {
+        Color::TRANSPARENT
+    }
+
+    fn add_scaled(&self, other: &Self, scale: f32) -> Self {
+        Color {
+            r: self.r.add_scaled(&other.r, scale),
+            g: self.g.add_scaled(&other.g, scale),
+            b: self.b.add_scaled(&other.b, scale),
+            a: self.a.add_scaled(&other.a, scale),
+        }
+    }
+
+    fn magnitude(&self) -> f32 {
+        (self.r * self.r + self.g * self.g + self.b * self.b + self.a * self.a)
+            .sqrt()
+    }
+}
+
+impl Spatial for Point {
+    fn zero() -> Self {
+        Point::ORIGIN
+    }
+
+    fn add_scaled(&self, other: &Self, scale: f32) -> Self {
+        Point::new(
+            self.x.add_scaled(&other.x, scale),
+            self.y.add_scaled(&other.y, scale),
+        )
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.distance(Point::ORIGIN)
+    }
+}
+
+impl Spatial for Vector {
+    fn zero() -> Self {
+        Vector::ZERO
+    }
+
+    fn add_scaled(&self, other: &Self, scale: f32) -> Self {
+        Vector::new(
+            self.x.add_scaled(&other.x, scale),
+            self.y.add_scaled(&other.y, scale),
+        )
+    }
+
+    fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+impl Spatial for Size {
+    fn zero() -> Self {
+        Size::ZERO
+    }
+
+    fn add_scaled(&self, other: &Self, scale: f32) -> Self {
+        Size::new(
+            self.width.add_scaled(&other.width, scale),
+            self.height.add_scaled(&other.height, scale),
+        )
+    }
+
+    fn magnitude(&self) -> f32 {
+        (self.width * self.width + self.height * self.height).sqrt()
+    }
+}
+
+/// A value animated toward a target.
+///
+/// # Example
+/// ```no_run
+/// use iced_runtime::animation::{Animation, Easing};
+/// use iced_runtime::core::time::{Duration, Instant};
+///
+/// let mut opacity = Animation::new(0.0)
+///     .duration(Duration::from_millis(300))
+///     .easing(Easing::EaseInOut);
+///
+/// opacity.go_to(1.0, Instant::now());
+///
+/// // Later, while drawing...
+/// let current = opacity.value(Instant::now());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate,
+{
+    /// The default [`Duration`] of an [`Animation`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            duration: Self::DEFAULT_DURATION,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Duration`] of the [`Animation`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] of the [`Animation`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Starts animating toward the given target.
+    ///
+    /// The animation begins from the value found at `now`, so changing the
+    /// target mid-flight does not make the value jump.
+    pub fn go_to(&mut self, target: T, now: Instant) {
+        self.from = self.value(now);
+        self.to = target;
+        self.started_at = Some(now);
+    }
+
+    /// Moves the [`Animation`] to the given value immediately.
+    pub fn snap_to(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.started_at = None;
+    }
+
+    /// Returns the target of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns the progress of the [`Animation`] at `now`, in the
+    /// `0.0..=1.0` range and before applying any [`Easing`].
+    pub fn progress(&self, now: Instant) -> f32 {
+        let Some(started_at) = self.started_at else {
+            return 1.0;
+        };
+
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        let elapsed = now.saturating_duration_since(started_at);
+
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns the value of the [`Animation`] at `now`.
+    pub fn value(&self, now: Instant) -> T {
+        let progress = self.progress(now);
+
+        if progress >= 1.0 {
+            return self.to.clone();
+        }
+
+        self.from.interpolate(&self.to, self.easing.apply(progress))
+    }
+
+    /// Returns whether the [`Animation`] is still in progress at `now`.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.progress(now) < 1.0
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the
+    /// [`Animation`] going, if it is still in progress at `now`.
+    pub fn redraw_request(
+        &self,
+        now: Instant,
+    ) -> Option<window::RedrawRequest> {
+        self.is_animating(now)
+            .then_some(window::RedrawRequest::NextFrame)
+    }
+
+    /// Requests a redraw to the [`Shell`] if the [`Animation`] is still in
+    /// progress at `now`.
+    ///
+    /// Widgets can call this when handling a
+    /// [`window::Event::RedrawRequested`] event to keep their animations
+    /// running until they settle.
+    pub fn request_redraw<Message>(
+        &self,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(request) = self.redraw_request(now) {
+            shell.request_redraw(request);
+        }
+    }
+}
+
+/// A timeline of values played from a starting [`Instant`].
+#[derive(Debug, Clone)]
+pub struct Keyframes<T> {
+    initial: T,
+    frames: Vec<Frame<T>>,
+    repeat: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Frame<T> {
+    value: T,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T> Keyframes<T>
+where
+    T: Interpolate,
+{
+    /// Creates new [`Keyframes`] starting at the given value.
+    pub fn new(initial: T) -> Self {
+        Self {
+            initial,
+            frames: Vec::new(),
+            repeat: false,
+        }
+    }
+
+    /// Adds a frame reaching the given value after the given [`Duration`],
+    /// counting from the previous frame.
+    pub fn then(
+        mut self,
+        value: T,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        self.frames.push(Frame {
+            value,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Sets whether the [`Keyframes`] start over once they reach the end.
+    pub fn repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns the total [`Duration`] of a single run of the [`Keyframes`].
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+
+    /// Returns the value of the [`Keyframes`] at `now`, when they started
+    /// playing at `started_at`.
+    pub fn value(&self, started_at: Instant, now: Instant) -> T {
+        let total = self.duration();
+        let mut elapsed = now.saturating_duration_since(started_at);
+
+        if total.is_zero() {
+            return self
+                .frames
+                .last()
+                .map(|frame| frame.value.clone())
+                .unwrap_or_else(|| self.initial.clone());
+        }
+
+        if self.repeat {
+            elapsed = Duration::from_secs_f64(
+                elapsed.as_secs_f64() % total.as_secs_f64(),
+            );
+        }
+
+        let mut from = &self.initial;
+
+        for frame in &self.frames {
+            if elapsed < frame.duration {
+                let progress =
+                    elapsed.as_secs_f32() / frame.duration.as_secs_f32();
+
+                return from
+                    .interpolate(&frame.value, frame.easing.apply(progress));
+            }
+
+            elapsed -= frame.duration;
+            from = &frame.value;
+        }
+
+        from.clone()
+    }
+
+    /// Returns whether the [`Keyframes`] are still playing at `now`, when
+    /// they started playing at `started_at`.
+    pub fn is_animating(&self, started_at: Instant, now: Instant) -> bool {
+        let total = self.duration();
+
+        !total.is_zero()
+            && (self.repeat
+                || now.saturating_duration_since(started_at) < total)
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the
+    /// [`Keyframes`] going, if they are still playing at `now`.
+    pub fn redraw_request(
+        &self,
+        started_at: Instant,
+        now: Instant,
+    ) -> Option<window::RedrawRequest> {
+        self.is_animating(started_at, now)
+            .then_some(window::RedrawRequest::NextFrame)
+    }
+}
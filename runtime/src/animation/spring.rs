@@ -0,0 +1,198 @@
+use crate::animation::Spatial;
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::Shell;
+
+/// The longest step of time simulated at once.
+const MAX_STEP: Duration = Duration::from_micros(4_000);
+
+/// The longest gap between two ticks that is simulated.
+///
+/// Anything longer (e.g. the window was hidden) is treated as this long,
+/// so a stale [`Spring`] does not block the runtime catching up.
+const MAX_ELAPSED: Duration = Duration::from_millis(250);
+
+/// A value following its target with spring physics.
+///
+/// Unlike an [`Animation`], a [`Spring`] has no fixed duration. It keeps
+/// its velocity when the target changes, which makes it a good fit for
+/// drag releases, overscroll, and layout transitions.
+///
+/// A [`Spring`] must be ticked with [`Spring::tick`] (e.g. on every
+/// [`window::Event::RedrawRequested`]) to advance its simulation.
+///
+/// [`Animation`]: crate::animation::Animation
+#[derive(Debug, Clone)]
+pub struct Spring<T> {
+    position: T,
+    velocity: T,
+    target: T,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    precision: f32,
+    last_tick: Option<Instant>,
+    is_resting: bool,
+}
+
+impl<T> Spring<T>
+where
+    T: Spatial,
+{
+    /// The default stiffness of a [`Spring`].
+    pub const DEFAULT_STIFFNESS: f32 = 170.0;
+
+    /// The default damping of a [`Spring`].
+    pub const DEFAULT_DAMPING: f32 = 26.0;
+
+    /// The default mass of a [`Spring`].
+    pub const DEFAULT_MASS: f32 = 1.0;
+
+    /// Creates a new [`Spring`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            position: value.clone(),
+            velocity: T::zero(),
+            target: value,
+            stiffness: Self::DEFAULT_STIFFNESS,
+            damping: Self::DEFAULT_DAMPING,
+            mass: Self::DEFAULT_MASS,
+            precision: 0.001,
+            last_tick: None,
+            is_resting: true,
+        }
+    }
+
+    /// Sets the stiffness of the [`Spring`].
+    ///
+    /// A stiffer spring pulls harder toward its target.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness.max(0.0);
+        self
+    }
+
+    /// Sets the damping of the [`Spring`].
+    ///
+    /// Lower damping makes the spring oscillate around its target.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping.max(0.0);
+        self
+    }
+
+    /// Sets the mass of the [`Spring`].
+    ///
+    /// A heavier spring is slower to react and to settle.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(f32::EPSILON);
+        self
+    }
+
+    /// Sets the distance and speed under which the [`Spring`] is
+    /// considered at rest.
+    pub fn precision(mut self, precision: f32) -> Self {
+        self.precision = precision.abs();
+        self
+    }
+
+    /// Starts moving toward the given target, keeping the current velocity.
+    pub fn go_to(&mut self, target: T, now: Instant) {
+        self.target = target;
+        self.wake_up(now);
+    }
+
+    /// Sets the velocity of the [`Spring`], in units per second.
+    ///
+    /// This is useful to carry the momentum of a gesture (e.g. releasing a
+    /// drag) into the [`Spring`].
+    pub fn fling(&mut self, velocity: T, now: Instant) {
+        self.velocity = velocity;
+        self.wake_up(now);
+    }
+
+    /// Moves the [`Spring`] to the given value immediately, at rest.
+    pub fn snap_to(&mut self, value: T) {
+        self.position = value.clone();
+        self.velocity = T::zero();
+        self.target = value;
+        self.last_tick = None;
+        self.is_resting = true;
+    }
+
+    /// Advances the simulation of the [`Spring`] up to `now`.
+    pub fn tick(&mut self, now: Instant) {
+        if self.is_resting {
+            return;
+        }
+
+        let last_tick = self.last_tick.unwrap_or(now);
+        let mut remaining =
+            now.saturating_duration_since(last_tick).min(MAX_ELAPSED);
+
+        self.last_tick = Some(now);
+
+        while !remaining.is_zero() {
+            let step = remaining.min(MAX_STEP);
+            let dt = step.as_secs_f32();
+
+            let displacement = self.position.add_scaled(&self.target, -1.0);
+
+            self.velocity = self
+                .velocity
+                .add_scaled(&displacement, -self.stiffness / self.mass * dt)
+                .add_scaled(&self.velocity, -self.damping / self.mass * dt);
+
+            self.position = self.position.add_scaled(&self.velocity, dt);
+
+            remaining -= step;
+        }
+
+        let displacement = self.position.add_scaled(&self.target, -1.0);
+
+        if displacement.magnitude() < self.precision
+            && self.velocity.magnitude() < self.precision
+        {
+            self.snap_to(self.target.clone());
+        }
+    }
+
+    /// Returns the current value of the [`Spring`].
+    pub fn value(&self) -> &T {
+        &self.position
+    }
+
+    /// Returns the current velocity of the [`Spring`], in units per second.
+    pub fn velocity(&self) -> &T {
+        &self.velocity
+    }
+
+    /// Returns the target of the [`Spring`].
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns whether the [`Spring`] is still moving.
+    pub fn is_animating(&self) -> bool {
+        !self.is_resting
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the [`Spring`]
+    /// going, if it is still moving.
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.is_animating()
+            .then_some(window::RedrawRequest::NextFrame)
+    }
+
+    /// Requests a redraw to the [`Shell`] if the [`Spring`] is still moving.
+    pub fn request_redraw<Message>(&self, shell: &mut Shell<'_, Message>) {
+        if let Some(request) = self.redraw_request() {
+            shell.request_redraw(request);
+        }
+    }
+
+    fn wake_up(&mut self, now: Instant) {
+        if self.is_resting {
+            self.last_tick = Some(now);
+            self.is_resting = false;
+        }
+    }
+}
@@ -1,8 +1,10 @@
 //! Access the clipboard.
 use crate::command::{self, Command};
+use crate::core::image;
 use crate::futures::MaybeSend;
 
 use std::fmt;
+use std::path::PathBuf;
 
 /// A clipboard action to be performed by some [`Command`].
 ///
@@ -13,6 +15,30 @@ pub enum Action<T> {
 
     /// Write the given contents to the clipboard.
     Write(String),
+
+    /// Read an image from the clipboard and produce `T` with the result.
+    ReadImage(Box<dyn Fn(Option<image::Handle>) -> T>),
+
+    /// Write the given image to the clipboard.
+    WriteImage(image::Handle),
+
+    /// Read HTML from the clipboard and produce `T` with the result.
+    ReadHtml(Box<dyn Fn(Option<String>) -> T>),
+
+    /// Write the given HTML to the clipboard.
+    WriteHtml {
+        /// The HTML to write.
+        html: String,
+        /// The plain text alternative of the HTML, if any.
+        alt_text: Option<String>,
+    },
+
+    /// Read a list of files from the clipboard and produce `T` with the
+    /// result.
+    ReadFiles(Box<dyn Fn(Option<Vec<PathBuf>>) -> T>),
+
+    /// Write the given list of files to the clipboard.
+    WriteFiles(Vec<PathBuf>),
 }
 
 impl<T> Action<T> {
@@ -27,6 +53,18 @@ impl<T> Action<T> {
         match self {
             Self::Read(o) => Action::Read(Box::new(move |s| f(o(s)))),
             Self::Write(content) => Action::Write(content),
+            Self::ReadImage(o) => {
+                Action::ReadImage(Box::new(move |image| f(o(image))))
+            }
+            Self::WriteImage(image) => Action::WriteImage(image),
+            Self::ReadHtml(o) => Action::ReadHtml(Box::new(move |s| f(o(s)))),
+            Self::WriteHtml { html, alt_text } => {
+                Action::WriteHtml { html, alt_text }
+            }
+            Self::ReadFiles(o) => {
+                Action::ReadFiles(Box::new(move |files| f(o(files))))
+            }
+            Self::WriteFiles(files) => Action::WriteFiles(files),
         }
     }
 }
@@ -36,6 +74,14 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Read(_) => write!(f, "Action::Read"),
             Self::Write(_) => write!(f, "Action::Write"),
+            Self::ReadImage(_) => write!(f, "Action::ReadImage"),
+            Self::WriteImage(_) => write!(f, "Action::WriteImage"),
+            Self::ReadHtml(_) => write!(f, "Action::ReadHtml"),
+            Self::WriteHtml { .. } => write!(f, "Action::WriteHtml"),
+            Self::ReadFiles(_) => write!(f, "Action::ReadFiles"),
+            Self::WriteFiles(files) => {
+                write!(f, "Action::WriteFiles({files:?})")
+            }
         }
     }
 }
@@ -51,3 +97,56 @@ pub fn read<Message>(
 pub fn write<Message>(contents: String) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::Write(contents)))
 }
+
+/// Read the current image of the clipboard.
+///
+/// The image is produced as an [`image::Handle`] of RGBA pixels.
+pub fn read_image<Message>(
+    f: impl Fn(Option<image::Handle>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadImage(Box::new(f))))
+}
+
+/// Write the given image to the clipboard.
+///
+/// Encoded images, like PNG bytes, are decoded first.
+pub fn write_image<Message>(
+    image: impl Into<image::Handle>,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteImage(
+        image.into(),
+    )))
+}
+
+/// Read the current HTML contents of the clipboard.
+pub fn read_html<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadHtml(Box::new(f))))
+}
+
+/// Write the given HTML to the clipboard, along with an optional plain
+/// text alternative for applications that do not support HTML.
+pub fn write_html<Message>(
+    html: String,
+    alt_text: Option<String>,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteHtml {
+        html,
+        alt_text,
+    }))
+}
+
+/// Read the list of files in the clipboard, like the ones copied in a file
+/// manager.
+pub fn read_files<Message>(
+    f: impl Fn(Option<Vec<PathBuf>>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadFiles(Box::new(f))))
+}
+
+/// Write the given list of files to the clipboard, so they can be pasted
+/// in a file manager.
+pub fn write_files<Message>(files: Vec<PathBuf>) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteFiles(files)))
+}
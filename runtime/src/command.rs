@@ -4,6 +4,7 @@ mod action;
 pub use action::Action;
 
 use crate::core::widget;
+use crate::futures::executor::Priority;
 use crate::futures::MaybeSend;
 
 use std::fmt;
@@ -48,6 +49,28 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Sets the [`Priority`] of the futures of the [`Command`].
+    ///
+    /// Heavy background work—like indexing files or generating
+    /// thumbnails—can use [`Priority::Low`] to stop starving the futures the
+    /// user is waiting for.
+    pub fn with_priority(self, priority: Priority) -> Self {
+        let prioritize = |action| match action {
+            Action::Future(future) | Action::Prioritized { future, .. } => {
+                Action::Prioritized { future, priority }
+            }
+            action => action,
+        };
+
+        match self.0 {
+            Internal::None => Command::none(),
+            Internal::Single(action) => Command::single(prioritize(action)),
+            Internal::Batch(batch) => Command(Internal::Batch(
+                batch.into_iter().map(prioritize).collect(),
+            )),
+        }
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
@@ -1,9 +1,12 @@
 use crate::clipboard;
 use crate::core::widget;
+use crate::dnd;
 use crate::font;
+use crate::notification;
 use crate::system;
 use crate::window;
 
+use iced_futures::executor::Priority;
 use iced_futures::MaybeSend;
 
 use std::borrow::Cow;
@@ -18,12 +21,29 @@ pub enum Action<T> {
     /// [`Future`]: iced_futures::BoxFuture
     Future(iced_futures::BoxFuture<T>),
 
+    /// Run a [`Future`] to completion with the given [`Priority`].
+    ///
+    /// [`Future`]: iced_futures::BoxFuture
+    Prioritized {
+        /// The future to run.
+        future: iced_futures::BoxFuture<T>,
+
+        /// The [`Priority`] of the future.
+        priority: Priority,
+    },
+
     /// Run a clipboard action.
     Clipboard(clipboard::Action<T>),
 
+    /// Run a drag and drop action.
+    Dnd(dnd::Action<T>),
+
     /// Run a window action.
     Window(window::Action<T>),
 
+    /// Run a notification action.
+    Notification(notification::Action<T>),
+
     /// Run a system action.
     System(system::Action<T>),
 
@@ -56,8 +76,14 @@ impl<T> Action<T> {
 
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
+            Self::Prioritized { future, priority } => Action::Prioritized {
+                future: Box::pin(future.map(f)),
+                priority,
+            },
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
+            Self::Dnd(action) => Action::Dnd(action.map(f)),
             Self::Window(window) => Action::Window(window.map(f)),
+            Self::Notification(action) => Action::Notification(action.map(f)),
             Self::System(system) => Action::System(system.map(f)),
             Self::Widget(operation) => {
                 Action::Widget(Box::new(widget::operation::map(operation, f)))
@@ -74,10 +100,17 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Future(_) => write!(f, "Action::Future"),
+            Self::Prioritized { priority, .. } => {
+                write!(f, "Action::Prioritized({priority:?})")
+            }
             Self::Clipboard(action) => {
                 write!(f, "Action::Clipboard({action:?})")
             }
+            Self::Dnd(action) => write!(f, "Action::Dnd({action:?})"),
             Self::Window(action) => write!(f, "Action::Window({action:?})"),
+            Self::Notification(action) => {
+                write!(f, "Action::Notification({action:?})")
+            }
             Self::System(action) => write!(f, "Action::System({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
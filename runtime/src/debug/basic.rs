@@ -102,6 +102,10 @@ impl Debug {
         self.layout_durations.push(self.layout_start.elapsed());
     }
 
+    pub fn layout_duration(&self) -> time::Duration {
+        self.layout_durations.average()
+    }
+
     pub fn event_processing_started(&mut self) {
         self.event_start = time::Instant::now();
     }
@@ -1,4 +1,6 @@
 #![allow(missing_docs)]
+use crate::core::time;
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -23,6 +25,10 @@ impl Debug {
 
     pub fn layout_finished(&mut self) {}
 
+    pub fn layout_duration(&self) -> time::Duration {
+        time::Duration::ZERO
+    }
+
     pub fn event_processing_started(&mut self) {}
 
     pub fn event_processing_finished(&mut self) {}
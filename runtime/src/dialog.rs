@@ -0,0 +1,152 @@
+//! Show native file dialogs.
+//!
+//! The dialogs are shown without blocking the application, and their result
+//! is produced as a message once the user closes them.
+use crate::command::Command;
+use crate::futures::MaybeSend;
+
+use std::path::PathBuf;
+
+/// The settings of a native file dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileDialog {
+    /// The title of the dialog, if any.
+    pub title: Option<String>,
+    /// The directory initially shown by the dialog, if any.
+    pub directory: Option<PathBuf>,
+    /// The file name initially filled in the dialog, if any.
+    pub file_name: Option<String>,
+    /// The [`Filter`]s the user can choose from.
+    pub filters: Vec<Filter>,
+}
+
+/// A named set of file extensions shown by a [`FileDialog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// The name of the [`Filter`], like "Images".
+    pub name: String,
+    /// The extensions allowed by the [`Filter`], without a leading dot.
+    pub extensions: Vec<String>,
+}
+
+impl FileDialog {
+    /// Creates a new [`FileDialog`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the [`FileDialog`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the directory initially shown by the [`FileDialog`].
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the file name initially filled in the [`FileDialog`].
+    ///
+    /// It is mostly useful when saving a file.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Adds a [`Filter`] with the given name and extensions to the
+    /// [`FileDialog`].
+    pub fn filter(
+        mut self,
+        name: impl Into<String>,
+        extensions: &[impl AsRef<str>],
+    ) -> Self {
+        self.filters.push(Filter {
+            name: name.into(),
+            extensions: extensions
+                .iter()
+                .map(|extension| extension.as_ref().to_owned())
+                .collect(),
+        });
+        self
+    }
+
+    fn into_rfd(self) -> rfd::AsyncFileDialog {
+        let mut dialog = rfd::AsyncFileDialog::new();
+
+        if let Some(title) = &self.title {
+            dialog = dialog.set_title(title);
+        }
+
+        if let Some(directory) = &self.directory {
+            dialog = dialog.set_directory(directory);
+        }
+
+        if let Some(file_name) = &self.file_name {
+            dialog = dialog.set_file_name(file_name);
+        }
+
+        for filter in &self.filters {
+            dialog =
+                dialog.add_filter(&filter.name, filter.extensions.as_slice());
+        }
+
+        dialog
+    }
+}
+
+/// Asks the user to pick a file to open, producing a message with its path.
+///
+/// The path is `None` if the dialog was cancelled.
+pub fn open_file<Message>(
+    dialog: FileDialog,
+    f: impl FnOnce(Option<PathBuf>) -> Message + 'static + MaybeSend,
+) -> Command<Message> {
+    Command::perform(dialog.into_rfd().pick_file(), move |file| {
+        f(file.map(|file| file.path().to_path_buf()))
+    })
+}
+
+/// Asks the user to pick some files to open, producing a message with their
+/// paths.
+///
+/// The paths are `None` if the dialog was cancelled.
+pub fn open_files<Message>(
+    dialog: FileDialog,
+    f: impl FnOnce(Option<Vec<PathBuf>>) -> Message + 'static + MaybeSend,
+) -> Command<Message> {
+    Command::perform(dialog.into_rfd().pick_files(), move |files| {
+        f(files.map(|files| {
+            files
+                .into_iter()
+                .map(|file| file.path().to_path_buf())
+                .collect()
+        }))
+    })
+}
+
+/// Asks the user to pick a folder, producing a message with its path.
+///
+/// The path is `None` if the dialog was cancelled.
+pub fn pick_folder<Message>(
+    dialog: FileDialog,
+    f: impl FnOnce(Option<PathBuf>) -> Message + 'static + MaybeSend,
+) -> Command<Message> {
+    Command::perform(dialog.into_rfd().pick_folder(), move |folder| {
+        f(folder.map(|folder| folder.path().to_path_buf()))
+    })
+}
+
+/// Asks the user where to save a file, producing a message with its path.
+///
+/// The path is `None` if the dialog was cancelled. The file itself is not
+/// created.
+pub fn save_file<Message>(
+    dialog: FileDialog,
+    f: impl FnOnce(Option<PathBuf>) -> Message + 'static + MaybeSend,
+) -> Command<Message> {
+    Command::perform(dialog.into_rfd().save_file(), move |file| {
+        f(file.map(|file| file.path().to_path_buf()))
+    })
+}
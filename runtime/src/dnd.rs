@@ -0,0 +1,178 @@
+//! Drag data out of the application.
+//!
+//! A drag must be started while a mouse button is held, normally right after
+//! a widget is pressed. The operating system takes over the drag from then
+//! on, so it can be dropped into other applications.
+use crate::command::{self, Command};
+use crate::core::widget::{Id, Operation};
+use crate::core::{Rectangle, Size};
+use crate::futures::MaybeSend;
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// The data carried by a drag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Payload {
+    /// Some plain text.
+    Text(String),
+    /// A list of files.
+    Files(Vec<PathBuf>),
+    /// Some data of a custom MIME type.
+    Data {
+        /// The MIME type of the data.
+        mime: String,
+        /// The bytes of the data.
+        bytes: Vec<u8>,
+    },
+}
+
+/// The image shown under the cursor during a drag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Preview {
+    /// An image with the given RGBA pixels.
+    Image {
+        /// The RGBA pixels of the image.
+        rgba: Vec<u8>,
+        /// The size of the image, in pixels.
+        size: Size<u32>,
+    },
+    /// The current contents of the widget with the given [`Id`].
+    ///
+    /// The widget must report its [`Id`] as a container, like a
+    /// `Container` does.
+    Widget(Id),
+}
+
+/// A drag out of the application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Drag {
+    /// The [`Payload`] of the [`Drag`].
+    pub payload: Payload,
+    /// The [`Preview`] of the [`Drag`], if any.
+    pub preview: Option<Preview>,
+}
+
+impl Drag {
+    /// Creates a new [`Drag`] of the given [`Payload`].
+    pub fn new(payload: Payload) -> Self {
+        Self {
+            payload,
+            preview: None,
+        }
+    }
+
+    /// Sets the [`Preview`] of the [`Drag`].
+    pub fn preview(mut self, preview: Preview) -> Self {
+        self.preview = Some(preview);
+        self
+    }
+}
+
+impl Preview {
+    /// Creates a [`Preview::Widget`] of the widget with the given [`Id`].
+    pub fn widget(id: impl Into<Id>) -> Self {
+        Self::Widget(id.into())
+    }
+}
+
+impl From<Payload> for Drag {
+    fn from(payload: Payload) -> Self {
+        Self::new(payload)
+    }
+}
+
+/// How a [`Drag`] finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The [`Payload`] was dropped on a target accepting it.
+    Dropped,
+    /// The [`Drag`] was cancelled or could not be started.
+    Cancelled,
+}
+
+/// A drag and drop action to be performed by some [`Command`].
+pub enum Action<T> {
+    /// Start the given [`Drag`] and produce `T` once it finishes.
+    StartDrag(Drag, Box<dyn Fn(Outcome) -> T>),
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a drag and drop [`Action`] using the provided
+    /// closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::StartDrag(drag, o) => {
+                Action::StartDrag(drag, Box::new(move |outcome| f(o(outcome))))
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartDrag(drag, _) => {
+                write!(f, "Action::StartDrag({drag:?})")
+            }
+        }
+    }
+}
+
+/// Starts dragging the given [`Drag`] out of the application, producing a
+/// message with its [`Outcome`].
+pub fn start_drag<Message>(
+    drag: impl Into<Drag>,
+    on_finish: impl Fn(Outcome) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Dnd(Action::StartDrag(
+        drag.into(),
+        Box::new(on_finish),
+    )))
+}
+
+/// Produces an [`Operation`] that finds the bounds of the widget with the
+/// given [`Id`], used to render a [`Preview::Widget`].
+pub fn find_bounds(id: Id) -> FindBounds {
+    FindBounds { id, bounds: None }
+}
+
+/// An [`Operation`] that finds the bounds of a widget.
+#[derive(Debug)]
+pub struct FindBounds {
+    id: Id,
+    bounds: Option<Rectangle>,
+}
+
+impl FindBounds {
+    /// Returns the bounds of the widget, if found.
+    pub fn bounds(&self) -> Option<Rectangle> {
+        self.bounds
+    }
+}
+
+impl<T> Operation<T> for FindBounds {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.bounds.is_some() {
+            return;
+        }
+
+        if id == Some(&self.id) {
+            self.bounds = Some(bounds);
+            return;
+        }
+
+        operate_on_children(self);
+    }
+}
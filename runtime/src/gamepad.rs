@@ -0,0 +1,141 @@
+//! Listen and react to gamepad input.
+use crate::command::Command;
+use crate::core::widget::operation;
+
+/// The identifier of a connected gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(pub usize);
+
+/// A gamepad event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A gamepad was connected.
+    Connected {
+        /// The [`Id`] of the gamepad.
+        id: Id,
+        /// The name of the gamepad.
+        name: String,
+    },
+
+    /// A gamepad was disconnected.
+    Disconnected {
+        /// The [`Id`] of the gamepad.
+        id: Id,
+    },
+
+    /// A button was pressed.
+    ButtonPressed {
+        /// The [`Id`] of the gamepad.
+        id: Id,
+        /// The [`Button`] pressed.
+        button: Button,
+    },
+
+    /// A button was released.
+    ButtonReleased {
+        /// The [`Id`] of the gamepad.
+        id: Id,
+        /// The [`Button`] released.
+        button: Button,
+    },
+
+    /// An axis changed its value.
+    AxisChanged {
+        /// The [`Id`] of the gamepad.
+        id: Id,
+        /// The [`Axis`] that changed.
+        axis: Axis,
+        /// The new value of the [`Axis`], from `-1.0` to `1.0`.
+        ///
+        /// Up and right are positive.
+        value: f32,
+    },
+}
+
+/// A button of a gamepad, named after its position in a standard layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Button {
+    /// The bottom action button, like A on Xbox controllers.
+    South,
+    /// The right action button, like B on Xbox controllers.
+    East,
+    /// The top action button, like Y on Xbox controllers.
+    North,
+    /// The left action button, like X on Xbox controllers.
+    West,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    /// The button in the center of the gamepad, usually a logo.
+    Mode,
+    /// The left stick, pressed down.
+    LeftStick,
+    /// The right stick, pressed down.
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    /// A button outside of the standard layout.
+    Other,
+}
+
+/// An axis of a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Axis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+    /// The horizontal axis of a D-pad reported as an axis.
+    DPadX,
+    /// The vertical axis of a D-pad reported as an axis.
+    DPadY,
+    /// An axis outside of the standard layout.
+    Other,
+}
+
+/// Moves the focus of the widgets following the D-pad in the given
+/// [`Event`].
+///
+/// Up and left focus the previous widget in tab order, while down and right
+/// focus the next one. Any other [`Event`] produces [`Command::none`].
+pub fn navigate<Message>(event: &Event) -> Command<Message>
+where
+    Message: 'static,
+{
+    /// The value from which an [`Axis`] of the D-pad counts as pressed.
+    const THRESHOLD: f32 = 0.5;
+
+    let is_forward = match *event {
+        Event::ButtonPressed { button, .. } => match button {
+            Button::DPadDown | Button::DPadRight => true,
+            Button::DPadUp | Button::DPadLeft => false,
+            _ => return Command::none(),
+        },
+        Event::AxisChanged {
+            axis: Axis::DPadX,
+            value,
+            ..
+        } if value.abs() >= THRESHOLD => value > 0.0,
+        Event::AxisChanged {
+            axis: Axis::DPadY,
+            value,
+            ..
+        } if value.abs() >= THRESHOLD => value < 0.0,
+        _ => return Command::none(),
+    };
+
+    if is_forward {
+        Command::widget(operation::focusable::focus_next())
+    } else {
+        Command::widget(operation::focusable::focus_previous())
+    }
+}
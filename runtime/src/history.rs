@@ -0,0 +1,433 @@
+//! Undo and redo the changes of an application.
+//!
+//! A [`History`] records [`Edit`]s as they are applied to some target, which
+//! is normally the state of an application. Each [`Edit`] knows how to revert
+//! itself, so the [`History`] can walk back and forth through them.
+//!
+//! Rapid edits, like typing, can be coalesced into a single step by
+//! implementing [`Edit::merge`], and multiple edits can be grouped together
+//! explicitly with [`History::begin_group`] and [`History::end_group`].
+//!
+//! Undoing and redoing is driven by the messages of the application, which
+//! can be produced by the actions of a menu, with [`History::undo_action`]
+//! and [`History::redo_action`], or by the usual keyboard shortcuts, with
+//! [`bind`].
+use crate::core::keyboard::{KeyCode, Modifiers};
+use crate::core::time::{Duration, Instant};
+use crate::futures::keymap::{Chord, Keymap, Shortcut};
+use crate::menu;
+
+use std::collections::VecDeque;
+
+/// An invertible change of some `Target`.
+pub trait Edit<Target> {
+    /// Applies the [`Edit`] to the target.
+    fn apply(&self, target: &mut Target);
+
+    /// Reverts the [`Edit`], leaving the target as it was before it was
+    /// applied.
+    fn revert(&self, target: &mut Target);
+
+    /// Merges the `next` [`Edit`], which has already been applied, into this
+    /// one; so both can be undone in a single step.
+    ///
+    /// Returns `false` if the edits cannot be merged, which is the default.
+    fn merge(&mut self, _next: &Self) -> bool {
+        false
+    }
+}
+
+/// The record of the [`Edit`]s applied to some target.
+#[derive(Debug, Clone)]
+pub struct History<E> {
+    undo: VecDeque<Vec<E>>,
+    redo: Vec<Vec<E>>,
+    group: Option<Vec<E>>,
+    capacity: usize,
+    coalesce_window: Duration,
+    last_edit: Option<Instant>,
+}
+
+impl<E> History<E> {
+    /// The default amount of steps kept by a [`History`].
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// The default time during which consecutive edits are merged.
+    pub const DEFAULT_COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
+    /// Creates an empty [`History`].
+    pub fn new() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            group: None,
+            capacity: Self::DEFAULT_CAPACITY,
+            coalesce_window: Self::DEFAULT_COALESCE_WINDOW,
+            last_edit: None,
+        }
+    }
+
+    /// Sets the maximum amount of steps that can be undone.
+    ///
+    /// The oldest steps are forgotten once the capacity is exceeded.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Sets the time during which consecutive edits are merged using
+    /// [`Edit::merge`].
+    ///
+    /// A zero [`Duration`] disables coalescing.
+    pub fn coalesce_within(mut self, window: Duration) -> Self {
+        self.coalesce_window = window;
+        self
+    }
+
+    /// Applies the given [`Edit`] to the target and records it.
+    ///
+    /// Any step that was undone can no longer be redone.
+    pub fn apply<Target>(&mut self, edit: E, target: &mut Target)
+    where
+        E: Edit<Target>,
+    {
+        edit.apply(target);
+        self.record(edit);
+    }
+
+    /// Records an [`Edit`] that has already been applied to the target.
+    ///
+    /// Any step that was undone can no longer be redone.
+    pub fn record<Target>(&mut self, edit: E)
+    where
+        E: Edit<Target>,
+    {
+        let now = Instant::now();
+
+        let is_recent = self.last_edit.is_some_and(|last_edit| {
+            now.duration_since(last_edit) < self.coalesce_window
+        });
+
+        self.redo.clear();
+        self.last_edit = Some(now);
+
+        if let Some(group) = &mut self.group {
+            let merged = is_recent
+                && group.last_mut().is_some_and(|last| last.merge(&edit));
+
+            if !merged {
+                group.push(edit);
+            }
+
+            return;
+        }
+
+        let is_merged = is_recent
+            && self
+                .undo
+                .back_mut()
+                .and_then(|step| step.last_mut())
+                .is_some_and(|last| last.merge(&edit));
+
+        if !is_merged {
+            self.push(vec![edit]);
+        }
+    }
+
+    /// Starts a group of edits, which are undone and redone in a single
+    /// step.
+    ///
+    /// Nothing happens if a group has already been started.
+    pub fn begin_group(&mut self) {
+        if self.group.is_none() {
+            self.group = Some(Vec::new());
+            self.last_edit = None;
+        }
+    }
+
+    /// Ends the current group of edits, recording it as a single step.
+    pub fn end_group(&mut self) {
+        if let Some(group) = self.group.take() {
+            if !group.is_empty() {
+                self.push(group);
+            }
+        }
+
+        self.last_edit = None;
+    }
+
+    /// Undoes the last step, reverting its edits on the target.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo<Target>(&mut self, target: &mut Target) -> bool
+    where
+        E: Edit<Target>,
+    {
+        self.end_group();
+
+        let Some(step) = self.undo.pop_back() else {
+            return false;
+        };
+
+        for edit in step.iter().rev() {
+            edit.revert(target);
+        }
+
+        self.redo.push(step);
+
+        true
+    }
+
+    /// Redoes the last undone step, applying its edits to the target again.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo<Target>(&mut self, target: &mut Target) -> bool
+    where
+        E: Edit<Target>,
+    {
+        self.end_group();
+
+        let Some(step) = self.redo.pop() else {
+            return false;
+        };
+
+        for edit in &step {
+            edit.apply(target);
+        }
+
+        self.undo.push_back(step);
+
+        true
+    }
+
+    /// Returns whether there is a step that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+            || self.group.as_ref().is_some_and(|group| !group.is_empty())
+    }
+
+    /// Returns whether there is a step that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all the recorded steps.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group = None;
+        self.last_edit = None;
+    }
+
+    /// Returns a [`menu::Action`] with the given label that produces the
+    /// message when activated, and is disabled when nothing can be undone.
+    pub fn undo_action<Message>(
+        &self,
+        label: impl Into<String>,
+        message: Message,
+    ) -> menu::Action<Message> {
+        let action = menu::Action::new(label);
+
+        if self.can_undo() {
+            action.on_activate(message)
+        } else {
+            action
+        }
+    }
+
+    /// Returns a [`menu::Action`] with the given label that produces the
+    /// message when activated, and is disabled when nothing can be redone.
+    pub fn redo_action<Message>(
+        &self,
+        label: impl Into<String>,
+        message: Message,
+    ) -> menu::Action<Message> {
+        let action = menu::Action::new(label);
+
+        if self.can_redo() {
+            action.on_activate(message)
+        } else {
+            action
+        }
+    }
+
+    fn push(&mut self, step: Vec<E>) {
+        self.undo.push_back(step);
+
+        while self.undo.len() > self.capacity {
+            let _ = self.undo.pop_front();
+        }
+    }
+}
+
+impl<E> Default for History<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Binds the usual shortcuts to undo and redo to the given messages in a
+/// [`Keymap`].
+///
+/// Undoing is bound to `Cmd+Z`, and redoing to `Cmd+Shift+Z`, where `Cmd`
+/// is the `Ctrl` key on platforms other than macOS.
+pub fn bind<Message>(
+    keymap: Keymap<Message>,
+    undo: Message,
+    redo: Message,
+) -> Keymap<Message> {
+    keymap.bind("undo", undo_shortcut(), undo).bind(
+        "redo",
+        redo_shortcut(),
+        redo,
+    )
+}
+
+/// Returns the usual [`Shortcut`] to undo: `Cmd+Z`.
+pub fn undo_shortcut() -> Shortcut {
+    Shortcut::new(Chord::new(Modifiers::COMMAND, KeyCode::Z))
+}
+
+/// Returns the usual [`Shortcut`] to redo: `Cmd+Shift+Z`.
+pub fn redo_shortcut() -> Shortcut {
+    Shortcut::new(Chord::new(
+        Modifiers::COMMAND | Modifiers::SHIFT,
+        KeyCode::Z,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Insert(String);
+
+    impl Edit<String> for Insert {
+        fn apply(&self, target: &mut String) {
+            target.push_str(&self.0);
+        }
+
+        fn revert(&self, target: &mut String) {
+            target.truncate(target.len() - self.0.len());
+        }
+
+        fn merge(&mut self, next: &Self) -> bool {
+            self.0.push_str(&next.0);
+            true
+        }
+    }
+
+    fn insert(text: &str) -> Insert {
+        Insert(text.to_owned())
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut text = String::new();
+        let mut history = History::new().coalesce_within(Duration::ZERO);
+
+        history.apply(insert("a"), &mut text);
+        history.apply(insert("b"), &mut text);
+        assert_eq!(text, "ab");
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "a");
+        assert!(history.can_redo());
+
+        assert!(history.redo(&mut text));
+        assert_eq!(text, "ab");
+        assert!(!history.redo(&mut text));
+
+        assert!(history.undo(&mut text));
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "");
+        assert!(!history.undo(&mut text));
+
+        assert!(history.redo(&mut text));
+        history.apply(insert("c"), &mut text);
+        assert_eq!(text, "ac");
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn merges_within_the_coalesce_window() {
+        let mut text = String::new();
+        let mut history =
+            History::new().coalesce_within(Duration::from_secs(60));
+
+        history.apply(insert("a"), &mut text);
+        history.apply(insert("b"), &mut text);
+        history.apply(insert("c"), &mut text);
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "");
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn groups_are_a_single_step() {
+        let mut text = String::new();
+        let mut history = History::new().coalesce_within(Duration::ZERO);
+
+        history.apply(insert("a"), &mut text);
+
+        history.begin_group();
+        history.apply(insert("b"), &mut text);
+        history.apply(insert("c"), &mut text);
+        assert!(history.can_undo());
+        history.end_group();
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "a");
+
+        assert!(history.redo(&mut text));
+        assert_eq!(text, "abc");
+    }
+
+    #[test]
+    fn undo_ends_the_current_group() {
+        let mut text = String::new();
+        let mut history = History::new().coalesce_within(Duration::ZERO);
+
+        history.begin_group();
+        history.apply(insert("a"), &mut text);
+        history.apply(insert("b"), &mut text);
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn evicts_the_oldest_steps() {
+        let mut text = String::new();
+        let mut history =
+            History::new().capacity(2).coalesce_within(Duration::ZERO);
+
+        history.apply(insert("a"), &mut text);
+        history.apply(insert("b"), &mut text);
+        history.apply(insert("c"), &mut text);
+
+        assert!(history.undo(&mut text));
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "a");
+        assert!(!history.undo(&mut text));
+    }
+
+    #[test]
+    fn binds_the_usual_shortcuts() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Message {
+            Undo,
+            Redo,
+        }
+
+        let keymap = bind(Keymap::new(), Message::Undo, Message::Redo);
+
+        assert_eq!(keymap.shortcut("undo"), Some(&undo_shortcut()));
+        assert_eq!(keymap.shortcut("redo"), Some(&redo_shortcut()));
+        assert_eq!(Ok(undo_shortcut()), "Cmd+Z".parse());
+        assert_eq!(Ok(redo_shortcut()), "Cmd+Shift+Z".parse());
+        assert!(keymap.conflicts().is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+//! Translate and localize your application.
+//!
+//! The current [`Locale`] is global and read during `view`. Switching it
+//! with [`switch`] produces a message, so the application is updated and
+//! viewed again right after.
+//!
+//! Locales written from right to left, like Arabic or Hebrew, mirror the
+//! layout of the built-in widgets. See [`Direction`].
+//!
+//! Widgets cached with `lazy` must include [`locale`] in their dependencies
+//! to be rebuilt when it changes.
+#[cfg(feature = "i18n")]
+mod localization;
+
+pub use crate::core::i18n::{locale, Locale};
+pub use crate::core::layout::Direction;
+
+#[cfg(feature = "i18n")]
+pub use localization::{Args, Error, Localization};
+
+use crate::command::Command;
+use crate::futures::MaybeSend;
+
+/// Switches the current [`Locale`] of the application, producing the
+/// message returned by the given function once done.
+pub fn switch<Message>(
+    locale: impl Into<Locale>,
+    f: impl FnOnce(Locale) -> Message + 'static + MaybeSend,
+) -> Command<Message> {
+    let locale = locale.into();
+
+    Command::perform(
+        async move {
+            crate::core::i18n::set_locale(locale.clone());
+
+            locale
+        },
+        f,
+    )
+}
@@ -0,0 +1,162 @@
+use crate::core::i18n::{self, Locale};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+/// A set of translated messages, written in [Fluent].
+///
+/// Messages are looked up in the current [`Locale`] first, then in its
+/// language, and finally in the fallback [`Locale`].
+///
+/// [Fluent]: https://projectfluent.org
+#[allow(missing_debug_implementations)]
+pub struct Localization {
+    fallback: Locale,
+    bundles: Vec<(Locale, FluentBundle<FluentResource>)>,
+}
+
+impl Localization {
+    /// Creates an empty [`Localization`] with the given fallback [`Locale`].
+    pub fn new(fallback: impl Into<Locale>) -> Self {
+        Self {
+            fallback: fallback.into(),
+            bundles: Vec::new(),
+        }
+    }
+
+    /// Adds the messages of the given Fluent source to the [`Localization`]
+    /// for the given [`Locale`].
+    pub fn resource(
+        mut self,
+        locale: impl Into<Locale>,
+        source: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let locale = locale.into();
+
+        let resource = FluentResource::try_new(source.into()).map_err(
+            |(_resource, errors)| {
+                Error::InvalidResource(
+                    errors.iter().map(ToString::to_string).collect(),
+                )
+            },
+        )?;
+
+        let index = match self.bundles.iter().position(|(l, _)| *l == locale) {
+            Some(index) => index,
+            None => {
+                let language: LanguageIdentifier =
+                    locale.tag().parse().map_err(|_| {
+                        Error::InvalidLocale(locale.tag().to_owned())
+                    })?;
+
+                let mut bundle = FluentBundle::new_concurrent(vec![language]);
+
+                // Unicode isolation marks are not rendered properly by
+                // every font
+                bundle.set_use_isolating(false);
+
+                self.bundles.push((locale, bundle));
+                self.bundles.len() - 1
+            }
+        };
+
+        self.bundles[index]
+            .1
+            .add_resource(resource)
+            .map_err(|errors| {
+                Error::DuplicateMessages(
+                    errors.iter().map(ToString::to_string).collect(),
+                )
+            })?;
+
+        Ok(self)
+    }
+
+    /// Returns the [`Locale`]s with messages in the [`Localization`].
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.bundles.iter().map(|(locale, _)| locale)
+    }
+
+    /// Returns the message with the given id in the current [`Locale`].
+    ///
+    /// The id itself is returned if no [`Locale`] has the message.
+    pub fn message(&self, id: &str) -> String {
+        self.message_with(id, &Args::new())
+    }
+
+    /// Returns the message with the given id in the current [`Locale`],
+    /// filling in the given [`Args`].
+    ///
+    /// Plural and other selectors of the message use the rules of the
+    /// [`Locale`] it is found in.
+    ///
+    /// The id itself is returned if no [`Locale`] has the message.
+    pub fn message_with(&self, id: &str, args: &Args) -> String {
+        let current = i18n::locale();
+
+        let exact = self.bundles.iter().filter(|(l, _)| *l == current);
+
+        let language = self.bundles.iter().filter(|(l, _)| {
+            *l != current && l.language() == current.language()
+        });
+
+        let fallback = self.bundles.iter().filter(|(l, _)| *l == self.fallback);
+
+        for (locale, bundle) in exact.chain(language).chain(fallback) {
+            let Some(pattern) =
+                bundle.get_message(id).and_then(|message| message.value())
+            else {
+                continue;
+            };
+
+            let mut errors = Vec::new();
+            let message =
+                bundle.format_pattern(pattern, Some(&args.0), &mut errors);
+
+            for error in errors {
+                log::warn!("Invalid message {id:?} in {locale}: {error}");
+            }
+
+            return message.into_owned();
+        }
+
+        id.to_owned()
+    }
+}
+
+/// The arguments of a message of a [`Localization`].
+#[derive(Debug, Default)]
+pub struct Args(FluentArgs<'static>);
+
+impl Args {
+    /// Creates an empty set of [`Args`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the argument with the given name to the given value, like a
+    /// number or a string.
+    pub fn set(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<FluentValue<'static>>,
+    ) -> Self {
+        self.0.set(name.into(), value);
+        self
+    }
+}
+
+/// An error produced when adding messages to a [`Localization`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The language tag of the [`Locale`] is invalid.
+    #[error("invalid language tag: {0}")]
+    InvalidLocale(String),
+    /// The Fluent source has syntax errors.
+    #[error("invalid Fluent resource: {}", .0.join(", "))]
+    InvalidResource(Vec<String>),
+    /// The Fluent source redefines messages of the [`Locale`].
+    #[error("duplicate messages: {}", .0.join(", "))]
+    DuplicateMessages(Vec<String>),
+}
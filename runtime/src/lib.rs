@@ -16,16 +16,32 @@
     rustdoc::broken_intra_doc_links
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod animation;
 pub mod clipboard;
 pub mod command;
+pub mod dnd;
 pub mod font;
+pub mod gamepad;
+pub mod history;
+pub mod i18n;
 pub mod keyboard;
+pub mod loader;
+pub mod menu;
+pub mod notification;
 pub mod overlay;
 pub mod program;
+pub mod query;
+pub mod recording;
+pub mod report;
 pub mod system;
+pub mod tray;
 pub mod user_interface;
+pub mod webview;
 pub mod window;
 
+#[cfg(all(feature = "dialog", not(target_arch = "wasm32")))]
+pub mod dialog;
+
 // We disable debug capabilities on release builds unless the `debug` feature
 // is explicitly enabled.
 #[cfg(feature = "debug")]
@@ -0,0 +1,405 @@
+//! Load resources asynchronously, by priority and with limited concurrency.
+//!
+//! A [`Loader`] queues the requests of an application—like the images of a
+//! scrolling grid—and only runs a few of them at once, starting the most
+//! important ones first. Requests that are no longer needed can be
+//! cancelled, even if they already started.
+//!
+//! The results of the [`Loader`] reach the application as an [`Event`],
+//! which must be given back to [`Loader::update`] to free its slot.
+//!
+//! A request can be tied to the [`widget::Id`] of the widget that needs it
+//! with [`Loader::request_for`]. The [`Loader`] sweeps the widget tree while
+//! it works, and cancels the requests of the widgets that were removed.
+use crate::command::Command;
+use crate::core::widget::{self, operation, Operation};
+use crate::core::Rectangle;
+use crate::futures::futures::future::{self, AbortHandle};
+use crate::futures::{BoxFuture, MaybeSend};
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+
+/// The importance of a request of a [`Loader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// The request can wait, like a prefetch.
+    Low,
+    /// A regular request.
+    #[default]
+    Normal,
+    /// The request is needed right away, like a visible image.
+    High,
+}
+
+/// An update of a [`Loader`].
+#[derive(Debug, Clone)]
+pub enum Event<K, T> {
+    /// The request with the given key finished loading.
+    Loaded(K, T),
+    /// The request with the given key was cancelled while loading.
+    Cancelled(K),
+    /// The widgets present in the interface of the application, found by a
+    /// sweep of the [`Loader`].
+    ///
+    /// The requests of any other widget are cancelled.
+    Swept(HashSet<widget::Id>),
+}
+
+/// The progress of a [`Loader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Progress {
+    /// The amount of requests that finished loading.
+    pub completed: usize,
+    /// The amount of requests made, including the completed ones.
+    pub total: usize,
+}
+
+/// A queue of asynchronous requests identified by keys of type `K` and
+/// producing values of type `T`.
+#[allow(missing_debug_implementations)]
+pub struct Loader<K, T> {
+    concurrency: usize,
+    pending: Vec<Pending<K, T>>,
+    loading: HashMap<K, Loading>,
+    next_sequence: u64,
+    progress: Progress,
+    needs_sweep: bool,
+}
+
+struct Pending<K, T> {
+    key: K,
+    owner: Option<widget::Id>,
+    priority: Priority,
+    sequence: u64,
+    future: BoxFuture<T>,
+}
+
+struct Loading {
+    owner: Option<widget::Id>,
+    handle: AbortHandle,
+}
+
+impl<K, T> Loader<K, T>
+where
+    K: Hash + Eq + Clone + MaybeSend + 'static,
+    T: MaybeSend + 'static,
+{
+    /// Creates a new [`Loader`] running at most `concurrency` requests at
+    /// once.
+    pub fn new(concurrency: usize) -> Self {
+        Self {
+            concurrency: concurrency.max(1),
+            pending: Vec::new(),
+            loading: HashMap::new(),
+            next_sequence: 0,
+            progress: Progress::default(),
+            needs_sweep: false,
+        }
+    }
+
+    /// Queues a request with the given key, [`Priority`], and future.
+    ///
+    /// If a request with the same key is already queued, only its
+    /// [`Priority`] and owner are changed. Requests with the same [`Priority`] are
+    /// started in the order they were made.
+    pub fn request(
+        &mut self,
+        key: K,
+        priority: Priority,
+        future: impl Future<Output = T> + MaybeSend + 'static,
+    ) {
+        self.push(None, key, priority, future);
+    }
+
+    /// Queues a request needed by the widget with the given [`widget::Id`].
+    ///
+    /// The request is cancelled once the widget is removed from the
+    /// interface of the application, whether it is queued or already
+    /// loading. Otherwise, it behaves like [`Loader::request`].
+    pub fn request_for(
+        &mut self,
+        owner: impl Into<widget::Id>,
+        key: K,
+        priority: Priority,
+        future: impl Future<Output = T> + MaybeSend + 'static,
+    ) {
+        self.push(Some(owner.into()), key, priority, future);
+    }
+
+    fn push(
+        &mut self,
+        owner: Option<widget::Id>,
+        key: K,
+        priority: Priority,
+        future: impl Future<Output = T> + MaybeSend + 'static,
+    ) {
+        self.needs_sweep |= owner.is_some();
+
+        if let Some(loading) = self.loading.get_mut(&key) {
+            loading.owner = owner;
+            return;
+        }
+
+        if let Some(pending) =
+            self.pending.iter_mut().find(|pending| pending.key == key)
+        {
+            pending.owner = owner;
+            pending.priority = priority;
+            return;
+        }
+
+        self.pending.push(Pending {
+            key,
+            owner,
+            priority,
+            sequence: self.next_sequence,
+            future: Box::pin(future),
+        });
+
+        self.next_sequence += 1;
+        self.progress.total += 1;
+    }
+
+    /// Cancels the request with the given key, whether it is queued or
+    /// already loading.
+    pub fn cancel(&mut self, key: &K) {
+        self.retain(|other| other != key);
+    }
+
+    /// Cancels every request whose key does not satisfy the predicate.
+    ///
+    /// This is useful to forget the resources of the widgets that are no
+    /// longer visible.
+    pub fn retain(&mut self, mut f: impl FnMut(&K) -> bool) {
+        self.retain_requests(|key, _owner| f(key));
+    }
+
+    fn retain_requests(
+        &mut self,
+        mut f: impl FnMut(&K, Option<&widget::Id>) -> bool,
+    ) {
+        let pending = self.pending.len();
+
+        self.pending
+            .retain(|pending| f(&pending.key, pending.owner.as_ref()));
+
+        let mut cancelled = pending - self.pending.len();
+
+        self.loading.retain(|key, loading| {
+            let keep = f(key, loading.owner.as_ref());
+
+            if !keep {
+                loading.handle.abort();
+                cancelled += 1;
+            }
+
+            keep
+        });
+
+        self.progress.total -= cancelled;
+    }
+
+    /// Processes an [`Event`] produced by the [`Loader`], freeing its slot.
+    ///
+    /// Returns the loaded value, if any.
+    pub fn update(&mut self, event: Event<K, T>) -> Option<(K, T)> {
+        match event {
+            Event::Loaded(key, value) => {
+                // Requests cancelled right as they finished are discarded
+                let _ = self.loading.remove(&key)?;
+
+                self.progress.completed += 1;
+                self.needs_sweep = true;
+
+                Some((key, value))
+            }
+            // The slot was freed when the request was cancelled
+            Event::Cancelled(_) => None,
+            Event::Swept(present) => {
+                self.retain_requests(|_key, owner| {
+                    owner.is_none_or(|owner| present.contains(owner))
+                });
+
+                None
+            }
+        }
+    }
+
+    /// Starts as many queued requests as the concurrency limit allows, most
+    /// important first, producing their [`Event`]s with the given function.
+    ///
+    /// The widget tree is swept as well if any request was made with
+    /// [`Loader::request_for`] since the last sweep, or if a request has
+    /// finished loading since then.
+    pub fn next<Message>(
+        &mut self,
+        f: impl Fn(Event<K, T>) -> Message + Clone + MaybeSend + 'static,
+    ) -> Command<Message>
+    where
+        Message: 'static,
+    {
+        let mut commands = Vec::new();
+
+        while self.loading.len() < self.concurrency && !self.pending.is_empty()
+        {
+            let (index, _) = self
+                .pending
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, pending)| {
+                    (pending.priority, std::cmp::Reverse(pending.sequence))
+                })
+                .expect("Pending request");
+
+            let Pending {
+                key, owner, future, ..
+            } = self.pending.remove(index);
+
+            let (future, handle) = future::abortable(future);
+
+            let _ = self.loading.insert(key.clone(), Loading { owner, handle });
+
+            let f = f.clone();
+
+            commands.push(Command::perform(future, move |result| {
+                f(match result {
+                    Ok(value) => Event::Loaded(key, value),
+                    Err(future::Aborted) => Event::Cancelled(key),
+                })
+            }));
+        }
+
+        if self.needs_sweep && self.is_owned() {
+            commands.push(self.sweep(f));
+        }
+
+        self.needs_sweep = false;
+
+        Command::batch(commands)
+    }
+
+    /// Sweeps the widget tree right away, cancelling the requests of the
+    /// widgets that were removed once the resulting [`Event`] is given back
+    /// to [`Loader::update`].
+    ///
+    /// This is useful to cancel requests as soon as their widgets disappear,
+    /// without waiting for other requests to finish loading.
+    pub fn sweep<Message>(
+        &self,
+        f: impl Fn(Event<K, T>) -> Message + 'static,
+    ) -> Command<Message>
+    where
+        Message: 'static,
+    {
+        Command::widget(Sweep {
+            present: HashSet::new(),
+            on_finish: Box::new(move |present| f(Event::Swept(present))),
+        })
+    }
+
+    /// Returns the [`Progress`] of the [`Loader`].
+    pub fn progress(&self) -> Progress {
+        self.progress
+    }
+
+    /// Returns whether the [`Loader`] has nothing to load.
+    pub fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.loading.is_empty()
+    }
+
+    fn is_owned(&self) -> bool {
+        self.pending.iter().any(|pending| pending.owner.is_some())
+            || self.loading.values().any(|loading| loading.owner.is_some())
+    }
+}
+
+/// An [`Operation`] that finds the widgets present in the widget tree.
+struct Sweep<Message> {
+    present: HashSet<widget::Id>,
+    on_finish: Box<dyn Fn(HashSet<widget::Id>) -> Message>,
+}
+
+impl<Message> Sweep<Message> {
+    fn visit(&mut self, id: Option<&widget::Id>) {
+        if let Some(id) = id {
+            let _ = self.present.insert(id.clone());
+        }
+    }
+}
+
+impl<Message> Operation<Message> for Sweep<Message> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Message>),
+    ) {
+        self.visit(id);
+        operate_on_children(self);
+    }
+
+    fn focusable(
+        &mut self,
+        _state: &mut dyn operation::Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        self.visit(id);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _translation: crate::core::Vector,
+    ) {
+        self.visit(id);
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn operation::TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        self.visit(id);
+    }
+
+    fn custom(
+        &mut self,
+        _state: &mut dyn std::any::Any,
+        id: Option<&widget::Id>,
+    ) {
+        self.visit(id);
+    }
+
+    fn finish(&self) -> operation::Outcome<Message> {
+        operation::Outcome::Some((self.on_finish)(self.present.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sweeping_cancels_the_requests_of_removed_widgets() {
+        let mut loader = Loader::new(1);
+
+        loader.request_for(widget::Id::new("a"), 1, Priority::Normal, async {});
+        loader.request_for(widget::Id::new("b"), 2, Priority::High, async {});
+        loader.request(3, Priority::Low, async {});
+
+        let _ = loader.next(|event: Event<u32, ()>| event);
+        assert_eq!(loader.progress().total, 3);
+
+        let present = HashSet::from([widget::Id::new("a")]);
+        assert!(loader.update(Event::Swept(present)).is_none());
+
+        assert_eq!(loader.progress().total, 2);
+        assert!(loader.pending.iter().any(|pending| pending.key == 1));
+        assert!(loader.pending.iter().any(|pending| pending.key == 3));
+        assert!(loader.loading.is_empty());
+    }
+}
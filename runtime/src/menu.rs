@@ -0,0 +1,142 @@
+//! Describe native menus.
+//!
+//! A [`Menu`] is only a description; the shell builds the native menu out of
+//! it and produces the message of an [`Action`] when it is activated.
+
+/// A native menu, made of a list of [`Item`]s.
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    items: Vec<Item<Message>>,
+}
+
+/// An entry of a [`Menu`].
+#[derive(Debug, Clone)]
+pub enum Item<Message> {
+    /// An [`Action`] that can be activated.
+    Action(Action<Message>),
+    /// A line separating groups of items.
+    Separator,
+    /// An item that opens a nested [`Menu`].
+    Submenu {
+        /// The label of the item.
+        label: String,
+        /// The nested [`Menu`].
+        menu: Menu<Message>,
+    },
+}
+
+/// An item of a [`Menu`] that produces a message when activated.
+#[derive(Debug, Clone)]
+pub struct Action<Message> {
+    /// The label of the [`Action`].
+    pub label: String,
+    /// The message produced when the [`Action`] is activated.
+    ///
+    /// The [`Action`] is disabled if it is `None`.
+    pub on_activate: Option<Message>,
+    /// Whether the [`Action`] shows a checkmark, if it can be checked.
+    pub is_checked: Option<bool>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates an empty [`Menu`].
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds an [`Action`] that produces the given message when activated.
+    pub fn item(self, label: impl Into<String>, message: Message) -> Self {
+        self.action(Action::new(label).on_activate(message))
+    }
+
+    /// Adds the given [`Action`].
+    pub fn action(self, action: Action<Message>) -> Self {
+        self.push(Item::Action(action))
+    }
+
+    /// Adds a separator.
+    pub fn separator(self) -> Self {
+        self.push(Item::Separator)
+    }
+
+    /// Adds an item that opens the given nested [`Menu`].
+    pub fn submenu(self, label: impl Into<String>, menu: Self) -> Self {
+        self.push(Item::Submenu {
+            label: label.into(),
+            menu,
+        })
+    }
+
+    /// Adds the given [`Item`].
+    pub fn push(mut self, item: Item<Message>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Returns the [`Item`]s of the [`Menu`].
+    pub fn items(&self) -> &[Item<Message>] {
+        &self.items
+    }
+
+    /// Applies a transformation to the messages of the [`Menu`].
+    pub fn map<B>(self, f: &impl Fn(Message) -> B) -> Menu<B> {
+        Menu {
+            items: self.items.into_iter().map(|item| item.map(f)).collect(),
+        }
+    }
+}
+
+impl<Message> Item<Message> {
+    /// Applies a transformation to the messages of the [`Item`].
+    pub fn map<B>(self, f: &impl Fn(Message) -> B) -> Item<B> {
+        match self {
+            Self::Action(action) => Item::Action(action.map(f)),
+            Self::Separator => Item::Separator,
+            Self::Submenu { label, menu } => Item::Submenu {
+                label,
+                menu: menu.map(f),
+            },
+        }
+    }
+}
+
+impl<Message> Action<Message> {
+    /// Creates a new [`Action`] with the given label.
+    ///
+    /// It is disabled until a message is set with [`Action::on_activate`].
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            on_activate: None,
+            is_checked: None,
+        }
+    }
+
+    /// Sets the message produced when the [`Action`] is activated.
+    pub fn on_activate(mut self, message: Message) -> Self {
+        self.on_activate = Some(message);
+        self
+    }
+
+    /// Makes the [`Action`] checkable, showing a checkmark when
+    /// `is_checked` is true.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        self.is_checked = Some(is_checked);
+        self
+    }
+
+    /// Applies a transformation to the message of the [`Action`].
+    pub fn map<B>(self, f: &impl Fn(Message) -> B) -> Action<B> {
+        Action {
+            label: self.label,
+            on_activate: self.on_activate.map(f),
+            is_checked: self.is_checked,
+        }
+    }
+}
+
+impl<Message> Default for Menu<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,93 @@
+//! Show desktop notifications.
+use crate::command::{self, Command};
+use crate::futures::MaybeSend;
+
+use std::fmt;
+
+/// A desktop notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification<Message> {
+    /// The title of the [`Notification`].
+    pub title: String,
+    /// The body of the [`Notification`].
+    pub body: String,
+    /// The name of the icon of the [`Notification`], or the path to an
+    /// image file, if any.
+    pub icon: Option<String>,
+    /// The message produced when the [`Notification`] is clicked, if any.
+    pub on_click: Option<Message>,
+}
+
+impl<Message> Notification<Message> {
+    /// Creates a new [`Notification`] with the given title and body.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    /// Sets the icon of the [`Notification`], either the name of an icon of
+    /// the desktop theme or the path to an image file.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the message produced when the [`Notification`] is clicked.
+    ///
+    /// Not every platform reports clicks on notifications.
+    pub fn on_click(mut self, message: Message) -> Self {
+        self.on_click = Some(message);
+        self
+    }
+
+    /// Applies a transformation to the message of the [`Notification`].
+    pub fn map<B>(self, f: impl Fn(Message) -> B) -> Notification<B> {
+        Notification {
+            title: self.title,
+            body: self.body,
+            icon: self.icon,
+            on_click: self.on_click.map(f),
+        }
+    }
+}
+
+/// A notification action to be performed by some [`Command`].
+pub enum Action<T> {
+    /// Show the given [`Notification`].
+    Show(Notification<T>),
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a notification [`Action`] using the provided
+    /// closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Show(notification) => Action::Show(notification.map(f)),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Show(notification) => {
+                write!(f, "Action::Show({:?})", notification.title)
+            }
+        }
+    }
+}
+
+/// Shows the given [`Notification`].
+pub fn show<Message>(notification: Notification<Message>) -> Command<Message> {
+    Command::single(command::Action::Notification(Action::Show(notification)))
+}
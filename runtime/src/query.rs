@@ -0,0 +1,201 @@
+//! Query the live widget tree of an application.
+//!
+//! Widgets can be found by their [`Id`]—any [`Element`] can be given one
+//! with [`Element::tag`]—or by the text they show. This enables end-to-end
+//! automation and accessibility tooling.
+//!
+//! [`Element`]: crate::core::Element
+//! [`Element::tag`]: crate::core::Element::tag
+use crate::command::Command;
+use crate::core::accessibility;
+use crate::core::widget::operation::{self, Operation, Outcome};
+use crate::core::widget::Id;
+use crate::core::{Rectangle, Vector};
+
+/// A way to find a widget.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Selector {
+    /// The widget with the given [`Id`].
+    Id(Id),
+    /// The widget showing the given text, like a text or the label of a
+    /// button.
+    Text(String),
+}
+
+impl Selector {
+    fn matches(&self, id: Option<&Id>, label: Option<&str>) -> bool {
+        match self {
+            Selector::Id(target) => id == Some(target),
+            Selector::Text(text) => label == Some(text.as_str()),
+        }
+    }
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// A widget matching a [`Selector`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+    /// The bounds of the widget, in logical pixels.
+    pub bounds: Rectangle,
+    /// The accessible label of the widget, if any.
+    pub label: Option<String>,
+}
+
+/// Produces the bounds of the first widget matching the [`Selector`], if
+/// any.
+///
+/// The center of the bounds can be used to click the widget.
+pub fn bounds(selector: impl Into<Selector>) -> Command<Option<Rectangle>> {
+    Command::widget(Query {
+        find: find(selector),
+        output: |matches| matches.first().map(|found| found.bounds),
+    })
+}
+
+/// Produces every widget matching the [`Selector`], in tree order.
+pub fn find_all(selector: impl Into<Selector>) -> Command<Vec<Match>> {
+    Command::widget(Query {
+        find: find(selector),
+        output: <[Match]>::to_vec,
+    })
+}
+
+/// Returns an [`Operation`] that finds the widgets matching the
+/// [`Selector`].
+///
+/// This is useful for shells and testing harnesses that own the widget
+/// tree.
+pub fn find(selector: impl Into<Selector>) -> Find {
+    Find {
+        selector: selector.into(),
+        matches: Vec::new(),
+    }
+}
+
+/// An [`Operation`] that finds the widgets matching a [`Selector`].
+#[derive(Debug)]
+pub struct Find {
+    selector: Selector,
+    matches: Vec<Match>,
+}
+
+impl Find {
+    /// Returns the widgets found, in tree order.
+    pub fn matches(&self) -> &[Match] {
+        &self.matches
+    }
+
+    fn visit(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        label: Option<&str>,
+    ) {
+        if self.selector.matches(id, label) {
+            self.matches.push(Match {
+                id: id.cloned(),
+                bounds,
+                label: label.map(str::to_owned),
+            });
+        }
+    }
+}
+
+impl<T> Operation<T> for Find {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.visit(id, bounds, None);
+
+        operate_on_children(self);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.visit(id, bounds, node.label.as_deref());
+
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        self.visit(id, bounds, None);
+    }
+}
+
+/// A [`Find`] producing its matches as the output of a [`Command`].
+struct Query<T> {
+    find: Find,
+    output: fn(&[Match]) -> T,
+}
+
+impl<T> Operation<T> for Query<T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.find.visit(id, bounds, None);
+
+        operate_on_children(self);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.find.visit(id, bounds, node.label.as_deref());
+
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        self.find.visit(id, bounds, None);
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        Outcome::Some((self.output)(&self.find.matches))
+    }
+}
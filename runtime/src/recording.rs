@@ -0,0 +1,144 @@
+//! Record the messages of an application and replay them later.
+//!
+//! A [`Recording`] keeps every message handled by an application, alongside
+//! the time it was received. Replaying it on a fresh instance of the
+//! application reproduces the exact same state, which is useful to travel
+//! back in time while debugging or to attach a reproducible bug report.
+//!
+//! The [`Command`]s produced while replaying are discarded. Their results
+//! reached the application as messages too, so they are already part of the
+//! [`Recording`]; running them again would break determinism.
+use crate::core::event::Event;
+use crate::core::time::{Duration, Instant};
+use crate::Command;
+
+/// The messages and events received by an application over time.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde"),
+    serde(bound(
+        serialize = "Message: serde::Serialize",
+        deserialize = "Message: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Recording<Message> {
+    #[cfg_attr(feature = "serde", serde(skip, default = "Instant::now"))]
+    started_at: Instant,
+    messages: Vec<Entry<Message>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    events: Vec<Entry<Event>>,
+}
+
+/// A value of a [`Recording`] and the time it was recorded at.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde"))]
+pub struct Entry<T> {
+    /// The time since the [`Recording`] started.
+    pub at: Duration,
+
+    /// The recorded value.
+    pub value: T,
+}
+
+impl<Message> Recording<Message> {
+    /// Creates an empty [`Recording`] starting now.
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            messages: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Records a message that is about to be handled by the application.
+    ///
+    /// It should be called at the start of its `update` logic.
+    pub fn record(&mut self, message: Message) {
+        self.messages.push(Entry {
+            at: self.started_at.elapsed(),
+            value: message,
+        });
+    }
+
+    /// Records an [`Event`] received by the application.
+    ///
+    /// Events are not replayed, since they only change the application
+    /// through messages; but they give context to the messages when
+    /// inspecting a [`Recording`].
+    pub fn record_event(&mut self, event: Event) {
+        self.events.push(Entry {
+            at: self.started_at.elapsed(),
+            value: event,
+        });
+    }
+
+    /// Returns the recorded messages, in the order they were handled.
+    pub fn messages(&self) -> &[Entry<Message>] {
+        &self.messages
+    }
+
+    /// Returns the recorded events, in the order they were received.
+    pub fn events(&self) -> &[Entry<Event>] {
+        &self.events
+    }
+
+    /// Returns the amount of recorded messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns whether no message has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Forgets everything recorded, starting over now.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Keeps only the first `count` messages, along with the events received
+    /// before the last of them.
+    ///
+    /// This is useful to branch off a past state after traveling back in
+    /// time.
+    pub fn truncate(&mut self, count: usize) {
+        self.messages.truncate(count);
+
+        let until = self.messages.last().map(|entry| entry.at);
+
+        self.events
+            .retain(|entry| until.is_some_and(|until| entry.at <= until));
+    }
+
+    /// Replays all the recorded messages using the given `update` logic.
+    pub fn replay(&self, update: impl FnMut(Message) -> Command<Message>)
+    where
+        Message: Clone,
+    {
+        self.replay_until(self.messages.len(), update);
+    }
+
+    /// Replays the first `count` recorded messages using the given `update`
+    /// logic, reproducing the state of the application at that point.
+    pub fn replay_until(
+        &self,
+        count: usize,
+        mut update: impl FnMut(Message) -> Command<Message>,
+    ) where
+        Message: Clone,
+    {
+        for entry in self.messages.iter().take(count) {
+            let _ = update(entry.value.clone());
+        }
+    }
+}
+
+impl<Message> Default for Recording<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,97 @@
+//! Catch the panics of an application and describe them.
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// The part of an application that panicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The application panicked while producing its view.
+    View,
+    /// The application panicked while handling a message.
+    Update,
+    /// The application panicked while producing its subscriptions.
+    Subscription,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::View => "view",
+            Phase::Update => "update",
+            Phase::Subscription => "subscription",
+        })
+    }
+}
+
+/// A description of a panic of an application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The part of the application that panicked.
+    pub phase: Phase,
+    /// The message of the panic.
+    pub message: String,
+    /// The location in the source code where the panic happened, if known.
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "panicked during {}: {}", self.phase, self.message)?;
+
+        if let Some(location) = &self.location {
+            write!(f, " ({location})")?;
+        }
+
+        Ok(())
+    }
+}
+
+thread_local! {
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs the given closure, producing a [`Report`] if it panics.
+///
+/// The panic is still printed by the panic hook in place.
+pub fn catch<T>(phase: Phase, f: impl FnOnce() -> T) -> Result<T, Report> {
+    static HOOK: Once = Once::new();
+
+    // The location is only known by the panic hook, so it is remembered
+    // before calling the previous one
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            LOCATION.with(|location| {
+                *location.borrow_mut() =
+                    info.location().map(ToString::to_string);
+            });
+
+            previous(info);
+        }));
+    });
+
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| Report {
+        phase,
+        message: message(payload.as_ref()),
+        location: LOCATION.with(|location| location.borrow_mut().take()),
+    })
+}
+
+/// Resumes unwinding with the given [`Report`], as if it was never caught.
+pub fn resume(report: Report) -> ! {
+    panic::resume_unwind(Box::new(report.to_string()))
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
@@ -1,6 +1,10 @@
 //! Access the native system.
 mod action;
+mod appearance;
+mod event;
 mod information;
 
 pub use action::Action;
+pub use appearance::Appearance;
+pub use event::Event;
 pub use information::Information;
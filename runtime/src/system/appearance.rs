@@ -0,0 +1,11 @@
+use crate::core::Color;
+
+/// The appearance preferred by the user for the applications of the
+/// system.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Appearance {
+    /// Whether the user prefers a dark color scheme.
+    pub is_dark: bool,
+    /// The accent color chosen by the user, if the system has one.
+    pub accent: Option<Color>,
+}
@@ -0,0 +1,27 @@
+/// A power or session event of the system.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The system is about to be suspended.
+    Suspended,
+
+    /// The system was resumed after being suspended.
+    Resumed,
+
+    /// The lid of the device was closed.
+    LidClosed,
+
+    /// The lid of the device was opened.
+    LidOpened,
+
+    /// The battery of the device is running low while discharging.
+    BatteryLow {
+        /// The remaining charge of the battery, from 0 to 100.
+        percentage: f32,
+    },
+
+    /// The session of the user was locked.
+    SessionLocked,
+
+    /// The session of the user was unlocked.
+    SessionUnlocked,
+}
@@ -0,0 +1,77 @@
+//! Show an icon in the system tray.
+//!
+//! A [`Tray`] is returned by an application on every update, like its title.
+//! The shell creates the native icon the first time, keeps its icon, tooltip,
+//! and [`Menu`] in sync afterwards, and removes it once no [`Tray`] is
+//! returned anymore.
+//!
+//! Combined with hiding the window on close, it lets an application keep
+//! running in the background.
+use crate::core::window::Icon;
+use crate::menu::Menu;
+
+/// An icon in the system tray, or status area, of the desktop.
+#[derive(Debug, Clone)]
+pub struct Tray<Message> {
+    /// The [`Icon`] of the [`Tray`].
+    pub icon: Icon,
+    /// The text shown when hovering the [`Tray`], if any.
+    pub tooltip: Option<String>,
+    /// The [`Menu`] opened by the [`Tray`], if any.
+    pub menu: Option<Menu<Message>>,
+    /// The message produced when the [`Tray`] is clicked, if any.
+    pub on_click: Option<Message>,
+    /// The message produced when the [`Tray`] is double-clicked, if any.
+    pub on_double_click: Option<Message>,
+}
+
+impl<Message> Tray<Message> {
+    /// Creates a new [`Tray`] with the given [`Icon`].
+    pub fn new(icon: Icon) -> Self {
+        Self {
+            icon,
+            tooltip: None,
+            menu: None,
+            on_click: None,
+            on_double_click: None,
+        }
+    }
+
+    /// Sets the tooltip of the [`Tray`].
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets the [`Menu`] of the [`Tray`].
+    ///
+    /// On some platforms, clicking the [`Tray`] opens its [`Menu`] instead
+    /// of producing the `on_click` message.
+    pub fn menu(mut self, menu: Menu<Message>) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Sets the message produced when the [`Tray`] is clicked.
+    pub fn on_click(mut self, message: Message) -> Self {
+        self.on_click = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the [`Tray`] is double-clicked.
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    /// Applies a transformation to the messages of the [`Tray`].
+    pub fn map<B>(self, f: impl Fn(Message) -> B) -> Tray<B> {
+        Tray {
+            icon: self.icon,
+            tooltip: self.tooltip,
+            menu: self.menu.map(|menu| menu.map(&f)),
+            on_click: self.on_click.map(&f),
+            on_double_click: self.on_double_click.map(&f),
+        }
+    }
+}
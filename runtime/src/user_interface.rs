@@ -1,5 +1,6 @@
 //! Implement your own event loop to drive a user interface.
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -27,6 +28,7 @@ pub struct UserInterface<'a, Message, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    is_pointer_captured: bool,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -107,6 +109,7 @@ where
             state,
             overlay: None,
             bounds,
+            is_pointer_captured: false,
         }
     }
 
@@ -188,6 +191,8 @@ where
         let mut outdated = false;
         let mut redraw_request = None;
 
+        self.is_pointer_captured = false;
+
         let mut manual_overlay = ManuallyDrop::new(
             self.root
                 .as_widget_mut()
@@ -261,6 +266,10 @@ where
                 if shell.are_widgets_invalid() {
                     outdated = true;
                 }
+
+                if shell.is_pointer_captured() {
+                    self.is_pointer_captured = true;
+                }
             }
 
             let base_cursor = if manual_overlay
@@ -292,7 +301,7 @@ where
 
         let _ = ManuallyDrop::into_inner(manual_overlay);
 
-        let event_statuses = events
+        let mut event_statuses: Vec<_> = events
             .iter()
             .cloned()
             .zip(overlay_statuses)
@@ -342,10 +351,42 @@ where
                     outdated = true;
                 }
 
+                if shell.is_pointer_captured() {
+                    self.is_pointer_captured = true;
+                }
+
                 event_status.merge(overlay_status)
             })
             .collect();
 
+        // Tab presses no widget cares about move the focus along the tab
+        // order
+        for (event, status) in events.iter().zip(event_statuses.iter_mut()) {
+            if let (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Tab,
+                    modifiers,
+                    ..
+                }),
+                event::Status::Ignored,
+            ) = (event, *status)
+            {
+                if modifiers.shift() {
+                    self.focus_with(
+                        renderer,
+                        widget::operation::focusable::focus_previous(),
+                    );
+                } else {
+                    self.focus_with(
+                        renderer,
+                        widget::operation::focusable::focus_next(),
+                    );
+                }
+
+                *status = event::Status::Captured;
+            }
+        }
+
         (
             if outdated {
                 State::Outdated
@@ -578,6 +619,37 @@ where
         }
     }
 
+    /// Applies a focus [`widget::Operation`], following any chained
+    /// operations until it finishes.
+    fn focus_with(
+        &mut self,
+        renderer: &Renderer,
+        mut operation: impl widget::Operation<Message>,
+    ) {
+        self.operate(renderer, &mut operation);
+
+        let mut outcome = operation.finish();
+
+        while let widget::operation::Outcome::Chain(mut next) = outcome {
+            self.operate(renderer, next.as_mut());
+
+            outcome = next.finish();
+        }
+    }
+
+    /// Returns the [`Layout`] of the root widget of the [`UserInterface`].
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.base)
+    }
+
+    /// Returns whether a widget captured the pointer during the last
+    /// [`update`](Self::update).
+    ///
+    /// The pointer stays captured until every mouse button is released.
+    pub fn is_pointer_captured(&self) -> bool {
+        self.is_pointer_captured
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -0,0 +1,218 @@
+//! Embed web content in your application.
+//!
+//! The web content itself is rendered by a native surface managed by the
+//! shell; the widget tree only keeps track of the [`State`] of every web view
+//! (its location, its bounds, and the messages exchanged with its page).
+use crate::command::Command;
+use crate::core::widget::{self, Operation};
+use crate::core::{Rectangle, Vector};
+
+use std::any::Any;
+
+/// The identifier of a web view.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// The state of a web view, shared between its widget and the shell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    url: String,
+    bounds: Rectangle,
+    scripts: Vec<String>,
+    messages: Vec<String>,
+}
+
+impl State {
+    /// Creates a new [`State`] of a web view showing the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            bounds: Rectangle::default(),
+            scripts: Vec::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns the URL the web view should show.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Makes the web view navigate to the given URL.
+    pub fn navigate(&mut self, url: impl Into<String>) {
+        self.url = url.into();
+    }
+
+    /// Returns the layout bounds of the web view, in logical coordinates of
+    /// the window, before any scrolling.
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Sets the bounds of the web view.
+    pub fn set_bounds(&mut self, bounds: Rectangle) {
+        self.bounds = bounds;
+    }
+
+    /// Queues some JavaScript to be evaluated in the page of the web view.
+    pub fn evaluate(&mut self, script: impl Into<String>) {
+        self.scripts.push(script.into());
+    }
+
+    /// Takes the scripts queued for evaluation.
+    pub fn take_scripts(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.scripts)
+    }
+
+    /// Stores a message posted by the page of the web view.
+    pub fn receive(&mut self, message: String) {
+        self.messages.push(message);
+    }
+
+    /// Takes the messages posted by the page of the web view.
+    pub fn take_messages(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.messages)
+    }
+}
+
+/// Produces a [`Command`] that makes the web view with the given [`Id`]
+/// navigate to a URL.
+pub fn navigate<Message: 'static>(
+    id: Id,
+    url: impl Into<String>,
+) -> Command<Message> {
+    let url = url.into();
+
+    Command::widget(update(id, move |state| state.navigate(url.clone())))
+}
+
+/// Produces a [`Command`] that evaluates some JavaScript in the page of the
+/// web view with the given [`Id`].
+///
+/// The page can answer by posting messages with
+/// `window.ipc.postMessage(message)`.
+pub fn evaluate<Message: 'static>(
+    id: Id,
+    script: impl Into<String>,
+) -> Command<Message> {
+    let script = script.into();
+
+    Command::widget(update(id, move |state| state.evaluate(script.clone())))
+}
+
+/// Produces an [`Operation`] that visits the [`State`] of every web view in
+/// the widget tree.
+///
+/// Besides the [`State`], the function receives the bounds of the web view
+/// in the window once scrolled, or `None` if it is scrolled out of view.
+pub fn visit<T, F>(f: F) -> impl Operation<T>
+where
+    F: FnMut(&widget::Id, &mut State, Option<Rectangle>),
+{
+    struct Visit<F> {
+        f: F,
+        translation: Vector,
+        viewport: Option<Rectangle>,
+        scrollable: Option<(Rectangle, Vector)>,
+    }
+
+    impl<T, F> Operation<T> for Visit<F>
+    where
+        F: FnMut(&widget::Id, &mut State, Option<Rectangle>),
+    {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            // The contents of a scrollable are translated and clipped
+            let Some((bounds, translation)) = self.scrollable.take() else {
+                operate_on_children(self);
+                return;
+            };
+
+            let bounds = bounds - self.translation;
+            let (previous_translation, previous_viewport) =
+                (self.translation, self.viewport);
+
+            self.viewport = Some(match self.viewport {
+                Some(viewport) => {
+                    viewport.intersection(&bounds).unwrap_or(Rectangle {
+                        width: 0.0,
+                        height: 0.0,
+                        ..bounds
+                    })
+                }
+                None => bounds,
+            });
+            self.translation = self.translation + translation;
+
+            operate_on_children(self);
+
+            self.translation = previous_translation;
+            self.viewport = previous_viewport;
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn widget::operation::Scrollable,
+            _id: Option<&widget::Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.scrollable = Some((bounds, translation));
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if let (Some(id), Some(state)) = (id, state.downcast_mut::<State>())
+            {
+                let bounds = state.bounds() - self.translation;
+
+                let is_visible = self.viewport.is_none_or(|viewport| {
+                    viewport.intersection(&bounds).is_some()
+                });
+
+                (self.f)(id, state, is_visible.then_some(bounds));
+            }
+        }
+    }
+
+    Visit {
+        f,
+        translation: Vector::new(0.0, 0.0),
+        viewport: None,
+        scrollable: None,
+    }
+}
+
+fn update<T>(
+    target: Id,
+    f: impl Fn(&mut State) + 'static,
+) -> impl Operation<T> {
+    visit(move |id, state, _bounds| {
+        if id == &target.0 {
+            f(state);
+        }
+    })
+}
@@ -1,6 +1,7 @@
 //! Build window-based GUI applications.
 mod action;
 
+pub mod drag_area;
 pub mod screenshot;
 
 pub use action::Action;
@@ -8,7 +9,10 @@ pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
 use crate::core::time::Instant;
-use crate::core::window::{Event, Icon, Level, Mode, UserAttention};
+use crate::core::window::{
+    CursorGrab, Direction, Event, Icon, Level, Mode, Monitor, Progress,
+    UserAttention,
+};
 use crate::core::Size;
 use crate::futures::event;
 use crate::futures::Subscription;
@@ -28,6 +32,31 @@ pub fn frames() -> Subscription<Instant> {
     })
 }
 
+/// Subscribes to the changes in the placement of the window of the running
+/// application.
+///
+/// The resulting [`Subscription`] produces the [`Event`]s of the window
+/// being moved, resized, maximized, minimized, restored, moved to another
+/// monitor, changing its [`Mode`], or changing its zoom. Listening to
+/// [`Event::ResizeEnded`] instead of [`Event::Resized`] is useful to persist
+/// the geometry of the window without doing so for every intermediate size.
+pub fn placement() -> Subscription<Event> {
+    event::listen_raw(|event, _status| match event {
+        iced_core::Event::Window(
+            event @ (Event::Moved { .. }
+            | Event::Resized { .. }
+            | Event::ResizeEnded { .. }
+            | Event::Maximized
+            | Event::Minimized
+            | Event::Restored
+            | Event::MonitorChanged { .. }
+            | Event::ModeChanged(_)
+            | Event::ZoomChanged(_)),
+        ) => Some(event),
+        _ => None,
+    })
+}
+
 /// Closes the current window and exits the application.
 pub fn close<Message>() -> Command<Message> {
     Command::single(command::Action::Window(Action::Close))
@@ -38,6 +67,12 @@ pub fn drag<Message>() -> Command<Message> {
     Command::single(command::Action::Window(Action::Drag))
 }
 
+/// Begins resizing the window from the given edge or corner while the left
+/// mouse button is held.
+pub fn drag_resize<Message>(direction: Direction) -> Command<Message> {
+    Command::single(command::Action::Window(Action::DragResize(direction)))
+}
+
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(new_size: Size<u32>) -> Command<Message> {
     Command::single(command::Action::Window(Action::Resize(new_size)))
@@ -65,6 +100,20 @@ pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
     Command::single(command::Action::Window(Action::Move { x, y }))
 }
 
+/// Moves the window to the given [`Monitor`], centering it.
+///
+/// A fullscreen window stays fullscreen in the new [`Monitor`].
+pub fn move_to_monitor<Message>(monitor: Monitor) -> Command<Message> {
+    Command::single(command::Action::Window(Action::MoveToMonitor(monitor)))
+}
+
+/// Fetches the monitors connected to the system.
+pub fn monitors<Message>(
+    f: impl FnOnce(Vec<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchMonitors(Box::new(f))))
+}
+
 /// Changes the [`Mode`] of the window.
 pub fn change_mode<Message>(mode: Mode) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeMode(mode)))
@@ -116,6 +165,33 @@ pub fn change_level<Message>(level: Level) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeLevel(level)))
 }
 
+/// Keeps the window above all the other windows, or back to normal.
+///
+/// This is a shorthand for changing the window [`Level`].
+pub fn always_on_top<Message>(is_on_top: bool) -> Command<Message> {
+    change_level(if is_on_top {
+        Level::AlwaysOnTop
+    } else {
+        Level::Normal
+    })
+}
+
+/// Shows or hides the taskbar entry of the window.
+///
+/// Hiding it is useful for applications living in the system tray.
+pub fn skip_taskbar<Message>(skip: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SkipTaskbar(skip)))
+}
+
+/// Changes the [`Progress`] shown in the taskbar entry of the window.
+///
+/// Providing `None` removes it.
+pub fn change_progress<Message>(
+    progress: Option<Progress>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeProgress(progress)))
+}
+
 /// Fetches an identifier unique to the window.
 pub fn fetch_id<Message>(
     f: impl FnOnce(u64) -> Message + 'static,
@@ -123,11 +199,36 @@ pub fn fetch_id<Message>(
     Command::single(command::Action::Window(Action::FetchId(Box::new(f))))
 }
 
+/// Changes the zoom of the user interface, in percent.
+///
+/// The zoom is applied on top of the scale factor of the window, so
+/// `100` leaves the user interface as is.
+pub fn change_zoom<Message>(percent: u32) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeZoom(percent)))
+}
+
+/// Fetches the current zoom of the user interface, in percent.
+pub fn fetch_zoom<Message>(
+    f: impl FnOnce(u32) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchZoom(Box::new(f))))
+}
+
 /// Changes the [`Icon`] of the window.
 pub fn change_icon<Message>(icon: Icon) -> Command<Message> {
     Command::single(command::Action::Window(Action::ChangeIcon(icon)))
 }
 
+/// Changes how the cursor is held inside of the window.
+///
+/// Locking the cursor and listening to [`mouse::Event::RelativeMotion`]
+/// enables looking around in 3D viewports.
+///
+/// [`mouse::Event::RelativeMotion`]: crate::core::mouse::Event::RelativeMotion
+pub fn grab_cursor<Message>(grab: CursorGrab) -> Command<Message> {
+    Command::single(command::Action::Window(Action::GrabCursor(grab)))
+}
+
 /// Captures a [`Screenshot`] from the window.
 pub fn screenshot<Message>(
     f: impl FnOnce(Screenshot) -> Message + Send + 'static,
@@ -1,4 +1,6 @@
-use crate::core::window::{Icon, Level, Mode, UserAttention};
+use crate::core::window::{
+    CursorGrab, Direction, Icon, Level, Mode, Monitor, Progress, UserAttention,
+};
 use crate::core::Size;
 use crate::futures::MaybeSend;
 use crate::window::Screenshot;
@@ -15,6 +17,12 @@ pub enum Action<T> {
     /// There’s no guarantee that this will work unless the left mouse
     /// button was pressed immediately before this function is called.
     Drag,
+    /// Resize the window from the given edge or corner with the left mouse
+    /// button until the button is released.
+    ///
+    /// There’s no guarantee that this will work unless the left mouse
+    /// button was pressed immediately before this function is called.
+    DragResize(Direction),
     /// Resize the window.
     Resize(Size<u32>),
     /// Fetch the current size of the window.
@@ -32,6 +40,12 @@ pub enum Action<T> {
         /// The new logical y location of the window
         y: i32,
     },
+    /// Move the window to the given [`Monitor`], centering it.
+    ///
+    /// A fullscreen window stays fullscreen in the new [`Monitor`].
+    MoveToMonitor(Monitor),
+    /// Fetch the monitors connected to the system.
+    FetchMonitors(Box<dyn FnOnce(Vec<Monitor>) -> T + 'static>),
     /// Change the [`Mode`] of the window.
     ChangeMode(Mode),
     /// Fetch the current [`Mode`] of the window.
@@ -71,6 +85,16 @@ pub enum Action<T> {
     GainFocus,
     /// Change the window [`Level`].
     ChangeLevel(Level),
+    /// Show or hide the taskbar entry of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS / Linux / Web:** Unsupported.
+    SkipTaskbar(bool),
+    /// Change the [`Progress`] shown in the taskbar entry of the window.
+    ///
+    /// Providing `None` removes it.
+    ChangeProgress(Option<Progress>),
     /// Fetch an identifier unique to the window.
     FetchId(Box<dyn FnOnce(u64) -> T + 'static>),
     /// Change the window [`Icon`].
@@ -88,8 +112,22 @@ pub enum Action<T> {
     /// - **X11:** Has no universal guidelines for icon sizes, so you're at the whims of the WM. That
     ///   said, it's usually in the same ballpark as on Windows.
     ChangeIcon(Icon),
+    /// Change how the cursor is held inside of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** [`CursorGrab::Confined`] locks the cursor instead.
+    /// - **Windows / X11:** [`CursorGrab::Locked`] confines the cursor instead.
+    /// - **iOS / Android:** Unsupported.
+    GrabCursor(CursorGrab),
     /// Screenshot the viewport of the window.
     Screenshot(Box<dyn FnOnce(Screenshot) -> T + 'static>),
+    /// Change the zoom of the user interface, in percent.
+    ///
+    /// It is applied on top of the scale factor of the window.
+    ChangeZoom(u32),
+    /// Fetch the current zoom of the user interface, in percent.
+    FetchZoom(Box<dyn FnOnce(u32) -> T + 'static>),
 }
 
 impl<T> Action<T> {
@@ -104,11 +142,16 @@ impl<T> Action<T> {
         match self {
             Self::Close => Action::Close,
             Self::Drag => Action::Drag,
+            Self::DragResize(direction) => Action::DragResize(direction),
             Self::Resize(size) => Action::Resize(size),
             Self::FetchSize(o) => Action::FetchSize(Box::new(move |s| f(o(s)))),
             Self::Maximize(maximized) => Action::Maximize(maximized),
             Self::Minimize(minimized) => Action::Minimize(minimized),
             Self::Move { x, y } => Action::Move { x, y },
+            Self::MoveToMonitor(monitor) => Action::MoveToMonitor(monitor),
+            Self::FetchMonitors(o) => {
+                Action::FetchMonitors(Box::new(move |s| f(o(s))))
+            }
             Self::ChangeMode(mode) => Action::ChangeMode(mode),
             Self::FetchMode(o) => Action::FetchMode(Box::new(move |s| f(o(s)))),
             Self::ToggleMaximize => Action::ToggleMaximize,
@@ -118,13 +161,18 @@ impl<T> Action<T> {
             }
             Self::GainFocus => Action::GainFocus,
             Self::ChangeLevel(level) => Action::ChangeLevel(level),
+            Self::SkipTaskbar(skip) => Action::SkipTaskbar(skip),
+            Self::ChangeProgress(progress) => Action::ChangeProgress(progress),
             Self::FetchId(o) => Action::FetchId(Box::new(move |s| f(o(s)))),
             Self::ChangeIcon(icon) => Action::ChangeIcon(icon),
+            Self::GrabCursor(grab) => Action::GrabCursor(grab),
             Self::Screenshot(tag) => {
                 Action::Screenshot(Box::new(move |screenshot| {
                     f(tag(screenshot))
                 }))
             }
+            Self::ChangeZoom(percent) => Action::ChangeZoom(percent),
+            Self::FetchZoom(o) => Action::FetchZoom(Box::new(move |s| f(o(s)))),
         }
     }
 }
@@ -134,6 +182,9 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Close => write!(f, "Action::Close"),
             Self::Drag => write!(f, "Action::Drag"),
+            Self::DragResize(direction) => {
+                write!(f, "Action::DragResize({direction:?})")
+            }
             Self::Resize(size) => write!(f, "Action::Resize({size:?})"),
             Self::FetchSize(_) => write!(f, "Action::FetchSize"),
             Self::Maximize(maximized) => {
@@ -145,6 +196,10 @@ impl<T> fmt::Debug for Action<T> {
             Self::Move { x, y } => {
                 write!(f, "Action::Move {{ x: {x}, y: {y} }}")
             }
+            Self::MoveToMonitor(monitor) => {
+                write!(f, "Action::MoveToMonitor({monitor:?})")
+            }
+            Self::FetchMonitors(_) => write!(f, "Action::FetchMonitors"),
             Self::ChangeMode(mode) => write!(f, "Action::SetMode({mode:?})"),
             Self::FetchMode(_) => write!(f, "Action::FetchMode"),
             Self::ToggleMaximize => write!(f, "Action::ToggleMaximize"),
@@ -156,11 +211,24 @@ impl<T> fmt::Debug for Action<T> {
             Self::ChangeLevel(level) => {
                 write!(f, "Action::ChangeLevel({level:?})")
             }
+            Self::SkipTaskbar(skip) => {
+                write!(f, "Action::SkipTaskbar({skip})")
+            }
+            Self::ChangeProgress(progress) => {
+                write!(f, "Action::ChangeProgress({progress:?})")
+            }
             Self::FetchId(_) => write!(f, "Action::FetchId"),
             Self::ChangeIcon(_icon) => {
                 write!(f, "Action::ChangeIcon(icon)")
             }
+            Self::GrabCursor(grab) => {
+                write!(f, "Action::GrabCursor({grab:?})")
+            }
             Self::Screenshot(_) => write!(f, "Action::Screenshot"),
+            Self::ChangeZoom(percent) => {
+                write!(f, "Action::ChangeZoom({percent})")
+            }
+            Self::FetchZoom(_) => write!(f, "Action::FetchZoom"),
         }
     }
 }
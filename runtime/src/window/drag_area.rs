@@ -0,0 +1,75 @@
+//! Mark the regions of a window that move or resize it.
+//!
+//! Frameless windows have no title bar or borders to grab. Instead, widgets
+//! can report [`Region`]s while operating, and the shell moves or resizes the
+//! window when one of them is pressed and no widget captures the press.
+use crate::core::widget::{self, Operation};
+use crate::core::window::Direction;
+use crate::core::{Point, Rectangle};
+
+use std::any::Any;
+
+/// What happens when a [`Region`] is pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// The window is moved, like when dragging its title bar.
+    ///
+    /// Double-clicking it toggles whether the window is maximized.
+    Move,
+    /// The window is resized, like when dragging one of its borders.
+    Resize(Direction),
+}
+
+/// A region of a window that moves or resizes it when pressed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Region {
+    /// The [`Kind`] of the [`Region`].
+    pub kind: Kind,
+    /// The bounds of the [`Region`].
+    pub bounds: Rectangle,
+}
+
+/// Produces an [`Operation`] that finds the [`Kind`] of the topmost
+/// [`Region`] containing the given position.
+///
+/// Regions reported later while operating are considered to be on top.
+pub fn find(position: Point) -> Find {
+    Find {
+        position,
+        kind: None,
+    }
+}
+
+/// An [`Operation`] that finds the [`Kind`] of the [`Region`] at some
+/// position.
+#[derive(Debug)]
+pub struct Find {
+    position: Point,
+    kind: Option<Kind>,
+}
+
+impl Find {
+    /// Returns the [`Kind`] of the topmost [`Region`] found, if any.
+    pub fn kind(&self) -> Option<Kind> {
+        self.kind
+    }
+}
+
+impl<T> Operation<T> for Find {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&widget::Id>) {
+        if let Some(region) = state.downcast_ref::<Region>() {
+            if region.bounds.contains(self.position) {
+                self.kind = Some(region.kind);
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
 //! Build interactive cross-platform applications.
+use crate::menu::Menu;
+use crate::report::Report;
+use crate::tray::Tray;
 use crate::{Command, Element, Executor, Settings, Subscription};
 
 pub use crate::style::application::{Appearance, StyleSheet};
@@ -18,7 +21,8 @@ pub use crate::style::application::{Appearance, StyleSheet};
 /// interface.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`, and a performance overlay by pressing
+/// `Ctrl+Shift+P`.
 ///
 /// # Examples
 /// [The repository has a bunch of examples] that use the [`Application`] trait:
@@ -167,6 +171,40 @@ pub trait Application: Sized {
         Subscription::none()
     }
 
+    /// Returns the native menu bar of the [`Application`], if any.
+    ///
+    /// Its top-level items should be submenus, and their actions produce
+    /// messages handled by [`update`](#tymethod.update). The native menu is
+    /// kept in sync with it, so items can be enabled, disabled, and checked
+    /// dynamically. It is only shown when the `menu` feature is enabled.
+    ///
+    /// By default, it returns `None`.
+    fn menu(&self) -> Option<Menu<Self::Message>> {
+        None
+    }
+
+    /// Returns the [`Tray`] of the [`Application`], if any.
+    ///
+    /// The icon in the system tray is kept in sync with it, like the title
+    /// of the window. It is only shown when the `tray` feature is enabled.
+    ///
+    /// By default, it returns `None`.
+    fn tray(&self) -> Option<Tray<Self::Message>> {
+        None
+    }
+
+    /// Reports a panic in the `view`, `update`, or `subscription` of the
+    /// [`Application`], producing the message that handles it.
+    ///
+    /// While the `view` panics, a fallback screen describing the [`Report`]
+    /// is shown instead. This way, kiosk deployments can show an error and
+    /// recover instead of aborting.
+    ///
+    /// By default, it returns `None` and the panic is not caught.
+    fn report(&self, _report: Report) -> Option<Self::Message> {
+        None
+    }
+
     /// Returns the scale factor of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -202,6 +240,8 @@ pub trait Application: Sized {
             } else {
                 None
             },
+            image_cache_budget: settings.image_cache_budget,
+            svg_size_bucket: settings.svg_size_bucket,
             ..crate::renderer::Settings::default()
         };
 
@@ -259,6 +299,18 @@ where
         self.0.subscription()
     }
 
+    fn menu(&self) -> Option<Menu<Self::Message>> {
+        self.0.menu()
+    }
+
+    fn tray(&self) -> Option<Tray<Self::Message>> {
+        self.0.tray()
+    }
+
+    fn report(&self, report: Report) -> Option<Self::Message> {
+        self.0.report(report)
+    }
+
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
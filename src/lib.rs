@@ -173,29 +173,53 @@ mod sandbox;
 
 pub mod application;
 pub mod settings;
+pub mod theme;
 pub mod time;
 pub mod window;
 
+#[cfg(feature = "serde")]
+pub mod persistence;
+
 #[cfg(feature = "advanced")]
 pub mod advanced;
 
-pub use style::theme;
-
 pub use crate::core::alignment;
 pub use crate::core::gradient;
 pub use crate::core::{
     color, Alignment, Background, BorderRadius, Color, ContentFit, Degrees,
-    Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Size, Vector,
+    Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Shadow, Size,
+    Vector,
 };
 pub use crate::runtime::Command;
 
 pub mod clipboard {
     //! Access the clipboard.
-    pub use crate::runtime::clipboard::{read, write};
+    //!
+    //! Images, HTML, and lists of files need the `rich-clipboard` feature.
+    pub use crate::runtime::clipboard::{
+        read, read_files, read_html, read_image, write, write_files,
+        write_html, write_image,
+    };
+}
+
+#[cfg(all(feature = "dialog", not(target_arch = "wasm32")))]
+pub mod dialog {
+    //! Show native file dialogs.
+    pub use crate::runtime::dialog::{
+        open_file, open_files, pick_folder, save_file, FileDialog, Filter,
+    };
+}
+
+pub mod dnd {
+    //! Drag data out of the application.
+    pub use crate::runtime::dnd::{
+        start_drag, Drag, Outcome, Payload, Preview,
+    };
 }
 
 pub mod executor {
     //! Choose your preferred executor to power your application.
+    pub use iced_futures::executor::{Priority, Settings};
     pub use iced_futures::Executor;
 
     /// A default cross-platform executor.
@@ -223,17 +247,84 @@ pub mod event {
     pub use iced_futures::event::{listen, listen_raw, listen_with};
 }
 
+pub mod gamepad {
+    //! Listen and react to gamepad input.
+    pub use crate::runtime::gamepad::{navigate, Axis, Button, Event, Id};
+
+    #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+    pub use crate::shell::gamepad::events;
+}
+
+pub mod history {
+    //! Undo and redo the changes of an application.
+    pub use crate::runtime::history::{
+        bind, redo_shortcut, undo_shortcut, Edit, History,
+    };
+}
+
+pub mod i18n {
+    //! Translate and localize your application.
+    pub use crate::runtime::i18n::{locale, switch, Direction, Locale};
+
+    #[cfg(feature = "i18n")]
+    pub use crate::runtime::i18n::{Args, Error, Localization};
+}
+
+pub mod idle {
+    //! Detect when the user stops interacting with the application.
+    pub use iced_futures::backend::default::idle::*;
+    pub use iced_futures::idle::{is_activity, Event};
+}
+
 pub mod keyboard {
     //! Listen and react to keyboard events.
-    pub use crate::core::keyboard::{Event, KeyCode, Modifiers};
+    pub use crate::core::keyboard::{Event, KeyCode, Modifiers, Repeat};
     pub use iced_futures::keyboard::{on_key_press, on_key_release};
 }
 
+pub mod keymap {
+    //! Declare keyboard shortcuts and map them to messages.
+    pub use iced_futures::keymap::{
+        Binding, Chord, Conflict, Keymap, ParseError, RebindError, Shortcut,
+    };
+}
+
+pub mod loader {
+    //! Load resources asynchronously, by priority and with limited concurrency.
+    pub use crate::runtime::loader::{Event, Loader, Priority, Progress};
+}
+
+pub mod menu {
+    //! Describe native menus.
+    pub use crate::runtime::menu::{Action, Item, Menu};
+}
+
 pub mod mouse {
     //! Listen and react to mouse events.
     pub use crate::core::mouse::{
         Button, Cursor, Event, Interaction, ScrollDelta,
     };
+    pub use iced_futures::mouse::on_motion;
+}
+
+pub mod pen {
+    //! Listen and react to pen events.
+    pub use crate::core::pen::{Event, State, Tilt, Tool};
+}
+
+pub mod query {
+    //! Query the live widget tree of an application.
+    pub use crate::runtime::query::{bounds, find_all, Match, Selector};
+}
+
+pub mod recording {
+    //! Record the messages of an application and replay them later.
+    pub use crate::runtime::recording::{Entry, Recording};
+}
+
+pub mod report {
+    //! Catch the panics of an application and describe them.
+    pub use crate::runtime::report::{Phase, Report};
 }
 
 pub mod subscription {
@@ -245,11 +336,16 @@ pub mod subscription {
 
 #[cfg(feature = "system")]
 pub mod system {
-    //! Retrieve system information.
-    pub use crate::runtime::system::Information;
+    //! Retrieve system information and listen to system events.
+    pub use crate::runtime::system::{Appearance, Event, Information};
     pub use crate::shell::system::*;
 }
 
+pub mod notification {
+    //! Show desktop notifications.
+    pub use crate::runtime::notification::{show, Notification};
+}
+
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
@@ -264,12 +360,22 @@ pub mod overlay {
     pub use iced_widget::overlay::*;
 }
 
+pub mod token {
+    //! Share the spacing, radii, and text sizes of an application.
+    pub use crate::core::token::*;
+}
+
 pub mod touch {
     //! Listen and react to touch events.
-    pub use crate::core::touch::{Event, Finger};
+    pub use crate::core::touch::{Event, Finger, Gesture};
 }
 
 #[allow(hidden_glob_reexports)]
+pub mod tray {
+    //! Show an icon in the system tray.
+    pub use crate::runtime::tray::Tray;
+}
+
 pub mod widget {
     //! Use the built-in widgets or create your own.
     pub use iced_widget::*;
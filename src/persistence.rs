@@ -0,0 +1,297 @@
+//! Save the state of windows and widgets and restore it at startup.
+//!
+//! A [`Profile`] keeps the geometry and zoom of the window, the layouts of
+//! pane grids—including the position of their splits—and the offsets of
+//! scrollables. It can be saved to a file when the application exits and
+//! loaded back the next time it starts.
+//!
+//! Only scrollables with a custom [`scrollable::Id`] are remembered, since
+//! unique identifiers change every run.
+use crate::core::widget::operation::{self, Operation, Outcome};
+use crate::core::widget::Id;
+use crate::core::{Rectangle, Vector};
+use crate::widget::pane_grid;
+use crate::widget::scrollable::{self, AbsoluteOffset};
+use crate::window;
+use crate::Command;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The saved state of the windows and widgets of an application.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(crate = "serde", default)]
+pub struct Profile {
+    /// The [`Geometry`] of the window, if known.
+    pub window: Option<Geometry>,
+
+    /// The zoom of the user interface, in percent, if it was changed.
+    pub zoom: Option<u32>,
+
+    /// The layouts of pane grids, by name.
+    pub pane_grids: BTreeMap<String, pane_grid::Node>,
+
+    /// The offsets of scrollables, by the name of their [`scrollable::Id`].
+    pub scroll_offsets: BTreeMap<String, AbsoluteOffset>,
+}
+
+/// The position and size of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "serde")]
+pub struct Geometry {
+    /// The logical x position of the window.
+    pub x: i32,
+
+    /// The logical y position of the window.
+    pub y: i32,
+
+    /// The logical width of the window.
+    pub width: u32,
+
+    /// The logical height of the window.
+    pub height: u32,
+
+    /// Whether the window is maximized.
+    ///
+    /// The rest of the [`Geometry`] describes the window once restored.
+    pub is_maximized: bool,
+
+    #[serde(skip)]
+    is_minimized: bool,
+}
+
+impl Geometry {
+    /// Creates the [`Geometry`] of a restored window with the given logical
+    /// position and size.
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            is_maximized: false,
+            is_minimized: false,
+        }
+    }
+
+    /// Sets whether the window is maximized.
+    pub fn maximized(mut self, is_maximized: bool) -> Self {
+        self.is_maximized = is_maximized;
+        self
+    }
+}
+
+/// An error that occurred while loading or saving a [`Profile`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The profile file could not be read or written.
+    #[error("the profile file could not be accessed: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The contents of the profile file are not valid.
+    #[error("the profile file is invalid: {0}")]
+    Format(Arc<serde_json::Error>),
+}
+
+impl Profile {
+    /// Loads a [`Profile`] from the file at the given path.
+    ///
+    /// A missing file produces an empty [`Profile`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = match std::fs::read(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::default());
+            }
+            Err(error) => return Err(Error::Io(Arc::new(error))),
+        };
+
+        serde_json::from_slice(&contents)
+            .map_err(|error| Error::Format(Arc::new(error)))
+    }
+
+    /// Saves the [`Profile`] to the file at the given path, creating its
+    /// parent directories if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        let contents = serde_json::to_vec_pretty(self)
+            .map_err(|error| Error::Format(Arc::new(error)))?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|error| Error::Io(Arc::new(error)))?;
+        }
+
+        std::fs::write(path, contents)
+            .map_err(|error| Error::Io(Arc::new(error)))
+    }
+
+    /// Updates the [`Geometry`] and the zoom of the window with the given
+    /// [`window::Event`].
+    ///
+    /// The events of [`window::placement`] are enough to keep track of the
+    /// window.
+    pub fn track(&mut self, event: &window::Event) {
+        if let window::Event::ZoomChanged(percent) = *event {
+            self.zoom = Some(percent);
+
+            return;
+        }
+
+        let geometry = self.window.get_or_insert(Geometry::new(0, 0, 0, 0));
+
+        match *event {
+            // A maximized or minimized window keeps its restored geometry
+            window::Event::Moved { x, y }
+                if !geometry.is_maximized && !geometry.is_minimized =>
+            {
+                geometry.x = x;
+                geometry.y = y;
+            }
+            window::Event::Resized { width, height }
+                if !geometry.is_maximized && !geometry.is_minimized =>
+            {
+                geometry.width = width;
+                geometry.height = height;
+            }
+            window::Event::Maximized => {
+                geometry.is_maximized = true;
+                geometry.is_minimized = false;
+            }
+            window::Event::Minimized => {
+                geometry.is_minimized = true;
+            }
+            window::Event::Restored => {
+                geometry.is_maximized = false;
+                geometry.is_minimized = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies the saved [`Geometry`] of the window to the given
+    /// [`window::Settings`].
+    pub fn window_settings(
+        &self,
+        settings: window::Settings,
+    ) -> window::Settings {
+        match self.window {
+            Some(geometry) if geometry.width > 0 && geometry.height > 0 => {
+                window::Settings {
+                    size: (geometry.width, geometry.height),
+                    position: window::Position::Specific(
+                        geometry.x, geometry.y,
+                    ),
+                    ..settings
+                }
+            }
+            _ => settings,
+        }
+    }
+
+    /// Saves the layout of the given [`pane_grid::State`] with the given
+    /// name.
+    pub fn save_pane_grid<T>(
+        &mut self,
+        name: impl Into<String>,
+        state: &pane_grid::State<T>,
+    ) {
+        let _ = self.pane_grids.insert(name.into(), state.layout().clone());
+    }
+
+    /// Restores the [`pane_grid::State`] saved with the given name,
+    /// initializing the state of each of its panes with the provided
+    /// function.
+    pub fn pane_grid<T>(
+        &self,
+        name: &str,
+        f: impl FnMut(pane_grid::Pane) -> T,
+    ) -> Option<pane_grid::State<T>> {
+        let layout = self.pane_grids.get(name)?;
+
+        Some(pane_grid::State::with_layout(layout.clone(), f))
+    }
+
+    /// Produces a [`Command`] that restores the saved offsets of the
+    /// scrollables and the zoom, and maximizes the window, if it was
+    /// maximized.
+    ///
+    /// It should be returned when the application starts.
+    pub fn restore<Message: 'static>(&self) -> Command<Message> {
+        let scroll_offsets =
+            self.scroll_offsets.iter().map(|(name, offset)| {
+                scrollable::scroll_to(
+                    scrollable::Id::new(name.clone()),
+                    *offset,
+                )
+            });
+
+        let maximize = self
+            .window
+            .filter(|geometry| geometry.is_maximized)
+            .map(|_| window::maximize(true));
+
+        let zoom = self.zoom.map(window::change_zoom);
+
+        Command::batch(scroll_offsets.chain(maximize).chain(zoom))
+    }
+}
+
+/// Produces a [`Command`] that fetches the offsets of the scrollables with
+/// a custom [`scrollable::Id`], keyed by its name.
+///
+/// The offsets can be stored in [`Profile::scroll_offsets`].
+pub fn scroll_offsets<Message: 'static>(
+    f: impl Fn(BTreeMap<String, AbsoluteOffset>) -> Message + 'static,
+) -> Command<Message> {
+    struct ScrollOffsets<F> {
+        offsets: BTreeMap<String, AbsoluteOffset>,
+        f: F,
+    }
+
+    impl<F, Message> Operation<Message> for ScrollOffsets<F>
+    where
+        F: Fn(BTreeMap<String, AbsoluteOffset>) -> Message,
+    {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Message>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn operation::Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            translation: Vector,
+        ) {
+            if let Some(name) = id.and_then(Id::name) {
+                let _ = self.offsets.insert(
+                    name.to_owned(),
+                    AbsoluteOffset {
+                        x: translation.x,
+                        y: translation.y,
+                    },
+                );
+            }
+        }
+
+        fn finish(&self) -> Outcome<Message> {
+            Outcome::Some((self.f)(self.offsets.clone()))
+        }
+    }
+
+    Command::widget(ScrollOffsets {
+        offsets: BTreeMap::new(),
+        f,
+    })
+}
@@ -1,4 +1,7 @@
 //! Configure your application.
+use crate::executor;
+use crate::keyboard;
+use crate::time::Duration;
 use crate::window;
 use crate::{Font, Pixels};
 
@@ -42,6 +45,26 @@ pub struct Settings<Flags> {
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
 
+    /// The memory budget of the cache of raster images, in bytes.
+    ///
+    /// Without a budget, the images that are not drawn during a frame are
+    /// evicted right away. With a budget, they are kept until the budget is
+    /// exceeded—so scrolling back to them is instant—and then the least
+    /// recently drawn ones are evicted first.
+    ///
+    /// By default, there is no budget.
+    pub image_cache_budget: Option<usize>,
+
+    /// The size step of the rasters of vector graphics, in physical pixels.
+    ///
+    /// With a step, the rasters of SVGs are rounded up to a multiple of it;
+    /// so they are reused while a window is resized, instead of rasterized
+    /// again on every frame. Bigger steps cause less hitches, but use more
+    /// memory.
+    ///
+    /// By default, there is no step.
+    pub svg_size_bucket: Option<u32>,
+
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
@@ -49,6 +72,36 @@ pub struct Settings<Flags> {
     ///
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
+
+    /// How the keys held down by the user repeat.
+    ///
+    /// By default, they repeat as configured in the system.
+    pub key_repeat: keyboard::Repeat,
+
+    /// How long it takes to fade between themes when the theme of the
+    /// [`Application`] changes.
+    ///
+    /// By default, it takes 200 milliseconds. A zero [`Duration`] switches
+    /// themes immediately.
+    ///
+    /// [`Application`]: crate::Application
+    pub theme_transition: Duration,
+
+    /// Whether the user can zoom the user interface with `Ctrl` and `+`,
+    /// `-`, `0`, or the mouse wheel, like in a web browser.
+    ///
+    /// The zoom is applied on top of the scale factor of the window, and
+    /// it can be persisted by listening to [`window::Event::ZoomChanged`]
+    /// and restored with [`window::change_zoom`].
+    ///
+    /// By default, it is disabled.
+    pub zoom_shortcuts: bool,
+
+    /// The configuration of the executor running the futures of the
+    /// [`Application`], like its amount of threads.
+    ///
+    /// [`Application`]: crate::Application
+    pub executor: executor::Settings,
 }
 
 impl<Flags> Settings<Flags> {
@@ -65,7 +118,13 @@ impl<Flags> Settings<Flags> {
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
             antialiasing: default_settings.antialiasing,
+            image_cache_budget: default_settings.image_cache_budget,
+            svg_size_bucket: default_settings.svg_size_bucket,
             exit_on_close_request: default_settings.exit_on_close_request,
+            key_repeat: default_settings.key_repeat,
+            theme_transition: default_settings.theme_transition,
+            zoom_shortcuts: default_settings.zoom_shortcuts,
+            executor: default_settings.executor,
         }
     }
 }
@@ -82,7 +141,13 @@ where
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            image_cache_budget: None,
+            svg_size_bucket: None,
             exit_on_close_request: true,
+            key_repeat: keyboard::Repeat::default(),
+            theme_transition: Duration::from_millis(200),
+            zoom_shortcuts: false,
+            executor: executor::Settings::default(),
         }
     }
 }
@@ -94,6 +159,10 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window.into(),
             flags: settings.flags,
             exit_on_close_request: settings.exit_on_close_request,
+            key_repeat: settings.key_repeat,
+            theme_transition: settings.theme_transition,
+            zoom_shortcuts: settings.zoom_shortcuts,
+            executor: settings.executor,
         }
     }
 }
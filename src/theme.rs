@@ -0,0 +1,121 @@
+//! Use the built-in theme and styles.
+pub use crate::style::theme::*;
+
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// An error produced when loading a theme file or a style sheet.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read.
+    #[error("the file could not be read: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The contents of the file are not valid.
+    #[error("the file is invalid: {0}")]
+    Invalid(file::Error),
+}
+
+/// Loads a [`Theme`] from the file at the given path.
+///
+/// See the [`file`] module to learn about the format of theme files.
+pub fn load(path: impl AsRef<Path>) -> Result<Theme, Error> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| Error::Io(Arc::new(error)))?;
+
+    file::parse(&contents).map_err(Error::Invalid)
+}
+
+/// Loads a [`Sheet`] from the file at the given path.
+///
+/// See the [`sheet`] module to learn about the format of style sheets.
+pub fn load_sheet(path: impl AsRef<Path>) -> Result<Sheet, Error> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| Error::Io(Arc::new(error)))?;
+
+    Sheet::parse(&contents).map_err(Error::Invalid)
+}
+
+/// Watches the theme file at the given path, producing the loaded [`Theme`]
+/// right away and every time the file changes.
+///
+/// Errors are produced too, so they can be shown while the file is being
+/// edited. Returning the [`Theme`] from the `theme` method of an
+/// application restyles it without recompiling.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch(
+    path: impl Into<std::path::PathBuf>,
+) -> crate::Subscription<Result<Theme, Error>> {
+    watch_file(path.into(), |path| load(path))
+}
+
+/// Watches the style sheet at the given path, producing the loaded
+/// [`Sheet`] right away and every time the file changes.
+///
+/// Setting the [`Sheet`] in the [`Theme`] of an application with
+/// [`Theme::with_sheet`] restyles the widgets with a class without
+/// recompiling.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch_sheet(
+    path: impl Into<std::path::PathBuf>,
+) -> crate::Subscription<Result<Sheet, Error>> {
+    watch_file(path.into(), |path| load_sheet(path))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn watch_file<T: Send + 'static>(
+    path: std::path::PathBuf,
+    load: fn(&Path) -> Result<T, Error>,
+) -> crate::Subscription<Result<T, Error>> {
+    use crate::futures::channel::mpsc;
+    use crate::futures::{SinkExt, StreamExt};
+    use crate::time::Duration;
+
+    /// How often the modification time of the file is checked.
+    const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+    crate::subscription::channel(
+        (path.clone(), std::any::TypeId::of::<T>()),
+        1,
+        move |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            // Polling the file blocks, so it is done in its own thread
+            let _ = std::thread::spawn(move || {
+                let mut last_modified = None;
+                let mut is_first = true;
+
+                loop {
+                    let modified = std::fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok();
+
+                    if is_first || modified != last_modified {
+                        is_first = false;
+                        last_modified = modified;
+
+                        if sender.unbounded_send(load(&path)).is_err() {
+                            break;
+                        }
+                    }
+
+                    std::thread::sleep(POLL_INTERVAL);
+
+                    if sender.is_closed() {
+                        break;
+                    }
+                }
+            });
+
+            loop {
+                match receiver.next().await {
+                    Some(result) => {
+                        let _ = output.send(result).await;
+                    }
+                    None => crate::futures::future::pending::<()>().await,
+                }
+            }
+        },
+    )
+}
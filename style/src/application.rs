@@ -1,4 +1,5 @@
 //! Change the appearance of an application.
+use iced_core::token::Tokens;
 use iced_core::Color;
 
 /// A set of rules that dictate the style of an application.
@@ -10,6 +11,28 @@ pub trait StyleSheet {
     ///
     /// [`Style`]: Self::Style
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Returns the theme found at `t` between `self` and `other`, where `0.0`
+    /// is `self` and `1.0` is `other`.
+    ///
+    /// Applications use it to fade between themes when their theme changes.
+    /// By default, themes cannot be interpolated and `None` is returned, so
+    /// they are switched immediately.
+    fn interpolate(&self, _other: &Self, _t: f32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Returns the design [`Tokens`] of the theme, if any.
+    ///
+    /// Applications make them the current [`Tokens`] whenever their theme
+    /// changes, so the built-in widgets pick them up. By default, `None` is
+    /// returned and the current [`Tokens`] are left untouched.
+    fn tokens(&self) -> Option<Tokens> {
+        None
+    }
 }
 
 /// The appearance of an application.
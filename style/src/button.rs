@@ -1,5 +1,5 @@
 //! Change the apperance of a button.
-use iced_core::{Background, BorderRadius, Color, Vector};
+use iced_core::{Background, BorderRadius, Color, Shadow, Vector};
 
 /// The appearance of a button.
 #[derive(Debug, Clone, Copy)]
@@ -16,6 +16,8 @@ pub struct Appearance {
     pub border_color: Color,
     /// The text [`Color`] of the button.
     pub text_color: Color,
+    /// The [`Shadow`] of the button.
+    pub shadow: Shadow,
 }
 
 impl std::default::Default for Appearance {
@@ -27,10 +29,90 @@ impl std::default::Default for Appearance {
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             text_color: Color::BLACK,
+            shadow: Shadow::default(),
         }
     }
 }
 
+impl Appearance {
+    /// Returns the [`Appearance`] found at `t` between `self` and `other`,
+    /// where `0.0` is `self` and `1.0` is `other`.
+    ///
+    /// Colors fade into each other, and a missing background fades from or
+    /// to transparent. Gradients cannot be blended, so they switch halfway.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let border_radius: [f32; 4] = self.border_radius.into();
+        let other_border_radius: [f32; 4] = other.border_radius.into();
+
+        Self {
+            shadow_offset: self.shadow_offset
+                + (other.shadow_offset - self.shadow_offset) * t,
+            background: mix_background(self.background, other.background, t),
+            border_radius: [0, 1, 2, 3]
+                .map(|i| mix_f32(border_radius[i], other_border_radius[i], t))
+                .into(),
+            border_width: mix_f32(self.border_width, other.border_width, t),
+            border_color: mix(self.border_color, other.border_color, t),
+            text_color: mix(self.text_color, other.text_color, t),
+            shadow: Shadow {
+                color: mix(self.shadow.color, other.shadow.color, t),
+                offset: self.shadow.offset
+                    + (other.shadow.offset - self.shadow.offset) * t,
+                blur_radius: mix_f32(
+                    self.shadow.blur_radius,
+                    other.shadow.blur_radius,
+                    t,
+                ),
+                spread_radius: mix_f32(
+                    self.shadow.spread_radius,
+                    other.shadow.spread_radius,
+                    t,
+                ),
+            },
+        }
+    }
+}
+
+fn mix_background(
+    a: Option<Background>,
+    b: Option<Background>,
+    t: f32,
+) -> Option<Background> {
+    let transparent = |color: Color| Color { a: 0.0, ..color };
+
+    match (a, b) {
+        (Some(Background::Color(a)), Some(Background::Color(b))) => {
+            Some(Background::Color(mix(a, b, t)))
+        }
+        (Some(Background::Color(a)), None) => {
+            Some(Background::Color(mix(a, transparent(a), t)))
+        }
+        (None, Some(Background::Color(b))) => {
+            Some(Background::Color(mix(transparent(b), b, t)))
+        }
+        (a, b) => {
+            if t < 0.5 {
+                a
+            } else {
+                b
+            }
+        }
+    }
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: mix_f32(a.r, b.r, t),
+        g: mix_f32(a.g, b.g, t),
+        b: mix_f32(a.b, b.b, t),
+        a: mix_f32(a.a, b.a, t),
+    }
+}
+
+fn mix_f32(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
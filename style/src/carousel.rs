@@ -0,0 +1,20 @@
+//! Change the appearance of a carousel.
+use iced_core::Color;
+
+/// The appearance of a carousel.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the page indicators of the carousel.
+    pub indicator: Color,
+    /// The [`Color`] of the indicator of the current page of the carousel.
+    pub active_indicator: Color,
+}
+
+/// A set of rules that dictate the style of a carousel.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the carousel.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
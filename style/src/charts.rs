@@ -0,0 +1,28 @@
+//! Change the appearance of charts.
+use iced_core::Color;
+
+/// The appearance of a chart.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the axes of the chart.
+    pub axis: Color,
+    /// The [`Color`] of the grid lines of the chart.
+    pub grid: Color,
+    /// The [`Color`] of the labels of the chart.
+    pub text: Color,
+    /// The background [`Color`] of the tooltips of the chart.
+    pub tooltip_background: Color,
+    /// The text [`Color`] of the tooltips of the chart.
+    pub tooltip_text: Color,
+    /// The [`Color`]s used, in order, for series without an explicit color.
+    pub series: [Color; 6],
+}
+
+/// A set of rules that dictate the style of a chart.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the chart.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -1,5 +1,5 @@
 //! Change the appearance of a container.
-use iced_core::{Background, BorderRadius, Color};
+use iced_core::{Background, BorderRadius, Color, Shadow};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +14,14 @@ pub struct Appearance {
     pub border_width: f32,
     /// The border [`Color`] of the container.
     pub border_color: Color,
+    /// The [`Shadow`] of the container.
+    pub shadow: Shadow,
+    /// The radius of the blur applied to whatever is drawn behind the
+    /// container, in logical pixels.
+    ///
+    /// A translucent [`Background`] on top of a blurred backdrop gives the
+    /// container a frosted-glass look.
+    pub backdrop_blur: f32,
 }
 
 impl std::default::Default for Appearance {
@@ -24,6 +32,8 @@ impl std::default::Default for Appearance {
             border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+            backdrop_blur: 0.0,
         }
     }
 }
@@ -0,0 +1,24 @@
+//! Change the appearance of the focus ring of widgets.
+use iced_core::{BorderRadius, Color};
+
+/// The appearance of the ring drawn around a focused widget.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the ring.
+    pub color: Color,
+    /// The width of the ring.
+    pub width: f32,
+    /// The gap between the widget and the ring.
+    pub offset: f32,
+    /// The border radius of the ring.
+    pub border_radius: BorderRadius,
+}
+
+/// A set of rules that dictate the style of the focus ring.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the focus ring.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
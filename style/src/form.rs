@@ -0,0 +1,20 @@
+//! Change the appearance of a form.
+use iced_core::Color;
+
+/// The appearance of a form.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the labels of the fields.
+    pub label_color: Color,
+    /// The text [`Color`] of the validation errors of the fields.
+    pub error_color: Color,
+}
+
+/// A set of rules that dictate the style of a form.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a form.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,27 @@
+//! Change the appearance of a gauge.
+use iced_core::Color;
+
+/// The appearance of a gauge.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the track of the gauge.
+    pub track: Color,
+    /// The [`Color`] of the filled part of the track, when the gauge has
+    /// no zones.
+    pub bar: Color,
+    /// The [`Color`] of the needle of the gauge.
+    pub needle: Color,
+    /// The [`Color`] of the threshold markers of the gauge.
+    pub marker: Color,
+    /// The [`Color`] of the label of the gauge.
+    pub text: Color,
+}
+
+/// A set of rules that dictate the style of a gauge.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the gauge.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,33 @@
+//! Change the appearance of a gradient editor.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a gradient editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] behind the gradient, visible through translucent
+    /// colors.
+    pub background: Background,
+    /// The border radius of the gradient bar.
+    pub border_radius: BorderRadius,
+    /// The border width of the gradient bar.
+    pub border_width: f32,
+    /// The border [`Color`] of the gradient bar.
+    pub border_color: Color,
+    /// The border [`Color`] of the handles of the color stops.
+    pub handle_border: Color,
+    /// The border [`Color`] of the handle of the selected color stop.
+    pub selected_handle_border: Color,
+    /// The [`Color`] of the tracks of the channel sliders.
+    pub track: Color,
+    /// The [`Color`] of the labels of the channel sliders.
+    pub text: Color,
+}
+
+/// A set of rules that dictate the style of a gradient editor.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the gradient editor.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,29 @@
+//! Change the appearance of a heatmap calendar.
+use iced_core::Color;
+
+/// The appearance of a heatmap calendar.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the cells of the days without a value.
+    pub empty: Color,
+    /// The [`Color`]s of the cells of the days with a value, from the lowest
+    /// to the highest intensity.
+    pub levels: [Color; 4],
+    /// The [`Color`] of the border of the hovered cell.
+    pub hovered: Color,
+    /// The [`Color`] of the month and day labels.
+    pub text: Color,
+    /// The background [`Color`] of the tooltips.
+    pub tooltip_background: Color,
+    /// The text [`Color`] of the tooltips.
+    pub tooltip_text: Color,
+}
+
+/// A set of rules that dictate the style of a heatmap calendar.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the heatmap calendar.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -18,19 +18,35 @@ pub use iced_core as core;
 
 pub mod application;
 pub mod button;
+pub mod carousel;
+pub mod charts;
 pub mod checkbox;
 pub mod container;
+pub mod focus;
+pub mod form;
+pub mod gauge;
+pub mod gradient_editor;
+pub mod heatmap_calendar;
+pub mod map;
 pub mod menu;
+pub mod node_editor;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod segmented;
+pub mod shortcuts_overlay;
 pub mod slider;
+pub mod sparkline;
 pub mod svg;
+pub mod terminal;
 pub mod text_input;
 pub mod theme;
+pub mod timeline;
 pub mod toggler;
+pub mod video;
+pub mod virtual_keyboard;
 
 pub use theme::Theme;
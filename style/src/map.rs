@@ -0,0 +1,24 @@
+//! Change the appearance of a map.
+use iced_core::Color;
+
+/// The appearance of a map.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] behind the tiles of the map.
+    pub background: Color,
+    /// The [`Color`] of the tiles that are still loading.
+    pub placeholder: Color,
+    /// The [`Color`] of the background of the attribution.
+    pub attribution_background: Color,
+    /// The [`Color`] of the text of the attribution.
+    pub attribution_text: Color,
+}
+
+/// A set of rules that dictate the style of a map.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the map.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
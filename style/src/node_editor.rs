@@ -0,0 +1,37 @@
+//! Change the appearance of a node editor.
+use iced_core::Color;
+
+/// The appearance of a node editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the background of the node editor.
+    pub background: Color,
+    /// The [`Color`] of the grid lines of the node editor.
+    pub grid: Color,
+    /// The [`Color`] of the body of the nodes.
+    pub node_background: Color,
+    /// The [`Color`] of the border of the nodes.
+    pub node_border: Color,
+    /// The [`Color`] of the header of the nodes.
+    pub header_background: Color,
+    /// The [`Color`] of the title of the nodes.
+    pub header_text: Color,
+    /// The [`Color`] of the labels of the ports.
+    pub text: Color,
+    /// The [`Color`] of the border of the selected nodes and of the
+    /// selection box.
+    pub selection: Color,
+    /// The [`Color`] filling the selection box.
+    pub selection_background: Color,
+    /// The colors of the ports and their connections, picked by port kind.
+    pub ports: [Color; 6],
+}
+
+/// A set of rules that dictate the style of a node editor.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the node editor.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
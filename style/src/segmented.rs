@@ -0,0 +1,32 @@
+//! Change the appearance of a segmented control.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a segment of a segmented control.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the segment.
+    pub background: Background,
+    /// The text [`Color`] of the segment.
+    pub text_color: Color,
+    /// The border radius of the segmented control.
+    ///
+    /// Only the outer corners of the first and last segments are rounded.
+    pub border_radius: BorderRadius,
+    /// The border width of the segmented control.
+    pub border_width: f32,
+    /// The [`Color`] of the border and the dividers of the segmented
+    /// control.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a segmented control.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a segment.
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a segment.
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+}
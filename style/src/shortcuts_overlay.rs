@@ -0,0 +1,26 @@
+//! Change the appearance of shortcut hints.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a shortcut hint.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the hint.
+    pub background: Background,
+    /// The text [`Color`] of the hint.
+    pub text_color: Color,
+    /// The border radius of the hint.
+    pub border_radius: BorderRadius,
+    /// The border width of the hint.
+    pub border_width: f32,
+    /// The border [`Color`] of the hint.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of shortcut hints.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a shortcut hint.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,22 @@
+//! Change the appearance of a sparkline.
+use iced_core::Color;
+
+/// The appearance of a sparkline.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the line or the bars of the sparkline.
+    pub color: Color,
+    /// The [`Color`] of the area below the line of the sparkline, if any.
+    pub fill: Option<Color>,
+    /// The [`Color`] of the bars of the sparkline with negative values.
+    pub negative: Color,
+}
+
+/// A set of rules that dictate the style of a sparkline.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the sparkline.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,27 @@
+//! Change the appearance of a terminal.
+use iced_core::Color;
+
+/// The appearance of a terminal.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The default [`Color`] of the background of the terminal.
+    pub background: Color,
+    /// The default [`Color`] of the text of the terminal.
+    pub foreground: Color,
+    /// The [`Color`] of the cursor of the terminal.
+    pub cursor: Color,
+    /// The [`Color`] of the selected text of the terminal.
+    pub selection: Color,
+    /// The first 16 colors that programs can pick; from black to bright
+    /// white.
+    pub palette: [Color; 16],
+}
+
+/// A set of rules that dictate the style of a terminal.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the terminal.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -46,4 +46,12 @@ pub trait StyleSheet {
 
     /// Produces the style of a disabled text input.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Color`] of the strength meter of a password input,
+    /// given a strength between `0.0` and `1.0`.
+    fn strength_color(&self, style: &Self::Style, strength: f32) -> Color {
+        let _ = strength;
+
+        self.selection_color(style)
+    }
 }
@@ -1,28 +1,51 @@
 //! Use the built-in theme and styles.
+pub mod file;
 pub mod palette;
+pub mod parameters;
+pub mod sheet;
 
 pub use palette::Palette;
+pub use parameters::Parameters;
+pub use sheet::Sheet;
 
 use crate::application;
 use crate::button;
+use crate::carousel;
+use crate::charts;
 use crate::checkbox;
 use crate::container;
 use crate::core::widget::text;
+use crate::focus;
+use crate::form;
+use crate::gauge;
+use crate::gradient_editor;
+use crate::heatmap_calendar;
+use crate::map;
 use crate::menu;
+use crate::node_editor;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
 use crate::radio;
 use crate::rule;
 use crate::scrollable;
+use crate::segmented;
+use crate::shortcuts_overlay;
 use crate::slider;
+use crate::sparkline;
 use crate::svg;
+use crate::terminal;
 use crate::text_input;
+use crate::timeline;
 use crate::toggler;
+use crate::video;
+use crate::virtual_keyboard;
 
-use iced_core::{Background, Color, Vector};
+use iced_core::token::Tokens;
+use iced_core::{Background, Color, Shadow, Vector};
 
 use std::rc::Rc;
+use std::sync::Arc;
 
 /// A built-in theme.
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -32,6 +55,10 @@ pub enum Theme {
     Light,
     /// The built-in dark variant.
     Dark,
+    /// The built-in high contrast variant.
+    ///
+    /// See [`Palette::HIGH_CONTRAST`].
+    HighContrast,
     /// A [`Theme`] that uses a [`Custom`] palette.
     Custom(Box<Custom>),
 }
@@ -42,6 +69,14 @@ impl Theme {
         Self::custom_with_fn(palette, palette::Extended::generate)
     }
 
+    /// Creates a new custom [`Theme`] from a single accent [`Color`], like
+    /// the color of a brand.
+    ///
+    /// See [`Palette::from_accent`] to learn how the colors are derived.
+    pub fn from_accent(accent: Color, dark: bool) -> Self {
+        Self::custom(Palette::from_accent(accent, dark))
+    }
+
     /// Creates a new custom [`Theme`] from the given [`Palette`], with
     /// a custom generator of a [`palette::Extended`].
     pub fn custom_with_fn(
@@ -56,6 +91,7 @@ impl Theme {
         match self {
             Self::Light => Palette::LIGHT,
             Self::Dark => Palette::DARK,
+            Self::HighContrast => Palette::HIGH_CONTRAST,
             Self::Custom(custom) => custom.palette,
         }
     }
@@ -65,16 +101,121 @@ impl Theme {
         match self {
             Self::Light => &palette::EXTENDED_LIGHT,
             Self::Dark => &palette::EXTENDED_DARK,
+            Self::HighContrast => &palette::EXTENDED_HIGH_CONTRAST,
             Self::Custom(custom) => &custom.extended,
         }
     }
+
+    /// Returns the [`Parameters`] of the built-in styles of the [`Theme`].
+    pub fn parameters(&self) -> &Parameters {
+        match self {
+            Self::Light | Self::Dark => &Parameters::DEFAULT,
+            Self::HighContrast => &Parameters::HIGH_CONTRAST,
+            Self::Custom(custom) => &custom.parameters,
+        }
+    }
+
+    /// Changes the [`Parameters`] of the built-in styles of the [`Theme`],
+    /// keeping its colors.
+    pub fn with_parameters(self, parameters: Parameters) -> Self {
+        let mut custom = self.into_custom();
+
+        custom.parameters = parameters;
+
+        Self::Custom(Box::new(custom))
+    }
+
+    /// Changes the [`Palette`] of the [`Theme`], keeping its [`Parameters`]
+    /// and [`Sheet`].
+    pub fn with_palette(self, palette: Palette) -> Self {
+        let custom = self.into_custom();
+
+        Self::Custom(Box::new(Custom {
+            palette,
+            extended: palette::Extended::generate(palette),
+            ..custom
+        }))
+    }
+
+    /// Returns the design [`Tokens`] of the [`Theme`].
+    pub fn tokens(&self) -> Tokens {
+        match self {
+            Self::Light | Self::Dark | Self::HighContrast => Tokens::default(),
+            Self::Custom(custom) => custom.tokens,
+        }
+    }
+
+    /// Changes the design [`Tokens`] of the [`Theme`], keeping its colors.
+    ///
+    /// The radii of its [`Parameters`] are taken from the radius scale of
+    /// the [`Tokens`].
+    pub fn with_tokens(self, tokens: Tokens) -> Self {
+        let mut custom = self.into_custom();
+
+        custom.parameters = Parameters {
+            border_width: custom.parameters.border_width,
+            ..Parameters::from_tokens(&tokens)
+        };
+        custom.tokens = tokens;
+
+        Self::Custom(Box::new(custom))
+    }
+
+    /// Returns the [`Sheet`] of the [`Theme`], if any.
+    pub fn sheet(&self) -> Option<&Sheet> {
+        match self {
+            Self::Light | Self::Dark | Self::HighContrast => None,
+            Self::Custom(custom) => custom.sheet.as_deref(),
+        }
+    }
+
+    /// Sets the [`Sheet`] used to style the widgets of the [`Theme`] that
+    /// have a class, keeping its colors and [`Parameters`].
+    pub fn with_sheet(self, sheet: Sheet) -> Self {
+        let mut custom = self.into_custom();
+
+        custom.sheet = Some(Arc::new(sheet));
+
+        Self::Custom(Box::new(custom))
+    }
+
+    /// Returns the [`sheet::Properties`] of the given [`sheet::Class`] in
+    /// the [`Sheet`] of the [`Theme`].
+    pub fn class(&self, class: impl Into<sheet::Class>) -> sheet::Properties {
+        self.properties(&class.into(), None)
+    }
+
+    fn properties(
+        &self,
+        class: &sheet::Class,
+        state: Option<&str>,
+    ) -> sheet::Properties {
+        self.sheet()
+            .map(|sheet| sheet.get(class, state))
+            .unwrap_or_default()
+    }
+
+    fn into_custom(self) -> Custom {
+        match self {
+            Self::Light => Custom::new(Palette::LIGHT),
+            Self::Dark => Custom::new(Palette::DARK),
+            Self::HighContrast => Custom {
+                parameters: Parameters::HIGH_CONTRAST,
+                ..Custom::new(Palette::HIGH_CONTRAST)
+            },
+            Self::Custom(custom) => *custom,
+        }
+    }
 }
 
 /// A [`Theme`] with a customized [`Palette`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Custom {
     palette: Palette,
     extended: palette::Extended,
+    parameters: Parameters,
+    tokens: Tokens,
+    sheet: Option<Arc<Sheet>>,
 }
 
 impl Custom {
@@ -92,6 +233,9 @@ impl Custom {
         Self {
             palette,
             extended: generate(palette),
+            parameters: Parameters::DEFAULT,
+            tokens: Tokens::default(),
+            sheet: None,
         }
     }
 }
@@ -120,6 +264,25 @@ impl application::StyleSheet for Theme {
             Application::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn interpolate(&self, other: &Self, t: f32) -> Option<Self> {
+        Some(Self::Custom(Box::new(Custom {
+            palette: self.palette().interpolate(&other.palette(), t),
+            extended: self
+                .extended_palette()
+                .interpolate(other.extended_palette(), t),
+            parameters: *other.parameters(),
+            tokens: other.tokens(),
+            sheet: match other {
+                Self::Light | Self::Dark | Self::HighContrast => None,
+                Self::Custom(custom) => custom.sheet.clone(),
+            },
+        })))
+    }
+
+    fn tokens(&self) -> Option<Tokens> {
+        Some(Theme::tokens(self))
+    }
 }
 
 impl<T: Fn(&Theme) -> application::Appearance> application::StyleSheet for T {
@@ -154,10 +317,19 @@ pub enum Button {
     ///
     /// Useful for links!
     Text,
+    /// A class of the [`Sheet`] of the [`Theme`], based on the primary
+    /// style.
+    Class(sheet::Class),
     /// A custom style.
     Custom(Box<dyn button::StyleSheet<Style = Theme>>),
 }
 
+impl From<sheet::Class> for Button {
+    fn from(class: sheet::Class) -> Self {
+        Self::Class(class)
+    }
+}
+
 impl Button {
     /// Creates a custom [`Button`] style variant.
     pub fn custom(
@@ -174,7 +346,7 @@ impl button::StyleSheet for Theme {
         let palette = self.extended_palette();
 
         let appearance = button::Appearance {
-            border_radius: 2.0.into(),
+            border_radius: self.parameters().button_radius.into(),
             ..button::Appearance::default()
         };
 
@@ -193,6 +365,9 @@ impl button::StyleSheet for Theme {
                 text_color: palette.background.base.text,
                 ..appearance
             },
+            Button::Class(class) => self
+                .properties(class, None)
+                .button(from_pair(palette.primary.strong)),
             Button::Custom(custom) => custom.active(self),
         }
     }
@@ -200,8 +375,14 @@ impl button::StyleSheet for Theme {
     fn hovered(&self, style: &Self::Style) -> button::Appearance {
         let palette = self.extended_palette();
 
-        if let Button::Custom(custom) = style {
-            return custom.hovered(self);
+        match style {
+            Button::Custom(custom) => return custom.hovered(self),
+            Button::Class(class) => {
+                return self
+                    .properties(class, Some("hovered"))
+                    .button(self.hovered(&Button::Primary));
+            }
+            _ => {}
         }
 
         let active = self.active(style);
@@ -211,7 +392,7 @@ impl button::StyleSheet for Theme {
             Button::Secondary => Some(palette.background.strong.color),
             Button::Positive => Some(palette.success.strong.color),
             Button::Destructive => Some(palette.danger.strong.color),
-            Button::Text | Button::Custom(_) => None,
+            Button::Text | Button::Class(_) | Button::Custom(_) => None,
         };
 
         button::Appearance {
@@ -221,8 +402,14 @@ impl button::StyleSheet for Theme {
     }
 
     fn pressed(&self, style: &Self::Style) -> button::Appearance {
-        if let Button::Custom(custom) = style {
-            return custom.pressed(self);
+        match style {
+            Button::Custom(custom) => return custom.pressed(self),
+            Button::Class(class) => {
+                return self
+                    .properties(class, Some("pressed"))
+                    .button(self.pressed(&Button::Primary));
+            }
+            _ => {}
         }
 
         button::Appearance {
@@ -238,7 +425,7 @@ impl button::StyleSheet for Theme {
 
         let active = self.active(style);
 
-        button::Appearance {
+        let disabled = button::Appearance {
             shadow_offset: Vector::default(),
             background: active.background.map(|background| match background {
                 Background::Color(color) => Background::Color(Color {
@@ -254,10 +441,110 @@ impl button::StyleSheet for Theme {
                 ..active.text_color
             },
             ..active
+        };
+
+        // Only the properties of the disabled state are applied on top,
+        // since the ones of the class are already faded out
+        match style {
+            Button::Class(class) => self
+                .properties(&class.state("disabled"), None)
+                .button(disabled),
+            _ => disabled,
+        }
+    }
+}
+
+/// The style of a carousel.
+#[derive(Default)]
+pub enum Carousel {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn carousel::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> carousel::Appearance + 'static> From<T> for Carousel {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl carousel::StyleSheet for Theme {
+    type Style = Carousel;
+
+    fn appearance(&self, style: &Self::Style) -> carousel::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Carousel::Default => carousel::Appearance {
+                indicator: palette.background.strong.color,
+                active_indicator: palette.primary.base.color,
+            },
+            Carousel::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> carousel::Appearance> carousel::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> carousel::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a chart.
+#[derive(Default)]
+pub enum Charts {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn charts::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> charts::Appearance + 'static> From<T> for Charts {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl charts::StyleSheet for Theme {
+    type Style = Charts;
+
+    fn appearance(&self, style: &Self::Style) -> charts::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Charts::Default => charts::Appearance {
+                axis: palette.background.strong.color,
+                grid: palette.background.weak.color,
+                text: palette.background.base.text,
+                tooltip_background: palette.background.strong.color,
+                tooltip_text: palette.background.strong.text,
+                series: [
+                    palette.primary.base.color,
+                    palette.success.base.color,
+                    palette.danger.base.color,
+                    Color::from_rgb8(0xF5, 0xA6, 0x23),
+                    Color::from_rgb8(0x9B, 0x59, 0xB6),
+                    Color::from_rgb8(0x1A, 0xBC, 0x9C),
+                ],
+            },
+            Charts::Custom(custom) => custom.appearance(self),
         }
     }
 }
 
+impl<T: Fn(&Theme) -> charts::Appearance> charts::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> charts::Appearance {
+        (self)(style)
+    }
+}
+
 /// The style of a checkbox.
 #[derive(Default)]
 pub enum Checkbox {
@@ -286,24 +573,28 @@ impl checkbox::StyleSheet for Theme {
 
         match style {
             Checkbox::Primary => checkbox_appearance(
+                self.parameters(),
                 palette.primary.strong.text,
                 palette.background.base,
                 palette.primary.strong,
                 is_checked,
             ),
             Checkbox::Secondary => checkbox_appearance(
+                self.parameters(),
                 palette.background.base.text,
                 palette.background.base,
                 palette.background.base,
                 is_checked,
             ),
             Checkbox::Success => checkbox_appearance(
+                self.parameters(),
                 palette.success.base.text,
                 palette.background.base,
                 palette.success.base,
                 is_checked,
             ),
             Checkbox::Danger => checkbox_appearance(
+                self.parameters(),
                 palette.danger.base.text,
                 palette.background.base,
                 palette.danger.base,
@@ -322,24 +613,28 @@ impl checkbox::StyleSheet for Theme {
 
         match style {
             Checkbox::Primary => checkbox_appearance(
+                self.parameters(),
                 palette.primary.strong.text,
                 palette.background.weak,
                 palette.primary.base,
                 is_checked,
             ),
             Checkbox::Secondary => checkbox_appearance(
+                self.parameters(),
                 palette.background.base.text,
                 palette.background.weak,
                 palette.background.base,
                 is_checked,
             ),
             Checkbox::Success => checkbox_appearance(
+                self.parameters(),
                 palette.success.base.text,
                 palette.background.weak,
                 palette.success.base,
                 is_checked,
             ),
             Checkbox::Danger => checkbox_appearance(
+                self.parameters(),
                 palette.danger.base.text,
                 palette.background.weak,
                 palette.danger.base,
@@ -351,6 +646,7 @@ impl checkbox::StyleSheet for Theme {
 }
 
 fn checkbox_appearance(
+    parameters: &Parameters,
     icon_color: Color,
     base: palette::Pair,
     accent: palette::Pair,
@@ -363,8 +659,8 @@ fn checkbox_appearance(
             base.color
         }),
         icon_color,
-        border_radius: 2.0.into(),
-        border_width: 1.0,
+        border_radius: parameters.checkbox_radius.into(),
+        border_width: parameters.border_width,
         border_color: accent.color,
         text_color: None,
     }
@@ -378,10 +674,19 @@ pub enum Container {
     Transparent,
     /// A simple box.
     Box,
+    /// A class of the [`Sheet`] of the [`Theme`], based on the transparent
+    /// style.
+    Class(sheet::Class),
     /// A custom style.
     Custom(Box<dyn container::StyleSheet<Style = Theme>>),
 }
 
+impl From<sheet::Class> for Container {
+    fn from(class: sheet::Class) -> Self {
+        Self::Class(class)
+    }
+}
+
 impl<T: Fn(&Theme) -> container::Appearance + 'static> From<T> for Container {
     fn from(f: T) -> Self {
         Self::Custom(Box::new(f))
@@ -400,11 +705,16 @@ impl container::StyleSheet for Theme {
                 container::Appearance {
                     text_color: None,
                     background: Some(palette.background.weak.color.into()),
-                    border_radius: 2.0.into(),
+                    border_radius: self.parameters().container_radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                    backdrop_blur: 0.0,
                 }
             }
+            Container::Class(class) => self
+                .properties(class, None)
+                .container(container::Appearance::default()),
             Container::Custom(custom) => custom.appearance(self),
         }
     }
@@ -418,6 +728,279 @@ impl<T: Fn(&Theme) -> container::Appearance> container::StyleSheet for T {
     }
 }
 
+/// The style of the focus ring.
+#[derive(Default)]
+pub enum Focus {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn focus::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> focus::Appearance + 'static> From<T> for Focus {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl focus::StyleSheet for Theme {
+    type Style = Focus;
+
+    fn appearance(&self, style: &Self::Style) -> focus::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Focus::Default => focus::Appearance {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                offset: 2.0,
+                border_radius: 4.0.into(),
+            },
+            Focus::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> focus::Appearance> focus::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> focus::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a form.
+#[derive(Default)]
+pub enum Form {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn form::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> form::Appearance + 'static> From<T> for Form {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl form::StyleSheet for Theme {
+    type Style = Form;
+
+    fn appearance(&self, style: &Self::Style) -> form::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Form::Default => form::Appearance {
+                label_color: palette.background.base.text,
+                error_color: palette.danger.base.color,
+            },
+            Form::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> form::Appearance> form::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> form::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a gauge.
+#[derive(Default)]
+pub enum Gauge {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn gauge::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> gauge::Appearance + 'static> From<T> for Gauge {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl gauge::StyleSheet for Theme {
+    type Style = Gauge;
+
+    fn appearance(&self, style: &Self::Style) -> gauge::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Gauge::Default => gauge::Appearance {
+                track: palette.background.strong.color,
+                bar: palette.primary.base.color,
+                needle: palette.background.base.text,
+                marker: palette.danger.strong.color,
+                text: palette.background.base.text,
+            },
+            Gauge::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> gauge::Appearance> gauge::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> gauge::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a gradient editor.
+#[derive(Default)]
+pub enum GradientEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn gradient_editor::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> gradient_editor::Appearance + 'static> From<T>
+    for GradientEditor
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl gradient_editor::StyleSheet for Theme {
+    type Style = GradientEditor;
+
+    fn appearance(&self, style: &Self::Style) -> gradient_editor::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            GradientEditor::Default => gradient_editor::Appearance {
+                background: Background::Color(Color::WHITE),
+                border_radius: 4.0.into(),
+                border_width: 1.0,
+                border_color: palette.background.strong.color,
+                handle_border: palette.background.strong.color,
+                selected_handle_border: palette.primary.strong.color,
+                track: palette.background.strong.color,
+                text: palette.background.base.text,
+            },
+            GradientEditor::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> gradient_editor::Appearance> gradient_editor::StyleSheet
+    for T
+{
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> gradient_editor::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a heatmap calendar.
+#[derive(Default)]
+pub enum HeatmapCalendar {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn heatmap_calendar::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> heatmap_calendar::Appearance + 'static> From<T>
+    for HeatmapCalendar
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl heatmap_calendar::StyleSheet for Theme {
+    type Style = HeatmapCalendar;
+
+    fn appearance(&self, style: &Self::Style) -> heatmap_calendar::Appearance {
+        let palette = self.extended_palette();
+
+        let level = |alpha: f32| Color {
+            a: alpha,
+            ..palette.success.base.color
+        };
+
+        match style {
+            HeatmapCalendar::Default => heatmap_calendar::Appearance {
+                empty: palette.background.weak.color,
+                levels: [level(0.4), level(0.6), level(0.8), level(1.0)],
+                hovered: palette.background.base.text,
+                text: palette.background.base.text,
+                tooltip_background: palette.background.strong.color,
+                tooltip_text: palette.background.strong.text,
+            },
+            HeatmapCalendar::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> heatmap_calendar::Appearance> heatmap_calendar::StyleSheet
+    for T
+{
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> heatmap_calendar::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a map.
+#[derive(Default)]
+pub enum Map {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn map::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> map::Appearance + 'static> From<T> for Map {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl map::StyleSheet for Theme {
+    type Style = Map;
+
+    fn appearance(&self, style: &Self::Style) -> map::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Map::Default => map::Appearance {
+                background: palette.background.weak.color,
+                placeholder: palette.background.strong.color,
+                attribution_background: Color {
+                    a: 0.7,
+                    ..palette.background.base.color
+                },
+                attribution_text: palette.background.base.text,
+            },
+            Map::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> map::Appearance> map::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> map::Appearance {
+        (self)(style)
+    }
+}
+
 /// The style of a slider.
 #[derive(Default)]
 pub enum Slider {
@@ -545,6 +1128,69 @@ impl From<PickList> for Menu {
     }
 }
 
+/// The style of a node editor.
+#[derive(Default)]
+pub enum NodeEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn node_editor::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> node_editor::Appearance + 'static> From<T>
+    for NodeEditor
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl node_editor::StyleSheet for Theme {
+    type Style = NodeEditor;
+
+    fn appearance(&self, style: &Self::Style) -> node_editor::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            NodeEditor::Default => node_editor::Appearance {
+                background: palette.background.weak.color,
+                grid: Color {
+                    a: 0.1,
+                    ..palette.background.base.text
+                },
+                node_background: palette.background.base.color,
+                node_border: palette.background.strong.color,
+                header_background: palette.background.strong.color,
+                header_text: palette.background.strong.text,
+                text: palette.background.base.text,
+                selection: palette.primary.strong.color,
+                selection_background: Color {
+                    a: 0.2,
+                    ..palette.primary.base.color
+                },
+                ports: [
+                    palette.primary.base.color,
+                    palette.success.base.color,
+                    palette.danger.base.color,
+                    palette.secondary.strong.color,
+                    palette.primary.strong.color,
+                    palette.success.strong.color,
+                ],
+            },
+            NodeEditor::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> node_editor::Appearance> node_editor::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> node_editor::Appearance {
+        (self)(style)
+    }
+}
+
 /// The style of a pick list.
 #[derive(Clone, Default)]
 pub enum PickList {
@@ -571,8 +1217,8 @@ impl pick_list::StyleSheet for Theme {
                     background: palette.background.weak.color.into(),
                     placeholder_color: palette.background.strong.color,
                     handle_color: palette.background.weak.text,
-                    border_radius: 2.0.into(),
-                    border_width: 1.0,
+                    border_radius: self.parameters().pick_list_radius.into(),
+                    border_width: self.parameters().border_width,
                     border_color: palette.background.strong.color,
                 }
             }
@@ -585,52 +1231,116 @@ impl pick_list::StyleSheet for Theme {
             PickList::Default => {
                 let palette = self.extended_palette();
 
-                pick_list::Appearance {
-                    text_color: palette.background.weak.text,
-                    background: palette.background.weak.color.into(),
-                    placeholder_color: palette.background.strong.color,
-                    handle_color: palette.background.weak.text,
-                    border_radius: 2.0.into(),
-                    border_width: 1.0,
-                    border_color: palette.primary.strong.color,
+                pick_list::Appearance {
+                    text_color: palette.background.weak.text,
+                    background: palette.background.weak.color.into(),
+                    placeholder_color: palette.background.strong.color,
+                    handle_color: palette.background.weak.text,
+                    border_radius: self.parameters().pick_list_radius.into(),
+                    border_width: self.parameters().border_width,
+                    border_color: palette.primary.strong.color,
+                }
+            }
+            PickList::Custom(custom, _) => custom.hovered(self),
+        }
+    }
+}
+
+/// The style of a radio button.
+#[derive(Default)]
+pub enum Radio {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn radio::StyleSheet<Style = Theme>>),
+}
+
+impl radio::StyleSheet for Theme {
+    type Style = Radio;
+
+    fn active(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> radio::Appearance {
+        match style {
+            Radio::Default => {
+                let palette = self.extended_palette();
+
+                radio::Appearance {
+                    background: Color::TRANSPARENT.into(),
+                    dot_color: palette.primary.strong.color,
+                    border_width: 1.0,
+                    border_color: palette.primary.strong.color,
+                    text_color: None,
+                }
+            }
+            Radio::Custom(custom) => custom.active(self, is_selected),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> radio::Appearance {
+        match style {
+            Radio::Default => {
+                let active = self.active(style, is_selected);
+                let palette = self.extended_palette();
+
+                radio::Appearance {
+                    dot_color: palette.primary.strong.color,
+                    background: palette.primary.weak.color.into(),
+                    ..active
                 }
             }
-            PickList::Custom(custom, _) => custom.hovered(self),
+            Radio::Custom(custom) => custom.hovered(self, is_selected),
         }
     }
 }
 
-/// The style of a radio button.
+/// The style of a segmented control.
 #[derive(Default)]
-pub enum Radio {
+pub enum Segmented {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(Box<dyn radio::StyleSheet<Style = Theme>>),
+    Custom(Box<dyn segmented::StyleSheet<Style = Theme>>),
 }
 
-impl radio::StyleSheet for Theme {
-    type Style = Radio;
+impl segmented::StyleSheet for Theme {
+    type Style = Segmented;
 
     fn active(
         &self,
         style: &Self::Style,
         is_selected: bool,
-    ) -> radio::Appearance {
+    ) -> segmented::Appearance {
         match style {
-            Radio::Default => {
+            Segmented::Default => {
                 let palette = self.extended_palette();
 
-                radio::Appearance {
-                    background: Color::TRANSPARENT.into(),
-                    dot_color: palette.primary.strong.color,
+                let (background, text_color) = if is_selected {
+                    (palette.primary.strong.color, palette.primary.strong.text)
+                } else {
+                    (
+                        palette.background.base.color,
+                        palette.background.base.text,
+                    )
+                };
+
+                segmented::Appearance {
+                    background: background.into(),
+                    text_color,
+                    border_radius: 4.0.into(),
                     border_width: 1.0,
-                    border_color: palette.primary.strong.color,
-                    text_color: None,
+                    border_color: palette.background.strong.color,
                 }
             }
-            Radio::Custom(custom) => custom.active(self, is_selected),
+            Segmented::Custom(custom) => custom.active(self, is_selected),
         }
     }
 
@@ -638,19 +1348,27 @@ impl radio::StyleSheet for Theme {
         &self,
         style: &Self::Style,
         is_selected: bool,
-    ) -> radio::Appearance {
+    ) -> segmented::Appearance {
         match style {
-            Radio::Default => {
+            Segmented::Default => {
                 let active = self.active(style, is_selected);
                 let palette = self.extended_palette();
 
-                radio::Appearance {
-                    dot_color: palette.primary.strong.color,
-                    background: palette.primary.weak.color.into(),
-                    ..active
+                if is_selected {
+                    segmented::Appearance {
+                        background: palette.primary.base.color.into(),
+                        text_color: palette.primary.base.text,
+                        ..active
+                    }
+                } else {
+                    segmented::Appearance {
+                        background: palette.background.weak.color.into(),
+                        text_color: palette.background.weak.text,
+                        ..active
+                    }
                 }
             }
-            Radio::Custom(custom) => custom.hovered(self, is_selected),
+            Segmented::Custom(custom) => custom.hovered(self, is_selected),
         }
     }
 }
@@ -818,7 +1536,7 @@ impl progress_bar::StyleSheet for Theme {
         let from_palette = |bar: Color| progress_bar::Appearance {
             background: palette.background.strong.color.into(),
             bar: bar.into(),
-            border_radius: 2.0.into(),
+            border_radius: self.parameters().progress_bar_radius.into(),
         };
 
         match style {
@@ -880,6 +1598,97 @@ impl<T: Fn(&Theme) -> rule::Appearance> rule::StyleSheet for T {
     }
 }
 
+/// The style of the hints of a shortcuts overlay.
+#[derive(Default)]
+pub enum ShortcutsOverlay {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn shortcuts_overlay::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> shortcuts_overlay::Appearance + 'static> From<T>
+    for ShortcutsOverlay
+{
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl shortcuts_overlay::StyleSheet for Theme {
+    type Style = ShortcutsOverlay;
+
+    fn appearance(&self, style: &Self::Style) -> shortcuts_overlay::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            ShortcutsOverlay::Default => shortcuts_overlay::Appearance {
+                background: palette.background.strong.color.into(),
+                text_color: palette.background.strong.text,
+                border_radius: 4.0.into(),
+                border_width: 1.0,
+                border_color: palette.background.base.text,
+            },
+            ShortcutsOverlay::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> shortcuts_overlay::Appearance>
+    shortcuts_overlay::StyleSheet for T
+{
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> shortcuts_overlay::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a sparkline.
+#[derive(Default)]
+pub enum Sparkline {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn sparkline::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> sparkline::Appearance + 'static> From<T> for Sparkline {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl sparkline::StyleSheet for Theme {
+    type Style = Sparkline;
+
+    fn appearance(&self, style: &Self::Style) -> sparkline::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Sparkline::Default => sparkline::Appearance {
+                color: palette.primary.strong.color,
+                fill: Some(Color {
+                    a: 0.2,
+                    ..palette.primary.base.color
+                }),
+                negative: palette.danger.base.color,
+            },
+            Sparkline::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> sparkline::Appearance> sparkline::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> sparkline::Appearance {
+        (self)(style)
+    }
+}
+
 /**
  * Svg
  */
@@ -918,6 +1727,255 @@ impl svg::StyleSheet for fn(&Theme) -> svg::Appearance {
     }
 }
 
+/// The style of a terminal.
+#[derive(Default)]
+pub enum Terminal {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn terminal::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> terminal::Appearance + 'static> From<T> for Terminal {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl terminal::StyleSheet for Theme {
+    type Style = Terminal;
+
+    fn appearance(&self, style: &Self::Style) -> terminal::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Terminal::Default => terminal::Appearance {
+                background: palette.background.base.color,
+                foreground: palette.background.base.text,
+                cursor: palette.background.base.text,
+                selection: Color {
+                    a: 0.4,
+                    ..palette.primary.base.color
+                },
+                palette: [
+                    Color::from_rgb8(0x00, 0x00, 0x00),
+                    Color::from_rgb8(0xCD, 0x31, 0x31),
+                    Color::from_rgb8(0x0D, 0xBC, 0x79),
+                    Color::from_rgb8(0xE5, 0xE5, 0x10),
+                    Color::from_rgb8(0x24, 0x72, 0xC8),
+                    Color::from_rgb8(0xBC, 0x3F, 0xBC),
+                    Color::from_rgb8(0x11, 0xA8, 0xCD),
+                    Color::from_rgb8(0xE5, 0xE5, 0xE5),
+                    Color::from_rgb8(0x66, 0x66, 0x66),
+                    Color::from_rgb8(0xF1, 0x4C, 0x4C),
+                    Color::from_rgb8(0x23, 0xD1, 0x8B),
+                    Color::from_rgb8(0xF5, 0xF5, 0x43),
+                    Color::from_rgb8(0x3B, 0x8E, 0xEA),
+                    Color::from_rgb8(0xD6, 0x70, 0xD6),
+                    Color::from_rgb8(0x29, 0xB8, 0xDB),
+                    Color::from_rgb8(0xFF, 0xFF, 0xFF),
+                ],
+            },
+            Terminal::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> terminal::Appearance> terminal::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> terminal::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a timeline.
+#[derive(Default)]
+pub enum Timeline {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn timeline::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> timeline::Appearance + 'static> From<T> for Timeline {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl timeline::StyleSheet for Theme {
+    type Style = Timeline;
+
+    fn appearance(&self, style: &Self::Style) -> timeline::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Timeline::Default => timeline::Appearance {
+                background: palette.background.base.color,
+                header_background: palette.background.weak.color,
+                group_background: palette.background.strong.color,
+                text: palette.background.base.text,
+                grid: Color {
+                    a: 0.1,
+                    ..palette.background.base.text
+                },
+                bar: palette.primary.base.color,
+                bar_text: palette.primary.base.text,
+                dependency: palette.background.strong.text,
+            },
+            Timeline::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> timeline::Appearance> timeline::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> timeline::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a video.
+#[derive(Default)]
+pub enum Video {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn video::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> video::Appearance + 'static> From<T> for Video {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl video::StyleSheet for Theme {
+    type Style = Video;
+
+    fn appearance(&self, style: &Self::Style) -> video::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Video::Default => video::Appearance {
+                background: Color::BLACK.into(),
+                controls_background: Color {
+                    a: 0.6,
+                    ..Color::BLACK
+                }
+                .into(),
+                icon: Color::WHITE,
+                track: Color {
+                    a: 0.3,
+                    ..Color::WHITE
+                },
+                progress: palette.primary.base.color,
+                text: Color::WHITE,
+            },
+            Video::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> video::Appearance> video::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> video::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a virtual keyboard.
+#[derive(Default)]
+pub enum VirtualKeyboard {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn virtual_keyboard::StyleSheet<Style = Theme>>),
+}
+
+impl virtual_keyboard::StyleSheet for Theme {
+    type Style = VirtualKeyboard;
+
+    fn appearance(&self, style: &Self::Style) -> virtual_keyboard::Appearance {
+        match style {
+            VirtualKeyboard::Default => {
+                let palette = self.extended_palette();
+
+                virtual_keyboard::Appearance {
+                    background: palette.background.weak.color.into(),
+                    border_radius: 0.0.into(),
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                }
+            }
+            VirtualKeyboard::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn key(
+        &self,
+        style: &Self::Style,
+        is_pressed: bool,
+    ) -> virtual_keyboard::Key {
+        match style {
+            VirtualKeyboard::Default => {
+                let palette = self.extended_palette();
+
+                let pair = if is_pressed {
+                    palette.primary.strong
+                } else {
+                    palette.background.base
+                };
+
+                virtual_keyboard::Key {
+                    background: pair.color.into(),
+                    text_color: pair.text,
+                    border_radius: 4.0.into(),
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                }
+            }
+            VirtualKeyboard::Custom(custom) => custom.key(self, is_pressed),
+        }
+    }
+
+    fn hovered_key(
+        &self,
+        style: &Self::Style,
+        is_pressed: bool,
+    ) -> virtual_keyboard::Key {
+        match style {
+            VirtualKeyboard::Default => {
+                let key = self.key(style, is_pressed);
+
+                if is_pressed {
+                    key
+                } else {
+                    virtual_keyboard::Key {
+                        background: self
+                            .extended_palette()
+                            .background
+                            .strong
+                            .color
+                            .into(),
+                        ..key
+                    }
+                }
+            }
+            VirtualKeyboard::Custom(custom) => {
+                custom.hovered_key(self, is_pressed)
+            }
+        }
+    }
+}
+
 /// The style of a scrollable.
 #[derive(Default)]
 pub enum Scrollable {
@@ -947,12 +2005,15 @@ impl scrollable::StyleSheet for Theme {
 
                 scrollable::Scrollbar {
                     background: Some(palette.background.weak.color.into()),
-                    border_radius: 2.0.into(),
+                    border_radius: self.parameters().scrollbar_radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                     scroller: scrollable::Scroller {
                         color: palette.background.strong.color,
-                        border_radius: 2.0.into(),
+                        border_radius: self
+                            .parameters()
+                            .scrollbar_radius
+                            .into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
                     },
@@ -974,12 +2035,18 @@ impl scrollable::StyleSheet for Theme {
 
                     scrollable::Scrollbar {
                         background: Some(palette.background.weak.color.into()),
-                        border_radius: 2.0.into(),
+                        border_radius: self
+                            .parameters()
+                            .scrollbar_radius
+                            .into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
                         scroller: scrollable::Scroller {
                             color: palette.primary.strong.color,
-                            border_radius: 2.0.into(),
+                            border_radius: self
+                                .parameters()
+                                .scrollbar_radius
+                                .into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
                         },
@@ -1065,56 +2132,83 @@ pub enum TextInput {
     /// The default style.
     #[default]
     Default,
+    /// A class of the [`Sheet`] of the [`Theme`], based on the default
+    /// style.
+    Class(sheet::Class),
     /// A custom style.
     Custom(Box<dyn text_input::StyleSheet<Style = Theme>>),
 }
 
+impl From<sheet::Class> for TextInput {
+    fn from(class: sheet::Class) -> Self {
+        Self::Class(class)
+    }
+}
+
 impl text_input::StyleSheet for Theme {
     type Style = TextInput;
 
     fn active(&self, style: &Self::Style) -> text_input::Appearance {
-        if let TextInput::Custom(custom) = style {
-            return custom.active(self);
+        match style {
+            TextInput::Custom(custom) => return custom.active(self),
+            TextInput::Class(class) => {
+                return self
+                    .properties(class, None)
+                    .text_input(self.active(&TextInput::Default));
+            }
+            TextInput::Default => {}
         }
 
         let palette = self.extended_palette();
 
         text_input::Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 2.0.into(),
-            border_width: 1.0,
+            border_radius: self.parameters().text_input_radius.into(),
+            border_width: self.parameters().border_width,
             border_color: palette.background.strong.color,
             icon_color: palette.background.weak.text,
         }
     }
 
     fn hovered(&self, style: &Self::Style) -> text_input::Appearance {
-        if let TextInput::Custom(custom) = style {
-            return custom.hovered(self);
+        match style {
+            TextInput::Custom(custom) => return custom.hovered(self),
+            TextInput::Class(class) => {
+                return self
+                    .properties(class, Some("hovered"))
+                    .text_input(self.hovered(&TextInput::Default));
+            }
+            TextInput::Default => {}
         }
 
         let palette = self.extended_palette();
 
         text_input::Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 2.0.into(),
-            border_width: 1.0,
+            border_radius: self.parameters().text_input_radius.into(),
+            border_width: self.parameters().border_width,
             border_color: palette.background.base.text,
             icon_color: palette.background.weak.text,
         }
     }
 
     fn focused(&self, style: &Self::Style) -> text_input::Appearance {
-        if let TextInput::Custom(custom) = style {
-            return custom.focused(self);
+        match style {
+            TextInput::Custom(custom) => return custom.focused(self),
+            TextInput::Class(class) => {
+                return self
+                    .properties(class, Some("focused"))
+                    .text_input(self.focused(&TextInput::Default));
+            }
+            TextInput::Default => {}
         }
 
         let palette = self.extended_palette();
 
         text_input::Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 2.0.into(),
-            border_width: 1.0,
+            border_radius: self.parameters().text_input_radius.into(),
+            border_width: self.parameters().border_width,
             border_color: palette.primary.strong.color,
             icon_color: palette.background.weak.text,
         }
@@ -1137,7 +2231,13 @@ impl text_input::StyleSheet for Theme {
 
         let palette = self.extended_palette();
 
-        palette.background.base.text
+        match style {
+            TextInput::Class(class) => self
+                .properties(class, None)
+                .text_color
+                .unwrap_or(palette.background.base.text),
+            _ => palette.background.base.text,
+        }
     }
 
     fn selection_color(&self, style: &Self::Style) -> Color {
@@ -1151,16 +2251,22 @@ impl text_input::StyleSheet for Theme {
     }
 
     fn disabled(&self, style: &Self::Style) -> text_input::Appearance {
-        if let TextInput::Custom(custom) = style {
-            return custom.disabled(self);
+        match style {
+            TextInput::Custom(custom) => return custom.disabled(self),
+            TextInput::Class(class) => {
+                return self
+                    .properties(class, Some("disabled"))
+                    .text_input(self.disabled(&TextInput::Default));
+            }
+            TextInput::Default => {}
         }
 
         let palette = self.extended_palette();
 
         text_input::Appearance {
             background: palette.background.weak.color.into(),
-            border_radius: 2.0.into(),
-            border_width: 1.0,
+            border_radius: self.parameters().text_input_radius.into(),
+            border_width: self.parameters().border_width,
             border_color: palette.background.strong.color,
             icon_color: palette.background.strong.color,
         }
@@ -1173,4 +2279,20 @@ impl text_input::StyleSheet for Theme {
 
         self.placeholder_color(style)
     }
+
+    fn strength_color(&self, style: &Self::Style, strength: f32) -> Color {
+        if let TextInput::Custom(custom) = style {
+            return custom.strength_color(self, strength);
+        }
+
+        let palette = self.extended_palette();
+
+        if strength < 1.0 / 3.0 {
+            palette.danger.base.color
+        } else if strength < 2.0 / 3.0 {
+            palette.primary.base.color
+        } else {
+            palette.success.base.color
+        }
+    }
 }
@@ -0,0 +1,117 @@
+//! Load a theme from a plain text file.
+//!
+//! A theme file is a list of `name = value` lines. Colors of the
+//! [`Palette`] are written in hexadecimal, and the [`Parameters`] of the
+//! built-in styles as numbers. Lines starting with `#` are comments.
+//!
+//! ```text
+//! # A dark theme with rounded widgets
+//! base = dark
+//! background = #1e1e2e
+//! text = #cdd6f4
+//! primary = #89b4fa
+//!
+//! button.border_radius = 6
+//! text_input.border_radius = 6
+//! ```
+//!
+//! Any missing color is taken from the `base` theme, which can be `light`
+//! (the default), `dark`, or `high_contrast`.
+use crate::core::Color;
+use crate::theme::{Palette, Parameters, Theme};
+
+/// An error produced when parsing a theme file.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("line {line}: {reason}")]
+pub struct Error {
+    /// The line of the file where the error was found, starting at 1.
+    pub line: usize,
+    /// The reason of the error.
+    pub reason: String,
+}
+
+/// Parses the contents of a theme file into a [`Theme`].
+pub fn parse(contents: &str) -> Result<Theme, Error> {
+    let mut palette = None;
+    let mut parameters = Parameters::DEFAULT;
+    let mut colors = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let error = |reason: String| Error {
+            line: index + 1,
+            reason,
+        };
+
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once('=')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .ok_or_else(|| error(String::from("expected `name = value`")))?;
+
+        match name {
+            "base" => {
+                palette = Some(match value {
+                    "light" => Palette::LIGHT,
+                    "dark" => Palette::DARK,
+                    "high_contrast" => Palette::HIGH_CONTRAST,
+                    _ => return Err(error(format!("unknown base `{value}`"))),
+                });
+            }
+            "background" | "text" | "primary" | "success" | "danger" => {
+                let color = parse_color(value)
+                    .ok_or_else(|| error(format!("invalid color `{value}`")))?;
+
+                colors.push((name, color));
+            }
+            _ => {
+                let number = value
+                    .parse()
+                    .map_err(|_| error(format!("invalid number `{value}`")))?;
+
+                if !parameters.set(name, number) {
+                    return Err(error(format!("unknown name `{name}`")));
+                }
+            }
+        }
+    }
+
+    // Colors are applied last, so the base can be set anywhere
+    let mut palette = palette.unwrap_or(Palette::LIGHT);
+
+    for (name, color) in colors {
+        let target = match name {
+            "background" => &mut palette.background,
+            "text" => &mut palette.text,
+            "primary" => &mut palette.primary,
+            "success" => &mut palette.success,
+            _ => &mut palette.danger,
+        };
+
+        *target = color;
+    }
+
+    Ok(Theme::custom(palette).with_parameters(parameters))
+}
+
+pub(crate) fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+
+    let channel =
+        |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+
+    match hex.len() {
+        6 => Some(Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?)),
+        8 => Some(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            f32::from(channel(6)?) / 255.0,
+        )),
+        _ => None,
+    }
+}
@@ -67,6 +67,144 @@ impl Palette {
             0x3F as f32 / 255.0,
         ),
     };
+
+    /// The built-in high contrast variant of a [`Palette`].
+    ///
+    /// Every color has a contrast ratio of at least `7.0` with the
+    /// background, the enhanced level (AAA) of the [WCAG].
+    ///
+    /// [WCAG]: https://www.w3.org/TR/WCAG21/#contrast-enhanced
+    pub const HIGH_CONTRAST: Self = Self {
+        background: Color::BLACK,
+        text: Color::WHITE,
+        primary: Color::from_rgb(
+            0xFF as f32 / 255.0,
+            0xD4 as f32 / 255.0,
+            0x00 as f32 / 255.0,
+        ),
+        success: Color::from_rgb(
+            0x4C as f32 / 255.0,
+            0xFF as f32 / 255.0,
+            0x7A as f32 / 255.0,
+        ),
+        danger: Color::from_rgb(
+            0xFF as f32 / 255.0,
+            0x70 as f32 / 255.0,
+            0x70 as f32 / 255.0,
+        ),
+    };
+
+    /// Generates a [`Palette`] from a single accent [`Color`], like the
+    /// color of a brand.
+    ///
+    /// The background and the text are tinted with the hue of the accent,
+    /// and the success and danger colors share its saturation. Every color
+    /// is lightened or darkened as needed to contrast with the background:
+    /// enough to read the text, and enough to see the rest.
+    pub fn from_accent(accent: Color, dark: bool) -> Self {
+        /// The minimum contrast of text with the background (WCAG AAA).
+        const TEXT_CONTRAST: f32 = 7.0;
+
+        /// The minimum contrast of other colors with the background.
+        const ACCENT_CONTRAST: f32 = 3.0;
+
+        /// The hue of the success color, in degrees.
+        const SUCCESS_HUE: f32 = 150.0;
+
+        /// The hue of the danger color, in degrees.
+        const DANGER_HUE: f32 = 5.0;
+
+        let hsl = to_hsl(accent);
+        let hue = hsl.hue.into_positive_degrees();
+        let saturation = hsl.saturation.clamp(0.4, 0.8);
+
+        let (background, text, lightness) = if dark {
+            (
+                from_hsl(Hsl::new(hue, hsl.saturation * 0.2, 0.12)),
+                from_hsl(Hsl::new(hue, hsl.saturation * 0.1, 0.92)),
+                0.55,
+            )
+        } else {
+            (
+                from_hsl(Hsl::new(hue, hsl.saturation * 0.3, 0.98)),
+                from_hsl(Hsl::new(hue, hsl.saturation * 0.2, 0.10)),
+                0.40,
+            )
+        };
+
+        let status = |hue: f32| {
+            with_contrast(
+                from_hsl(Hsl::new(hue, saturation, lightness)),
+                background,
+                ACCENT_CONTRAST,
+            )
+        };
+
+        Self {
+            background,
+            text: with_contrast(text, background, TEXT_CONTRAST),
+            primary: with_contrast(accent, background, ACCENT_CONTRAST),
+            success: status(SUCCESS_HUE),
+            danger: status(DANGER_HUE),
+        }
+    }
+
+    /// Checks the contrast of the colors of the [`Palette`] with its
+    /// background, returning the ones below the minimum of the [WCAG].
+    ///
+    /// The text needs a contrast ratio of `4.5` (level AA), and the other
+    /// colors a contrast ratio of `3.0`, since they fill widgets and mark
+    /// their state. An empty list means the [`Palette`] is accessible.
+    ///
+    /// [WCAG]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+    pub fn check_contrast(&self) -> Vec<LowContrast> {
+        /// The minimum contrast of text with the background (WCAG AA).
+        const TEXT_CONTRAST: f32 = 4.5;
+
+        /// The minimum contrast of other colors with the background.
+        const ACCENT_CONTRAST: f32 = 3.0;
+
+        [
+            ("text", self.text, TEXT_CONTRAST),
+            ("primary", self.primary, ACCENT_CONTRAST),
+            ("success", self.success, ACCENT_CONTRAST),
+            ("danger", self.danger, ACCENT_CONTRAST),
+        ]
+        .into_iter()
+        .filter_map(|(color, value, minimum)| {
+            let ratio = value.contrast_ratio(self.background);
+
+            (ratio < minimum).then_some(LowContrast {
+                color,
+                ratio,
+                minimum,
+            })
+        })
+        .collect()
+    }
+
+    /// Returns the [`Palette`] found at `t` between `self` and `other`, where
+    /// `0.0` is `self` and `1.0` is `other`.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: mix(self.background, other.background, t),
+            text: mix(self.text, other.text, t),
+            primary: mix(self.primary, other.primary, t),
+            success: mix(self.success, other.success, t),
+            danger: mix(self.danger, other.danger, t),
+        }
+    }
+}
+
+/// A color of a [`Palette`] without enough contrast with its background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LowContrast {
+    /// The name of the color in the [`Palette`], like `"text"`.
+    pub color: &'static str,
+    /// The contrast ratio of the color with the background.
+    pub ratio: f32,
+    /// The minimum contrast ratio needed.
+    pub minimum: f32,
 }
 
 /// An extended set of colors generated from a [`Palette`].
@@ -92,6 +230,10 @@ pub static EXTENDED_LIGHT: Lazy<Extended> =
 pub static EXTENDED_DARK: Lazy<Extended> =
     Lazy::new(|| Extended::generate(Palette::DARK));
 
+/// The built-in high contrast variant of an [`Extended`] palette.
+pub static EXTENDED_HIGH_CONTRAST: Lazy<Extended> =
+    Lazy::new(|| Extended::generate(Palette::HIGH_CONTRAST));
+
 impl Extended {
     /// Generates an [`Extended`] palette from a simple [`Palette`].
     pub fn generate(palette: Palette) -> Self {
@@ -115,6 +257,18 @@ impl Extended {
             ),
         }
     }
+
+    /// Returns the [`Extended`] palette found at `t` between `self` and
+    /// `other`, where `0.0` is `self` and `1.0` is `other`.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, t),
+            primary: self.primary.interpolate(&other.primary, t),
+            secondary: self.secondary.interpolate(&other.secondary, t),
+            success: self.success.interpolate(&other.success, t),
+            danger: self.danger.interpolate(&other.danger, t),
+        }
+    }
 }
 
 /// A pair of background and text colors.
@@ -139,8 +293,40 @@ impl Pair {
             text: readable(color, text),
         }
     }
+
+    /// Returns the [`Pair`] found at `t` between `self` and `other`, where
+    /// `0.0` is `self` and `1.0` is `other`.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            color: mix(self.color, other.color, t),
+            text: mix(self.text, other.text, t),
+        }
+    }
 }
 
+macro_rules! interpolate_pairs {
+    ($($set:ident),*) => {
+        $(
+            impl $set {
+                #[doc = concat!(
+                    "Returns the set of [`", stringify!($set), "`] colors ",
+                    "found at `t` between `self` and `other`, where `0.0` ",
+                    "is `self` and `1.0` is `other`."
+                )]
+                pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+                    Self {
+                        base: self.base.interpolate(&other.base, t),
+                        weak: self.weak.interpolate(&other.weak, t),
+                        strong: self.strong.interpolate(&other.strong, t),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+interpolate_pairs!(Background, Primary, Secondary, Success, Danger);
+
 /// A set of background colors.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Background {
@@ -328,6 +514,24 @@ fn is_readable(a: Color, b: Color) -> bool {
     a_srgb.has_enhanced_contrast_text(b_srgb)
 }
 
+/// Lightens or darkens the color until it has the given contrast ratio with
+/// the background, if possible.
+fn with_contrast(color: Color, background: Color, ratio: f32) -> Color {
+    let step = if is_dark(background) { 0.05 } else { -0.05 };
+
+    let mut hsl = to_hsl(color);
+    let mut adjusted = color;
+
+    while adjusted.contrast_ratio(background) < ratio
+        && (0.0..=1.0).contains(&(hsl.lightness + step))
+    {
+        hsl.lightness += step;
+        adjusted = from_hsl(hsl);
+    }
+
+    adjusted
+}
+
 fn to_hsl(color: Color) -> Hsl {
     Hsl::from_color(Rgb::from(color))
 }
@@ -0,0 +1,92 @@
+//! Tweak the shapes of the built-in styles of a theme.
+use crate::core::token::Tokens;
+
+/// The numeric parameters used by the built-in styles of a
+/// [`Theme`](crate::Theme).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parameters {
+    /// The border radius of buttons.
+    pub button_radius: f32,
+    /// The border radius of checkboxes.
+    pub checkbox_radius: f32,
+    /// The border radius of boxed containers.
+    pub container_radius: f32,
+    /// The border radius of pick lists.
+    pub pick_list_radius: f32,
+    /// The border radius of progress bars.
+    pub progress_bar_radius: f32,
+    /// The border radius of the scrollbars of scrollables.
+    pub scrollbar_radius: f32,
+    /// The border radius of text inputs.
+    pub text_input_radius: f32,
+    /// The border width of checkboxes, pick lists, and text inputs.
+    pub border_width: f32,
+}
+
+impl Parameters {
+    /// The [`Parameters`] of the built-in themes.
+    pub const DEFAULT: Self = Self {
+        button_radius: 2.0,
+        checkbox_radius: 2.0,
+        container_radius: 2.0,
+        pick_list_radius: 2.0,
+        progress_bar_radius: 2.0,
+        scrollbar_radius: 2.0,
+        text_input_radius: 2.0,
+        border_width: 1.0,
+    };
+
+    /// The [`Parameters`] of the built-in high contrast theme, with thicker
+    /// borders.
+    pub const HIGH_CONTRAST: Self = Self {
+        border_width: 2.0,
+        ..Self::DEFAULT
+    };
+
+    /// Creates the [`Parameters`] with the radii of the radius scale of the
+    /// given [`Tokens`].
+    ///
+    /// Boxed containers use the medium radius, and everything else the small
+    /// one.
+    pub fn from_tokens(tokens: &Tokens) -> Self {
+        let small = tokens.radius.sm;
+
+        Self {
+            button_radius: small,
+            checkbox_radius: small,
+            container_radius: tokens.radius.md,
+            pick_list_radius: small,
+            progress_bar_radius: small,
+            scrollbar_radius: small,
+            text_input_radius: small,
+            border_width: 1.0,
+        }
+    }
+
+    /// Sets the parameter with the given name, as written in a theme file.
+    ///
+    /// Returns `false` if there is no parameter with such name.
+    pub fn set(&mut self, name: &str, value: f32) -> bool {
+        let parameter = match name {
+            "button.border_radius" => &mut self.button_radius,
+            "checkbox.border_radius" => &mut self.checkbox_radius,
+            "container.border_radius" => &mut self.container_radius,
+            "pick_list.border_radius" => &mut self.pick_list_radius,
+            "progress_bar.border_radius" => &mut self.progress_bar_radius,
+            "scrollable.border_radius" => &mut self.scrollbar_radius,
+            "text_input.border_radius" => &mut self.text_input_radius,
+            "border_width" => &mut self.border_width,
+            _ => return false,
+        };
+
+        *parameter = value;
+
+        true
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,352 @@
+//! Style widgets by class with a style sheet.
+//!
+//! A style sheet is written in a small subset of TOML. Every table is a
+//! class, and its keys are the [`Properties`] of the widgets of that class.
+//! A table named after a class and a state—like `hovered`, `pressed`,
+//! `focused`, or `disabled`—overrides the class in that state only.
+//!
+//! ```toml
+//! # Buttons that destroy things
+//! [danger]
+//! background = "#c3423f"
+//! text = "#ffffff"
+//! border_color = "#8f2d2b"
+//! border_width = 1
+//! border_radius = 8
+//! padding = 12
+//!
+//! [danger.hovered]
+//! background = "#d9534f"
+//!
+//! # Attached to the bottom of something else
+//! [drawer]
+//! border_radius = "12 12 0 0"
+//! ```
+//!
+//! A `border_radius` is either a single radius for every corner, or four
+//! radii for the top-left, top-right, bottom-right, and bottom-left corners.
+//!
+//! Widgets opt in to a class with their `class` method, once the [`Sheet`]
+//! is set in the [`Theme`] with [`Theme::with_sheet`]. Any missing property
+//! is taken from the default style of the widget.
+//!
+//! The padding of a class is not applied by `class`, since widgets are laid
+//! out before they are styled. Read it with [`Theme::class`] instead.
+//!
+//! [`Theme`]: crate::Theme
+//! [`Theme::with_sheet`]: crate::Theme::with_sheet
+//! [`Theme::class`]: crate::Theme::class
+use crate::button;
+use crate::container;
+use crate::core::{Background, BorderRadius, Color};
+use crate::text_input;
+use crate::theme::file::{self, Error};
+
+use std::collections::BTreeMap;
+
+/// A set of classes and their [`Properties`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Sheet {
+    classes: BTreeMap<String, Properties>,
+}
+
+impl Sheet {
+    /// Parses the contents of a style sheet into a [`Sheet`].
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        let mut classes: BTreeMap<String, Properties> = BTreeMap::new();
+        let mut current = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let error = |reason: String| Error {
+                line: index + 1,
+                reason,
+            };
+
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let name = table
+                    .strip_suffix(']')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| error(String::from("expected `[class]`")))?;
+
+                let name = unquote(name).to_owned();
+                let _ = classes.entry(name.clone()).or_default();
+
+                current = Some(name);
+
+                continue;
+            }
+
+            let properties = current
+                .as_ref()
+                .and_then(|name| classes.get_mut(name))
+                .ok_or_else(|| {
+                    error(String::from(
+                        "expected `[class]` before any property",
+                    ))
+                })?;
+
+            let (name, value) = line
+                .split_once('=')
+                .map(|(name, value)| (name.trim(), unquote(value.trim())))
+                .ok_or_else(|| {
+                    error(String::from("expected `name = value`"))
+                })?;
+
+            let color = || {
+                file::parse_color(value)
+                    .ok_or_else(|| error(format!("invalid color `{value}`")))
+            };
+
+            let number = || {
+                value
+                    .parse::<f32>()
+                    .map_err(|_| error(format!("invalid number `{value}`")))
+            };
+
+            match name {
+                "background" => properties.background = Some(color()?),
+                "text" => properties.text_color = Some(color()?),
+                "border_color" => properties.border_color = Some(color()?),
+                "border_width" => properties.border_width = Some(number()?),
+                "border_radius" => {
+                    properties.border_radius =
+                        Some(parse_border_radius(value).ok_or_else(|| {
+                            error(format!("invalid border radius `{value}`"))
+                        })?);
+                }
+                "padding" => properties.padding = Some(number()?),
+                _ => return Err(error(format!("unknown property `{name}`"))),
+            }
+        }
+
+        Ok(Self { classes })
+    }
+
+    /// Returns the [`Properties`] of the given [`Class`] in the given state,
+    /// if any.
+    ///
+    /// The properties of the state override the ones of the class.
+    pub fn get(&self, class: &Class, state: Option<&str>) -> Properties {
+        let properties =
+            self.classes.get(&class.0).copied().unwrap_or_default();
+
+        match state.and_then(|state| self.classes.get(&class.state(state).0)) {
+            Some(overrides) => properties.merge(overrides),
+            None => properties,
+        }
+    }
+}
+
+/// The style properties of a class.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Properties {
+    /// The background color.
+    pub background: Option<Color>,
+    /// The text color.
+    pub text_color: Option<Color>,
+    /// The color of the border.
+    pub border_color: Option<Color>,
+    /// The width of the border.
+    pub border_width: Option<f32>,
+    /// The radii of the corners of the border.
+    pub border_radius: Option<BorderRadius>,
+    /// The padding, in logical pixels.
+    pub padding: Option<f32>,
+}
+
+impl Properties {
+    /// Overrides the [`Properties`] with the ones set in `other`.
+    pub fn merge(self, other: &Self) -> Self {
+        Self {
+            background: other.background.or(self.background),
+            text_color: other.text_color.or(self.text_color),
+            border_color: other.border_color.or(self.border_color),
+            border_width: other.border_width.or(self.border_width),
+            border_radius: other.border_radius.or(self.border_radius),
+            padding: other.padding.or(self.padding),
+        }
+    }
+
+    pub(crate) fn button(
+        &self,
+        appearance: button::Appearance,
+    ) -> button::Appearance {
+        button::Appearance {
+            background: self
+                .background
+                .map(Background::from)
+                .or(appearance.background),
+            text_color: self.text_color.unwrap_or(appearance.text_color),
+            border_radius: self
+                .border_radius
+                .unwrap_or(appearance.border_radius),
+            border_width: self.border_width.unwrap_or(appearance.border_width),
+            border_color: self.border_color.unwrap_or(appearance.border_color),
+            ..appearance
+        }
+    }
+
+    pub(crate) fn container(
+        &self,
+        appearance: container::Appearance,
+    ) -> container::Appearance {
+        container::Appearance {
+            text_color: self.text_color.or(appearance.text_color),
+            background: self
+                .background
+                .map(Background::from)
+                .or(appearance.background),
+            border_radius: self
+                .border_radius
+                .unwrap_or(appearance.border_radius),
+            border_width: self.border_width.unwrap_or(appearance.border_width),
+            border_color: self.border_color.unwrap_or(appearance.border_color),
+            ..appearance
+        }
+    }
+
+    pub(crate) fn text_input(
+        &self,
+        appearance: text_input::Appearance,
+    ) -> text_input::Appearance {
+        text_input::Appearance {
+            background: self
+                .background
+                .map_or(appearance.background, Background::from),
+            border_radius: self
+                .border_radius
+                .unwrap_or(appearance.border_radius),
+            border_width: self.border_width.unwrap_or(appearance.border_width),
+            border_color: self.border_color.unwrap_or(appearance.border_color),
+            ..appearance
+        }
+    }
+}
+
+/// The name of a class of a [`Sheet`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Class(pub String);
+
+impl Class {
+    /// Returns the [`Class`] overriding this one in the given state.
+    pub(crate) fn state(&self, state: &str) -> Self {
+        Self(format!("{}.{state}", self.0))
+    }
+}
+
+impl From<&str> for Class {
+    fn from(name: &str) -> Self {
+        Self(name.to_owned())
+    }
+}
+
+impl From<String> for Class {
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+fn parse_border_radius(value: &str) -> Option<BorderRadius> {
+    let radii = value
+        .split_whitespace()
+        .map(|radius| radius.parse::<f32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    match radii[..] {
+        [radius] => Some(BorderRadius::from(radius)),
+        [top_left, top_right, bottom_right, bottom_left] => {
+            Some(BorderRadius::from([
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            ]))
+        }
+        _ => None,
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = r##"
+        # Buttons that destroy things
+        [danger]
+        background = "#c3423f"
+        text = "#ffffff"
+        border_color = "#8f2d2b"
+        border_width = 1
+        border_radius = 8
+        padding = 12
+
+        [danger.hovered]
+        background = "#d9534f"
+
+        # Attached to the bottom of something else
+        [drawer]
+        border_radius = "12 12 0 0"
+    "##;
+
+    #[test]
+    fn parse_and_get() {
+        let sheet = Sheet::parse(SHEET).expect("Parse style sheet");
+        let danger = Class::from("danger");
+
+        let properties = sheet.get(&danger, None);
+
+        assert_eq!(
+            properties.background,
+            Some(Color::from_rgb8(0xc3, 0x42, 0x3f))
+        );
+        assert_eq!(properties.text_color, Some(Color::WHITE));
+        assert_eq!(properties.border_width, Some(1.0));
+        assert_eq!(properties.border_radius, Some(BorderRadius::from(8.0)));
+        assert_eq!(properties.padding, Some(12.0));
+
+        let hovered = sheet.get(&danger, Some("hovered"));
+
+        assert_eq!(
+            hovered.background,
+            Some(Color::from_rgb8(0xd9, 0x53, 0x4f))
+        );
+        assert_eq!(hovered.text_color, properties.text_color);
+        assert_eq!(hovered.padding, properties.padding);
+
+        assert_eq!(sheet.get(&danger, Some("pressed")), properties);
+
+        assert_eq!(
+            sheet.get(&Class::from("drawer"), None).border_radius,
+            Some(BorderRadius::from([12.0, 12.0, 0.0, 0.0]))
+        );
+
+        assert_eq!(
+            sheet.get(&Class::from("missing"), None),
+            Properties::default()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Sheet::parse("padding = 4").unwrap_err();
+        assert_eq!(error.line, 1);
+
+        let error =
+            Sheet::parse("[drawer]\nborder_radius = \"1 2\"").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
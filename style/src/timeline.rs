@@ -0,0 +1,32 @@
+//! Change the appearance of a timeline.
+use iced_core::Color;
+
+/// The appearance of a timeline.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the background of the timeline.
+    pub background: Color,
+    /// The [`Color`] of the background of the time axis and the labels.
+    pub header_background: Color,
+    /// The [`Color`] of the background of the headers of the groups.
+    pub group_background: Color,
+    /// The [`Color`] of the text of the timeline.
+    pub text: Color,
+    /// The [`Color`] of the grid lines of the timeline.
+    pub grid: Color,
+    /// The default [`Color`] of the bars.
+    pub bar: Color,
+    /// The [`Color`] of the labels of the bars.
+    pub bar_text: Color,
+    /// The [`Color`] of the dependency arrows.
+    pub dependency: Color,
+}
+
+/// A set of rules that dictate the style of a timeline.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the timeline.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
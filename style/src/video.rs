@@ -0,0 +1,28 @@
+//! Change the appearance of a video.
+use iced_core::{Background, Color};
+
+/// The appearance of a video.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] behind the frames of the video.
+    pub background: Background,
+    /// The [`Background`] of the playback controls.
+    pub controls_background: Background,
+    /// The [`Color`] of the play and pause icons.
+    pub icon: Color,
+    /// The [`Color`] of the seek bar.
+    pub track: Color,
+    /// The [`Color`] of the played part of the seek bar.
+    pub progress: Color,
+    /// The [`Color`] of the elapsed and total time.
+    pub text: Color,
+}
+
+/// A set of rules that dictate the style of a video.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the video.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
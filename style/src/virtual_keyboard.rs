@@ -0,0 +1,49 @@
+//! Change the appearance of a virtual keyboard.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of the panel of a virtual keyboard.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the panel.
+    pub background: Background,
+    /// The border radius of the panel.
+    pub border_radius: BorderRadius,
+    /// The border width of the panel.
+    pub border_width: f32,
+    /// The border [`Color`] of the panel.
+    pub border_color: Color,
+}
+
+/// The appearance of a key of a virtual keyboard.
+#[derive(Debug, Clone, Copy)]
+pub struct Key {
+    /// The [`Background`] of the key.
+    pub background: Background,
+    /// The text [`Color`] of the key.
+    pub text_color: Color,
+    /// The border radius of the key.
+    pub border_radius: BorderRadius,
+    /// The border width of the key.
+    pub border_width: f32,
+    /// The border [`Color`] of the key.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a virtual keyboard.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the panel of a virtual keyboard.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Key`] appearance of a key.
+    ///
+    /// Toggled keys, like an active shift key, are considered pressed.
+    fn key(&self, style: &Self::Style, is_pressed: bool) -> Key;
+
+    /// Produces the [`Key`] appearance of a hovered key.
+    fn hovered_key(&self, style: &Self::Style, is_pressed: bool) -> Key {
+        self.key(style, is_pressed)
+    }
+}
@@ -0,0 +1,406 @@
+//! Test iced widgets without a window.
+//!
+//! A [`Simulator`] mounts a widget tree headlessly, drives it with synthetic
+//! events—like clicks, key presses, and text input—and lets tests assert on
+//! the messages it produces and the [`Primitive`]s it draws.
+//!
+//! ```no_run
+//! use iced_test::simulator;
+//! use iced_widget::button;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! let mut ui = simulator(button("Increment").on_press(Message::Increment));
+//!
+//! ui.click("Increment").expect("Button must be visible");
+//!
+//! assert_eq!(ui.messages().collect::<Vec<_>>(), [Message::Increment]);
+//! ```
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![forbid(unsafe_code, rust_2018_idioms)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unused_results,
+    rustdoc::broken_intra_doc_links
+)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod renderer;
+
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+
+pub use renderer::{Primitive, Renderer};
+pub use runtime::query::Selector;
+
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::Style;
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::{Color, Element, Point, Rectangle, Size};
+use crate::runtime::query;
+use crate::runtime::user_interface::{self, UserInterface};
+
+/// The size of the window simulated by default.
+pub const DEFAULT_SIZE: Size = Size::new(1024.0, 768.0);
+
+/// Creates a new [`Simulator`] of the given widget tree, with the
+/// [`DEFAULT_SIZE`] and the built-in theme.
+pub fn simulator<'a, Message>(
+    element: impl Into<Element<'a, Message, Renderer>>,
+) -> Simulator<'a, Message> {
+    Simulator::new(element)
+}
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum Error {
+    /// No widget matches the [`Selector`].
+    #[error("no widget matches the selector: {0:?}")]
+    NotFound(Selector),
+}
+
+/// A widget tree running without a window, driven by synthetic events.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme = iced_style::Theme> {
+    raw: UserInterface<'a, Message, Renderer<Theme>>,
+    renderer: Renderer<Theme>,
+    theme: Theme,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    clipboard: Clipboard,
+    messages: Vec<Message>,
+    now: Instant,
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme>
+where
+    Theme: Default,
+{
+    /// Mounts the given widget tree in a new [`Simulator`], with the
+    /// [`DEFAULT_SIZE`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    ) -> Self {
+        Self::with_size(DEFAULT_SIZE, element)
+    }
+
+    /// Mounts the given widget tree in a new [`Simulator`] of the given
+    /// [`Size`].
+    pub fn with_size(
+        size: Size,
+        element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    ) -> Self {
+        let mut renderer = Renderer::default();
+
+        let raw = UserInterface::build(
+            element,
+            size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            theme: Theme::default(),
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::default(),
+            clipboard: Clipboard::default(),
+            messages: Vec::new(),
+            now: Instant::now(),
+        }
+    }
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme> {
+    /// Sets the theme used to draw the widgets of the [`Simulator`].
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Finds the bounds of the first widget matching the [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Rectangle, Error> {
+        let selector = selector.into();
+        let mut find = query::find(selector.clone());
+
+        self.raw.operate(&self.renderer, &mut find);
+
+        find.matches()
+            .first()
+            .map(|found| found.bounds)
+            .ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the cursor of the [`Simulator`] to the given position.
+    pub fn point_at(&mut self, position: Point) {
+        self.cursor = mouse::Cursor::Available(position);
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    /// Clicks the center of the first widget matching the [`Selector`].
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<(), Error> {
+        let bounds = self.find(selector)?;
+
+        self.point_at(bounds.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(())
+    }
+
+    /// Presses and releases the given key, with the current modifiers.
+    pub fn tap_key(&mut self, key_code: keyboard::KeyCode) {
+        let _ = self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: self.modifiers,
+                scancode: None,
+                is_repeat: false,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers: self.modifiers,
+                scancode: None,
+            }),
+        ]);
+    }
+
+    /// Holds the given [`keyboard::Modifiers`] for the following key
+    /// presses.
+    pub fn hold(&mut self, modifiers: keyboard::Modifiers) {
+        self.modifiers = modifiers;
+
+        let _ = self.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(modifiers),
+        )]);
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn typewrite(&mut self, text: &str) {
+        let _ = self.simulate(text.chars().map(|character| {
+            Event::Keyboard(keyboard::Event::CharacterReceived(character))
+        }));
+    }
+
+    /// Advances the clock of the [`Simulator`] by the given [`Duration`],
+    /// letting animated widgets make progress.
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+
+        let _ = self.simulate([Event::Window(window::Event::RedrawRequested(
+            self.now,
+        ))]);
+    }
+
+    /// Feeds the given events to the widgets of the [`Simulator`], returning
+    /// whether each of them was captured.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Draws the widgets of the [`Simulator`], returning the resulting
+    /// [`Primitive`]s.
+    pub fn draw(&mut self) -> &[Primitive] {
+        let _ = self.raw.draw(
+            &mut self.renderer,
+            &self.theme,
+            &Style {
+                text_color: Color::BLACK,
+            },
+            self.cursor,
+        );
+
+        self.renderer.primitives()
+    }
+
+    /// Takes the messages produced by the widgets of the [`Simulator`] so
+    /// far.
+    pub fn messages(&mut self) -> impl Iterator<Item = Message> + '_ {
+        self.messages.drain(..)
+    }
+
+    /// Returns the contents of the clipboard of the [`Simulator`].
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.contents.as_deref()
+    }
+}
+
+/// A clipboard kept in memory.
+#[derive(Debug, Default)]
+struct Clipboard {
+    contents: Option<String>,
+}
+
+impl clipboard::Clipboard for Clipboard {
+    fn read(&self) -> Option<String> {
+        self.contents.clone()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.contents = Some(contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_widget::{button, column, text, text_input};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        NameChanged(String),
+        Submit,
+    }
+
+    #[derive(Debug, Default)]
+    struct Greeter {
+        count: i32,
+        name: String,
+        submitted: bool,
+    }
+
+    impl Greeter {
+        fn update(&mut self, message: Message) {
+            match message {
+                Message::Increment => self.count += 1,
+                Message::NameChanged(name) => self.name = name,
+                Message::Submit => self.submitted = true,
+            }
+        }
+
+        fn view(&self) -> Element<'_, Message, Renderer> {
+            column![
+                text(format!("Hello, {}!", self.name)),
+                text(self.count),
+                button("Increment").on_press(Message::Increment),
+                text_input("Name", &self.name)
+                    .on_input(Message::NameChanged)
+                    .on_submit(Message::Submit),
+            ]
+            .into()
+        }
+    }
+
+    /// Runs the given interaction on a fresh [`Simulator`] of the view of
+    /// the [`Greeter`], updating it with the produced messages.
+    fn interact(
+        greeter: &mut Greeter,
+        interaction: impl FnOnce(&mut Simulator<'_, Message>),
+    ) -> Vec<Message> {
+        let messages: Vec<Message> = {
+            let mut ui = simulator(greeter.view());
+            interaction(&mut ui);
+
+            ui.messages().collect()
+        };
+
+        for message in messages.iter().cloned() {
+            greeter.update(message);
+        }
+
+        messages
+    }
+
+    #[test]
+    fn click_button() {
+        let mut greeter = Greeter::default();
+
+        let messages = interact(&mut greeter, |ui| {
+            ui.click("Increment").expect("Find button");
+            ui.click("Increment").expect("Find button");
+        });
+
+        assert_eq!(messages, [Message::Increment, Message::Increment]);
+        assert_eq!(greeter.count, 2);
+    }
+
+    #[test]
+    fn type_into_text_input() {
+        let mut greeter = Greeter::default();
+
+        let messages = interact(&mut greeter, |ui| {
+            ui.click("Name").expect("Find text input");
+            ui.typewrite("iced");
+            ui.tap_key(keyboard::KeyCode::Enter);
+        });
+
+        assert_eq!(
+            messages,
+            [
+                Message::NameChanged(String::from("i")),
+                Message::NameChanged(String::from("ic")),
+                Message::NameChanged(String::from("ice")),
+                Message::NameChanged(String::from("iced")),
+                Message::Submit,
+            ]
+        );
+        assert_eq!(greeter.name, "iced");
+        assert!(greeter.submitted);
+
+        let mut ui = simulator(greeter.view());
+
+        assert!(ui.find("Hello, iced!").is_ok());
+        assert!(ui.draw().iter().any(|primitive| matches!(
+            primitive,
+            Primitive::Text { content, .. } if content == "Hello, iced!"
+        )));
+    }
+
+    #[test]
+    fn typing_needs_focus() {
+        let mut greeter = Greeter::default();
+
+        let messages = interact(&mut greeter, |ui| ui.typewrite("iced"));
+
+        assert!(messages.is_empty());
+        assert!(greeter.name.is_empty());
+    }
+
+    #[test]
+    fn missing_widget() {
+        let greeter = Greeter::default();
+        let mut ui = simulator(greeter.view());
+
+        assert_eq!(
+            ui.click("Decrement"),
+            Err(Error::NotFound(Selector::from("Decrement")))
+        );
+        assert_eq!(ui.messages().count(), 0);
+    }
+}
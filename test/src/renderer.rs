@@ -0,0 +1,374 @@
+//! Record what widgets draw, without a window or a GPU.
+use crate::core::alignment;
+use crate::core::image;
+use crate::core::renderer;
+use crate::core::svg;
+use crate::core::text::{self, LineHeight, Shaping, Text};
+use crate::core::{
+    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// The width of a character in relation to the size of its text.
+///
+/// The [`Renderer`] does not load any fonts, so it measures text as if it was
+/// monospaced.
+const CHARACTER_WIDTH: f32 = 0.6;
+
+/// A headless renderer that records the [`Primitive`]s drawn by widgets.
+#[derive(Debug)]
+pub struct Renderer<Theme = iced_style::Theme> {
+    primitives: Vec<Primitive>,
+    translation: Vector,
+    scale: f32,
+    default_font: Font,
+    default_size: Pixels,
+    theme: PhantomData<Theme>,
+}
+
+/// Something drawn by a widget, in absolute coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Primitive {
+    /// A filled quad.
+    Quad {
+        /// The [`renderer::Quad`] drawn.
+        quad: renderer::Quad,
+        /// The background of the quad.
+        background: Background,
+    },
+    /// Some text.
+    Text {
+        /// The content of the text.
+        content: String,
+        /// The position of the text.
+        position: Point,
+        /// The color of the text.
+        color: Color,
+        /// The size of the text.
+        size: Pixels,
+    },
+    /// A raster image.
+    Image {
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+    /// A vector image.
+    Svg {
+        /// The bounds of the image.
+        bounds: Rectangle,
+        /// The color the image was drawn with, if any.
+        color: Option<Color>,
+    },
+}
+
+impl<Theme> Renderer<Theme> {
+    /// Creates a new [`Renderer`] with the given default [`Font`] and text
+    /// size.
+    pub fn new(default_font: Font, default_size: Pixels) -> Self {
+        Self {
+            primitives: Vec::new(),
+            translation: Vector::new(0.0, 0.0),
+            scale: 1.0,
+            default_font,
+            default_size,
+            theme: PhantomData,
+        }
+    }
+
+    /// Returns the [`Primitive`]s drawn since the [`Renderer`] was last
+    /// cleared.
+    pub fn primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+
+    fn project(&self, point: Point) -> Point {
+        Point::new(point.x * self.scale, point.y * self.scale)
+            + self.translation
+    }
+
+    fn project_bounds(&self, bounds: Rectangle) -> Rectangle {
+        bounds * self.scale + self.translation
+    }
+}
+
+impl<Theme> Default for Renderer<Theme> {
+    fn default() -> Self {
+        Self::new(Font::default(), Pixels(16.0))
+    }
+}
+
+impl<Theme> renderer::Renderer for Renderer<Theme> {
+    type Theme = Theme;
+
+    fn with_layer(&mut self, _bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    fn with_translation(
+        &mut self,
+        translation: Vector,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let previous = self.translation;
+        self.translation = previous + translation * self.scale;
+
+        f(self);
+
+        self.translation = previous;
+    }
+
+    fn with_scale(&mut self, scale: f32, f: impl FnOnce(&mut Self)) {
+        let previous = self.scale;
+        self.scale = previous * scale;
+
+        f(self);
+
+        self.scale = previous;
+    }
+
+    fn fill_quad(
+        &mut self,
+        quad: renderer::Quad,
+        background: impl Into<Background>,
+    ) {
+        self.primitives.push(Primitive::Quad {
+            quad: renderer::Quad {
+                bounds: self.project_bounds(quad.bounds),
+                ..quad
+            },
+            background: background.into(),
+        });
+    }
+
+    fn clear(&mut self) {
+        self.primitives.clear();
+    }
+}
+
+impl<Theme> text::Renderer for Renderer<Theme> {
+    type Font = Font;
+    type Paragraph = Paragraph;
+
+    const ICON_FONT: Font = Font::with_name("Iced-Icons");
+    const CHECKMARK_ICON: char = '\u{f00c}';
+    const ARROW_DOWN_ICON: char = '\u{e800}';
+
+    fn default_font(&self) -> Self::Font {
+        self.default_font
+    }
+
+    fn default_size(&self) -> Pixels {
+        self.default_size
+    }
+
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+
+    fn create_paragraph(&self, text: Text<'_, Self::Font>) -> Self::Paragraph {
+        Paragraph::new(text)
+    }
+
+    fn resize_paragraph(
+        &self,
+        paragraph: &mut Self::Paragraph,
+        new_bounds: Size,
+    ) {
+        paragraph.bounds = new_bounds;
+    }
+
+    fn fill_paragraph(
+        &mut self,
+        paragraph: &Self::Paragraph,
+        position: Point,
+        color: Color,
+    ) {
+        self.primitives.push(Primitive::Text {
+            content: paragraph.content.clone(),
+            position: self.project(position),
+            color,
+            size: Pixels(paragraph.size.0 * self.scale),
+        });
+    }
+
+    fn fill_text(
+        &mut self,
+        text: Text<'_, Self::Font>,
+        position: Point,
+        color: Color,
+    ) {
+        self.primitives.push(Primitive::Text {
+            content: text.content.to_owned(),
+            position: self.project(position),
+            color,
+            size: Pixels(text.size.0 * self.scale),
+        });
+    }
+}
+
+impl<Theme> image::Renderer for Renderer<Theme> {
+    type Handle = image::Handle;
+
+    fn dimensions(&self, handle: &Self::Handle) -> Size<u32> {
+        // Only decoded images know their size without decoding them
+        match handle.data() {
+            image::Data::Rgba { width, height, .. }
+            | image::Data::Texture { width, height, .. }
+            | image::Data::Raw { width, height, .. } => {
+                Size::new(*width, *height)
+            }
+            _ => Size::new(0, 0),
+        }
+    }
+
+    fn draw(&mut self, image: image::Image<Self::Handle>) {
+        self.primitives.push(Primitive::Image {
+            bounds: self.project_bounds(image.bounds),
+        });
+    }
+}
+
+impl<Theme> svg::Renderer for Renderer<Theme> {
+    fn dimensions(&self, _handle: &svg::Handle) -> Size<u32> {
+        Size::new(0, 0)
+    }
+
+    fn draw(
+        &mut self,
+        _handle: svg::Handle,
+        color: Option<Color>,
+        bounds: Rectangle,
+    ) {
+        self.primitives.push(Primitive::Svg {
+            bounds: self.project_bounds(bounds),
+            color,
+        });
+    }
+}
+
+/// A paragraph measured by a [`Renderer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paragraph {
+    content: String,
+    size: Pixels,
+    line_height: LineHeight,
+    font: Font,
+    shaping: Shaping,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    bounds: Size,
+}
+
+impl Paragraph {
+    fn new(text: Text<'_, Font>) -> Self {
+        Self {
+            content: text.content.to_owned(),
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            shaping: text.shaping,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            bounds: text.bounds,
+        }
+    }
+
+    fn absolute_line_height(&self) -> f32 {
+        self.line_height.to_absolute(self.size).0
+    }
+}
+
+impl Default for Paragraph {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            size: Pixels(16.0),
+            line_height: LineHeight::default(),
+            font: Font::default(),
+            shaping: Shaping::default(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            bounds: Size::ZERO,
+        }
+    }
+}
+
+impl text::Paragraph for Paragraph {
+    type Font = Font;
+
+    fn content(&self) -> &str {
+        &self.content
+    }
+
+    fn text_size(&self) -> Pixels {
+        self.size
+    }
+
+    fn line_height(&self) -> LineHeight {
+        self.line_height
+    }
+
+    fn font(&self) -> Self::Font {
+        self.font
+    }
+
+    fn shaping(&self) -> Shaping {
+        self.shaping
+    }
+
+    fn horizontal_alignment(&self) -> alignment::Horizontal {
+        self.horizontal_alignment
+    }
+
+    fn vertical_alignment(&self) -> alignment::Vertical {
+        self.vertical_alignment
+    }
+
+    fn bounds(&self) -> Size {
+        self.bounds
+    }
+
+    fn min_bounds(&self) -> Size {
+        let lines = self.content.lines();
+        let line_count = lines.clone().count().max(1);
+
+        let width = lines
+            .map(|line| line.chars().count() as f32)
+            .fold(0.0, f32::max)
+            * self.size.0
+            * CHARACTER_WIDTH;
+
+        Size::new(
+            width.min(self.bounds.width),
+            line_count as f32 * self.absolute_line_height(),
+        )
+    }
+
+    fn hit_test(&self, point: Point) -> Option<text::Hit> {
+        let line = (point.y / self.absolute_line_height()).floor().max(0.0);
+        let column =
+            (point.x / (self.size.0 * CHARACTER_WIDTH)).round().max(0.0);
+
+        let offset = self
+            .content
+            .lines()
+            .take(line as usize)
+            .map(|line| line.chars().count() + 1)
+            .sum::<usize>();
+
+        let length = self.content.chars().count();
+
+        Some(text::Hit::CharOffset(
+            (offset + column as usize).min(length),
+        ))
+    }
+
+    fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
+        let _ = self.content.lines().nth(line)?;
+
+        Some(Point::new(
+            index as f32 * self.size.0 * CHARACTER_WIDTH,
+            line as f32 * self.absolute_line_height(),
+        ))
+    }
+}
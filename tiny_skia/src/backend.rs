@@ -1,8 +1,11 @@
-use crate::core::{Background, Color, Gradient, Rectangle, Vector};
+use crate::core::gradient::{self, ColorStop};
+use crate::core::{Background, Color, Gradient, Rectangle, Shadow, Vector};
 use crate::graphics::backend;
+use crate::graphics::compositor::ImageCache;
 use crate::graphics::text;
 use crate::graphics::{Damage, Viewport};
 use crate::primitive::{self, Primitive};
+use crate::Settings;
 
 use std::borrow::Cow;
 
@@ -17,15 +20,22 @@ pub struct Backend {
 }
 
 impl Backend {
-    pub fn new() -> Self {
+    pub fn new(settings: Settings) -> Self {
+        #[cfg(not(any(feature = "image", feature = "svg")))]
+        let _ = settings;
+
         Self {
             text_pipeline: crate::text::Pipeline::new(),
 
             #[cfg(feature = "image")]
-            raster_pipeline: crate::raster::Pipeline::new(),
+            raster_pipeline: crate::raster::Pipeline::new(
+                settings.image_cache_budget,
+            ),
 
             #[cfg(feature = "svg")]
-            vector_pipeline: crate::vector::Pipeline::new(),
+            vector_pipeline: crate::vector::Pipeline::new(
+                settings.svg_size_bucket,
+            ),
         }
     }
 
@@ -153,15 +163,29 @@ impl Backend {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let physical_bounds = (*bounds + translation) * scale_factor;
 
-                if !clip_bounds.intersects(&physical_bounds) {
+                let physical_shadow_bounds =
+                    (!shadow.is_invisible()).then(|| {
+                        (shadow.bounds(*bounds) + translation) * scale_factor
+                    });
+
+                if !clip_bounds.intersects(&physical_bounds)
+                    && !physical_shadow_bounds.is_some_and(|shadow_bounds| {
+                        clip_bounds.intersects(&shadow_bounds)
+                    })
+                {
                     return;
                 }
 
+                let shadow_clip_mask = physical_shadow_bounds
+                    .is_some_and(|bounds| !bounds.is_within(&clip_bounds))
+                    .then_some(&*clip_mask);
+
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-                    .then_some(clip_mask as &_);
+                    .then_some(&*clip_mask);
 
                 let transform = tiny_skia::Transform::from_translate(
                     translation.x,
@@ -182,6 +206,31 @@ impl Backend {
                 }
                 let path = rounded_rectangle(*bounds, fill_border_radius);
 
+                if let Some(shadow_bounds) =
+                    physical_shadow_bounds.and_then(|shadow_bounds| {
+                        shadow_bounds.intersection(&clip_bounds)
+                    })
+                {
+                    draw_shadow(
+                        pixels,
+                        shadow,
+                        physical_bounds,
+                        shadow_bounds,
+                        fill_border_radius.map(|radius| radius * scale_factor),
+                        scale_factor,
+                        shadow_clip_mask,
+                    );
+                }
+
+                // tiny-skia has no conic gradients, so they are drawn into a
+                // pixmap first
+                let conic = match background {
+                    Background::Gradient(Gradient::Conic(conic)) => {
+                        conic_pixmap(conic, *bounds, scale_factor)
+                    }
+                    _ => None,
+                };
+
                 pixels.fill_path(
                     &path,
                     &tiny_skia::Paint {
@@ -195,44 +244,56 @@ impl Backend {
                                 let (start, end) =
                                     linear.angle.to_distance(bounds);
 
-                                let stops: Vec<tiny_skia::GradientStop> =
-                                    linear
-                                        .stops
-                                        .into_iter()
-                                        .flatten()
-                                        .map(|stop| {
-                                            tiny_skia::GradientStop::new(
-                                                stop.offset,
-                                                tiny_skia::Color::from_rgba(
-                                                    stop.color.b,
-                                                    stop.color.g,
-                                                    stop.color.r,
-                                                    stop.color.a,
-                                                )
-                                                .expect("Create color"),
-                                            )
-                                        })
-                                        .collect();
-
                                 tiny_skia::LinearGradient::new(
                                     tiny_skia::Point {
                                         x: start.x,
                                         y: start.y,
                                     },
                                     tiny_skia::Point { x: end.x, y: end.y },
-                                    if stops.is_empty() {
-                                        vec![tiny_skia::GradientStop::new(
-                                            0.0,
-                                            tiny_skia::Color::BLACK,
-                                        )]
-                                    } else {
-                                        stops
-                                    },
+                                    into_gradient_stops(&linear.stops),
                                     tiny_skia::SpreadMode::Pad,
                                     tiny_skia::Transform::identity(),
                                 )
                                 .expect("Create linear gradient")
                             }
+                            Background::Gradient(Gradient::Radial(radial)) => {
+                                let (center, radius) = radial.to_circle(bounds);
+
+                                let center = tiny_skia::Point {
+                                    x: center.x,
+                                    y: center.y,
+                                };
+
+                                tiny_skia::RadialGradient::new(
+                                    center,
+                                    center,
+                                    radius,
+                                    into_gradient_stops(&radial.stops),
+                                    tiny_skia::SpreadMode::Pad,
+                                    tiny_skia::Transform::identity(),
+                                )
+                                .unwrap_or(
+                                    tiny_skia::Shader::SolidColor(
+                                        tiny_skia::Color::TRANSPARENT,
+                                    ),
+                                )
+                            }
+                            Background::Gradient(Gradient::Conic(_)) => {
+                                match &conic {
+                                    Some((pixmap, transform)) => {
+                                        tiny_skia::Pattern::new(
+                                            pixmap.as_ref(),
+                                            tiny_skia::SpreadMode::Pad,
+                                            tiny_skia::FilterQuality::Bilinear,
+                                            1.0,
+                                            *transform,
+                                        )
+                                    }
+                                    None => tiny_skia::Shader::SolidColor(
+                                        tiny_skia::Color::TRANSPARENT,
+                                    ),
+                                }
+                            }
                         },
                         anti_alias: true,
                         ..tiny_skia::Paint::default()
@@ -421,7 +482,14 @@ impl Backend {
                 );
             }
             #[cfg(feature = "image")]
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                filter_method,
+                orientation,
+                effects,
+                region,
+                bounds,
+            } => {
                 let physical_bounds = (*bounds + translation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
@@ -437,8 +505,17 @@ impl Backend {
                 )
                 .post_scale(scale_factor, scale_factor);
 
-                self.raster_pipeline
-                    .draw(handle, *bounds, pixels, transform, clip_mask);
+                self.raster_pipeline.draw(
+                    handle,
+                    *filter_method,
+                    *orientation,
+                    effects,
+                    *region,
+                    *bounds,
+                    pixels,
+                    transform,
+                    clip_mask,
+                );
             }
             #[cfg(not(feature = "image"))]
             Primitive::Image { .. } => {
@@ -566,6 +643,18 @@ impl Backend {
                     translation + *offset,
                 );
             }
+            Primitive::Scale { scale, content } => {
+                // Scaling the content is the same as drawing it with a
+                // larger scale factor, once the translation is scaled back
+                self.draw_primitive(
+                    content,
+                    pixels,
+                    clip_mask,
+                    clip_bounds,
+                    scale_factor * scale,
+                    translation * (1.0 / scale),
+                );
+            }
             Primitive::Clip { bounds, content } => {
                 let bounds = (*bounds + translation) * scale_factor;
 
@@ -603,6 +692,69 @@ impl Backend {
                     adjust_clip_mask(clip_mask, clip_bounds);
                 }
             }
+            Primitive::Backdrop {
+                bounds,
+                border_radius,
+                radius,
+                content,
+            } => {
+                let physical_bounds = (*bounds + translation) * scale_factor;
+
+                if let Some(blurred_bounds) =
+                    clip_bounds.intersection(&physical_bounds)
+                {
+                    blur_backdrop(
+                        pixels,
+                        physical_bounds,
+                        blurred_bounds,
+                        border_radius.map(|radius| radius * scale_factor),
+                        radius * scale_factor,
+                    );
+                }
+
+                self.draw_primitive(
+                    content,
+                    pixels,
+                    clip_mask,
+                    clip_bounds,
+                    scale_factor,
+                    translation,
+                );
+            }
+            Primitive::Opacity { opacity, content } => {
+                if *opacity <= 0.0 {
+                    return;
+                }
+
+                let Some(mut layer) =
+                    tiny_skia::Pixmap::new(pixels.width(), pixels.height())
+                else {
+                    return;
+                };
+
+                // The content is drawn in its own layer first, so overlapping
+                // primitives do not show through each other
+                self.draw_primitive(
+                    content,
+                    &mut layer.as_mut(),
+                    clip_mask,
+                    clip_bounds,
+                    scale_factor,
+                    translation,
+                );
+
+                pixels.draw_pixmap(
+                    0,
+                    0,
+                    layer.as_ref(),
+                    &tiny_skia::PixmapPaint {
+                        opacity: opacity.min(1.0),
+                        ..Default::default()
+                    },
+                    tiny_skia::Transform::identity(),
+                    None,
+                );
+            }
             Primitive::Cache { content } => {
                 self.draw_primitive(
                     content,
@@ -628,6 +780,368 @@ fn into_color(color: Color) -> tiny_skia::Color {
         .expect("Convert color from iced to tiny_skia")
 }
 
+fn into_gradient_stops(
+    stops: &[Option<ColorStop>; 8],
+) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<_> = stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            tiny_skia::GradientStop::new(stop.offset, into_color(stop.color))
+        })
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+/// Draws a [`gradient::Conic`] filling the given bounds into a pixmap, and
+/// returns it with the transform placing it in the bounds.
+fn conic_pixmap(
+    conic: &gradient::Conic,
+    bounds: Rectangle,
+    scale_factor: f32,
+) -> Option<(tiny_skia::Pixmap, tiny_skia::Transform)> {
+    let width = (bounds.width * scale_factor).ceil().max(1.0) as u32;
+    let height = (bounds.height * scale_factor).ceil().max(1.0) as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+    let center = conic.to_center(&bounds);
+
+    for (i, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        let x = bounds.x + ((i as u32 % width) as f32 + 0.5) / scale_factor;
+        let y = bounds.y + ((i as u32 / width) as f32 + 0.5) / scale_factor;
+
+        let angle = (y - center.y).atan2(x - center.x)
+            + std::f32::consts::FRAC_PI_2
+            - conic.angle.0;
+
+        let color = color_at(
+            &conic.stops,
+            (angle / std::f32::consts::TAU).rem_euclid(1.0),
+        );
+
+        *pixel = into_color(color).premultiply().to_color_u8();
+    }
+
+    let transform = tiny_skia::Transform::from_scale(
+        1.0 / scale_factor,
+        1.0 / scale_factor,
+    )
+    .post_translate(bounds.x, bounds.y);
+
+    Some((pixmap, transform))
+}
+
+/// Interpolates the color of the given stops at the given offset.
+fn color_at(stops: &[Option<ColorStop>; 8], offset: f32) -> Color {
+    let mut stops = stops.iter().flatten();
+
+    let Some(mut previous) = stops.next() else {
+        return Color::BLACK;
+    };
+
+    if offset <= previous.offset {
+        return previous.color;
+    }
+
+    for stop in stops {
+        if offset <= stop.offset {
+            let t = (offset - previous.offset)
+                / (stop.offset - previous.offset).max(f32::EPSILON);
+
+            let mix = |a: f32, b: f32| a + (b - a) * t;
+
+            return Color::new(
+                mix(previous.color.r, stop.color.r),
+                mix(previous.color.g, stop.color.g),
+                mix(previous.color.b, stop.color.b),
+                mix(previous.color.a, stop.color.a),
+            );
+        }
+
+        previous = stop;
+    }
+
+    previous.color
+}
+
+fn draw_shadow(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    shadow: &Shadow,
+    physical_bounds: Rectangle,
+    shadow_bounds: Rectangle,
+    radii: [f32; 4],
+    scale_factor: f32,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let x = shadow_bounds.x.floor() as i32;
+    let y = shadow_bounds.y.floor() as i32;
+    let width = (shadow_bounds.x + shadow_bounds.width).ceil() as i32 - x;
+    let height = (shadow_bounds.y + shadow_bounds.height).ceil() as i32 - y;
+
+    let Some(size) = tiny_skia::IntSize::from_wh(width as u32, height as u32)
+    else {
+        return;
+    };
+
+    let spread_radius = shadow.spread_radius * scale_factor;
+    let blur_radius = (shadow.blur_radius * scale_factor).max(0.5);
+
+    let half_size = Vector::new(
+        physical_bounds.width / 2.0 + spread_radius,
+        physical_bounds.height / 2.0 + spread_radius,
+    );
+
+    let center = Vector::new(
+        physical_bounds.x + physical_bounds.width / 2.0,
+        physical_bounds.y + physical_bounds.height / 2.0,
+    ) + shadow.offset * scale_factor;
+
+    let radii = radii.map(|radius| {
+        (radius + spread_radius)
+            .min(half_size.x)
+            .min(half_size.y)
+            .max(0.0)
+    });
+
+    let data = (y..y + height)
+        .flat_map(|y| (x..x + width).map(move |x| (x, y)))
+        .flat_map(|(x, y)| {
+            let distance = rounded_box_sdf(
+                Vector::new(
+                    x as f32 + 0.5 - center.x,
+                    y as f32 + 0.5 - center.y,
+                ),
+                half_size,
+                radii,
+            );
+
+            let mut color = into_color(shadow.color);
+            color.apply_opacity(
+                1.0 - smoothstep(-blur_radius, blur_radius, distance),
+            );
+
+            let color = color.premultiply().to_color_u8();
+
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+
+    if let Some(pixmap) = tiny_skia::Pixmap::from_vec(data, size) {
+        pixels.draw_pixmap(
+            x,
+            y,
+            pixmap.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::identity(),
+            clip_mask,
+        );
+    }
+}
+
+/// Blurs the pixels inside of the given rounded bounds.
+///
+/// The gaussian blur of the GPU is approximated with three box blurs.
+fn blur_backdrop(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    physical_bounds: Rectangle,
+    blurred_bounds: Rectangle,
+    radii: [f32; 4],
+    radius: f32,
+) {
+    let width = pixels.width() as i32;
+    let height = pixels.height() as i32;
+
+    let x0 = (blurred_bounds.x.floor() as i32).max(0);
+    let y0 = (blurred_bounds.y.floor() as i32).max(0);
+    let x1 =
+        ((blurred_bounds.x + blurred_bounds.width).ceil() as i32).min(width);
+    let y1 =
+        ((blurred_bounds.y + blurred_bounds.height).ceil() as i32).min(height);
+
+    if x0 >= x1 || y0 >= y1 {
+        return;
+    }
+
+    // The pixels around the bounds bleed into the blurred area
+    let padding = radius.max(0.0).ceil() as i32;
+
+    let region_x = (x0 - padding).max(0);
+    let region_y = (y0 - padding).max(0);
+    let region_width = ((x1 + padding).min(width) - region_x) as usize;
+    let region_height = ((y1 + padding).min(height) - region_y) as usize;
+
+    let mut region: Vec<[f32; 4]> = {
+        let source = pixels.pixels();
+
+        (region_y..region_y + region_height as i32)
+            .flat_map(|y| {
+                (region_x..region_x + region_width as i32)
+                    .map(move |x| (y * width + x) as usize)
+            })
+            .map(|i| {
+                let pixel = source[i];
+
+                [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+                    .map(f32::from)
+            })
+            .collect()
+    };
+
+    let sigma = (radius / 2.0).max(0.5);
+    let box_radius =
+        (((4.0 * sigma * sigma + 1.0).sqrt() - 1.0) / 2.0).round() as usize;
+
+    if box_radius > 0 {
+        for _ in 0..3 {
+            box_blur(
+                &mut region,
+                region_height,
+                region_width,
+                |line, i| line * region_width + i,
+                box_radius,
+            );
+
+            box_blur(
+                &mut region,
+                region_width,
+                region_height,
+                |line, i| i * region_width + line,
+                box_radius,
+            );
+        }
+    }
+
+    let half_size =
+        Vector::new(physical_bounds.width / 2.0, physical_bounds.height / 2.0);
+
+    let center = Vector::new(
+        physical_bounds.x + half_size.x,
+        physical_bounds.y + half_size.y,
+    );
+
+    let radii =
+        radii.map(|radius| radius.min(half_size.x).min(half_size.y).max(0.0));
+
+    let target = pixels.pixels_mut();
+
+    for y in y0..y1 {
+        for x in x0..x1 {
+            let distance = rounded_box_sdf(
+                Vector::new(
+                    x as f32 + 0.5 - center.x,
+                    y as f32 + 0.5 - center.y,
+                ),
+                half_size,
+                radii,
+            );
+
+            let coverage = 1.0 - smoothstep(-0.5, 0.5, distance);
+
+            if coverage <= 0.0 {
+                continue;
+            }
+
+            let blurred = region[(y - region_y) as usize * region_width
+                + (x - region_x) as usize];
+
+            let pixel = &mut target[(y * width + x) as usize];
+
+            let current =
+                [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()];
+
+            let [r, g, b, a] = [0, 1, 2, 3].map(|i| {
+                let current = f32::from(current[i]);
+
+                (current + (blurred[i] - current) * coverage)
+                    .round()
+                    .clamp(0.0, 255.0) as u8
+            });
+
+            if let Some(color) = tiny_skia::PremultipliedColorU8::from_rgba(
+                r.min(a),
+                g.min(a),
+                b.min(a),
+                a,
+            ) {
+                *pixel = color;
+            }
+        }
+    }
+}
+
+/// Blurs every line of the given data with a box of the given radius.
+fn box_blur(
+    data: &mut [[f32; 4]],
+    lines: usize,
+    length: usize,
+    index: impl Fn(usize, usize) -> usize,
+    radius: usize,
+) {
+    let size = (radius * 2 + 1) as f32;
+    let mut line = vec![[0.0; 4]; length];
+
+    for i in 0..lines {
+        for (j, pixel) in line.iter_mut().enumerate() {
+            *pixel = data[index(i, j)];
+        }
+
+        // The edges of the line are extended
+        let at = |j: isize| line[j.clamp(0, length as isize - 1) as usize];
+
+        let mut sum = [0.0; 4];
+
+        for j in -(radius as isize)..=radius as isize {
+            for (sum, channel) in sum.iter_mut().zip(at(j)) {
+                *sum += channel;
+            }
+        }
+
+        for j in 0..length {
+            data[index(i, j)] = sum.map(|channel| channel / size);
+
+            let incoming = at((j + radius + 1) as isize);
+            let outgoing = at(j as isize - radius as isize);
+
+            for ((sum, incoming), outgoing) in
+                sum.iter_mut().zip(incoming).zip(outgoing)
+            {
+                *sum += incoming - outgoing;
+            }
+        }
+    }
+}
+
+fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
+    let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
+
+    x * x * (3.0 - 2.0 * x)
+}
+
+fn rounded_box_sdf(
+    to_center: Vector,
+    half_size: Vector,
+    radii: [f32; 4],
+) -> f32 {
+    // The radii are in the same order as the border radius of a quad:
+    // top-left, top-right, bottom-right, and bottom-left
+    let radius = match (to_center.x > 0.0, to_center.y > 0.0) {
+        (false, false) => radii[0],
+        (true, false) => radii[1],
+        (true, true) => radii[2],
+        (false, true) => radii[3],
+    };
+
+    let x = (to_center.x.abs() - half_size.x + radius).max(0.0);
+    let y = (to_center.y.abs() - half_size.y + radius).max(0.0);
+
+    (x * x + y * y).sqrt() - radius
+}
+
 fn rounded_rectangle(
     bounds: Rectangle,
     border_radius: [f32; 4],
@@ -800,6 +1314,18 @@ fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
 
 impl iced_graphics::Backend for Backend {
     type Primitive = primitive::Custom;
+
+    fn image_cache(&self) -> Option<ImageCache> {
+        #[cfg(feature = "image")]
+        {
+            Some(self.raster_pipeline.image_cache())
+        }
+
+        #[cfg(not(feature = "image"))]
+        {
+            None
+        }
+    }
 }
 
 impl backend::Text for Backend {
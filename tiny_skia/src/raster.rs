@@ -1,19 +1,19 @@
 use crate::core::image as raster;
-use crate::core::{Rectangle, Size};
+use crate::core::{Point, Rectangle, Size};
 use crate::graphics;
+use crate::graphics::compositor::ImageCache;
 
-use rustc_hash::{FxHashMap, FxHashSet};
 use std::cell::RefCell;
-use std::collections::hash_map;
+use std::collections::{HashMap, HashSet};
 
 pub struct Pipeline {
     cache: RefCell<Cache>,
 }
 
 impl Pipeline {
-    pub fn new() -> Self {
+    pub fn new(image_cache_budget: Option<usize>) -> Self {
         Self {
-            cache: RefCell::new(Cache::default()),
+            cache: RefCell::new(Cache::new(image_cache_budget)),
         }
     }
 
@@ -28,23 +28,73 @@ impl Pipeline {
     pub fn draw(
         &mut self,
         handle: &raster::Handle,
+        filter_method: raster::FilterMethod,
+        orientation: raster::Orientation,
+        effects: &raster::Effects,
+        region: Option<Rectangle>,
         bounds: Rectangle,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         transform: tiny_skia::Transform,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
-        if let Some(image) = self.cache.borrow_mut().allocate(handle) {
-            let width_scale = bounds.width / image.width() as f32;
-            let height_scale = bounds.height / image.height() as f32;
+        let quality = quality(filter_method);
+        let mut cache = self.cache.borrow_mut();
+
+        // The opacity alone does not need a filtered copy of the image
+        let is_opacity_only = raster::Effects {
+            opacity: 1.0,
+            ..*effects
+        }
+        .is_identity();
+
+        let (image, opacity) = if is_opacity_only {
+            (cache.allocate(handle), effects.opacity.clamp(0.0, 1.0))
+        } else {
+            (cache.allocate_with_effects(handle, effects), 1.0)
+        };
+
+        if let Some(image) = image {
+            if let Some(region) = region {
+                draw_region(
+                    image,
+                    quality,
+                    opacity,
+                    orientation,
+                    region,
+                    bounds,
+                    pixels,
+                    transform,
+                    clip_mask,
+                );
 
-            let transform = transform.pre_scale(width_scale, height_scale);
+                return;
+            }
+
+            let width = image.width() as f32;
+            let height = image.height() as f32;
+
+            // Map the corners of the image to their oriented position
+            let origin = orientation.apply(Point::ORIGIN);
+            let x_axis = orientation.apply(Point::new(1.0, 0.0)) - origin;
+            let y_axis = orientation.apply(Point::new(0.0, 1.0)) - origin;
+
+            let transform =
+                transform.pre_concat(tiny_skia::Transform::from_row(
+                    bounds.width * x_axis.x / width,
+                    bounds.height * x_axis.y / width,
+                    bounds.width * y_axis.x / height,
+                    bounds.height * y_axis.y / height,
+                    bounds.x + bounds.width * origin.x,
+                    bounds.y + bounds.height * origin.y,
+                ));
 
             pixels.draw_pixmap(
-                (bounds.x / width_scale) as i32,
-                (bounds.y / height_scale) as i32,
+                0,
+                0,
                 image,
                 &tiny_skia::PixmapPaint {
-                    quality: tiny_skia::FilterQuality::Bilinear,
+                    quality,
+                    opacity,
                     ..Default::default()
                 },
                 transform,
@@ -56,22 +106,128 @@ impl Pipeline {
     pub fn trim_cache(&mut self) {
         self.cache.borrow_mut().trim();
     }
+
+    pub fn image_cache(&self) -> ImageCache {
+        self.cache.borrow().cache.usage()
+    }
 }
 
-#[derive(Default)]
+/// Returns the [`tiny_skia::FilterQuality`] of a [`raster::FilterMethod`].
+///
+/// There are no mipmaps in software, so bicubic filtering is the closest
+/// match to the smoother methods.
+fn quality(filter_method: raster::FilterMethod) -> tiny_skia::FilterQuality {
+    match filter_method {
+        raster::FilterMethod::Nearest => tiny_skia::FilterQuality::Nearest,
+        raster::FilterMethod::Linear => tiny_skia::FilterQuality::Bilinear,
+        raster::FilterMethod::Trilinear | raster::FilterMethod::Anisotropic => {
+            tiny_skia::FilterQuality::Bicubic
+        }
+    }
+}
+
+/// Draws a region of an image, in pixels, by filling the `bounds` with the
+/// image as a pattern.
+fn draw_region(
+    image: tiny_skia::PixmapRef<'_>,
+    quality: tiny_skia::FilterQuality,
+    opacity: f32,
+    orientation: raster::Orientation,
+    region: Rectangle,
+    bounds: Rectangle,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    transform: tiny_skia::Transform,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let Some(rect) = tiny_skia::Rect::from_xywh(
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+    ) else {
+        return;
+    };
+
+    // Map the corners of the region to their oriented position
+    let origin = orientation.apply(Point::ORIGIN);
+    let x_axis = orientation.apply(Point::new(1.0, 0.0)) - origin;
+    let y_axis = orientation.apply(Point::new(0.0, 1.0)) - origin;
+
+    let pattern_transform = tiny_skia::Transform::from_row(
+        bounds.width * x_axis.x / region.width,
+        bounds.height * x_axis.y / region.width,
+        bounds.width * y_axis.x / region.height,
+        bounds.height * y_axis.y / region.height,
+        bounds.x + bounds.width * origin.x,
+        bounds.y + bounds.height * origin.y,
+    )
+    .pre_translate(-region.x, -region.y);
+
+    pixels.fill_rect(
+        rect,
+        &tiny_skia::Paint {
+            shader: tiny_skia::Pattern::new(
+                image,
+                tiny_skia::SpreadMode::Pad,
+                quality,
+                opacity,
+                pattern_transform,
+            ),
+            ..Default::default()
+        },
+        transform,
+        clip_mask,
+    );
+}
+
+/// The key of an image filtered with some [`raster::Effects`]: the id of its
+/// handle and the bits of its color matrix.
+type Filtered = (u64, [u32; 20]);
+
 struct Cache {
-    entries: FxHashMap<u64, Option<Entry>>,
-    hits: FxHashSet<u64>,
+    cache: graphics::image::Cache<Entry>,
+    filtered: HashMap<Filtered, tiny_skia::Pixmap>,
+    filtered_hits: HashSet<Filtered>,
 }
 
 impl Cache {
-    pub fn allocate(
+    fn new(budget: Option<usize>) -> Self {
+        Self {
+            cache: graphics::image::Cache::new(budget),
+            filtered: HashMap::new(),
+            filtered_hits: HashSet::new(),
+        }
+    }
+
+    /// Returns a copy of the image of the given handle with the given
+    /// [`raster::Effects`] applied.
+    ///
+    /// The copies are kept around as long as they are drawn every frame.
+    pub fn allocate_with_effects(
         &mut self,
         handle: &raster::Handle,
+        effects: &raster::Effects,
     ) -> Option<tiny_skia::PixmapRef<'_>> {
-        let id = handle.id();
+        let matrix = effects.color_matrix();
+        let key = (handle.id(), matrix.map(f32::to_bits));
 
-        if let hash_map::Entry::Vacant(entry) = self.entries.entry(id) {
+        if !self.filtered.contains_key(&key) {
+            let image = self.allocate(handle)?;
+            let pixmap = apply_color_matrix(image, &matrix);
+
+            let _ = self.filtered.insert(key, pixmap);
+        }
+
+        let _ = self.filtered_hits.insert(key);
+
+        self.filtered.get(&key).map(tiny_skia::Pixmap::as_ref)
+    }
+
+    pub fn allocate(
+        &mut self,
+        handle: &raster::Handle,
+    ) -> Option<tiny_skia::PixmapRef<'_>> {
+        if !self.cache.contains(handle) {
             let image = graphics::image::load(handle).ok()?.into_rgba8();
 
             let mut buffer =
@@ -85,15 +241,20 @@ impl Cache {
                 );
             }
 
-            let _ = entry.insert(Some(Entry {
-                width: image.width(),
-                height: image.height(),
-                pixels: buffer,
-            }));
+            let bytes = buffer.len() * 4;
+
+            let _ = self.cache.insert(
+                handle,
+                Entry {
+                    width: image.width(),
+                    height: image.height(),
+                    pixels: buffer,
+                },
+                bytes,
+            );
         }
 
-        let _ = self.hits.insert(id);
-        self.entries.get(&id).unwrap().as_ref().map(|entry| {
+        self.cache.get(handle).map(|entry| {
             tiny_skia::PixmapRef::from_bytes(
                 bytemuck::cast_slice(&entry.pixels),
                 entry.width,
@@ -104,11 +265,48 @@ impl Cache {
     }
 
     fn trim(&mut self) {
-        self.entries.retain(|key, _| self.hits.contains(key));
-        self.hits.clear();
+        self.cache.trim(drop);
+
+        let hits = &self.filtered_hits;
+        self.filtered.retain(|key, _| hits.contains(key));
+        self.filtered_hits.clear();
     }
 }
 
+/// Applies a color matrix, as described by [`raster::Effects::color_matrix`],
+/// to a copy of the given image.
+fn apply_color_matrix(
+    image: tiny_skia::PixmapRef<'_>,
+    matrix: &[f32; 20],
+) -> tiny_skia::Pixmap {
+    let mut pixmap = image.to_owned();
+
+    for pixel in pixmap.pixels_mut() {
+        let color = pixel.demultiply();
+
+        // The channels are stored in BGRA order
+        let input = [color.blue(), color.green(), color.red(), color.alpha()]
+            .map(|channel| f32::from(channel) / 255.0);
+
+        let [r, g, b, a]: [u8; 4] = std::array::from_fn(|row| {
+            let factors = &matrix[row * 5..row * 5 + 5];
+
+            let value = factors[4]
+                + factors[..4]
+                    .iter()
+                    .zip(input)
+                    .map(|(factor, channel)| factor * channel)
+                    .sum::<f32>();
+
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8
+        });
+
+        *pixel = tiny_skia::ColorU8::from_rgba(b, g, r, a).premultiply();
+    }
+
+    pixmap
+}
+
 struct Entry {
     width: u32,
     height: u32,
@@ -1,5 +1,6 @@
-use crate::core::svg::{Data, Handle};
+use crate::core::svg::{self, Data, Handle};
 use crate::core::{Color, Rectangle, Size};
+use crate::graphics::svg::{Prerender, Tracker};
 
 use resvg::usvg;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -13,9 +14,12 @@ pub struct Pipeline {
 }
 
 impl Pipeline {
-    pub fn new() -> Self {
+    pub fn new(size_bucket: Option<u32>) -> Self {
         Self {
-            cache: RefCell::new(Cache::default()),
+            cache: RefCell::new(Cache {
+                size_bucket,
+                ..Cache::default()
+            }),
         }
     }
 
@@ -34,19 +38,39 @@ impl Pipeline {
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
-        if let Some(image) = self.cache.borrow_mut().draw(
-            handle,
-            color,
-            Size::new(bounds.width as u32, bounds.height as u32),
-        ) {
-            pixels.draw_pixmap(
-                bounds.x as i32,
-                bounds.y as i32,
-                image,
-                &tiny_skia::PixmapPaint::default(),
-                tiny_skia::Transform::identity(),
-                clip_mask,
-            );
+        let size = Size::new(bounds.width as u32, bounds.height as u32);
+
+        if let Some(image) = self.cache.borrow_mut().draw(handle, color, size) {
+            if image.width() == size.width && image.height() == size.height {
+                pixels.draw_pixmap(
+                    bounds.x as i32,
+                    bounds.y as i32,
+                    image,
+                    &tiny_skia::PixmapPaint::default(),
+                    tiny_skia::Transform::identity(),
+                    clip_mask,
+                );
+            } else {
+                // The raster was rounded up to a bucket
+                pixels.draw_pixmap(
+                    0,
+                    0,
+                    image,
+                    &tiny_skia::PixmapPaint {
+                        quality: tiny_skia::FilterQuality::Bilinear,
+                        ..tiny_skia::PixmapPaint::default()
+                    },
+                    tiny_skia::Transform::from_row(
+                        size.width as f32 / image.width() as f32,
+                        0.0,
+                        0.0,
+                        size.height as f32 / image.height() as f32,
+                        bounds.x.trunc(),
+                        bounds.y.trunc(),
+                    ),
+                    clip_mask,
+                );
+            }
         }
     }
 
@@ -61,6 +85,9 @@ struct Cache {
     tree_hits: FxHashSet<u64>,
     rasters: FxHashMap<RasterKey, tiny_skia::Pixmap>,
     raster_hits: FxHashSet<RasterKey>,
+    pinned: FxHashSet<RasterKey>,
+    size_bucket: Option<u32>,
+    tracker: Tracker,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -72,24 +99,14 @@ struct RasterKey {
 
 impl Cache {
     fn load(&mut self, handle: &Handle) -> Option<&usvg::Tree> {
-        use usvg::TreeParsing;
-
         let id = handle.id();
 
         if let hash_map::Entry::Vacant(entry) = self.trees.entry(id) {
             let svg = match handle.data() {
-                Data::Path(path) => {
-                    fs::read_to_string(path).ok().and_then(|contents| {
-                        usvg::Tree::from_str(
-                            &contents,
-                            &usvg::Options::default(),
-                        )
-                        .ok()
-                    })
-                }
-                Data::Bytes(bytes) => {
-                    usvg::Tree::from_data(bytes, &usvg::Options::default()).ok()
-                }
+                Data::Path(path) => fs::read(path)
+                    .ok()
+                    .and_then(|data| parse(&data, handle.style())),
+                Data::Bytes(bytes) => parse(bytes, handle.style()),
             };
 
             let _ = entry.insert(svg);
@@ -108,27 +125,85 @@ impl Cache {
         ))
     }
 
+    fn key(
+        &self,
+        handle: &Handle,
+        color: Option<Color>,
+        size: Size<u32>,
+    ) -> RasterKey {
+        RasterKey {
+            id: handle.id(),
+            color: color.map(Color::into_rgba8),
+            size: crate::graphics::svg::bucket(size, self.size_bucket),
+        }
+    }
+
+    /// Applies the SVGs prerendered and evicted since the last time.
+    fn sync(&mut self) {
+        let Some(changes) = self.tracker.changes() else {
+            return;
+        };
+
+        for id in changes.evicted {
+            let _ = self.trees.remove(&id);
+            let _ = self.tree_hits.remove(&id);
+
+            self.rasters.retain(|key, _| key.id != id);
+            self.raster_hits.retain(|key| key.id != id);
+            self.pinned.retain(|key| key.id != id);
+        }
+
+        for Prerender {
+            handle,
+            size,
+            color,
+        } in changes.prerendered
+        {
+            let key = self.key(&handle, color, size);
+
+            if self.pinned.contains(&key) {
+                continue;
+            }
+
+            if self.rasterize(&handle, key, size).is_some() {
+                let _ = self.pinned.insert(key);
+            }
+        }
+    }
+
     fn draw(
         &mut self,
         handle: &Handle,
         color: Option<Color>,
         size: Size<u32>,
+    ) -> Option<tiny_skia::PixmapRef<'_>> {
+        self.sync();
+
+        let key = self.key(handle, color, size);
+
+        let _ = self.raster_hits.insert(key);
+
+        self.rasterize(handle, key, size)
+    }
+
+    /// Rasterizes the SVG fitting the given size into a raster of the size
+    /// of the given key, unless it is already cached.
+    fn rasterize(
+        &mut self,
+        handle: &Handle,
+        key: RasterKey,
+        size: Size<u32>,
     ) -> Option<tiny_skia::PixmapRef<'_>> {
         if size.width == 0 || size.height == 0 {
             return None;
         }
 
-        let key = RasterKey {
-            id: handle.id(),
-            color: color.map(Color::into_rgba8),
-            size,
-        };
-
         #[allow(clippy::map_entry)]
         if !self.rasters.contains_key(&key) {
             let tree = self.load(handle)?;
 
-            let mut image = tiny_skia::Pixmap::new(size.width, size.height)?;
+            let mut image =
+                tiny_skia::Pixmap::new(key.size.width, key.size.height)?;
 
             let tree_size = tree.size.to_int_size();
 
@@ -150,6 +225,12 @@ impl Cache {
                 tiny_skia::Transform::default()
             };
 
+            // The raster may be bigger than the size when bucketing
+            let transform = transform.post_scale(
+                key.size.width as f32 / size.width as f32,
+                key.size.height as f32 / size.height as f32,
+            );
+
             resvg::Tree::from_usvg(tree).render(transform, &mut image.as_mut());
 
             if let Some([r, g, b, _]) = key.color {
@@ -181,15 +262,40 @@ impl Cache {
             let _ = self.rasters.insert(key, image);
         }
 
-        let _ = self.raster_hits.insert(key);
         self.rasters.get(&key).map(tiny_skia::Pixmap::as_ref)
     }
 
     fn trim(&mut self) {
-        self.trees.retain(|key, _| self.tree_hits.contains(key));
-        self.rasters.retain(|key, _| self.raster_hits.contains(key));
+        self.trees.retain(|id, _| {
+            self.tree_hits.contains(id)
+                || self.pinned.iter().any(|key| key.id == *id)
+        });
+        self.rasters.retain(|key, _| {
+            self.raster_hits.contains(key) || self.pinned.contains(key)
+        });
 
         self.tree_hits.clear();
         self.raster_hits.clear();
     }
 }
+
+/// Parses the given SVG data, injecting the given CSS into it, if any.
+fn parse(data: &[u8], style: Option<&str>) -> Option<usvg::Tree> {
+    use usvg::TreeParsing;
+
+    let options = usvg::Options::default();
+
+    let Some(style) = style else {
+        return usvg::Tree::from_data(data, &options).ok();
+    };
+
+    let data = if data.starts_with(&[0x1f, 0x8b]) {
+        usvg::decompress_svgz(data).ok()?
+    } else {
+        data.to_vec()
+    };
+
+    let source = svg::inject_style(std::str::from_utf8(&data).ok()?, style);
+
+    usvg::Tree::from_str(&source, &options).ok()
+}
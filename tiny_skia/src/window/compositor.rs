@@ -28,7 +28,7 @@ impl<Theme> crate::graphics::Compositor for Compositor<Theme> {
         settings: Self::Settings,
         _compatible_window: Option<&W>,
     ) -> Result<(Self, Self::Renderer), Error> {
-        let (compositor, backend) = new();
+        let (compositor, backend) = new(settings);
 
         Ok((
             compositor,
@@ -119,14 +119,18 @@ impl<Theme> crate::graphics::Compositor for Compositor<Theme> {
             )
         })
     }
+
+    fn statistics(&self, renderer: &Self::Renderer) -> compositor::Statistics {
+        renderer.statistics()
+    }
 }
 
-pub fn new<Theme>() -> (Compositor<Theme>, Backend) {
+pub fn new<Theme>(settings: Settings) -> (Compositor<Theme>, Backend) {
     (
         Compositor {
             _theme: PhantomData,
         },
-        Backend::new(),
+        Backend::new(settings),
     )
 }
 
@@ -1,8 +1,10 @@
-use crate::core::{Color, Size};
+use crate::blur;
+use crate::core::{Color, Rectangle, Size};
 use crate::graphics;
 use crate::graphics::backend;
 use crate::graphics::color;
 use crate::graphics::{Transformation, Viewport};
+use crate::opacity;
 use crate::primitive::{self, Primitive};
 use crate::quad;
 use crate::text;
@@ -26,6 +28,8 @@ pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    blur_pipeline: blur::Pipeline,
+    opacity_pipeline: opacity::Pipeline,
 
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
@@ -43,14 +47,23 @@ impl Backend {
         let quad_pipeline = quad::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, settings.antialiasing);
+        let blur_pipeline = blur::Pipeline::new(device, format);
+        let opacity_pipeline = opacity::Pipeline::new(device, format);
 
         #[cfg(any(feature = "image", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(device, format);
+        let image_pipeline = image::Pipeline::new(
+            device,
+            format,
+            settings.image_cache_budget,
+            settings.svg_size_bucket,
+        );
 
         Self {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            blur_pipeline,
+            opacity_pipeline,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
@@ -124,7 +137,18 @@ impl Backend {
         transformation: Transformation,
         layers: &[Layer<'_>],
     ) {
+        if has_backdrop(layers) {
+            self.blur_pipeline.prepare(device, target_size);
+        }
+
+        let depth = group_depth(layers);
+
+        if depth > 0 {
+            self.opacity_pipeline.prepare(device, target_size, depth);
+        }
+
         for layer in layers {
+            let scale_factor = scale_factor * layer.scale;
             let bounds = (layer.bounds * scale_factor).snap();
 
             if bounds.width < 1 || bounds.height < 1 {
@@ -187,7 +211,7 @@ impl Backend {
         &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
-        target: &wgpu::TextureView,
+        frame: &wgpu::TextureView,
         clear_color: Option<Color>,
         scale_factor: f32,
         target_size: Size<u32>,
@@ -195,47 +219,111 @@ impl Backend {
     ) {
         use std::mem::ManuallyDrop;
 
+        // Layers with a backdrop need to read what is drawn behind them, so
+        // the whole frame is drawn in an offscreen scene first
+        let scene = if has_backdrop(layers) {
+            self.blur_pipeline.scene()
+        } else {
+            None
+        };
+
+        let has_scene = scene.is_some();
+        let scene = scene.unwrap_or(frame);
+        let mut target = scene;
+
+        // The groups being drawn, each in the offscreen texture of its depth
+        let mut groups: Vec<(usize, f32, Rectangle<u32>)> = Vec::new();
+
         let mut quad_layer = 0;
         let mut triangle_layer = 0;
         #[cfg(any(feature = "image", feature = "svg"))]
         let mut image_layer = 0;
         let mut text_layer = 0;
 
-        let mut render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("iced_wgpu::quad render pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: match clear_color {
-                            Some(background_color) => wgpu::LoadOp::Clear({
-                                let [r, g, b, a] =
-                                    color::pack(background_color).components();
-
-                                wgpu::Color {
-                                    r: f64::from(r),
-                                    g: f64::from(g),
-                                    b: f64::from(b),
-                                    a: f64::from(a),
-                                }
-                            }),
-                            None => wgpu::LoadOp::Load,
-                        },
-                        store: true,
-                    },
-                })],
-                depth_stencil_attachment: None,
+        let mut render_pass = ManuallyDrop::new(begin_render_pass(
+            encoder,
+            target,
+            match clear_color {
+                Some(background_color) => wgpu::LoadOp::Clear({
+                    let [r, g, b, a] =
+                        color::pack(background_color).components();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                None if has_scene => {
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)
+                }
+                None => wgpu::LoadOp::Load,
             },
         ));
 
-        for layer in layers {
+        for (i, layer) in layers.iter().enumerate() {
+            while groups.last().is_some_and(|(end, _, _)| *end <= i) {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                target = composite_group(
+                    &self.opacity_pipeline,
+                    device,
+                    encoder,
+                    &mut groups,
+                    scene,
+                );
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
+                ));
+            }
+
+            let scale_factor = scale_factor * layer.scale;
+
             let bounds = (layer.bounds * scale_factor).snap();
 
+            if let Some(group) = &layer.group {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                groups.push((i + group.layers, group.opacity, bounds));
+
+                target = self
+                    .opacity_pipeline
+                    .target(groups.len() - 1)
+                    .unwrap_or(target);
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                ));
+            }
+
             if bounds.width < 1 || bounds.height < 1 {
                 continue;
             }
 
+            if let Some(backdrop) = &layer.backdrop {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                self.blur_pipeline.render(
+                    device,
+                    encoder,
+                    backdrop,
+                    bounds,
+                    scale_factor,
+                );
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
+                ));
+            }
+
             if !layer.quads.is_empty() {
                 self.quad_pipeline.render(
                     quad_layer,
@@ -262,21 +350,10 @@ impl Backend {
 
                 triangle_layer += 1;
 
-                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-                    &wgpu::RenderPassDescriptor {
-                        label: Some("iced_wgpu::quad render pass"),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view: target,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Load,
-                                    store: true,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                    },
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
                 ));
             }
 
@@ -302,11 +379,113 @@ impl Backend {
         }
 
         let _ = ManuallyDrop::into_inner(render_pass);
+
+        while !groups.is_empty() {
+            let _ = composite_group(
+                &self.opacity_pipeline,
+                device,
+                encoder,
+                &mut groups,
+                scene,
+            );
+        }
+
+        if has_scene {
+            self.blur_pipeline.draw(device, encoder, frame);
+        }
     }
 }
 
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    target: &'a wgpu::TextureView,
+    load: wgpu::LoadOp<wgpu::Color>,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu::quad render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: target,
+            resolve_target: None,
+            ops: wgpu::Operations { load, store: true },
+        })],
+        depth_stencil_attachment: None,
+    })
+}
+
+fn has_backdrop(layers: &[Layer<'_>]) -> bool {
+    layers.iter().any(|layer| layer.backdrop.is_some())
+}
+
+/// Composites the innermost group of the given stack on top of the
+/// group enclosing it, or the scene if there is none.
+///
+/// Returns the target where the group was composited.
+fn composite_group<'a>(
+    pipeline: &'a opacity::Pipeline,
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    groups: &mut Vec<(usize, f32, Rectangle<u32>)>,
+    scene: &'a wgpu::TextureView,
+) -> &'a wgpu::TextureView {
+    let Some((_, opacity, bounds)) = groups.pop() else {
+        return scene;
+    };
+
+    let depth = groups.len();
+
+    let target = depth
+        .checked_sub(1)
+        .and_then(|depth| pipeline.target(depth))
+        .unwrap_or(scene);
+
+    pipeline.render(device, encoder, depth, opacity, bounds, target);
+
+    target
+}
+
+/// Returns the maximum amount of nested groups in the given layers.
+fn group_depth(layers: &[Layer<'_>]) -> usize {
+    let mut ends = Vec::new();
+    let mut depth = 0;
+
+    for (i, layer) in layers.iter().enumerate() {
+        ends.retain(|end| *end > i);
+
+        if let Some(group) = &layer.group {
+            ends.push(i + group.layers);
+            depth = depth.max(ends.len());
+        }
+    }
+
+    depth
+}
+
 impl crate::graphics::Backend for Backend {
     type Primitive = primitive::Custom;
+
+    fn atlas_occupancy(&self) -> Option<f32> {
+        #[cfg(any(feature = "image", feature = "svg"))]
+        {
+            Some(self.image_pipeline.atlas_occupancy())
+        }
+
+        #[cfg(not(any(feature = "image", feature = "svg")))]
+        {
+            None
+        }
+    }
+
+    fn image_cache(&self) -> Option<graphics::compositor::ImageCache> {
+        #[cfg(feature = "image")]
+        {
+            Some(self.image_pipeline.image_cache())
+        }
+
+        #[cfg(not(feature = "image"))]
+        {
+            None
+        }
+    }
 }
 
 impl backend::Text for Backend {
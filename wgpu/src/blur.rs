@@ -0,0 +1,408 @@
+//! Blur what is drawn behind some bounds.
+use crate::core::{Rectangle, Size};
+use crate::layer::Backdrop;
+
+use bytemuck::{Pod, Zeroable};
+
+/// A pipeline that draws a frame in an offscreen scene, so the backdrop of
+/// a layer can be blurred before the layer is drawn on top.
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    blur: wgpu::RenderPipeline,
+    blit: wgpu::RenderPipeline,
+    sampler: wgpu::Sampler,
+    constants_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    targets: Option<Targets>,
+}
+
+impl Pipeline {
+    /// Creates a new blur [`Pipeline`].
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let constants_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::blur uniforms layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering,
+                        ),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                std::mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::blur texture layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: true,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::blur pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constants_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu blur shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/blur.wgsl"),
+                )),
+            });
+
+        let pipeline = |label, entry_point| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(
+                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                        ),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+        };
+
+        let blur = pipeline("iced_wgpu::blur pipeline", "fs_blur");
+        let blit = pipeline("iced_wgpu::blur blit pipeline", "fs_blit");
+
+        Self {
+            format,
+            blur,
+            blit,
+            sampler,
+            constants_layout,
+            texture_layout,
+            targets: None,
+        }
+    }
+
+    /// Prepares the offscreen scene of the given size, where a frame with
+    /// backdrops must be drawn.
+    pub fn prepare(&mut self, device: &wgpu::Device, size: Size<u32>) {
+        match &self.targets {
+            Some(targets) if targets.size == size => {}
+            _ => {
+                self.targets = Some(Targets::new(
+                    device,
+                    self.format,
+                    &self.texture_layout,
+                    size,
+                ));
+            }
+        }
+    }
+
+    /// Returns the offscreen scene, if prepared.
+    pub fn scene(&self) -> Option<&wgpu::TextureView> {
+        self.targets.as_ref().map(|targets| &targets.scene)
+    }
+
+    /// Blurs the scene behind the given [`Backdrop`], inside of the given
+    /// clip bounds.
+    ///
+    /// [`Pipeline::prepare`] must be called first.
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        backdrop: &Backdrop,
+        clip_bounds: Rectangle<u32>,
+        scale_factor: f32,
+    ) {
+        let Some(targets) = &self.targets else {
+            return;
+        };
+
+        let Some(bounds) = (backdrop.bounds * scale_factor)
+            .intersection(&clip_bounds.into())
+            .map(Rectangle::snap)
+        else {
+            return;
+        };
+
+        let radius = (backdrop.radius * scale_factor).max(0.0);
+        let padding = radius.ceil() as u32;
+
+        // The vertical pass samples the rows above and below the bounds
+        let top = bounds.y.saturating_sub(padding);
+        let bottom =
+            (bounds.y + bounds.height + padding).min(targets.size.height);
+
+        let horizontal = Rectangle {
+            x: bounds.x,
+            y: top,
+            width: bounds.width,
+            height: bottom - top,
+        };
+
+        let uniforms = Uniforms {
+            bounds: [
+                backdrop.bounds.x * scale_factor,
+                backdrop.bounds.y * scale_factor,
+                backdrop.bounds.width * scale_factor,
+                backdrop.bounds.height * scale_factor,
+            ],
+            border_radius: backdrop
+                .border_radius
+                .map(|radius| radius * scale_factor),
+            direction: [1.0, 0.0],
+            radius,
+            mask: 0,
+        };
+
+        self.pass(
+            device,
+            encoder,
+            "iced_wgpu::blur horizontal pass",
+            &self.blur,
+            &uniforms,
+            &targets.scene_binding,
+            &targets.intermediate,
+            wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+            horizontal,
+        );
+
+        self.pass(
+            device,
+            encoder,
+            "iced_wgpu::blur vertical pass",
+            &self.blur,
+            &Uniforms {
+                direction: [0.0, 1.0],
+                mask: 1,
+                ..uniforms
+            },
+            &targets.intermediate_binding,
+            &targets.scene,
+            wgpu::LoadOp::Load,
+            bounds,
+        );
+    }
+
+    /// Draws the scene in the given target.
+    ///
+    /// [`Pipeline::prepare`] must be called first.
+    pub fn draw(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+    ) {
+        let Some(targets) = &self.targets else {
+            return;
+        };
+
+        self.pass(
+            device,
+            encoder,
+            "iced_wgpu::blur blit pass",
+            &self.blit,
+            &Uniforms::zeroed(),
+            &targets.scene_binding,
+            target,
+            wgpu::LoadOp::Load,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: targets.size.width,
+                height: targets.size.height,
+            },
+        );
+    }
+
+    fn pass(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        label: &str,
+        pipeline: &wgpu::RenderPipeline,
+        uniforms: &Uniforms,
+        source: &wgpu::BindGroup,
+        target: &wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+        scissor: Rectangle<u32>,
+    ) {
+        use wgpu::util::DeviceExt;
+
+        if scissor.width < 1 || scissor.height < 1 {
+            return;
+        }
+
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::blur uniforms buffer"),
+                contents: bytemuck::bytes_of(uniforms),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::blur uniforms bind group"),
+            layout: &self.constants_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some(label),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load, store: true },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_scissor_rect(
+            scissor.x,
+            scissor.y,
+            scissor.width,
+            scissor.height,
+        );
+        render_pass.set_bind_group(0, &constants, &[]);
+        render_pass.set_bind_group(1, source, &[]);
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+#[derive(Debug)]
+struct Targets {
+    scene: wgpu::TextureView,
+    scene_binding: wgpu::BindGroup,
+    intermediate: wgpu::TextureView,
+    intermediate_binding: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Targets {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        texture_layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Self {
+        let texture = |label| {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width: size.width,
+                    height: size.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+
+            let view =
+                texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+            let binding =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_wgpu::blur texture bind group"),
+                    layout: texture_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    }],
+                });
+
+            (view, binding)
+        };
+
+        let (scene, scene_binding) = texture("iced_wgpu::blur scene");
+        let (intermediate, intermediate_binding) =
+            texture("iced_wgpu::blur intermediate");
+
+        Self {
+            scene,
+            scene_binding,
+            intermediate,
+            intermediate_binding,
+            size,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+    direction: [f32; 2],
+    radius: f32,
+    mask: u32,
+}
@@ -3,24 +3,32 @@ mod atlas;
 #[cfg(feature = "image")]
 mod raster;
 
+#[cfg(feature = "image")]
+mod raw;
+
 #[cfg(feature = "svg")]
 mod vector;
 
 use atlas::Atlas;
 
-use crate::core::{Rectangle, Size};
+use crate::core::image::{Effects, FilterMethod, Orientation};
+use crate::core::{Point, Rectangle, Size};
 use crate::graphics::Transformation;
 use crate::layer;
 use crate::Buffer;
 
 use std::cell::RefCell;
 use std::mem;
+use std::ops::Range;
 
 use bytemuck::{Pod, Zeroable};
 
 #[cfg(feature = "image")]
 use crate::core::image;
 
+#[cfg(feature = "image")]
+use crate::graphics::compositor::ImageCache;
+
 #[cfg(feature = "svg")]
 use crate::core::svg;
 
@@ -31,13 +39,15 @@ use tracing::info_span;
 pub struct Pipeline {
     #[cfg(feature = "image")]
     raster_cache: RefCell<raster::Cache>,
+    #[cfg(feature = "image")]
+    raw_converter: raw::Converter,
     #[cfg(feature = "svg")]
     vector_cache: RefCell<vector::Cache>,
 
     pipeline: wgpu::RenderPipeline,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
-    sampler: wgpu::Sampler,
+    samplers: [wgpu::Sampler; FILTER_METHODS.len()],
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_atlas: Atlas,
@@ -48,19 +58,36 @@ pub struct Pipeline {
     prepare_layer: usize,
 }
 
+/// Every [`FilterMethod`], in the order of the samplers of the [`Pipeline`].
+const FILTER_METHODS: [FilterMethod; 4] = [
+    FilterMethod::Nearest,
+    FilterMethod::Linear,
+    FilterMethod::Trilinear,
+    FilterMethod::Anisotropic,
+];
+
+fn sampler_index(filter_method: FilterMethod) -> usize {
+    match filter_method {
+        FilterMethod::Nearest => 0,
+        FilterMethod::Linear => 1,
+        FilterMethod::Trilinear => 2,
+        FilterMethod::Anisotropic => 3,
+    }
+}
+
 #[derive(Debug)]
 struct Layer {
     uniforms: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    constants: Vec<wgpu::BindGroup>,
     instances: Buffer<Instance>,
-    instance_count: usize,
+    batches: Vec<(usize, Range<u32>)>,
 }
 
 impl Layer {
     fn new(
         device: &wgpu::Device,
         constant_layout: &wgpu::BindGroupLayout,
-        sampler: &wgpu::Sampler,
+        samplers: &[wgpu::Sampler],
     ) -> Self {
         let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("iced_wgpu::image uniforms buffer"),
@@ -69,26 +96,31 @@ impl Layer {
             mapped_at_creation: false,
         });
 
-        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("iced_wgpu::image constants bind group"),
-            layout: constant_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: wgpu::BindingResource::Buffer(
-                        wgpu::BufferBinding {
-                            buffer: &uniforms,
-                            offset: 0,
-                            size: None,
+        let constants = samplers
+            .iter()
+            .map(|sampler| {
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_wgpu::image constants bind group"),
+                    layout: constant_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::Buffer(
+                                wgpu::BufferBinding {
+                                    buffer: &uniforms,
+                                    offset: 0,
+                                    size: None,
+                                },
+                            ),
                         },
-                    ),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: wgpu::BindingResource::Sampler(sampler),
-                },
-            ],
-        });
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(sampler),
+                        },
+                    ],
+                })
+            })
+            .collect();
 
         let instances = Buffer::new(
             device,
@@ -101,7 +133,7 @@ impl Layer {
             uniforms,
             constants,
             instances,
-            instance_count: 0,
+            batches: Vec::new(),
         }
     }
 
@@ -110,6 +142,7 @@ impl Layer {
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         instances: &[Instance],
+        filter_methods: &[FilterMethod],
         transformation: Transformation,
     ) {
         queue.write_buffer(
@@ -123,33 +156,95 @@ impl Layer {
         let _ = self.instances.resize(device, instances.len());
         let _ = self.instances.write(queue, 0, instances);
 
-        self.instance_count = instances.len();
+        // Consecutive instances sharing a sampler are drawn together
+        self.batches.clear();
+
+        for (i, filter_method) in filter_methods.iter().enumerate() {
+            let sampler = sampler_index(*filter_method);
+            let i = i as u32;
+
+            match self.batches.last_mut() {
+                Some((last, range)) if *last == sampler => {
+                    range.end = i + 1;
+                }
+                _ => {
+                    self.batches.push((sampler, i..i + 1));
+                }
+            }
+        }
     }
 
     fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
-        render_pass.set_bind_group(0, &self.constants, &[]);
         render_pass.set_vertex_buffer(1, self.instances.slice(..));
 
-        render_pass.draw_indexed(
-            0..QUAD_INDICES.len() as u32,
-            0,
-            0..self.instance_count as u32,
-        );
+        for (sampler, instances) in &self.batches {
+            render_pass.set_bind_group(0, &self.constants[*sampler], &[]);
+
+            render_pass.draw_indexed(
+                0..QUAD_INDICES.len() as u32,
+                0,
+                instances.clone(),
+            );
+        }
     }
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        image_cache_budget: Option<usize>,
+        svg_size_bucket: Option<u32>,
+    ) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
+        #[cfg(not(feature = "image"))]
+        let _ = image_cache_budget;
+
+        #[cfg(not(feature = "svg"))]
+        let _ = svg_size_bucket;
+
+        let samplers = FILTER_METHODS.map(|filter_method| {
+            let (filter, mipmap_filter, lod_max_clamp, anisotropy_clamp) =
+                match filter_method {
+                    FilterMethod::Nearest => (
+                        wgpu::FilterMode::Nearest,
+                        wgpu::FilterMode::Nearest,
+                        0.0,
+                        1,
+                    ),
+                    FilterMethod::Linear => (
+                        wgpu::FilterMode::Linear,
+                        wgpu::FilterMode::Nearest,
+                        0.0,
+                        1,
+                    ),
+                    FilterMethod::Trilinear => (
+                        wgpu::FilterMode::Linear,
+                        wgpu::FilterMode::Linear,
+                        32.0,
+                        1,
+                    ),
+                    FilterMethod::Anisotropic => (
+                        wgpu::FilterMode::Linear,
+                        wgpu::FilterMode::Linear,
+                        32.0,
+                        16,
+                    ),
+                };
+
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                label: Some("iced_wgpu::image sampler"),
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_filter,
+                lod_max_clamp,
+                anisotropy_clamp,
+                ..Default::default()
+            })
         });
 
         let constant_layout =
@@ -237,6 +332,12 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Uint32,
+                                7 => Float32x4,
+                                8 => Float32x4,
+                                9 => Float32x4,
+                                10 => Float32x4,
+                                11 => Float32x4,
                             ),
                         },
                     ],
@@ -304,15 +405,21 @@ impl Pipeline {
 
         Pipeline {
             #[cfg(feature = "image")]
-            raster_cache: RefCell::new(raster::Cache::default()),
+            raster_cache: RefCell::new(raster::Cache::new(image_cache_budget)),
+
+            #[cfg(feature = "image")]
+            raw_converter: raw::Converter::new(
+                device,
+                texture_atlas.texture_format(),
+            ),
 
             #[cfg(feature = "svg")]
-            vector_cache: RefCell::new(vector::Cache::default()),
+            vector_cache: RefCell::new(vector::Cache::new(svg_size_bucket)),
 
             pipeline,
             vertices,
             indices,
-            sampler,
+            samplers,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_atlas,
@@ -340,6 +447,15 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    pub fn atlas_occupancy(&self) -> f32 {
+        self.texture_atlas.occupancy()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn image_cache(&self) -> ImageCache {
+        self.raster_cache.borrow().usage()
+    }
+
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
@@ -356,29 +472,50 @@ impl Pipeline {
         let _ = info_span!("Wgpu::Image", "DRAW").entered();
 
         let instances: &mut Vec<Instance> = &mut Vec::new();
+        let filter_methods: &mut Vec<FilterMethod> = &mut Vec::new();
 
         #[cfg(feature = "image")]
         let mut raster_cache = self.raster_cache.borrow_mut();
 
+        // The commands of the last frame have been submitted by now
+        #[cfg(feature = "image")]
+        self.raw_converter.recall();
+
         #[cfg(feature = "svg")]
         let mut vector_cache = self.vector_cache.borrow_mut();
 
+        #[cfg(feature = "svg")]
+        vector_cache.sync(device, encoder, &mut self.texture_atlas);
+
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    filter_method,
+                    orientation,
+                    effects,
+                    region,
+                    bounds,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         device,
                         encoder,
                         handle,
                         &mut self.texture_atlas,
+                        &mut self.raw_converter,
                     ) {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *orientation,
+                            effects,
+                            *region,
                             atlas_entry,
                             instances,
                         );
+
+                        filter_methods.resize(instances.len(), *filter_method);
                     }
                 }
                 #[cfg(not(feature = "image"))]
@@ -404,9 +541,15 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            Orientation::default(),
+                            &Effects::default(),
+                            None,
                             atlas_entry,
                             instances,
                         );
+
+                        filter_methods
+                            .resize(instances.len(), FilterMethod::Linear);
                     }
                 }
                 #[cfg(not(feature = "svg"))]
@@ -418,6 +561,8 @@ impl Pipeline {
             return;
         }
 
+        self.texture_atlas.generate_mipmaps(device, encoder);
+
         let texture_version = self.texture_atlas.layer_count();
 
         if self.texture_version != texture_version {
@@ -442,12 +587,12 @@ impl Pipeline {
             self.layers.push(Layer::new(
                 device,
                 &self.constant_layout,
-                &self.sampler,
+                &self.samplers,
             ));
         }
 
         let layer = &mut self.layers[self.prepare_layer];
-        layer.prepare(device, queue, instances, transformation);
+        layer.prepare(device, queue, instances, filter_methods, transformation);
 
         self.prepare_layer += 1;
     }
@@ -483,6 +628,9 @@ impl Pipeline {
         #[cfg(feature = "image")]
         self.raster_cache.borrow_mut().trim(&mut self.texture_atlas);
 
+        #[cfg(feature = "image")]
+        self.raw_converter.finish();
+
         #[cfg(feature = "svg")]
         self.vector_cache.borrow_mut().trim(&mut self.texture_atlas);
 
@@ -521,6 +669,8 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _orientation: u32,
+    _color_matrix: [[f32; 4]; 5],
 }
 
 impl Instance {
@@ -536,66 +686,125 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    orientation: Orientation,
+    effects: &Effects,
+    region: Option<Rectangle>,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    let [x, y] = image_position;
+
+    // The columns of the color matrix, with the offset last
+    let color_matrix = {
+        let matrix = effects.color_matrix();
+
+        std::array::from_fn(|column| {
+            std::array::from_fn(|row| matrix[row * 5 + column])
+        })
+    };
+    let [width, height] = image_size;
+
+    let size = match entry {
+        atlas::Entry::Contiguous(allocation) => allocation.size(),
+        atlas::Entry::Fragmented { size, .. } => *size,
+    };
+
+    let whole =
+        Rectangle::with_size(Size::new(size.width as f32, size.height as f32));
+
+    let Some(region) =
+        region.map_or(Some(whole), |region| region.intersection(&whole))
+    else {
+        return;
+    };
+
+    let mut add_fragment =
+        |position: (u32, u32), allocation: &atlas::Allocation| {
+            let Size {
+                width: fragment_width,
+                height: fragment_height,
+            } = allocation.size();
+
+            let fragment = Rectangle {
+                x: position.0 as f32,
+                y: position.1 as f32,
+                width: fragment_width as f32,
+                height: fragment_height as f32,
+            };
+
+            let Some(piece) = fragment.intersection(&region) else {
+                return;
+            };
+
+            // The corners of the piece, normalized to the region and oriented
+            let a = orientation.apply(Point::new(
+                (piece.x - region.x) / region.width,
+                (piece.y - region.y) / region.height,
+            ));
+
+            let b = orientation.apply(Point::new(
+                (piece.x + piece.width - region.x) / region.width,
+                (piece.y + piece.height - region.y) / region.height,
+            ));
+
+            add_instance(
+                [x + a.x.min(b.x) * width, y + a.y.min(b.y) * height],
+                [(a.x - b.x).abs() * width, (a.y - b.y).abs() * height],
+                orientation,
+                color_matrix,
+                allocation,
+                Rectangle {
+                    x: piece.x - fragment.x,
+                    y: piece.y - fragment.y,
+                    ..piece
+                },
+                instances,
+            );
+        };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_fragment((0, 0), allocation);
         }
-        atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = image_size[0] / size.width as f32;
-            let scaling_y = image_size[1] / size.height as f32;
-
+        atlas::Entry::Fragmented { fragments, .. } => {
             for fragment in fragments {
-                let allocation = &fragment.allocation;
-
-                let [x, y] = image_position;
-                let (fragment_x, fragment_y) = fragment.position;
-                let Size {
-                    width: fragment_width,
-                    height: fragment_height,
-                } = allocation.size();
-
-                let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
-                ];
-
-                let size = [
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
-
-                add_instance(position, size, allocation, instances);
+                add_fragment(fragment.position, &fragment.allocation);
             }
         }
     }
 }
 
+/// Adds an instance drawing the given `region` of an [`atlas::Allocation`],
+/// in pixels of the allocation.
 #[inline]
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    orientation: Orientation,
+    color_matrix: [[f32; 4]; 5],
     allocation: &atlas::Allocation,
+    region: Rectangle,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
-    let Size { width, height } = allocation.size();
     let layer = allocation.layer();
 
     let instance = Instance {
         _position: position,
         _size: size,
         _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
+            (x as f32 + region.x + 0.5) / atlas::SIZE as f32,
+            (y as f32 + region.y + 0.5) / atlas::SIZE as f32,
         ],
         _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
+            (region.width - 1.0).max(0.0) / atlas::SIZE as f32,
+            (region.height - 1.0).max(0.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _orientation: orientation.rotation.quarter_turns()
+            | (u32::from(orientation.flip_horizontal) << 2)
+            | (u32::from(orientation.flip_vertical) << 3),
+        _color_matrix: color_matrix,
     };
 
     instances.push(instance);
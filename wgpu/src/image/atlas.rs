@@ -3,6 +3,7 @@ pub mod entry;
 mod allocation;
 mod allocator;
 mod layer;
+mod mipmap;
 
 pub use allocation::Allocation;
 pub use entry::Entry;
@@ -12,14 +13,24 @@ use allocator::Allocator;
 
 pub const SIZE: u32 = 2048;
 
+/// The amount of mip levels of every layer, including the first one.
+///
+/// The last level is 128x128, which is small enough for thumbnails while
+/// keeping the bleeding between neighboring allocations at bay.
+pub const MIP_LEVELS: u32 = 5;
+
 use crate::core::Size;
 use crate::graphics::color;
 
+use std::collections::BTreeSet;
+
 #[derive(Debug)]
 pub struct Atlas {
     texture: wgpu::Texture,
     texture_view: wgpu::TextureView,
     layers: Vec<Layer>,
+    mipmaps: mipmap::Generator,
+    outdated: BTreeSet<usize>,
 }
 
 impl Atlas {
@@ -33,7 +44,7 @@ impl Atlas {
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("iced_wgpu::image texture atlas"),
             size: extent,
-            mip_level_count: 1,
+            mip_level_count: MIP_LEVELS,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: if color::GAMMA_CORRECTION {
@@ -43,7 +54,8 @@ impl Atlas {
             },
             usage: wgpu::TextureUsages::COPY_DST
                 | wgpu::TextureUsages::COPY_SRC
-                | wgpu::TextureUsages::TEXTURE_BINDING,
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::RENDER_ATTACHMENT,
             view_formats: &[],
         });
 
@@ -52,10 +64,14 @@ impl Atlas {
             ..Default::default()
         });
 
+        let mipmaps = mipmap::Generator::new(device, texture.format());
+
         Atlas {
             texture,
             texture_view,
             layers: vec![Layer::Empty],
+            mipmaps,
+            outdated: BTreeSet::new(),
         }
     }
 
@@ -63,10 +79,31 @@ impl Atlas {
         &self.texture_view
     }
 
+    pub fn texture_format(&self) -> wgpu::TextureFormat {
+        self.texture.format()
+    }
+
     pub fn layer_count(&self) -> usize {
         self.layers.len()
     }
 
+    /// Returns the fraction of the [`Atlas`] in use.
+    pub fn occupancy(&self) -> f32 {
+        let layer_area = u64::from(SIZE) * u64::from(SIZE);
+
+        let used: u64 = self
+            .layers
+            .iter()
+            .map(|layer| match layer {
+                Layer::Empty => 0,
+                Layer::Busy(allocator) => allocator.area(),
+                Layer::Full => layer_area,
+            })
+            .sum();
+
+        used as f32 / (layer_area * self.layers.len() as u64).max(1) as f32
+    }
+
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
@@ -144,6 +181,79 @@ impl Atlas {
         Some(entry)
     }
 
+    /// Copies the given texture to the [`Atlas`] in the GPU.
+    ///
+    /// The texture must have the same format as the [`Atlas`] and it must
+    /// be a copy source.
+    pub fn copy(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        width: u32,
+        height: u32,
+        texture: &wgpu::Texture,
+    ) -> Option<Entry> {
+        if texture.format() != self.texture.format()
+            || !texture.usage().contains(wgpu::TextureUsages::COPY_SRC)
+            || texture.width() < width
+            || texture.height() < height
+        {
+            log::warn!("Unsupported texture for atlas: {texture:?}");
+
+            return None;
+        }
+
+        let entry = {
+            let current_size = self.layers.len();
+            let entry = self.allocate(width, height)?;
+
+            // We grow the internal texture after allocating if necessary
+            let new_layers = self.layers.len() - current_size;
+            self.grow(new_layers, device, encoder);
+
+            entry
+        };
+
+        log::info!("Allocated atlas entry: {entry:?}");
+
+        match &entry {
+            Entry::Contiguous(allocation) => {
+                self.copy_allocation(texture, (0, 0), allocation, encoder);
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    self.copy_allocation(
+                        texture,
+                        fragment.position,
+                        &fragment.allocation,
+                        encoder,
+                    );
+                }
+            }
+        }
+
+        Some(entry)
+    }
+
+    /// Generates the mipmaps of the layers of the [`Atlas`] that have changed
+    /// since the last time.
+    pub fn generate_mipmaps(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        for layer in std::mem::take(&mut self.outdated) {
+            if layer < self.layers.len() {
+                self.mipmaps.generate(
+                    device,
+                    encoder,
+                    &self.texture,
+                    layer as u32,
+                );
+            }
+        }
+    }
+
     pub fn remove(&mut self, entry: &Entry) {
         log::info!("Removing atlas entry: {entry:?}");
 
@@ -295,6 +405,8 @@ impl Atlas {
         let Size { width, height } = allocation.size();
         let layer = allocation.layer();
 
+        let _ = self.outdated.insert(layer);
+
         let extent = wgpu::Extent3d {
             width,
             height,
@@ -331,6 +443,48 @@ impl Atlas {
         );
     }
 
+    fn copy_allocation(
+        &mut self,
+        texture: &wgpu::Texture,
+        (source_x, source_y): (u32, u32),
+        allocation: &Allocation,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let (x, y) = allocation.position();
+        let Size { width, height } = allocation.size();
+        let layer = allocation.layer();
+
+        let _ = self.outdated.insert(layer);
+
+        encoder.copy_texture_to_texture(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: source_x,
+                    y: source_y,
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::default(),
+            },
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x,
+                    y,
+                    z: layer as u32,
+                },
+                aspect: wgpu::TextureAspect::default(),
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
     fn grow(
         &mut self,
         amount: usize,
@@ -348,7 +502,7 @@ impl Atlas {
                 height: SIZE,
                 depth_or_array_layers: self.layers.len() as u32,
             },
-            mip_level_count: 1,
+            mip_level_count: MIP_LEVELS,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: if color::GAMMA_CORRECTION {
@@ -358,7 +512,8 @@ impl Atlas {
             },
             usage: wgpu::TextureUsages::COPY_DST
                 | wgpu::TextureUsages::COPY_SRC
-                | wgpu::TextureUsages::TEXTURE_BINDING,
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::RENDER_ATTACHMENT,
             view_formats: &[],
         });
 
@@ -371,6 +526,9 @@ impl Atlas {
                 continue;
             }
 
+            // Only the first level is copied, so the rest must be generated
+            let _ = self.outdated.insert(i);
+
             encoder.copy_texture_to_texture(
                 wgpu::ImageCopyTexture {
                     texture: &self.texture,
@@ -3,6 +3,7 @@ use guillotiere::{AtlasAllocator, Size};
 pub struct Allocator {
     raw: AtlasAllocator,
     allocations: usize,
+    area: u64,
 }
 
 impl Allocator {
@@ -12,6 +13,7 @@ impl Allocator {
         Allocator {
             raw,
             allocations: 0,
+            area: 0,
         }
     }
 
@@ -19,20 +21,29 @@ impl Allocator {
         let allocation =
             self.raw.allocate(Size::new(width as i32, height as i32))?;
 
+        let region = Region { allocation };
+
         self.allocations += 1;
+        self.area += region.area();
 
-        Some(Region { allocation })
+        Some(region)
     }
 
     pub fn deallocate(&mut self, region: &Region) {
         self.raw.deallocate(region.allocation.id);
 
         self.allocations = self.allocations.saturating_sub(1);
+        self.area = self.area.saturating_sub(region.area());
     }
 
     pub fn is_empty(&self) -> bool {
         self.allocations == 0
     }
+
+    /// Returns the area of the allocated regions, in pixels.
+    pub fn area(&self) -> u64 {
+        self.area
+    }
 }
 
 pub struct Region {
@@ -51,6 +62,12 @@ impl Region {
 
         crate::core::Size::new(size.width as u32, size.height as u32)
     }
+
+    fn area(&self) -> u64 {
+        let size = self.size();
+
+        u64::from(size.width) * u64::from(size.height)
+    }
 }
 
 impl std::fmt::Debug for Allocator {
@@ -1,10 +1,10 @@
-use crate::core::image;
+use crate::core::image::{Data, Handle};
 use crate::core::Size;
 use crate::graphics;
+use crate::graphics::compositor::ImageCache;
 use crate::graphics::image::image_rs;
 use crate::image::atlas::{self, Atlas};
-
-use std::collections::{HashMap, HashSet};
+use crate::image::raw;
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
@@ -13,6 +13,10 @@ pub enum Memory {
     Host(image_rs::ImageBuffer<image_rs::Rgba<u8>, Vec<u8>>),
     /// Storage entry
     Device(atlas::Entry),
+    /// Texture on device, not yet copied to storage
+    Texture(Size<u32>),
+    /// Raw frame on host, not yet converted to storage
+    Raw(Size<u32>),
     /// Image not found
     NotFound,
     /// Invalid image data
@@ -29,34 +33,81 @@ impl Memory {
                 Size::new(width, height)
             }
             Memory::Device(entry) => entry.size(),
+            Memory::Texture(size) | Memory::Raw(size) => *size,
             Memory::NotFound => Size::new(1, 1),
             Memory::Invalid => Size::new(1, 1),
         }
     }
+
+    /// Amount of bytes taken by the image
+    fn bytes(&self) -> usize {
+        match self {
+            Memory::Host(_)
+            | Memory::Device(_)
+            | Memory::Texture(_)
+            | Memory::Raw(_) => {
+                let Size { width, height } = self.dimensions();
+
+                width as usize * height as usize * 4
+            }
+            Memory::NotFound | Memory::Invalid => 0,
+        }
+    }
 }
 
 /// Caches image raster data
 #[derive(Debug, Default)]
 pub struct Cache {
-    map: HashMap<u64, Memory>,
-    hits: HashSet<u64>,
+    cache: graphics::image::Cache<Memory>,
 }
 
 impl Cache {
+    /// Creates a new [`Cache`] with the given memory budget, in bytes.
+    pub fn new(budget: Option<usize>) -> Self {
+        Self {
+            cache: graphics::image::Cache::new(budget),
+        }
+    }
+
     /// Load image
-    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
-        if self.contains(handle) {
-            return self.get(handle).unwrap();
+    pub fn load(&mut self, handle: &Handle) -> &mut Memory {
+        if self.cache.contains(handle) {
+            return self.cache.get(handle).unwrap();
         }
 
-        let memory = match graphics::image::load(handle) {
-            Ok(image) => Memory::Host(image.to_rgba8()),
-            Err(image_rs::error::ImageError::IoError(_)) => Memory::NotFound,
-            Err(_) => Memory::Invalid,
+        let memory = match handle.data() {
+            // Avoid decoding raw pixels, since they may change every frame
+            Data::Rgba {
+                width,
+                height,
+                pixels,
+            } => image_rs::ImageBuffer::from_raw(
+                *width,
+                *height,
+                pixels.to_vec(),
+            )
+            .map(Memory::Host)
+            .unwrap_or(Memory::Invalid),
+            // Textures are copied to the atlas once uploaded
+            Data::Texture { width, height, .. } => {
+                Memory::Texture(Size::new(*width, *height))
+            }
+            // Raw frames are converted in the device once uploaded
+            Data::Raw { width, height, .. } => {
+                Memory::Raw(Size::new(*width, *height))
+            }
+            _ => match graphics::image::load(handle) {
+                Ok(image) => Memory::Host(image.to_rgba8()),
+                Err(image_rs::error::ImageError::IoError(_)) => {
+                    Memory::NotFound
+                }
+                Err(_) => Memory::Invalid,
+            },
         };
 
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+        let bytes = memory.bytes();
+
+        self.cache.insert(handle, memory, bytes)
     }
 
     /// Load image and upload raster data
@@ -64,8 +115,9 @@ impl Cache {
         &mut self,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
-        handle: &image::Handle,
+        handle: &Handle,
         atlas: &mut Atlas,
+        converter: &mut raw::Converter,
     ) -> Option<&atlas::Entry> {
         let memory = self.load(handle);
 
@@ -77,6 +129,39 @@ impl Cache {
             *memory = Memory::Device(entry);
         }
 
+        if let Memory::Texture(size) = memory {
+            let entry = texture(handle).and_then(|texture| {
+                atlas.copy(device, encoder, size.width, size.height, texture)
+            });
+
+            match entry {
+                Some(entry) => *memory = Memory::Device(entry),
+                None => *memory = Memory::Invalid,
+            }
+        }
+
+        if let Memory::Raw(size) = memory {
+            let entry = match handle.data() {
+                Data::Raw { format, planes, .. } => converter
+                    .convert(device, encoder, *size, *format, planes)
+                    .and_then(|texture| {
+                        atlas.copy(
+                            device,
+                            encoder,
+                            size.width,
+                            size.height,
+                            texture,
+                        )
+                    }),
+                _ => None,
+            };
+
+            match entry {
+                Some(entry) => *memory = Memory::Device(entry),
+                None => *memory = Memory::Invalid,
+            }
+        }
+
         if let Memory::Device(allocation) = memory {
             Some(allocation)
         } else {
@@ -84,36 +169,34 @@ impl Cache {
         }
     }
 
-    /// Trim cache misses from cache
+    /// Evicts the images that are invalidated or over budget from the cache
     pub fn trim(&mut self, atlas: &mut Atlas) {
-        let hits = &self.hits;
-
-        self.map.retain(|k, memory| {
-            let retain = hits.contains(k);
-
-            if !retain {
-                if let Memory::Device(entry) = memory {
-                    atlas.remove(entry);
-                }
+        self.cache.trim(|memory| {
+            if let Memory::Device(entry) = memory {
+                atlas.remove(&entry);
             }
-
-            retain
         });
-
-        self.hits.clear();
     }
 
-    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
-        let _ = self.hits.insert(handle.id());
-
-        self.map.get_mut(&handle.id())
-    }
-
-    fn insert(&mut self, handle: &image::Handle, memory: Memory) {
-        let _ = self.map.insert(handle.id(), memory);
+    /// Returns the memory usage of the cache
+    pub fn usage(&self) -> ImageCache {
+        self.cache.usage()
     }
+}
 
-    fn contains(&self, handle: &image::Handle) -> bool {
-        self.map.contains_key(&handle.id())
-    }
+/// Returns the [`wgpu::Texture`] of the given [`Handle`], if any.
+///
+/// The texture may be given either directly or behind an [`Arc`].
+///
+/// [`Arc`]: std::sync::Arc
+fn texture(handle: &Handle) -> Option<&wgpu::Texture> {
+    let Data::Texture { texture, .. } = handle.data() else {
+        return None;
+    };
+
+    texture.downcast_ref::<wgpu::Texture>().or_else(|| {
+        texture
+            .downcast_ref::<std::sync::Arc<wgpu::Texture>>()
+            .map(AsRef::as_ref)
+    })
 }
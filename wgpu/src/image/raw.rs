@@ -0,0 +1,419 @@
+use crate::core::image::{Format, Plane};
+use crate::core::Size;
+
+use std::borrow::Cow;
+use std::num::NonZeroU64;
+
+/// The size of the chunks of the staging belt; enough for the luma plane of
+/// a 1080p frame.
+const CHUNK_SIZE: u64 = 2 * 1024 * 1024;
+
+/// Converts raw video frames to the format of the [`Atlas`] in the GPU.
+///
+/// The planes of a frame are written to a staging belt, copied to a texture
+/// each, and then drawn to a texture that can be copied to the [`Atlas`].
+///
+/// [`Atlas`]: super::Atlas
+pub struct Converter {
+    pipelines: [wgpu::RenderPipeline; 3],
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    format: wgpu::TextureFormat,
+    belt: wgpu::util::StagingBelt,
+    upload: Option<wgpu::Buffer>,
+    target: Option<Target>,
+}
+
+/// The textures of the frames with some [`Format`] and size.
+#[derive(Debug)]
+struct Target {
+    format: Format,
+    size: Size<u32>,
+    planes: Vec<wgpu::Texture>,
+    texture: wgpu::Texture,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Converter {
+    /// Creates a new [`Converter`] producing textures of the given format.
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let plane = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float {
+                    filterable: true,
+                },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::image::raw layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering,
+                        ),
+                        count: None,
+                    },
+                    plane(1),
+                    plane(2),
+                    plane(3),
+                ],
+            });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::image::raw pipeline layout"),
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::image::raw shader"),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!(
+                    "../shader/raw.wgsl"
+                ))),
+            });
+
+        // The planes hold gamma encoded colors, which are drawn untouched
+        let target_format = format.remove_srgb_suffix();
+
+        let pipelines = ["fs_bgra", "fs_nv12", "fs_i420"].map(|entry_point| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::image::raw pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: target_format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..wgpu::PrimitiveState::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("iced_wgpu::image::raw sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..wgpu::SamplerDescriptor::default()
+        });
+
+        Self {
+            pipelines,
+            layout,
+            sampler,
+            format,
+            belt: wgpu::util::StagingBelt::new(CHUNK_SIZE),
+            upload: None,
+            target: None,
+        }
+    }
+
+    /// Converts the given [`Plane`] of a frame of the given size and
+    /// [`Format`], returning a texture with the converted frame.
+    ///
+    /// The texture is reused by the next conversion, so it must be copied
+    /// right away.
+    pub fn convert(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        size: Size<u32>,
+        format: Format,
+        planes: &[Plane],
+    ) -> Option<&wgpu::Texture> {
+        if size.width == 0
+            || size.height == 0
+            || !is_valid(size, format, planes)
+        {
+            log::warn!("Invalid raw frame: {format:?} {size:?}");
+
+            return None;
+        }
+
+        if !matches!(
+            &self.target,
+            Some(target) if target.format == format && target.size == size
+        ) {
+            self.target = Some(Target::new(
+                device,
+                &self.layout,
+                &self.sampler,
+                self.format,
+                format,
+                size,
+            ));
+        }
+
+        let target = self.target.as_ref()?;
+
+        for (i, (plane, texture)) in
+            planes.iter().zip(&target.planes).enumerate()
+        {
+            let plane_size = format.plane_size(i, size);
+            let bytes_per_row = plane_size.width * format.bytes_per_sample(i);
+
+            // Rows of a buffer copied to a texture must be aligned
+            let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+            let padded_bytes_per_row =
+                (bytes_per_row + align - 1) / align * align;
+
+            let buffer_size =
+                u64::from(padded_bytes_per_row) * u64::from(plane_size.height);
+
+            if self
+                .upload
+                .as_ref()
+                .map_or(true, |upload| upload.size() < buffer_size)
+            {
+                self.upload =
+                    Some(device.create_buffer(&wgpu::BufferDescriptor {
+                        label: Some("iced_wgpu::image::raw upload buffer"),
+                        size: buffer_size.next_power_of_two(),
+                        usage: wgpu::BufferUsages::COPY_DST
+                            | wgpu::BufferUsages::COPY_SRC,
+                        mapped_at_creation: false,
+                    }));
+            }
+
+            let upload = self.upload.as_ref()?;
+
+            {
+                let mut view = self.belt.write_buffer(
+                    encoder,
+                    upload,
+                    0,
+                    NonZeroU64::new(buffer_size)?,
+                    device,
+                );
+
+                for row in 0..plane_size.height {
+                    let bytes = plane.row(row, bytes_per_row)?;
+                    let offset = (row * padded_bytes_per_row) as usize;
+
+                    view[offset..offset + bytes.len()].copy_from_slice(bytes);
+                }
+            }
+
+            encoder.copy_buffer_to_texture(
+                wgpu::ImageCopyBuffer {
+                    buffer: upload,
+                    layout: wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(padded_bytes_per_row),
+                        rows_per_image: Some(plane_size.height),
+                    },
+                },
+                texture.as_image_copy(),
+                wgpu::Extent3d {
+                    width: plane_size.width,
+                    height: plane_size.height,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+
+        let view = target.texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("iced_wgpu::image::raw target view"),
+            format: Some(self.format.remove_srgb_suffix()),
+            ..wgpu::TextureViewDescriptor::default()
+        });
+
+        {
+            let mut pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::image::raw render pass"),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view: &view,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(
+                                    wgpu::Color::TRANSPARENT,
+                                ),
+                                store: true,
+                            },
+                        },
+                    )],
+                    depth_stencil_attachment: None,
+                });
+
+            pass.set_pipeline(&self.pipelines[pipeline_index(format)]);
+            pass.set_bind_group(0, &target.bind_group, &[]);
+            pass.draw(0..6, 0..1);
+        }
+
+        Some(&target.texture)
+    }
+
+    /// Closes the staging belt, before the commands of the frame are
+    /// submitted.
+    pub fn finish(&mut self) {
+        self.belt.finish();
+    }
+
+    /// Reclaims the staging belt, once the commands of the last frame have
+    /// been submitted.
+    pub fn recall(&mut self) {
+        self.belt.recall();
+    }
+}
+
+impl std::fmt::Debug for Converter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Converter")
+            .field("format", &self.format)
+            .field("target", &self.target)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        texture_format: wgpu::TextureFormat,
+        format: Format,
+        size: Size<u32>,
+    ) -> Self {
+        let planes: Vec<wgpu::Texture> = (0..format.planes())
+            .map(|i| {
+                let plane_size = format.plane_size(i, size);
+
+                device.create_texture(&wgpu::TextureDescriptor {
+                    label: Some("iced_wgpu::image::raw plane"),
+                    size: wgpu::Extent3d {
+                        width: plane_size.width,
+                        height: plane_size.height,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: match format.bytes_per_sample(i) {
+                        4 => wgpu::TextureFormat::Bgra8Unorm,
+                        2 => wgpu::TextureFormat::Rg8Unorm,
+                        _ => wgpu::TextureFormat::R8Unorm,
+                    },
+                    usage: wgpu::TextureUsages::COPY_DST
+                        | wgpu::TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                })
+            })
+            .collect();
+
+        let views: Vec<wgpu::TextureView> = planes
+            .iter()
+            .map(|plane| {
+                plane.create_view(&wgpu::TextureViewDescriptor::default())
+            })
+            .collect();
+
+        // Unused planes are bound to the first one
+        let view = |i: usize| views.get(i).unwrap_or(&views[0]);
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::image::raw bind group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(view(0)),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(view(1)),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(view(2)),
+                },
+            ],
+        });
+
+        let view_format = texture_format.remove_srgb_suffix();
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::image::raw target"),
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: texture_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: if view_format == texture_format {
+                &[]
+            } else {
+                std::slice::from_ref(&view_format)
+            },
+        });
+
+        Self {
+            format,
+            size,
+            planes,
+            texture,
+            bind_group,
+        }
+    }
+}
+
+/// Returns true if the given [`Plane`] hold every row of a frame of the
+/// given size and [`Format`].
+fn is_valid(size: Size<u32>, format: Format, planes: &[Plane]) -> bool {
+    planes.len() >= format.planes()
+        && planes
+            .iter()
+            .take(format.planes())
+            .enumerate()
+            .all(|(i, plane)| {
+                let plane_size = format.plane_size(i, size);
+                let bytes_per_row =
+                    plane_size.width * format.bytes_per_sample(i);
+
+                plane.stride >= bytes_per_row
+                    && plane.row(plane_size.height - 1, bytes_per_row).is_some()
+            })
+}
+
+fn pipeline_index(format: Format) -> usize {
+    match format {
+        Format::Bgra => 0,
+        Format::Nv12 => 1,
+        Format::I420 => 2,
+    }
+}
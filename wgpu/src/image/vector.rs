@@ -1,5 +1,6 @@
 use crate::core::svg;
 use crate::core::{Color, Size};
+use crate::graphics::svg::{Prerender, Tracker};
 use crate::image::atlas::{self, Atlas};
 
 use resvg::tiny_skia;
@@ -33,39 +34,108 @@ impl Svg {
 #[derive(Debug, Default)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32, ColorFilter), atlas::Entry>,
+    rasterized: HashMap<Key, atlas::Entry>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32, ColorFilter)>,
+    rasterized_hits: HashSet<Key>,
+    pinned: HashSet<Key>,
+    size_bucket: Option<u32>,
+    tracker: Tracker,
 }
 
 type ColorFilter = Option<[u8; 4]>;
 
+type Key = (u64, u32, u32, ColorFilter);
+
 impl Cache {
-    /// Load svg
-    pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
-        use usvg::TreeParsing;
+    /// Creates a new [`Cache`] rounding the size of its rasters up to the
+    /// given bucket, if any.
+    pub fn new(size_bucket: Option<u32>) -> Self {
+        Self {
+            size_bucket,
+            ..Self::default()
+        }
+    }
 
-        if self.svgs.contains_key(&handle.id()) {
-            return self.svgs.get(&handle.id()).unwrap();
+    /// Applies the SVGs prerendered and evicted since the last time.
+    pub fn sync(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        atlas: &mut Atlas,
+    ) {
+        let Some(changes) = self.tracker.changes() else {
+            return;
+        };
+
+        for id in changes.evicted {
+            let _ = self.svgs.remove(&id);
+            let _ = self.svg_hits.remove(&id);
+
+            self.pinned.retain(|key| key.0 != id);
+            self.rasterized_hits.retain(|key| key.0 != id);
+            self.rasterized.retain(|key, entry| {
+                let retain = key.0 != id;
+
+                if !retain {
+                    atlas.remove(entry);
+                }
+
+                retain
+            });
         }
 
-        let svg = match handle.data() {
-            svg::Data::Path(path) => {
-                let tree = fs::read_to_string(path).ok().and_then(|contents| {
-                    usvg::Tree::from_str(&contents, &usvg::Options::default())
-                        .ok()
-                });
+        for Prerender {
+            handle,
+            size,
+            color,
+        } in changes.prerendered
+        {
+            let key = self.key(&handle, size, color);
 
-                tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
+            if self.pinned.contains(&key) {
+                continue;
             }
-            svg::Data::Bytes(bytes) => {
-                match usvg::Tree::from_data(bytes, &usvg::Options::default()) {
-                    Ok(tree) => Svg::Loaded(tree),
-                    Err(_) => Svg::NotFound,
-                }
+
+            if self
+                .rasterize(device, encoder, &handle, key, size, atlas)
+                .is_some()
+            {
+                let _ = self.pinned.insert(key);
             }
+        }
+    }
+
+    fn key(
+        &self,
+        handle: &svg::Handle,
+        size: Size<u32>,
+        color: Option<Color>,
+    ) -> Key {
+        let size = crate::graphics::svg::bucket(size, self.size_bucket);
+
+        (
+            handle.id(),
+            size.width,
+            size.height,
+            color.map(Color::into_rgba8),
+        )
+    }
+
+    /// Load svg
+    pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
+        if self.svgs.contains_key(&handle.id()) {
+            return self.svgs.get(&handle.id()).unwrap();
+        }
+
+        let tree = match handle.data() {
+            svg::Data::Path(path) => fs::read(path)
+                .ok()
+                .and_then(|data| parse(&data, handle.style())),
+            svg::Data::Bytes(bytes) => parse(bytes, handle.style()),
         };
 
+        let svg = tree.map(Svg::Loaded).unwrap_or(Svg::NotFound);
+
         let _ = self.svgs.insert(handle.id(), svg);
         self.svgs.get(&handle.id()).unwrap()
     }
@@ -81,30 +151,43 @@ impl Cache {
         scale: f32,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
-        let id = handle.id();
-
-        let (width, height) = (
+        let size = Size::new(
             (scale * width).ceil() as u32,
             (scale * height).ceil() as u32,
         );
 
-        let color = color.map(Color::into_rgba8);
-        let key = (id, width, height, color);
+        let key = self.key(handle, size, color);
 
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
         if self.rasterized.contains_key(&key) {
-            let _ = self.svg_hits.insert(id);
+            let _ = self.svg_hits.insert(key.0);
             let _ = self.rasterized_hits.insert(key);
 
             return self.rasterized.get(&key);
         }
 
+        self.rasterize(device, encoder, handle, key, size, atlas)
+    }
+
+    /// Rasterizes the SVG fitting the given size into a raster of the size
+    /// of the given key, and uploads it.
+    fn rasterize(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        handle: &svg::Handle,
+        key: Key,
+        size: Size<u32>,
+        atlas: &mut Atlas,
+    ) -> Option<&atlas::Entry> {
+        let (id, width, height, color) = key;
+
         match self.load(handle) {
             Svg::Loaded(tree) => {
-                if width == 0 || height == 0 {
+                if size.width == 0 || size.height == 0 {
                     return None;
                 }
 
@@ -116,10 +199,10 @@ impl Cache {
 
                 let tree_size = tree.size.to_int_size();
 
-                let target_size = if width > height {
-                    tree_size.scale_to_width(width)
+                let target_size = if size.width > size.height {
+                    tree_size.scale_to_width(size.width)
                 } else {
-                    tree_size.scale_to_height(height)
+                    tree_size.scale_to_height(size.height)
                 };
 
                 let transform = if let Some(target_size) = target_size {
@@ -134,6 +217,12 @@ impl Cache {
                     tiny_skia::Transform::default()
                 };
 
+                // The raster may be bigger than the size when bucketing
+                let transform = transform.post_scale(
+                    width as f32 / size.width as f32,
+                    height as f32 / size.height as f32,
+                );
+
                 resvg::Tree::from_usvg(tree)
                     .render(transform, &mut img.as_mut());
 
@@ -168,10 +257,13 @@ impl Cache {
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let svg_hits = &self.svg_hits;
         let rasterized_hits = &self.rasterized_hits;
+        let pinned = &self.pinned;
 
-        self.svgs.retain(|k, _| svg_hits.contains(k));
+        self.svgs.retain(|k, _| {
+            svg_hits.contains(k) || pinned.iter().any(|key| key.0 == *k)
+        });
         self.rasterized.retain(|k, entry| {
-            let retain = rasterized_hits.contains(k);
+            let retain = rasterized_hits.contains(k) || pinned.contains(k);
 
             if !retain {
                 atlas.remove(entry);
@@ -184,6 +276,27 @@ impl Cache {
     }
 }
 
+/// Parses the given SVG data, injecting the given CSS into it, if any.
+fn parse(data: &[u8], style: Option<&str>) -> Option<usvg::Tree> {
+    use usvg::TreeParsing;
+
+    let options = usvg::Options::default();
+
+    let Some(style) = style else {
+        return usvg::Tree::from_data(data, &options).ok();
+    };
+
+    let data = if data.starts_with(&[0x1f, 0x8b]) {
+        usvg::decompress_svgz(data).ok()?
+    } else {
+        data.to_vec()
+    };
+
+    let source = svg::inject_style(std::str::from_utf8(&data).ok()?, style);
+
+    usvg::Tree::from_str(&source, &options).ok()
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
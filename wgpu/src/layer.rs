@@ -23,6 +23,9 @@ pub struct Layer<'a> {
     /// The clipping bounds of the [`Layer`].
     pub bounds: Rectangle,
 
+    /// The scale of the contents of the [`Layer`].
+    pub scale: f32,
+
     /// The quads of the [`Layer`].
     pub quads: quad::Batch,
 
@@ -34,6 +37,35 @@ pub struct Layer<'a> {
 
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
+
+    /// The [`Backdrop`] blurred before drawing the [`Layer`], if any.
+    pub backdrop: Option<Backdrop>,
+
+    /// The [`Group`] of layers starting at the [`Layer`], if any.
+    pub group: Option<Group>,
+}
+
+/// Some consecutive layers composited together with some opacity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Group {
+    /// The opacity of the [`Group`], from `0.0` to `1.0`.
+    pub opacity: f32,
+
+    /// The amount of layers in the [`Group`], including the first one.
+    pub layers: usize,
+}
+
+/// A blur of whatever is drawn behind some bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The bounds of the blurred area.
+    pub bounds: Rectangle,
+
+    /// The border radii of the blurred area.
+    pub border_radius: [f32; 4],
+
+    /// The radius of the blur, in logical pixels.
+    pub radius: f32,
 }
 
 impl<'a> Layer<'a> {
@@ -41,10 +73,13 @@ impl<'a> Layer<'a> {
     pub fn new(bounds: Rectangle) -> Self {
         Self {
             bounds,
+            scale: 1.0,
             quads: quad::Batch::default(),
             meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            backdrop: None,
+            group: None,
         }
     }
 
@@ -157,6 +192,7 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -169,15 +205,30 @@ impl<'a> Layer<'a> {
                     border_color: color::pack(*border_color),
                     border_radius: *border_radius,
                     border_width: *border_width,
+                    shadow_color: color::pack(shadow.color),
+                    shadow_offset: shadow.offset.into(),
+                    shadow_blur_radius: shadow.blur_radius,
+                    shadow_spread_radius: shadow.spread_radius,
                 };
 
                 layer.quads.add(quad, background);
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                filter_method,
+                orientation,
+                effects,
+                region,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    filter_method: *filter_method,
+                    orientation: *orientation,
+                    effects: *effects,
+                    region: *region,
                     bounds: *bounds + translation,
                 });
             }
@@ -213,7 +264,11 @@ impl<'a> Layer<'a> {
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
+                    let clip_layer = Layer {
+                        scale: layer.scale,
+                        ..Layer::new(clip_bounds)
+                    };
+
                     layers.push(clip_layer);
 
                     Self::process_primitive(
@@ -224,6 +279,77 @@ impl<'a> Layer<'a> {
                     );
                 }
             }
+            Primitive::Backdrop {
+                bounds,
+                border_radius,
+                radius,
+                content,
+            } => {
+                let layer = &mut layers[current_layer];
+                let translated_bounds = *bounds + translation;
+
+                // Only blur visible content
+                if layer.bounds.intersects(&translated_bounds) {
+                    let backdrop_layer = Layer {
+                        scale: layer.scale,
+                        backdrop: Some(Backdrop {
+                            bounds: translated_bounds,
+                            border_radius: *border_radius,
+                            radius: *radius,
+                        }),
+                        ..Layer::new(layer.bounds)
+                    };
+
+                    layers.push(backdrop_layer);
+
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        content,
+                        layers.len() - 1,
+                    );
+                }
+            }
+            Primitive::Opacity { opacity, content } => {
+                // Invisible content is not drawn at all
+                if *opacity <= 0.0 {
+                    return;
+                }
+
+                // Opaque content does not need its own group
+                if *opacity >= 1.0 {
+                    Self::process_primitive(
+                        layers,
+                        translation,
+                        content,
+                        current_layer,
+                    );
+
+                    return;
+                }
+
+                let layer = &layers[current_layer];
+
+                let group_layer = Layer {
+                    scale: layer.scale,
+                    ..Layer::new(layer.bounds)
+                };
+
+                let first_layer = layers.len();
+                layers.push(group_layer);
+
+                Self::process_primitive(
+                    layers,
+                    translation,
+                    content,
+                    first_layer,
+                );
+
+                layers[first_layer].group = Some(Group {
+                    opacity: *opacity,
+                    layers: layers.len() - first_layer,
+                });
+            }
             Primitive::Translate {
                 translation: new_translation,
                 content,
@@ -235,6 +361,25 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
+            Primitive::Scale { scale, content } => {
+                let layer = &mut layers[current_layer];
+
+                // Scaled content is drawn in its own layer with a larger
+                // scale factor, so its bounds and translation are scaled back
+                let scaled_layer = Layer {
+                    scale: layer.scale * scale,
+                    ..Layer::new(layer.bounds * (1.0 / scale))
+                };
+
+                layers.push(scaled_layer);
+
+                Self::process_primitive(
+                    layers,
+                    translation * (1.0 / scale),
+                    content,
+                    layers.len() - 1,
+                );
+            }
             Primitive::Cache { content } => {
                 Self::process_primitive(
                     layers,
@@ -10,6 +10,18 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The filter method of the image.
+        filter_method: image::FilterMethod,
+
+        /// The orientation of the image.
+        orientation: image::Orientation,
+
+        /// The color effects of the image.
+        effects: image::Effects,
+
+        /// The region of the image to draw, in pixels, if not all of it.
+        region: Option<Rectangle>,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -38,8 +38,10 @@ pub mod window;
 pub mod geometry;
 
 mod backend;
+mod blur;
 mod buffer;
 mod color;
+mod opacity;
 mod quad;
 mod text;
 mod triangle;
@@ -0,0 +1,281 @@
+//! Composite groups of layers with some opacity.
+use crate::core::{Rectangle, Size};
+
+use bytemuck::{Pod, Zeroable};
+
+/// A pipeline that draws groups of layers in offscreen textures, so they
+/// can be composited together with some opacity.
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    sampler: wgpu::Sampler,
+    constants_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    targets: Vec<Target>,
+    size: Size<u32>,
+}
+
+impl Pipeline {
+    /// Creates a new opacity [`Pipeline`].
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler =
+            device.create_sampler(&wgpu::SamplerDescriptor::default());
+
+        let constants_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::opacity uniforms layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::NonFiltering,
+                        ),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                std::mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::opacity texture layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: false,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::opacity pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constants_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu opacity shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/opacity.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::opacity pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(
+                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                        ),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            });
+
+        Self {
+            format,
+            pipeline,
+            sampler,
+            constants_layout,
+            texture_layout,
+            targets: Vec::new(),
+            size: Size::new(0, 0),
+        }
+    }
+
+    /// Prepares enough offscreen textures of the given size to draw groups
+    /// nested up to the given depth.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        size: Size<u32>,
+        depth: usize,
+    ) {
+        if self.size != size {
+            self.targets.clear();
+            self.size = size;
+        }
+
+        while self.targets.len() < depth {
+            self.targets.push(Target::new(
+                device,
+                self.format,
+                &self.texture_layout,
+                size,
+            ));
+        }
+    }
+
+    /// Returns the offscreen texture where the groups at the given depth
+    /// are drawn, if prepared.
+    pub fn target(&self, depth: usize) -> Option<&wgpu::TextureView> {
+        self.targets.get(depth).map(|target| &target.view)
+    }
+
+    /// Composites the group drawn at the given depth on top of the given
+    /// target, inside of the given bounds.
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        depth: usize,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
+        use wgpu::util::DeviceExt;
+
+        let Some(group) = self.targets.get(depth) else {
+            return;
+        };
+
+        if bounds.width < 1 || bounds.height < 1 {
+            return;
+        }
+
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::opacity uniforms buffer"),
+                contents: bytemuck::bytes_of(&Uniforms {
+                    opacity,
+                    _padding: [0.0; 3],
+                }),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::opacity uniforms bind group"),
+            layout: &self.constants_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::opacity render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+        render_pass.set_bind_group(0, &constants, &[]);
+        render_pass.set_bind_group(1, &group.binding, &[]);
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+#[derive(Debug)]
+struct Target {
+    view: wgpu::TextureView,
+    binding: wgpu::BindGroup,
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        texture_layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::opacity group"),
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let binding = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::opacity texture bind group"),
+            layout: texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Self { view, binding }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    opacity: f32,
+    // Uniform buffers need to be 16 bytes long on some backends
+    _padding: [f32; 3],
+}
@@ -225,6 +225,18 @@ pub struct Quad {
 
     /// The border width of the [`Quad`].
     pub border_width: f32,
+
+    /// The shadow color of the [`Quad`], in __linear RGB__.
+    pub shadow_color: color::Packed,
+
+    /// The shadow offset of the [`Quad`].
+    pub shadow_offset: [f32; 2],
+
+    /// The shadow blur radius of the [`Quad`].
+    pub shadow_blur_radius: f32,
+
+    /// The shadow spread radius of the [`Quad`].
+    pub shadow_spread_radius: f32,
 }
 
 /// A group of [`Quad`]s rendered together.
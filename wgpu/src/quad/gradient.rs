@@ -125,14 +125,24 @@ impl Pipeline {
                                 5 => Uint32x4,
                                 // Direction
                                 6 => Float32x4,
+                                // Kind
+                                7 => Uint32,
                                 // Position & Scale
-                                7 => Float32x4,
-                                // Border color
                                 8 => Float32x4,
-                                // Border radius
+                                // Border color
                                 9 => Float32x4,
+                                // Border radius
+                                10 => Float32x4,
                                 // Border width
-                                10 => Float32
+                                11 => Float32,
+                                // Shadow color
+                                12 => Float32x4,
+                                // Shadow offset
+                                13 => Float32x2,
+                                // Shadow blur radius
+                                14 => Float32,
+                                // Shadow spread radius
+                                15 => Float32,
                             ),
                         },
                     ],
@@ -27,6 +27,24 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The memory budget of the cache of raster images, in bytes.
+    ///
+    /// Without a budget, the images that are not drawn during a frame are
+    /// evicted right away. With a budget, they are kept until the budget is
+    /// exceeded, and then the least recently drawn ones are evicted first.
+    ///
+    /// By default, it is `None`.
+    pub image_cache_budget: Option<usize>,
+
+    /// The size step of the rasters of vector graphics, in physical pixels.
+    ///
+    /// With a step, the rasters are rounded up to a multiple of it, so they
+    /// are reused while a window is resized instead of rasterized on every
+    /// frame.
+    ///
+    /// By default, it is `None`.
+    pub svg_size_bucket: Option<u32>,
 }
 
 impl Settings {
@@ -61,6 +79,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            image_cache_budget: None,
+            svg_size_bucket: None,
         }
     }
 }
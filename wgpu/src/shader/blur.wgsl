@@ -0,0 +1,111 @@
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+var<private> uvs: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(0.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(1.0, 0.0),
+    vec2<f32>(1.0, 1.0)
+);
+
+// The maximum amount of samples taken on each side of a pixel.
+const MAX_SAMPLES: f32 = 32.0;
+
+struct Uniforms {
+    // The bounds of the blurred area, in physical pixels.
+    bounds: vec4<f32>,
+    // The border radii of the blurred area, in physical pixels.
+    border_radius: vec4<f32>,
+    // The direction of the blur pass.
+    direction: vec2<f32>,
+    // The radius of the blur, in physical pixels.
+    radius: f32,
+    // Whether to mask the result with the rounded bounds.
+    mask: u32,
+}
+
+@group(0) @binding(0) var u_sampler: sampler;
+@group(0) @binding(1) var<uniform> uniforms: Uniforms;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.uv = uvs[input.vertex_index];
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>) -> f32 {
+    var rx = radi.x;
+    var ry = radi.y;
+    rx = select(radi.x, radi.y, position.x > center.x);
+    ry = select(radi.w, radi.z, position.x > center.x);
+    rx = select(rx, ry, position.y > center.y);
+    return rx;
+}
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+// Blurs the texture in one direction with a gaussian kernel.
+@fragment
+fn fs_blur(input: VertexOutput) -> @location(0) vec4<f32> {
+    let size = vec2<f32>(textureDimensions(u_texture));
+    let position = input.position.xy;
+
+    let sigma = max(uniforms.radius / 2.0, 0.5);
+    let samples = min(ceil(uniforms.radius), MAX_SAMPLES);
+    let stride = max(uniforms.radius / max(samples, 1.0), 1.0);
+
+    var color = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    var total = 0.0;
+
+    for (var i = -i32(samples); i <= i32(samples); i++) {
+        let distance = f32(i) * stride;
+        let weight = exp(-(distance * distance) / (2.0 * sigma * sigma));
+        let uv = (position + uniforms.direction * distance) / size;
+
+        color += textureSampleLevel(u_texture, u_sampler, uv, 0.0) * weight;
+        total += weight;
+    }
+
+    color /= total;
+
+    if (uniforms.mask == 0u) {
+        return color;
+    }
+
+    let half_size = uniforms.bounds.zw / 2.0;
+    let center = uniforms.bounds.xy + half_size;
+
+    let radius = select_border_radius(uniforms.border_radius, position, center);
+    let distance = rounded_box_sdf(position - center, half_size, radius);
+
+    return color * (1.0 - smoothstep(-0.5, 0.5, distance));
+}
+
+// Copies the texture as is.
+@fragment
+fn fs_blit(input: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSampleLevel(u_texture, u_sampler, input.uv, 0.0);
+}
@@ -13,20 +13,62 @@ struct VertexInput {
     @location(3) atlas_pos: vec2<f32>,
     @location(4) atlas_scale: vec2<f32>,
     @location(5) layer: i32,
+    @location(6) orientation: u32,
+    @location(7) red: vec4<f32>,
+    @location(8) green: vec4<f32>,
+    @location(9) blue: vec4<f32>,
+    @location(10) alpha: vec4<f32>,
+    @location(11) offset: vec4<f32>,
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
+    @location(2) red: vec4<f32>,
+    @location(3) green: vec4<f32>,
+    @location(4) blue: vec4<f32>,
+    @location(5) alpha: vec4<f32>,
+    @location(6) offset: vec4<f32>,
 }
 
 @vertex
 fn vs_main(input: VertexInput) -> VertexOutput {
     var out: VertexOutput;
 
-    out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
+    // The orientation is undone to find the texel of every vertex:
+    // bits 0-1 are the clockwise quarter turns, bit 2 the horizontal flip,
+    // and bit 3 the vertical flip.
+    var uv: vec2<f32> = input.v_pos;
+
+    if (input.orientation & 4u) != 0u {
+        uv.x = 1.0 - uv.x;
+    }
+
+    if (input.orientation & 8u) != 0u {
+        uv.y = 1.0 - uv.y;
+    }
+
+    switch input.orientation & 3u {
+        case 1u: {
+            uv = vec2<f32>(uv.y, 1.0 - uv.x);
+        }
+        case 2u: {
+            uv = vec2<f32>(1.0 - uv.x, 1.0 - uv.y);
+        }
+        case 3u: {
+            uv = vec2<f32>(1.0 - uv.y, uv.x);
+        }
+        default: {}
+    }
+
+    out.uv = vec2<f32>(uv * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.red = input.red;
+    out.green = input.green;
+    out.blue = input.blue;
+    out.alpha = input.alpha;
+    out.offset = input.offset;
 
     var transform: mat4x4<f32> = mat4x4<f32>(
         vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
@@ -42,5 +84,10 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    let color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    // The color matrix of the effects, column by column
+    let effects = mat4x4<f32>(input.red, input.green, input.blue, input.alpha);
+
+    return clamp(effects * color + input.offset, vec4<f32>(0.0), vec4<f32>(1.0));
 }
@@ -37,3 +37,61 @@ fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>)
     rx = select(rx, ry, position.y > center.y);
     return rx;
 }
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+// Returns the bounds of a quad grown to fit its shadow, as a position and a size.
+fn shadow_bounds(
+    position: vec2<f32>,
+    size: vec2<f32>,
+    shadow_offset: vec2<f32>,
+    shadow_blur_radius: f32,
+    shadow_spread_radius: f32
+) -> vec4<f32> {
+    let extent = shadow_blur_radius + shadow_spread_radius;
+
+    return vec4<f32>(
+        position + min(shadow_offset, vec2<f32>(0.0, 0.0)) - vec2<f32>(extent, extent),
+        size + abs(shadow_offset) + vec2<f32>(extent, extent) * 2.0
+    );
+}
+
+// Composites the shadow of a quad under the color of the quad.
+fn with_shadow(
+    quad_color: vec4<f32>,
+    radius_alpha: f32,
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    border_radius: f32,
+    shadow_color: vec4<f32>,
+    shadow_offset: vec2<f32>,
+    shadow_blur_radius: f32,
+    shadow_spread_radius: f32
+) -> vec4<f32> {
+    if (shadow_color.a <= 0.0) {
+        return quad_color;
+    }
+
+    let half_size = size * 0.5 + vec2<f32>(shadow_spread_radius, shadow_spread_radius);
+
+    let shadow_distance = rounded_box_sdf(
+        frag_coord - position - shadow_offset - size * 0.5,
+        half_size,
+        min(border_radius + shadow_spread_radius, min(half_size.x, half_size.y))
+    );
+
+    // Keep the edge of an unblurred shadow antialiased
+    let blur = max(shadow_blur_radius, 0.5);
+    let shadow_alpha = 1.0 - smoothstep(-blur, blur, shadow_distance);
+
+    let base_color = select(
+        vec4<f32>(shadow_color.x, shadow_color.y, shadow_color.z, 0.0),
+        quad_color,
+        quad_color.a > 0.0
+    );
+
+    return mix(base_color, shadow_color, (1.0 - radius_alpha) * shadow_alpha);
+}
@@ -6,10 +6,15 @@ struct GradientVertexInput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
-    @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(7) @interpolate(flat) kind: u32,
+    @location(8) position_and_scale: vec4<f32>,
+    @location(9) border_color: vec4<f32>,
+    @location(10) border_radius: vec4<f32>,
+    @location(11) border_width: f32,
+    @location(12) shadow_color: vec4<f32>,
+    @location(13) shadow_offset: vec2<f32>,
+    @location(14) shadow_blur_radius: f32,
+    @location(15) shadow_spread_radius: f32,
 }
 
 struct GradientVertexOutput {
@@ -20,18 +25,31 @@ struct GradientVertexOutput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
-    @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(7) @interpolate(flat) kind: u32,
+    @location(8) position_and_scale: vec4<f32>,
+    @location(9) border_color: vec4<f32>,
+    @location(10) border_radius: vec4<f32>,
+    @location(11) border_width: f32,
+    @location(12) shadow_color: vec4<f32>,
+    @location(13) shadow_offset: vec2<f32>,
+    @location(14) shadow_blur_radius: f32,
+    @location(15) shadow_spread_radius: f32,
 }
 
 @vertex
 fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     var out: GradientVertexOutput;
 
-    var pos: vec2<f32> = input.position_and_scale.xy * globals.scale;
-    var scale: vec2<f32> = input.position_and_scale.zw * globals.scale;
+    let bounds = shadow_bounds(
+        input.position_and_scale.xy,
+        input.position_and_scale.zw,
+        input.shadow_offset,
+        input.shadow_blur_radius,
+        input.shadow_spread_radius
+    );
+
+    var pos: vec2<f32> = bounds.xy * globals.scale;
+    var scale: vec2<f32> = bounds.zw * globals.scale;
 
     var min_border_radius = min(input.position_and_scale.z, input.position_and_scale.w) * 0.5;
     var border_radius: vec4<f32> = vec4<f32>(
@@ -55,14 +73,26 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.colors_4 = input.colors_4;
     out.offsets = input.offsets;
     out.direction = input.direction * globals.scale;
-    out.position_and_scale = vec4<f32>(pos, scale);
+    out.kind = input.kind;
+
+    // The angle of a conic gradient is not scaled
+    if (input.kind == 2u) {
+        out.direction.z = input.direction.z;
+    }
+    out.position_and_scale = input.position_and_scale * globals.scale;
     out.border_color = input.border_color;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = input.shadow_offset * globals.scale;
+    out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_spread_radius = input.shadow_spread_radius * globals.scale;
 
     return out;
 }
 
+const PI: f32 = 3.14159265358979;
+
 fn random(coords: vec2<f32>) -> f32 {
     return fract(sin(dot(coords, vec2(12.9898,78.233))) * 43758.5453);
 }
@@ -71,17 +101,37 @@ fn random(coords: vec2<f32>) -> f32 {
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    kind: u32,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
+    var coord_offset: f32;
+
+    switch kind {
+        // Radial: the center and the radius
+        case 1u: {
+            coord_offset = length(raw_position - direction.xy) / direction.z;
+        }
+        // Conic: the center and the starting angle, clockwise from the top
+        case 2u: {
+            let v = raw_position - direction.xy;
+            let angle = atan2(v.y, v.x) + PI / 2.0 - direction.z;
+
+            coord_offset = fract(angle / (2.0 * PI));
+        }
+        // Linear: the start and end points
+        default: {
+            let start = direction.xy;
+            let end = direction.zw;
 
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+            let v1 = end - start;
+            let v2 = raw_position - start;
+            let unit = normalize(v1);
+
+            coord_offset = dot(unit, v2) / length(v1);
+        }
+    }
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -152,7 +202,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, input.kind, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
@@ -194,7 +244,20 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         border_radius + 0.5,
         dist);
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    let quad_color = vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+
+    return with_shadow(
+        quad_color,
+        radius_alpha,
+        input.position.xy,
+        pos,
+        scale,
+        border_radius,
+        input.shadow_color,
+        input.shadow_offset,
+        input.shadow_blur_radius,
+        input.shadow_spread_radius
+    );
 }
 
 fn unpack_u32(color: vec2<u32>) -> vec4<f32> {
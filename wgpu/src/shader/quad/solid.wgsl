@@ -6,6 +6,10 @@ struct SolidVertexInput {
     @location(4) border_color: vec4<f32>,
     @location(5) border_radius: vec4<f32>,
     @location(6) border_width: f32,
+    @location(7) shadow_color: vec4<f32>,
+    @location(8) shadow_offset: vec2<f32>,
+    @location(9) shadow_blur_radius: f32,
+    @location(10) shadow_spread_radius: f32,
 }
 
 struct SolidVertexOutput {
@@ -16,14 +20,26 @@ struct SolidVertexOutput {
     @location(3) scale: vec2<f32>,
     @location(4) border_radius: vec4<f32>,
     @location(5) border_width: f32,
+    @location(6) shadow_color: vec4<f32>,
+    @location(7) shadow_offset: vec2<f32>,
+    @location(8) shadow_blur_radius: f32,
+    @location(9) shadow_spread_radius: f32,
 }
 
 @vertex
 fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     var out: SolidVertexOutput;
 
-    var pos: vec2<f32> = input.pos * globals.scale;
-    var scale: vec2<f32> = input.scale * globals.scale;
+    let bounds = shadow_bounds(
+        input.pos,
+        input.scale,
+        input.shadow_offset,
+        input.shadow_blur_radius,
+        input.shadow_spread_radius
+    );
+
+    var pos: vec2<f32> = bounds.xy * globals.scale;
+    var scale: vec2<f32> = bounds.zw * globals.scale;
 
     var min_border_radius = min(input.scale.x, input.scale.y) * 0.5;
     var border_radius: vec4<f32> = vec4<f32>(
@@ -43,10 +59,14 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
     out.color = input.color;
     out.border_color = input.border_color;
-    out.pos = pos;
-    out.scale = scale;
+    out.pos = input.pos * globals.scale;
+    out.scale = input.scale * globals.scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = input.shadow_offset * globals.scale;
+    out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_spread_radius = input.shadow_spread_radius * globals.scale;
 
     return out;
 }
@@ -95,5 +115,18 @@ fn solid_fs_main(
         dist
     );
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    let quad_color = vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+
+    return with_shadow(
+        quad_color,
+        radius_alpha,
+        input.position.xy,
+        input.pos,
+        input.scale,
+        border_radius,
+        input.shadow_color,
+        input.shadow_offset,
+        input.shadow_blur_radius,
+        input.shadow_spread_radius
+    );
 }
@@ -0,0 +1,79 @@
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+var<private> uvs: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(0.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(1.0, 0.0),
+    vec2<f32>(1.0, 1.0)
+);
+
+@group(0) @binding(0) var u_sampler: sampler;
+@group(0) @binding(1) var u_plane_0: texture_2d<f32>;
+@group(0) @binding(2) var u_plane_1: texture_2d<f32>;
+@group(0) @binding(3) var u_plane_2: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.uv = uvs[input.vertex_index];
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+// Converts limited range BT.709 YUV to gamma encoded RGB
+fn yuv_to_rgb(y: f32, u: f32, v: f32) -> vec4<f32> {
+    let luma = (y - 16.0 / 255.0) * 1.164383;
+    let cb = u - 128.0 / 255.0;
+    let cr = v - 128.0 / 255.0;
+
+    return vec4<f32>(
+        clamp(vec3<f32>(
+            luma + 1.792741 * cr,
+            luma - 0.213249 * cb - 0.532909 * cr,
+            luma + 2.112402 * cb,
+        ), vec3<f32>(0.0), vec3<f32>(1.0)),
+        1.0,
+    );
+}
+
+@fragment
+fn fs_bgra(input: VertexOutput) -> @location(0) vec4<f32> {
+    // The plane is a `Bgra8Unorm` texture, so it is already swizzled
+    return textureSample(u_plane_0, u_sampler, input.uv);
+}
+
+@fragment
+fn fs_nv12(input: VertexOutput) -> @location(0) vec4<f32> {
+    let y = textureSample(u_plane_0, u_sampler, input.uv).r;
+    let uv = textureSample(u_plane_1, u_sampler, input.uv).rg;
+
+    return yuv_to_rgb(y, uv.r, uv.g);
+}
+
+@fragment
+fn fs_i420(input: VertexOutput) -> @location(0) vec4<f32> {
+    let y = textureSample(u_plane_0, u_sampler, input.uv).r;
+    let u = textureSample(u_plane_1, u_sampler, input.uv).r;
+    let v = textureSample(u_plane_2, u_sampler, input.uv).r;
+
+    return yuv_to_rgb(y, u, v);
+}
@@ -310,6 +310,10 @@ impl<Theme> graphics::Compositor for Compositor<Theme> {
             )
         })
     }
+
+    fn statistics(&self, renderer: &Self::Renderer) -> compositor::Statistics {
+        renderer.statistics()
+    }
 }
 
 /// Renders the current surface to an offscreen buffer.
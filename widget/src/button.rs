@@ -1,18 +1,27 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::core::accessibility;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::token::{self, Step};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
-    Rectangle, Shell, Vector, Widget,
+    Rectangle, Shadow, Shell, Vector, Widget,
 };
+use crate::focus_ring;
+use crate::runtime::animation::{Animation, Easing};
+use crate::style::focus;
+use crate::style::theme::sheet::Class;
 
 pub use iced_style::button::{Appearance, StyleSheet};
 
@@ -61,6 +70,8 @@ where
     width: Length,
     height: Length,
     padding: Padding,
+    tab_index: i32,
+    transition: Duration,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -69,6 +80,10 @@ where
     Renderer: crate::core::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    /// The default [`Duration`] of the transitions between the appearances of
+    /// a [`Button`].
+    pub const DEFAULT_TRANSITION: Duration = Duration::from_millis(120);
+
     /// Creates a new [`Button`] with the given content.
     pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Button {
@@ -76,7 +91,9 @@ where
             on_press: None,
             width: Length::Shrink,
             height: Length::Shrink,
-            padding: Padding::new(5.0),
+            padding: Padding::new(token::spacing(Step::Sm)),
+            tab_index: 0,
+            transition: Self::DEFAULT_TRANSITION,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
     }
@@ -116,6 +133,27 @@ where
         self
     }
 
+    /// Sets the position of the [`Button`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Sets the [`Duration`] of the transitions of the [`Button`] as it is
+    /// hovered, pressed, and released.
+    ///
+    /// The background, the border, and the shadow of the [`Button`] are
+    /// interpolated between its appearances during the transition. A zero
+    /// [`Duration`] disables the transitions.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = duration;
+        self
+    }
+
     /// Sets the style variant of this [`Button`].
     pub fn style(
         mut self,
@@ -124,6 +162,19 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the class of the [`Button`], styling it with the properties of
+    /// the class in the style sheet of the theme.
+    ///
+    /// See the [`sheet`] module to learn about style sheets.
+    ///
+    /// [`sheet`]: crate::style::theme::sheet
+    pub fn class(self, class: impl Into<Class>) -> Self
+    where
+        <Renderer::Theme as StyleSheet>::Style: From<Class>,
+    {
+        self.style(class.into())
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -131,7 +182,7 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
 where
     Message: 'a + Clone,
     Renderer: 'a + crate::core::Renderer,
-    Renderer::Theme: StyleSheet,
+    Renderer::Theme: StyleSheet + focus::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -179,7 +230,22 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
+        if self.on_press.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.focusable(
+                &mut operation::focusable::with_tab_index(
+                    state,
+                    self.tab_index,
+                ),
+                None,
+            );
+        }
+
+        let node = accessibility::Node::new(accessibility::Role::Button)
+            .disabled(self.on_press.is_none());
+
+        operation.accessible(None, layout.bounds(), &node, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
                 layout.children().next().unwrap(),
@@ -200,6 +266,20 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            let status = status(
+                cursor,
+                layout.bounds(),
+                self.on_press.is_some(),
+                || &*state,
+            );
+
+            state.transition_to(status, now, self.transition);
+            state.transition.request_redraw(now, shell);
+        }
+
         if let event::Status::Captured = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
@@ -230,16 +310,28 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
+        let state = tree.state.downcast_ref::<State>();
 
-        let styling = draw(
-            renderer,
-            bounds,
-            cursor,
-            self.on_press.is_some(),
-            theme,
-            &self.style,
-            || tree.state.downcast_ref::<State>(),
-        );
+        let status = status(cursor, bounds, self.on_press.is_some(), || state);
+
+        let now = Instant::now();
+
+        let styling = match state.previous {
+            Some(previous)
+                if state.status == Some(status)
+                    && state.transition.is_animating(now) =>
+            {
+                let progress = state.transition.value(now);
+
+                appearance(theme, &self.style, previous).interpolate(
+                    &appearance(theme, &self.style, status),
+                    progress,
+                )
+            }
+            _ => appearance(theme, &self.style, status),
+        };
+
+        draw_appearance(renderer, bounds, &styling);
 
         self.content.as_widget().draw(
             &tree.children[0],
@@ -252,6 +344,12 @@ where
             cursor,
             &bounds,
         );
+
+        if self.on_press.is_some()
+            && tree.state.downcast_ref::<State>().is_focused
+        {
+            focus_ring::draw(renderer, bounds, theme);
+        }
     }
 
     fn mouse_interaction(
@@ -284,7 +382,7 @@ impl<'a, Message, Renderer> From<Button<'a, Message, Renderer>>
 where
     Message: Clone + 'a,
     Renderer: crate::core::Renderer + 'a,
-    Renderer::Theme: StyleSheet,
+    Renderer::Theme: StyleSheet + focus::StyleSheet,
 {
     fn from(button: Button<'a, Message, Renderer>) -> Self {
         Self::new(button)
@@ -292,15 +390,78 @@ where
 }
 
 /// The local state of a [`Button`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
+    status: Option<Status>,
+    previous: Option<Status>,
+    transition: Animation<f32>,
 }
 
 impl State {
     /// Creates a new [`State`].
     pub fn new() -> State {
-        State::default()
+        State {
+            is_pressed: false,
+            is_focused: false,
+            status: None,
+            previous: None,
+            transition: Animation::new(1.0),
+        }
+    }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Starts a transition from the current [`Status`] to the given one, if
+    /// they differ.
+    fn transition_to(
+        &mut self,
+        status: Status,
+        now: Instant,
+        duration: Duration,
+    ) {
+        let Some(current) = self.status.replace(status) else {
+            return;
+        };
+
+        if current == status {
+            return;
+        }
+
+        if duration.is_zero() {
+            self.previous = None;
+            return;
+        }
+
+        self.previous = Some(current);
+        self.transition = Animation::new(0.0)
+            .duration(duration)
+            .easing(Easing::EaseOut);
+        self.transition.go_to(1.0, now);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
     }
 }
 
@@ -317,18 +478,33 @@ pub fn update<'a, Message: Clone>(
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let state = state();
+
+            // Pointer interactions hide the focus ring
+            state.is_focused = false;
+
             if on_press.is_some() {
                 let bounds = layout.bounds();
 
                 if cursor.is_over(bounds) {
-                    let state = state();
-
                     state.is_pressed = true;
 
                     return event::Status::Captured;
                 }
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::Space,
+            ..
+        }) => {
+            if let Some(on_press) = on_press.clone() {
+                if state().is_focused {
+                    shell.publish(on_press);
+
+                    return event::Status::Captured;
+                }
+            }
+        }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. }) => {
             if let Some(on_press) = on_press.clone() {
@@ -358,6 +534,56 @@ pub fn update<'a, Message: Clone>(
     event::Status::Ignored
 }
 
+/// The interaction status of a [`Button`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Button`] can be pressed.
+    Active,
+    /// The [`Button`] can be pressed and it is being hovered.
+    Hovered,
+    /// The [`Button`] is being pressed.
+    Pressed,
+    /// The [`Button`] cannot be pressed.
+    Disabled,
+}
+
+/// Returns the [`Status`] of a [`Button`].
+pub fn status<'a>(
+    cursor: mouse::Cursor,
+    bounds: Rectangle,
+    is_enabled: bool,
+    state: impl FnOnce() -> &'a State,
+) -> Status {
+    if !is_enabled {
+        Status::Disabled
+    } else if cursor.is_over(bounds) {
+        if state().is_pressed {
+            Status::Pressed
+        } else {
+            Status::Hovered
+        }
+    } else {
+        Status::Active
+    }
+}
+
+/// Returns the [`Appearance`] of a [`Button`] with the given [`Status`].
+pub fn appearance<Theme>(
+    style_sheet: &Theme,
+    style: &Theme::Style,
+    status: Status,
+) -> Appearance
+where
+    Theme: StyleSheet + ?Sized,
+{
+    match status {
+        Status::Active => style_sheet.active(style),
+        Status::Hovered => style_sheet.hovered(style),
+        Status::Pressed => style_sheet.pressed(style),
+        Status::Disabled => style_sheet.disabled(style),
+    }
+}
+
 /// Draws a [`Button`].
 pub fn draw<'a, Renderer: crate::core::Renderer>(
     renderer: &mut Renderer,
@@ -373,54 +599,61 @@ pub fn draw<'a, Renderer: crate::core::Renderer>(
 where
     Renderer::Theme: StyleSheet,
 {
-    let is_mouse_over = cursor.is_over(bounds);
+    let status = status(cursor, bounds, is_enabled, state);
+    let styling = appearance(style_sheet, style, status);
 
-    let styling = if !is_enabled {
-        style_sheet.disabled(style)
-    } else if is_mouse_over {
-        let state = state();
+    draw_appearance(renderer, bounds, &styling);
 
-        if state.is_pressed {
-            style_sheet.pressed(style)
-        } else {
-            style_sheet.hovered(style)
-        }
-    } else {
-        style_sheet.active(style)
-    };
-
-    if styling.background.is_some() || styling.border_width > 0.0 {
-        if styling.shadow_offset != Vector::default() {
-            // TODO: Implement proper shadow support
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: Rectangle {
-                        x: bounds.x + styling.shadow_offset.x,
-                        y: bounds.y + styling.shadow_offset.y,
-                        ..bounds
-                    },
-                    border_radius: styling.border_radius,
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                },
-                Background::Color([0.0, 0.0, 0.0, 0.5].into()),
-            );
-        }
+    styling
+}
 
+/// Draws the background, the border, and the shadow of a [`Button`] with
+/// the given [`Appearance`].
+pub fn draw_appearance<Renderer: crate::core::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    appearance: &Appearance,
+) {
+    let shadow = shadow(appearance);
+
+    if appearance.background.is_some()
+        || appearance.border_width > 0.0
+        || !shadow.is_invisible()
+    {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
-                border_radius: styling.border_radius,
-                border_width: styling.border_width,
-                border_color: styling.border_color,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+                shadow,
             },
-            styling
+            appearance
                 .background
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
         );
     }
+}
 
-    styling
+/// Returns the [`Shadow`] of a button with the given [`Appearance`], moved
+/// by its `shadow_offset`.
+fn shadow(appearance: &Appearance) -> Shadow {
+    let shadow = if appearance.shadow.is_invisible()
+        && appearance.shadow_offset != Vector::default()
+    {
+        // A hard shadow, for styles that only set an offset
+        Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            ..Shadow::default()
+        }
+    } else {
+        appearance.shadow
+    };
+
+    Shadow {
+        offset: shadow.offset + appearance.shadow_offset,
+        ..shadow
+    }
 }
 
 /// Computes the layout of a [`Button`].
@@ -439,6 +672,10 @@ pub fn layout(
 
     content.move_to(Point::new(padding.left, padding.top));
 
+    if layout::Direction::current().is_rtl() {
+        content.mirror(size.width);
+    }
+
     layout::Node::with_children(size, vec![content])
 }
 
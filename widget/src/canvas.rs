@@ -155,6 +155,7 @@ where
         let canvas_event = match event {
             core::Event::Mouse(mouse_event) => Some(Event::Mouse(mouse_event)),
             core::Event::Touch(touch_event) => Some(Event::Touch(touch_event)),
+            core::Event::Pen(pen_event) => Some(Event::Pen(pen_event)),
             core::Event::Keyboard(keyboard_event) => {
                 Some(Event::Keyboard(keyboard_event))
             }
@@ -164,9 +165,19 @@ where
         if let Some(canvas_event) = canvas_event {
             let state = tree.state.downcast_mut::<P::State>();
 
+            let is_press = matches!(
+                canvas_event,
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+            );
+
             let (event_status, message) =
                 self.program.update(state, canvas_event, bounds, cursor);
 
+            // A program handling a press keeps its drag outside of the bounds
+            if is_press && event_status == event::Status::Captured {
+                shell.capture_pointer();
+            }
+
             if let Some(message) = message {
                 shell.publish(message);
             }
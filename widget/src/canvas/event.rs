@@ -1,6 +1,7 @@
 //! Handle events of a canvas.
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::pen;
 use crate::core::touch;
 
 pub use crate::core::event::Status;
@@ -16,6 +17,9 @@ pub enum Event {
     /// A touch event.
     Touch(touch::Event),
 
+    /// A pen event.
+    Pen(pen::Event),
+
     /// A keyboard event.
     Keyboard(keyboard::Event),
 }
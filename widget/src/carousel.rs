@@ -0,0 +1,643 @@
+//! Swipe through pages of content.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shadow, Shell,
+    Size, Vector, Widget,
+};
+
+pub use iced_style::carousel::{Appearance, StyleSheet};
+
+/// The distance the cursor needs to travel horizontally before a press turns
+/// into a swipe.
+const SWIPE_THRESHOLD: f32 = 8.0;
+
+/// The velocity, in pixels per second, a swipe needs to change pages
+/// regardless of the distance travelled.
+const FLING_VELOCITY: f32 = 500.0;
+
+/// The diameter of a page indicator.
+const INDICATOR_SIZE: f32 = 8.0;
+
+/// A container that displays one of its pages at a time.
+///
+/// Pages can be changed by swiping or dragging horizontally, and optionally
+/// by autoplay. Every change of page is animated.
+#[allow(missing_debug_implementations)]
+pub struct Carousel<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    width: Length,
+    height: Length,
+    indicators: bool,
+    autoplay: Option<Duration>,
+    transition: Duration,
+    on_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    pages: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Carousel<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default duration of the transition between pages.
+    pub const DEFAULT_TRANSITION: Duration = Duration::from_millis(300);
+
+    /// Creates an empty [`Carousel`].
+    pub fn new() -> Self {
+        Self::with_pages(Vec::new())
+    }
+
+    /// Creates a [`Carousel`] with the given pages.
+    pub fn with_pages(pages: Vec<Element<'a, Message, Renderer>>) -> Self {
+        Carousel {
+            width: Length::Fill,
+            height: Length::Shrink,
+            indicators: true,
+            autoplay: None,
+            transition: Self::DEFAULT_TRANSITION,
+            on_change: None,
+            style: Default::default(),
+            pages,
+        }
+    }
+
+    /// Sets the width of the [`Carousel`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Carousel`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets whether the page indicators of the [`Carousel`] are shown.
+    ///
+    /// By default, they are shown.
+    pub fn indicators(mut self, indicators: bool) -> Self {
+        self.indicators = indicators;
+        self
+    }
+
+    /// Makes the [`Carousel`] move to the next page every `interval`,
+    /// looping back to the first page after the last one.
+    ///
+    /// The countdown restarts whenever the page changes.
+    pub fn autoplay(mut self, interval: Duration) -> Self {
+        self.autoplay = Some(interval);
+        self
+    }
+
+    /// Sets the duration of the animated transition between pages of the
+    /// [`Carousel`].
+    pub fn transition(mut self, transition: Duration) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Sets the message that will be produced when the current page of the
+    /// [`Carousel`] changes.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the style of the [`Carousel`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Adds a page to the [`Carousel`].
+    pub fn push(
+        mut self,
+        page: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.pages.push(page.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Carousel<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Carousel<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.pages.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.pages);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.current >= self.pages.len() {
+            state.current = self.pages.len().saturating_sub(1);
+            state.position = state.current as f32;
+            state.transition = None;
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let pages: Vec<_> = self
+            .pages
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(page, tree)| {
+                page.as_widget().layout(tree, renderer, &limits)
+            })
+            .collect();
+
+        let intrinsic_size = pages
+            .iter()
+            .fold(Size::ZERO, |size, page| size.max(page.size()));
+
+        layout::Node::with_children(limits.resolve(intrinsic_size), pages)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.pages
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((page, state), layout)| {
+                    page.as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if self.pages.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.animate(now, self.transition);
+
+            if let Some(interval) = self.autoplay {
+                let changed_at = *state.changed_at.get_or_insert(now);
+
+                if state.drag.is_none() && now >= changed_at + interval {
+                    let next = (state.current + 1) % self.pages.len();
+
+                    state.go_to(next, now);
+
+                    if let Some(on_change) = &self.on_change {
+                        shell.publish(on_change(next));
+                    }
+                }
+
+                if let Some(changed_at) = state.changed_at {
+                    shell.request_redraw(window::RedrawRequest::At(
+                        changed_at + interval,
+                    ));
+                }
+            }
+
+            if state.transition.is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let current = state.current;
+
+        if !state.is_moving() {
+            let status = self.pages[current].as_widget_mut().on_event(
+                &mut tree.children[current],
+                event.clone(),
+                layout.children().nth(current).unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if let event::Status::Captured = status {
+                return event::Status::Captured;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(cursor_position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.drag = Some(Drag::new(cursor_position));
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(drag) = &mut state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(cursor_position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                drag.update(cursor_position);
+
+                if drag.is_swiping {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if !drag.is_swiping {
+                    return event::Status::Ignored;
+                }
+
+                // The page moves in the opposite direction of the swipe
+                let target = if drag.offset < -bounds.width / 4.0
+                    || drag.velocity < -FLING_VELOCITY
+                {
+                    (state.current + 1).min(self.pages.len() - 1)
+                } else if drag.offset > bounds.width / 4.0
+                    || drag.velocity > FLING_VELOCITY
+                {
+                    state.current.saturating_sub(1)
+                } else {
+                    state.current
+                };
+
+                state.position =
+                    state.current as f32 - drag.offset / bounds.width.max(1.0);
+
+                let changed = target != state.current;
+
+                state.go_to(target, Instant::now());
+
+                if changed {
+                    if let Some(on_change) = &self.on_change {
+                        shell.publish(on_change(target));
+                    }
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.as_ref().is_some_and(|drag| drag.is_swiping) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if state.is_moving() || self.pages.is_empty() {
+            return mouse::Interaction::Idle;
+        }
+
+        self.pages[state.current].as_widget().mouse_interaction(
+            &tree.children[state.current],
+            layout.children().nth(state.current).unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let position = state.visual_position(bounds.width);
+
+        let cursor = if state.is_moving() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, ((page, tree), layout)) in self
+                .pages
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let distance = index as f32 - position;
+
+                if distance.abs() >= 1.0 {
+                    continue;
+                }
+
+                let translation = Vector::new(distance * bounds.width, 0.0);
+
+                renderer.with_translation(translation, |renderer| {
+                    page.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        if index == state.current {
+                            cursor
+                        } else {
+                            mouse::Cursor::Unavailable
+                        },
+                        &(*viewport - translation),
+                    );
+                });
+            }
+        });
+
+        if self.indicators && self.pages.len() > 1 {
+            let appearance = theme.appearance(&self.style);
+
+            let total_width =
+                self.pages.len() as f32 * INDICATOR_SIZE * 2.0 - INDICATOR_SIZE;
+
+            let origin = Point::new(
+                bounds.center_x() - total_width / 2.0,
+                bounds.y + bounds.height - INDICATOR_SIZE * 2.5,
+            );
+
+            for index in 0..self.pages.len() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: origin.x + index as f32 * INDICATOR_SIZE * 2.0,
+                            y: origin.y,
+                            width: INDICATOR_SIZE,
+                            height: INDICATOR_SIZE,
+                        },
+                        border_radius: (INDICATOR_SIZE / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    },
+                    if index == state.current {
+                        appearance.active_indicator
+                    } else {
+                        appearance.indicator
+                    },
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_moving() || self.pages.is_empty() {
+            return None;
+        }
+
+        let current = state.current;
+
+        self.pages[current].as_widget_mut().overlay(
+            &mut tree.children[current],
+            layout.children().nth(current).unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Carousel<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(carousel: Carousel<'a, Message, Renderer>) -> Self {
+        Self::new(carousel)
+    }
+}
+
+/// The local state of a [`Carousel`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    current: usize,
+    position: f32,
+    transition: Option<Transition>,
+    drag: Option<Drag>,
+    changed_at: Option<Instant>,
+}
+
+/// An animated transition between pages of a [`Carousel`].
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    from: f32,
+    started_at: Instant,
+}
+
+/// A press, and possibly a swipe, in progress in a [`Carousel`].
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    offset: f32,
+    velocity: f32,
+    moved_at: Instant,
+    is_swiping: bool,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            current: 0,
+            position: 0.0,
+            transition: None,
+            drag: None,
+            changed_at: None,
+        }
+    }
+
+    fn is_moving(&self) -> bool {
+        self.transition.is_some()
+            || self.drag.is_some_and(|drag| drag.is_swiping)
+    }
+
+    /// Returns the page position currently shown, taking any swipe in
+    /// progress into account.
+    fn visual_position(&self, width: f32) -> f32 {
+        match self.drag {
+            Some(drag) if drag.is_swiping => {
+                self.position - drag.offset / width.max(1.0)
+            }
+            _ => self.position,
+        }
+    }
+
+    /// Starts a transition from the current position to the given page.
+    fn go_to(&mut self, page: usize, now: Instant) {
+        self.transition = Some(Transition {
+            from: self.position,
+            started_at: now,
+        });
+        self.current = page;
+        self.changed_at = Some(now);
+    }
+
+    /// Advances the current transition, if any.
+    fn animate(&mut self, now: Instant, duration: Duration) {
+        let Some(transition) = self.transition else {
+            return;
+        };
+
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (now.duration_since(transition.started_at).as_secs_f32()
+                / duration.as_secs_f32())
+            .min(1.0)
+        };
+
+        // Ease out cubic
+        let eased = 1.0 - (1.0 - progress).powi(3);
+
+        self.position =
+            transition.from + (self.current as f32 - transition.from) * eased;
+
+        if progress >= 1.0 {
+            self.position = self.current as f32;
+            self.transition = None;
+        }
+    }
+}
+
+impl Drag {
+    fn new(origin: Point) -> Self {
+        Drag {
+            origin,
+            offset: 0.0,
+            velocity: 0.0,
+            moved_at: Instant::now(),
+            is_swiping: false,
+        }
+    }
+
+    fn update(&mut self, position: Point) {
+        let now = Instant::now();
+        let offset = position.x - self.origin.x;
+        let elapsed = now.duration_since(self.moved_at).as_secs_f32();
+
+        if elapsed > 0.0 {
+            self.velocity = (offset - self.offset) / elapsed;
+        }
+
+        self.offset = offset;
+        self.moved_at = now;
+
+        if !self.is_swiping && offset.abs() > SWIPE_THRESHOLD {
+            self.is_swiping = true;
+        }
+    }
+}
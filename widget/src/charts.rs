@@ -0,0 +1,301 @@
+//! Plot data with line, area, bar, and pie charts.
+mod chart;
+mod pie;
+
+pub use chart::Chart;
+pub use pie::Pie;
+
+pub use iced_style::charts::{Appearance, StyleSheet};
+
+use crate::canvas::{Frame, Path, Text};
+use crate::core::alignment;
+use crate::core::i18n;
+use crate::core::{Color, Point, Size};
+
+/// The size of the labels of a chart.
+const TEXT_SIZE: f32 = 12.0;
+
+/// A series of data points plotted in a [`Chart`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    label: String,
+    kind: Kind,
+    color: Option<Color>,
+    points: Vec<(f32, f32)>,
+}
+
+/// The way a [`Series`] is plotted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// The points are joined by straight lines.
+    Line,
+    /// The points are joined by straight lines and the area below them is
+    /// filled.
+    Area,
+    /// Every point is plotted as a vertical bar.
+    Bar,
+}
+
+impl Series {
+    /// Creates a new [`Series`] of the given [`Kind`] with a label and some
+    /// `(x, y)` points.
+    pub fn new(
+        kind: Kind,
+        label: impl Into<String>,
+        points: impl IntoIterator<Item = (f32, f32)>,
+    ) -> Self {
+        let mut points: Vec<_> = points
+            .into_iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+
+        points.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Self {
+            label: label.into(),
+            kind,
+            color: None,
+            points,
+        }
+    }
+
+    /// Creates a new [`Kind::Line`] [`Series`].
+    pub fn line(
+        label: impl Into<String>,
+        points: impl IntoIterator<Item = (f32, f32)>,
+    ) -> Self {
+        Self::new(Kind::Line, label, points)
+    }
+
+    /// Creates a new [`Kind::Area`] [`Series`].
+    pub fn area(
+        label: impl Into<String>,
+        points: impl IntoIterator<Item = (f32, f32)>,
+    ) -> Self {
+        Self::new(Kind::Area, label, points)
+    }
+
+    /// Creates a new [`Kind::Bar`] [`Series`].
+    pub fn bar(
+        label: impl Into<String>,
+        points: impl IntoIterator<Item = (f32, f32)>,
+    ) -> Self {
+        Self::new(Kind::Bar, label, points)
+    }
+
+    /// Sets the [`Color`] of the [`Series`].
+    ///
+    /// By default, the colors of the [`Appearance`] are used in order.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// A slice of a [`Pie`] chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slice {
+    label: String,
+    value: f32,
+    color: Option<Color>,
+}
+
+impl Slice {
+    /// Creates a new [`Slice`] with a label and a value.
+    ///
+    /// Negative values are treated as zero.
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value: if value.is_finite() {
+                value.max(0.0)
+            } else {
+                0.0
+            },
+            color: None,
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Slice`].
+    ///
+    /// By default, the colors of the [`Appearance`] are used in order.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Returns the color of the element at `index`, given its explicit color and
+/// the [`Appearance`] of the chart.
+fn color(index: usize, color: Option<Color>, appearance: &Appearance) -> Color {
+    color.unwrap_or(appearance.series[index % appearance.series.len()])
+}
+
+/// Returns a "nice" distance between ticks to divide the given range in
+/// roughly `count` parts.
+fn tick_step(range: f32, count: usize) -> f32 {
+    if range <= 0.0 || !range.is_finite() {
+        return 1.0;
+    }
+
+    let raw = range / count.max(1) as f32;
+    let magnitude = 10.0_f32.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+
+    let nice = if normalized < 1.5 {
+        1.0
+    } else if normalized < 3.0 {
+        2.0
+    } else if normalized < 7.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+/// Returns the values of the ticks with the given `step` within the range.
+fn ticks(min: f32, max: f32, step: f32) -> impl Iterator<Item = f32> {
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+
+    (first..=last).map(move |i| i as f32 * step)
+}
+
+/// Formats a value with just enough decimals for ticks with the given `step`.
+fn format(value: f32, step: f32) -> String {
+    let decimals = if step >= 1.0 {
+        0
+    } else {
+        (-step.log10()).ceil() as usize
+    };
+
+    // Avoid displaying negative zeros
+    let value = if value.abs() < step / 2.0 { 0.0 } else { value };
+
+    i18n::locale().format_number(f64::from(value), decimals)
+}
+
+/// Draws a label.
+fn label(
+    frame: &mut Frame,
+    content: String,
+    position: Point,
+    color: Color,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) {
+    frame.fill_text(Text {
+        content,
+        position,
+        color,
+        size: TEXT_SIZE.into(),
+        horizontal_alignment,
+        vertical_alignment,
+        ..Text::default()
+    });
+}
+
+/// Draws a legend with the given entries starting at `origin` and flowing
+/// horizontally or vertically.
+fn legend<'a>(
+    frame: &mut Frame,
+    origin: Point,
+    vertical: bool,
+    entries: impl IntoIterator<Item = (&'a str, Color)>,
+    appearance: &Appearance,
+) {
+    let mut position = origin;
+
+    for (content, color) in entries {
+        let width = TEXT_SIZE + 4.0 + text_width(content);
+
+        frame.fill(
+            &Path::rectangle(
+                Point::new(position.x, position.y + 2.0),
+                Size::new(TEXT_SIZE - 4.0, TEXT_SIZE - 4.0),
+            ),
+            color,
+        );
+
+        label(
+            frame,
+            content.to_owned(),
+            Point::new(position.x + TEXT_SIZE, position.y),
+            appearance.text,
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+        );
+
+        if vertical {
+            position.y += TEXT_SIZE * 1.5;
+        } else {
+            position.x += width + TEXT_SIZE;
+        }
+    }
+}
+
+/// Draws a tooltip with the given lines next to the cursor, keeping it
+/// within the given bounds.
+fn tooltip(
+    frame: &mut Frame,
+    cursor: Point,
+    lines: &[String],
+    bounds: Size,
+    appearance: &Appearance,
+) {
+    const PADDING: f32 = 6.0;
+    const OFFSET: f32 = 12.0;
+
+    let line_height = TEXT_SIZE * 1.3;
+
+    let size = Size::new(
+        lines
+            .iter()
+            .map(|line| text_width(line))
+            .fold(0.0, f32::max)
+            + PADDING * 2.0,
+        lines.len() as f32 * line_height + PADDING * 2.0,
+    );
+
+    let x = if cursor.x + OFFSET + size.width > bounds.width {
+        cursor.x - OFFSET - size.width
+    } else {
+        cursor.x + OFFSET
+    };
+
+    let y = if cursor.y + OFFSET + size.height > bounds.height {
+        cursor.y - OFFSET - size.height
+    } else {
+        cursor.y + OFFSET
+    };
+
+    let top_left = Point::new(x.max(0.0), y.max(0.0));
+
+    frame.fill(
+        &Path::rectangle(top_left, size),
+        appearance.tooltip_background,
+    );
+
+    for (i, line) in lines.iter().enumerate() {
+        label(
+            frame,
+            line.clone(),
+            Point::new(
+                top_left.x + PADDING,
+                top_left.y + PADDING + i as f32 * line_height,
+            ),
+            appearance.tooltip_text,
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+        );
+    }
+}
+
+/// Estimates the width of some text drawn as a label.
+///
+/// A [`Frame`] cannot measure text, so an average glyph width is assumed.
+fn text_width(content: &str) -> f32 {
+    content.chars().count() as f32 * TEXT_SIZE * 0.6
+}
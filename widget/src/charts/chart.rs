@@ -0,0 +1,650 @@
+use super::{Kind, Series, StyleSheet};
+
+use crate::canvas::{Frame, Path, Stroke};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+/// The space reserved for the labels of the vertical axis.
+const LEFT: f32 = 48.0;
+
+/// The space reserved for the labels of the horizontal axis.
+const BOTTOM: f32 = 24.0;
+
+/// The space reserved for the legend.
+const LEGEND: f32 = 24.0;
+
+/// The minimum spacing between the edges of the widget and the plot.
+const MARGIN: f32 = 12.0;
+
+/// The minimum horizontal space between two ticks of the horizontal axis.
+const TICK_SPACING: f32 = 80.0;
+
+/// A chart plotting [`Series`] of data as lines, areas, or bars.
+///
+/// The user can zoom the horizontal axis with the mouse wheel and pan it
+/// by dragging the plot once zoomed in. Hovering the plot displays the
+/// values closest to the cursor in a tooltip.
+#[allow(missing_debug_implementations)]
+pub struct Chart<Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    series: Vec<Series>,
+    width: Length,
+    height: Length,
+    legend: bool,
+    interactive: bool,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme> Chart<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default height of a [`Chart`].
+    pub const DEFAULT_HEIGHT: f32 = 250.0;
+
+    /// Creates a new [`Chart`] with the given [`Series`].
+    pub fn new(series: impl IntoIterator<Item = Series>) -> Self {
+        Self {
+            series: series.into_iter().collect(),
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            legend: true,
+            interactive: true,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Series`] to the [`Chart`].
+    pub fn push(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets the width of the [`Chart`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Chart`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets whether the legend of the [`Chart`] is displayed.
+    ///
+    /// It is displayed by default.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets whether the user can zoom, pan, and hover the [`Chart`].
+    ///
+    /// It is interactive by default.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Sets the style of the [`Chart`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the full horizontal and vertical ranges of the data.
+    fn ranges(&self) -> ((f32, f32), (f32, f32)) {
+        let points = self.series.iter().flat_map(|series| &series.points);
+
+        let (x_min, x_max, y_min, y_max) = points.fold(
+            (f32::INFINITY, f32::NEG_INFINITY, 0.0_f32, f32::NEG_INFINITY),
+            |(x_min, x_max, y_min, y_max), (x, y)| {
+                (x_min.min(*x), x_max.max(*x), y_min.min(*y), y_max.max(*y))
+            },
+        );
+
+        if !x_min.is_finite() {
+            return ((0.0, 1.0), (0.0, 1.0));
+        }
+
+        // Make room for the bars at both ends
+        let (x_min, x_max) = if self.has_bars() {
+            let half = self.bar_spacing() / 2.0;
+
+            (x_min - half, x_max + half)
+        } else if x_min == x_max {
+            (x_min - 0.5, x_max + 0.5)
+        } else {
+            (x_min, x_max)
+        };
+
+        let y_max = y_max.max(0.0);
+
+        let (y_min, y_max) = if y_min == y_max {
+            (y_min, y_min + 1.0)
+        } else {
+            let step = super::tick_step(y_max - y_min, 5);
+
+            ((y_min / step).floor() * step, (y_max / step).ceil() * step)
+        };
+
+        ((x_min, x_max), (y_min, y_max))
+    }
+
+    fn has_bars(&self) -> bool {
+        self.series.iter().any(|series| series.kind == Kind::Bar)
+    }
+
+    /// Returns the minimum horizontal distance between two bars of the
+    /// same series.
+    fn bar_spacing(&self) -> f32 {
+        self.series
+            .iter()
+            .filter(|series| series.kind == Kind::Bar)
+            .flat_map(|series| {
+                series.points.windows(2).map(|pair| pair[1].0 - pair[0].0)
+            })
+            .filter(|distance| *distance > 0.0)
+            .fold(None, |min: Option<f32>, distance| {
+                Some(min.map_or(distance, |min| min.min(distance)))
+            })
+            .unwrap_or(1.0)
+    }
+
+    /// Returns the bounds of the plot, relative to the bounds of the
+    /// [`Chart`].
+    fn plot(&self, size: Size) -> Rectangle {
+        let top = if self.legend && !self.series.is_empty() {
+            LEGEND
+        } else {
+            MARGIN
+        };
+
+        Rectangle {
+            x: LEFT,
+            y: top,
+            width: (size.width - LEFT - MARGIN).max(0.0),
+            height: (size.height - top - BOTTOM).max(0.0),
+        }
+    }
+}
+
+/// The local state of a [`Chart`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    window: Option<(f32, f32)>,
+    drag: Option<(f32, (f32, f32))>,
+}
+
+impl State {
+    fn window(&self, full: (f32, f32)) -> (f32, f32) {
+        self.window.unwrap_or(full)
+    }
+}
+
+/// Keeps a window of the horizontal axis within the full range of the data.
+fn clamp((start, end): (f32, f32), full: (f32, f32)) -> Option<(f32, f32)> {
+    let span = end - start;
+    let full_span = full.1 - full.0;
+
+    if span >= full_span {
+        return None;
+    }
+
+    let start = start.max(full.0).min(full.1 - span);
+
+    Some((start, start + span))
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Chart<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.interactive {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let plot = self.plot(bounds.size()) + Vector::new(bounds.x, bounds.y);
+
+        let (full, _) = self.ranges();
+        let (start, end) = state.window(full);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(plot) else {
+                    return event::Status::Ignored;
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                if lines == 0.0 {
+                    return event::Status::Ignored;
+                }
+
+                let factor = 0.9_f32.powf(lines);
+                let span =
+                    ((end - start) * factor).max((full.1 - full.0) / 1000.0);
+
+                let anchor =
+                    start + (position.x - plot.x) / plot.width * (end - start);
+                let ratio = (anchor - start) / (end - start);
+                let start = anchor - ratio * span;
+
+                state.window = clamp((start, start + span), full);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                match cursor.position_over(plot) {
+                    Some(position) if state.window.is_some() => {
+                        state.drag = Some((position.x, (start, end)));
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some((origin, (start, end))) = state.drag {
+                    let delta =
+                        (origin - position.x) / plot.width * (end - start);
+
+                    state.window = clamp((start + delta, end + delta), full);
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.drag.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let plot = self.plot(bounds.size()) + Vector::new(bounds.x, bounds.y);
+
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if self.interactive
+            && state.window.is_some()
+            && cursor.is_over(plot)
+        {
+            mouse::Interaction::Grab
+        } else if self.interactive && cursor.is_over(plot) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let plot = self.plot(bounds.size());
+
+        if plot.width <= 0.0 || plot.height <= 0.0 {
+            return;
+        }
+
+        let (full, (y_min, y_max)) = self.ranges();
+        let (x_min, x_max) = state.window(full);
+
+        let to_x = |x: f32| (x - x_min) / (x_max - x_min) * plot.width;
+        let to_y =
+            |y: f32| plot.height - (y - y_min) / (y_max - y_min) * plot.height;
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        if self.legend && !self.series.is_empty() {
+            super::legend(
+                &mut frame,
+                Point::new(LEFT, (LEGEND - super::TEXT_SIZE) / 2.0),
+                false,
+                self.series.iter().enumerate().map(|(i, series)| {
+                    (
+                        series.label.as_str(),
+                        super::color(i, series.color, &appearance),
+                    )
+                }),
+                &appearance,
+            );
+        }
+
+        // Grid and vertical labels
+        let y_step = super::tick_step(y_max - y_min, 5);
+
+        for tick in super::ticks(y_min, y_max, y_step) {
+            let y = plot.y + to_y(tick);
+
+            frame.stroke(
+                &Path::line(
+                    Point::new(plot.x, y),
+                    Point::new(plot.x + plot.width, y),
+                ),
+                Stroke::default()
+                    .with_color(appearance.grid)
+                    .with_width(1.0),
+            );
+
+            super::label(
+                &mut frame,
+                super::format(tick, y_step),
+                Point::new(plot.x - 6.0, y),
+                appearance.text,
+                alignment::Horizontal::Right,
+                alignment::Vertical::Center,
+            );
+        }
+
+        // Grid and horizontal labels
+        let x_step = super::tick_step(
+            x_max - x_min,
+            (plot.width / TICK_SPACING).floor() as usize,
+        );
+
+        for tick in super::ticks(x_min, x_max, x_step) {
+            let x = plot.x + to_x(tick);
+
+            frame.stroke(
+                &Path::line(
+                    Point::new(x, plot.y),
+                    Point::new(x, plot.y + plot.height),
+                ),
+                Stroke::default()
+                    .with_color(appearance.grid)
+                    .with_width(1.0),
+            );
+
+            super::label(
+                &mut frame,
+                super::format(tick, x_step),
+                Point::new(x, plot.y + plot.height + 6.0),
+                appearance.text,
+                alignment::Horizontal::Center,
+                alignment::Vertical::Top,
+            );
+        }
+
+        // Series
+        let baseline = to_y(0.0_f32.clamp(y_min, y_max));
+
+        let bars: Vec<_> = self
+            .series
+            .iter()
+            .filter(|series| series.kind == Kind::Bar)
+            .collect();
+
+        let group_width = to_x(x_min + self.bar_spacing()) * 0.8;
+        let bar_width = group_width / bars.len().max(1) as f32;
+
+        frame.with_clip(plot, |frame| {
+            for (i, series) in self.series.iter().enumerate() {
+                let color = super::color(i, series.color, &appearance);
+
+                match series.kind {
+                    Kind::Line | Kind::Area => {
+                        let Some(first) = series.points.first() else {
+                            continue;
+                        };
+
+                        let line = Path::new(|builder| {
+                            builder.move_to(Point::new(
+                                to_x(first.0),
+                                to_y(first.1),
+                            ));
+
+                            for (x, y) in &series.points[1..] {
+                                builder.line_to(Point::new(to_x(*x), to_y(*y)));
+                            }
+                        });
+
+                        if series.kind == Kind::Area {
+                            let last = series.points[series.points.len() - 1];
+
+                            let area = Path::new(|builder| {
+                                builder.move_to(Point::new(
+                                    to_x(first.0),
+                                    baseline,
+                                ));
+
+                                for (x, y) in &series.points {
+                                    builder.line_to(Point::new(
+                                        to_x(*x),
+                                        to_y(*y),
+                                    ));
+                                }
+
+                                builder.line_to(Point::new(
+                                    to_x(last.0),
+                                    baseline,
+                                ));
+                                builder.close();
+                            });
+
+                            frame.fill(
+                                &area,
+                                Color {
+                                    a: color.a * 0.3,
+                                    ..color
+                                },
+                            );
+                        }
+
+                        frame.stroke(
+                            &line,
+                            Stroke::default().with_color(color).with_width(2.0),
+                        );
+                    }
+                    Kind::Bar => {
+                        let index = bars
+                            .iter()
+                            .position(|bar| std::ptr::eq(*bar, series))
+                            .unwrap_or_default();
+
+                        for (x, y) in &series.points {
+                            let left = to_x(*x) - group_width / 2.0
+                                + index as f32 * bar_width;
+                            let top = to_y(*y).min(baseline);
+                            let height = (to_y(*y) - baseline).abs();
+
+                            frame.fill_rectangle(
+                                Point::new(left, top),
+                                Size::new((bar_width - 1.0).max(1.0), height),
+                                color,
+                            );
+                        }
+                    }
+                }
+            }
+        });
+
+        // Axes
+        let axis = Stroke::default()
+            .with_color(appearance.axis)
+            .with_width(1.0);
+
+        frame.stroke(
+            &Path::line(
+                Point::new(plot.x, plot.y),
+                Point::new(plot.x, plot.y + plot.height),
+            ),
+            axis.clone(),
+        );
+
+        frame.stroke(
+            &Path::line(
+                Point::new(plot.x, plot.y + plot.height),
+                Point::new(plot.x + plot.width, plot.y + plot.height),
+            ),
+            axis,
+        );
+
+        // Tooltip
+        if let Some(position) = cursor
+            .position_in(bounds)
+            .filter(|position| self.interactive && plot.contains(*position))
+            .filter(|_| state.drag.is_none())
+        {
+            let hovered =
+                x_min + (position.x - plot.x) / plot.width * (x_max - x_min);
+
+            let nearest = self
+                .series
+                .iter()
+                .flat_map(|series| series.points.iter().map(|(x, _)| *x))
+                .filter(|x| (x_min..=x_max).contains(x))
+                .min_by(|a, b| {
+                    (a - hovered).abs().total_cmp(&(b - hovered).abs())
+                });
+
+            if let Some(nearest) = nearest {
+                let x = plot.x + to_x(nearest);
+
+                frame.stroke(
+                    &Path::line(
+                        Point::new(x, plot.y),
+                        Point::new(x, plot.y + plot.height),
+                    ),
+                    Stroke::default()
+                        .with_color(appearance.axis)
+                        .with_width(1.0),
+                );
+
+                let mut lines = vec![format!("{nearest}")];
+
+                for (i, series) in self.series.iter().enumerate() {
+                    for (_, y) in
+                        series.points.iter().filter(|(x, _)| *x == nearest)
+                    {
+                        if series.kind != Kind::Bar {
+                            frame.fill(
+                                &Path::circle(
+                                    Point::new(x, plot.y + to_y(*y)),
+                                    3.5,
+                                ),
+                                super::color(i, series.color, &appearance),
+                            );
+                        }
+
+                        lines.push(format!("{}: {y}", series.label));
+                    }
+                }
+
+                super::tooltip(
+                    &mut frame,
+                    position,
+                    &lines,
+                    bounds.size(),
+                    &appearance,
+                );
+            }
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Chart<Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Theme: StyleSheet + 'a,
+{
+    fn from(chart: Chart<Theme>) -> Self {
+        Self::new(chart)
+    }
+}
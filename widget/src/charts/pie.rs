@@ -0,0 +1,340 @@
+use super::{Slice, StyleSheet};
+
+use crate::canvas::{Frame, Path};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::widget::Tree;
+use crate::core::{
+    Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+/// The space reserved for the legend, when displayed.
+const LEGEND_WIDTH: f32 = 120.0;
+
+/// The distance a hovered [`Slice`] is pulled out of the [`Pie`].
+const HOVER_OFFSET: f32 = 6.0;
+
+/// A chart displaying proportions as [`Slice`]s of a circle.
+///
+/// Hovering a [`Slice`] highlights it and displays its value in a tooltip.
+#[allow(missing_debug_implementations)]
+pub struct Pie<Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    slices: Vec<Slice>,
+    width: Length,
+    height: Length,
+    inner_radius: f32,
+    legend: bool,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Theme> Pie<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default height of a [`Pie`].
+    pub const DEFAULT_HEIGHT: f32 = 200.0;
+
+    /// Creates a new [`Pie`] with the given [`Slice`]s.
+    pub fn new(slices: impl IntoIterator<Item = Slice>) -> Self {
+        Self {
+            slices: slices.into_iter().collect(),
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            inner_radius: 0.0,
+            legend: true,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Slice`] to the [`Pie`].
+    pub fn push(mut self, slice: Slice) -> Self {
+        self.slices.push(slice);
+        self
+    }
+
+    /// Sets the width of the [`Pie`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Pie`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Turns the [`Pie`] into a donut, with a hole of the given radius
+    /// relative to the outer radius, between `0.0` and `1.0`.
+    pub fn donut(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Sets whether the legend of the [`Pie`] is displayed.
+    ///
+    /// It is displayed by default.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets the style of the [`Pie`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the center and the radius of the [`Pie`].
+    fn circle(&self, size: Size) -> (Point, f32) {
+        let width = if self.legend && !self.slices.is_empty() {
+            (size.width - LEGEND_WIDTH).max(0.0)
+        } else {
+            size.width
+        };
+
+        let radius = (width.min(size.height) / 2.0 - HOVER_OFFSET).max(0.0);
+
+        (Point::new(width / 2.0, size.height / 2.0), radius)
+    }
+
+    /// Returns the start and end angles of every [`Slice`], clockwise from
+    /// the top of the circle.
+    fn angles(&self) -> Vec<(f32, f32)> {
+        let total: f32 = self.slices.iter().map(|slice| slice.value).sum();
+
+        if total <= 0.0 {
+            return Vec::new();
+        }
+
+        let mut start = -FRAC_PI_2;
+
+        self.slices
+            .iter()
+            .map(|slice| {
+                let end = start + slice.value / total * TAU;
+                let angles = (start, end);
+
+                start = end;
+
+                angles
+            })
+            .collect()
+    }
+
+    /// Returns the index of the [`Slice`] under the given position.
+    fn hovered(
+        &self,
+        position: Point,
+        center: Point,
+        radius: f32,
+        angles: &[(f32, f32)],
+    ) -> Option<usize> {
+        let distance = position.distance(center);
+
+        if distance > radius || distance < radius * self.inner_radius {
+            return None;
+        }
+
+        let angle = (position.y - center.y).atan2(position.x - center.x);
+
+        // Normalize the angle to the range of the slices
+        let angle = if angle < -FRAC_PI_2 {
+            angle + TAU
+        } else {
+            angle
+        };
+
+        angles
+            .iter()
+            .position(|(start, end)| (*start..*end).contains(&angle))
+    }
+}
+
+/// Builds the path of a slice of a ring between the given angles.
+fn wedge(center: Point, inner: f32, outer: f32, start: f32, end: f32) -> Path {
+    // Arcs are approximated by segments to keep the wedge in a single subpath
+    let steps = ((end - start) / TAU * 128.0).ceil().max(1.0) as usize;
+
+    let point = |radius: f32, angle: f32| {
+        Point::new(
+            center.x + radius * angle.cos(),
+            center.y + radius * angle.sin(),
+        )
+    };
+
+    Path::new(|builder| {
+        builder.move_to(point(outer, start));
+
+        for step in 1..=steps {
+            let angle = start + (end - start) * step as f32 / steps as f32;
+
+            builder.line_to(point(outer, angle));
+        }
+
+        if inner > 0.0 {
+            for step in (0..=steps).rev() {
+                let angle = start + (end - start) * step as f32 / steps as f32;
+
+                builder.line_to(point(inner, angle));
+            }
+        } else {
+            builder.line_to(center);
+        }
+
+        builder.close();
+    })
+}
+
+impl<Message, Theme> Widget<Message, Renderer<Theme>> for Pie<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let (center, radius) = self.circle(bounds.size());
+
+        if radius <= 0.0 {
+            return;
+        }
+
+        let angles = self.angles();
+        let position = cursor.position_in(bounds);
+
+        let hovered = position.and_then(|position| {
+            self.hovered(position, center, radius, &angles)
+        });
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        for (i, (slice, (start, end))) in
+            self.slices.iter().zip(&angles).enumerate()
+        {
+            if start == end {
+                continue;
+            }
+
+            let center = if hovered == Some(i) {
+                let middle = (start + end) / 2.0;
+
+                center
+                    + Vector::new(
+                        middle.cos() * HOVER_OFFSET,
+                        middle.sin() * HOVER_OFFSET,
+                    )
+            } else {
+                center
+            };
+
+            frame.fill(
+                &wedge(
+                    center,
+                    radius * self.inner_radius,
+                    radius,
+                    *start,
+                    *end,
+                ),
+                super::color(i, slice.color, &appearance),
+            );
+        }
+
+        if self.legend && !self.slices.is_empty() {
+            let entries = self.slices.len() as f32;
+
+            super::legend(
+                &mut frame,
+                Point::new(
+                    bounds.width - LEGEND_WIDTH + super::TEXT_SIZE,
+                    (bounds.height - entries * super::TEXT_SIZE * 1.5) / 2.0,
+                ),
+                true,
+                self.slices.iter().enumerate().map(|(i, slice)| {
+                    (
+                        slice.label.as_str(),
+                        super::color(i, slice.color, &appearance),
+                    )
+                }),
+                &appearance,
+            );
+        }
+
+        if let (Some(position), Some(hovered)) = (position, hovered) {
+            let slice = &self.slices[hovered];
+            let total: f32 = self.slices.iter().map(|slice| slice.value).sum();
+
+            super::tooltip(
+                &mut frame,
+                position,
+                &[format!(
+                    "{}: {} ({:.1}%)",
+                    slice.label,
+                    slice.value,
+                    slice.value / total * 100.0
+                )],
+                bounds.size(),
+                &appearance,
+            );
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Pie<Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Theme: StyleSheet + 'a,
+{
+    fn from(pie: Pie<Theme>) -> Self {
+        Self::new(pie)
+    }
+}
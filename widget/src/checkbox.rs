@@ -1,16 +1,22 @@
 //! Show toggle controls using checkboxes.
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::token::{self, Step};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Element, Layout, Length, Pixels, Rectangle, Shell, Size, Widget,
+    Clipboard, Element, Layout, Length, Pixels, Rectangle, Shadow, Shell, Size,
+    Widget,
 };
+use crate::focus_ring;
+use crate::style::focus;
 
 pub use iced_style::checkbox::{Appearance, StyleSheet};
 
@@ -49,6 +55,7 @@ where
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     icon: Icon<Renderer::Font>,
+    tab_index: i32,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -60,9 +67,6 @@ where
     /// The default size of a [`Checkbox`].
     const DEFAULT_SIZE: f32 = 20.0;
 
-    /// The default spacing of a [`Checkbox`].
-    const DEFAULT_SPACING: f32 = 10.0;
-
     /// Creates a new [`Checkbox`].
     ///
     /// It expects:
@@ -81,7 +85,7 @@ where
             label: label.into(),
             width: Length::Shrink,
             size: Self::DEFAULT_SIZE,
-            spacing: Self::DEFAULT_SPACING,
+            spacing: token::spacing(Step::Md),
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
@@ -93,6 +97,7 @@ where
                 line_height: text::LineHeight::default(),
                 shaping: text::Shaping::Basic,
             },
+            tab_index: 0,
             style: Default::default(),
         }
     }
@@ -150,6 +155,16 @@ where
         self
     }
 
+    /// Sets the position of the [`Checkbox`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     pub fn style(
         mut self,
@@ -164,14 +179,14 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Checkbox<'a, Message, Renderer>
 where
     Renderer: text::Renderer,
-    Renderer::Theme: StyleSheet + crate::text::StyleSheet,
+    Renderer::Theme: StyleSheet + crate::text::StyleSheet + focus::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn width(&self) -> Length {
@@ -193,12 +208,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -215,9 +229,33 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(
+            &mut widget::operation::focusable::with_tab_index(
+                state,
+                self.tab_index,
+            ),
+            None,
+        );
+
+        let node = accessibility::Node::new(accessibility::Role::CheckBox)
+            .label(self.label.clone())
+            .toggled(self.is_checked);
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -226,9 +264,14 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Pointer interactions hide the focus ring
+                state.is_focused = false;
+
                 let mouse_over = cursor.is_over(layout.bounds());
 
                 if mouse_over {
@@ -237,6 +280,14 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            }) if state.is_focused => {
+                shell.publish((self.on_toggle)(!self.is_checked));
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -268,6 +319,7 @@ where
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let is_mouse_over = cursor.is_over(layout.bounds());
 
         let mut children = layout.children();
@@ -288,10 +340,15 @@ where
                     border_radius: custom_style.border_radius,
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    shadow: Shadow::default(),
                 },
                 custom_style.background,
             );
 
+            if state.is_focused {
+                focus_ring::draw(renderer, bounds, theme);
+            }
+
             let Icon {
                 font,
                 code_point,
@@ -326,7 +383,7 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance {
                     color: custom_style.text_color,
                 },
@@ -340,7 +397,7 @@ impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
 where
     Message: 'a,
     Renderer: 'a + text::Renderer,
-    Renderer::Theme: StyleSheet + crate::text::StyleSheet,
+    Renderer::Theme: StyleSheet + crate::text::StyleSheet + focus::StyleSheet,
 {
     fn from(
         checkbox: Checkbox<'a, Message, Renderer>,
@@ -349,6 +406,26 @@ where
     }
 }
 
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+}
+
+impl<P: text::Paragraph> widget::operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The icon in a [`Checkbox`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Icon<Font> {
@@ -1,4 +1,5 @@
 //! Distribute content vertically.
+use crate::core::cascade;
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
@@ -13,7 +14,7 @@ use crate::core::{
 /// A container that distributes its contents vertically.
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message, Renderer = crate::Renderer> {
-    spacing: f32,
+    spacing: Option<f32>,
     padding: Padding,
     width: Length,
     height: Length,
@@ -33,7 +34,7 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         children: Vec<Element<'a, Message, Renderer>>,
     ) -> Self {
         Column {
-            spacing: 0.0,
+            spacing: None,
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -49,7 +50,7 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
     pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
-        self.spacing = amount.into().0;
+        self.spacing = Some(amount.into().0);
         self
     }
 
@@ -136,7 +137,7 @@ where
             renderer,
             &limits,
             self.padding,
-            self.spacing,
+            self.spacing.or_else(cascade::spacing).unwrap_or(0.0),
             self.align_items,
             &self.children,
             &mut tree.children,
@@ -7,7 +7,9 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::Instant;
+use crate::core::token::{self, Step};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{Clipboard, Element, Length, Padding, Rectangle, Shell};
 use crate::overlay::menu;
 use crate::text::LineHeight;
@@ -70,7 +72,7 @@ where
             on_input: None,
             on_close: None,
             menu_style: Default::default(),
-            padding: text_input::DEFAULT_PADDING,
+            padding: Padding::new(token::spacing(Step::Sm)),
             size: None,
         }
     }
@@ -457,6 +459,17 @@ where
                             event_status = event::Status::Captured;
                         }
 
+                        (keyboard::KeyCode::Escape, _) => {
+                            tree.children[0]
+                                .state
+                                .downcast_mut::<text_input::State<
+                                    Renderer::Paragraph,
+                                >>()
+                                .unfocus();
+
+                            event_status = event::Status::Captured;
+                        }
+
                         (keyboard::KeyCode::Up, _)
                         | (keyboard::KeyCode::Tab, true) => {
                             if let Some(index) = &mut menu.hovered_option {
@@ -492,6 +505,11 @@ where
                                 }
                             }
 
+                            menu.menu.scroll_to_hovered();
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+
                             event_status = event::Status::Captured;
                         }
                         (keyboard::KeyCode::Down, _)
@@ -539,6 +557,11 @@ where
                                 }
                             }
 
+                            menu.menu.scroll_to_hovered();
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+
                             event_status = event::Status::Captured;
                         }
                         _ => {}
@@ -691,6 +714,7 @@ where
             )
             .width(bounds.width)
             .padding(self.padding)
+            .keyboard_navigation(false)
             .style(self.menu_style.clone());
 
             if let Some(font) = self.font {
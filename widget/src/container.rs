@@ -1,6 +1,7 @@
 //! Decorate content and apply alignment.
 use crate::core::alignment::{self, Alignment};
 use crate::core::event::{self, Event};
+use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
@@ -12,6 +13,7 @@ use crate::core::{
     Point, Rectangle, Shell, Size, Vector, Widget,
 };
 use crate::runtime::Command;
+use crate::style::theme::sheet::Class;
 
 pub use iced_style::container::{Appearance, StyleSheet};
 
@@ -33,6 +35,7 @@ where
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     style: <Renderer::Theme as StyleSheet>::Style,
+    background_image: Option<Box<dyn Fn(&mut Renderer, Rectangle) + 'a>>,
     content: Element<'a, Message, Renderer>,
 }
 
@@ -56,6 +59,7 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: Default::default(),
+            background_image: None,
             content: content.into(),
         }
     }
@@ -128,6 +132,49 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the class of the [`Container`], styling it with the properties of
+    /// the class in the style sheet of the theme.
+    ///
+    /// See the [`sheet`] module to learn about style sheets.
+    ///
+    /// [`sheet`]: crate::style::theme::sheet
+    pub fn class(self, class: impl Into<Class>) -> Self
+    where
+        <Renderer::Theme as StyleSheet>::Style: From<Class>,
+    {
+        self.style(class.into())
+    }
+
+    /// Sets the image drawn as the background of the [`Container`] using
+    /// nine-slice scaling.
+    ///
+    /// The `insets`, in pixels of the image, cut it into nine slices. The
+    /// corners keep their size, while the edges and the center stretch to
+    /// fill the [`Container`]; so artist-drawn panels can be skinned with
+    /// any size.
+    ///
+    /// The image is drawn on top of the background of the style.
+    pub fn nine_slice(
+        mut self,
+        handle: impl Into<image::Handle>,
+        insets: impl Into<Padding>,
+    ) -> Self
+    where
+        Renderer: image::Renderer<Handle = image::Handle>,
+    {
+        let handle = handle.into();
+        let insets = insets.into();
+
+        self.background_image =
+            Some(Box::new(move |renderer: &mut Renderer, bounds| {
+                renderer.draw_nine_slice(
+                    image::Image::new(handle.clone(), bounds),
+                    insets,
+                );
+            }));
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -251,22 +298,40 @@ where
         viewport: &Rectangle,
     ) {
         let style = theme.appearance(&self.style);
+        let bounds = layout.bounds();
 
-        draw_background(renderer, &style, layout.bounds());
+        let draw = |renderer: &mut Renderer| {
+            draw_background(renderer, &style, bounds);
 
-        self.content.as_widget().draw(
-            tree,
-            renderer,
-            theme,
-            &renderer::Style {
-                text_color: style
-                    .text_color
-                    .unwrap_or(renderer_style.text_color),
-            },
-            layout.children().next().unwrap(),
-            cursor,
-            viewport,
-        );
+            if let Some(draw_image) = &self.background_image {
+                draw_image(renderer, bounds);
+            }
+
+            self.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style
+                        .text_color
+                        .unwrap_or(renderer_style.text_color),
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        };
+
+        if style.backdrop_blur > 0.0 {
+            renderer.with_backdrop_blur(
+                bounds,
+                style.border_radius,
+                style.backdrop_blur,
+                draw,
+            );
+        } else {
+            draw(renderer);
+        }
     }
 
     fn overlay<'b>(
@@ -298,6 +363,9 @@ where
 }
 
 /// Computes the layout of a [`Container`].
+///
+/// The content is mirrored when the current [`layout::Direction`] is right
+/// to left, swapping its horizontal alignment and padding.
 pub fn layout(
     limits: &layout::Limits,
     width: Length,
@@ -327,7 +395,13 @@ pub fn layout(
         size,
     );
 
-    layout::Node::with_children(size.pad(padding), vec![content])
+    let size = size.pad(padding);
+
+    if layout::Direction::current().is_rtl() {
+        content.mirror(size.width);
+    }
+
+    layout::Node::with_children(size, vec![content])
 }
 
 /// Draws the background of a [`Container`] given its [`Appearance`] and its `bounds`.
@@ -338,13 +412,17 @@ pub fn draw_background<Renderer>(
 ) where
     Renderer: crate::core::Renderer,
 {
-    if appearance.background.is_some() || appearance.border_width > 0.0 {
+    if appearance.background.is_some()
+        || appearance.border_width > 0.0
+        || !appearance.shadow.is_invisible()
+    {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border_radius: appearance.border_radius,
                 border_width: appearance.border_width,
                 border_color: appearance.border_color,
+                shadow: appearance.shadow,
             },
             appearance
                 .background
@@ -0,0 +1,290 @@
+//! Replace the mouse cursor with an image while hovering some content.
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A widget that shows an image as the mouse cursor while its content is
+/// hovered.
+///
+/// The cursor of the system is hidden and the image is drawn on top of the
+/// user interface, with its hotspot at the position of the cursor.
+///
+/// # Example
+///
+/// ```no_run
+/// # type CustomCursor<'a, Message> =
+/// #     iced_widget::CustomCursor<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # use iced_widget::core::{image, Point};
+/// #
+/// # #[derive(Clone)]
+/// # enum Message {}
+/// #
+/// let brush = image::Handle::from_path("brush.png");
+///
+/// let area = CustomCursor::new("Paint here!", brush)
+///     .hotspot(Point::new(2.0, 30.0));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CustomCursor<'a, Message, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    image: image::Handle,
+    hotspot: Point,
+    size: Option<Size>,
+}
+
+impl<'a, Message, Renderer> CustomCursor<'a, Message, Renderer> {
+    /// Creates a new [`CustomCursor`] showing the given image over the
+    /// given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        image: impl Into<image::Handle>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            image: image.into(),
+            hotspot: Point::ORIGIN,
+            size: None,
+        }
+    }
+
+    /// Sets the hotspot of the [`CustomCursor`]; the point of the image,
+    /// relative to its top-left corner, that follows the cursor.
+    pub fn hotspot(mut self, hotspot: Point) -> Self {
+        self.hotspot = hotspot;
+        self
+    }
+
+    /// Sets the size of the image of the [`CustomCursor`].
+    ///
+    /// By default, the image is drawn at its own dimensions.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CustomCursor<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::Hidden;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        );
+
+        // The image is drawn in an overlay to stay on top of other widgets
+        let cursor = overlay::Element::new(
+            layout.position(),
+            Box::new(Overlay {
+                image: &self.image,
+                hotspot: self.hotspot,
+                size: self.size,
+                content_bounds: layout.bounds(),
+            }),
+        );
+
+        Some(
+            overlay::Group::with_children(
+                content.into_iter().chain(Some(cursor)).collect(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<CustomCursor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + image::Renderer<Handle = image::Handle>,
+{
+    fn from(
+        custom_cursor: CustomCursor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(custom_cursor)
+    }
+}
+
+struct Overlay<'a> {
+    image: &'a image::Handle,
+    hotspot: Point,
+    size: Option<Size>,
+    content_bounds: Rectangle,
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer> for Overlay<'a>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn layout(
+        &mut self,
+        _renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let mut node = layout::Node::new(self.content_bounds.size());
+        node.move_to(position);
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return;
+        };
+
+        let size = self.size.unwrap_or_else(|| {
+            let dimensions = renderer.dimensions(self.image);
+
+            Size::new(dimensions.width as f32, dimensions.height as f32)
+        });
+
+        renderer.draw(image::Image::new(
+            self.image.clone(),
+            Rectangle::new(
+                position - Vector::new(self.hotspot.x, self.hotspot.y),
+                size,
+            ),
+        ));
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The image never gets in the way of the cursor
+        false
+    }
+}
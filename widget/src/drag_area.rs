@@ -0,0 +1,274 @@
+//! Move and resize frameless windows.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::window::Direction;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Widget,
+};
+use crate::runtime::window::drag_area::{Kind, Region};
+
+/// A container that moves or resizes the window when pressed.
+///
+/// Presses captured by its content (e.g. by a button in a title bar) are
+/// left alone; only the empty parts of a [`DragArea`] grab the window. It
+/// needs a shell that supports drag areas, like `iced_winit`.
+#[allow(missing_debug_implementations)]
+pub struct DragArea<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    is_movable: bool,
+    resize_border: f32,
+}
+
+impl<'a, Message, Renderer> DragArea<'a, Message, Renderer> {
+    /// Creates a [`DragArea`] with the given content.
+    ///
+    /// By default, pressing it moves the window.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        DragArea {
+            content: content.into(),
+            is_movable: true,
+            resize_border: 0.0,
+        }
+    }
+
+    /// Sets whether pressing the [`DragArea`] moves the window.
+    pub fn movable(mut self, is_movable: bool) -> Self {
+        self.is_movable = is_movable;
+        self
+    }
+
+    /// Sets the width of the border of the [`DragArea`] that resizes the
+    /// window, like the borders of a native window.
+    ///
+    /// The border is on top of the content of the [`DragArea`].
+    pub fn resize_border(mut self, width: f32) -> Self {
+        self.resize_border = width.max(0.0);
+        self
+    }
+
+    /// Returns the resize regions of the [`DragArea`] with the given bounds.
+    ///
+    /// Corners come first, so they take precedence over edges.
+    fn edges(&self, bounds: Rectangle) -> Vec<(Direction, Rectangle)> {
+        let border = self.resize_border;
+
+        if border <= 0.0 {
+            return Vec::new();
+        }
+
+        let region = |x: f32, y: f32, width: f32, height: f32| Rectangle {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        let right = bounds.x + bounds.width - border;
+        let bottom = bounds.y + bounds.height - border;
+
+        vec![
+            (
+                Direction::North,
+                region(bounds.x, bounds.y, bounds.width, border),
+            ),
+            (
+                Direction::South,
+                region(bounds.x, bottom, bounds.width, border),
+            ),
+            (
+                Direction::West,
+                region(bounds.x, bounds.y, border, bounds.height),
+            ),
+            (
+                Direction::East,
+                region(right, bounds.y, border, bounds.height),
+            ),
+            (
+                Direction::NorthWest,
+                region(bounds.x, bounds.y, border, border),
+            ),
+            (
+                Direction::NorthEast,
+                region(right, bounds.y, border, border),
+            ),
+            (
+                Direction::SouthWest,
+                region(bounds.x, bottom, border, border),
+            ),
+            (Direction::SouthEast, region(right, bottom, border, border)),
+        ]
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DragArea<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        // Regions reported later are on top of the previous ones
+        if self.is_movable {
+            operation.custom(
+                &mut Region {
+                    kind: Kind::Move,
+                    bounds,
+                },
+                None,
+            );
+        }
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+
+        for (direction, bounds) in self.edges(bounds).into_iter().rev() {
+            operation.custom(
+                &mut Region {
+                    kind: Kind::Resize(direction),
+                    bounds,
+                },
+                None,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let edge = cursor.position().and_then(|position| {
+            self.edges(layout.bounds())
+                .into_iter()
+                .rev()
+                .find(|(_, bounds)| bounds.contains(position))
+        });
+
+        if let Some((direction, _)) = edge {
+            return direction.mouse_interaction();
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<DragArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        drag_area: DragArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drag_area)
+    }
+}
@@ -0,0 +1,272 @@
+//! Receive the files dropped over some content.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Widget,
+};
+
+use std::path::PathBuf;
+
+/// A widget that receives the files dropped over its content.
+///
+/// Unlike listening to [`window::Event::FileDropped`], drops are routed to
+/// the [`DropZone`] under the dragged files, so different parts of an
+/// application can receive different files.
+///
+/// The files are followed while they are dragged on Windows. Other platforms
+/// do not report their position, so the last known position of the cursor
+/// is used instead.
+///
+/// # Example
+///
+/// ```no_run
+/// # type DropZone<'a, Message> =
+/// #     iced_widget::DropZone<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # use std::path::PathBuf;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     AttachmentDropped(PathBuf),
+///     AttachmentHovered(bool),
+/// }
+///
+/// let attachments = DropZone::new("Drop your attachments here")
+///     .on_drop(Message::AttachmentDropped)
+///     .on_hover(Message::AttachmentHovered);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DropZone<'a, Message, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_drop: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+    on_hover: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+}
+
+impl<'a, Message, Renderer> DropZone<'a, Message, Renderer> {
+    /// Creates a new [`DropZone`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_hover: None,
+        }
+    }
+
+    /// Sets the message that should be produced when a file is dropped over
+    /// the [`DropZone`].
+    ///
+    /// When multiple files are dropped at once, a message is produced for
+    /// each of them.
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(PathBuf) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message that should be produced when dragged files start or
+    /// stop hovering the [`DropZone`].
+    pub fn on_hover(mut self, on_hover: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+}
+
+/// The local state of a [`DropZone`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_hovered: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DropZone<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let is_over = cursor.is_over(layout.bounds());
+
+        let is_hovered = match event {
+            Event::Window(
+                window::Event::FileHovered(_)
+                | window::Event::FilesHoveredMoved { .. },
+            ) => is_over,
+            Event::Window(window::Event::FileDropped(path)) => {
+                if state.is_hovered || is_over {
+                    // Dropping the files ends their hover
+                    if state.is_hovered {
+                        state.is_hovered = false;
+
+                        if let Some(on_hover) = &self.on_hover {
+                            shell.publish(on_hover(false));
+                        }
+                    }
+
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(path));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                return event::Status::Ignored;
+            }
+            Event::Window(window::Event::FilesHoveredLeft) => false,
+            _ => return event::Status::Ignored,
+        };
+
+        if is_hovered != state.is_hovered {
+            state.is_hovered = is_hovered;
+
+            if let Some(on_hover) = &self.on_hover {
+                shell.publish(on_hover(is_hovered));
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<DropZone<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        drop_zone: DropZone<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_zone)
+    }
+}
@@ -0,0 +1,33 @@
+//! Draw the ring around focused widgets.
+use crate::core::renderer;
+use crate::core::{Color, Rectangle, Shadow};
+use crate::style::focus::StyleSheet;
+
+/// Draws the focus ring of the theme around the given bounds.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    theme: &Renderer::Theme,
+) where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let appearance = theme.appearance(&Default::default());
+    let spread = appearance.offset + appearance.width;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: bounds.x - spread,
+                y: bounds.y - spread,
+                width: bounds.width + spread * 2.0,
+                height: bounds.height + spread * 2.0,
+            },
+            border_radius: appearance.border_radius,
+            border_width: appearance.width,
+            border_color: appearance.color,
+            shadow: Shadow::default(),
+        },
+        Color::TRANSPARENT,
+    );
+}
@@ -0,0 +1,789 @@
+//! Collect and validate user input in a group of fields.
+use crate::button;
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::token::{self, Step};
+use crate::core::touch;
+use crate::core::widget::operation::{Focusable, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Id;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::form::{Appearance, StyleSheet};
+
+/// A group of validated fields with a submit button.
+///
+/// The submit button of a [`Form`] stays disabled until every [`Field`] is
+/// valid. The validation error of a [`Field`] is displayed under it once the
+/// user has typed into it, or has tried to submit the [`Form`]. Trying to
+/// submit an invalid [`Form`] focuses its first invalid [`Field`].
+///
+/// Pressing Enter while a [`Field`] is focused submits the [`Form`].
+///
+/// # Example
+/// ```no_run
+/// # type Form<'a, Message> =
+/// #     iced_widget::Form<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type TextInput<'a, Message> =
+/// #     iced_widget::TextInput<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::form::Field;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     EmailChanged(String),
+///     Submit,
+/// }
+///
+/// let email = "iced@example";
+///
+/// let form = Form::new("Sign up", Message::Submit).push(
+///     Field::new(
+///         TextInput::new("you@example.com", email)
+///             .on_input(Message::EmailChanged),
+///     )
+///     .label("Email")
+///     .validate(if email.contains('@') {
+///         Ok(())
+///     } else {
+///         Err("Email addresses must contain an @")
+///     }),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Form<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    fields: Vec<Field<'a, Message, Renderer>>,
+    submit: Element<'a, Message, Renderer>,
+    on_submit: Message,
+    width: Length,
+    spacing: f32,
+    submit_padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    submit_style: <Renderer::Theme as button::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Form<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    /// The default spacing between the fields of a [`Form`] with the
+    /// comfortable [`Tokens`].
+    ///
+    /// [`Tokens`]: crate::core::token::Tokens
+    pub const DEFAULT_SPACING: f32 = 10.0;
+
+    /// Creates a new empty [`Form`] with the given content of its submit
+    /// button and the message it produces when submitted.
+    pub fn new(
+        submit: impl Into<Element<'a, Message, Renderer>>,
+        on_submit: Message,
+    ) -> Self {
+        Self {
+            fields: Vec::new(),
+            submit: submit.into(),
+            on_submit,
+            width: Length::Shrink,
+            spacing: token::spacing(Step::Md),
+            submit_padding: Padding::new(token::spacing(Step::Sm)),
+            text_size: None,
+            font: None,
+            style: Default::default(),
+            submit_style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Field`] to the [`Form`].
+    pub fn push(mut self, field: Field<'a, Message, Renderer>) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Sets the width of the [`Form`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the fields of the [`Form`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the submit button of the [`Form`].
+    pub fn submit_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.submit_padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the labels and errors of the [`Form`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels and errors of the [`Form`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Form`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the submit button of the [`Form`].
+    pub fn submit_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as button::StyleSheet>::Style>,
+    ) -> Self {
+        self.submit_style = style.into();
+        self
+    }
+
+    /// Returns whether every [`Field`] of the [`Form`] is valid.
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|field| field.error.is_none())
+    }
+
+    fn has_focus(
+        &self,
+        children: &mut [Tree],
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> bool {
+        let mut operation = HasFocus(false);
+
+        for ((field, tree), layout) in
+            self.fields.iter().zip(children).zip(layout.children())
+        {
+            field.content.as_widget().operate(
+                tree,
+                content_layout(layout),
+                renderer,
+                &mut operation,
+            );
+        }
+
+        operation.0
+    }
+
+    fn submit(
+        &self,
+        state: &mut State,
+        children: &mut [Tree],
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(invalid) =
+            self.fields.iter().position(|field| field.error.is_some())
+        else {
+            // The fields are pristine again after a successful submission
+            state.is_submitted = false;
+            state
+                .touched
+                .iter_mut()
+                .for_each(|touched| *touched = false);
+
+            shell.publish(self.on_submit.clone());
+            return;
+        };
+
+        state.is_submitted = true;
+        shell.invalidate_layout();
+
+        for (index, ((field, tree), layout)) in self
+            .fields
+            .iter()
+            .zip(children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            field.content.as_widget().operate(
+                tree,
+                content_layout(layout),
+                renderer,
+                &mut FocusFirst(index == invalid),
+            );
+        }
+    }
+
+    fn fill_text(
+        &self,
+        renderer: &mut Renderer,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+    ) {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.fill_text(
+            Text {
+                content,
+                bounds: bounds.size(),
+                size,
+                line_height: text::LineHeight::default(),
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            },
+            bounds.position(),
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Form<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            touched: vec![false; self.fields.len()],
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.fields
+            .iter()
+            .map(|field| Tree::new(&field.content))
+            .chain(std::iter::once(Tree::new(&self.submit)))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.touched.resize(self.fields.len(), false);
+
+        let children: Vec<_> = self
+            .fields
+            .iter()
+            .map(|field| &field.content)
+            .chain(std::iter::once(&self.submit))
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_ref::<State>();
+        let limits = limits.width(self.width);
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = text::LineHeight::default().to_absolute(text_size).0;
+
+        let contents: Vec<_> = self
+            .fields
+            .iter()
+            .zip(children.iter_mut())
+            .map(|(field, tree)| {
+                field.content.as_widget().layout(
+                    tree,
+                    renderer,
+                    &limits.loose(),
+                )
+            })
+            .collect();
+
+        let mut submit = button::layout(
+            &limits.loose(),
+            Length::Shrink,
+            Length::Shrink,
+            self.submit_padding,
+            |limits| {
+                self.submit.as_widget().layout(
+                    &mut children[self.fields.len()],
+                    renderer,
+                    limits,
+                )
+            },
+        );
+
+        let width = limits
+            .resolve(Size::new(
+                contents
+                    .iter()
+                    .map(|content| content.size().width)
+                    .fold(submit.size().width, f32::max),
+                0.0,
+            ))
+            .width;
+
+        let mut y = 0.0;
+        let mut nodes = Vec::with_capacity(self.fields.len() + 1);
+
+        for (index, (field, mut content)) in
+            self.fields.iter().zip(contents).enumerate()
+        {
+            let mut height = 0.0;
+
+            let label = if field.label.is_some() {
+                height += line_height + LABEL_SPACING;
+
+                layout::Node::new(Size::new(width, line_height))
+            } else {
+                layout::Node::new(Size::ZERO)
+            };
+
+            content.move_to(Point::new(0.0, height));
+            height += content.size().height;
+
+            let error = if state.shows_error(index, field) {
+                let mut error =
+                    layout::Node::new(Size::new(width, line_height));
+
+                error.move_to(Point::new(0.0, height + LABEL_SPACING));
+                height += LABEL_SPACING + line_height;
+
+                error
+            } else {
+                layout::Node::new(Size::ZERO)
+            };
+
+            let mut node = layout::Node::with_children(
+                Size::new(width, height),
+                vec![label, content, error],
+            );
+
+            node.move_to(Point::new(0.0, y));
+            nodes.push(node);
+
+            y += height + self.spacing;
+        }
+
+        submit.move_to(Point::new(0.0, y));
+        y += submit.size().height;
+        nodes.push(submit);
+
+        layout::Node::with_children(Size::new(width, y), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((field, tree), layout) in self
+                .fields
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                field.content.as_widget().operate(
+                    tree,
+                    content_layout(layout),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let is_valid = self.is_valid();
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter,
+            ..
+        }) = &event
+        {
+            if self.has_focus(children, layout, renderer) {
+                self.submit(state, children, layout, renderer, shell);
+
+                return event::Status::Captured;
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+
+        for (index, ((field, tree), field_layout)) in self
+            .fields
+            .iter_mut()
+            .zip(children.iter_mut())
+            .zip(layout.children())
+            .enumerate()
+        {
+            let field_status = field.content.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                content_layout(field_layout),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            // A field is touched once the user types into it
+            if let (event::Status::Captured, Event::Keyboard(_)) =
+                (field_status, &event)
+            {
+                if let Some(touched) = state.touched.get_mut(index) {
+                    if !*touched {
+                        *touched = true;
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+
+            status = status.merge(field_status);
+        }
+
+        let submit_layout = layout.children().last().unwrap();
+
+        if !is_valid {
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            ))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                if cursor.is_over(submit_layout.bounds()) {
+                    self.submit(state, children, layout, renderer, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+
+            return status;
+        }
+
+        let mut pressed = Vec::new();
+
+        let submit_status = button::update(
+            event,
+            submit_layout,
+            cursor,
+            &mut Shell::new(&mut pressed),
+            &Some(()),
+            || &mut state.submit,
+        );
+
+        if !pressed.is_empty() {
+            self.submit(state, children, layout, renderer, shell);
+        }
+
+        status.merge(submit_status)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance =
+            <Renderer::Theme as StyleSheet>::appearance(theme, &self.style);
+
+        let mut children_layout = layout.children();
+
+        for (index, ((field, tree), field_layout)) in self
+            .fields
+            .iter()
+            .zip(&tree.children)
+            .zip(&mut children_layout)
+            .enumerate()
+        {
+            let mut parts = field_layout.children();
+            let label_layout = parts.next().unwrap();
+            let content_layout = parts.next().unwrap();
+            let error_layout = parts.next().unwrap();
+
+            if let Some(label) = &field.label {
+                self.fill_text(
+                    renderer,
+                    label,
+                    label_layout.bounds(),
+                    appearance.label_color,
+                );
+            }
+
+            field.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+
+            if let Some(error) = field
+                .error
+                .as_deref()
+                .filter(|_| state.shows_error(index, field))
+            {
+                self.fill_text(
+                    renderer,
+                    error,
+                    error_layout.bounds(),
+                    appearance.error_color,
+                );
+            }
+        }
+
+        let submit_layout = children_layout.next().unwrap();
+        let bounds = submit_layout.bounds();
+
+        let styling = button::draw(
+            renderer,
+            bounds,
+            cursor,
+            self.is_valid(),
+            theme,
+            &self.submit_style,
+            || &state.submit,
+        );
+
+        self.submit.as_widget().draw(
+            &tree.children[self.fields.len()],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: styling.text_color,
+            },
+            submit_layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let submit = button::mouse_interaction(
+            layout.children().last().unwrap(),
+            cursor,
+            self.is_valid(),
+        );
+
+        self.fields
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((field, tree), layout)| {
+                field.content.as_widget().mouse_interaction(
+                    tree,
+                    content_layout(layout),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .fold(submit, mouse::Interaction::max)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .fields
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((field, tree), layout)| {
+                field.content.as_widget_mut().overlay(
+                    tree,
+                    content_layout(layout),
+                    renderer,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Form<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + button::StyleSheet,
+{
+    fn from(form: Form<'a, Message, Renderer>) -> Self {
+        Element::new(form)
+    }
+}
+
+/// A labeled field of a [`Form`], with its validation result.
+#[allow(missing_debug_implementations)]
+pub struct Field<'a, Message, Renderer = crate::Renderer> {
+    label: Option<String>,
+    content: Element<'a, Message, Renderer>,
+    error: Option<String>,
+}
+
+impl<'a, Message, Renderer> Field<'a, Message, Renderer> {
+    /// Creates a new valid [`Field`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            label: None,
+            content: content.into(),
+            error: None,
+        }
+    }
+
+    /// Sets the label displayed above the [`Field`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the validation result of the [`Field`].
+    ///
+    /// A [`Form`] cannot be submitted while any of its fields has an error.
+    pub fn validate(mut self, result: Result<(), impl ToString>) -> Self {
+        self.error = result.err().map(|error| error.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    submit: button::State,
+    touched: Vec<bool>,
+    is_submitted: bool,
+}
+
+impl State {
+    fn shows_error<Message, Renderer>(
+        &self,
+        index: usize,
+        field: &Field<'_, Message, Renderer>,
+    ) -> bool {
+        field.error.is_some()
+            && (self.is_submitted
+                || self.touched.get(index).copied().unwrap_or(false))
+    }
+}
+
+struct HasFocus(bool);
+
+impl<T> Operation<T> for HasFocus {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        self.0 = self.0 || state.is_focused();
+    }
+}
+
+/// Focuses the first focusable widget it finds, if enabled, and unfocuses
+/// any other.
+struct FocusFirst(bool);
+
+impl<T> Operation<T> for FocusFirst {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.0 {
+            state.focus();
+            self.0 = false;
+        } else {
+            state.unfocus();
+        }
+    }
+}
+
+fn content_layout(field: Layout<'_>) -> Layout<'_> {
+    field.children().nth(1).unwrap()
+}
+
+const LABEL_SPACING: f32 = 4.0;
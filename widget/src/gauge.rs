@@ -0,0 +1,431 @@
+//! Display a value on a dial with a needle.
+use crate::canvas::{self, Frame, Path, Stroke, Text};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Radians,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+pub use iced_style::gauge::{Appearance, StyleSheet};
+
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+
+/// The default duration of the animation of the needle of a [`Gauge`].
+pub const DEFAULT_ANIMATION: Duration = Duration::from_millis(500);
+
+/// A dial displaying a value within a range with a needle.
+///
+/// The track of a [`Gauge`] can be split in colored [`Zone`]s and marked at
+/// some thresholds. The needle moves smoothly whenever the value changes.
+#[allow(missing_debug_implementations)]
+pub struct Gauge<'a, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    start: Radians,
+    sweep: Radians,
+    width: Length,
+    height: Length,
+    thickness: f32,
+    zones: Vec<Zone>,
+    thresholds: Vec<f32>,
+    label: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    animation: Duration,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// A colored section of the track of a [`Gauge`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zone {
+    /// The range of values covered by the [`Zone`].
+    pub range: RangeInclusive<f32>,
+    /// The [`Color`] of the [`Zone`].
+    pub color: Color,
+}
+
+impl<'a, Theme> Gauge<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default size of a [`Gauge`].
+    pub const DEFAULT_SIZE: f32 = 160.0;
+
+    /// Creates a new [`Gauge`] with the given range and value.
+    ///
+    /// By default, the arc of the [`Gauge`] spans 270 degrees and opens
+    /// at the bottom.
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            start: Radians(0.75 * PI),
+            sweep: Radians(1.5 * PI),
+            width: Length::Fixed(Self::DEFAULT_SIZE),
+            height: Length::Fixed(Self::DEFAULT_SIZE),
+            thickness: 12.0,
+            zones: Vec::new(),
+            thresholds: Vec::new(),
+            label: None,
+            animation: DEFAULT_ANIMATION,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the arc of the [`Gauge`], given its start angle and how far it
+    /// sweeps clockwise.
+    ///
+    /// Angles are measured clockwise from the positive horizontal axis.
+    pub fn arc(
+        mut self,
+        start: impl Into<Radians>,
+        sweep: impl Into<Radians>,
+    ) -> Self {
+        self.start = start.into();
+        self.sweep = Radians(sweep.into().0.clamp(0.0, 2.0 * PI));
+        self
+    }
+
+    /// Sets the width of the [`Gauge`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Gauge`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the thickness of the track of the [`Gauge`].
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Adds a colored [`Zone`] to the track of the [`Gauge`].
+    ///
+    /// When a [`Gauge`] has zones, the track is not filled up to the value.
+    pub fn zone(
+        mut self,
+        range: RangeInclusive<f32>,
+        color: impl Into<Color>,
+    ) -> Self {
+        self.zones.push(Zone {
+            range,
+            color: color.into(),
+        });
+        self
+    }
+
+    /// Adds a threshold marker to the [`Gauge`] at the given value.
+    pub fn threshold(mut self, value: f32) -> Self {
+        self.thresholds.push(value);
+        self
+    }
+
+    /// Sets the function that produces the label of the [`Gauge`], displayed
+    /// below its center, from its current value.
+    pub fn label(mut self, label: impl Fn(f32) -> String + 'a) -> Self {
+        self.label = Some(Box::new(label));
+        self
+    }
+
+    /// Sets the duration of the animation of the needle of the [`Gauge`].
+    ///
+    /// A zero [`Duration`] disables the animation.
+    pub fn animation(mut self, animation: Duration) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Sets the style of the [`Gauge`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the angle of the given value on the arc of the [`Gauge`].
+    fn angle(&self, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        let ratio = if end > start {
+            ((value - start) / (end - start)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        self.start.0 + ratio * self.sweep.0
+    }
+}
+
+/// The local state of a [`Gauge`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    value: f32,
+    target: f32,
+    transition: Option<Transition>,
+}
+
+/// An animated transition of the needle of a [`Gauge`].
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    from: f32,
+    started_at: Instant,
+}
+
+impl State {
+    fn new(value: f32) -> Self {
+        State {
+            value,
+            target: value,
+            transition: None,
+        }
+    }
+
+    /// Starts a transition from the current value to the given one.
+    fn go_to(&mut self, target: f32, now: Instant) {
+        self.transition = Some(Transition {
+            from: self.value,
+            started_at: now,
+        });
+        self.target = target;
+    }
+
+    /// Advances the current transition, if any.
+    fn animate(&mut self, now: Instant, duration: Duration) {
+        let Some(transition) = self.transition else {
+            return;
+        };
+
+        let progress = if duration.is_zero() {
+            1.0
+        } else {
+            (now.duration_since(transition.started_at).as_secs_f32()
+                / duration.as_secs_f32())
+            .min(1.0)
+        };
+
+        // Ease out cubic
+        let eased = 1.0 - (1.0 - progress).powi(3);
+
+        self.value = transition.from + (self.target - transition.from) * eased;
+
+        if progress >= 1.0 {
+            self.value = self.target;
+            self.transition = None;
+        }
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>> for Gauge<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.value))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.target != self.value {
+            if self.animation.is_zero() {
+                *state = State::new(self.value);
+            } else {
+                state.go_to(self.value, Instant::now());
+            }
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.animate(now, self.animation);
+
+            if state.transition.is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let track = radius - self.thickness / 2.0;
+
+        if track <= 0.0 {
+            return;
+        }
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let center = frame.center();
+
+        let arc = |from: f32, to: f32, color: Color| {
+            let path = Path::new(|builder| {
+                builder.arc(canvas::path::Arc {
+                    center,
+                    radius: track,
+                    start_angle: from,
+                    end_angle: to,
+                });
+            });
+
+            (
+                path,
+                Stroke::default()
+                    .with_color(color)
+                    .with_width(self.thickness),
+            )
+        };
+
+        let end = self.start.0 + self.sweep.0;
+
+        let (path, stroke) = arc(self.start.0, end, appearance.track);
+        frame.stroke(&path, stroke);
+
+        if self.zones.is_empty() {
+            let (path, stroke) =
+                arc(self.start.0, self.angle(state.value), appearance.bar);
+
+            frame.stroke(&path, stroke);
+        } else {
+            for zone in &self.zones {
+                let (path, stroke) = arc(
+                    self.angle(*zone.range.start()),
+                    self.angle(*zone.range.end()),
+                    zone.color,
+                );
+
+                frame.stroke(&path, stroke);
+            }
+        }
+
+        let point = |angle: f32, distance: f32| {
+            center + Vector::new(angle.cos(), angle.sin()) * distance
+        };
+
+        for threshold in &self.thresholds {
+            let angle = self.angle(*threshold);
+
+            frame.stroke(
+                &Path::line(
+                    point(angle, radius - self.thickness - 4.0),
+                    point(angle, radius),
+                ),
+                Stroke::default()
+                    .with_color(appearance.marker)
+                    .with_width(2.0),
+            );
+        }
+
+        if let Some(label) = &self.label {
+            frame.fill_text(Text {
+                content: label(state.value),
+                position: Point::new(center.x, center.y + radius * 0.45),
+                color: appearance.text,
+                size: (radius * 0.25).max(10.0).into(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+
+        let angle = self.angle(state.value);
+        let base = (radius * 0.04).max(2.0);
+
+        let needle = Path::new(|builder| {
+            builder.move_to(point(angle, radius - self.thickness - 6.0));
+            builder.line_to(point(angle + PI / 2.0, base));
+            builder.line_to(point(angle + PI, base * 2.0));
+            builder.line_to(point(angle - PI / 2.0, base));
+            builder.close();
+        });
+
+        frame.fill(&needle, appearance.needle);
+        frame.fill(&Path::circle(center, base * 2.0), appearance.needle);
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Gauge<'a, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Theme: StyleSheet + 'a,
+{
+    fn from(gauge: Gauge<'a, Theme>) -> Self {
+        Self::new(gauge)
+    }
+}
@@ -0,0 +1,698 @@
+//! Edit the color stops of a [`Gradient`].
+//!
+//! A [`GradientEditor`] has some local [`State`].
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::gradient::{self, ColorStop, Gradient};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Radians,
+    Rectangle, Shadow, Shell, Size, Widget,
+};
+
+use std::f32::consts::FRAC_PI_2;
+
+pub use iced_style::gradient_editor::{Appearance, StyleSheet};
+
+/// The size of the handle of a color stop.
+const HANDLE_SIZE: f32 = 12.0;
+
+/// The height of a channel slider.
+const CHANNEL_HEIGHT: f32 = 18.0;
+
+/// The width of the label of a channel slider.
+const LABEL_WIDTH: f32 = 16.0;
+
+/// The width of the value of a channel slider.
+const VALUE_WIDTH: f32 = 32.0;
+
+/// The vertical space between the parts of a [`GradientEditor`].
+const SPACING: f32 = 4.0;
+
+/// The maximum amount of color stops of a [`Gradient`].
+const MAX_STOPS: usize = 8;
+
+/// An editor of the color stops of a [`Gradient`].
+///
+/// The user can:
+/// - drag the handles below the gradient bar to move the color stops,
+/// - double-click the gradient bar to add a new color stop,
+/// - right-click a handle to remove its color stop, and
+/// - select a handle to edit the channels of its color with the sliders
+///   below it.
+///
+/// # Example
+/// ```no_run
+/// # type GradientEditor<'a, Message> =
+/// #     iced_widget::GradientEditor<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_core::gradient::{self, Gradient};
+/// use iced_core::{Color, Degrees};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     GradientChanged(Gradient),
+/// }
+///
+/// let gradient = Gradient::from(
+///     gradient::Linear::new(Degrees(90.0))
+///         .add_stop(0.0, Color::BLACK)
+///         .add_stop(1.0, Color::WHITE),
+/// );
+///
+/// let editor = GradientEditor::new(gradient, Message::GradientChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GradientEditor<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    gradient: Gradient,
+    on_change: Box<dyn Fn(Gradient) -> Message + 'a>,
+    width: Length,
+    height: f32,
+    text_size: Option<Pixels>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> GradientEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default height of the gradient bar of a [`GradientEditor`].
+    pub const DEFAULT_HEIGHT: f32 = 24.0;
+
+    /// Creates a new [`GradientEditor`] with the given [`Gradient`] and a
+    /// function producing a message when the user edits it.
+    ///
+    /// The angle of the [`Gradient`] is kept in the edited value.
+    pub fn new(
+        gradient: impl Into<Gradient>,
+        on_change: impl Fn(Gradient) -> Message + 'a,
+    ) -> Self {
+        Self {
+            gradient: gradient.into(),
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            text_size: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`GradientEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the gradient bar of the [`GradientEditor`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the text size of the channel sliders of the [`GradientEditor`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the [`GradientEditor`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn stops(&self) -> Vec<ColorStop> {
+        self.gradient.stops().iter().flatten().copied().collect()
+    }
+
+    /// Produces the edited [`Gradient`] with the given color stops and
+    /// returns the new index of the stop at `index` after sorting them.
+    fn publish(
+        &self,
+        mut stops: Vec<ColorStop>,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) -> usize {
+        let mut order: Vec<usize> = (0..stops.len()).collect();
+
+        order.sort_by(|a, b| stops[*a].offset.total_cmp(&stops[*b].offset));
+
+        let index = order.iter().position(|i| *i == index).unwrap_or(index);
+
+        stops = order.into_iter().map(|i| stops[i]).collect();
+
+        let gradient = match &self.gradient {
+            Gradient::Linear(linear) => Gradient::Linear(
+                gradient::Linear::new(linear.angle).add_stops(stops),
+            ),
+            Gradient::Radial(radial) => Gradient::Radial(
+                gradient::Radial::new(radial.radius)
+                    .center(radial.center)
+                    .add_stops(stops),
+            ),
+            Gradient::Conic(conic) => Gradient::Conic(
+                gradient::Conic::new(conic.angle)
+                    .center(conic.center)
+                    .add_stops(stops),
+            ),
+        };
+
+        shell.publish((self.on_change)(gradient));
+
+        index
+    }
+
+    fn regions(&self, bounds: Rectangle) -> Regions {
+        let bar = Rectangle {
+            x: bounds.x + HANDLE_SIZE / 2.0,
+            y: bounds.y,
+            width: (bounds.width - HANDLE_SIZE).max(0.0),
+            height: self.height,
+        };
+
+        let handles_y = bar.y + bar.height + SPACING / 2.0;
+        let channels_y = handles_y + HANDLE_SIZE + SPACING * 2.0;
+
+        let channels = std::array::from_fn(|i| Rectangle {
+            x: bounds.x + LABEL_WIDTH,
+            y: channels_y + (CHANNEL_HEIGHT + SPACING) * i as f32,
+            width: (bounds.width - LABEL_WIDTH - VALUE_WIDTH).max(0.0),
+            height: CHANNEL_HEIGHT,
+        });
+
+        Regions {
+            bar,
+            handles_y,
+            channels,
+        }
+    }
+}
+
+/// The areas of the parts of a [`GradientEditor`].
+struct Regions {
+    bar: Rectangle,
+    handles_y: f32,
+    channels: [Rectangle; 4],
+}
+
+impl Regions {
+    fn handle(&self, offset: f32) -> Rectangle {
+        Rectangle {
+            x: self.bar.x + offset * self.bar.width - HANDLE_SIZE / 2.0,
+            y: self.handles_y,
+            width: HANDLE_SIZE,
+            height: HANDLE_SIZE,
+        }
+    }
+
+    fn offset(&self, x: f32) -> f32 {
+        if self.bar.width <= 0.0 {
+            return 0.0;
+        }
+
+        ((x - self.bar.x) / self.bar.width).max(0.0).min(1.0)
+    }
+
+    /// Returns the index of the topmost handle under the cursor.
+    fn hovered_handle(
+        &self,
+        stops: &[ColorStop],
+        position: Point,
+    ) -> Option<usize> {
+        stops
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, stop)| {
+                self.handle(stop.offset).expand(2.0).contains(position)
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+/// The local state of a [`GradientEditor`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    selected: Option<usize>,
+    drag: Option<Drag>,
+    last_click: Option<mouse::Click>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    Stop,
+    Channel(usize),
+}
+
+/// Returns the color of the given color stops at the given offset.
+fn sample(stops: &[ColorStop], offset: f32) -> Color {
+    let Some(first) = stops.first() else {
+        return Color::BLACK;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    for pair in stops.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+
+        if offset <= b.offset {
+            let t = if b.offset > a.offset {
+                (offset - a.offset) / (b.offset - a.offset)
+            } else {
+                0.0
+            };
+
+            return Color {
+                r: a.color.r + (b.color.r - a.color.r) * t,
+                g: a.color.g + (b.color.g - a.color.g) * t,
+                b: a.color.b + (b.color.b - a.color.b) * t,
+                a: a.color.a + (b.color.a - a.color.a) * t,
+            };
+        }
+    }
+
+    stops.last().map(|stop| stop.color).unwrap_or(first.color)
+}
+
+fn channel(color: Color, channel: usize) -> f32 {
+    match channel {
+        0 => color.r,
+        1 => color.g,
+        2 => color.b,
+        _ => color.a,
+    }
+}
+
+fn channel_mut(color: &mut Color, channel: usize) -> &mut f32 {
+    match channel {
+        0 => &mut color.r,
+        1 => &mut color.g,
+        2 => &mut color.b,
+        _ => &mut color.a,
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for GradientEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.height
+            + SPACING / 2.0
+            + HANDLE_SIZE
+            + SPACING * 2.0
+            + (CHANNEL_HEIGHT + SPACING) * 4.0
+            - SPACING;
+
+        let limits = limits.width(self.width).height(height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let regions = self.regions(layout.bounds());
+        let mut stops = self.stops();
+
+        if state
+            .selected
+            .is_some_and(|selected| selected >= stops.len())
+        {
+            state.selected = None;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                if let Some(index) = regions.hovered_handle(&stops, position) {
+                    state.selected = Some(index);
+                    state.drag = Some(Drag::Stop);
+
+                    return event::Status::Captured;
+                }
+
+                if regions.bar.expand(HANDLE_SIZE / 2.0).contains(position)
+                    && position.y <= regions.bar.y + regions.bar.height
+                {
+                    if click.kind() == mouse::click::Kind::Double
+                        && stops.len() < MAX_STOPS
+                    {
+                        let offset = regions.offset(position.x);
+
+                        stops.push(ColorStop {
+                            offset,
+                            color: sample(&stops, offset),
+                        });
+
+                        let index = stops.len() - 1;
+
+                        state.selected =
+                            Some(self.publish(stops, index, shell));
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(selected) = state.selected {
+                    if let Some(i) = regions
+                        .channels
+                        .iter()
+                        .position(|channel| channel.contains(position))
+                    {
+                        let track = regions.channels[i];
+
+                        *channel_mut(&mut stops[selected].color, i) =
+                            ((position.x - track.x) / track.width.max(1.0))
+                                .max(0.0)
+                                .min(1.0);
+
+                        state.drag = Some(Drag::Channel(i));
+                        state.selected =
+                            Some(self.publish(stops, selected, shell));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let Some(position) = cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(index) = regions.hovered_handle(&stops, position)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                // A gradient needs at least two stops
+                if stops.len() > 2 {
+                    let _ = stops.remove(index);
+
+                    state.selected = match state.selected {
+                        Some(selected) if selected == index => None,
+                        Some(selected) if selected > index => {
+                            Some(selected - 1)
+                        }
+                        selected => selected,
+                    };
+
+                    let _ = self.publish(stops, 0, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let (Some(drag), Some(selected)) = (state.drag, state.selected)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                match drag {
+                    Drag::Stop => {
+                        stops[selected].offset = regions.offset(position.x);
+                    }
+                    Drag::Channel(i) => {
+                        let track = regions.channels[i];
+
+                        *channel_mut(&mut stops[selected].color, i) =
+                            ((position.x - track.x) / track.width.max(1.0))
+                                .max(0.0)
+                                .min(1.0);
+                    }
+                }
+
+                state.selected = Some(self.publish(stops, selected, shell));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.drag.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.drag {
+            Some(Drag::Stop) => return mouse::Interaction::Grabbing,
+            Some(Drag::Channel(_)) => {
+                return mouse::Interaction::ResizingHorizontally
+            }
+            None => {}
+        }
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let regions = self.regions(layout.bounds());
+
+        if regions.hovered_handle(&self.stops(), position).is_some() {
+            mouse::Interaction::Grab
+        } else if regions.bar.contains(position) {
+            mouse::Interaction::Crosshair
+        } else if state.selected.is_some()
+            && regions
+                .channels
+                .iter()
+                .any(|channel| channel.contains(position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let regions = self.regions(layout.bounds());
+        let stops = self.stops();
+
+        let selected =
+            state.selected.filter(|selected| *selected < stops.len());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.bar,
+                border_radius: appearance.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        // The bar is always horizontal, regardless of the angle
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.bar,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+                shadow: Shadow::default(),
+            },
+            gradient::Linear::new(Radians(FRAC_PI_2)).add_stops(stops.clone()),
+        );
+
+        for (index, stop) in stops.iter().enumerate() {
+            let is_selected = selected == Some(index);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: regions.handle(stop.offset),
+                    border_radius: 3.0.into(),
+                    border_width: if is_selected { 2.0 } else { 1.0 },
+                    border_color: if is_selected {
+                        appearance.selected_handle_border
+                    } else {
+                        appearance.handle_border
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: 1.0,
+                    ..stop.color
+                },
+            );
+        }
+
+        let Some(selected) = selected else {
+            return;
+        };
+
+        let color = stops[selected].color;
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = renderer.default_font();
+
+        for (i, (track, label)) in regions
+            .channels
+            .iter()
+            .zip(["R", "G", "B", "A"])
+            .enumerate()
+        {
+            let mut from = color;
+            let mut to = color;
+            *channel_mut(&mut from, i) = 0.0;
+            *channel_mut(&mut to, i) = 1.0;
+
+            let rail = Rectangle {
+                y: track.center_y() - 3.0,
+                height: 6.0,
+                ..*track
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rail,
+                    border_radius: 3.0.into(),
+                    border_width: 1.0,
+                    border_color: appearance.track,
+                    shadow: Shadow::default(),
+                },
+                gradient::Linear::new(Radians(FRAC_PI_2))
+                    .add_stop(0.0, from)
+                    .add_stop(1.0, to),
+            );
+
+            let value = channel(color, i);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: track.x + value * track.width - 3.0,
+                        y: track.y + 2.0,
+                        width: 6.0,
+                        height: track.height - 4.0,
+                    },
+                    border_radius: 2.0.into(),
+                    border_width: 1.0,
+                    border_color: appearance.handle_border,
+                    shadow: Shadow::default(),
+                },
+                Color::WHITE,
+            );
+
+            let text = |content| Text {
+                content,
+                bounds: Size::new(f32::INFINITY, track.height),
+                size,
+                line_height: text::LineHeight::default(),
+                font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+            };
+
+            let value = format!("{}", (value * 255.0).round() as u8);
+
+            renderer.fill_text(
+                text(label),
+                Point::new(track.x - LABEL_WIDTH / 2.0, track.center_y()),
+                appearance.text,
+            );
+
+            renderer.fill_text(
+                text(&value),
+                Point::new(
+                    track.x + track.width + VALUE_WIDTH / 2.0,
+                    track.center_y(),
+                ),
+                appearance.text,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<GradientEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        gradient_editor: GradientEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(gradient_editor)
+    }
+}
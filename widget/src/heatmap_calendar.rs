@@ -0,0 +1,557 @@
+//! Display values per day as a grid of colored cells.
+use crate::canvas::{Frame, Path, Stroke, Text};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::i18n;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::touch;
+use crate::core::widget::Tree;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+pub use iced_style::heatmap_calendar::{Appearance, StyleSheet};
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The size of the labels of a [`HeatmapCalendar`].
+const TEXT_SIZE: f32 = 10.0;
+
+/// The space reserved for the day labels.
+const DAY_LABELS: f32 = 28.0;
+
+/// The space reserved for the month labels.
+const MONTH_LABELS: f32 = 16.0;
+
+/// A grid of days colored by intensity, in the style of a contributions
+/// calendar.
+///
+/// Every column is a week and every row a day of the week. Hovering a day
+/// displays its value in a tooltip.
+#[allow(missing_debug_implementations)]
+pub struct HeatmapCalendar<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    range: RangeInclusive<Date>,
+    values: BTreeMap<Date, f32>,
+    cell_size: f32,
+    spacing: f32,
+    starts_on_monday: bool,
+    tooltip: Option<Box<dyn Fn(Date, Option<f32>) -> String + 'a>>,
+    on_press: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> HeatmapCalendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default size of the cells of a [`HeatmapCalendar`].
+    pub const DEFAULT_CELL_SIZE: f32 = 11.0;
+
+    /// The default spacing between the cells of a [`HeatmapCalendar`].
+    pub const DEFAULT_SPACING: f32 = 2.0;
+
+    /// Creates a new [`HeatmapCalendar`] displaying the days in the given
+    /// range with the given values.
+    ///
+    /// Values of the same day are added up.
+    pub fn new(
+        range: RangeInclusive<Date>,
+        values: impl IntoIterator<Item = (Date, f32)>,
+    ) -> Self {
+        let mut map = BTreeMap::new();
+
+        for (date, value) in values {
+            *map.entry(date).or_insert(0.0) += value;
+        }
+
+        Self {
+            range,
+            values: map,
+            cell_size: Self::DEFAULT_CELL_SIZE,
+            spacing: Self::DEFAULT_SPACING,
+            starts_on_monday: false,
+            tooltip: None,
+            on_press: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the size of the cells of the [`HeatmapCalendar`].
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the spacing between the cells of the [`HeatmapCalendar`].
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets whether the weeks of the [`HeatmapCalendar`] start on Monday,
+    /// instead of Sunday.
+    pub fn starts_on_monday(mut self, starts_on_monday: bool) -> Self {
+        self.starts_on_monday = starts_on_monday;
+        self
+    }
+
+    /// Sets the function that produces the tooltip of a hovered day from its
+    /// [`Date`] and value, if any.
+    pub fn tooltip(
+        mut self,
+        tooltip: impl Fn(Date, Option<f32>) -> String + 'a,
+    ) -> Self {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the message that should be produced when a day of the
+    /// [`HeatmapCalendar`] is pressed.
+    pub fn on_press(mut self, on_press: impl Fn(Date) -> Message + 'a) -> Self {
+        self.on_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets the style of the [`HeatmapCalendar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the index of the row of the given [`Date`].
+    fn row(&self, date: Date) -> i64 {
+        let weekday = date.weekday();
+
+        if self.starts_on_monday {
+            (weekday + 6) % 7
+        } else {
+            weekday
+        }
+    }
+
+    /// Returns the first day of the first week of the calendar.
+    fn first_day(&self) -> i64 {
+        let start = *self.range.start();
+
+        start.days() - self.row(start)
+    }
+
+    fn weeks(&self) -> i64 {
+        let end = self.range.end().days();
+
+        if end < self.range.start().days() {
+            0
+        } else {
+            (end - self.first_day()) / 7 + 1
+        }
+    }
+
+    /// Returns the bounds of the cell at the given column and row, relative
+    /// to the bounds of the [`HeatmapCalendar`].
+    fn cell(&self, column: i64, row: i64) -> Rectangle {
+        let step = self.cell_size + self.spacing;
+
+        Rectangle {
+            x: DAY_LABELS + column as f32 * step,
+            y: MONTH_LABELS + row as f32 * step,
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    /// Returns the [`Date`] of the cell under the given position, relative
+    /// to the bounds of the [`HeatmapCalendar`].
+    fn date_at(&self, position: Point) -> Option<Date> {
+        let step = self.cell_size + self.spacing;
+
+        let x = position.x - DAY_LABELS;
+        let y = position.y - MONTH_LABELS;
+
+        if x < 0.0 || y < 0.0 || x % step > self.cell_size {
+            return None;
+        }
+
+        let (column, row) = ((x / step) as i64, (y / step) as i64);
+
+        if column >= self.weeks() || row >= 7 || y % step > self.cell_size {
+            return None;
+        }
+
+        let date = Date::from_days(self.first_day() + column * 7 + row);
+
+        self.range.contains(&date).then_some(date)
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>>
+    for HeatmapCalendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let step = self.cell_size + self.spacing;
+
+        layout::Node::new(Size::new(
+            DAY_LABELS + (self.weeks() as f32 * step - self.spacing).max(0.0),
+            MONTH_LABELS + 7.0 * step - self.spacing,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_press) = &self.on_press else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(date) = cursor
+                    .position_in(layout.bounds())
+                    .and_then(|position| self.date_at(position))
+                {
+                    shell.publish(on_press(date));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let is_over_day = cursor
+            .position_in(layout.bounds())
+            .and_then(|position| self.date_at(position))
+            .is_some();
+
+        if is_over_day && self.on_press.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        if self.weeks() == 0 {
+            return;
+        }
+
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let locale = i18n::locale();
+
+        let label = |frame: &mut Frame, content: &str, position: Point| {
+            frame.fill_text(Text {
+                content: content.to_owned(),
+                position,
+                color: appearance.text,
+                size: TEXT_SIZE.into(),
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        };
+
+        // Day labels, every other day
+        for row in 0..7 {
+            let weekday = if self.starts_on_monday {
+                (row + 1) % 7
+            } else {
+                row
+            };
+
+            if weekday % 2 == 1 {
+                label(
+                    &mut frame,
+                    locale.weekday_name(weekday as u32, true),
+                    Point::new(0.0, self.cell(0, row).center_y()),
+                );
+            }
+        }
+
+        // Month labels, on the first week of each month
+        let first_day = self.first_day();
+        let mut last_label = None;
+
+        for column in 0..self.weeks() {
+            let date = if column == 0 {
+                *self.range.start()
+            } else {
+                Date::from_days(first_day + column * 7 + 6)
+            };
+
+            let is_new_month = column == 0 || date.day <= 7;
+            let has_room = last_label.map_or(true, |last| column - last > 2);
+
+            if is_new_month && has_room {
+                label(
+                    &mut frame,
+                    locale.month_name(date.month, true),
+                    Point::new(self.cell(column, 0).x, MONTH_LABELS / 2.0),
+                );
+
+                last_label = Some(column);
+            }
+        }
+
+        // Cells
+        let max = self
+            .values
+            .range(self.range.clone())
+            .map(|(_, value)| *value)
+            .fold(0.0_f32, f32::max);
+
+        let levels = appearance.levels.len();
+
+        for days in self.range.start().days()..=self.range.end().days() {
+            let date = Date::from_days(days);
+            let offset = days - first_day;
+
+            let color = match self.values.get(&date) {
+                Some(value) if *value > 0.0 && max > 0.0 => {
+                    let level = (value / max * levels as f32).ceil() as usize;
+
+                    appearance.levels[level.clamp(1, levels) - 1]
+                }
+                _ => appearance.empty,
+            };
+
+            let cell = self.cell(offset / 7, offset % 7);
+
+            frame.fill_rectangle(cell.position(), cell.size(), color);
+        }
+
+        // Tooltip
+        if let Some((position, date)) = cursor
+            .position_in(bounds)
+            .and_then(|position| Some((position, self.date_at(position)?)))
+        {
+            let offset = date.days() - first_day;
+            let cell = self.cell(offset / 7, offset % 7);
+
+            frame.stroke(
+                &Path::rectangle(cell.position(), cell.size()),
+                Stroke::default()
+                    .with_color(appearance.hovered)
+                    .with_width(1.0),
+            );
+
+            let value = self.values.get(&date).copied();
+
+            let content = match &self.tooltip {
+                Some(tooltip) => tooltip(date, value),
+                None => {
+                    let day =
+                        locale.format_date(date.year, date.month, date.day);
+
+                    match value {
+                        Some(value) => format!("{day}: {value}"),
+                        None => format!("{day}: no data"),
+                    }
+                }
+            };
+
+            tooltip(&mut frame, position, content, bounds.size(), &appearance);
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+/// Draws a tooltip next to the cursor, keeping it within the given bounds.
+fn tooltip(
+    frame: &mut Frame,
+    cursor: Point,
+    content: String,
+    bounds: Size,
+    appearance: &Appearance,
+) {
+    const PADDING: f32 = 4.0;
+    const OFFSET: f32 = 10.0;
+
+    // A frame cannot measure text, so an average glyph width is assumed
+    let size = Size::new(
+        content.chars().count() as f32 * TEXT_SIZE * 0.6 + PADDING * 2.0,
+        TEXT_SIZE * 1.3 + PADDING * 2.0,
+    );
+
+    let x = if cursor.x + OFFSET + size.width > bounds.width {
+        cursor.x - OFFSET - size.width
+    } else {
+        cursor.x + OFFSET
+    };
+
+    let y = if cursor.y + OFFSET + size.height > bounds.height {
+        cursor.y - OFFSET - size.height
+    } else {
+        cursor.y + OFFSET
+    };
+
+    let top_left = Point::new(x.max(0.0), y.max(0.0));
+
+    frame.fill(
+        &Path::rectangle(top_left, size),
+        appearance.tooltip_background,
+    );
+
+    frame.fill_text(Text {
+        content,
+        position: Point::new(top_left.x + PADDING, top_left.y + PADDING),
+        color: appearance.tooltip_text,
+        size: TEXT_SIZE.into(),
+        ..Text::default()
+    });
+}
+
+impl<'a, Message, Theme> From<HeatmapCalendar<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+{
+    fn from(calendar: HeatmapCalendar<'a, Message, Theme>) -> Self {
+        Self::new(calendar)
+    }
+}
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year of the [`Date`].
+    pub year: i32,
+    /// The month of the [`Date`], from `1` to `12`.
+    pub month: u32,
+    /// The day of the month of the [`Date`], from `1` to `31`.
+    pub day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`].
+    ///
+    /// Out of range months and days overflow into the following ones; for
+    /// instance, February 30 becomes March 1 or 2.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.max(1);
+        let year = year + (month as i32 - 1) / 12;
+        let month = (month - 1) % 12 + 1;
+
+        let first = Self {
+            year,
+            month,
+            day: 1,
+        };
+
+        Self::from_days(first.days() + i64::from(day.max(1)) - 1)
+    }
+
+    /// Creates the [`Date`] that is the given number of days after
+    /// January 1, 1970.
+    pub fn from_days(days: i64) -> Self {
+        // Algorithm from http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// Returns the number of days between January 1, 1970 and the [`Date`].
+    pub fn days(self) -> i64 {
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let doy = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Returns the day of the week of the [`Date`], from `0` (Sunday) to
+    /// `6` (Saturday).
+    pub fn weekday(self) -> i64 {
+        // January 1, 1970 was a Thursday
+        (self.days() + 4).rem_euclid(7)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
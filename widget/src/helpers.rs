@@ -1,5 +1,6 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
+use crate::carousel::{self, Carousel};
 use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
@@ -15,11 +16,14 @@ use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::sticky::Sticky;
 use crate::text::{self, Text};
 use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
-use crate::{Column, MouseArea, Row, Space, VerticalSlider};
+use crate::{
+    Column, DragArea, MouseArea, Row, Space, VerticalSlider, Zoomable,
+};
 
 use std::borrow::Cow;
 use std::ops::RangeInclusive;
@@ -102,6 +106,19 @@ where
     Scrollable::new(content)
 }
 
+/// Creates a new [`Sticky`] section with the provided header and content.
+///
+/// [`Sticky`]: crate::Sticky
+pub fn sticky<'a, Message, Renderer>(
+    header: impl Into<Element<'a, Message, Renderer>>,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> Sticky<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Sticky::new(header, content)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: crate::Button
@@ -116,20 +133,87 @@ where
     Button::new(content)
 }
 
-/// Creates a new [`Tooltip`] with the provided content, tooltip text, and [`tooltip::Position`].
+/// Creates a new [`MenuButton`] with the given content and actions.
+///
+/// [`MenuButton`]: crate::MenuButton
+pub fn menu_button<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    actions: impl IntoIterator<Item = crate::menu_button::Action<Message>>,
+) -> crate::MenuButton<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: button::StyleSheet + overlay::menu::StyleSheet,
+{
+    crate::MenuButton::new(content, actions)
+}
+
+/// Creates a new [`SplitButton`] with the given content and actions.
+///
+/// [`SplitButton`]: crate::SplitButton
+pub fn split_button<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    actions: impl IntoIterator<Item = crate::menu_button::Action<Message>>,
+) -> crate::SplitButton<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: button::StyleSheet + overlay::menu::StyleSheet,
+{
+    crate::SplitButton::new(content, actions)
+}
+
+/// Creates a new [`Carousel`] with the provided pages.
+///
+/// [`Carousel`]: crate::Carousel
+pub fn carousel<'a, Message, Renderer>(
+    pages: Vec<Element<'a, Message, Renderer>>,
+) -> Carousel<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: carousel::StyleSheet,
+{
+    Carousel::with_pages(pages)
+}
+
+/// Creates a new [`Tooltip`] with the provided content, tooltip, and [`tooltip::Position`].
 ///
 /// [`Tooltip`]: crate::Tooltip
 /// [`tooltip::Position`]: crate::tooltip::Position
 pub fn tooltip<'a, Message, Renderer>(
     content: impl Into<Element<'a, Message, Renderer>>,
-    tooltip: impl ToString,
+    tooltip: impl Into<Element<'a, Message, Renderer>>,
     position: tooltip::Position,
 ) -> crate::Tooltip<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    Tooltip::new(content, tooltip, position)
+}
+
+/// Creates a new [`ShortcutsOverlay`] displaying the shortcut hints of the
+/// given content.
+///
+/// [`ShortcutsOverlay`]: crate::ShortcutsOverlay
+pub fn shortcuts_overlay<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::ShortcutsOverlay<'a, Message, Renderer>
 where
     Renderer: core::text::Renderer,
-    Renderer::Theme: container::StyleSheet + text::StyleSheet,
+    Renderer::Theme: crate::shortcuts_overlay::StyleSheet,
 {
-    Tooltip::new(content, tooltip.to_string(), position)
+    crate::ShortcutsOverlay::new(content)
+}
+
+/// Annotates the given content with the keyboard shortcut that triggers it.
+///
+/// The hint is displayed by the closest [`ShortcutsOverlay`] ancestor.
+///
+/// [`ShortcutsOverlay`]: crate::ShortcutsOverlay
+pub fn shortcut_hint<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    shortcut: impl ToString,
+) -> crate::shortcuts_overlay::Hint<'a, Message, Renderer> {
+    crate::shortcuts_overlay::Hint::new(content, shortcut)
 }
 
 /// Creates a new [`Text`] widget with the provided content.
@@ -176,6 +260,21 @@ where
     Radio::new(label, value, selected, on_click)
 }
 
+/// Creates a new [`Segmented`] control with the given labels.
+///
+/// [`Segmented`]: crate::Segmented
+pub fn segmented<'a, Message, Renderer>(
+    labels: impl IntoIterator<Item = impl ToString>,
+    selected: Option<usize>,
+    on_select: impl Fn(usize) -> Message + 'a,
+) -> crate::Segmented<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: crate::segmented::StyleSheet,
+{
+    crate::Segmented::new(labels, selected, on_select)
+}
+
 /// Creates a new [`Toggler`].
 ///
 /// [`Toggler`]: crate::Toggler
@@ -191,6 +290,20 @@ where
     Toggler::new(label, is_checked, f)
 }
 
+/// Creates a new [`Terminal`] displaying the given [`Grid`].
+///
+/// [`Terminal`]: crate::Terminal
+/// [`Grid`]: crate::terminal::Grid
+pub fn terminal<'a, Message, Renderer>(
+    grid: &'a crate::terminal::Grid,
+) -> crate::Terminal<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::terminal::StyleSheet,
+{
+    crate::Terminal::new(grid)
+}
+
 /// Creates a new [`TextInput`].
 ///
 /// [`TextInput`]: crate::TextInput
@@ -206,6 +319,54 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`Form`] with the given content of its submit button and
+/// the message it produces when submitted.
+///
+/// [`Form`]: crate::Form
+pub fn form<'a, Message, Renderer>(
+    submit: impl Into<Element<'a, Message, Renderer>>,
+    on_submit: Message,
+) -> crate::Form<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: crate::form::StyleSheet + button::StyleSheet,
+{
+    crate::Form::new(submit, on_submit)
+}
+
+/// Creates a new [`MaskedInput`] with the given [`Format`].
+///
+/// [`MaskedInput`]: crate::MaskedInput
+/// [`Format`]: crate::masked_input::Format
+pub fn masked_input<'a, Message, Renderer>(
+    placeholder: &str,
+    value: &str,
+    format: crate::masked_input::Format,
+) -> crate::MaskedInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    crate::MaskedInput::new(placeholder, value, format)
+}
+
+/// Creates a new [`PinInput`] with the given amount of boxes.
+///
+/// [`PinInput`]: crate::PinInput
+pub fn pin_input<'a, Message, Renderer>(
+    length: usize,
+    value: &str,
+    on_input: impl Fn(String) -> Message + 'a,
+) -> crate::PinInput<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text_input::StyleSheet,
+{
+    crate::PinInput::new(length, value, on_input)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: crate::Slider
@@ -240,6 +401,20 @@ where
     VerticalSlider::new(range, value, on_change)
 }
 
+/// Creates a new [`VirtualKeyboard`] typing into the given content.
+///
+/// [`VirtualKeyboard`]: crate::VirtualKeyboard
+pub fn virtual_keyboard<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    layout: crate::virtual_keyboard::Layout,
+) -> crate::VirtualKeyboard<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: crate::virtual_keyboard::StyleSheet,
+{
+    crate::VirtualKeyboard::new(content, layout)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// [`PickList`]: crate::PickList
@@ -279,6 +454,20 @@ where
     ComboBox::new(state, placeholder, selection, on_selected)
 }
 
+/// Creates a new [`GradientEditor`] for the given gradient.
+///
+/// [`GradientEditor`]: crate::GradientEditor
+pub fn gradient_editor<'a, Message, Renderer>(
+    gradient: impl Into<core::Gradient>,
+    on_change: impl Fn(core::Gradient) -> Message + 'a,
+) -> crate::GradientEditor<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: crate::gradient_editor::StyleSheet,
+{
+    crate::GradientEditor::new(gradient, on_change)
+}
+
 /// Creates a new horizontal [`Space`] with the given [`Length`].
 ///
 /// [`Space`]: crate::Space
@@ -341,6 +530,46 @@ pub fn image<Handle>(handle: impl Into<Handle>) -> crate::Image<Handle> {
     crate::Image::new(handle.into())
 }
 
+/// Creates a new [`CustomCursor`] showing the given image as the mouse
+/// cursor while the content is hovered.
+///
+/// [`CustomCursor`]: crate::CustomCursor
+#[cfg(feature = "image")]
+pub fn custom_cursor<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    image: impl Into<core::image::Handle>,
+) -> crate::CustomCursor<'a, Message, Renderer>
+where
+    Renderer: core::image::Renderer<Handle = core::image::Handle>,
+{
+    crate::CustomCursor::new(content, image)
+}
+
+/// Creates a new [`Video`] without any frame.
+///
+/// [`Video`]: crate::Video
+#[cfg(feature = "image")]
+pub fn video<'a, Message, Renderer>() -> crate::Video<'a, Message, Renderer>
+where
+    Renderer: core::image::Renderer<Handle = core::image::Handle>
+        + core::text::Renderer,
+    Renderer::Theme: crate::video::StyleSheet,
+{
+    crate::Video::new()
+}
+
+/// Creates a new [`WebView`] with the given [`Id`] showing the given URL.
+///
+/// [`WebView`]: crate::WebView
+/// [`Id`]: crate::webview::Id
+#[cfg(feature = "webview")]
+pub fn webview<'a, Message>(
+    id: crate::webview::Id,
+    url: impl Into<String>,
+) -> crate::WebView<'a, Message> {
+    crate::WebView::new(id, url)
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// [`Svg`]: crate::Svg
@@ -370,6 +599,92 @@ where
     crate::Canvas::new(program)
 }
 
+/// Creates a new [`Gauge`] with the given range and value.
+///
+/// [`Gauge`]: crate::Gauge
+#[cfg(feature = "canvas")]
+pub fn gauge<'a, Theme>(
+    range: std::ops::RangeInclusive<f32>,
+    value: f32,
+) -> crate::Gauge<'a, Theme>
+where
+    Theme: crate::gauge::StyleSheet,
+{
+    crate::Gauge::new(range, value)
+}
+
+/// Creates a new [`HeatmapCalendar`] displaying the days in the given range
+/// with the given values.
+///
+/// [`HeatmapCalendar`]: crate::HeatmapCalendar
+#[cfg(feature = "canvas")]
+pub fn heatmap_calendar<'a, Message, Theme>(
+    range: std::ops::RangeInclusive<crate::heatmap_calendar::Date>,
+    values: impl IntoIterator<Item = (crate::heatmap_calendar::Date, f32)>,
+) -> crate::HeatmapCalendar<'a, Message, Theme>
+where
+    Theme: crate::heatmap_calendar::StyleSheet,
+{
+    crate::HeatmapCalendar::new(range, values)
+}
+
+/// Creates a new [`Map`] displaying the given [`Tiles`], centered on the
+/// given [`Coordinate`] at the given zoom level.
+///
+/// [`Map`]: crate::Map
+/// [`Tiles`]: crate::map::Tiles
+/// [`Coordinate`]: crate::map::Coordinate
+#[cfg(feature = "map")]
+pub fn map<'a, Message, Theme>(
+    tiles: &'a crate::map::Tiles,
+    center: crate::map::Coordinate,
+    zoom: f32,
+) -> crate::Map<'a, Message, Theme>
+where
+    Theme: crate::map::StyleSheet,
+{
+    crate::Map::new(tiles, center, zoom)
+}
+
+/// Creates a new [`NodeEditor`] displaying the given nodes and connections.
+///
+/// [`NodeEditor`]: crate::NodeEditor
+#[cfg(feature = "canvas")]
+pub fn node_editor<'a, Message, Theme>(
+    nodes: &'a [crate::node_editor::Node],
+    connections: &'a [crate::node_editor::Connection],
+) -> crate::NodeEditor<'a, Message, Theme>
+where
+    Theme: crate::node_editor::StyleSheet,
+{
+    crate::NodeEditor::new(nodes, connections)
+}
+
+/// Creates a new [`Sparkline`] plotting the given values.
+///
+/// [`Sparkline`]: crate::Sparkline
+#[cfg(feature = "canvas")]
+pub fn sparkline<Theme>(values: &[f32]) -> crate::Sparkline<'_, Theme>
+where
+    Theme: crate::sparkline::StyleSheet,
+{
+    crate::Sparkline::new(values)
+}
+
+/// Creates a new [`Timeline`] displaying the given groups of rows and bars.
+///
+/// [`Timeline`]: crate::Timeline
+#[cfg(feature = "canvas")]
+pub fn timeline<'a, Message, Theme>(
+    groups: &'a [crate::timeline::Group],
+    bars: &'a [crate::timeline::Bar],
+) -> crate::Timeline<'a, Message, Theme>
+where
+    Theme: crate::timeline::StyleSheet,
+{
+    crate::Timeline::new(groups, bars)
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<Message>() -> Command<Message>
 where
@@ -386,6 +701,26 @@ where
     Command::widget(operation::focusable::focus_next())
 }
 
+/// A container that moves the window when pressed.
+pub fn drag_area<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> DragArea<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    DragArea::new(content)
+}
+
+/// A container receiving the files dropped over its content.
+pub fn drop_zone<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::DropZone<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    crate::DropZone::new(content)
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Renderer>(
     widget: impl Into<Element<'a, Message, Renderer>>,
@@ -395,3 +730,52 @@ where
 {
     MouseArea::new(widget)
 }
+
+/// Creates a new [`Zoomable`] with the provided content.
+///
+/// [`Zoomable`]: crate::Zoomable
+pub fn zoomable<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> Zoomable<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Zoomable::new(content)
+}
+
+/// Creates a new [`Transition`] with the provided content.
+///
+/// [`Transition`]: crate::Transition
+pub fn transition<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::Transition<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    crate::Transition::new(content)
+}
+
+/// Creates a new [`Opacity`] with the provided opacity and content.
+///
+/// [`Opacity`]: crate::Opacity
+pub fn opacity<'a, Message, Renderer>(
+    opacity: f32,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::Opacity<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    crate::Opacity::new(opacity, content)
+}
+
+/// Creates a new [`Styled`] with the provided content.
+///
+/// [`Styled`]: crate::Styled
+pub fn styled<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> crate::Styled<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    crate::Styled::new(content)
+}
@@ -1,5 +1,9 @@
 //! Display images in your user interface.
+pub mod animated;
+pub mod lazy;
 pub mod viewer;
+pub use animated::Animated;
+pub use lazy::Lazy;
 pub use viewer::Viewer;
 
 use crate::core::image;
@@ -8,18 +12,36 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::Tree;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
+    ContentFit, Element, Layout, Length, Padding, Rectangle, Size, Vector,
+    Widget,
 };
 
 use std::hash::Hash;
 
-pub use image::Handle;
+pub use crate::graphics::image::cache::invalidate;
+pub use image::{
+    Effects, FilterMethod, Format, Handle, Orientation, Plane, Rotation,
+    Stream, ToneMapping,
+};
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Creates a new [`Animated`] image playing the given [`animated::Frames`].
+pub fn animated<Message>(frames: &animated::Frames) -> Animated<Message> {
+    Animated::new(frames)
+}
+
+/// Creates a new [`Lazy`] image loading the given [`lazy::Source`] in the
+/// background.
+pub fn lazy<'a, Message, Renderer>(
+    source: impl Into<lazy::Source>,
+) -> Lazy<'a, Message, Renderer> {
+    Lazy::new(source)
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -37,6 +59,10 @@ pub struct Image<Handle> {
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    filter_method: FilterMethod,
+    effects: Effects,
+    region: Option<Rectangle>,
+    nine_slice: Option<Padding>,
 }
 
 impl<Handle> Image<Handle> {
@@ -47,6 +73,10 @@ impl<Handle> Image<Handle> {
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::default(),
+            effects: Effects::default(),
+            region: None,
+            nine_slice: None,
         }
     }
 
@@ -71,9 +101,65 @@ impl<Handle> Image<Handle> {
             ..self
         }
     }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// Consider [`FilterMethod::Trilinear`] for images that are scaled down
+    /// a lot, like thumbnails, to avoid aliasing.
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the color [`Effects`] of the [`Image`].
+    ///
+    /// Effects are applied while drawing, so assets do not need to be
+    /// processed beforehand; like a grayscale [`Image`] when it is disabled,
+    /// or a tint when it is hovered.
+    pub fn effects(mut self, effects: Effects) -> Self {
+        self.effects = effects;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], from `0.0` to `1.0`.
+    ///
+    /// This is a shorthand for the opacity of its [`Effects`].
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.effects.opacity = opacity;
+        self
+    }
+
+    /// Sets the region of the image displayed by the [`Image`], in pixels.
+    ///
+    /// Only the region is drawn and laid out, as if it was the whole image;
+    /// so the sprites of a sprite sheet or a cropped preview can be displayed
+    /// without decoding separate images.
+    pub fn region(mut self, region: Rectangle) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Draws the [`Image`] using nine-slice scaling with the given `insets`,
+    /// in pixels of the image.
+    ///
+    /// The insets cut the image into nine slices. The corners keep their
+    /// size, while the edges and the center stretch to fill the bounds of
+    /// the [`Image`]; so bitmap frames and speech bubbles can take any size
+    /// without distorting their corners.
+    ///
+    /// The [`ContentFit`] and the region of the [`Image`] are ignored, since
+    /// the whole image always fills its bounds.
+    pub fn nine_slice(mut self, insets: impl Into<Padding>) -> Self {
+        self.nine_slice = Some(insets.into());
+        self
+    }
 }
 
 /// Computes the layout of an [`Image`].
+///
+/// With a `region`, only that region of the image is laid out.
 pub fn layout<Renderer, Handle>(
     renderer: &Renderer,
     limits: &layout::Limits,
@@ -81,16 +167,13 @@ pub fn layout<Renderer, Handle>(
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    region: Option<Rectangle>,
 ) -> layout::Node
 where
     Renderer: image::Renderer<Handle = Handle>,
 {
     // The raw w/h of the underlying image
-    let image_size = {
-        let Size { width, height } = renderer.dimensions(handle);
-
-        Size::new(width as f32, height as f32)
-    };
+    let image_size = size(renderer, handle, region);
 
     // The size to be available to the widget prior to `Shrink`ing
     let raw_size = limits.width(width).height(height).resolve(image_size);
@@ -114,17 +197,21 @@ where
 }
 
 /// Draws an [`Image`]
+///
+/// With a `region`, only that region of the image is drawn.
 pub fn draw<Renderer, Handle>(
     renderer: &mut Renderer,
     layout: Layout<'_>,
     handle: &Handle,
     content_fit: ContentFit,
+    filter_method: FilterMethod,
+    effects: Effects,
+    region: Option<Rectangle>,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
 {
-    let Size { width, height } = renderer.dimensions(handle);
-    let image_size = Size::new(width as f32, height as f32);
+    let image_size = size(renderer, handle, region);
 
     let bounds = layout.bounds();
     let adjusted_fit = content_fit.fit(image_size, bounds.size());
@@ -141,7 +228,12 @@ pub fn draw<Renderer, Handle>(
             ..bounds
         };
 
-        renderer.draw(handle.clone(), drawing_bounds + offset);
+        renderer.draw(image::Image {
+            filter_method,
+            effects,
+            region,
+            ..image::Image::new(handle.clone(), drawing_bounds + offset)
+        });
     };
 
     if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
@@ -152,6 +244,27 @@ pub fn draw<Renderer, Handle>(
     }
 }
 
+/// Returns the size of the given region of an image, clipped to the image,
+/// or the size of the whole image.
+fn size<Renderer, Handle>(
+    renderer: &Renderer,
+    handle: &Handle,
+    region: Option<Rectangle>,
+) -> Size
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    let Size { width, height } = renderer.dimensions(handle);
+    let whole = Rectangle::with_size(Size::new(width as f32, height as f32));
+
+    match region {
+        Some(region) => region
+            .intersection(&whole)
+            .map_or(Size::ZERO, |region| region.size()),
+        None => whole.size(),
+    }
+}
+
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
@@ -171,13 +284,20 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let content_fit = if self.nine_slice.is_some() {
+            ContentFit::Fill
+        } else {
+            self.content_fit
+        };
+
         layout(
             renderer,
             limits,
             &self.handle,
             self.width,
             self.height,
-            self.content_fit,
+            content_fit,
+            self.region,
         )
     }
 
@@ -191,7 +311,26 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        draw(renderer, layout, &self.handle, self.content_fit);
+        if let Some(insets) = self.nine_slice {
+            renderer.draw_nine_slice(
+                image::Image {
+                    filter_method: self.filter_method,
+                    effects: self.effects,
+                    ..image::Image::new(self.handle.clone(), layout.bounds())
+                },
+                insets,
+            );
+        } else {
+            draw(
+                renderer,
+                layout,
+                &self.handle,
+                self.content_fit,
+                self.filter_method,
+                self.effects,
+                self.region,
+            );
+        }
     }
 }
 
@@ -0,0 +1,354 @@
+//! Play animated images, like GIFs.
+use crate::core::event::{self, Event};
+use crate::core::image::{self, Handle};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Layout, Length, Rectangle, Shell, Widget,
+};
+use crate::graphics::image::image_rs;
+
+use std::sync::Arc;
+
+/// The shortest delay of a frame that is honored.
+///
+/// Shorter delays are displayed for [`DEFAULT_DELAY`] instead, just like
+/// web browsers do, since many images rely on it.
+const MIN_DELAY: Duration = Duration::from_millis(20);
+
+/// The delay of the frames with a delay shorter than [`MIN_DELAY`].
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// The decoded frames of an animated image.
+///
+/// Cloning [`Frames`] is cheap, since they are shared.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    id: u64,
+    frames: Arc<[Frame]>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    handle: Handle,
+    delay: Duration,
+}
+
+impl Frames {
+    /// Decodes all the frames of the image of the given [`Handle`].
+    ///
+    /// Animated GIF, APNG, and WebP images are supported. Any other image
+    /// produces a single frame.
+    ///
+    /// Decoding is expensive and blocks until it is done, so consider
+    /// decoding in a [`Command`] instead of the `update` logic of your
+    /// application.
+    ///
+    /// [`Command`]: crate::runtime::Command
+    pub fn load(handle: &Handle) -> Result<Self, Error> {
+        let frames = crate::graphics::image::load_frames(handle)
+            .map_err(|error| Error::Decoding(Arc::new(error)))?;
+
+        if frames.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        Ok(Self {
+            id: handle.id(),
+            frames: frames
+                .into_iter()
+                .map(|(buffer, delay)| Frame {
+                    handle: Handle::from_pixels(
+                        buffer.width(),
+                        buffer.height(),
+                        buffer.into_raw(),
+                    ),
+                    delay: if delay < MIN_DELAY {
+                        DEFAULT_DELAY
+                    } else {
+                        delay
+                    },
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns true if there is a single frame, which is never animated.
+    pub fn is_static(&self) -> bool {
+        self.frames.len() < 2
+    }
+
+    /// Returns how long it takes to play every frame once.
+    pub fn duration(&self) -> Duration {
+        if self.is_static() {
+            return Duration::ZERO;
+        }
+
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    fn first(&self) -> &Handle {
+        &self.frames[0].handle
+    }
+}
+
+/// An error produced when decoding [`Frames`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The image could not be decoded.
+    #[error("the image could not be decoded: {0}")]
+    Decoding(Arc<image_rs::ImageError>),
+
+    /// The image does not have any frame.
+    #[error("the image does not have any frame")]
+    Empty,
+}
+
+/// A widget that plays the [`Frames`] of an animated image, keeping its
+/// aspect ratio.
+///
+/// It plays on its own, following the delay of every frame.
+#[allow(missing_debug_implementations)]
+pub struct Animated<Message> {
+    frames: Frames,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    is_playing: bool,
+    is_looping: bool,
+    on_finish: Option<Message>,
+}
+
+impl<Message> Animated<Message> {
+    /// Creates a new [`Animated`] image playing the given [`Frames`].
+    pub fn new(frames: &Frames) -> Self {
+        Self {
+            frames: frames.clone(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            is_playing: true,
+            is_looping: true,
+            on_finish: None,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets whether the [`Animated`] image is playing.
+    ///
+    /// A paused image keeps its current frame, and it resumes from it. It
+    /// is playing by default.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Animated`] image starts over once its last frame
+    /// has been played.
+    ///
+    /// Otherwise, it stops at its last frame. It loops by default.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    /// Sets the message that should be produced when the last frame of an
+    /// [`Animated`] image that does not loop has been played.
+    pub fn on_finish(mut self, message: Message) -> Self {
+        self.on_finish = Some(message);
+        self
+    }
+}
+
+/// The local state of an [`Animated`] image.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    frames: u64,
+    index: usize,
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+    is_finished: bool,
+}
+
+impl State {
+    fn new(frames: &Frames) -> Self {
+        Self {
+            frames: frames.id,
+            index: 0,
+            elapsed: Duration::ZERO,
+            last_tick: None,
+            is_finished: false,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Animated<Message>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Message: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.frames))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Different frames play from the start
+        if state.frames != self.frames.id {
+            *state = State::new(&self.frames);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        super::layout(
+            renderer,
+            limits,
+            self.frames.first(),
+            self.width,
+            self.height,
+            self.content_fit,
+            None,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.is_playing || self.frames.is_static() || state.is_finished {
+            state.last_tick = None;
+
+            return event::Status::Ignored;
+        }
+
+        if let Some(last_tick) = state.last_tick {
+            state.elapsed += now.saturating_duration_since(last_tick);
+        }
+
+        state.last_tick = Some(now);
+
+        loop {
+            let delay = self.frames.frames[state.index].delay;
+
+            if state.elapsed < delay {
+                shell.request_redraw(window::RedrawRequest::At(
+                    now + (delay - state.elapsed),
+                ));
+
+                break;
+            }
+
+            state.elapsed -= delay;
+
+            if state.index + 1 < self.frames.frames.len() {
+                state.index += 1;
+            } else if self.is_looping {
+                state.index = 0;
+            } else {
+                state.is_finished = true;
+
+                if let Some(on_finish) = &self.on_finish {
+                    shell.publish(on_finish.clone());
+                }
+
+                break;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let frame = self
+            .frames
+            .frames
+            .get(state.index)
+            .unwrap_or(&self.frames.frames[0]);
+
+        super::draw(
+            renderer,
+            layout,
+            &frame.handle,
+            self.content_fit,
+            image::FilterMethod::default(),
+            image::Effects::default(),
+            None,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Animated<Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Message: Clone + 'a,
+{
+    fn from(animated: Animated<Message>) -> Element<'a, Message, Renderer> {
+        Element::new(animated)
+    }
+}
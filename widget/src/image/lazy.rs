@@ -0,0 +1,535 @@
+//! Load and decode images in the background.
+//!
+//! A [`Lazy`] image is read—or fetched, in the case of a URL—and decoded
+//! in a thread of its own, while a placeholder is displayed. The results are
+//! cached for the whole process, so any other [`Lazy`] image with the same
+//! [`Source`] is displayed right away.
+//!
+//! The widget does not know how to download images by itself. Instead, the
+//! application provides the function that fetches the bytes of a URL with
+//! [`Lazy::fetch`]—for instance, using a blocking HTTP client.
+use crate::core::event::{self, Event};
+use crate::core::image::{self, Handle};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Duration;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Layout, Length, Rectangle, Shell, Size,
+    Widget,
+};
+use crate::graphics::image::image_rs;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// How often a [`Lazy`] image checks whether its [`Source`] has been loaded.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The results of every [`Source`] that has been loaded so far.
+static CACHE: Mutex<BTreeMap<Source, Status>> = Mutex::new(BTreeMap::new());
+
+/// The function that fetches the bytes of a URL.
+type Fetch = Arc<dyn Fn(&str) -> Result<Vec<u8>, String> + Send + Sync>;
+
+/// The location of the image of a [`Lazy`] image.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Source {
+    /// A file in the file system.
+    Path(PathBuf),
+
+    /// A URL, fetched with [`Lazy::fetch`].
+    Url(String),
+}
+
+impl From<&str> for Source {
+    fn from(source: &str) -> Self {
+        Self::from(source.to_owned())
+    }
+}
+
+impl From<String> for Source {
+    fn from(source: String) -> Self {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            Self::Url(source)
+        } else {
+            Self::Path(PathBuf::from(source))
+        }
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&std::path::Path> for Source {
+    fn from(path: &std::path::Path) -> Self {
+        Self::Path(path.to_path_buf())
+    }
+}
+
+/// An error produced when loading a [`Lazy`] image.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file of the image could not be read.
+    #[error("the image could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+
+    /// The URL of the image could not be fetched.
+    #[error("the image could not be fetched: {0}")]
+    Fetch(String),
+
+    /// The image is a URL, but no [`Lazy::fetch`] function was provided.
+    #[error("the image is a URL, but there is no way to fetch it")]
+    Unsupported,
+
+    /// The image could not be decoded.
+    #[error("the image could not be decoded: {0}")]
+    Decoding(Arc<image_rs::ImageError>),
+}
+
+/// Removes the result of the given [`Source`] from the cache, so that it is
+/// loaded again the next time it is displayed.
+///
+/// This is useful to retry images that have failed to load.
+pub fn forget(source: impl Into<Source>) {
+    let _ = cache().remove(&source.into());
+}
+
+/// Removes every result from the cache.
+pub fn clear() {
+    cache().clear();
+}
+
+fn cache() -> std::sync::MutexGuard<'static, BTreeMap<Source, Status>> {
+    CACHE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// The loading status of a [`Source`].
+#[derive(Debug, Clone)]
+enum Status {
+    Loading,
+    Loaded(Handle),
+    Failed(Error),
+}
+
+impl Status {
+    fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+}
+
+/// Returns the [`Status`] of the given [`Source`], starting to load it in the
+/// background if it is not in the cache.
+fn load(source: &Source, fetch: Option<&Fetch>) -> Status {
+    let mut cache = cache();
+
+    if let Some(status) = cache.get(source) {
+        return status.clone();
+    }
+
+    let _ = cache.insert(source.clone(), Status::Loading);
+    drop(cache);
+
+    let source = source.clone();
+    let fetch = fetch.cloned();
+
+    spawn(move || {
+        let status = match decode(&source, fetch.as_ref()) {
+            Ok(handle) => Status::Loaded(handle),
+            Err(error) => Status::Failed(error),
+        };
+
+        let _ = cache().insert(source, status);
+    });
+
+    Status::Loading
+}
+
+fn decode(source: &Source, fetch: Option<&Fetch>) -> Result<Handle, Error> {
+    let bytes = match source {
+        Source::Path(path) => {
+            std::fs::read(path).map_err(|error| Error::Io(Arc::new(error)))?
+        }
+        Source::Url(url) => {
+            let fetch = fetch.ok_or(Error::Unsupported)?;
+
+            fetch(url).map_err(Error::Fetch)?
+        }
+    };
+
+    let image = crate::graphics::image::load(&Handle::from_memory(bytes))
+        .map_err(|error| Error::Decoding(Arc::new(error)))?
+        .into_rgba8();
+
+    Ok(Handle::from_pixels(
+        image.width(),
+        image.height(),
+        image.into_raw(),
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn(f: impl FnOnce() + Send + 'static) {
+    let _ = std::thread::spawn(f);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn spawn(f: impl FnOnce() + Send + 'static) {
+    f();
+}
+
+/// An image that is loaded and decoded in the background, keeping its
+/// aspect ratio.
+///
+/// A placeholder is displayed while the image is loading, and an error
+/// widget if it fails to load. Both are empty by default.
+#[allow(missing_debug_implementations)]
+pub struct Lazy<'a, Message, Renderer> {
+    source: Source,
+    fetch: Option<Fetch>,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    placeholder: Option<Element<'a, Message, Renderer>>,
+    error: Option<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Lazy<'a, Message, Renderer> {
+    /// Creates a new [`Lazy`] image loading the given [`Source`].
+    pub fn new(source: impl Into<Source>) -> Self {
+        Self {
+            source: source.into(),
+            fetch: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::default(),
+            placeholder: None,
+            error: None,
+        }
+    }
+
+    /// Sets the width of the [`Lazy`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Lazy`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Lazy`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Lazy`] image.
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the function that fetches the bytes of the [`Lazy`] image when
+    /// its [`Source`] is a URL.
+    ///
+    /// The function runs in a background thread, so it can block.
+    pub fn fetch(
+        mut self,
+        fetch: impl Fn(&str) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.fetch = Some(Arc::new(fetch));
+        self
+    }
+
+    /// Sets the widget displayed while the [`Lazy`] image is loading—like a
+    /// spinner.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the widget displayed when the [`Lazy`] image fails to load.
+    pub fn error(
+        mut self,
+        error: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    fn content(
+        &self,
+        status: &Status,
+    ) -> Option<(usize, &Element<'a, Message, Renderer>)> {
+        match status {
+            Status::Loading => self.placeholder.as_ref().map(|p| (0, p)),
+            Status::Failed(_) => self.error.as_ref().map(|e| (1, e)),
+            Status::Loaded(_) => None,
+        }
+    }
+
+    fn content_mut(
+        &mut self,
+        status: &Status,
+    ) -> Option<(usize, &mut Element<'a, Message, Renderer>)> {
+        match status {
+            Status::Loading => self.placeholder.as_mut().map(|p| (0, p)),
+            Status::Failed(_) => self.error.as_mut().map(|e| (1, e)),
+            Status::Loaded(_) => None,
+        }
+    }
+}
+
+/// The local state of a [`Lazy`] image.
+#[derive(Debug)]
+struct State {
+    source: Source,
+    status: Status,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Lazy<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            source: self.source.clone(),
+            status: load(&self.source, self.fetch.as_ref()),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        [&self.placeholder, &self.error]
+            .into_iter()
+            .map(|element| element.as_ref().map_or_else(Tree::empty, Tree::new))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.source != self.source {
+            state.source = self.source.clone();
+            state.status = load(&self.source, self.fetch.as_ref());
+        }
+
+        for (element, tree) in [&self.placeholder, &self.error]
+            .into_iter()
+            .zip(&mut tree.children)
+        {
+            match element {
+                Some(element) => tree.diff(element),
+                None => *tree = Tree::empty(),
+            }
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let status = &tree.state.downcast_ref::<State>().status;
+
+        if let Status::Loaded(handle) = status {
+            return super::layout(
+                renderer,
+                limits,
+                handle,
+                self.width,
+                self.height,
+                self.content_fit,
+                None,
+            );
+        }
+
+        let limits = limits.width(self.width).height(self.height);
+
+        match self.content(status) {
+            Some((index, content)) => content.as_widget().layout(
+                &mut tree.children[index],
+                renderer,
+                &limits,
+            ),
+            None => layout::Node::new(limits.resolve(Size::ZERO)),
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let status = &tree.state.downcast_ref::<State>().status;
+
+        if let Some((index, content)) = self.content(status) {
+            content.as_widget().operate(
+                &mut tree.children[index],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.status.is_loading() {
+                state.status = load(&state.source, self.fetch.as_ref());
+
+                if state.status.is_loading() {
+                    shell.request_redraw(window::RedrawRequest::At(
+                        now + POLL_INTERVAL,
+                    ));
+                } else {
+                    shell.invalidate_layout();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                return event::Status::Ignored;
+            }
+        }
+
+        let status = state.status.clone();
+
+        match self.content_mut(&status) {
+            Some((index, content)) => content.as_widget_mut().on_event(
+                &mut tree.children[index],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let status = &tree.state.downcast_ref::<State>().status;
+
+        match self.content(status) {
+            Some((index, content)) => content.as_widget().mouse_interaction(
+                &tree.children[index],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let status = &tree.state.downcast_ref::<State>().status;
+
+        if let Status::Loaded(handle) = status {
+            super::draw(
+                renderer,
+                layout,
+                handle,
+                self.content_fit,
+                self.filter_method,
+                image::Effects::default(),
+                None,
+            );
+
+            return;
+        }
+
+        if let Some((index, content)) = self.content(status) {
+            content.as_widget().draw(
+                &tree.children[index],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Lazy<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+{
+    fn from(
+        lazy: Lazy<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy)
+    }
+}
@@ -1,26 +1,34 @@
 //! Zoom and pan on an image.
 use crate::core::event::{self, Event};
-use crate::core::image;
+use crate::core::image::{self, FilterMethod, Orientation, Rotation};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
-    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
-    Vector, Widget,
+    Clipboard, ContentFit, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
+use crate::runtime::Command;
 
+use std::any::Any;
 use std::hash::Hash;
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
 #[allow(missing_debug_implementations)]
 pub struct Viewer<Handle> {
+    id: Option<Id>,
     padding: f32,
     width: Length,
     height: Length,
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    orientation: Orientation,
     handle: Handle,
 }
 
@@ -28,16 +36,26 @@ impl<Handle> Viewer<Handle> {
     /// Creates a new [`Viewer`] with the given [`State`].
     pub fn new(handle: Handle) -> Self {
         Viewer {
+            id: None,
             padding: 0.0,
             width: Length::Shrink,
             height: Length::Shrink,
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            content_fit: ContentFit::ScaleDown,
+            filter_method: FilterMethod::default(),
+            orientation: Orientation::default(),
             handle,
         }
     }
 
+    /// Sets the [`Id`] of the [`Viewer`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the padding of the [`Viewer`].
     pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
         self.padding = padding.into().0;
@@ -80,6 +98,44 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Sets the [`ContentFit`] of the image of the [`Viewer`] when it is not
+    /// scaled.
+    ///
+    /// [`ContentFit::Contain`] fits the image to the [`Viewer`], while
+    /// [`ContentFit::Cover`] fills it.
+    ///
+    /// Default is [`ContentFit::ScaleDown`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the image of the [`Viewer`].
+    ///
+    /// Default is [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the [`Rotation`] of the image of the [`Viewer`].
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.orientation.rotation = rotation;
+        self
+    }
+
+    /// Sets whether the image of the [`Viewer`] is mirrored horizontally.
+    pub fn flip_horizontal(mut self, flip_horizontal: bool) -> Self {
+        self.orientation.flip_horizontal = flip_horizontal;
+        self
+    }
+
+    /// Sets whether the image of the [`Viewer`] is mirrored vertically.
+    pub fn flip_vertical(mut self, flip_vertical: bool) -> Self {
+        self.orientation.flip_vertical = flip_vertical;
+        self
+    }
 }
 
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Viewer<Handle>
@@ -109,7 +165,9 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let Size { width, height } = renderer.dimensions(&self.handle);
+        let Size { width, height } = self
+            .orientation
+            .apply_to_size(renderer.dimensions(&self.handle));
 
         let mut size = limits
             .width(self.width)
@@ -140,6 +198,22 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Keep the scale limits in the state, so commands can respect them
+        state.min_scale = self.min_scale;
+        state.max_scale = self.max_scale;
+
+        operation.custom(state, self.id.as_ref().map(|id| &id.0));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -180,6 +254,8 @@ where
                                 &self.handle,
                                 state,
                                 bounds.size(),
+                                self.content_fit,
+                                self.orientation,
                             );
 
                             let factor = state.scale / previous_scale - 1.0;
@@ -240,6 +316,8 @@ where
                         &self.handle,
                         state,
                         bounds.size(),
+                        self.content_fit,
+                        self.orientation,
                     );
 
                     let hidden_width = (image_size.width - bounds.width / 2.0)
@@ -312,8 +390,14 @@ where
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
 
-        let image_size =
-            image_size(renderer, &self.handle, state, bounds.size());
+        let image_size = image_size(
+            renderer,
+            &self.handle,
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.orientation,
+        );
 
         let translation = {
             let image_top_left = Vector::new(
@@ -328,11 +412,17 @@ where
             renderer.with_translation(translation, |renderer| {
                 image::Renderer::draw(
                     renderer,
-                    self.handle.clone(),
-                    Rectangle {
-                        x: bounds.x,
-                        y: bounds.y,
-                        ..Rectangle::with_size(image_size)
+                    image::Image {
+                        filter_method: self.filter_method,
+                        orientation: self.orientation,
+                        ..image::Image::new(
+                            self.handle.clone(),
+                            Rectangle {
+                                x: bounds.x,
+                                y: bounds.y,
+                                ..Rectangle::with_size(image_size)
+                            },
+                        )
                     },
                 );
             });
@@ -344,6 +434,8 @@ where
 #[derive(Debug, Clone, Copy)]
 pub struct State {
     scale: f32,
+    min_scale: f32,
+    max_scale: f32,
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
@@ -353,6 +445,8 @@ impl Default for State {
     fn default() -> Self {
         Self {
             scale: 1.0,
+            min_scale: 0.25,
+            max_scale: 10.0,
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
@@ -385,6 +479,26 @@ impl State {
     pub fn is_cursor_grabbed(&self) -> bool {
         self.cursor_grabbed_at.is_some()
     }
+
+    /// Returns the current scale of the [`State`].
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Sets the scale of the [`State`].
+    ///
+    /// The scale is clamped to the min and max scale of the [`Viewer`].
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(self.min_scale, self.max_scale);
+    }
+
+    /// Sets the offset of the image from the center of the [`Viewer`].
+    ///
+    /// The offset will be clamped to keep the image in view.
+    pub fn set_offset(&mut self, offset: Vector) {
+        self.starting_offset = offset;
+        self.current_offset = offset;
+    }
 }
 
 impl<'a, Message, Renderer, Handle> From<Viewer<Handle>>
@@ -400,34 +514,108 @@ where
 }
 
 /// Returns the bounds of the underlying image, given the bounds of
-/// the [`Viewer`]. Scaling will be applied and original aspect ratio
-/// will be respected.
+/// the [`Viewer`]. The [`ContentFit`], [`Orientation`], and scaling will be
+/// applied and original aspect ratio will be respected.
 pub fn image_size<Renderer>(
     renderer: &Renderer,
     handle: &<Renderer as image::Renderer>::Handle,
     state: &State,
     bounds: Size,
+    content_fit: ContentFit,
+    orientation: Orientation,
 ) -> Size
 where
     Renderer: image::Renderer,
 {
-    let Size { width, height } = renderer.dimensions(handle);
+    let Size { width, height } =
+        orientation.apply_to_size(renderer.dimensions(handle));
 
-    let (width, height) = {
-        let dimensions = (width as f32, height as f32);
+    let Size { width, height } =
+        content_fit.fit(Size::new(width as f32, height as f32), bounds);
 
-        let width_ratio = bounds.width / dimensions.0;
-        let height_ratio = bounds.height / dimensions.1;
+    Size::new(width * state.scale, height * state.scale)
+}
 
-        let ratio = width_ratio.min(height_ratio);
-        let scale = state.scale;
+/// The identifier of a [`Viewer`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
 
-        if ratio < 1.0 {
-            (dimensions.0 * ratio * scale, dimensions.1 * ratio * scale)
-        } else {
-            (dimensions.0 * scale, dimensions.1 * scale)
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that sets the scale of the [`Viewer`] with the
+/// given [`Id`].
+pub fn scale_to<Message: 'static>(id: Id, scale: f32) -> Command<Message> {
+    Command::widget(update(id, move |state| state.set_scale(scale)))
+}
+
+/// Produces a [`Command`] that sets the offset of the image of the [`Viewer`]
+/// with the given [`Id`] from its center.
+pub fn offset_to<Message: 'static>(id: Id, offset: Vector) -> Command<Message> {
+    Command::widget(update(id, move |state| state.set_offset(offset)))
+}
+
+/// Produces a [`Command`] that resets the scale and offset of the [`Viewer`]
+/// with the given [`Id`].
+pub fn reset<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(update(id, |state| {
+        *state = State {
+            min_scale: state.min_scale,
+            max_scale: state.max_scale,
+            ..State::new()
+        };
+    }))
+}
+
+/// Produces an [`Operation`] that updates the [`State`] of the [`Viewer`]
+/// with the given [`Id`].
+fn update<T>(
+    target: Id,
+    f: impl Fn(&mut State) + 'static,
+) -> impl Operation<T> {
+    struct Update<F> {
+        target: Id,
+        f: F,
+    }
+
+    impl<T, F> Operation<T> for Update<F>
+    where
+        F: Fn(&mut State),
+    {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if Some(&self.target.0) == id {
+                if let Some(state) = state.downcast_mut::<State>() {
+                    (self.f)(state);
+                }
+            }
         }
-    };
+    }
 
-    Size::new(width, height)
+    Update { target, f }
 }
@@ -1,4 +1,5 @@
 //! Distribute content vertically.
+use crate::core::cascade;
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
@@ -17,7 +18,7 @@ pub struct Column<'a, Key, Message, Renderer = crate::Renderer>
 where
     Key: Copy + PartialEq,
 {
-    spacing: f32,
+    spacing: Option<f32>,
     padding: Padding,
     width: Length,
     height: Length,
@@ -51,7 +52,7 @@ where
         );
 
         Column {
-            spacing: 0.0,
+            spacing: None,
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -68,7 +69,7 @@ where
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
     pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
-        self.spacing = amount.into().0;
+        self.spacing = Some(amount.into().0);
         self
     }
 
@@ -197,7 +198,7 @@ where
             renderer,
             &limits,
             self.padding,
-            self.spacing,
+            self.spacing.or_else(cascade::spacing).unwrap_or(0.0),
             self.align_items,
             &self.children,
             &mut tree.children,
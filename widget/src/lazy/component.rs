@@ -1,4 +1,5 @@
 //! Build and reuse custom widgets using The Elm Architecture.
+use crate::core::accessibility;
 use crate::core::event;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
@@ -358,6 +359,21 @@ where
                 });
             }
 
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &accessibility::Node,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation
+                    .accessible(id, bounds, node, &mut |operation| {
+                        operate_on_children(&mut MapOperation { operation });
+                    });
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -17,28 +17,47 @@ pub use iced_runtime::core;
 pub use iced_style as style;
 
 mod column;
+mod drag_area;
+mod focus_ring;
 mod mouse_area;
 mod row;
 
 pub mod button;
+pub mod carousel;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod drop_zone;
+pub mod form;
+pub mod gradient_editor;
 pub mod keyed;
+pub mod masked_input;
+pub mod menu_button;
+pub mod opacity;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod pin_input;
 pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod segmented;
+pub mod shortcuts_overlay;
 pub mod slider;
 pub mod space;
+pub mod split_button;
+pub mod sticky;
+pub mod styled;
+pub mod terminal;
 pub mod text;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod transition;
 pub mod vertical_slider;
+pub mod virtual_keyboard;
+pub mod zoomable;
 
 mod helpers;
 
@@ -56,6 +75,8 @@ pub use crate::lazy::helpers::*;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use carousel::Carousel;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
@@ -64,12 +85,28 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use drag_area::DragArea;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
+pub use form::Form;
+#[doc(no_inline)]
+pub use gradient_editor::GradientEditor;
+#[doc(no_inline)]
+pub use masked_input::MaskedInput;
+#[doc(no_inline)]
+pub use menu_button::MenuButton;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
+pub use pin_input::PinInput;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
@@ -80,10 +117,22 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use segmented::Segmented;
+#[doc(no_inline)]
+pub use shortcuts_overlay::ShortcutsOverlay;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use split_button::SplitButton;
+#[doc(no_inline)]
+pub use sticky::Sticky;
+#[doc(no_inline)]
+pub use styled::Styled;
+#[doc(no_inline)]
+pub use terminal::Terminal;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
@@ -92,7 +141,13 @@ pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use transition::Transition;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_keyboard::VirtualKeyboard;
+#[doc(no_inline)]
+pub use zoomable::Zoomable;
 
 #[cfg(feature = "svg")]
 pub mod svg;
@@ -108,6 +163,20 @@ pub mod image;
 #[doc(no_inline)]
 pub use image::Image;
 
+#[cfg(feature = "image")]
+pub mod custom_cursor;
+
+#[cfg(feature = "image")]
+#[doc(no_inline)]
+pub use custom_cursor::CustomCursor;
+
+#[cfg(feature = "image")]
+pub mod video;
+
+#[cfg(feature = "image")]
+#[doc(no_inline)]
+pub use video::Video;
+
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
@@ -115,6 +184,51 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "canvas")]
+pub mod charts;
+
+#[cfg(feature = "canvas")]
+pub mod gauge;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use gauge::Gauge;
+
+#[cfg(feature = "canvas")]
+pub mod heatmap_calendar;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use heatmap_calendar::HeatmapCalendar;
+
+#[cfg(feature = "map")]
+pub mod map;
+
+#[cfg(feature = "map")]
+#[doc(no_inline)]
+pub use map::Map;
+
+#[cfg(feature = "canvas")]
+pub mod node_editor;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use node_editor::NodeEditor;
+
+#[cfg(feature = "canvas")]
+pub mod sparkline;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use sparkline::Sparkline;
+
+#[cfg(feature = "canvas")]
+pub mod timeline;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use timeline::Timeline;
+
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
@@ -122,4 +236,11 @@ pub mod qr_code;
 #[doc(no_inline)]
 pub use qr_code::QRCode;
 
+#[cfg(feature = "webview")]
+pub mod webview;
+
+#[cfg(feature = "webview")]
+#[doc(no_inline)]
+pub use webview::WebView;
+
 type Renderer<Theme = style::Theme> = renderer::Renderer<Theme>;
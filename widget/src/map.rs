@@ -0,0 +1,819 @@
+//! Display slippy maps made of raster tiles.
+//!
+//! A [`Map`] does not fetch its tiles by itself. Instead, it asks the
+//! application for the tiles it is missing through [`Map::on_request`].
+//! The application can then fetch them asynchronously—for instance, with a
+//! `Command::perform` downloading the URL given by [`Tile::url`]—and store
+//! the results in the [`Tiles`] cache handed to the [`Map`].
+//!
+//! Markers and other overlays can be drawn on top of the tiles with
+//! [`Map::layer`], using a [`Projection`] to locate coordinates.
+use crate::canvas::{Frame, Text};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shadow, Shell,
+    Size, Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::f64::consts::PI;
+
+pub use crate::style::map::{Appearance, StyleSheet};
+
+/// The size of a tile, in logical pixels.
+pub const TILE_SIZE: f32 = 256.0;
+
+/// The maximum latitude that can be displayed with the Web Mercator
+/// projection.
+const MAX_LATITUDE: f64 = 85.051_128_78;
+
+/// The maximum distance the cursor can travel between a press and a release
+/// for them to be considered a click.
+const CLICK_DISTANCE: f32 = 4.0;
+
+/// The size of the text of the attribution.
+const TEXT_SIZE: f32 = 12.0;
+
+/// A slippy map made of raster tiles that can be panned and zoomed.
+///
+/// The user can pan the [`Map`] by dragging it and zoom around the cursor
+/// with the mouse wheel.
+#[allow(missing_debug_implementations)]
+pub struct Map<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    tiles: &'a Tiles,
+    center: Coordinate,
+    zoom: f32,
+    max_zoom: u8,
+    width: Length,
+    height: Length,
+    layers: Vec<Box<dyn Fn(&mut Frame, &Projection) + 'a>>,
+    attribution: Option<String>,
+    on_request: Option<Box<dyn Fn(Vec<Tile>) -> Message + 'a>>,
+    on_move: Option<Box<dyn Fn(Coordinate, f32) -> Message + 'a>>,
+    on_press: Option<Box<dyn Fn(Coordinate) -> Message + 'a>>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Map<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default height of a [`Map`].
+    pub const DEFAULT_HEIGHT: f32 = 400.0;
+
+    /// The default maximum zoom level of a [`Map`].
+    pub const DEFAULT_MAX_ZOOM: u8 = 19;
+
+    /// Creates a new [`Map`] displaying the given [`Tiles`], centered on the
+    /// given [`Coordinate`] at the given zoom level.
+    ///
+    /// The [`Map`] keeps track of the changes made by the user. It only
+    /// moves back when the center or the zoom level given here change.
+    pub fn new(tiles: &'a Tiles, center: Coordinate, zoom: f32) -> Self {
+        Self {
+            tiles,
+            center,
+            zoom,
+            max_zoom: Self::DEFAULT_MAX_ZOOM,
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            layers: Vec::new(),
+            attribution: None,
+            on_request: None,
+            on_move: None,
+            on_press: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Map`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Map`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximum zoom level of the [`Map`].
+    ///
+    /// It should match the maximum zoom level of the tile server.
+    pub fn max_zoom(mut self, max_zoom: u8) -> Self {
+        self.max_zoom = max_zoom.min(24);
+        self
+    }
+
+    /// Adds a layer drawn on top of the tiles of the [`Map`].
+    ///
+    /// The closure draws on a [`Frame`] the size of the [`Map`] and can
+    /// locate coordinates with the given [`Projection`].
+    pub fn layer(
+        mut self,
+        layer: impl Fn(&mut Frame, &Projection) + 'a,
+    ) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Sets the attribution displayed in the bottom right corner of the
+    /// [`Map`].
+    ///
+    /// Most tile servers require one!
+    pub fn attribution(mut self, attribution: impl Into<String>) -> Self {
+        self.attribution = Some(attribution.into());
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Map`] needs some
+    /// tiles that are not in its [`Tiles`] cache.
+    ///
+    /// Every [`Tile`] is only requested once; so the application should
+    /// either [`Tiles::insert`] it or mark it as [`Tiles::fail`]ed.
+    pub fn on_request(
+        mut self,
+        on_request: impl Fn(Vec<Tile>) -> Message + 'a,
+    ) -> Self {
+        self.on_request = Some(Box::new(on_request));
+        self
+    }
+
+    /// Sets the message that should be produced when the user pans or zooms
+    /// the [`Map`].
+    ///
+    /// The closure receives the new center and the new zoom level.
+    pub fn on_move(
+        mut self,
+        on_move: impl Fn(Coordinate, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the message that should be produced when the user clicks the
+    /// [`Map`].
+    pub fn on_press(
+        mut self,
+        on_press: impl Fn(Coordinate) -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets the style of the [`Map`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn clamp_zoom(&self, zoom: f32) -> f32 {
+        zoom.max(0.0).min(f32::from(self.max_zoom))
+    }
+
+    fn publish_move(&self, state: &State, shell: &mut Shell<'_, Message>) {
+        if let Some(on_move) = &self.on_move {
+            shell.publish(on_move(
+                Coordinate::from_world(state.center),
+                state.zoom,
+            ));
+        }
+    }
+}
+
+/// A geographic coordinate, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coordinate {
+    /// The latitude of the [`Coordinate`].
+    pub latitude: f64,
+    /// The longitude of the [`Coordinate`].
+    pub longitude: f64,
+}
+
+impl Coordinate {
+    /// Creates a new [`Coordinate`] with the given latitude and longitude.
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Projects the [`Coordinate`] with the Web Mercator projection, in
+    /// units of the whole world.
+    fn to_world(self) -> World {
+        let latitude = self
+            .latitude
+            .max(-MAX_LATITUDE)
+            .min(MAX_LATITUDE)
+            .to_radians();
+
+        World {
+            x: (self.longitude + 180.0) / 360.0,
+            y: (1.0 - (latitude.tan() + 1.0 / latitude.cos()).ln() / PI) / 2.0,
+        }
+    }
+
+    fn from_world(world: World) -> Self {
+        Self {
+            latitude: (PI * (1.0 - 2.0 * world.y)).sinh().atan().to_degrees(),
+            longitude: world.x.rem_euclid(1.0) * 360.0 - 180.0,
+        }
+    }
+}
+
+/// A position in the Web Mercator projection, where the whole world spans
+/// from 0 to 1 in both axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct World {
+    x: f64,
+    y: f64,
+}
+
+/// Returns the number of logical pixels per world unit at the given zoom
+/// level.
+fn scale(zoom: f32) -> f64 {
+    f64::from(TILE_SIZE) * 2.0_f64.powf(f64::from(zoom))
+}
+
+/// A raster tile of a [`Map`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// The zoom level of the [`Tile`].
+    pub zoom: u8,
+    /// The column of the [`Tile`].
+    pub x: u32,
+    /// The row of the [`Tile`].
+    pub y: u32,
+}
+
+impl Tile {
+    /// Returns the URL of the [`Tile`] by replacing the `{z}`, `{x}`, and
+    /// `{y}` placeholders of the given template.
+    ///
+    /// For instance, `https://tile.openstreetmap.org/{z}/{x}/{y}.png`.
+    pub fn url(&self, template: &str) -> String {
+        template
+            .replace("{z}", &self.zoom.to_string())
+            .replace("{x}", &self.x.to_string())
+            .replace("{y}", &self.y.to_string())
+    }
+}
+
+/// A cache of the tiles of a [`Map`].
+///
+/// Once full, the oldest tiles are evicted first.
+#[derive(Debug, Clone)]
+pub struct Tiles {
+    entries: HashMap<Tile, Option<image::Handle>>,
+    order: VecDeque<Tile>,
+    capacity: usize,
+}
+
+impl Tiles {
+    /// The default capacity of the [`Tiles`] cache.
+    pub const DEFAULT_CAPACITY: usize = 512;
+
+    /// Creates an empty [`Tiles`] cache with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates an empty [`Tiles`] cache holding up to the given amount of
+    /// tiles.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Stores the image of a [`Tile`].
+    pub fn insert(&mut self, tile: Tile, handle: impl Into<image::Handle>) {
+        self.store(tile, Some(handle.into()));
+    }
+
+    /// Marks a [`Tile`] as failed, so that it is not requested again until
+    /// it is evicted.
+    pub fn fail(&mut self, tile: Tile) {
+        self.store(tile, None);
+    }
+
+    /// Returns the image of a [`Tile`], if loaded.
+    pub fn get(&self, tile: &Tile) -> Option<&image::Handle> {
+        self.entries.get(tile).and_then(Option::as_ref)
+    }
+
+    /// Returns true if the [`Tile`] is either loaded or failed.
+    pub fn contains(&self, tile: &Tile) -> bool {
+        self.entries.contains_key(tile)
+    }
+
+    /// Returns the amount of tiles in the [`Tiles`] cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Tiles`] cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all the tiles of the [`Tiles`] cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn store(&mut self, tile: Tile, entry: Option<image::Handle>) {
+        if self.entries.insert(tile, entry).is_none() {
+            self.order.push_back(tile);
+        }
+
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                let _ = self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl Default for Tiles {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The projection of a [`Map`], mapping coordinates to points relative to
+/// its top left corner and back.
+#[derive(Debug, Clone, Copy)]
+pub struct Projection {
+    center: World,
+    zoom: f32,
+    size: Size,
+}
+
+impl Projection {
+    /// Returns the [`Coordinate`] at the center of the [`Map`].
+    pub fn center(&self) -> Coordinate {
+        Coordinate::from_world(self.center)
+    }
+
+    /// Returns the current zoom level of the [`Map`].
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Returns the [`Size`] of the [`Map`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the [`Point`] of the [`Map`] where the given [`Coordinate`]
+    /// is displayed.
+    ///
+    /// The closest copy of the world is picked when the [`Map`] wraps
+    /// around.
+    pub fn project(&self, coordinate: Coordinate) -> Point {
+        let world = coordinate.to_world();
+        let scale = scale(self.zoom);
+
+        let dx = world.x - self.center.x;
+        let dx = dx - dx.round();
+        let dy = world.y - self.center.y;
+
+        Point::new(
+            self.size.width / 2.0 + (dx * scale) as f32,
+            self.size.height / 2.0 + (dy * scale) as f32,
+        )
+    }
+
+    /// Returns the [`Coordinate`] displayed at the given [`Point`] of the
+    /// [`Map`].
+    pub fn unproject(&self, point: Point) -> Coordinate {
+        Coordinate::from_world(self.world(point))
+    }
+
+    fn world(&self, point: Point) -> World {
+        let scale = scale(self.zoom);
+
+        World {
+            x: self.center.x
+                + f64::from(point.x - self.size.width / 2.0) / scale,
+            y: self.center.y
+                + f64::from(point.y - self.size.height / 2.0) / scale,
+        }
+    }
+
+    /// Returns the visible tiles with their bounds, relative to the top left
+    /// corner of the [`Map`].
+    fn tiles(&self, max_zoom: u8) -> Vec<(Tile, Rectangle)> {
+        let zoom = (self.zoom.round().max(0.0) as u8).min(max_zoom);
+        let count = 1_i64 << zoom;
+
+        let size = f64::from(TILE_SIZE)
+            * 2.0_f64.powf(f64::from(self.zoom) - f64::from(zoom));
+        let scale = scale(self.zoom);
+
+        let left = self.center.x * scale - f64::from(self.size.width) / 2.0;
+        let top = self.center.y * scale - f64::from(self.size.height) / 2.0;
+        let right = left + f64::from(self.size.width);
+        let bottom = top + f64::from(self.size.height);
+
+        let columns =
+            (left / size).floor() as i64..(right / size).ceil() as i64;
+        let rows = ((top / size).floor() as i64).max(0)
+            ..((bottom / size).ceil() as i64).min(count);
+
+        rows.flat_map(|y| {
+            columns.clone().map(move |x| {
+                // Round the edges to avoid seams between tiles
+                let x0 = (x as f64 * size - left).round() as f32;
+                let y0 = (y as f64 * size - top).round() as f32;
+                let x1 = ((x + 1) as f64 * size - left).round() as f32;
+                let y1 = ((y + 1) as f64 * size - top).round() as f32;
+
+                (
+                    Tile {
+                        zoom,
+                        x: x.rem_euclid(count) as u32,
+                        y: y as u32,
+                    },
+                    Rectangle {
+                        x: x0,
+                        y: y0,
+                        width: x1 - x0,
+                        height: y1 - y0,
+                    },
+                )
+            })
+        })
+        .collect()
+    }
+}
+
+/// The local state of a [`Map`].
+#[derive(Debug, Clone)]
+struct State {
+    center: World,
+    zoom: f32,
+    source: (Coordinate, f32),
+    drag: Option<Drag>,
+    requested: HashSet<Tile>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    center: World,
+    moved: bool,
+}
+
+impl State {
+    fn new(center: Coordinate, zoom: f32) -> Self {
+        Self {
+            center: center.to_world(),
+            zoom,
+            source: (center, zoom),
+            drag: None,
+            requested: HashSet::new(),
+        }
+    }
+
+    fn projection(&self, size: Size) -> Projection {
+        Projection {
+            center: self.center,
+            zoom: self.zoom,
+            size,
+        }
+    }
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>>
+    for Map<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.center, self.clamp_zoom(self.zoom)))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.source != (self.center, self.zoom) {
+            state.center = self.center.to_world();
+            state.zoom = self.clamp_zoom(self.zoom);
+            state.source = (self.center, self.zoom);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                let Some(on_request) = &self.on_request else {
+                    return event::Status::Ignored;
+                };
+
+                state.requested.retain(|tile| !self.tiles.contains(tile));
+
+                let missing: Vec<_> = state
+                    .projection(bounds.size())
+                    .tiles(self.max_zoom)
+                    .into_iter()
+                    .map(|(tile, _)| tile)
+                    .filter(|tile| {
+                        !self.tiles.contains(tile)
+                            && state.requested.insert(*tile)
+                    })
+                    .collect();
+
+                if !missing.is_empty() {
+                    shell.publish(on_request(missing));
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                let zoom = self.clamp_zoom(state.zoom + lines / 2.0);
+
+                if zoom != state.zoom {
+                    // Keep the coordinate below the cursor in place
+                    let anchor =
+                        state.projection(bounds.size()).world(position);
+                    let scale = scale(zoom);
+
+                    state.zoom = zoom;
+                    state.center = World {
+                        x: (anchor.x
+                            - f64::from(position.x - bounds.width / 2.0)
+                                / scale)
+                            .rem_euclid(1.0),
+                        y: (anchor.y
+                            - f64::from(position.y - bounds.height / 2.0)
+                                / scale)
+                            .max(0.0)
+                            .min(1.0),
+                    };
+
+                    self.publish_move(state, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.drag = Some(Drag {
+                    origin: position,
+                    center: state.center,
+                    moved: false,
+                });
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(drag) = &mut state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let delta =
+                    position - Vector::new(bounds.x, bounds.y) - drag.origin;
+
+                if !drag.moved && delta.x.hypot(delta.y) < CLICK_DISTANCE {
+                    return event::Status::Captured;
+                }
+
+                let scale = scale(state.zoom);
+
+                drag.moved = true;
+                state.center = World {
+                    x: (drag.center.x - f64::from(delta.x) / scale)
+                        .rem_euclid(1.0),
+                    y: (drag.center.y - f64::from(delta.y) / scale)
+                        .max(0.0)
+                        .min(1.0),
+                };
+
+                self.publish_move(state, shell);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(on_press) = &self.on_press {
+                    if !drag.moved {
+                        shell.publish(on_press(
+                            state
+                                .projection(bounds.size())
+                                .unproject(drag.origin),
+                        ));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.drag {
+            Some(Drag { moved: true, .. }) => mouse::Interaction::Grabbing,
+            _ if cursor.is_over(layout.bounds()) => mouse::Interaction::Grab,
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let projection = state.projection(bounds.size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            for (tile, area) in projection.tiles(self.max_zoom) {
+                let area = area + Vector::new(bounds.x, bounds.y);
+
+                if let Some(handle) = self.tiles.get(&tile) {
+                    image::Renderer::draw(
+                        renderer,
+                        image::Image::new(handle.clone(), area),
+                    );
+                } else if !self.tiles.contains(&tile) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: area,
+                            border_radius: 0.0.into(),
+                            border_width: 1.0,
+                            border_color: appearance.background,
+                            shadow: Shadow::default(),
+                        },
+                        appearance.placeholder,
+                    );
+                }
+            }
+        });
+
+        if self.layers.is_empty() && self.attribution.is_none() {
+            return;
+        }
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        for layer in &self.layers {
+            layer(&mut frame, &projection);
+        }
+
+        if let Some(attribution) = &self.attribution {
+            const PADDING: f32 = 4.0;
+
+            let size = Size::new(
+                attribution.chars().count() as f32 * TEXT_SIZE * 0.6
+                    + PADDING * 2.0,
+                TEXT_SIZE + PADDING * 2.0,
+            );
+
+            frame.fill_rectangle(
+                Point::new(
+                    bounds.width - size.width,
+                    bounds.height - size.height,
+                ),
+                size,
+                appearance.attribution_background,
+            );
+
+            frame.fill_text(Text {
+                content: attribution.clone(),
+                position: Point::new(
+                    bounds.width - PADDING,
+                    bounds.height - PADDING,
+                ),
+                color: appearance.attribution_text,
+                size: TEXT_SIZE.into(),
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Bottom,
+                ..Text::default()
+            });
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(bounds.x, bounds.y),
+                |renderer| {
+                    renderer.draw(vec![geometry]);
+                },
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<Map<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+{
+    fn from(map: Map<'a, Message, Theme>) -> Self {
+        Self::new(map)
+    }
+}
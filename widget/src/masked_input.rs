@@ -0,0 +1,529 @@
+//! Fill text fields following a format, like phone or credit card numbers.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle, Shell,
+};
+use crate::text_input::{self, TextInput};
+
+pub use crate::text_input::{Appearance, StyleSheet};
+
+/// A [`TextInput`] that inserts the separators of a [`Format`] while typing.
+///
+/// # Example
+/// ```no_run
+/// # type MaskedInput<'a, Message> =
+/// #     iced_widget::MaskedInput<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::masked_input::{Format, Masked};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PhoneChanged(Masked),
+/// }
+///
+/// let phone = "5551234";
+///
+/// let input = MaskedInput::new("Phone number", phone, Format::phone())
+///     .on_input(Message::PhoneChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MaskedInput<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<widget::Id>,
+    format: Format,
+    text_input: TextInput<'a, TextInputEvent, Renderer>,
+    on_input: Option<Box<dyn Fn(Masked) -> Message + 'a>>,
+    on_submit: Option<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum TextInputEvent {
+    TextChanged(String),
+    Submitted,
+}
+
+impl<'a, Message, Renderer> MaskedInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`MaskedInput`] with the given placeholder, raw value,
+    /// and [`Format`].
+    pub fn new(placeholder: &str, value: &str, format: Format) -> Self {
+        let formatted = format.format(&format.raw(value));
+
+        Self {
+            id: None,
+            text_input: TextInput::new(placeholder, &formatted),
+            format,
+            on_input: None,
+            on_submit: None,
+        }
+    }
+
+    /// Sets the [`text_input::Id`] of the [`MaskedInput`].
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id.clone().into());
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into
+    /// the [`MaskedInput`].
+    ///
+    /// If this method is not called, the [`MaskedInput`] will be disabled.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(Masked) -> Message + 'a,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self.text_input = self.text_input.on_input(TextInputEvent::TextChanged);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`MaskedInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self.text_input = self.text_input.on_submit(TextInputEvent::Submitted);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`MaskedInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the width of the [`MaskedInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MaskedInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`MaskedInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the [`text::LineHeight`] of the [`MaskedInput`].
+    pub fn line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_input = self.text_input.line_height(line_height);
+        self
+    }
+
+    /// Sets the style of the [`MaskedInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MaskedInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        Widget::<TextInputEvent, Renderer>::tag(&self.text_input)
+    }
+
+    fn state(&self) -> tree::State {
+        Widget::<TextInputEvent, Renderer>::state(&self.text_input)
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        Widget::<TextInputEvent, Renderer>::diff(&self.text_input, tree);
+    }
+
+    fn width(&self) -> Length {
+        Widget::<TextInputEvent, Renderer>::width(&self.text_input)
+    }
+
+    fn height(&self) -> Length {
+        Widget::<TextInputEvent, Renderer>::height(&self.text_input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.text_input.layout(tree, renderer, limits, None)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.focusable(state, self.id.as_ref());
+        operation.text_input(state, self.id.as_ref());
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        let status = self.text_input.on_event(
+            tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        for message in local_messages {
+            match message {
+                TextInputEvent::TextChanged(text) => {
+                    let state: &mut text_input::State<Renderer::Paragraph> =
+                        tree.state.downcast_mut();
+
+                    let position = match state
+                        .cursor()
+                        .state(&text_input::Value::new(&text))
+                    {
+                        text_input::cursor::State::Index(position) => position,
+                        text_input::cursor::State::Selection {
+                            end, ..
+                        } => end,
+                    };
+
+                    let raw = self.format.raw(&text);
+                    let formatted = self.format.format(&raw);
+
+                    // Keep the cursor after the same raw character, even if
+                    // separators were inserted or removed before it
+                    let raw_before = self
+                        .format
+                        .raw(&text.chars().take(position).collect::<String>())
+                        .chars()
+                        .count();
+
+                    state.move_cursor_to(
+                        self.format.position(&formatted, raw_before),
+                    );
+
+                    if let Some(on_input) = &self.on_input {
+                        shell.publish(on_input(Masked { raw, formatted }));
+                    }
+                }
+                TextInputEvent::Submitted => {
+                    if let Some(on_submit) = self.on_submit.clone() {
+                        shell.publish(on_submit);
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        self.text_input
+            .draw(tree, renderer, theme, layout, cursor, None);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<TextInputEvent, Renderer>::mouse_interaction(
+            &self.text_input,
+            tree,
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<MaskedInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(masked_input: MaskedInput<'a, Message, Renderer>) -> Self {
+        Element::new(masked_input)
+    }
+}
+
+/// The value of a [`MaskedInput`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Masked {
+    /// The value without any separators, as entered by the user.
+    pub raw: String,
+    /// The value as displayed, including the separators of its [`Format`].
+    pub formatted: String,
+}
+
+/// The format of a [`MaskedInput`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// A fixed pattern of characters.
+    ///
+    /// In a pattern, `#` stands for a digit, `A` for a letter, and `*` for
+    /// either. Letters are always entered in uppercase. Any other character
+    /// is a separator inserted automatically.
+    Pattern(String),
+    /// An amount of money, grouped in thousands.
+    Currency {
+        /// The symbol displayed before the amount.
+        symbol: String,
+        /// The maximum amount of decimal digits.
+        decimals: usize,
+    },
+}
+
+impl Format {
+    /// A phone number, like `(555) 123-4567`.
+    pub fn phone() -> Self {
+        Self::Pattern(String::from("(###) ###-####"))
+    }
+
+    /// A credit card number, like `4111 1111 1111 1111`.
+    pub fn credit_card() -> Self {
+        Self::Pattern(String::from("#### #### #### ####"))
+    }
+
+    /// An International Bank Account Number, like
+    /// `GB82 WEST 1234 5698 7654 32`.
+    pub fn iban() -> Self {
+        Self::Pattern(String::from("AA## **** **** **** **** **** **** **"))
+    }
+
+    /// An amount of money with two decimals, like `$1,234.56`.
+    pub fn currency(symbol: impl Into<String>) -> Self {
+        Self::Currency {
+            symbol: symbol.into(),
+            decimals: 2,
+        }
+    }
+
+    /// Extracts the raw value out of some text, dropping its separators and
+    /// any character not accepted by the [`Format`].
+    pub fn raw(&self, text: &str) -> String {
+        match self {
+            Self::Pattern(pattern) => {
+                let mut raw = String::new();
+                let mut slots = pattern.chars().peekable();
+
+                'text: for c in text.chars() {
+                    // Skip separators, consuming the ones actually typed
+                    while let Some(&slot) = slots.peek() {
+                        if is_slot(slot) {
+                            break;
+                        }
+
+                        let _ = slots.next();
+
+                        if slot == c {
+                            continue 'text;
+                        }
+                    }
+
+                    let Some(&slot) = slots.peek() else {
+                        break;
+                    };
+
+                    if accepts(slot, c) {
+                        raw.push(c.to_ascii_uppercase());
+                        let _ = slots.next();
+                    }
+                }
+
+                raw
+            }
+            Self::Currency { decimals, .. } => {
+                let mut raw = String::new();
+                let mut fraction = None;
+
+                for c in text.chars() {
+                    match c {
+                        '0'..='9' => match &mut fraction {
+                            Some(digits) if *digits >= *decimals => {}
+                            Some(digits) => {
+                                *digits += 1;
+                                raw.push(c);
+                            }
+                            None => {
+                                if raw == "0" {
+                                    raw.clear();
+                                }
+
+                                raw.push(c);
+                            }
+                        },
+                        '.' if fraction.is_none() && *decimals > 0 => {
+                            if raw.is_empty() {
+                                raw.push('0');
+                            }
+
+                            fraction = Some(0);
+                            raw.push(c);
+                        }
+                        _ => {}
+                    }
+                }
+
+                raw
+            }
+        }
+    }
+
+    /// Formats a raw value, inserting the separators of the [`Format`].
+    pub fn format(&self, raw: &str) -> String {
+        match self {
+            Self::Pattern(pattern) => {
+                let mut formatted = String::new();
+                let mut separators = String::new();
+                let mut raw = raw.chars();
+
+                for slot in pattern.chars() {
+                    if !is_slot(slot) {
+                        separators.push(slot);
+                        continue;
+                    }
+
+                    let Some(c) = raw.next() else {
+                        break;
+                    };
+
+                    // Separators only appear once the next slot is filled
+                    formatted.push_str(&separators);
+                    formatted.push(c);
+                    separators.clear();
+                }
+
+                formatted
+            }
+            Self::Currency { symbol, .. } => {
+                if raw.is_empty() {
+                    return String::new();
+                }
+
+                let (integer, fraction) = match raw.split_once('.') {
+                    Some((integer, fraction)) => (integer, Some(fraction)),
+                    None => (raw, None),
+                };
+
+                let mut formatted = symbol.clone();
+
+                for (i, digit) in integer.chars().enumerate() {
+                    if i > 0 && (integer.len() - i) % 3 == 0 {
+                        formatted.push(',');
+                    }
+
+                    formatted.push(digit);
+                }
+
+                if let Some(fraction) = fraction {
+                    formatted.push('.');
+                    formatted.push_str(fraction);
+                }
+
+                formatted
+            }
+        }
+    }
+
+    /// Returns the position in the formatted text right after the given
+    /// amount of raw characters.
+    fn position(&self, formatted: &str, raw_characters: usize) -> usize {
+        if raw_characters == 0 {
+            return 0;
+        }
+
+        (1..=formatted.chars().count())
+            .find(|&end| {
+                let prefix: String = formatted.chars().take(end).collect();
+
+                self.raw(&prefix).chars().count() >= raw_characters
+            })
+            .unwrap_or(usize::MAX)
+    }
+}
+
+fn is_slot(c: char) -> bool {
+    matches!(c, '#' | 'A' | '*')
+}
+
+fn accepts(slot: char, c: char) -> bool {
+    match slot {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_ascii_alphabetic(),
+        _ => c.is_ascii_alphanumeric(),
+    }
+}
@@ -0,0 +1,538 @@
+//! Open a menu of actions anchored to a button.
+//!
+//! A [`MenuButton`] has some local [`State`].
+use crate::button;
+use crate::container;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::token::{self, Step};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Rectangle, Shadow, Shell, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+use crate::scrollable;
+
+use std::fmt;
+
+/// A button that opens an anchored menu of [`Action`]s when pressed.
+///
+/// # Example
+/// ```no_run
+/// # type MenuButton<'a, Message> =
+/// #     iced_widget::MenuButton<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::menu_button::Action;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Cut,
+///     Copy,
+///     Paste,
+/// }
+///
+/// let menu_button = MenuButton::new("Edit", vec![
+///     Action::new("Cut", Message::Cut),
+///     Action::new("Copy", Message::Copy),
+///     Action::new("Paste", Message::Paste),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuButton<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    actions: Vec<Action<Message>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    menu_width: Option<f32>,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as button::StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> MenuButton<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    /// Creates a new [`MenuButton`] with the given content and the
+    /// [`Action`]s of its menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        actions: impl IntoIterator<Item = Action<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            actions: actions.into_iter().collect(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::new(token::spacing(Step::Sm)),
+            menu_width: None,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`MenuButton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`MenuButton`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MenuButton`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the menu of the [`MenuButton`].
+    ///
+    /// By default, the menu is as wide as the [`MenuButton`].
+    pub fn menu_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.menu_width = Some(width.into().0);
+        self
+    }
+
+    /// Sets the text size of the [`Action`]s of the menu.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Action`]s of the menu.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuButton`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as button::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`MenuButton`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+}
+
+/// An entry in the menu of a [`MenuButton`] or a [`SplitButton`].
+///
+/// [`SplitButton`]: crate::SplitButton
+#[derive(Debug, Clone, PartialEq)]
+pub struct Action<Message> {
+    label: String,
+    on_press: Message,
+}
+
+impl<Message> Action<Message> {
+    /// Creates a new [`Action`] with the given label and the message
+    /// produced when it is selected.
+    pub fn new(label: impl Into<String>, on_press: Message) -> Self {
+        Self {
+            label: label.into(),
+            on_press,
+        }
+    }
+}
+
+impl<Message> fmt::Display for Action<Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// The local state of a [`MenuButton`] or a [`SplitButton`].
+///
+/// [`SplitButton`]: crate::SplitButton
+#[derive(Debug, Default)]
+pub struct State {
+    menu: menu::State,
+    is_open: bool,
+    hovered_option: Option<usize>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// Processes a press on the given area and toggles the menu accordingly.
+///
+/// Presses outside of the area close the menu, since they were not captured
+/// by its overlay. Pressing Escape closes it as well.
+pub fn update(
+    event: &Event,
+    area: Rectangle,
+    cursor: mouse::Cursor,
+    is_enabled: bool,
+    state: &mut State,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if state.is_open {
+                state.is_open = false;
+
+                event::Status::Captured
+            } else if is_enabled && cursor.is_over(area) {
+                state.is_open = true;
+                state.hovered_option = None;
+
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            ..
+        }) if state.is_open => {
+            state.is_open = false;
+
+            event::Status::Captured
+        }
+        _ => event::Status::Ignored,
+    }
+}
+
+/// Draws a segment of a button with the given [`button::Appearance`]
+/// resolved for its interaction state.
+pub fn draw_segment<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    cursor: mouse::Cursor,
+    is_enabled: bool,
+    is_pressed: bool,
+    border_radius: [f32; 4],
+    theme: &Renderer::Theme,
+    style: &<Renderer::Theme as button::StyleSheet>::Style,
+) -> button::Appearance
+where
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: button::StyleSheet,
+{
+    use button::StyleSheet;
+
+    let appearance = if !is_enabled {
+        theme.disabled(style)
+    } else if is_pressed {
+        theme.pressed(style)
+    } else if cursor.is_over(bounds) {
+        theme.hovered(style)
+    } else {
+        theme.active(style)
+    };
+
+    if appearance.background.is_some() || appearance.border_width > 0.0 {
+        let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+            appearance.border_radius.into();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: [
+                    top_left.min(border_radius[0]),
+                    top_right.min(border_radius[1]),
+                    bottom_right.min(border_radius[2]),
+                    bottom_left.min(border_radius[3]),
+                ]
+                .into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+                shadow: Shadow::default(),
+            },
+            appearance
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+    }
+
+    appearance
+}
+
+/// Produces the menu overlay of the given [`Action`]s, if open.
+pub fn overlay<'a, Message, Renderer>(
+    layout: Layout<'_>,
+    state: &'a mut State,
+    actions: &'a [Action<Message>],
+    width: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as menu::StyleSheet>::Style,
+) -> Option<overlay::Element<'a, Message, Renderer>>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        menu::StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    if !state.is_open || actions.is_empty() {
+        return None;
+    }
+
+    let bounds = layout.bounds();
+
+    let mut menu = Menu::new(
+        &mut state.menu,
+        actions,
+        &mut state.hovered_option,
+        |action: Action<Message>| {
+            state.is_open = false;
+
+            action.on_press
+        },
+        None,
+    )
+    .width(width)
+    .padding(padding)
+    .style(style);
+
+    if let Some(text_size) = text_size {
+        menu = menu.text_size(text_size);
+    }
+
+    if let Some(font) = font {
+        menu = menu.font(font);
+    }
+
+    Some(menu.overlay(layout.position(), bounds.height))
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MenuButton<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        button::layout(
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            |limits| {
+                self.content.as_widget().layout(
+                    &mut tree.children[0],
+                    renderer,
+                    limits,
+                )
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        update(
+            &event,
+            layout.bounds(),
+            cursor,
+            !self.actions.is_empty(),
+            tree.state.downcast_mut::<State>(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = draw_segment(
+            renderer,
+            bounds,
+            cursor,
+            !self.actions.is_empty(),
+            state.is_open,
+            [f32::INFINITY; 4],
+            theme,
+            &self.style,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        button::mouse_interaction(layout, cursor, !self.actions.is_empty())
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        if state.is_open {
+            overlay(
+                layout,
+                state,
+                &self.actions,
+                self.menu_width.unwrap_or(layout.bounds().width),
+                self.padding,
+                self.text_size,
+                self.font,
+                self.menu_style.clone(),
+            )
+        } else {
+            self.content.as_widget_mut().overlay(
+                &mut children[0],
+                layout.children().next().unwrap(),
+                renderer,
+            )
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuButton<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn from(menu_button: MenuButton<'a, Message, Renderer>) -> Self {
+        Self::new(menu_button)
+    }
+}
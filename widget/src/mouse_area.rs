@@ -21,6 +21,7 @@ pub struct MouseArea<'a, Message, Renderer> {
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
     on_middle_release: Option<Message>,
+    interaction: Option<mouse::Interaction>,
 }
 
 impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
@@ -65,6 +66,14 @@ impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
         self.on_middle_release = Some(message);
         self
     }
+
+    /// The [`mouse::Interaction`] to show while the [`MouseArea`] is hovered,
+    /// replacing the one of its content.
+    #[must_use]
+    pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
+        self.interaction = Some(interaction);
+        self
+    }
 }
 
 /// Local state of the [`MouseArea`].
@@ -84,6 +93,7 @@ impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
             on_right_release: None,
             on_middle_press: None,
             on_middle_release: None,
+            interaction: None,
         }
     }
 }
@@ -179,6 +189,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if let Some(interaction) = self.interaction {
+            if cursor.is_over(layout.bounds()) {
+                return interaction;
+            }
+        }
+
         self.content.as_widget().mouse_interaction(
             &tree.children[0],
             layout,
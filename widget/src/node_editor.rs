@@ -0,0 +1,1019 @@
+//! Edit graphs of nodes connected through their ports.
+//!
+//! A [`NodeEditor`] displays the [`Node`]s and [`Connection`]s owned by the
+//! application and reports the changes made by the user as [`Change`]s,
+//! leaving the application in charge of applying them.
+use crate::canvas::{Frame, Path, Stroke};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::text::{self, Renderer as _};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+
+pub use crate::style::node_editor::{Appearance, StyleSheet};
+
+/// The default width of a [`Node`].
+const NODE_WIDTH: f32 = 160.0;
+
+/// The height of the header of a [`Node`].
+const HEADER_HEIGHT: f32 = 26.0;
+
+/// The height of a row of ports of a [`Node`].
+const ROW_HEIGHT: f32 = 22.0;
+
+/// The radius of a port.
+const PORT_RADIUS: f32 = 5.0;
+
+/// The spacing between the lines of the background grid.
+const GRID_SPACING: f32 = 24.0;
+
+/// The size of the text of a [`Node`].
+const TEXT_SIZE: f32 = 13.0;
+
+/// The minimum and maximum zoom levels.
+const ZOOM_RANGE: (f32, f32) = (0.25, 2.0);
+
+/// An editor of graphs made of [`Node`]s and [`Connection`]s.
+///
+/// The user can:
+/// - drag nodes around by their body,
+/// - connect an output port to an input port of the same kind by dragging
+///   from one to the other,
+/// - detach a connection by dragging it away from its input port,
+/// - select nodes by clicking them—holding `Shift` to add to the
+///   selection—or with a selection box by dragging the background,
+/// - delete the selected nodes with `Delete` or `Backspace`,
+/// - pan by dragging with the middle or right mouse button, and
+/// - zoom around the cursor with the mouse wheel.
+#[allow(missing_debug_implementations)]
+pub struct NodeEditor<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    nodes: &'a [Node],
+    connections: &'a [Connection],
+    width: Length,
+    height: Length,
+    on_change: Option<Box<dyn Fn(Change) -> Message + 'a>>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> NodeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`NodeEditor`] displaying the given [`Node`]s and
+    /// [`Connection`]s.
+    pub fn new(nodes: &'a [Node], connections: &'a [Connection]) -> Self {
+        Self {
+            nodes,
+            connections,
+            width: Length::Fill,
+            height: Length::Fill,
+            on_change: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`NodeEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`NodeEditor`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that should be produced when the user changes the
+    /// graph.
+    ///
+    /// The graph is read-only if this is not set.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Change) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the style of the [`NodeEditor`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the topmost [`Target`] at the given position of the graph.
+    fn target(&self, position: Point, zoom: f32) -> Option<Target> {
+        let radius = (PORT_RADIUS + 3.0) / zoom.min(1.0);
+
+        self.nodes
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, node)| {
+                let port = |sockets: &mut dyn Iterator<
+                    Item = (usize, Point),
+                >| {
+                    sockets
+                        .find(|(_, point)| point.distance(position) <= radius)
+                        .map(|(port, _)| Socket { node: index, port })
+                };
+
+                if let Some(socket) = port(&mut node.output_positions()) {
+                    Some(Target::Output(socket))
+                } else if let Some(socket) = port(&mut node.input_positions()) {
+                    Some(Target::Input(socket))
+                } else if node.bounds().contains(position) {
+                    Some(Target::Node(index))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Returns the [`Port`] of the given output [`Socket`].
+    fn output(&self, socket: Socket) -> Option<(&Port, Point)> {
+        let node = self.nodes.get(socket.node)?;
+
+        Some((
+            node.outputs.get(socket.port)?,
+            node.output_position(socket.port),
+        ))
+    }
+
+    /// Returns the [`Port`] of the given input [`Socket`].
+    fn input(&self, socket: Socket) -> Option<(&Port, Point)> {
+        let node = self.nodes.get(socket.node)?;
+
+        Some((
+            node.inputs.get(socket.port)?,
+            node.input_position(socket.port),
+        ))
+    }
+
+    fn publish(&self, change: Change, shell: &mut Shell<'_, Message>) {
+        if let Some(on_change) = &self.on_change {
+            shell.publish(on_change(change));
+        }
+    }
+}
+
+/// A node of a [`NodeEditor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    title: String,
+    position: Point,
+    width: f32,
+    inputs: Vec<Port>,
+    outputs: Vec<Port>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given title at the given position of
+    /// the graph.
+    pub fn new(title: impl Into<String>, position: Point) -> Self {
+        Self {
+            title: title.into(),
+            position,
+            width: NODE_WIDTH,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Adds an input [`Port`] to the [`Node`].
+    pub fn input(mut self, port: Port) -> Self {
+        self.inputs.push(port);
+        self
+    }
+
+    /// Adds an output [`Port`] to the [`Node`].
+    pub fn output(mut self, port: Port) -> Self {
+        self.outputs.push(port);
+        self
+    }
+
+    /// Sets the width of the [`Node`].
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the position of the [`Node`] in the graph.
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// Moves the [`Node`] by the given amount.
+    pub fn translate(&mut self, delta: Vector) {
+        self.position = self.position + delta;
+    }
+
+    fn bounds(&self) -> Rectangle {
+        let rows = self.inputs.len().max(self.outputs.len()) as f32;
+
+        Rectangle::new(
+            self.position,
+            Size::new(self.width, HEADER_HEIGHT + rows * ROW_HEIGHT + 6.0),
+        )
+    }
+
+    fn row(&self, index: usize) -> f32 {
+        self.position.y + HEADER_HEIGHT + ROW_HEIGHT * (index as f32 + 0.5)
+    }
+
+    fn input_positions(&self) -> impl Iterator<Item = (usize, Point)> + '_ {
+        (0..self.inputs.len()).map(|index| (index, self.input_position(index)))
+    }
+
+    fn output_positions(&self) -> impl Iterator<Item = (usize, Point)> + '_ {
+        (0..self.outputs.len())
+            .map(|index| (index, self.output_position(index)))
+    }
+
+    fn input_position(&self, index: usize) -> Point {
+        Point::new(self.position.x, self.row(index))
+    }
+
+    fn output_position(&self, index: usize) -> Point {
+        Point::new(self.position.x + self.width, self.row(index))
+    }
+}
+
+/// A port of a [`Node`].
+///
+/// Only ports of the same kind can be connected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Port {
+    label: String,
+    kind: String,
+}
+
+impl Port {
+    /// Creates a new [`Port`] with the given label and kind.
+    pub fn new(label: impl Into<String>, kind: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            kind: kind.into(),
+        }
+    }
+
+    /// Returns the kind of the [`Port`].
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn color(&self, appearance: &Appearance) -> Color {
+        let mut hasher = DefaultHasher::new();
+        self.kind.hash(&mut hasher);
+
+        appearance.ports[hasher.finish() as usize % appearance.ports.len()]
+    }
+}
+
+/// A port of a specific [`Node`], identified by their indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Socket {
+    /// The index of the [`Node`].
+    pub node: usize,
+    /// The index of the [`Port`] among the inputs or the outputs of the
+    /// [`Node`].
+    pub port: usize,
+}
+
+/// A connection from an output [`Socket`] to an input [`Socket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Connection {
+    /// The output [`Socket`].
+    pub from: Socket,
+    /// The input [`Socket`].
+    pub to: Socket,
+}
+
+/// A change of the graph of a [`NodeEditor`] made by the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// Some nodes were moved by the given amount.
+    Moved {
+        /// The indices of the moved nodes.
+        nodes: Vec<usize>,
+        /// The amount the nodes were moved by.
+        delta: Vector,
+    },
+    /// A [`Connection`] was made.
+    ///
+    /// The input may already be connected; in which case the application
+    /// decides whether to replace the existing [`Connection`].
+    Connected(Connection),
+    /// A [`Connection`] was removed.
+    Disconnected(Connection),
+    /// Some nodes were deleted, along with their connections.
+    Deleted(Vec<usize>),
+}
+
+/// What the cursor is pointing at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Node(usize),
+    Input(Socket),
+    Output(Socket),
+}
+
+/// The local state of a [`NodeEditor`].
+#[derive(Debug, Clone)]
+struct State {
+    offset: Vector,
+    zoom: f32,
+    selection: BTreeSet<usize>,
+    interaction: Interaction,
+    is_focused: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Default)]
+enum Interaction {
+    #[default]
+    Idle,
+    Panning {
+        origin: Point,
+        offset: Vector,
+    },
+    Moving {
+        last: Point,
+    },
+    Connecting {
+        from: Socket,
+        cursor: Point,
+    },
+    Selecting {
+        origin: Point,
+        current: Point,
+        base: BTreeSet<usize>,
+    },
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            offset: Vector::new(0.0, 0.0),
+            zoom: 1.0,
+            selection: BTreeSet::new(),
+            interaction: Interaction::Idle,
+            is_focused: false,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+impl State {
+    /// Converts a position of the widget into a position of the graph.
+    fn to_graph(&self, bounds: Rectangle, position: Point) -> Point {
+        let local = position - Vector::new(bounds.x, bounds.y) - self.offset;
+
+        Point::new(local.x / self.zoom, local.y / self.zoom)
+    }
+
+    /// Converts a position of the graph into a position relative to the
+    /// top left corner of the widget.
+    fn to_local(&self, point: Point) -> Point {
+        Point::new(
+            point.x * self.zoom + self.offset.x,
+            point.y * self.zoom + self.offset.y,
+        )
+    }
+}
+
+/// Returns the rectangle spanned by two points.
+fn span(a: Point, b: Point) -> Rectangle {
+    Rectangle {
+        x: a.x.min(b.x),
+        y: a.y.min(b.y),
+        width: (a.x - b.x).abs(),
+        height: (a.y - b.y).abs(),
+    }
+}
+
+/// Returns the cubic bezier curve connecting an output to an input.
+fn wire(from: Point, to: Point) -> Path {
+    let handle = ((to.x - from.x).abs() / 2.0).max(40.0);
+
+    Path::new(|builder| {
+        builder.move_to(from);
+        builder.bezier_curve_to(
+            Point::new(from.x + handle, from.y),
+            Point::new(to.x - handle, to.y),
+            to,
+        );
+    })
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>>
+    for NodeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Forget about deleted nodes
+        state.selection.retain(|index| *index < self.nodes.len());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let point = state.to_graph(bounds, position);
+                state.is_focused = true;
+
+                state.interaction = match self.target(point, state.zoom) {
+                    Some(Target::Output(from)) if self.on_change.is_some() => {
+                        Interaction::Connecting {
+                            from,
+                            cursor: position,
+                        }
+                    }
+                    Some(Target::Input(to)) if self.on_change.is_some() => {
+                        // Detach the connection of the input, if any
+                        match self
+                            .connections
+                            .iter()
+                            .rev()
+                            .find(|connection| connection.to == to)
+                        {
+                            Some(connection) => {
+                                self.publish(
+                                    Change::Disconnected(*connection),
+                                    shell,
+                                );
+
+                                Interaction::Connecting {
+                                    from: connection.from,
+                                    cursor: position,
+                                }
+                            }
+                            None => Interaction::Idle,
+                        }
+                    }
+                    Some(
+                        Target::Node(node)
+                        | Target::Input(Socket { node, .. })
+                        | Target::Output(Socket { node, .. }),
+                    ) => {
+                        if state.modifiers.shift() {
+                            if !state.selection.remove(&node) {
+                                let _ = state.selection.insert(node);
+                            }
+                        } else if !state.selection.contains(&node) {
+                            state.selection = BTreeSet::from([node]);
+                        }
+
+                        if state.selection.contains(&node) {
+                            Interaction::Moving { last: point }
+                        } else {
+                            Interaction::Idle
+                        }
+                    }
+                    None => {
+                        if !state.modifiers.shift() {
+                            state.selection.clear();
+                        }
+
+                        Interaction::Selecting {
+                            origin: point,
+                            current: point,
+                            base: state.selection.clone(),
+                        }
+                    }
+                };
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle | mouse::Button::Right,
+            )) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.interaction = Interaction::Panning {
+                    origin: position,
+                    offset: state.offset,
+                };
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let point = state.to_graph(bounds, position);
+
+                match &mut state.interaction {
+                    Interaction::Idle => return event::Status::Ignored,
+                    Interaction::Panning { origin, offset } => {
+                        state.offset = *offset + (position - *origin);
+                    }
+                    Interaction::Moving { last } => {
+                        let delta = point - *last;
+                        *last = point;
+
+                        if self.on_change.is_some() {
+                            self.publish(
+                                Change::Moved {
+                                    nodes: state
+                                        .selection
+                                        .iter()
+                                        .copied()
+                                        .collect(),
+                                    delta,
+                                },
+                                shell,
+                            );
+                        }
+                    }
+                    Interaction::Connecting { cursor, .. } => {
+                        *cursor = position;
+                    }
+                    Interaction::Selecting {
+                        origin,
+                        current,
+                        base,
+                    } => {
+                        *current = point;
+
+                        let area = span(*origin, *current);
+
+                        state.selection = base
+                            .iter()
+                            .copied()
+                            .chain(
+                                self.nodes
+                                    .iter()
+                                    .enumerate()
+                                    .filter(|(_, node)| {
+                                        node.bounds().intersects(&area)
+                                    })
+                                    .map(|(index, _)| index),
+                            )
+                            .collect();
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_)) => {
+                match std::mem::take(&mut state.interaction) {
+                    Interaction::Idle => event::Status::Ignored,
+                    Interaction::Connecting { from, cursor } => {
+                        let point = state.to_graph(bounds, cursor);
+
+                        if let Some(Target::Input(to)) =
+                            self.target(point, state.zoom)
+                        {
+                            let is_compatible =
+                                match (self.output(from), self.input(to)) {
+                                    (Some((output, _)), Some((input, _))) => {
+                                        from.node != to.node
+                                            && output.kind == input.kind
+                                    }
+                                    _ => false,
+                                };
+
+                            if is_compatible {
+                                self.publish(
+                                    Change::Connected(Connection { from, to }),
+                                    shell,
+                                );
+                            }
+                        }
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Captured,
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                let zoom = (state.zoom * 1.1_f32.powf(lines))
+                    .max(ZOOM_RANGE.0)
+                    .min(ZOOM_RANGE.1);
+
+                // Keep the point below the cursor in place
+                let point = Point::new(
+                    (position.x - state.offset.x) / state.zoom,
+                    (position.y - state.offset.y) / state.zoom,
+                );
+
+                state.offset = Vector::new(
+                    position.x - point.x * zoom,
+                    position.y - point.y * zoom,
+                );
+                state.zoom = zoom;
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code:
+                    keyboard::KeyCode::Delete | keyboard::KeyCode::Backspace,
+                ..
+            }) if state.is_focused
+                && !state.selection.is_empty()
+                && self.on_change.is_some() =>
+            {
+                let nodes = std::mem::take(&mut state.selection);
+
+                self.publish(
+                    Change::Deleted(nodes.into_iter().collect()),
+                    shell,
+                );
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        match state.interaction {
+            Interaction::Panning { .. } | Interaction::Moving { .. } => {
+                return mouse::Interaction::Grabbing;
+            }
+            Interaction::Connecting { .. } => {
+                return mouse::Interaction::Crosshair;
+            }
+            _ => {}
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        match self.target(state.to_graph(bounds, position), state.zoom) {
+            Some(Target::Input(_) | Target::Output(_))
+                if self.on_change.is_some() =>
+            {
+                mouse::Interaction::Crosshair
+            }
+            Some(_) => mouse::Interaction::Grab,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+        let zoom = state.zoom;
+        let translation = Vector::new(bounds.x, bounds.y);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        // Grid and connections
+        let mut frame = Frame::new(renderer, bounds.size());
+        let spacing = GRID_SPACING * zoom;
+
+        if spacing >= 8.0 {
+            let grid = Stroke::default()
+                .with_color(appearance.grid)
+                .with_width(1.0);
+
+            let mut x = state.offset.x.rem_euclid(spacing);
+
+            while x < bounds.width {
+                frame.stroke(
+                    &Path::line(
+                        Point::new(x, 0.0),
+                        Point::new(x, bounds.height),
+                    ),
+                    grid.clone(),
+                );
+
+                x += spacing;
+            }
+
+            let mut y = state.offset.y.rem_euclid(spacing);
+
+            while y < bounds.height {
+                frame.stroke(
+                    &Path::line(
+                        Point::new(0.0, y),
+                        Point::new(bounds.width, y),
+                    ),
+                    grid.clone(),
+                );
+
+                y += spacing;
+            }
+        }
+
+        for connection in self.connections {
+            let (Some((port, from)), Some((_, to))) =
+                (self.output(connection.from), self.input(connection.to))
+            else {
+                continue;
+            };
+
+            frame.stroke(
+                &wire(state.to_local(from), state.to_local(to)),
+                Stroke::default()
+                    .with_color(port.color(&appearance))
+                    .with_width(2.0 * zoom),
+            );
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                renderer.draw(vec![geometry]);
+            });
+        });
+
+        // Nodes, each in its own layer so that they overlap properly
+        let font = renderer.default_font();
+        let text_size = Pixels(TEXT_SIZE * zoom);
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let area = node.bounds();
+            let top_left = state.to_local(area.position()) + translation;
+            let area = Rectangle::new(
+                top_left,
+                Size::new(area.width * zoom, area.height * zoom),
+            );
+
+            if !area.intersects(&bounds) {
+                continue;
+            }
+
+            let is_selected = state.selection.contains(&index);
+            let radius = 4.0 * zoom;
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: area,
+                        border_radius: radius.into(),
+                        border_width: if is_selected { 2.0 } else { 1.0 },
+                        border_color: if is_selected {
+                            appearance.selection
+                        } else {
+                            appearance.node_border
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    appearance.node_background,
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            height: HEADER_HEIGHT * zoom,
+                            ..area
+                        },
+                        border_radius: [radius, radius, 0.0, 0.0].into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    },
+                    appearance.header_background,
+                );
+
+                let label = |renderer: &mut Renderer<Theme>,
+                             content: &str,
+                             position: Point,
+                             alignment: alignment::Horizontal,
+                             color: Color| {
+                    renderer.fill_text(
+                        text::Text {
+                            content,
+                            bounds: Size::new(area.width, ROW_HEIGHT * zoom),
+                            size: text_size,
+                            line_height: text::LineHeight::default(),
+                            font,
+                            horizontal_alignment: alignment,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                        },
+                        position,
+                        color,
+                    );
+                };
+
+                label(
+                    renderer,
+                    &node.title,
+                    Point::new(
+                        area.x + 8.0 * zoom,
+                        area.y + HEADER_HEIGHT * zoom / 2.0,
+                    ),
+                    alignment::Horizontal::Left,
+                    appearance.header_text,
+                );
+
+                let port = |renderer: &mut Renderer<Theme>,
+                            center: Point,
+                            color: Color| {
+                    let radius = PORT_RADIUS * zoom;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x - radius,
+                                y: center.y - radius,
+                                width: radius * 2.0,
+                                height: radius * 2.0,
+                            },
+                            border_radius: radius.into(),
+                            border_width: 1.0,
+                            border_color: appearance.node_background,
+                            shadow: Shadow::default(),
+                        },
+                        color,
+                    );
+                };
+
+                for (index, point) in node.input_positions() {
+                    let input = &node.inputs[index];
+                    let center = state.to_local(point) + translation;
+
+                    port(renderer, center, input.color(&appearance));
+                    label(
+                        renderer,
+                        &input.label,
+                        Point::new(center.x + 10.0 * zoom, center.y),
+                        alignment::Horizontal::Left,
+                        appearance.text,
+                    );
+                }
+
+                for (index, point) in node.output_positions() {
+                    let output = &node.outputs[index];
+                    let center = state.to_local(point) + translation;
+
+                    port(renderer, center, output.color(&appearance));
+                    label(
+                        renderer,
+                        &output.label,
+                        Point::new(center.x - 10.0 * zoom, center.y),
+                        alignment::Horizontal::Right,
+                        appearance.text,
+                    );
+                }
+            });
+        }
+
+        // Pending connection and selection box
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        match &state.interaction {
+            Interaction::Connecting { from, cursor } => {
+                if let Some((port, point)) = self.output(*from) {
+                    frame.stroke(
+                        &wire(state.to_local(point), *cursor - translation),
+                        Stroke::default()
+                            .with_color(port.color(&appearance))
+                            .with_width(2.0 * zoom),
+                    );
+                }
+            }
+            Interaction::Selecting {
+                origin, current, ..
+            } => {
+                let area =
+                    span(state.to_local(*origin), state.to_local(*current));
+                let path = Path::rectangle(area.position(), area.size());
+
+                frame.fill(&path, appearance.selection_background);
+                frame.stroke(
+                    &path,
+                    Stroke::default()
+                        .with_color(appearance.selection)
+                        .with_width(1.0),
+                );
+            }
+            _ => {}
+        }
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                renderer.draw(vec![geometry]);
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme> From<NodeEditor<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+{
+    fn from(node_editor: NodeEditor<'a, Message, Theme>) -> Self {
+        Self::new(node_editor)
+    }
+}
@@ -2,19 +2,25 @@
 use crate::container::{self, Container};
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
-use crate::core::widget::Tree;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::{Id, Tree};
 use crate::core::{
-    Clipboard, Color, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+    Clipboard, Color, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Vector,
 };
 use crate::core::{Element, Shell, Widget};
 use crate::scrollable::{self, Scrollable};
 
+use std::cell::Cell;
+
 pub use iced_style::menu::{Appearance, StyleSheet};
 
 /// A list of selectable options.
@@ -35,6 +41,7 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    keyboard_navigation: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -67,6 +74,7 @@ where
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
             font: None,
+            keyboard_navigation: true,
             style: Default::default(),
         }
     }
@@ -119,6 +127,19 @@ where
         self
     }
 
+    /// Sets whether the [`Menu`] handles the keyboard by itself.
+    ///
+    /// When enabled (the default), the arrow keys, Home, End, Page Up and
+    /// Page Down move the hovered option, Enter and Space select it, and
+    /// typing jumps to the next option starting with the typed text.
+    ///
+    /// Disable it when the owner of the [`Menu`] needs the keyboard, like
+    /// a text input filtering the options.
+    pub fn keyboard_navigation(mut self, enabled: bool) -> Self {
+        self.keyboard_navigation = enabled;
+        self
+    }
+
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
@@ -141,6 +162,8 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    search: Search,
+    scroll_to_hovered: bool,
 }
 
 impl State {
@@ -148,8 +171,19 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            search: Search::default(),
+            scroll_to_hovered: false,
         }
     }
+
+    /// Scrolls the [`Menu`] to its hovered option the next time it processes
+    /// an event.
+    ///
+    /// This is useful when the hovered option is changed from outside of the
+    /// [`Menu`]; e.g. by its owner while it has keyboard focus.
+    pub fn scroll_to_hovered(&mut self) {
+        self.scroll_to_hovered = true;
+    }
 }
 
 impl Default for State {
@@ -158,6 +192,34 @@ impl Default for State {
     }
 }
 
+/// The text typed to jump to an option of a [`Menu`].
+#[derive(Debug, Default)]
+struct Search {
+    query: String,
+    last_typed: Option<Instant>,
+}
+
+impl Search {
+    /// The time after which typing starts a new query.
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    fn is_typing(&self, now: Instant) -> bool {
+        !self.query.is_empty()
+            && self.last_typed.map_or(false, |last_typed| {
+                now.saturating_duration_since(last_typed) < Self::TIMEOUT
+            })
+    }
+
+    fn push(&mut self, c: char, now: Instant) {
+        if !self.is_typing(now) {
+            self.query.clear();
+        }
+
+        self.query.extend(c.to_lowercase());
+        self.last_typed = Some(now);
+    }
+}
+
 struct Overlay<'a, Message, Renderer>
 where
     Renderer: crate::core::Renderer,
@@ -165,8 +227,13 @@ where
 {
     state: &'a mut Tree,
     container: Container<'a, Message, Renderer>,
+    hovered_option: &'a Cell<Option<usize>>,
+    scroll_to_hovered: &'a Cell<bool>,
     width: f32,
     target_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -197,12 +264,24 @@ where
             text_size,
             text_line_height,
             text_shaping,
+            keyboard_navigation,
             style,
         } = menu;
 
+        let State {
+            tree,
+            search,
+            scroll_to_hovered,
+        } = state;
+
+        let hovered_option = Cell::from_mut(hovered_option);
+        let scroll_to_hovered = Cell::from_mut(scroll_to_hovered);
+
         let container = Container::new(Scrollable::new(List {
             options,
             hovered_option,
+            scroll_to_hovered,
+            search,
             on_selected,
             on_option_hovered,
             font,
@@ -210,16 +289,22 @@ where
             text_line_height,
             text_shaping,
             padding,
+            keyboard_navigation,
             style: style.clone(),
         }));
 
-        state.tree.diff(&container as &dyn Widget<_, _>);
+        tree.diff(&container as &dyn Widget<_, _>);
 
         Self {
-            state: &mut state.tree,
+            state: tree,
             container,
+            hovered_option,
+            scroll_to_hovered,
             width,
             target_height,
+            padding,
+            text_size,
+            text_line_height,
             style,
         }
     }
@@ -275,10 +360,35 @@ where
     ) -> event::Status {
         let bounds = layout.bounds();
 
-        self.container.on_event(
+        let status = self.container.on_event(
             self.state, event, layout, cursor, renderer, clipboard, shell,
             &bounds,
-        )
+        );
+
+        if self.scroll_to_hovered.take() {
+            if let Some(index) = self.hovered_option.get() {
+                let option_height = option_height(
+                    renderer,
+                    self.text_size,
+                    self.text_line_height,
+                    self.padding,
+                );
+
+                let top = option_height * index as f32;
+
+                self.container.operate(
+                    self.state,
+                    layout,
+                    renderer,
+                    &mut ScrollIntoView {
+                        top,
+                        bottom: top + option_height,
+                    },
+                );
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -309,6 +419,7 @@ where
                 border_color: appearance.border_color,
                 border_width: appearance.border_width,
                 border_radius: appearance.border_radius,
+                shadow: Shadow::default(),
             },
             appearance.background,
         );
@@ -324,7 +435,9 @@ where
     Renderer::Theme: StyleSheet,
 {
     options: &'a [T],
-    hovered_option: &'a mut Option<usize>,
+    hovered_option: &'a Cell<Option<usize>>,
+    scroll_to_hovered: &'a Cell<bool>,
+    search: &'a mut Search,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
     padding: Padding,
@@ -332,9 +445,68 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    keyboard_navigation: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
+impl<'a, T, Message, Renderer> List<'a, T, Message, Renderer>
+where
+    T: Clone + ToString,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn hover(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_option_hovered) = self.on_option_hovered {
+            if self.hovered_option.get() != Some(index) {
+                if let Some(option) = self.options.get(index) {
+                    shell.publish(on_option_hovered(option.clone()));
+                }
+            }
+        }
+
+        self.hovered_option.set(Some(index));
+        self.scroll_to_hovered.set(true);
+    }
+
+    fn select(&mut self, shell: &mut Shell<'_, Message>) -> event::Status {
+        if let Some(index) = self.hovered_option.get() {
+            if let Some(option) = self.options.get(index) {
+                shell.publish((self.on_selected)(option.clone()));
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Finds the option matching the current search query.
+    ///
+    /// Typing the same character repeatedly cycles through the options
+    /// starting with it.
+    fn find(&self) -> Option<usize> {
+        let mut characters = self.search.query.chars();
+        let first = characters.next()?;
+        let is_repeated = characters.all(|c| c == first);
+
+        let (query, skip) = if is_repeated {
+            (first.to_string(), 1)
+        } else {
+            (self.search.query.clone(), 0)
+        };
+
+        let start = self.hovered_option.get().map_or(0, |index| index + skip);
+
+        (0..self.options.len())
+            .map(|i| (start + i) % self.options.len())
+            .find(|index| {
+                self.options[*index]
+                    .to_string()
+                    .to_lowercase()
+                    .starts_with(&query)
+            })
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for List<'a, T, Message, Renderer>
 where
@@ -386,12 +558,12 @@ where
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if cursor.is_over(layout.bounds()) {
-                    if let Some(index) = *self.hovered_option {
+                    if let Some(index) = self.hovered_option.get() {
                         if let Some(option) = self.options.get(index) {
                             shell.publish((self.on_selected)(option.clone()));
                             return event::Status::Captured;
@@ -415,7 +587,8 @@ where
                         (cursor_position.y / option_height) as usize;
 
                     if let Some(on_option_hovered) = self.on_option_hovered {
-                        if *self.hovered_option != Some(new_hovered_option) {
+                        if self.hovered_option.get() != Some(new_hovered_option)
+                        {
                             if let Some(option) =
                                 self.options.get(new_hovered_option)
                             {
@@ -425,7 +598,7 @@ where
                         }
                     }
 
-                    *self.hovered_option = Some(new_hovered_option);
+                    self.hovered_option.set(Some(new_hovered_option));
                 }
             }
             Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -440,10 +613,11 @@ where
                         f32::from(self.text_line_height.to_absolute(text_size))
                             + self.padding.vertical();
 
-                    *self.hovered_option =
-                        Some((cursor_position.y / option_height) as usize);
+                    self.hovered_option.set(Some(
+                        (cursor_position.y / option_height) as usize,
+                    ));
 
-                    if let Some(index) = *self.hovered_option {
+                    if let Some(index) = self.hovered_option.get() {
                         if let Some(option) = self.options.get(index) {
                             shell.publish((self.on_selected)(option.clone()));
                             return event::Status::Captured;
@@ -451,6 +625,72 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.keyboard_navigation && !self.options.is_empty() => {
+                let last = self.options.len() - 1;
+                let hovered_option = self.hovered_option.get();
+
+                let page = {
+                    let option_height = option_height(
+                        renderer,
+                        self.text_size,
+                        self.text_line_height,
+                        self.padding,
+                    );
+
+                    ((viewport.height / option_height) as usize).max(1)
+                };
+
+                let index = match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        return self.select(shell);
+                    }
+                    keyboard::KeyCode::Space
+                        if !self.search.is_typing(Instant::now()) =>
+                    {
+                        return self.select(shell);
+                    }
+                    keyboard::KeyCode::Up => {
+                        hovered_option.map_or(last, |i| i.saturating_sub(1))
+                    }
+                    keyboard::KeyCode::Down => {
+                        hovered_option.map_or(0, |i| (i + 1).min(last))
+                    }
+                    keyboard::KeyCode::PageUp => {
+                        hovered_option.map_or(0, |i| i.saturating_sub(page))
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        hovered_option.map_or(0, |i| (i + page).min(last))
+                    }
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    _ => return event::Status::Ignored,
+                };
+
+                self.hover(index, shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.keyboard_navigation && !c.is_control() =>
+            {
+                let now = Instant::now();
+
+                // A space only selects when not typing, see above
+                if c == ' ' && !self.search.is_typing(now) {
+                    return event::Status::Ignored;
+                }
+
+                self.search.push(c, now);
+
+                if let Some(index) = self.find() {
+                    self.hover(index, shell);
+                }
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -501,7 +741,7 @@ where
 
         for (i, option) in visible_options.iter().enumerate() {
             let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
+            let is_selected = self.hovered_option.get() == Some(i);
 
             let bounds = Rectangle {
                 x: bounds.x,
@@ -521,6 +761,7 @@ where
                         border_color: Color::TRANSPARENT,
                         border_width: 0.0,
                         border_radius: appearance.border_radius,
+                        shadow: Shadow::default(),
                     },
                     appearance.selected_background,
                 );
@@ -560,3 +801,55 @@ where
         Element::new(list)
     }
 }
+
+fn option_height<Renderer>(
+    renderer: &Renderer,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    padding: Padding,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let text_size = text_size.unwrap_or_else(|| renderer.default_size());
+
+    f32::from(text_line_height.to_absolute(text_size)) + padding.vertical()
+}
+
+/// Scrolls the list of a [`Menu`] just enough to show the given span.
+struct ScrollIntoView {
+    top: f32,
+    bottom: f32,
+}
+
+impl<T> Operation<T> for ScrollIntoView {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let y = if self.top < translation.y {
+            self.top
+        } else if self.bottom > translation.y + bounds.height {
+            self.bottom - bounds.height
+        } else {
+            return;
+        };
+
+        state.scroll_to(operation::scrollable::AbsoluteOffset {
+            x: translation.x,
+            y,
+        });
+    }
+}
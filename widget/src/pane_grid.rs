@@ -42,8 +42,8 @@ use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
-    Size, Vector, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Vector, Widget,
 };
 
 /// A collection of panes distributed using either vertical or horizontal splits
@@ -892,6 +892,7 @@ pub fn draw<Renderer, T>(
                                     .border_radius,
                                 border_width: hovered_region_style.border_width,
                                 border_color: hovered_region_style.border_color,
+                                shadow: Shadow::default(),
                             },
                             theme.hovered_region(style).background,
                         );
@@ -921,6 +922,7 @@ pub fn draw<Renderer, T>(
                 border_radius: hovered_region_style.border_radius,
                 border_width: hovered_region_style.border_width,
                 border_color: hovered_region_style.border_color,
+                shadow: Shadow::default(),
             },
             theme.hovered_region(style).background,
         );
@@ -984,6 +986,7 @@ pub fn draw<Renderer, T>(
                         border_radius: 0.0.into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
                     },
                     highlight.color,
                 );
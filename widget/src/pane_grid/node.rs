@@ -7,6 +7,8 @@ use std::collections::BTreeMap;
 ///
 /// [`PaneGrid`]: super::PaneGrid
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde"))]
 pub enum Node {
     /// The region of this [`Node`] is split into two.
     Split {
@@ -64,6 +64,45 @@ impl<T> State<T> {
         }
     }
 
+    /// Creates a new [`State`] with the given layout [`Node`], initializing
+    /// the state of each of its panes with the provided function.
+    ///
+    /// This is useful to restore a layout that was saved before.
+    pub fn with_layout(layout: Node, mut f: impl FnMut(Pane) -> T) -> Self {
+        fn visit(
+            node: &Node,
+            last_id: &mut usize,
+            on_pane: &mut dyn FnMut(Pane),
+        ) {
+            match node {
+                Node::Split { id, a, b, .. } => {
+                    *last_id = (*last_id).max(id.0);
+
+                    visit(a, last_id, on_pane);
+                    visit(b, last_id, on_pane);
+                }
+                Node::Pane(pane) => {
+                    *last_id = (*last_id).max(pane.0);
+
+                    on_pane(*pane);
+                }
+            }
+        }
+
+        let mut panes = HashMap::new();
+        let mut last_id = 0;
+
+        visit(&layout, &mut last_id, &mut |pane| {
+            let _ = panes.insert(pane, f(pane));
+        });
+
+        State {
+            panes,
+            internal: Internal { layout, last_id },
+            maximized: None,
+        }
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -1,5 +1,6 @@
 //! Display a dropdown list of selectable values.
 use crate::container;
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -8,14 +9,18 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::token::{self, Step};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
-    Shell, Size, Widget,
+    Shadow, Shell, Size, Widget,
 };
+use crate::focus_ring;
 use crate::overlay::menu::{self, Menu};
 use crate::scrollable;
+use crate::style::focus;
 
 use std::borrow::Cow;
 
@@ -40,6 +45,7 @@ where
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    tab_index: i32,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -55,7 +61,10 @@ where
     <Renderer::Theme as menu::StyleSheet>::Style:
         From<<Renderer::Theme as StyleSheet>::Style>,
 {
-    /// The default padding of a [`PickList`].
+    /// The default padding of a [`PickList`] with the comfortable
+    /// [`Tokens`].
+    ///
+    /// [`Tokens`]: crate::core::token::Tokens
     pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
 
     /// Creates a new [`PickList`] with the given list of options, the current
@@ -71,12 +80,13 @@ where
             placeholder: None,
             selected,
             width: Length::Shrink,
-            padding: Self::DEFAULT_PADDING,
+            padding: Padding::new(token::spacing(Step::Sm)),
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
             font: None,
             handle: Handle::default(),
+            tab_index: 0,
             style: Default::default(),
         }
     }
@@ -132,6 +142,16 @@ where
         self
     }
 
+    /// Sets the position of the [`PickList`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     pub fn style(
         mut self,
@@ -152,7 +172,8 @@ where
     Renderer::Theme: StyleSheet
         + scrollable::StyleSheet
         + menu::StyleSheet
-        + container::StyleSheet,
+        + container::StyleSheet
+        + focus::StyleSheet,
     <Renderer::Theme as menu::StyleSheet>::Style:
         From<<Renderer::Theme as StyleSheet>::Style>,
 {
@@ -193,6 +214,31 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(
+            &mut operation::focusable::with_tab_index(state, self.tab_index),
+            None,
+        );
+
+        let mut node = accessibility::Node::new(accessibility::Role::ComboBox);
+
+        if let Some(selected) = &self.selected {
+            node = node.value(selected.to_string());
+        } else if let Some(placeholder) = &self.placeholder {
+            node = node.label(placeholder.clone());
+        }
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -254,6 +300,14 @@ where
             &self.style,
             || tree.state.downcast_ref::<State<Renderer::Paragraph>>(),
         );
+
+        if tree
+            .state
+            .downcast_ref::<State<Renderer::Paragraph>>()
+            .is_focused
+        {
+            focus_ring::draw(renderer, layout.bounds(), theme);
+        }
     }
 
     fn overlay<'b>(
@@ -288,7 +342,8 @@ where
     Renderer::Theme: StyleSheet
         + scrollable::StyleSheet
         + menu::StyleSheet
-        + container::StyleSheet,
+        + container::StyleSheet
+        + focus::StyleSheet,
     <Renderer::Theme as menu::StyleSheet>::Style:
         From<<Renderer::Theme as StyleSheet>::Style>,
 {
@@ -303,6 +358,7 @@ pub struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     options: Vec<P>,
     placeholder: P,
@@ -315,6 +371,7 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: P::default(),
@@ -328,6 +385,20 @@ impl<P: text::Paragraph> Default for State<P> {
     }
 }
 
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -483,6 +554,9 @@ where
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
 
+            // Pointer interactions hide the focus ring
+            state.is_focused = false;
+
             if state.is_open {
                 // Event wasn't processed by overlay, so cursor was clicked either outside it's
                 // bounds or on the drop-down, either way we close the overlay.
@@ -493,6 +567,7 @@ where
                 state.is_open = true;
                 state.hovered_option =
                     options.iter().position(|option| Some(option) == selected);
+                state.menu.scroll_to_hovered();
 
                 event::Status::Captured
             } else {
@@ -549,6 +624,40 @@ where
 
             event::Status::Ignored
         }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code:
+                keyboard::KeyCode::Enter
+                | keyboard::KeyCode::Space
+                | keyboard::KeyCode::Down,
+            ..
+        }) => {
+            let state = state();
+
+            if state.is_focused && !state.is_open {
+                state.is_open = true;
+                state.hovered_option =
+                    options.iter().position(|option| Some(option) == selected);
+                state.menu.scroll_to_hovered();
+
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            ..
+        }) => {
+            let state = state();
+
+            if state.is_open {
+                state.is_open = false;
+
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
         _ => event::Status::Ignored,
     }
 }
@@ -658,6 +767,7 @@ pub fn draw<'a, T, Renderer>(
             border_color: style.border_color,
             border_width: style.border_width,
             border_radius: style.border_radius,
+            shadow: Shadow::default(),
         },
         style.background,
     );
@@ -699,9 +809,21 @@ pub fn draw<'a, T, Renderer>(
         Handle::None => None,
     };
 
+    // The handle and the label switch sides in right to left layouts
+    let is_rtl = layout::Direction::current().is_rtl();
+
     if let Some((font, code_point, size, line_height, shaping)) = handle {
         let size = size.unwrap_or_else(|| renderer.default_size());
 
+        let (horizontal_alignment, x) = if is_rtl {
+            (alignment::Horizontal::Left, bounds.x + padding.horizontal())
+        } else {
+            (
+                alignment::Horizontal::Right,
+                bounds.x + bounds.width - padding.horizontal(),
+            )
+        };
+
         renderer.fill_text(
             Text {
                 content: &code_point.to_string(),
@@ -712,14 +834,11 @@ pub fn draw<'a, T, Renderer>(
                     bounds.width,
                     f32::from(line_height.to_absolute(size)),
                 ),
-                horizontal_alignment: alignment::Horizontal::Right,
+                horizontal_alignment,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping,
             },
-            Point::new(
-                bounds.x + bounds.width - padding.horizontal(),
-                bounds.center_y(),
-            ),
+            Point::new(x, bounds.center_y()),
             style.handle_color,
         );
     }
@@ -729,6 +848,15 @@ pub fn draw<'a, T, Renderer>(
     if let Some(label) = label.as_deref().or(placeholder) {
         let text_size = text_size.unwrap_or_else(|| renderer.default_size());
 
+        let (horizontal_alignment, x) = if is_rtl {
+            (
+                alignment::Horizontal::Right,
+                bounds.x + bounds.width - padding.left,
+            )
+        } else {
+            (alignment::Horizontal::Left, bounds.x + padding.left)
+        };
+
         renderer.fill_text(
             Text {
                 content: label,
@@ -739,11 +867,11 @@ pub fn draw<'a, T, Renderer>(
                     bounds.width - padding.horizontal(),
                     f32::from(text_line_height.to_absolute(text_size)),
                 ),
-                horizontal_alignment: alignment::Horizontal::Left,
+                horizontal_alignment,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text_shaping,
             },
-            Point::new(bounds.x + padding.left, bounds.center_y()),
+            Point::new(x, bounds.center_y()),
             if is_selected {
                 style.text_color
             } else {
@@ -0,0 +1,514 @@
+//! Enter short codes, like PINs or one-time passwords, character by character.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Widget,
+};
+use crate::runtime::Command;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// A row of character boxes used to enter a code of a fixed length.
+///
+/// The focus advances automatically to the next box as characters are typed,
+/// and pasting fills as many boxes as possible at once.
+///
+/// # Example
+/// ```no_run
+/// # type PinInput<'a, Message> =
+/// #     iced_widget::PinInput<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CodeChanged(String),
+///     CodeCompleted(String),
+/// }
+///
+/// let code = "12";
+///
+/// let pin_input = PinInput::new(6, code, Message::CodeChanged)
+///     .on_complete(Message::CodeCompleted);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PinInput<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    length: usize,
+    value: Vec<char>,
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    on_complete: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    is_masked: bool,
+    is_numeric: bool,
+    size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> PinInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of a box of a [`PinInput`].
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// The default spacing between the boxes of a [`PinInput`].
+    pub const DEFAULT_SPACING: f32 = 8.0;
+
+    /// Creates a new [`PinInput`].
+    ///
+    /// It expects:
+    ///   * the amount of characters of the code
+    ///   * the current value of the code
+    ///   * a function that produces a message when the code changes
+    pub fn new(
+        length: usize,
+        value: &str,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            length,
+            value: value.chars().take(length).collect(),
+            on_input: Box::new(on_input),
+            on_complete: None,
+            is_masked: false,
+            is_numeric: true,
+            size: Self::DEFAULT_SIZE,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`PinInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the message that should be produced when every box of the
+    /// [`PinInput`] has been filled.
+    pub fn on_complete(
+        mut self,
+        on_complete: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_complete = Some(Box::new(on_complete));
+        self
+    }
+
+    /// Sets whether the characters of the [`PinInput`] should be masked.
+    pub fn masked(mut self, is_masked: bool) -> Self {
+        self.is_masked = is_masked;
+        self
+    }
+
+    /// Sets whether the [`PinInput`] only accepts digits.
+    ///
+    /// By default, only digits are accepted. Otherwise, any alphanumeric
+    /// character is.
+    pub fn numeric(mut self, is_numeric: bool) -> Self {
+        self.is_numeric = is_numeric;
+        self
+    }
+
+    /// Sets the size of each box of the [`PinInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between the boxes of the [`PinInput`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`PinInput`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`PinInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`PinInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        if self.is_numeric {
+            c.is_ascii_digit()
+        } else {
+            c.is_alphanumeric()
+        }
+    }
+
+    fn cell(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + (self.size + self.spacing) * index as f32,
+            y: bounds.y,
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    /// Appends the accepted characters to the value, publishing the
+    /// resulting messages.
+    fn append(
+        &mut self,
+        characters: impl IntoIterator<Item = char>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let remaining = self.length.saturating_sub(self.value.len());
+
+        let characters: Vec<char> = characters
+            .into_iter()
+            .filter(|c| self.accepts(*c))
+            .take(remaining)
+            .collect();
+
+        if characters.is_empty() {
+            return false;
+        }
+
+        self.value.extend(characters);
+        self.publish(shell);
+
+        true
+    }
+
+    fn publish(&self, shell: &mut Shell<'_, Message>) {
+        let value: String = self.value.iter().collect();
+
+        shell.publish((self.on_input)(value.clone()));
+
+        if self.value.len() == self.length {
+            if let Some(on_complete) = &self.on_complete {
+                shell.publish(on_complete(value));
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for PinInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = self.size * self.length as f32
+            + self.spacing * self.length.saturating_sub(1) as f32;
+
+        layout::Node::new(limits.resolve(Size::new(width, self.size)))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                if state.is_focused {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused =>
+            {
+                if !state.keyboard_modifiers.command()
+                    && !c.is_control()
+                    && self.append([c], shell)
+                {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                match key_code {
+                    keyboard::KeyCode::Backspace => {
+                        if !self.value.is_empty() {
+                            if state.keyboard_modifiers.command() {
+                                self.value.clear();
+                            } else {
+                                let _ = self.value.pop();
+                            }
+
+                            self.publish(shell);
+                        }
+                    }
+                    keyboard::KeyCode::V
+                        if state.keyboard_modifiers.command()
+                            && !state.keyboard_modifiers.alt() =>
+                    {
+                        let content = clipboard.read().unwrap_or_default();
+
+                        let _ = self.append(
+                            content.chars().filter(|c| !c.is_whitespace()),
+                            shell,
+                        );
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                        state.keyboard_modifiers =
+                            keyboard::Modifiers::default();
+                    }
+                    keyboard::KeyCode::Tab
+                    | keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        // The box receiving the next character
+        let active = self.value.len().min(self.length.saturating_sub(1));
+        let value_color = theme.value_color(&self.style);
+
+        for index in 0..self.length {
+            let cell = self.cell(bounds, index);
+
+            let appearance = if state.is_focused && index == active {
+                theme.focused(&self.style)
+            } else if cursor.is_over(cell) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: cell,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+
+            if let Some(c) = self.value.get(index) {
+                let content = if self.is_masked {
+                    String::from('•')
+                } else {
+                    c.to_string()
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: &content,
+                        bounds: cell.size(),
+                        size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                    },
+                    Point::new(cell.center_x(), cell.center_y()),
+                    value_color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<PinInput<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(pin_input: PinInput<'a, Message, Renderer>) -> Self {
+        Element::new(pin_input)
+    }
+}
+
+/// The identifier of a [`PinInput`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`PinInput`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// The local state of a [`PinInput`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_focused: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`PinInput`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`PinInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`PinInput`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`PinInput`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self);
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self);
+    }
+}
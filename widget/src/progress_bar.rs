@@ -1,9 +1,13 @@
 //! Provide progress feedback to your users.
+use crate::core::accessibility;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::widget::Operation;
 use crate::core::widget::Tree;
-use crate::core::{Color, Element, Layout, Length, Rectangle, Size, Widget};
+use crate::core::{
+    Color, Element, Layout, Length, Rectangle, Shadow, Size, Widget,
+};
 
 use std::ops::RangeInclusive;
 
@@ -108,6 +112,25 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let node =
+            accessibility::Node::new(accessibility::Role::ProgressIndicator)
+                .numeric(
+                    f64::from(self.value),
+                    f64::from(*self.range.start())
+                        ..=f64::from(*self.range.end()),
+                    None,
+                );
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -136,6 +159,7 @@ where
                 border_radius: style.border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
             },
             style.background,
         );
@@ -150,6 +174,7 @@ where
                     border_radius: style.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 },
                 style.bar,
             );
@@ -1,17 +1,22 @@
 //! Create choices using radio buttons.
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::token::{self, Step};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Color, Element, Layout, Length, Pixels, Rectangle, Shell, Size,
-    Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Rectangle, Shadow,
+    Shell, Size, Widget,
 };
+use crate::focus_ring;
+use crate::style::focus;
 
 pub use iced_style::radio::{Appearance, StyleSheet};
 
@@ -84,6 +89,7 @@ where
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    tab_index: i32,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -96,7 +102,10 @@ where
     /// The default size of a [`Radio`] button.
     pub const DEFAULT_SIZE: f32 = 28.0;
 
-    /// The default spacing of a [`Radio`] button.
+    /// The default spacing of a [`Radio`] button with the comfortable
+    /// [`Tokens`].
+    ///
+    /// [`Tokens`]: crate::core::token::Tokens
     pub const DEFAULT_SPACING: f32 = 15.0;
 
     /// Creates a new [`Radio`] button.
@@ -123,11 +132,12 @@ where
             label: label.into(),
             width: Length::Shrink,
             size: Self::DEFAULT_SIZE,
-            spacing: Self::DEFAULT_SPACING, //15
+            spacing: token::spacing(Step::Lg),
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
             font: None,
+            tab_index: 0,
             style: Default::default(),
         }
     }
@@ -177,6 +187,16 @@ where
         self
     }
 
+    /// Sets the position of the [`Radio`] button in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the style of the [`Radio`] button.
     pub fn style(
         mut self,
@@ -191,14 +211,14 @@ impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
 where
     Message: Clone,
     Renderer: text::Renderer,
-    Renderer::Theme: StyleSheet + crate::text::StyleSheet,
+    Renderer::Theme: StyleSheet + crate::text::StyleSheet + focus::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn width(&self) -> Length {
@@ -220,12 +240,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -242,9 +261,33 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(
+            &mut widget::operation::focusable::with_tab_index(
+                state,
+                self.tab_index,
+            ),
+            None,
+        );
+
+        let node = accessibility::Node::new(accessibility::Role::RadioButton)
+            .label(self.label.clone())
+            .toggled(self.is_selected);
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -253,15 +296,28 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Pointer interactions hide the focus ring
+                state.is_focused = false;
+
                 if cursor.is_over(layout.bounds()) {
                     shell.publish(self.on_click.clone());
 
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            }) if state.is_focused => {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -293,6 +349,7 @@ where
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let is_mouse_over = cursor.is_over(layout.bounds());
 
         let mut children = layout.children();
@@ -316,10 +373,15 @@ where
                     border_radius: (size / 2.0).into(),
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    shadow: Shadow::default(),
                 },
                 custom_style.background,
             );
 
+            if state.is_focused {
+                focus_ring::draw(renderer, bounds, theme);
+            }
+
             if self.is_selected {
                 renderer.fill_quad(
                     renderer::Quad {
@@ -332,6 +394,7 @@ where
                         border_radius: (dot_size / 2.0).into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
                     },
                     custom_style.dot_color,
                 );
@@ -345,7 +408,7 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance {
                     color: custom_style.text_color,
                 },
@@ -359,9 +422,29 @@ impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
 where
     Message: 'a + Clone,
     Renderer: 'a + text::Renderer,
-    Renderer::Theme: StyleSheet + crate::text::StyleSheet,
+    Renderer::Theme: StyleSheet + crate::text::StyleSheet + focus::StyleSheet,
 {
     fn from(radio: Radio<Message, Renderer>) -> Element<'a, Message, Renderer> {
         Element::new(radio)
     }
 }
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+}
+
+impl<P: text::Paragraph> widget::operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
@@ -1,4 +1,5 @@
 //! Distribute content horizontally.
+use crate::core::cascade;
 use crate::core::event::{self, Event};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
@@ -13,7 +14,7 @@ use crate::core::{
 /// A container that distributes its contents horizontally.
 #[allow(missing_debug_implementations)]
 pub struct Row<'a, Message, Renderer = crate::Renderer> {
-    spacing: f32,
+    spacing: Option<f32>,
     padding: Padding,
     width: Length,
     height: Length,
@@ -32,7 +33,7 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         children: Vec<Element<'a, Message, Renderer>>,
     ) -> Self {
         Row {
-            spacing: 0.0,
+            spacing: None,
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -47,7 +48,7 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
     /// method instead! While less flexible, it helps you keep spacing between
     /// elements consistent.
     pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
-        self.spacing = amount.into().0;
+        self.spacing = Some(amount.into().0);
         self
     }
 
@@ -125,7 +126,7 @@ where
             renderer,
             &limits,
             self.padding,
-            self.spacing,
+            self.spacing.or_else(cascade::spacing).unwrap_or(0.0),
             self.align_items,
             &self.children,
             &mut tree.children,
@@ -4,7 +4,7 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::Tree;
 use crate::core::{
-    Color, Element, Layout, Length, Pixels, Rectangle, Size, Widget,
+    Color, Element, Layout, Length, Pixels, Rectangle, Shadow, Size, Widget,
 };
 
 pub use crate::style::rule::{Appearance, FillMode, StyleSheet};
@@ -130,6 +130,7 @@ where
                 border_radius: style.radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
             },
             style.color,
         );
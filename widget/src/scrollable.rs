@@ -5,13 +5,15 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Size, Vector, Widget,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
 };
 use crate::runtime::Command;
 
@@ -30,6 +32,7 @@ where
     width: Length,
     height: Length,
     direction: Direction,
+    kinetic: Option<Kinetic>,
     content: Element<'a, Message, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     style: <Renderer::Theme as StyleSheet>::Style,
@@ -47,6 +50,7 @@ where
             width: Length::Shrink,
             height: Length::Shrink,
             direction: Direction::default(),
+            kinetic: None,
             content: content.into(),
             on_scroll: None,
             style: Default::default(),
@@ -77,6 +81,17 @@ where
         self
     }
 
+    /// Enables [`Kinetic`] scrolling of the [`Scrollable`] for touch and
+    /// trackpad input.
+    ///
+    /// Kinetic scrolling keeps the momentum of the content after the fingers
+    /// are lifted and bounces it back when pulled past its edges. By default,
+    /// scrolling stops as soon as the fingers are lifted.
+    pub fn kinetic(mut self, kinetic: impl Into<Option<Kinetic>>) -> Self {
+        self.kinetic = kinetic.into();
+        self
+    }
+
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
     /// The function takes the [`Viewport`] of the [`Scrollable`]
@@ -198,6 +213,48 @@ pub enum Alignment {
     End,
 }
 
+/// The kinetic scrolling behavior of a [`Scrollable`] for touch and trackpad
+/// input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Kinetic {
+    deceleration: f32,
+    overscroll: f32,
+}
+
+impl Default for Kinetic {
+    fn default() -> Self {
+        Self {
+            deceleration: 0.998,
+            overscroll: 120.0,
+        }
+    }
+}
+
+impl Kinetic {
+    /// Creates new [`Kinetic`] properties for use in a [`Scrollable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the deceleration rate of the [`Kinetic`] scrolling.
+    ///
+    /// This is the ratio of velocity that is kept after every millisecond of
+    /// momentum. Lower values make the content stop sooner.
+    pub fn deceleration(mut self, deceleration: f32) -> Self {
+        self.deceleration = deceleration.clamp(0.0, 0.9999);
+        self
+    }
+
+    /// Sets the maximum distance the content can be pulled past its edges
+    /// before bouncing back.
+    ///
+    /// An overscroll of `0` disables the bounce effect.
+    pub fn overscroll(mut self, overscroll: impl Into<Pixels>) -> Self {
+        self.overscroll = overscroll.into().0.max(0.0);
+        self
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Scrollable<'a, Message, Renderer>
 where
@@ -305,6 +362,7 @@ where
             clipboard,
             shell,
             self.direction,
+            self.kinetic,
             &self.on_scroll,
             |event, layout, cursor, clipboard, shell, viewport| {
                 self.content.as_widget_mut().on_event(
@@ -504,6 +562,7 @@ pub fn update<Message>(
     clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
     direction: Direction,
+    kinetic: Option<Kinetic>,
     on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
     update_content: impl FnOnce(
         Event,
@@ -571,7 +630,7 @@ pub fn update<Message>(
                 return event::Status::Ignored;
             }
 
-            let delta = match delta {
+            let (delta, is_precise) = match delta {
                 mouse::ScrollDelta::Lines { x, y } => {
                     // TODO: Configurable speed/friction (?)
                     let movement = if state.keyboard_modifiers.shift() {
@@ -580,13 +639,30 @@ pub fn update<Message>(
                         Vector::new(x, y)
                     };
 
-                    movement * 60.0
+                    (movement * 60.0, false)
+                }
+                mouse::ScrollDelta::Pixels { x, y } => {
+                    (Vector::new(x, y), true)
                 }
-                mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
             };
 
+            if state.animated_at.is_some() {
+                state.stop_momentum();
+            }
+
             state.scroll(delta, direction, bounds, content_bounds);
 
+            // Precise deltas come from trackpads, which fling the content
+            // once the fingers stop scrolling
+            if is_precise && kinetic.is_some() {
+                state.track_velocity(delta, Instant::now());
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            } else {
+                state.velocity = Vector::new(0.0, 0.0);
+                state.moved_at = None;
+            }
+
             notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
 
             return event::Status::Captured;
@@ -602,6 +678,9 @@ pub fn update<Message>(
                     };
 
                     state.scroll_area_touched_at = Some(cursor_position);
+                    state.moved_at = Some(Instant::now());
+                    state.velocity = Vector::new(0.0, 0.0);
+                    state.animated_at = None;
                 }
                 touch::Event::FingerMoved { .. } => {
                     if let Some(scroll_box_touched_at) =
@@ -616,9 +695,16 @@ pub fn update<Message>(
                             cursor_position.y - scroll_box_touched_at.y,
                         );
 
-                        state.scroll(delta, direction, bounds, content_bounds);
+                        state.scroll_with_overscroll(
+                            delta,
+                            direction,
+                            bounds,
+                            content_bounds,
+                            kinetic.map_or(0.0, |kinetic| kinetic.overscroll),
+                        );
 
                         state.scroll_area_touched_at = Some(cursor_position);
+                        state.track_velocity(delta, Instant::now());
 
                         notify_on_scroll(
                             state,
@@ -631,12 +717,83 @@ pub fn update<Message>(
                 }
                 touch::Event::FingerLifted { .. }
                 | touch::Event::FingerLost { .. } => {
-                    state.scroll_area_touched_at = None;
+                    if state.scroll_area_touched_at.take().is_none() {
+                        return event::Status::Captured;
+                    }
+
+                    let is_flung = state.moved_at.is_some_and(|at| {
+                        at.elapsed().as_millis() < FLING_TIMEOUT_MILLIS
+                    });
+
+                    if kinetic.is_none() || !is_flung {
+                        state.velocity = Vector::new(0.0, 0.0);
+                    }
+
+                    state.moved_at = None;
+
+                    if state.is_animating() {
+                        state.animated_at = Some(Instant::now());
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
                 }
             }
 
             return event::Status::Captured;
         }
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            let Some(kinetic) = kinetic else {
+                return event::Status::Ignored;
+            };
+
+            // Trackpads do not report their fingers being lifted, so they
+            // are considered lifted once they stop scrolling for a while
+            if let (None, Some(moved_at)) =
+                (state.scroll_area_touched_at, state.moved_at)
+            {
+                if now.saturating_duration_since(moved_at).as_millis()
+                    < FLING_TIMEOUT_MILLIS
+                {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Ignored;
+                }
+
+                state.moved_at = None;
+
+                if state.is_animating() {
+                    state.animated_at = Some(now);
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                return event::Status::Ignored;
+            }
+
+            let Some(animated_at) = state.animated_at else {
+                return event::Status::Ignored;
+            };
+
+            state.animate(
+                (now - animated_at).as_secs_f32(),
+                kinetic,
+                direction,
+                bounds,
+                content_bounds,
+            );
+
+            notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+
+            if state.is_animating() {
+                state.animated_at = Some(now);
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            } else {
+                state.animated_at = None;
+            }
+
+            return event::Status::Ignored;
+        }
         _ => {}
     }
 
@@ -699,6 +856,7 @@ pub fn update<Message>(
                     );
 
                     state.y_scroller_grabbed_at = Some(scroller_grabbed_at);
+                    shell.capture_pointer();
 
                     notify_on_scroll(
                         state,
@@ -774,6 +932,7 @@ pub fn update<Message>(
                     );
 
                     state.x_scroller_grabbed_at = Some(scroller_grabbed_at);
+                    shell.capture_pointer();
 
                     notify_on_scroll(
                         state,
@@ -868,7 +1027,8 @@ pub fn draw<Renderer>(
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
         scrollbars.is_mouse_over(cursor);
 
-    let translation = state.translation(direction, bounds, content_bounds);
+    let translation =
+        state.translation(direction, bounds, content_bounds) + state.overscroll;
 
     let cursor = match cursor_over_scrollable {
         Some(cursor_position)
@@ -916,6 +1076,7 @@ pub fn draw<Renderer>(
                             border_radius: style.border_radius,
                             border_width: style.border_width,
                             border_color: style.border_color,
+                            shadow: Shadow::default(),
                         },
                         style
                             .background
@@ -936,6 +1097,7 @@ pub fn draw<Renderer>(
                             border_radius: style.scroller.border_radius,
                             border_width: style.scroller.border_width,
                             border_color: style.scroller.border_color,
+                            shadow: Shadow::default(),
                         },
                         style.scroller.color,
                     );
@@ -1037,10 +1199,24 @@ fn notify_on_scroll<Message>(
     }
 }
 
+/// The amount of time after the last finger movement during which lifting
+/// the finger still flings the content.
+const FLING_TIMEOUT_MILLIS: u128 = 100;
+
+/// The speed, in pixels per second, below which kinetic scrolling stops.
+const MIN_FLING_VELOCITY: f32 = 10.0;
+
+/// The rate at which overscrolled content bounces back to its edges.
+const BOUNCE_STIFFNESS: f32 = 12.0;
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
     scroll_area_touched_at: Option<Point>,
+    moved_at: Option<Instant>,
+    velocity: Vector,
+    overscroll: Vector,
+    animated_at: Option<Instant>,
     offset_y: Offset,
     y_scroller_grabbed_at: Option<f32>,
     offset_x: Offset,
@@ -1053,6 +1229,10 @@ impl Default for State {
     fn default() -> Self {
         Self {
             scroll_area_touched_at: None,
+            moved_at: None,
+            velocity: Vector::new(0.0, 0.0),
+            overscroll: Vector::new(0.0, 0.0),
+            animated_at: None,
             offset_y: Offset::Absolute(0.0),
             y_scroller_grabbed_at: None,
             offset_x: Offset::Absolute(0.0),
@@ -1243,14 +1423,169 @@ impl State {
         self.unsnap(bounds, content_bounds);
     }
 
+    /// Applies a scrolling offset like [`State::scroll`], but turns any
+    /// movement past the edges of the content into overscroll of up to
+    /// `max_overscroll` pixels.
+    fn scroll_with_overscroll(
+        &mut self,
+        delta: Vector<f32>,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        max_overscroll: f32,
+    ) {
+        // The content translation always moves against the delta
+        let mut movement = Vector::new(-delta.x, -delta.y);
+
+        // Undo any existing overscroll before scrolling the content
+        let recover = |overscroll: &mut f32, movement: &mut f32| {
+            let recovered = if *overscroll < 0.0 {
+                (*overscroll + *movement).min(0.0)
+            } else if *overscroll > 0.0 {
+                (*overscroll + *movement).max(0.0)
+            } else {
+                return;
+            };
+
+            *movement -= recovered - *overscroll;
+            *overscroll = recovered;
+        };
+
+        recover(&mut self.overscroll.x, &mut movement.x);
+        recover(&mut self.overscroll.y, &mut movement.y);
+
+        let before = self.translation(direction, bounds, content_bounds);
+
+        self.scroll(
+            Vector::new(-movement.x, -movement.y),
+            direction,
+            bounds,
+            content_bounds,
+        );
+
+        let after = self.translation(direction, bounds, content_bounds);
+
+        if max_overscroll <= 0.0 {
+            return;
+        }
+
+        let rubber_band = |overscroll: &mut f32, remaining: f32| {
+            let resistance = 1.0 - overscroll.abs() / max_overscroll;
+
+            *overscroll = (*overscroll + remaining * resistance.max(0.0))
+                .clamp(-max_overscroll, max_overscroll);
+        };
+
+        if direction.horizontal().is_some()
+            && content_bounds.width > bounds.width
+        {
+            rubber_band(
+                &mut self.overscroll.x,
+                movement.x - (after.x - before.x),
+            );
+        }
+
+        if direction.vertical().is_some()
+            && content_bounds.height > bounds.height
+        {
+            rubber_band(
+                &mut self.overscroll.y,
+                movement.y - (after.y - before.y),
+            );
+        }
+    }
+
+    /// Advances the momentum and the overscroll bounce of the [`State`] by
+    /// the given amount of seconds.
+    fn animate(
+        &mut self,
+        elapsed: f32,
+        kinetic: Kinetic,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.scroll_with_overscroll(
+            self.velocity * elapsed,
+            direction,
+            bounds,
+            content_bounds,
+            kinetic.overscroll,
+        );
+
+        let millis = elapsed * 1000.0;
+        let decelerate = |velocity: f32, overscroll: f32| {
+            // Overscrolled content quickly loses its momentum
+            let rate = if overscroll == 0.0 {
+                kinetic.deceleration
+            } else {
+                kinetic.deceleration.min(0.9)
+            };
+
+            velocity * rate.powf(millis)
+        };
+
+        self.velocity = Vector::new(
+            decelerate(self.velocity.x, self.overscroll.x),
+            decelerate(self.velocity.y, self.overscroll.y),
+        );
+
+        if self.velocity.x.hypot(self.velocity.y) < MIN_FLING_VELOCITY {
+            self.velocity = Vector::new(0.0, 0.0);
+        }
+
+        let bounce = |overscroll: f32| {
+            let overscroll = overscroll * (-BOUNCE_STIFFNESS * elapsed).exp();
+
+            if overscroll.abs() < 0.5 {
+                0.0
+            } else {
+                overscroll
+            }
+        };
+
+        self.overscroll =
+            Vector::new(bounce(self.overscroll.x), bounce(self.overscroll.y));
+    }
+
+    /// Updates the velocity of the [`State`] with a scrolling movement made
+    /// at the given [`Instant`].
+    fn track_velocity(&mut self, delta: Vector, now: Instant) {
+        if let Some(moved_at) = self.moved_at {
+            let elapsed = now.saturating_duration_since(moved_at).as_secs_f32();
+
+            if elapsed > 0.0 {
+                self.velocity = delta * (0.8 / elapsed) + self.velocity * 0.2;
+            }
+        }
+
+        self.moved_at = Some(now);
+    }
+
+    /// Returns whether the [`State`] has any momentum or overscroll left to
+    /// animate.
+    fn is_animating(&self) -> bool {
+        self.velocity != Vector::new(0.0, 0.0)
+            || self.overscroll != Vector::new(0.0, 0.0)
+    }
+
+    /// Stops any ongoing momentum and overscroll of the [`State`].
+    fn stop_momentum(&mut self) {
+        self.velocity = Vector::new(0.0, 0.0);
+        self.overscroll = Vector::new(0.0, 0.0);
+        self.animated_at = None;
+    }
+
     /// Snaps the scroll position to a [`RelativeOffset`].
     pub fn snap_to(&mut self, offset: RelativeOffset) {
+        self.stop_momentum();
         self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
         self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
     }
 
     /// Scroll to the provided [`AbsoluteOffset`].
     pub fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        self.stop_momentum();
         self.offset_x = Offset::Absolute(offset.x.max(0.0));
         self.offset_y = Offset::Absolute(offset.y.max(0.0));
     }
@@ -1268,6 +1603,8 @@ impl State {
 
     /// Returns the scrolling translation of the [`State`], given a [`Direction`],
     /// the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// Any overscroll is left out, since it is only applied when drawing.
     fn translation(
         &self,
         direction: Direction,
@@ -1320,6 +1657,23 @@ impl Scrollbars {
     ) -> Self {
         let translation = state.translation(direction, bounds, content_bounds);
 
+        // The vertical scrollbar is placed on the left in right to left
+        // layouts
+        let is_rtl = layout::Direction::current().is_rtl();
+
+        let mirror = |rectangle: Rectangle| {
+            if is_rtl {
+                Rectangle {
+                    x: 2.0 * bounds.x + bounds.width
+                        - rectangle.x
+                        - rectangle.width,
+                    ..rectangle
+                }
+            } else {
+                rectangle
+            }
+        };
+
         let show_scrollbar_x = direction
             .horizontal()
             .filter(|_| content_bounds.width > bounds.width);
@@ -1345,22 +1699,22 @@ impl Scrollbars {
                 width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
-            let total_scrollbar_bounds = Rectangle {
+            let total_scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width - total_scrollbar_width,
                 y: bounds.y,
                 width: total_scrollbar_width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
-            };
+            });
 
             // Bounds of just the scrollbar
-            let scrollbar_bounds = Rectangle {
+            let scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width
                     - total_scrollbar_width / 2.0
                     - width / 2.0,
                 y: bounds.y,
                 width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
-            };
+            });
 
             let ratio = bounds.height / content_bounds.height;
             // min height for easier grabbing with super tall content
@@ -1368,14 +1722,14 @@ impl Scrollbars {
             let scroller_offset =
                 translation.y * ratio * scrollbar_bounds.height / bounds.height;
 
-            let scroller_bounds = Rectangle {
+            let scroller_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width
                     - total_scrollbar_width / 2.0
                     - scroller_width / 2.0,
                 y: (scrollbar_bounds.y + scroller_offset).max(0.0),
                 width: scroller_width,
                 height: scroller_height,
-            };
+            });
 
             Some(internals::Scrollbar {
                 total_bounds: total_scrollbar_bounds,
@@ -1406,22 +1760,22 @@ impl Scrollbars {
                 width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
-            let total_scrollbar_bounds = Rectangle {
+            let total_scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x,
                 y: bounds.y + bounds.height - total_scrollbar_height,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: total_scrollbar_height,
-            };
+            });
 
             // Bounds of just the scrollbar
-            let scrollbar_bounds = Rectangle {
+            let scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x,
                 y: bounds.y + bounds.height
                     - total_scrollbar_height / 2.0
                     - width / 2.0,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: width,
-            };
+            });
 
             let ratio = bounds.width / content_bounds.width;
             // min width for easier grabbing with extra wide content
@@ -0,0 +1,391 @@
+//! Choose one option out of a group of connected toggle buttons.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
+use crate::core::touch;
+use crate::core::widget::Tree;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Widget,
+};
+
+pub use iced_style::segmented::{Appearance, StyleSheet};
+
+/// A group of mutually exclusive toggle buttons with connected styling.
+///
+/// Unlike a row of [`Radio`] buttons, a [`Segmented`] control is compact and
+/// well suited for switching between views.
+///
+/// # Example
+/// ```no_run
+/// # type Segmented<'a, Message> =
+/// #     iced_widget::Segmented<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     ViewSelected(usize),
+/// }
+///
+/// let selected = Some(0);
+///
+/// let segmented = Segmented::new(
+///     ["List", "Grid", "Gallery"],
+///     selected,
+///     Message::ViewSelected,
+/// );
+/// ```
+///
+/// [`Radio`]: crate::Radio
+#[allow(missing_debug_implementations)]
+pub struct Segmented<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    labels: Vec<String>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Segmented<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of a segment of a [`Segmented`] control.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 12.0,
+        left: 12.0,
+    };
+
+    /// Creates a new [`Segmented`] control.
+    ///
+    /// It expects:
+    ///   * the labels of the segments
+    ///   * the index of the selected segment, if any
+    ///   * a function that will be called when a segment is selected. It
+    ///   receives the index of the segment and must produce a `Message`.
+    pub fn new(
+        labels: impl IntoIterator<Item = impl ToString>,
+        selected: Option<usize>,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            labels: labels.into_iter().map(|label| label.to_string()).collect(),
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Segmented`] control.
+    ///
+    /// The segments always share the available width equally.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Segmented`] control.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each segment of the [`Segmented`] control.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Segmented`] control.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Segmented`] control.
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`Segmented`] control.
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the text font of the [`Segmented`] control.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Segmented`] control.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn segment(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let width = bounds.width / self.labels.len().max(1) as f32;
+
+        Rectangle {
+            x: bounds.x + width * index as f32,
+            width,
+            ..bounds
+        }
+    }
+
+    fn hovered_segment(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        (0..self.labels.len())
+            .find(|index| cursor.is_over(self.segment(bounds, *index)))
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Segmented<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let label = self
+            .labels
+            .iter()
+            .map(|label| {
+                renderer
+                    .create_paragraph(Text {
+                        content: label,
+                        bounds: Size::INFINITY,
+                        size,
+                        line_height: self.text_line_height,
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: self.text_shaping,
+                    })
+                    .min_bounds()
+            })
+            .fold(
+                Size::new(0.0, self.text_line_height.to_absolute(size).0),
+                |max, bounds| {
+                    Size::new(
+                        max.width.max(bounds.width),
+                        max.height.max(bounds.height),
+                    )
+                },
+            );
+
+        let intrinsic = Size::new(
+            (label.width + self.padding.horizontal())
+                * self.labels.len() as f32,
+            label.height + self.padding.vertical(),
+        );
+
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) =
+                    self.hovered_segment(layout.bounds(), cursor)
+                {
+                    if self.selected != Some(index) {
+                        shell.publish((self.on_select)(index));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) && !self.labels.is_empty() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let hovered = self.hovered_segment(bounds, cursor);
+        let last = self.labels.len().saturating_sub(1);
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let frame = theme.active(&self.style, false);
+        let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+            frame.border_radius.into();
+
+        for (index, label) in self.labels.iter().enumerate() {
+            let is_selected = self.selected == Some(index);
+            let segment = self.segment(bounds, index);
+
+            let appearance = if hovered == Some(index) {
+                theme.hovered(&self.style, is_selected)
+            } else {
+                theme.active(&self.style, is_selected)
+            };
+
+            // Only the outer corners of the control are rounded
+            let border_radius = [
+                if index == 0 { top_left } else { 0.0 },
+                if index == last { top_right } else { 0.0 },
+                if index == last { bottom_right } else { 0.0 },
+                if index == 0 { bottom_left } else { 0.0 },
+            ];
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: segment,
+                    border_radius: border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+
+            if index > 0 && frame.border_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: segment.x - frame.border_width / 2.0,
+                            width: frame.border_width,
+                            ..segment
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    },
+                    frame.border_color,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    bounds: segment.size(),
+                    size,
+                    line_height: self.text_line_height,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                },
+                Point::new(segment.center_x(), segment.center_y()),
+                appearance.text_color,
+            );
+        }
+
+        if frame.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: frame.border_radius,
+                    border_width: frame.border_width,
+                    border_color: frame.border_color,
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Segmented<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(segmented: Segmented<'a, Message, Renderer>) -> Self {
+        Element::new(segmented)
+    }
+}
@@ -0,0 +1,668 @@
+//! Display the keyboard shortcuts of the widgets of an application.
+//!
+//! Wrap the widgets triggered by a shortcut in a [`Hint`] and the whole
+//! application in a [`ShortcutsOverlay`]. While the trigger modifiers are
+//! held, the overlay displays every hint next to its widget.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation};
+use crate::core::{
+    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Vector, Widget,
+};
+
+use std::any::Any;
+
+pub use crate::style::shortcuts_overlay::{Appearance, StyleSheet};
+
+/// A widget that displays the shortcut [`Hint`]s of its contents while some
+/// keyboard modifiers are held.
+#[allow(missing_debug_implementations)]
+pub struct ShortcutsOverlay<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    modifiers: keyboard::Modifiers,
+    is_visible: Option<bool>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ShortcutsOverlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ShortcutsOverlay`] with the given content.
+    ///
+    /// By default, the hints are displayed while `Alt` is held.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            modifiers: keyboard::Modifiers::ALT,
+            is_visible: None,
+            padding: Padding::from([2, 5]),
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the keyboard modifiers that need to be held to display the hints.
+    pub fn modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Forces the hints to be shown or hidden, regardless of the keyboard
+    /// modifiers.
+    ///
+    /// This is useful to toggle the hints from the application itself.
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = Some(is_visible);
+        self
+    }
+
+    /// Sets the [`Padding`] of the hints.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the hints.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the hints.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the hints.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    modifiers: keyboard::Modifiers,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ShortcutsOverlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            tree.state.downcast_mut::<State>().modifiers = modifiers;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_ref::<State>();
+
+        let is_visible = self.is_visible.unwrap_or_else(|| {
+            !self.modifiers.is_empty()
+                && state.modifiers.contains(self.modifiers)
+        });
+
+        let hints = if is_visible {
+            let mut collect = Collect::default();
+
+            self.content.as_widget().operate(
+                &mut children[0],
+                layout,
+                renderer,
+                &mut collect,
+            );
+
+            collect.hints
+        } else {
+            Vec::new()
+        };
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut children[0],
+            layout,
+            renderer,
+        );
+
+        let hints = (!hints.is_empty()).then(|| {
+            overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    hints,
+                    origin: layout.position(),
+                    padding: self.padding,
+                    text_size: self.text_size,
+                    font: self.font,
+                    style: &self.style,
+                }),
+            )
+        });
+
+        if content.is_some() || hints.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(hints).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ShortcutsOverlay<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        shortcuts_overlay: ShortcutsOverlay<'a, Message, Renderer>,
+    ) -> Self {
+        Self::new(shortcuts_overlay)
+    }
+}
+
+/// A widget annotated with the keyboard shortcut that triggers it.
+///
+/// The shortcut is displayed by the closest [`ShortcutsOverlay`] ancestor.
+#[allow(missing_debug_implementations)]
+pub struct Hint<'a, Message, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    shortcut: String,
+}
+
+impl<'a, Message, Renderer> Hint<'a, Message, Renderer> {
+    /// Creates a new [`Hint`] with the given content and the description of
+    /// its shortcut.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        shortcut: impl ToString,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            shortcut: shortcut.to_string(),
+        }
+    }
+}
+
+/// A shortcut registered by a [`Hint`] during an [`Operation`].
+struct Registered {
+    bounds: Rectangle,
+    shortcut: String,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Hint<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.custom(
+            &mut Registered {
+                bounds: layout.bounds(),
+                shortcut: self.shortcut.clone(),
+            },
+            None,
+        );
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Hint<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(hint: Hint<'a, Message, Renderer>) -> Self {
+        Self::new(hint)
+    }
+}
+
+/// An [`Operation`] that collects the visible shortcuts of the [`Hint`]s
+/// in a widget tree.
+#[derive(Default)]
+struct Collect {
+    hints: Vec<(Rectangle, String)>,
+    translation: Vector,
+    clip: Option<Rectangle>,
+    scrollable: Option<(Rectangle, Vector)>,
+}
+
+impl<T> Operation<T> for Collect {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let Some((bounds, translation)) = self.scrollable.take() else {
+            operate_on_children(self);
+            return;
+        };
+
+        // The children of a scrollable are translated and clipped by it
+        let (previous_translation, previous_clip) =
+            (self.translation, self.clip);
+
+        let viewport = bounds - self.translation;
+
+        self.clip = Some(match self.clip {
+            Some(clip) => clip.intersection(&viewport).unwrap_or(Rectangle {
+                width: 0.0,
+                height: 0.0,
+                ..viewport
+            }),
+            None => viewport,
+        });
+        self.translation = self.translation + translation;
+
+        operate_on_children(self);
+
+        self.translation = previous_translation;
+        self.clip = previous_clip;
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn widget::operation::Scrollable,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.scrollable = Some((bounds, translation));
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&widget::Id>) {
+        let Some(registered) = state.downcast_ref::<Registered>() else {
+            return;
+        };
+
+        let bounds = registered.bounds - self.translation;
+
+        if self.clip.map_or(true, |clip| clip.intersects(&bounds)) {
+            self.hints.push((bounds, registered.shortcut.clone()));
+        }
+    }
+}
+
+struct Overlay<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    hints: Vec<(Rectangle, String)>,
+    origin: Point,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Renderer> Overlay<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn text<'b>(
+        &self,
+        renderer: &Renderer,
+        content: &'b str,
+    ) -> Text<'b, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let translation = position - self.origin;
+
+        let children = self
+            .hints
+            .iter()
+            .map(|(hint, shortcut)| {
+                let text = renderer
+                    .create_paragraph(self.text(renderer, shortcut))
+                    .min_bounds();
+
+                let size = Size::new(
+                    text.width + self.padding.horizontal(),
+                    text.height + self.padding.vertical(),
+                );
+
+                // Overlap the top left corner of the widget
+                let x = (hint.x + translation.x - size.width / 3.0)
+                    .max(0.0)
+                    .min((bounds.width - size.width).max(0.0));
+                let y = (hint.y + translation.y - size.height / 3.0)
+                    .max(0.0)
+                    .min((bounds.height - size.height).max(0.0));
+
+                layout::Node::new(size).translate(Vector::new(x, y))
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, children)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let appearance = theme.appearance(self.style);
+
+        for ((_, shortcut), layout) in self.hints.iter().zip(layout.children())
+        {
+            let bounds = layout.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+
+            renderer.fill_text(
+                self.text(renderer, shortcut),
+                Point::new(bounds.center_x(), bounds.center_y()),
+                appearance.text_color,
+            );
+        }
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        false
+    }
+}
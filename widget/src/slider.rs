@@ -1,16 +1,21 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
+use crate::core::accessibility;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
-    Size, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Widget,
 };
+use crate::focus_ring;
+use crate::style::focus;
 
 use std::ops::RangeInclusive;
 
@@ -53,6 +58,7 @@ where
     value: T,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
+    tab_index: i32,
     width: Length,
     height: f32,
     style: <Renderer::Theme as StyleSheet>::Style,
@@ -98,6 +104,7 @@ where
             step: T::from(1),
             on_change: Box::new(on_change),
             on_release: None,
+            tab_index: 0,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
             style: Default::default(),
@@ -136,6 +143,16 @@ where
         self
     }
 
+    /// Sets the position of the [`Slider`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the step size of the [`Slider`].
     pub fn step(mut self, step: impl Into<T>) -> Self {
         self.step = step.into();
@@ -149,7 +166,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Renderer: crate::core::Renderer,
-    Renderer::Theme: StyleSheet,
+    Renderer::Theme: StyleSheet + focus::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -179,6 +196,30 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(
+            &mut operation::focusable::with_tab_index(state, self.tab_index),
+            None,
+        );
+
+        let node = accessibility::Node::new(accessibility::Role::Slider)
+            .numeric(
+                self.value.into(),
+                (*self.range.start()).into()..=(*self.range.end()).into(),
+                Some(self.step.into()),
+            );
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -224,6 +265,10 @@ where
             theme,
             &self.style,
         );
+
+        if tree.state.downcast_ref::<State>().is_focused {
+            focus_ring::draw(renderer, layout.bounds(), theme);
+        }
     }
 
     fn mouse_interaction(
@@ -244,7 +289,7 @@ where
     T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
     Message: 'a + Clone,
     Renderer: 'a + crate::core::Renderer,
-    Renderer::Theme: StyleSheet,
+    Renderer::Theme: StyleSheet + focus::StyleSheet,
 {
     fn from(
         slider: Slider<'a, T, Message, Renderer>,
@@ -307,10 +352,14 @@ where
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            // Pointer interactions hide the focus ring
+            state.is_focused = false;
+
             if let Some(cursor_position) = cursor.position_over(layout.bounds())
             {
                 change(cursor_position);
                 state.is_dragging = true;
+                shell.capture_pointer();
 
                 return event::Status::Captured;
             }
@@ -335,6 +384,41 @@ where
                 return event::Status::Captured;
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused =>
+        {
+            let current = (*value).into();
+            let step = step.into();
+            let start = (*range.start()).into();
+            let end = (*range.end()).into();
+
+            let new_value = match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    current - step
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    current + step
+                }
+                keyboard::KeyCode::Home => start,
+                keyboard::KeyCode::End => end,
+                _ => return event::Status::Ignored,
+            };
+
+            if let Some(new_value) = T::from_f64(new_value.max(start).min(end))
+            {
+                if (current - new_value.into()).abs() > f64::EPSILON {
+                    shell.publish((on_change)(new_value));
+
+                    if let Some(on_release) = on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    *value = new_value;
+                }
+            }
+
+            return event::Status::Captured;
+        }
         _ => {}
     }
 
@@ -405,6 +489,7 @@ pub fn draw<T, R>(
             border_radius: style.rail.border_radius,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.0,
     );
@@ -420,6 +505,7 @@ pub fn draw<T, R>(
             border_radius: style.rail.border_radius,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.1,
     );
@@ -435,6 +521,7 @@ pub fn draw<T, R>(
             border_radius: handle_border_radius,
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            shadow: Shadow::default(),
         },
         style.handle.color,
     );
@@ -462,6 +549,7 @@ pub fn mouse_interaction(
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -469,4 +557,23 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
@@ -0,0 +1,317 @@
+//! Display a tiny chart of a series of values.
+use crate::canvas::{self, Frame, Path, Stroke};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Element, Layout, Length, Pixels, Point, Rectangle, Size, Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+pub use iced_style::sparkline::{Appearance, StyleSheet};
+
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A tiny chart of a series of values, without axes nor labels.
+///
+/// The geometry of a [`Sparkline`] is cached and only rebuilt when its
+/// values, its size, or its [`Appearance`] change; making it cheap to
+/// display thousands of them at once in tables or monitoring grids.
+#[allow(missing_debug_implementations)]
+pub struct Sparkline<'a, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    values: &'a [f32],
+    kind: Kind,
+    width: Length,
+    height: Length,
+    line_width: f32,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The way the values of a [`Sparkline`] are plotted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Kind {
+    /// The values are joined by straight lines.
+    #[default]
+    Line,
+    /// Every value is plotted as a vertical bar.
+    Bar,
+}
+
+impl<'a, Theme> Sparkline<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The default height of a [`Sparkline`].
+    pub const DEFAULT_HEIGHT: f32 = 24.0;
+
+    /// Creates a new [`Sparkline`] plotting the given values as a line.
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            kind: Kind::default(),
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            line_width: 1.5,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Kind`] of the [`Sparkline`].
+    pub fn kind(mut self, kind: Kind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Plots the values of the [`Sparkline`] as bars.
+    pub fn bars(self) -> Self {
+        self.kind(Kind::Bar)
+    }
+
+    /// Sets the width of the [`Sparkline`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Sparkline`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of the line of the [`Sparkline`].
+    pub fn line_width(mut self, line_width: impl Into<Pixels>) -> Self {
+        self.line_width = line_width.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Sparkline`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns a hash of everything that affects the geometry of the
+    /// [`Sparkline`], except its size.
+    fn key(&self, appearance: &Appearance) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.kind.hash(&mut hasher);
+        self.line_width.to_bits().hash(&mut hasher);
+
+        for value in self.values {
+            value.to_bits().hash(&mut hasher);
+        }
+
+        let colors = [
+            Some(appearance.color),
+            appearance.fill,
+            Some(appearance.negative),
+        ];
+
+        for color in colors {
+            color.map(|color| color.into_rgba8()).hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    fn draw_line(&self, frame: &mut Frame, appearance: &Appearance) {
+        let size = frame.size();
+        let (min, max) = range(self.values);
+
+        let padding = self.line_width / 2.0;
+        let height = (size.height - self.line_width).max(0.0);
+        let step = size.width / (self.values.len() - 1).max(1) as f32;
+
+        let point = |i: usize, value: f32| {
+            Point::new(
+                i as f32 * step,
+                padding + height - (value - min) / (max - min) * height,
+            )
+        };
+
+        let line = Path::new(|builder| {
+            for (i, value) in self.values.iter().enumerate() {
+                if i == 0 {
+                    builder.move_to(point(i, *value));
+                } else {
+                    builder.line_to(point(i, *value));
+                }
+            }
+        });
+
+        if let Some(fill) = appearance.fill {
+            let area = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, size.height));
+
+                for (i, value) in self.values.iter().enumerate() {
+                    builder.line_to(point(i, *value));
+                }
+
+                builder.line_to(Point::new(
+                    (self.values.len() - 1) as f32 * step,
+                    size.height,
+                ));
+                builder.close();
+            });
+
+            frame.fill(&area, fill);
+        }
+
+        frame.stroke(
+            &line,
+            Stroke::default()
+                .with_color(appearance.color)
+                .with_width(self.line_width),
+        );
+    }
+
+    fn draw_bars(&self, frame: &mut Frame, appearance: &Appearance) {
+        let size = frame.size();
+        let (min, max) = range(self.values);
+
+        // Bars grow from zero, whenever it is visible
+        let (min, max) = (min.min(0.0), max.max(0.0));
+
+        let to_y = |value: f32| {
+            size.height - (value - min) / (max - min) * size.height
+        };
+        let baseline = to_y(0.0);
+
+        let width = size.width / self.values.len() as f32;
+        let gap = if width > 3.0 { 1.0 } else { 0.0 };
+
+        for (i, value) in self.values.iter().enumerate() {
+            let y = to_y(*value);
+
+            frame.fill_rectangle(
+                Point::new(i as f32 * width, y.min(baseline)),
+                Size::new(width - gap, (y - baseline).abs()),
+                if *value < 0.0 {
+                    appearance.negative
+                } else {
+                    appearance.color
+                },
+            );
+        }
+    }
+}
+
+/// Returns the minimum and maximum values, ensuring they are different.
+fn range(values: &[f32]) -> (f32, f32) {
+    let (min, max) = values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        });
+
+    if !min.is_finite() {
+        (0.0, 1.0)
+    } else if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    }
+}
+
+/// The local state of a [`Sparkline`].
+#[derive(Default)]
+struct State {
+    cache: canvas::Cache,
+    key: Cell<u64>,
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>>
+    for Sparkline<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        if self.values.is_empty() {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        let key = self.key(&appearance);
+
+        if state.key.replace(key) != key {
+            state.cache.clear();
+        }
+
+        let geometry = state.cache.draw(renderer, bounds.size(), |frame| {
+            match self.kind {
+                Kind::Line => self.draw_line(frame, &appearance),
+                Kind::Bar => self.draw_bars(frame, &appearance),
+            }
+        });
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme> From<Sparkline<'a, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Theme: StyleSheet + 'a,
+{
+    fn from(sparkline: Sparkline<'a, Theme>) -> Self {
+        Self::new(sparkline)
+    }
+}
@@ -0,0 +1,500 @@
+//! Combine a primary action with a dropdown menu of secondary actions.
+//!
+//! A [`SplitButton`] has some local [`State`].
+use crate::button;
+use crate::container;
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::token::{self, Step};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Widget,
+};
+use crate::menu_button::{self, Action};
+use crate::overlay::menu;
+use crate::scrollable;
+
+/// A button with a primary action and an attached dropdown arrow that opens
+/// an anchored menu of secondary [`Action`]s.
+///
+/// # Example
+/// ```no_run
+/// # type SplitButton<'a, Message> =
+/// #     iced_widget::SplitButton<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::menu_button::Action;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     SaveAs,
+///     SaveAll,
+/// }
+///
+/// let split_button = SplitButton::new("Save", vec![
+///     Action::new("Save as...", Message::SaveAs),
+///     Action::new("Save all", Message::SaveAll),
+/// ])
+/// .on_press(Message::Save);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SplitButton<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    on_press: Option<Message>,
+    actions: Vec<Action<Message>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    menu_width: Option<f32>,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as button::StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> SplitButton<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    /// Creates a new [`SplitButton`] with the given content of its primary
+    /// action and the secondary [`Action`]s of its menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        actions: impl IntoIterator<Item = Action<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_press: None,
+            actions: actions.into_iter().collect(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::new(token::spacing(Step::Sm)),
+            menu_width: None,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the primary action of the
+    /// [`SplitButton`] is pressed.
+    ///
+    /// Unless `on_press` is called, the primary action will be disabled.
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(on_press);
+        self
+    }
+
+    /// Sets the message that will be produced when the primary action of the
+    /// [`SplitButton`] is pressed, if `Some`.
+    ///
+    /// If `None`, the primary action will be disabled.
+    pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
+        self.on_press = on_press;
+        self
+    }
+
+    /// Sets the width of the [`SplitButton`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SplitButton`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`SplitButton`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the menu of the [`SplitButton`].
+    ///
+    /// By default, the menu is as wide as the [`SplitButton`].
+    pub fn menu_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.menu_width = Some(width.into().0);
+        self
+    }
+
+    /// Sets the text size of the dropdown arrow and the [`Action`]s of the
+    /// menu.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Action`]s of the menu.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`SplitButton`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as button::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`SplitButton`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+
+    fn arrow_width(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size()).0
+            + self.padding.horizontal()
+    }
+
+    /// Returns the areas of the primary action and the dropdown arrow.
+    fn areas(
+        &self,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> (Rectangle, Rectangle) {
+        let arrow_width = self.arrow_width(renderer).min(bounds.width);
+
+        let primary = Rectangle {
+            width: bounds.width - arrow_width,
+            ..bounds
+        };
+
+        let arrow = Rectangle {
+            x: bounds.x + primary.width,
+            width: arrow_width,
+            ..bounds
+        };
+
+        (primary, arrow)
+    }
+}
+
+/// The local state of a [`SplitButton`].
+#[derive(Debug, Default)]
+pub struct State {
+    is_pressed: bool,
+    menu: menu_button::State,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SplitButton<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // Reserve the space of the arrow to the right of the content
+        let padding = Padding {
+            right: self.padding.right + self.arrow_width(renderer),
+            ..self.padding
+        };
+
+        button::layout(limits, self.width, self.height, padding, |limits| {
+            self.content.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                limits,
+            )
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let (primary, arrow) = self.areas(layout.bounds(), renderer);
+
+        if let event::Status::Captured = menu_button::update(
+            &event,
+            arrow,
+            cursor,
+            !self.actions.is_empty(),
+            &mut state.menu,
+        ) {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.on_press.is_some() && cursor.is_over(primary) {
+                    state.is_pressed = true;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if state.is_pressed {
+                    state.is_pressed = false;
+
+                    if let Some(on_press) = self.on_press.clone() {
+                        if cursor.is_over(primary) {
+                            shell.publish(on_press);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_pressed = false;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let (primary, arrow) = self.areas(bounds, renderer);
+
+        let appearance = menu_button::draw_segment(
+            renderer,
+            primary,
+            cursor,
+            self.on_press.is_some(),
+            state.is_pressed && cursor.is_over(primary),
+            [f32::INFINITY, 0.0, 0.0, f32::INFINITY],
+            theme,
+            &self.style,
+        );
+
+        let arrow_appearance = menu_button::draw_segment(
+            renderer,
+            arrow,
+            cursor,
+            !self.actions.is_empty(),
+            state.menu.is_open(),
+            [0.0, f32::INFINITY, f32::INFINITY, 0.0],
+            theme,
+            &self.style,
+        );
+
+        // Separate both segments
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: arrow.x,
+                    y: arrow.y + self.padding.top,
+                    width: 1.0,
+                    height: (arrow.height - self.padding.vertical()).max(0.0),
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            Color {
+                a: arrow_appearance.text_color.a * 0.3,
+                ..arrow_appearance.text_color
+            },
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            layout.children().next().unwrap(),
+            cursor,
+            &primary,
+        );
+
+        renderer.fill_text(
+            Text {
+                content: &Renderer::ARROW_DOWN_ICON.to_string(),
+                bounds: Size::new(arrow.width, arrow.height),
+                size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                line_height: text::LineHeight::default(),
+                font: Renderer::ICON_FONT,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+            },
+            Point::new(arrow.center_x(), arrow.center_y()),
+            arrow_appearance.text_color,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (primary, arrow) = self.areas(layout.bounds(), renderer);
+
+        if (self.on_press.is_some() && cursor.is_over(primary))
+            || (!self.actions.is_empty() && cursor.is_over(arrow))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+
+        if state.menu.is_open() {
+            menu_button::overlay(
+                layout,
+                &mut state.menu,
+                &self.actions,
+                self.menu_width.unwrap_or(layout.bounds().width),
+                self.padding,
+                self.text_size,
+                self.font,
+                self.menu_style.clone(),
+            )
+        } else {
+            self.content.as_widget_mut().overlay(
+                &mut children[0],
+                layout.children().next().unwrap(),
+                renderer,
+            )
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<SplitButton<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet,
+{
+    fn from(split_button: SplitButton<'a, Message, Renderer>) -> Self {
+        Self::new(split_button)
+    }
+}
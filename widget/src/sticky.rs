@@ -0,0 +1,334 @@
+//! Pin a header to the top of a [`Scrollable`] while its section is visible.
+//!
+//! [`Scrollable`]: crate::Scrollable
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A section with a header that sticks to the top of the viewport of its
+/// [`Scrollable`] while the section is in view.
+///
+/// Once the end of the section reaches the header, the header is pushed out
+/// of the viewport together with its section; which lets the header of the
+/// next [`Sticky`] section take its place.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct Sticky<'a, Message, Renderer = crate::Renderer> {
+    width: Length,
+    children: [Element<'a, Message, Renderer>; 2],
+}
+
+impl<'a, Message, Renderer> Sticky<'a, Message, Renderer> {
+    /// Creates a new [`Sticky`] section with the given header and content.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Renderer>>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Sticky {
+            width: Length::Shrink,
+            children: [header.into(), content.into()],
+        }
+    }
+
+    /// Sets the width of the [`Sticky`] section.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+/// The local state of a [`Sticky`] section.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Sticky<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let header = self.children[0].as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits,
+        );
+
+        let mut content = self.children[1].as_widget().layout(
+            &mut tree.children[1],
+            renderer,
+            &limits.shrink(Size::new(0.0, header.size().height)),
+        );
+
+        content.move_to(Point::new(0.0, header.size().height));
+
+        let size = limits.resolve(Size::new(
+            header.size().width.max(content.size().width),
+            header.size().height + content.size().height,
+        ));
+
+        layout::Node::with_children(size, vec![header, content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let offset = offset(layout, viewport);
+        tree.state.downcast_mut::<State>().offset = offset;
+
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let header_bounds = header_layout.bounds() + Vector::new(0.0, offset);
+        let is_over_header = cursor.is_over(header_bounds);
+
+        let [header, content] = &mut self.children;
+
+        let header_status = header.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            header_layout,
+            header_cursor(cursor, offset),
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let event::Status::Captured = header_status {
+            return event::Status::Captured;
+        }
+
+        content.as_widget_mut().on_event(
+            &mut tree.children[1],
+            event,
+            content_layout,
+            if is_over_header {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let offset = offset(layout, viewport);
+
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        if cursor.is_over(header_layout.bounds() + Vector::new(0.0, offset)) {
+            self.children[0].as_widget().mouse_interaction(
+                &tree.children[0],
+                header_layout,
+                header_cursor(cursor, offset),
+                viewport,
+                renderer,
+            )
+        } else {
+            self.children[1].as_widget().mouse_interaction(
+                &tree.children[1],
+                content_layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let offset = offset(layout, viewport);
+
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let is_over_header =
+            cursor.is_over(header_layout.bounds() + Vector::new(0.0, offset));
+
+        self.children[1].as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            if is_over_header {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        // The header is drawn in its own layer, so it stays on top of the
+        // content scrolling underneath it
+        renderer.with_translation(Vector::new(0.0, offset), |renderer| {
+            renderer.with_layer(header_layout.bounds(), |renderer| {
+                self.children[0].as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    header_layout,
+                    header_cursor(cursor, offset),
+                    &(*viewport - Vector::new(0.0, offset)),
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let offset = tree.state.downcast_ref::<State>().offset;
+
+        let mut children = layout.children();
+        let header_layout = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let [header, content] = &mut self.children;
+        let [header_tree, content_tree] = &mut tree.children[..] else {
+            return None;
+        };
+
+        let overlays = header
+            .as_widget_mut()
+            .overlay(header_tree, header_layout, renderer)
+            .map(|overlay| overlay.translate(Vector::new(0.0, offset)))
+            .into_iter()
+            .chain(content.as_widget_mut().overlay(
+                content_tree,
+                content_layout,
+                renderer,
+            ))
+            .collect::<Vec<_>>();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Sticky<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(sticky: Sticky<'a, Message, Renderer>) -> Self {
+        Self::new(sticky)
+    }
+}
+
+/// Returns the vertical offset of the header of a [`Sticky`] section, given
+/// its [`Layout`] and the current viewport.
+fn offset(layout: Layout<'_>, viewport: &Rectangle) -> f32 {
+    let bounds = layout.bounds();
+    let header = layout.children().next().unwrap().bounds();
+
+    (viewport.y - bounds.y).clamp(0.0, (bounds.height - header.height).max(0.0))
+}
+
+/// Returns the [`mouse::Cursor`] relative to the header of a [`Sticky`]
+/// section, given its current offset.
+fn header_cursor(cursor: mouse::Cursor, offset: f32) -> mouse::Cursor {
+    match cursor {
+        mouse::Cursor::Available(position) => {
+            mouse::Cursor::Available(position - Vector::new(0.0, offset))
+        }
+        mouse::Cursor::Unavailable => mouse::Cursor::Unavailable,
+    }
+}
@@ -0,0 +1,236 @@
+//! Restyle a whole subtree of widgets at once.
+use crate::core::cascade;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Pixels, Rectangle, Shell, Widget,
+};
+use crate::style::theme::{Palette, Theme};
+
+/// A wrapper that overrides the style of all of its descendants.
+///
+/// Every descendant that does not set a property explicitly inherits it
+/// from the closest [`Styled`] ancestor. This way, a section of an
+/// application can be restyled without changing the style of each of its
+/// widgets.
+///
+/// Overlays, like the menu of a pick list, keep the style of the
+/// application.
+#[allow(missing_debug_implementations)]
+pub struct Styled<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Renderer>,
+    text_color: Option<Color>,
+    font: Option<Renderer::Font>,
+    spacing: Option<f32>,
+    theme: Option<Box<dyn Fn(&Renderer::Theme) -> Renderer::Theme + 'a>>,
+}
+
+impl<'a, Message, Renderer> Styled<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Styled`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Styled {
+            content: content.into(),
+            text_color: None,
+            font: None,
+            spacing: None,
+            theme: None,
+        }
+    }
+
+    /// Sets the text color of the descendants of the [`Styled`].
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the text of the descendants of the [`Styled`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the spacing of the columns and rows inside the [`Styled`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets a function that produces the theme of the descendants of the
+    /// [`Styled`] from the theme of the application.
+    pub fn theme(
+        mut self,
+        theme: impl Fn(&Renderer::Theme) -> Renderer::Theme + 'a,
+    ) -> Self {
+        self.theme = Some(Box::new(theme));
+        self
+    }
+
+    /// Sets the [`Palette`] of the built-in [`Theme`] of the descendants of
+    /// the [`Styled`].
+    pub fn palette(self, palette: Palette) -> Self
+    where
+        Renderer: text::Renderer<Theme = Theme>,
+    {
+        self.theme(move |theme| theme.clone().with_palette(palette))
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Styled<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let overrides = cascade::Overrides::new()
+            .font(self.font)
+            .spacing(self.spacing);
+
+        cascade::scope(overrides, || {
+            self.content.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                limits,
+            )
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let overridden = self.theme.as_ref().map(|theme_fn| theme_fn(theme));
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            overridden.as_ref().unwrap_or(theme),
+            &renderer::Style {
+                text_color: self
+                    .text_color
+                    .unwrap_or(renderer_style.text_color),
+            },
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Styled<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        styled: Styled<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(styled)
+    }
+}
@@ -1,4 +1,8 @@
 //! Display vector graphics in your application.
+pub mod animated;
+
+pub use animated::Animated;
+
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -10,9 +14,21 @@ use crate::core::{
 
 use std::path::PathBuf;
 
+pub use crate::graphics::svg::{evict, prerender};
 pub use crate::style::svg::{Appearance, StyleSheet};
 pub use svg::Handle;
 
+/// Creates a new [`Animated`] SVG playing the given [`animated::Frames`].
+pub fn animated<Message, Renderer>(
+    frames: &animated::Frames,
+) -> Animated<Message, Renderer>
+where
+    Renderer: svg::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    Animated::new(frames)
+}
+
 /// A vector graphics image.
 ///
 /// An [`Svg`] image resizes smoothly without losing any quality.
@@ -110,32 +126,14 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        // The raw w/h of the underlying image
-        let Size { width, height } = renderer.dimensions(&self.handle);
-        let image_size = Size::new(width as f32, height as f32);
-
-        // The size to be available to the widget prior to `Shrink`ing
-        let raw_size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(image_size);
-
-        // The uncropped size of the image when fit to the bounds above
-        let full_size = self.content_fit.fit(image_size, raw_size);
-
-        // Shrink the widget to fit the resized image, if requested
-        let final_size = Size {
-            width: match self.width {
-                Length::Shrink => f32::min(raw_size.width, full_size.width),
-                _ => raw_size.width,
-            },
-            height: match self.height {
-                Length::Shrink => f32::min(raw_size.height, full_size.height),
-                _ => raw_size.height,
-            },
-        };
-
-        layout::Node::new(final_size)
+        layout(
+            renderer,
+            limits,
+            &self.handle,
+            self.width,
+            self.height,
+            self.content_fit,
+        )
     }
 
     fn draw(
@@ -148,40 +146,14 @@ where
         _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        let Size { width, height } = renderer.dimensions(&self.handle);
-        let image_size = Size::new(width as f32, height as f32);
-
-        let bounds = layout.bounds();
-        let adjusted_fit = self.content_fit.fit(image_size, bounds.size());
-
-        let render = |renderer: &mut Renderer| {
-            let offset = Vector::new(
-                (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
-                (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
-            );
-
-            let drawing_bounds = Rectangle {
-                width: adjusted_fit.width,
-                height: adjusted_fit.height,
-                ..bounds
-            };
-
-            let appearance = theme.appearance(&self.style);
-
-            renderer.draw(
-                self.handle.clone(),
-                appearance.color,
-                drawing_bounds + offset,
-            );
-        };
-
-        if adjusted_fit.width > bounds.width
-            || adjusted_fit.height > bounds.height
-        {
-            renderer.with_layer(bounds, render);
-        } else {
-            render(renderer);
-        }
+        draw(
+            renderer,
+            theme,
+            &self.style,
+            layout,
+            &self.handle,
+            self.content_fit,
+        );
     }
 }
 
@@ -195,3 +167,90 @@ where
         Element::new(icon)
     }
 }
+
+/// Computes the layout of an [`Svg`].
+pub fn layout<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    handle: &Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+) -> layout::Node
+where
+    Renderer: svg::Renderer,
+{
+    // The raw w/h of the underlying image
+    let Size {
+        width: image_width,
+        height: image_height,
+    } = renderer.dimensions(handle);
+    let image_size = Size::new(image_width as f32, image_height as f32);
+
+    // The size to be available to the widget prior to `Shrink`ing
+    let raw_size = limits.width(width).height(height).resolve(image_size);
+
+    // The uncropped size of the image when fit to the bounds above
+    let full_size = content_fit.fit(image_size, raw_size);
+
+    // Shrink the widget to fit the resized image, if requested
+    let final_size = Size {
+        width: match width {
+            Length::Shrink => f32::min(raw_size.width, full_size.width),
+            _ => raw_size.width,
+        },
+        height: match height {
+            Length::Shrink => f32::min(raw_size.height, full_size.height),
+            _ => raw_size.height,
+        },
+    };
+
+    layout::Node::new(final_size)
+}
+
+/// Draws an [`Svg`].
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+    layout: Layout<'_>,
+    handle: &Handle,
+    content_fit: ContentFit,
+) where
+    Renderer: svg::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let Size { width, height } = renderer.dimensions(handle);
+    let image_size = Size::new(width as f32, height as f32);
+
+    let bounds = layout.bounds();
+    let adjusted_fit = content_fit.fit(image_size, bounds.size());
+
+    let render = |renderer: &mut Renderer| {
+        let offset = Vector::new(
+            (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
+            (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
+        );
+
+        let drawing_bounds = Rectangle {
+            width: adjusted_fit.width,
+            height: adjusted_fit.height,
+            ..bounds
+        };
+
+        let appearance = theme.appearance(style);
+
+        renderer.draw(
+            handle.clone(),
+            appearance.color,
+            drawing_bounds + offset,
+        );
+    };
+
+    if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
+    {
+        renderer.with_layer(bounds, render);
+    } else {
+        render(renderer);
+    }
+}
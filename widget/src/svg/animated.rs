@@ -0,0 +1,464 @@
+//! Play animated vector graphics, like spinners.
+mod smil;
+
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::svg::{self, Handle};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Layout, Length, Rectangle, Shell, Widget,
+};
+use crate::style::svg::StyleSheet;
+
+use std::sync::Arc;
+
+/// The time between the samples of a SMIL animation.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(33);
+
+/// The most samples taken of a SMIL animation.
+const MAX_SAMPLES: usize = 300;
+
+/// The frames of an animated SVG.
+///
+/// Cloning [`Frames`] is cheap, since they are shared.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    id: u64,
+    frames: Arc<[Frame]>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    handle: Handle,
+    delay: Duration,
+}
+
+impl Frames {
+    /// Creates the [`Frames`] of an SVG with multiple named frames; that is,
+    /// elements with the given ids—like top-level groups.
+    ///
+    /// Every frame displays the SVG with the elements of all the other
+    /// frames hidden, for the given delay.
+    pub fn named<T: AsRef<str>>(
+        handle: &Handle,
+        frames: impl IntoIterator<Item = (T, Duration)>,
+    ) -> Result<Self, Error> {
+        let frames: Vec<(T, Duration)> = frames.into_iter().collect();
+
+        if frames.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let frames: Arc<[Frame]> = frames
+            .iter()
+            .enumerate()
+            .map(|(i, (_, delay))| {
+                let hidden: Vec<String> = frames
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, (id, _))| format!("#{}", id.as_ref()))
+                    .collect();
+
+                let handle = if hidden.is_empty() {
+                    handle.clone()
+                } else {
+                    handle.clone().with_style(format!(
+                        "{} {{ display: none !important; }}",
+                        hidden.join(", ")
+                    ))
+                };
+
+                Frame {
+                    handle,
+                    delay: *delay,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            id: handle.id(),
+            frames,
+        })
+    }
+
+    /// Samples the SMIL animations of the SVG of the given [`Handle`].
+    ///
+    /// Simple `<animate>`, `<animateTransform>`, and `<set>` animations are
+    /// supported. An SVG without any of them produces a single frame.
+    ///
+    /// Loading blocks until every frame is sampled, so consider doing it in
+    /// a [`Command`] instead of the `update` logic of your application.
+    ///
+    /// [`Command`]: crate::runtime::Command
+    pub fn load(handle: &Handle) -> Result<Self, Error> {
+        let bytes = match handle.data() {
+            svg::Data::Path(path) => std::fs::read(path)
+                .map_err(|error| Error::Io(Arc::new(error)))?,
+            svg::Data::Bytes(bytes) => bytes.to_vec(),
+        };
+
+        let source =
+            String::from_utf8(bytes).map_err(|_| Error::Unsupported)?;
+
+        let document = smil::Document::parse(&source);
+
+        if !document.is_animated() {
+            return Ok(Self {
+                id: handle.id(),
+                frames: Arc::new([Frame {
+                    handle: handle.clone(),
+                    delay: Duration::MAX,
+                }]),
+            });
+        }
+
+        let duration = Duration::from_secs_f32(document.duration());
+
+        let samples = ((duration.as_secs_f64() / SAMPLE_INTERVAL.as_secs_f64())
+            .ceil() as usize)
+            .clamp(1, MAX_SAMPLES);
+
+        let delay = duration / samples as u32;
+
+        let mut frames: Vec<(String, Duration)> = Vec::new();
+
+        for i in 0..samples {
+            let sample = document.sample((delay * i as u32).as_secs_f32());
+
+            // Equal samples are merged into a single, longer frame
+            match frames.last_mut() {
+                Some((last, last_delay)) if *last == sample => {
+                    *last_delay += delay;
+                }
+                _ => frames.push((sample, delay)),
+            }
+        }
+
+        Ok(Self {
+            id: handle.id(),
+            frames: frames
+                .into_iter()
+                .map(|(source, delay)| {
+                    let frame = Handle::from_memory(source.into_bytes());
+
+                    Frame {
+                        handle: match handle.style() {
+                            Some(style) => frame.with_style(style),
+                            None => frame,
+                        },
+                        delay: if delay.is_zero() {
+                            Duration::MAX
+                        } else {
+                            delay
+                        },
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns true if there is a single frame, which is never animated.
+    pub fn is_static(&self) -> bool {
+        self.frames.len() < 2
+    }
+
+    /// Returns how long it takes to play every frame once.
+    pub fn duration(&self) -> Duration {
+        if self.is_static() {
+            return Duration::ZERO;
+        }
+
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    fn first(&self) -> &Handle {
+        &self.frames[0].handle
+    }
+}
+
+/// An error produced when loading [`Frames`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file of the SVG could not be read.
+    #[error("the SVG could not be read: {0}")]
+    Io(Arc<std::io::Error>),
+
+    /// The SVG is compressed or it is not valid UTF-8.
+    #[error("the SVG is compressed or it is not valid UTF-8")]
+    Unsupported,
+
+    /// There are no frames.
+    #[error("there are no frames")]
+    Empty,
+}
+
+/// A widget that plays the [`Frames`] of an animated SVG.
+///
+/// It plays on its own, following the delay of every frame.
+#[allow(missing_debug_implementations)]
+pub struct Animated<Message, Renderer = crate::Renderer>
+where
+    Renderer: svg::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    frames: Frames,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    is_playing: bool,
+    is_looping: bool,
+    on_finish: Option<Message>,
+}
+
+impl<Message, Renderer> Animated<Message, Renderer>
+where
+    Renderer: svg::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Animated`] SVG playing the given [`Frames`].
+    pub fn new(frames: &Frames) -> Self {
+        Self {
+            frames: frames.clone(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            style: Default::default(),
+            is_playing: true,
+            is_looping: true,
+            on_finish: None,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] SVG.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] SVG.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] SVG.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the style variant of the [`Animated`] SVG.
+    pub fn style(
+        mut self,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether the [`Animated`] SVG is playing.
+    ///
+    /// A paused SVG keeps its current frame, and it resumes from it. It is
+    /// playing by default.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Animated`] SVG starts over once its last frame has
+    /// been played.
+    ///
+    /// Otherwise, it stops at its last frame. It loops by default.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    /// Sets the message that should be produced when the last frame of an
+    /// [`Animated`] SVG that does not loop has been played.
+    pub fn on_finish(mut self, message: Message) -> Self {
+        self.on_finish = Some(message);
+        self
+    }
+}
+
+/// The local state of an [`Animated`] SVG.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    frames: u64,
+    index: usize,
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+    is_finished: bool,
+}
+
+impl State {
+    fn new(frames: &Frames) -> Self {
+        Self {
+            frames: frames.id,
+            index: 0,
+            elapsed: Duration::ZERO,
+            last_tick: None,
+            is_finished: false,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for Animated<Message, Renderer>
+where
+    Renderer: svg::Renderer,
+    Renderer::Theme: StyleSheet,
+    Message: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.frames))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Different frames play from the start
+        if state.frames != self.frames.id {
+            *state = State::new(&self.frames);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        super::layout(
+            renderer,
+            limits,
+            self.frames.first(),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.is_playing || self.frames.is_static() || state.is_finished {
+            state.last_tick = None;
+
+            return event::Status::Ignored;
+        }
+
+        if let Some(last_tick) = state.last_tick {
+            state.elapsed += now.saturating_duration_since(last_tick);
+        }
+
+        state.last_tick = Some(now);
+
+        loop {
+            let delay = self.frames.frames[state.index].delay;
+
+            if state.elapsed < delay {
+                shell.request_redraw(window::RedrawRequest::At(
+                    now + (delay - state.elapsed),
+                ));
+
+                break;
+            }
+
+            state.elapsed -= delay;
+
+            if state.index + 1 < self.frames.frames.len() {
+                state.index += 1;
+            } else if self.is_looping {
+                state.index = 0;
+            } else {
+                state.is_finished = true;
+
+                if let Some(on_finish) = &self.on_finish {
+                    shell.publish(on_finish.clone());
+                }
+
+                break;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let frame = self
+            .frames
+            .frames
+            .get(state.index)
+            .unwrap_or(&self.frames.frames[0]);
+
+        super::draw(
+            renderer,
+            theme,
+            &self.style,
+            layout,
+            &frame.handle,
+            self.content_fit,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Animated<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: svg::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+    Message: Clone + 'a,
+{
+    fn from(
+        animated: Animated<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(animated)
+    }
+}
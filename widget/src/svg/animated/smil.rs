@@ -0,0 +1,547 @@
+//! Sample simple SMIL animations of an SVG document.
+//!
+//! Only `<animate>`, `<animateTransform>`, and `<set>` elements animating an
+//! attribute of their parent are supported, with clock values for `begin`
+//! and `dur`, `repeatCount`, `values` or `from` and `to`, `keyTimes`, and
+//! `discrete` or `linear` calculation modes. Any other animation is ignored.
+use std::ops::Range;
+
+/// The longest period of the infinite animations that is sampled, in
+/// seconds.
+const MAX_PERIOD: f32 = 10.0;
+
+/// A parsed SVG document.
+#[derive(Debug)]
+pub struct Document<'a> {
+    source: &'a str,
+    elements: Vec<Element>,
+    animations: Vec<Animation>,
+}
+
+#[derive(Debug)]
+struct Element {
+    attributes: Vec<Attribute>,
+    /// The position where new attributes can be inserted in the start tag.
+    end: usize,
+}
+
+#[derive(Debug)]
+struct Attribute {
+    name: Range<usize>,
+    value: Range<usize>,
+}
+
+#[derive(Debug)]
+struct Animation {
+    target: usize,
+    attribute: String,
+    transform: Option<String>,
+    values: Vec<String>,
+    key_times: Option<Vec<f32>>,
+    is_discrete: bool,
+    begin: f32,
+    duration: Option<f32>,
+    repeat: Option<f32>,
+    is_frozen: bool,
+}
+
+impl<'a> Document<'a> {
+    /// Parses the given SVG source.
+    pub fn parse(source: &'a str) -> Self {
+        let mut elements: Vec<Element> = Vec::new();
+        let mut animations = Vec::new();
+        let mut stack: Vec<usize> = Vec::new();
+        let mut position = 0;
+
+        while let Some(offset) = source[position..].find('<') {
+            let start = position + offset;
+            let rest = &source[start..];
+
+            let skip = |terminator: &str| {
+                rest.find(terminator)
+                    .map_or(source.len(), |end| start + end + terminator.len())
+            };
+
+            if rest.starts_with("<!--") {
+                position = skip("-->");
+            } else if rest.starts_with("<![CDATA[") {
+                position = skip("]]>");
+            } else if rest.starts_with("<?") {
+                position = skip("?>");
+            } else if rest.starts_with("<!") {
+                position = skip(">");
+            } else if rest.starts_with("</") {
+                let _ = stack.pop();
+                position = skip(">");
+            } else {
+                let Some((tag, end)) = parse_tag(source, start) else {
+                    break;
+                };
+
+                if let Some(&parent) = stack.last() {
+                    if let Some(animation) =
+                        parse_animation(source, &tag, &elements[parent])
+                    {
+                        animations.push(Animation {
+                            target: parent,
+                            ..animation
+                        });
+                    }
+                }
+
+                if !tag.is_empty {
+                    stack.push(elements.len());
+                }
+
+                elements.push(tag.element);
+                position = end;
+            }
+        }
+
+        Self {
+            source,
+            elements,
+            animations,
+        }
+    }
+
+    /// Returns true if the [`Document`] has any supported animation.
+    pub fn is_animated(&self) -> bool {
+        !self.animations.is_empty()
+    }
+
+    /// Returns the amount of seconds that must be sampled to play every
+    /// animation of the [`Document`] once.
+    ///
+    /// Infinite animations are played until all of them loop at once, which
+    /// is capped to [`MAX_PERIOD`].
+    pub fn duration(&self) -> f32 {
+        let mut end: f32 = 0.0;
+        let mut period: Option<u64> = None;
+
+        for animation in &self.animations {
+            let Some(duration) = animation.duration else {
+                end = end.max(animation.begin);
+                continue;
+            };
+
+            match animation.repeat {
+                Some(count) => {
+                    end = end.max(animation.begin + duration * count);
+                }
+                None => {
+                    let milliseconds =
+                        (duration * 1000.0).round().max(1.0) as u64;
+
+                    period = Some(match period {
+                        Some(period) => lcm(period, milliseconds)
+                            .min(MAX_PERIOD as u64 * 1000),
+                        None => milliseconds,
+                    });
+                }
+            }
+        }
+
+        let period = period
+            .map_or(0.0, |period| period as f32 / 1000.0)
+            .min(MAX_PERIOD);
+
+        end.max(period)
+    }
+
+    /// Returns the source of the [`Document`] with the value of every
+    /// animated attribute at the given time, in seconds.
+    pub fn sample(&self, time: f32) -> String {
+        // The last animation of an attribute wins
+        let mut edits: Vec<(usize, &str, String)> = Vec::new();
+
+        for animation in &self.animations {
+            if let Some(value) = animation.value(time) {
+                edits.retain(|(target, attribute, _)| {
+                    *target != animation.target
+                        || *attribute != animation.attribute
+                });
+
+                edits.push((
+                    animation.target,
+                    animation.attribute.as_str(),
+                    value,
+                ));
+            }
+        }
+
+        let mut replacements: Vec<(Range<usize>, String)> = edits
+            .into_iter()
+            .map(|(target, attribute, value)| {
+                let element = &self.elements[target];
+                let value = value.replace('"', "&quot;");
+
+                match element.attribute(self.source, attribute) {
+                    Some(current) => (current.value.clone(), value),
+                    None => (
+                        element.end..element.end,
+                        format!(" {attribute}=\"{value}\""),
+                    ),
+                }
+            })
+            .collect();
+
+        replacements.sort_by_key(|(range, _)| range.start);
+
+        let mut result = String::with_capacity(self.source.len());
+        let mut position = 0;
+
+        for (range, value) in replacements {
+            result.push_str(&self.source[position..range.start]);
+            result.push_str(&value);
+            position = range.end;
+        }
+
+        result.push_str(&self.source[position..]);
+        result
+    }
+}
+
+impl Element {
+    fn attribute(&self, source: &str, name: &str) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| &source[attribute.name.clone()] == name)
+    }
+
+    fn value<'a>(&self, source: &'a str, name: &str) -> Option<&'a str> {
+        self.attribute(source, name)
+            .map(|attribute| source[attribute.value.clone()].trim())
+    }
+}
+
+impl Animation {
+    fn value(&self, time: f32) -> Option<String> {
+        let local = time - self.begin;
+
+        if local < 0.0 {
+            return None;
+        }
+
+        let Some(duration) = self.duration else {
+            return self.values.last().cloned();
+        };
+
+        if duration <= 0.0 {
+            return None;
+        }
+
+        let progress = match self.repeat {
+            Some(count) if local >= duration * count => {
+                if !self.is_frozen {
+                    return None;
+                }
+
+                let progress = count.fract();
+
+                if progress == 0.0 {
+                    1.0
+                } else {
+                    progress
+                }
+            }
+            _ => (local % duration) / duration,
+        };
+
+        let value = self.interpolate(progress)?;
+
+        Some(match &self.transform {
+            Some(kind) => format!("{kind}({value})"),
+            None => value,
+        })
+    }
+
+    fn interpolate(&self, progress: f32) -> Option<String> {
+        let count = self.values.len();
+
+        if count < 2 {
+            return self.values.first().cloned();
+        }
+
+        let key_times = self
+            .key_times
+            .clone()
+            .filter(|key_times| key_times.len() == count)
+            .unwrap_or_else(|| {
+                let steps = if self.is_discrete { count } else { count - 1 };
+
+                (0..count).map(|i| i as f32 / steps as f32).collect()
+            });
+
+        let index = key_times
+            .iter()
+            .rposition(|key_time| *key_time <= progress)
+            .unwrap_or(0);
+
+        let numbers: Option<Vec<Vec<f32>>> =
+            self.values.iter().map(|value| numbers(value)).collect();
+
+        let (Some(numbers), false) = (numbers, self.is_discrete) else {
+            return Some(self.values[index].clone());
+        };
+
+        if index + 1 >= count
+            || numbers[index].len() != numbers[index + 1].len()
+        {
+            return Some(self.values[index].clone());
+        }
+
+        let span = key_times[index + 1] - key_times[index];
+
+        let amount = if span > 0.0 {
+            ((progress - key_times[index]) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let value: Vec<String> = numbers[index]
+            .iter()
+            .zip(&numbers[index + 1])
+            .map(|(from, to)| (from + (to - from) * amount).to_string())
+            .collect();
+
+        Some(value.join(" "))
+    }
+}
+
+struct Tag {
+    name: Range<usize>,
+    element: Element,
+    is_empty: bool,
+}
+
+impl Tag {
+    fn is(&self, source: &str, name: &str) -> bool {
+        &source[self.name.clone()] == name
+    }
+}
+
+/// Parses the start tag at the given position, returning it together with
+/// the position right after it.
+fn parse_tag(source: &str, start: usize) -> Option<(Tag, usize)> {
+    let bytes = source.as_bytes();
+    let is_name = |byte: u8| {
+        !byte.is_ascii_whitespace() && !matches!(byte, b'=' | b'>' | b'/')
+    };
+
+    let mut i = start + 1;
+
+    let name_start = i;
+    while i < bytes.len() && is_name(bytes[i]) {
+        i += 1;
+    }
+    let name = name_start..i;
+
+    let mut attributes = Vec::new();
+
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        match bytes.get(i)? {
+            b'>' => {
+                let element = Element { attributes, end: i };
+
+                return Some((
+                    Tag {
+                        name,
+                        element,
+                        is_empty: false,
+                    },
+                    i + 1,
+                ));
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'>') => {
+                let element = Element { attributes, end: i };
+
+                return Some((
+                    Tag {
+                        name,
+                        element,
+                        is_empty: true,
+                    },
+                    i + 2,
+                ));
+            }
+            _ => {}
+        }
+
+        let attribute_start = i;
+        while i < bytes.len() && is_name(bytes[i]) {
+            i += 1;
+        }
+        let attribute = attribute_start..i;
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        if bytes.get(i) != Some(&b'=') {
+            // Malformed attribute; skip it
+            i = (i + 1).max(attribute.end + 1);
+            continue;
+        }
+
+        i += 1;
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let quote = *bytes.get(i)?;
+
+        if quote != b'"' && quote != b'\'' {
+            return None;
+        }
+
+        let value_start = i + 1;
+        let value_end =
+            value_start + source[value_start..].find(quote as char)?;
+
+        attributes.push(Attribute {
+            name: attribute,
+            value: value_start..value_end,
+        });
+
+        i = value_end + 1;
+    }
+}
+
+/// Parses the [`Animation`] of the given tag, if it is a supported one, that
+/// animates the given parent.
+///
+/// The target of the returned [`Animation`] must be set by the caller.
+fn parse_animation(
+    source: &str,
+    tag: &Tag,
+    parent: &Element,
+) -> Option<Animation> {
+    let element = &tag.element;
+    let is_set = tag.is(source, "set");
+    let is_transform = tag.is(source, "animateTransform");
+
+    if !is_set && !is_transform && !tag.is(source, "animate") {
+        return None;
+    }
+
+    let attribute = element.value(source, "attributeName")?.to_owned();
+
+    let begin = match element.value(source, "begin") {
+        Some(begin) => clock(begin.split(';').next()?)?,
+        None => 0.0,
+    };
+
+    let duration = match element.value(source, "dur") {
+        Some("indefinite") | None => None,
+        Some(duration) => Some(clock(duration)?),
+    };
+
+    let repeat = match element.value(source, "repeatCount") {
+        Some("indefinite") => None,
+        Some(count) => Some(count.parse::<f32>().ok()?.max(0.0)),
+        None => Some(1.0),
+    };
+
+    let values: Vec<String> = if is_set {
+        vec![element.value(source, "to")?.to_owned()]
+    } else if let Some(values) = element.value(source, "values") {
+        values
+            .split(';')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect()
+    } else {
+        let to = element.value(source, "to")?;
+
+        // Without a starting value, the animation starts from the current
+        // value of the attribute
+        let from = element
+            .value(source, "from")
+            .or_else(|| parent.value(source, &attribute))?;
+
+        vec![from.to_owned(), to.to_owned()]
+    };
+
+    if values.is_empty() {
+        return None;
+    }
+
+    let key_times = element.value(source, "keyTimes").and_then(|key_times| {
+        key_times
+            .split(';')
+            .map(|key_time| key_time.trim().parse::<f32>().ok())
+            .collect()
+    });
+
+    Some(Animation {
+        target: 0,
+        attribute,
+        transform: is_transform.then(|| {
+            element
+                .value(source, "type")
+                .unwrap_or("translate")
+                .to_owned()
+        }),
+        values,
+        key_times,
+        is_discrete: is_set
+            || element.value(source, "calcMode") == Some("discrete"),
+        begin,
+        duration: if is_set { duration } else { Some(duration?) },
+        repeat,
+        is_frozen: element.value(source, "fill") == Some("freeze"),
+    })
+}
+
+/// Parses a SMIL clock value, in seconds.
+fn clock(value: &str) -> Option<f32> {
+    let value = value.trim();
+
+    if value.contains(':') {
+        return value.split(':').try_fold(0.0, |total, part| {
+            Some(total * 60.0 + part.parse::<f32>().ok()?)
+        });
+    }
+
+    let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix("min") {
+        (number, 60.0)
+    } else if let Some(number) = value.strip_suffix('h') {
+        (number, 3600.0)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else {
+        (value, 1.0)
+    };
+
+    Some(number.trim().parse::<f32>().ok()? * scale)
+}
+
+/// Parses a list of numbers separated by whitespace or commas.
+fn numbers(value: &str) -> Option<Vec<f32>> {
+    value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|number| !number.is_empty())
+        .map(|number| number.parse().ok())
+        .collect()
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    a / gcd(a, b) * b
+}
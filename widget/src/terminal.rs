@@ -0,0 +1,883 @@
+//! Embed a console in your application.
+//!
+//! A [`Terminal`] displays a [`Grid`] of character cells fed with the
+//! output of a program, usually running in a pseudoterminal. The keys typed
+//! by the user are encoded as the bytes a program expects and handed to the
+//! application with [`Terminal::on_input`].
+pub mod grid;
+
+pub use grid::{Cell, Grid};
+
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::font::{self, Font};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph as _, Text};
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Widget,
+};
+use crate::runtime::Command;
+
+pub use crate::style::terminal::{Appearance, StyleSheet};
+
+/// The amount of lines scrolled by a step of the mouse wheel.
+const SCROLL_LINES: f32 = 3.0;
+
+/// A terminal displaying a [`Grid`] of character cells.
+///
+/// The user can scroll through the scrollback of the [`Grid`] with the
+/// mouse wheel, select text by dragging, and copy or paste it with
+/// `Ctrl+Shift+C` and `Ctrl+Shift+V` (`Cmd+C` and `Cmd+V` on macOS).
+#[allow(missing_debug_implementations)]
+pub struct Terminal<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    grid: &'a Grid,
+    id: Option<Id>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    font: Font,
+    text_size: Option<Pixels>,
+    line_height: LineHeight,
+    on_input: Option<Box<dyn Fn(Vec<u8>) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Terminal<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of a [`Terminal`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`Terminal`] displaying the given [`Grid`].
+    pub fn new(grid: &'a Grid) -> Self {
+        Self {
+            grid,
+            id: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            font: Font::MONOSPACE,
+            text_size: None,
+            line_height: LineHeight::Relative(1.2),
+            on_input: None,
+            on_resize: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Terminal`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`Terminal`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Terminal`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Terminal`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Terminal`].
+    ///
+    /// It should be monospaced; [`Font::MONOSPACE`] is used by default.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the text size of the [`Terminal`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Terminal`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the message that should be produced when the user types in the
+    /// focused [`Terminal`].
+    ///
+    /// The closure receives the bytes that should be written to the input
+    /// of the program.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(Vec<u8>) -> Message + 'a,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the message that should be produced when the amount of columns
+    /// and rows that fit in the [`Terminal`] changes.
+    ///
+    /// The application should [`Grid::resize`] its [`Grid`]—and the
+    /// pseudoterminal of its program—accordingly.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the style of the [`Terminal`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the cells of the [`Terminal`].
+    fn content(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: (bounds.width - self.padding.horizontal()).max(0.0),
+            height: (bounds.height - self.padding.vertical()).max(0.0),
+        }
+    }
+
+    /// Returns the index of the first line of the [`Grid`] displayed.
+    fn first_line<P>(&self, state: &State<P>, rows: usize) -> usize {
+        self.grid.len().saturating_sub(rows + state.scroll)
+    }
+
+    /// Returns the line index and the column of the cell at the given
+    /// position; clamped to the visible cells.
+    fn hit<P>(
+        &self,
+        state: &State<P>,
+        content: Rectangle,
+        position: Point,
+    ) -> (usize, usize) {
+        let (columns, rows) = state.fit(content.size());
+        let first_line = self.first_line(state, rows);
+
+        let row = ((position.y - content.y) / state.cell.height).max(0.0);
+        let column = ((position.x - content.x) / state.cell.width).max(0.0);
+
+        (
+            (first_line + (row as usize).min(rows - 1))
+                .min(self.grid.len().saturating_sub(1)),
+            (column as usize)
+                .min(columns - 1)
+                .min(self.grid.columns() - 1),
+        )
+    }
+}
+
+/// The local state of a [`Terminal`].
+#[derive(Debug, Default)]
+struct State<P> {
+    metrics: P,
+    cell: Size,
+    size: (usize, usize),
+    scroll: usize,
+    selection: Option<((usize, usize), (usize, usize))>,
+    is_selecting: bool,
+    is_focused: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<P> State<P> {
+    /// Returns the amount of columns and rows that fit in the given
+    /// [`Size`].
+    fn fit(&self, size: Size) -> (usize, usize) {
+        if self.cell.width <= 0.0 || self.cell.height <= 0.0 {
+            return (1, 1);
+        }
+
+        (
+            ((size.width / self.cell.width) as usize).max(1),
+            ((size.height / self.cell.height) as usize).max(1),
+        )
+    }
+
+    /// Returns the selection, ordered from start to end.
+    fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.selection
+            .map(|(a, b)| if a <= b { (a, b) } else { (b, a) })
+    }
+}
+
+impl<P> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_selecting = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Terminal<'a, Message, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.update_paragraph(
+            &mut state.metrics,
+            Text {
+                content: "M",
+                bounds: Size::INFINITY,
+                size,
+                line_height: self.line_height,
+                font: self.font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+            },
+        );
+
+        state.cell = Size::new(
+            state.metrics.min_bounds().width,
+            self.line_height.to_absolute(size).0,
+        );
+
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let content = self.content(bounds);
+        let (_, rows) = state.fit(content.size());
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                let size = state.fit(content.size());
+
+                if state.size != size {
+                    state.size = size;
+
+                    if let Some(on_resize) = &self.on_resize {
+                        shell.publish(on_resize(size.0, size.1));
+                    }
+                }
+
+                state.scroll =
+                    state.scroll.min(self.grid.len().saturating_sub(rows));
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let hit = self.hit(state, content, position);
+
+                state.is_focused = true;
+                state.is_selecting = true;
+                state.selection = Some((hit, hit));
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if state.is_selecting =>
+            {
+                let hit = self.hit(state, content, position);
+
+                if let Some((_, head)) = &mut state.selection {
+                    *head = hit;
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_selecting =>
+            {
+                state.is_selecting = false;
+
+                if matches!(state.selection, Some((anchor, head)) if anchor == head)
+                {
+                    state.selection = None;
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_LINES,
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        y / state.cell.height.max(1.0)
+                    }
+                };
+
+                let max = self.grid.len().saturating_sub(rows) as f32;
+
+                state.scroll =
+                    (state.scroll as f32 + lines).round().max(0.0).min(max)
+                        as usize;
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+                ..
+            }) if state.is_focused => {
+                if is_shortcut(modifiers) {
+                    match key_code {
+                        keyboard::KeyCode::C => {
+                            if let Some((start, end)) = state.selection() {
+                                clipboard.write(self.grid.text(start, end));
+                            }
+                        }
+                        keyboard::KeyCode::V => {
+                            if let (Some(on_input), Some(contents)) =
+                                (&self.on_input, clipboard.read())
+                            {
+                                shell.publish(on_input(contents.into_bytes()));
+                                state.scroll = 0;
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                match key_code {
+                    keyboard::KeyCode::PageUp if modifiers.shift() => {
+                        state.scroll = (state.scroll + rows)
+                            .min(self.grid.len().saturating_sub(rows));
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::PageDown if modifiers.shift() => {
+                        state.scroll = state.scroll.saturating_sub(rows);
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+
+                if let Some(sequence) = sequence(key_code, modifiers) {
+                    if let Some(on_input) = &self.on_input {
+                        shell.publish(on_input(sequence.to_vec()));
+                        state.scroll = 0;
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(character))
+                if state.is_focused =>
+            {
+                if is_shortcut(state.modifiers) || state.modifiers.logo() {
+                    return event::Status::Ignored;
+                }
+
+                // These are sent when the key is pressed
+                if matches!(
+                    character,
+                    '\r' | '\n' | '\u{8}' | '\t' | '\u{1b}' | '\u{7f}'
+                ) {
+                    return event::Status::Captured;
+                }
+
+                if let Some(on_input) = &self.on_input {
+                    let mut bytes = Vec::new();
+
+                    // Alt sends a meta prefix
+                    if state.modifiers.alt() {
+                        bytes.push(0x1b);
+                    }
+
+                    bytes.extend_from_slice(
+                        character.encode_utf8(&mut [0; 4]).as_bytes(),
+                    );
+
+                    shell.publish(on_input(bytes));
+                    state.scroll = 0;
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let content = self.content(bounds);
+        let cell = state.cell;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        if cell.width <= 0.0 || cell.height <= 0.0 {
+            return;
+        }
+
+        let (columns, rows) = state.fit(content.size());
+        let first_line = self.first_line(state, rows);
+        let selection = state.selection();
+        let cursor = (self.grid.is_cursor_visible() && state.scroll == 0)
+            .then(|| self.grid.cursor());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let is_selected = |line: usize, column: usize| {
+            selection.map_or(false, |(start, end)| {
+                (line, column) >= start && (line, column) <= end
+            })
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            for (row, index) in
+                (first_line..self.grid.len()).take(rows).enumerate()
+            {
+                let line = self.grid.line(index).unwrap_or_default();
+                let line = &line[..line.len().min(columns)];
+                let y = content.y + row as f32 * cell.height;
+
+                let look = |column: usize| {
+                    let is_cursor =
+                        state.is_focused && cursor == Some((index, column));
+
+                    Look::new(
+                        &line[column],
+                        &appearance,
+                        is_selected(index, column),
+                        is_cursor,
+                    )
+                };
+
+                let mut start = 0;
+
+                while start < line.len() {
+                    let current = look(start);
+                    let end = (start + 1..line.len())
+                        .find(|column| look(*column) != current)
+                        .unwrap_or(line.len());
+
+                    let run = Rectangle {
+                        x: content.x + start as f32 * cell.width,
+                        y,
+                        width: (end - start) as f32 * cell.width,
+                        height: cell.height,
+                    };
+
+                    if let Some(background) = current.background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: run,
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                                shadow: Shadow::default(),
+                            },
+                            background,
+                        );
+                    }
+
+                    let characters: String = line[start..end]
+                        .iter()
+                        .map(|cell| cell.character)
+                        .collect();
+
+                    if !characters.trim().is_empty() {
+                        renderer.fill_text(
+                            Text {
+                                content: &characters,
+                                bounds: Size::new(f32::INFINITY, cell.height),
+                                size,
+                                line_height: self.line_height,
+                                font: Font {
+                                    weight: if current.is_bold {
+                                        font::Weight::Bold
+                                    } else {
+                                        self.font.weight
+                                    },
+                                    style: if current.is_italic {
+                                        font::Style::Italic
+                                    } else {
+                                        self.font.style
+                                    },
+                                    ..self.font
+                                },
+                                horizontal_alignment:
+                                    alignment::Horizontal::Left,
+                                vertical_alignment: alignment::Vertical::Top,
+                                shaping: text::Shaping::Basic,
+                            },
+                            run.position(),
+                            current.foreground,
+                        );
+                    }
+
+                    if current.is_underlined {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    y: run.y + run.height - 1.0,
+                                    height: 1.0,
+                                    ..run
+                                },
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                                shadow: Shadow::default(),
+                            },
+                            current.foreground,
+                        );
+                    }
+
+                    start = end;
+                }
+            }
+
+            // An unfocused terminal displays a hollow cursor
+            if let Some((line, column)) = cursor {
+                if !state.is_focused
+                    && line >= first_line
+                    && line < first_line + rows
+                    && column < columns
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: content.x + column as f32 * cell.width,
+                                y: content.y
+                                    + (line - first_line) as f32 * cell.height,
+                                width: cell.width,
+                                height: cell.height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 1.0,
+                            border_color: appearance.cursor,
+                            shadow: Shadow::default(),
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Terminal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(terminal: Terminal<'a, Message, Renderer>) -> Self {
+        Self::new(terminal)
+    }
+}
+
+/// The resolved colors and attributes of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Look {
+    foreground: Color,
+    background: Option<Color>,
+    is_bold: bool,
+    is_italic: bool,
+    is_underlined: bool,
+}
+
+impl Look {
+    fn new(
+        cell: &Cell,
+        appearance: &Appearance,
+        is_selected: bool,
+        is_cursor: bool,
+    ) -> Self {
+        let style = cell.style;
+
+        let mut foreground = color(
+            style.foreground,
+            appearance.foreground,
+            appearance,
+            style.is_bold,
+        );
+
+        let mut background = match style.background {
+            grid::Color::Default => None,
+            background => Some(color(
+                background,
+                appearance.background,
+                appearance,
+                false,
+            )),
+        };
+
+        if style.is_inverse {
+            let inverted = background.unwrap_or(appearance.background);
+
+            background = Some(foreground);
+            foreground = inverted;
+        }
+
+        if is_selected {
+            background = Some(appearance.selection);
+        }
+
+        if is_cursor {
+            background = Some(appearance.cursor);
+            foreground = appearance.background;
+        }
+
+        Self {
+            foreground,
+            background,
+            is_bold: style.is_bold,
+            is_italic: style.is_italic,
+            is_underlined: style.is_underlined,
+        }
+    }
+}
+
+/// Resolves a [`grid::Color`] with the given [`Appearance`].
+fn color(
+    color: grid::Color,
+    default: Color,
+    appearance: &Appearance,
+    is_bold: bool,
+) -> Color {
+    match color {
+        grid::Color::Default => default,
+        grid::Color::Indexed(index) if index < 16 => {
+            // Bold text uses the bright variants of the first 8 colors
+            let index = if is_bold && index < 8 {
+                index + 8
+            } else {
+                index
+            };
+
+            appearance.palette[usize::from(index)]
+        }
+        grid::Color::Indexed(index) if index < 232 => {
+            let index = index - 16;
+            let level =
+                |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+
+            Color::from_rgb8(
+                level(index / 36),
+                level(index / 6 % 6),
+                level(index % 6),
+            )
+        }
+        grid::Color::Indexed(index) => {
+            let gray = 8 + (index - 232) * 10;
+
+            Color::from_rgb8(gray, gray, gray)
+        }
+        grid::Color::Rgb(r, g, b) => Color::from_rgb8(r, g, b),
+    }
+}
+
+/// Returns true if the given modifiers trigger the clipboard shortcuts of
+/// a [`Terminal`]; since `Ctrl+C` and `Ctrl+V` are sent to the program.
+fn is_shortcut(modifiers: keyboard::Modifiers) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.logo()
+    } else {
+        modifiers.control() && modifiers.shift()
+    }
+}
+
+/// Returns the escape sequence sent to the program when the given key is
+/// pressed, if any.
+fn sequence(
+    key_code: keyboard::KeyCode,
+    modifiers: keyboard::Modifiers,
+) -> Option<&'static [u8]> {
+    use keyboard::KeyCode;
+
+    let sequence: &[u8] = match key_code {
+        KeyCode::Enter | KeyCode::NumpadEnter => b"\r",
+        KeyCode::Backspace => b"\x7f",
+        KeyCode::Tab if modifiers.shift() => b"\x1b[Z",
+        KeyCode::Tab => b"\t",
+        KeyCode::Escape => b"\x1b",
+        KeyCode::Up => b"\x1b[A",
+        KeyCode::Down => b"\x1b[B",
+        KeyCode::Right => b"\x1b[C",
+        KeyCode::Left => b"\x1b[D",
+        KeyCode::Home => b"\x1b[H",
+        KeyCode::End => b"\x1b[F",
+        KeyCode::Insert => b"\x1b[2~",
+        KeyCode::Delete => b"\x1b[3~",
+        KeyCode::PageUp => b"\x1b[5~",
+        KeyCode::PageDown => b"\x1b[6~",
+        KeyCode::F1 => b"\x1bOP",
+        KeyCode::F2 => b"\x1bOQ",
+        KeyCode::F3 => b"\x1bOR",
+        KeyCode::F4 => b"\x1bOS",
+        KeyCode::F5 => b"\x1b[15~",
+        KeyCode::F6 => b"\x1b[17~",
+        KeyCode::F7 => b"\x1b[18~",
+        KeyCode::F8 => b"\x1b[19~",
+        KeyCode::F9 => b"\x1b[20~",
+        KeyCode::F10 => b"\x1b[21~",
+        KeyCode::F11 => b"\x1b[23~",
+        KeyCode::F12 => b"\x1b[24~",
+        _ => return None,
+    };
+
+    Some(sequence)
+}
+
+/// The identifier of a [`Terminal`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`Terminal`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
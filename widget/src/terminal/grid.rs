@@ -0,0 +1,677 @@
+use std::collections::VecDeque;
+
+/// The character cells of a terminal, along with its scrollback.
+///
+/// A [`Grid`] is fed the output of a program—including its VT100 and ANSI
+/// escape sequences—with [`Grid::feed`].
+#[derive(Debug, Clone)]
+pub struct Grid {
+    columns: usize,
+    rows: usize,
+    scrollback: usize,
+    lines: VecDeque<Vec<Cell>>,
+    cursor: (usize, usize),
+    saved_cursor: (usize, usize),
+    is_cursor_visible: bool,
+    is_wrap_pending: bool,
+    style: Style,
+    parser: Parser,
+    utf8: Vec<u8>,
+}
+
+impl Grid {
+    /// The default amount of lines kept in the scrollback of a [`Grid`].
+    pub const DEFAULT_SCROLLBACK: usize = 10_000;
+
+    /// Creates an empty [`Grid`] with the given amount of columns and rows.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+
+        Self {
+            columns,
+            rows,
+            scrollback: Self::DEFAULT_SCROLLBACK,
+            lines: (0..rows)
+                .map(|_| blank(columns, Style::default()))
+                .collect(),
+            cursor: (0, 0),
+            saved_cursor: (0, 0),
+            is_cursor_visible: true,
+            is_wrap_pending: false,
+            style: Style::default(),
+            parser: Parser::Ground,
+            utf8: Vec::new(),
+        }
+    }
+
+    /// Sets the maximum amount of lines kept in the scrollback of the
+    /// [`Grid`].
+    pub fn with_scrollback(mut self, scrollback: usize) -> Self {
+        self.scrollback = scrollback;
+        self.trim();
+        self
+    }
+
+    /// Returns the amount of columns of the [`Grid`].
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the amount of rows of the [`Grid`].
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the amount of lines of the [`Grid`], including its
+    /// scrollback.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns true if the [`Grid`] has no lines.
+    ///
+    /// A [`Grid`] always has at least one row, so this is always false.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns the cells of the line at the given index, where the first
+    /// line is the oldest line of the scrollback.
+    pub fn line(&self, index: usize) -> Option<&[Cell]> {
+        self.lines.get(index).map(Vec::as_slice)
+    }
+
+    /// Returns the position of the cursor, as a line index and a column.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.first_row() + self.cursor.0, self.cursor.1)
+    }
+
+    /// Returns true if the program wants the cursor to be displayed.
+    pub fn is_cursor_visible(&self) -> bool {
+        self.is_cursor_visible
+    }
+
+    /// Returns the text between two positions of the [`Grid`], inclusive.
+    ///
+    /// Positions are given as a line index and a column.
+    pub fn text(&self, start: (usize, usize), end: (usize, usize)) -> String {
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        let mut text = String::new();
+
+        for index in start.0..=end.0.min(self.lines.len().saturating_sub(1)) {
+            let line = &self.lines[index];
+
+            let from = if index == start.0 { start.1 } else { 0 };
+            let to = if index == end.0 {
+                (end.1 + 1).min(line.len())
+            } else {
+                line.len()
+            };
+
+            if index != start.0 {
+                text.push('\n');
+            }
+
+            let content: String = line
+                .get(from..to)
+                .unwrap_or_default()
+                .iter()
+                .map(|cell| cell.character)
+                .collect();
+
+            text.push_str(content.trim_end());
+        }
+
+        text
+    }
+
+    /// Resizes the [`Grid`].
+    ///
+    /// Lines are truncated or padded, not reflowed.
+    pub fn resize(&mut self, columns: usize, rows: usize) {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+
+        if rows > self.rows {
+            // Bring back lines from the scrollback first
+            let pulled = (rows - self.rows).min(self.first_row());
+
+            for _ in 0..rows - self.rows - pulled {
+                self.lines.push_back(blank(self.columns, Style::default()));
+            }
+
+            self.cursor.0 += pulled;
+        } else if rows < self.rows {
+            let removed = self.rows - rows;
+
+            // Drop the empty lines below the cursor first
+            let below = self.rows - 1 - self.cursor.0;
+            let dropped = removed.min(below);
+
+            for _ in 0..dropped {
+                let _ = self.lines.pop_back();
+            }
+
+            self.cursor.0 -= removed - dropped;
+        }
+
+        for line in &mut self.lines {
+            line.resize(columns, Cell::default());
+        }
+
+        self.columns = columns;
+        self.rows = rows;
+        self.cursor.1 = self.cursor.1.min(columns - 1);
+        self.is_wrap_pending = false;
+
+        self.trim();
+    }
+
+    /// Removes all the lines of the [`Grid`], including its scrollback.
+    pub fn clear(&mut self) {
+        self.lines = (0..self.rows)
+            .map(|_| blank(self.columns, Style::default()))
+            .collect();
+        self.cursor = (0, 0);
+        self.is_wrap_pending = false;
+    }
+
+    /// Feeds the [`Grid`] with the output of a program.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.advance(*byte);
+        }
+    }
+
+    fn advance(&mut self, byte: u8) {
+        match std::mem::replace(&mut self.parser, Parser::Ground) {
+            Parser::Ground => self.ground(byte),
+            Parser::Escape => self.escape(byte),
+            Parser::Charset => {}
+            Parser::Csi(mut csi) => match byte {
+                b'0'..=b'9' => {
+                    csi.current = Some(
+                        csi.current
+                            .unwrap_or(0)
+                            .saturating_mul(10)
+                            .saturating_add(u16::from(byte - b'0')),
+                    );
+
+                    self.parser = Parser::Csi(csi);
+                }
+                b';' | b':' => {
+                    csi.parameters.push(csi.current.take().unwrap_or(0));
+
+                    self.parser = Parser::Csi(csi);
+                }
+                b'<'..=b'?' => {
+                    csi.is_private = true;
+
+                    self.parser = Parser::Csi(csi);
+                }
+                0x20..=0x2f => {
+                    self.parser = Parser::Csi(csi);
+                }
+                0x40..=0x7e => {
+                    if let Some(current) = csi.current.take() {
+                        csi.parameters.push(current);
+                    }
+
+                    self.csi(byte, &csi);
+                }
+                0x1b => {
+                    self.parser = Parser::Escape;
+                }
+                _ => {
+                    self.parser = Parser::Csi(csi);
+                }
+            },
+            Parser::Osc => {
+                self.parser = match byte {
+                    0x07 => Parser::Ground,
+                    0x1b => Parser::OscEscape,
+                    _ => Parser::Osc,
+                };
+            }
+            Parser::OscEscape => {}
+        }
+    }
+
+    fn ground(&mut self, byte: u8) {
+        if byte >= 0x80 {
+            self.utf8.push(byte);
+
+            match std::str::from_utf8(&self.utf8) {
+                Ok(decoded) => {
+                    let character = decoded.chars().next().unwrap_or(' ');
+
+                    self.utf8.clear();
+                    self.print(character);
+                }
+                Err(error) if error.error_len().is_none() => {}
+                Err(_) => {
+                    self.utf8.clear();
+                    self.print(char::REPLACEMENT_CHARACTER);
+                }
+            }
+
+            return;
+        }
+
+        if !self.utf8.is_empty() {
+            self.utf8.clear();
+            self.print(char::REPLACEMENT_CHARACTER);
+        }
+
+        match byte {
+            0x1b => self.parser = Parser::Escape,
+            b'\n' | 0x0b | 0x0c => self.line_feed(),
+            b'\r' => {
+                self.cursor.1 = 0;
+                self.is_wrap_pending = false;
+            }
+            0x08 => {
+                self.cursor.1 = self.cursor.1.saturating_sub(1);
+                self.is_wrap_pending = false;
+            }
+            b'\t' => {
+                self.cursor.1 =
+                    ((self.cursor.1 / 8 + 1) * 8).min(self.columns - 1);
+            }
+            0x20..=0x7e => self.print(char::from(byte)),
+            _ => {}
+        }
+    }
+
+    fn escape(&mut self, byte: u8) {
+        match byte {
+            b'[' => self.parser = Parser::Csi(Csi::default()),
+            b']' => self.parser = Parser::Osc,
+            b'(' | b')' | b'*' | b'+' => self.parser = Parser::Charset,
+            b'7' => self.saved_cursor = self.cursor,
+            b'8' => self.restore_cursor(),
+            b'D' => self.line_feed(),
+            b'E' => {
+                self.cursor.1 = 0;
+                self.line_feed();
+            }
+            b'M' => self.reverse_line_feed(),
+            b'c' => {
+                self.style = Style::default();
+                self.is_cursor_visible = true;
+                self.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn csi(&mut self, action: u8, csi: &Csi) {
+        let parameter = |index: usize| {
+            usize::from(csi.parameters.get(index).copied().unwrap_or(0))
+        };
+        let count = parameter(0).max(1);
+
+        if csi.is_private {
+            if parameter(0) == 25 {
+                match action {
+                    b'h' => self.is_cursor_visible = true,
+                    b'l' => self.is_cursor_visible = false,
+                    _ => {}
+                }
+            }
+
+            return;
+        }
+
+        self.is_wrap_pending = false;
+
+        match action {
+            b'm' => self.select_graphic_rendition(&csi.parameters),
+            b'A' => self.cursor.0 = self.cursor.0.saturating_sub(count),
+            b'B' | b'e' => {
+                self.cursor.0 = (self.cursor.0 + count).min(self.rows - 1);
+            }
+            b'C' | b'a' => {
+                self.cursor.1 = (self.cursor.1 + count).min(self.columns - 1);
+            }
+            b'D' => self.cursor.1 = self.cursor.1.saturating_sub(count),
+            b'E' => {
+                self.cursor = ((self.cursor.0 + count).min(self.rows - 1), 0);
+            }
+            b'F' => self.cursor = (self.cursor.0.saturating_sub(count), 0),
+            b'G' | b'`' => self.cursor.1 = (count - 1).min(self.columns - 1),
+            b'd' => self.cursor.0 = (count - 1).min(self.rows - 1),
+            b'H' | b'f' => {
+                self.cursor = (
+                    (parameter(0).max(1) - 1).min(self.rows - 1),
+                    (parameter(1).max(1) - 1).min(self.columns - 1),
+                );
+            }
+            b'J' => {
+                let (row, column) = self.cursor;
+
+                match parameter(0) {
+                    0 => {
+                        self.erase(row, column..self.columns);
+
+                        for row in row + 1..self.rows {
+                            self.erase(row, 0..self.columns);
+                        }
+                    }
+                    1 => {
+                        for row in 0..row {
+                            self.erase(row, 0..self.columns);
+                        }
+
+                        self.erase(row, 0..column + 1);
+                    }
+                    2 => {
+                        for row in 0..self.rows {
+                            self.erase(row, 0..self.columns);
+                        }
+                    }
+                    3 => {
+                        let first_row = self.first_row();
+
+                        drop(self.lines.drain(..first_row));
+                    }
+                    _ => {}
+                }
+            }
+            b'K' => {
+                let (row, column) = self.cursor;
+
+                match parameter(0) {
+                    0 => self.erase(row, column..self.columns),
+                    1 => self.erase(row, 0..column + 1),
+                    2 => self.erase(row, 0..self.columns),
+                    _ => {}
+                }
+            }
+            b'X' => {
+                let (row, column) = self.cursor;
+
+                self.erase(row, column..(column + count).min(self.columns));
+            }
+            b'P' => {
+                let (row, column) = self.cursor;
+                let background = self.blank_cell();
+                let line = self.row_mut(row);
+                let count = count.min(line.len() - column);
+
+                drop(line.drain(column..column + count));
+                line.extend(std::iter::repeat(background).take(count));
+            }
+            b'@' => {
+                let (row, column) = self.cursor;
+                let columns = self.columns;
+                let background = self.blank_cell();
+                let line = self.row_mut(row);
+                let count = count.min(columns - column);
+
+                for _ in 0..count {
+                    line.insert(column, background);
+                }
+
+                line.truncate(columns);
+            }
+            b'L' => {
+                for _ in 0..count.min(self.rows - self.cursor.0) {
+                    let index = self.first_row() + self.cursor.0;
+                    let last = self.lines.len() - 1;
+
+                    let _ = self.lines.remove(last);
+                    self.lines
+                        .insert(index, blank(self.columns, self.erase_style()));
+                }
+            }
+            b'M' => {
+                for _ in 0..count.min(self.rows - self.cursor.0) {
+                    let index = self.first_row() + self.cursor.0;
+
+                    let _ = self.lines.remove(index);
+                    self.lines
+                        .push_back(blank(self.columns, self.erase_style()));
+                }
+            }
+            b's' => self.saved_cursor = self.cursor,
+            b'u' => self.restore_cursor(),
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self, parameters: &[u16]) {
+        if parameters.is_empty() {
+            self.style = Style::default();
+            return;
+        }
+
+        let mut parameters = parameters.iter().copied();
+
+        while let Some(parameter) = parameters.next() {
+            match parameter {
+                0 => self.style = Style::default(),
+                1 => self.style.is_bold = true,
+                3 => self.style.is_italic = true,
+                4 => self.style.is_underlined = true,
+                7 => self.style.is_inverse = true,
+                22 => self.style.is_bold = false,
+                23 => self.style.is_italic = false,
+                24 => self.style.is_underlined = false,
+                27 => self.style.is_inverse = false,
+                30..=37 => {
+                    self.style.foreground =
+                        Color::Indexed((parameter - 30) as u8);
+                }
+                38 => {
+                    if let Some(color) = extended_color(&mut parameters) {
+                        self.style.foreground = color;
+                    }
+                }
+                39 => self.style.foreground = Color::Default,
+                40..=47 => {
+                    self.style.background =
+                        Color::Indexed((parameter - 40) as u8);
+                }
+                48 => {
+                    if let Some(color) = extended_color(&mut parameters) {
+                        self.style.background = color;
+                    }
+                }
+                49 => self.style.background = Color::Default,
+                90..=97 => {
+                    self.style.foreground =
+                        Color::Indexed((parameter - 90 + 8) as u8);
+                }
+                100..=107 => {
+                    self.style.background =
+                        Color::Indexed((parameter - 100 + 8) as u8);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn print(&mut self, character: char) {
+        if self.is_wrap_pending {
+            self.cursor.1 = 0;
+            self.line_feed();
+        }
+
+        let (row, column) = self.cursor;
+        let style = self.style;
+
+        self.row_mut(row)[column] = Cell { character, style };
+
+        if column + 1 < self.columns {
+            self.cursor.1 += 1;
+        } else {
+            self.is_wrap_pending = true;
+        }
+    }
+
+    fn line_feed(&mut self) {
+        self.is_wrap_pending = false;
+
+        if self.cursor.0 + 1 < self.rows {
+            self.cursor.0 += 1;
+        } else {
+            self.lines
+                .push_back(blank(self.columns, self.erase_style()));
+            self.trim();
+        }
+    }
+
+    fn reverse_line_feed(&mut self) {
+        self.is_wrap_pending = false;
+
+        if self.cursor.0 > 0 {
+            self.cursor.0 -= 1;
+        } else {
+            let first_row = self.first_row();
+
+            let _ = self.lines.pop_back();
+            self.lines
+                .insert(first_row, blank(self.columns, self.erase_style()));
+        }
+    }
+
+    fn restore_cursor(&mut self) {
+        self.cursor = (
+            self.saved_cursor.0.min(self.rows - 1),
+            self.saved_cursor.1.min(self.columns - 1),
+        );
+        self.is_wrap_pending = false;
+    }
+
+    fn erase(&mut self, row: usize, columns: std::ops::Range<usize>) {
+        let cell = self.blank_cell();
+        let line = self.row_mut(row);
+        let end = columns.end.min(line.len());
+
+        line[columns.start.min(end)..end].fill(cell);
+    }
+
+    /// The style of the cells erased with the current style, which only
+    /// keeps its background.
+    fn erase_style(&self) -> Style {
+        Style {
+            background: self.style.background,
+            ..Style::default()
+        }
+    }
+
+    fn blank_cell(&self) -> Cell {
+        Cell {
+            character: ' ',
+            style: self.erase_style(),
+        }
+    }
+
+    fn first_row(&self) -> usize {
+        self.lines.len() - self.rows
+    }
+
+    fn row_mut(&mut self, row: usize) -> &mut Vec<Cell> {
+        let index = self.first_row() + row;
+
+        &mut self.lines[index]
+    }
+
+    fn trim(&mut self) {
+        while self.lines.len() > self.rows + self.scrollback {
+            let _ = self.lines.pop_front();
+        }
+    }
+}
+
+/// A character of a [`Grid`] along with its [`Style`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    /// The character of the [`Cell`].
+    pub character: char,
+    /// The [`Style`] of the [`Cell`].
+    pub style: Style,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            character: ' ',
+            style: Style::default(),
+        }
+    }
+}
+
+/// The style of a [`Cell`], set by the program with escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Color`] of the character.
+    pub foreground: Color,
+    /// The [`Color`] of the background.
+    pub background: Color,
+    /// Whether the character is bold.
+    pub is_bold: bool,
+    /// Whether the character is italic.
+    pub is_italic: bool,
+    /// Whether the character is underlined.
+    pub is_underlined: bool,
+    /// Whether the foreground and background colors are swapped.
+    pub is_inverse: bool,
+}
+
+/// The color of a [`Cell`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Color {
+    /// The default color of the terminal.
+    #[default]
+    Default,
+    /// A color of the 256-color palette; where the first 16 colors are
+    /// given by the style of the terminal.
+    Indexed(u8),
+    /// A true color.
+    Rgb(u8, u8, u8),
+}
+
+fn extended_color(parameters: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    let mut next = || parameters.next().map(|value| value.min(255) as u8);
+
+    match next()? {
+        5 => Some(Color::Indexed(next()?)),
+        2 => Some(Color::Rgb(next()?, next()?, next()?)),
+        _ => None,
+    }
+}
+
+fn blank(columns: usize, style: Style) -> Vec<Cell> {
+    vec![
+        Cell {
+            character: ' ',
+            style,
+        };
+        columns
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum Parser {
+    Ground,
+    Escape,
+    Charset,
+    Csi(Csi),
+    Osc,
+    OscEscape,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Csi {
+    parameters: Vec<u16>,
+    current: Option<u16>,
+    is_private: bool,
+}
@@ -11,6 +11,7 @@ pub use value::Value;
 
 use editor::Editor;
 
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -19,6 +20,7 @@ use crate::core::mouse::{self, click};
 use crate::core::renderer;
 use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::time::{Duration, Instant};
+use crate::core::token::{self, Step};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
@@ -26,9 +28,10 @@ use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point,
-    Rectangle, Shell, Size, Vector, Widget,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
 };
 use crate::runtime::Command;
+use crate::style::theme::sheet::Class;
 
 pub use iced_style::text_input::{Appearance, StyleSheet};
 
@@ -61,9 +64,11 @@ where
     Renderer::Theme: StyleSheet,
 {
     id: Option<Id>,
+    tab_index: i32,
     placeholder: String,
     value: Value,
     is_secure: bool,
+    is_revealable: bool,
     font: Option<Renderer::Font>,
     width: Length,
     padding: Padding,
@@ -73,10 +78,14 @@ where
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    strength: Option<Box<dyn Fn(&str) -> f32 + 'a>>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
-/// The default [`Padding`] of a [`TextInput`].
+/// The default [`Padding`] of a [`TextInput`] with the comfortable
+/// [`Tokens`].
+///
+/// [`Tokens`]: crate::core::token::Tokens
 pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
 
 impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
@@ -93,18 +102,21 @@ where
     pub fn new(placeholder: &str, value: &str) -> Self {
         TextInput {
             id: None,
+            tab_index: 0,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            is_revealable: false,
             font: None,
             width: Length::Fill,
-            padding: DEFAULT_PADDING,
+            padding: Padding::new(token::spacing(Step::Sm)),
             size: None,
             line_height: text::LineHeight::default(),
             on_input: None,
             on_paste: None,
             on_submit: None,
             icon: None,
+            strength: None,
             style: Default::default(),
         }
     }
@@ -115,12 +127,41 @@ where
         self
     }
 
+    /// Sets the position of the [`TextInput`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
+    ///
+    /// The value of a password input is masked and can never be copied or
+    /// cut into the clipboard.
     pub fn password(mut self) -> Self {
         self.is_secure = true;
         self
     }
 
+    /// Sets whether a password [`TextInput`] displays a toggle that reveals
+    /// its value.
+    pub fn revealable(mut self, is_revealable: bool) -> Self {
+        self.is_revealable = is_revealable;
+        self
+    }
+
+    /// Displays a strength meter under the value of the [`TextInput`].
+    ///
+    /// The given scorer must produce the strength of a value, from `0.0` to
+    /// `1.0`.
+    pub fn strength(mut self, scorer: impl Fn(&str) -> f32 + 'a) -> Self {
+        self.strength = Some(Box::new(scorer));
+        self
+    }
+
     /// Sets the message that should be produced when some text is typed into
     /// the [`TextInput`].
     ///
@@ -200,6 +241,19 @@ where
         self
     }
 
+    /// Sets the class of the [`TextInput`], styling it with the properties of
+    /// the class in the style sheet of the theme.
+    ///
+    /// See the [`sheet`] module to learn about style sheets.
+    ///
+    /// [`sheet`]: crate::style::theme::sheet
+    pub fn class(self, class: impl Into<Class>) -> Self
+    where
+        <Renderer::Theme as StyleSheet>::Style: From<Class>,
+    {
+        self.style(class.into())
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -223,6 +277,7 @@ where
             value.unwrap_or(&self.value),
             &self.placeholder,
             self.is_secure,
+            self.is_revealable,
         )
     }
 
@@ -239,19 +294,29 @@ where
         cursor: mouse::Cursor,
         value: Option<&Value>,
     ) {
+        let value = value.unwrap_or(&self.value);
+
         draw(
             renderer,
             theme,
             layout,
             cursor,
             tree.state.downcast_ref::<State<Renderer::Paragraph>>(),
-            value.unwrap_or(&self.value),
+            value,
             self.on_input.is_none(),
             self.is_secure,
+            self.is_revealable,
             self.icon.as_ref(),
+            self.strength(value),
             &self.style,
         );
     }
+
+    fn strength(&self, value: &Value) -> Option<f32> {
+        self.strength
+            .as_ref()
+            .map(|scorer| scorer(&value.to_string()).max(0.0).min(1.0))
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -308,19 +373,39 @@ where
             &self.value,
             &self.placeholder,
             self.is_secure,
+            self.is_revealable,
         )
     }
 
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        let mut node = accessibility::Node::new(accessibility::Role::TextInput)
+            .label(self.placeholder.clone())
+            .focused(state.is_focused.is_some())
+            .disabled(self.on_input.is_none());
+
+        // Secure values are never exposed to assistive technologies
+        if !self.is_secure {
+            node = node.value(self.value.to_string());
+        }
+
+        operation.accessible(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &node,
+            &mut |_| {},
+        );
+        operation.focusable(
+            &mut operation::focusable::with_tab_index(state, self.tab_index),
+            self.id.as_ref().map(|id| &id.0),
+        );
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
     }
 
@@ -347,6 +432,7 @@ where
             self.line_height,
             self.font,
             self.is_secure,
+            self.is_revealable,
             self.on_input.as_deref(),
             self.on_paste.as_deref(),
             &self.on_submit,
@@ -373,7 +459,9 @@ where
             &self.value,
             self.on_input.is_none(),
             self.is_secure,
+            self.is_revealable,
             self.icon.as_ref(),
+            self.strength(&self.value),
             &self.style,
         );
     }
@@ -497,6 +585,7 @@ pub fn layout<Renderer>(
     value: &Value,
     placeholder: &str,
     is_secure: bool,
+    is_revealable: bool,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -510,7 +599,24 @@ where
         .pad(padding)
         .height(line_height.to_absolute(text_size));
 
-    let text_bounds = limits.resolve(Size::ZERO);
+    let mut text_bounds = limits.resolve(Size::ZERO);
+    let size = text_bounds.pad(padding);
+
+    let toggle = (is_secure && is_revealable).then(|| {
+        let toggle_size = text_bounds.height;
+
+        text_bounds.width =
+            (text_bounds.width - toggle_size - REVEAL_TOGGLE_SPACING).max(0.0);
+
+        let mut toggle = layout::Node::new(Size::new(toggle_size, toggle_size));
+
+        toggle.move_to(Point::new(
+            padding.left + text_bounds.width + REVEAL_TOGGLE_SPACING,
+            padding.top,
+        ));
+
+        toggle
+    });
 
     let placeholder_text = Text {
         font,
@@ -525,7 +631,8 @@ where
 
     renderer.update_paragraph(&mut state.placeholder, placeholder_text);
 
-    let secure_value = is_secure.then(|| value.secure());
+    let secure_value =
+        (is_secure && !state.is_revealed).then(|| value.secure());
     let value = secure_value.as_ref().unwrap_or(value);
 
     renderer.update_paragraph(
@@ -536,7 +643,7 @@ where
         },
     );
 
-    if let Some(icon) = icon {
+    let mut children = if let Some(icon) = icon {
         let icon_text = Text {
             line_height,
             content: &icon.code_point.to_string(),
@@ -578,16 +685,24 @@ where
             }
         };
 
-        layout::Node::with_children(
-            text_bounds.pad(padding),
-            vec![text_node, icon_node],
-        )
+        vec![text_node, icon_node]
     } else {
         let mut text = layout::Node::new(text_bounds);
         text.move_to(Point::new(padding.left, padding.top));
 
-        layout::Node::with_children(text_bounds.pad(padding), vec![text])
+        vec![text]
+    };
+
+    children.extend(toggle);
+
+    // The icon and the reveal toggle switch sides in right to left layouts
+    if layout::Direction::current().is_rtl() {
+        for child in &mut children {
+            child.mirror(size.width);
+        }
     }
+
+    layout::Node::with_children(size, children)
 }
 
 /// Processes an [`Event`] and updates the [`State`] of a [`TextInput`]
@@ -604,6 +719,7 @@ pub fn update<'a, Message, Renderer>(
     line_height: text::LineHeight,
     font: Option<Renderer::Font>,
     is_secure: bool,
+    is_revealable: bool,
     on_input: Option<&dyn Fn(String) -> Message>,
     on_paste: Option<&dyn Fn(String) -> Message>,
     on_submit: &Option<Message>,
@@ -622,6 +738,7 @@ where
             font,
             size,
             line_height,
+            is_secure,
         );
     };
 
@@ -630,6 +747,19 @@ where
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
 
+            if is_secure && is_revealable && on_input.is_some() {
+                let toggle = layout.children().last().unwrap();
+
+                if cursor.is_over(toggle.bounds()) {
+                    state.toggle_reveal();
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+
+            let is_masked = is_secure && !state.is_revealed;
+
             let click_position = if on_input.is_some() {
                 cursor.position_over(layout.bounds())
             } else {
@@ -660,7 +790,7 @@ where
                 match click.kind() {
                     click::Kind::Single => {
                         let position = if target > 0.0 {
-                            let value = if is_secure {
+                            let value = if is_masked {
                                 value.secure()
                             } else {
                                 value.clone()
@@ -688,7 +818,7 @@ where
                         state.is_dragging = true;
                     }
                     click::Kind::Double => {
-                        if is_secure {
+                        if is_masked {
                             state.cursor.select_all(value);
                         } else {
                             let position = find_cursor_position(
@@ -726,12 +856,13 @@ where
         Event::Mouse(mouse::Event::CursorMoved { position })
         | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
             let state = state();
+            let is_masked = is_secure && !state.is_revealed;
 
             if state.is_dragging {
                 let text_layout = layout.children().next().unwrap();
                 let target = position.x - text_layout.bounds().x;
 
-                let value = if is_secure {
+                let value = if is_masked {
                     value.secure()
                 } else {
                     value.clone()
@@ -788,6 +919,7 @@ where
                 };
 
                 let modifiers = state.keyboard_modifiers;
+                let is_masked = is_secure && !state.is_revealed;
                 focus.updated_at = Instant::now();
 
                 match key_code {
@@ -801,7 +933,7 @@ where
                         if platform::is_jump_modifier_pressed(modifiers)
                             && state.cursor.selection(value).is_none()
                         {
-                            if is_secure {
+                            if is_masked {
                                 let cursor_pos = state.cursor.end(value);
                                 state.cursor.select_range(0, cursor_pos);
                             } else {
@@ -821,7 +953,7 @@ where
                         if platform::is_jump_modifier_pressed(modifiers)
                             && state.cursor.selection(value).is_none()
                         {
-                            if is_secure {
+                            if is_masked {
                                 let cursor_pos = state.cursor.end(value);
                                 state
                                     .cursor
@@ -841,7 +973,7 @@ where
                     }
                     keyboard::KeyCode::Left => {
                         if platform::is_jump_modifier_pressed(modifiers)
-                            && !is_secure
+                            && !is_masked
                         {
                             if modifiers.shift() {
                                 state.cursor.select_left_by_words(value);
@@ -856,7 +988,7 @@ where
                     }
                     keyboard::KeyCode::Right => {
                         if platform::is_jump_modifier_pressed(modifiers)
-                            && !is_secure
+                            && !is_masked
                         {
                             if modifiers.shift() {
                                 state.cursor.select_right_by_words(value);
@@ -889,7 +1021,7 @@ where
                         }
                     }
                     keyboard::KeyCode::C
-                        if state.keyboard_modifiers.command() =>
+                        if state.keyboard_modifiers.command() && !is_secure =>
                     {
                         if let Some((start, end)) =
                             state.cursor.selection(value)
@@ -899,7 +1031,7 @@ where
                         }
                     }
                     keyboard::KeyCode::X
-                        if state.keyboard_modifiers.command() =>
+                        if state.keyboard_modifiers.command() && !is_secure =>
                     {
                         if let Some((start, end)) =
                             state.cursor.selection(value)
@@ -1056,13 +1188,16 @@ pub fn draw<Renderer>(
     value: &Value,
     is_disabled: bool,
     is_secure: bool,
+    is_revealable: bool,
     icon: Option<&Icon<Renderer::Font>>,
+    strength: Option<f32>,
     style: &<Renderer::Theme as StyleSheet>::Style,
 ) where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
-    let secure_value = is_secure.then(|| value.secure());
+    let secure_value =
+        (is_secure && !state.is_revealed).then(|| value.secure());
     let value = secure_value.as_ref().unwrap_or(value);
 
     let bounds = layout.bounds();
@@ -1088,6 +1223,7 @@ pub fn draw<Renderer>(
             border_radius: appearance.border_radius,
             border_width: appearance.border_width,
             border_color: appearance.border_color,
+            shadow: Shadow::default(),
         },
         appearance.background,
     );
@@ -1102,6 +1238,43 @@ pub fn draw<Renderer>(
         );
     }
 
+    if is_secure && is_revealable {
+        let toggle_layout = children_layout.next().unwrap();
+
+        draw_reveal_toggle(
+            renderer,
+            toggle_layout.bounds(),
+            state.is_revealed,
+            appearance.icon_color,
+        );
+    }
+
+    if let Some(strength) = strength {
+        let bottom = bounds.y + bounds.height - appearance.border_width;
+        let text_bottom = text_bounds.y + text_bounds.height;
+
+        if strength > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x,
+                        y: text_bottom
+                            + (bottom - text_bottom - STRENGTH_METER_HEIGHT)
+                                .max(0.0)
+                                / 2.0,
+                        width: text_bounds.width * strength,
+                        height: STRENGTH_METER_HEIGHT,
+                    },
+                    border_radius: (STRENGTH_METER_HEIGHT / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                theme.strength_color(style, strength),
+            );
+        }
+    }
+
     let text = value.to_string();
 
     let (cursor, offset) = if let Some(focus) = state
@@ -1136,6 +1309,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            shadow: Shadow::default(),
                         },
                         theme.value_color(style),
                     ))
@@ -1177,6 +1351,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            shadow: Shadow::default(),
                         },
                         theme.selection_color(style),
                     )),
@@ -1227,6 +1402,72 @@ pub fn draw<Renderer>(
     }
 }
 
+/// Draws the toggle revealing the value of a password [`TextInput`].
+///
+/// An open eye is displayed while the value is concealed, and a closed one
+/// once it is revealed.
+fn draw_reveal_toggle<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    is_revealed: bool,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    let eye = Rectangle {
+        x: bounds.x + bounds.width * 0.1,
+        y: bounds.center_y() - bounds.height * 0.25,
+        width: bounds.width * 0.8,
+        height: bounds.height * 0.5,
+    };
+
+    if is_revealed {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: eye.center_y() - 1.0,
+                    height: 2.0,
+                    ..eye
+                },
+                border_radius: 1.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    } else {
+        let pupil = eye.height * 0.5;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: eye,
+                border_radius: (eye.height / 2.0).into(),
+                border_width: 1.5,
+                border_color: color,
+                shadow: Shadow::default(),
+            },
+            Color::TRANSPARENT,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: eye.center_x() - pupil / 2.0,
+                    y: eye.center_y() - pupil / 2.0,
+                    width: pupil,
+                    height: pupil,
+                },
+                border_radius: (pupil / 2.0).into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+}
+
 /// Computes the current [`mouse::Interaction`] of the [`TextInput`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
@@ -1253,6 +1494,7 @@ pub struct State<P: text::Paragraph> {
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    is_revealed: bool,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
@@ -1281,6 +1523,7 @@ impl<P: text::Paragraph> State<P> {
             is_focused: None,
             is_dragging: false,
             is_pasting: None,
+            is_revealed: false,
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
@@ -1297,6 +1540,17 @@ impl<P: text::Paragraph> State<P> {
         self.cursor
     }
 
+    /// Returns whether the value of a password [`TextInput`] is currently
+    /// revealed or not.
+    pub fn is_revealed(&self) -> bool {
+        self.is_revealed
+    }
+
+    /// Toggles whether the value of a password [`TextInput`] is revealed.
+    pub fn toggle_reveal(&mut self) {
+        self.is_revealed = !self.is_revealed;
+    }
+
     /// Focuses the [`TextInput`].
     pub fn focus(&mut self) {
         let now = Instant::now();
@@ -1452,12 +1706,17 @@ fn replace_paragraph<Renderer>(
     font: Option<Renderer::Font>,
     text_size: Option<Pixels>,
     line_height: text::LineHeight,
+    is_secure: bool,
 ) where
     Renderer: text::Renderer,
 {
     let font = font.unwrap_or_else(|| renderer.default_font());
     let text_size = text_size.unwrap_or_else(|| renderer.default_size());
 
+    let secure_value =
+        (is_secure && !state.is_revealed).then(|| value.secure());
+    let value = secure_value.as_ref().unwrap_or(value);
+
     let mut children_layout = layout.children();
     let text_bounds = children_layout.next().unwrap().bounds();
 
@@ -1474,3 +1733,7 @@ fn replace_paragraph<Renderer>(
 }
 
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+
+const REVEAL_TOGGLE_SPACING: f32 = 5.0;
+
+const STRENGTH_METER_HEIGHT: f32 = 2.0;
@@ -0,0 +1,916 @@
+//! Lay out bars on a time axis, like a Gantt chart or the tracks of a media
+//! editor.
+//!
+//! A [`Timeline`] displays the [`Bar`]s owned by the application in rows
+//! organized in [`Group`]s. When the user drags or resizes a bar, the
+//! [`Timeline`] produces a [`Change`] that the application can apply.
+use crate::canvas::{Frame, Path, Stroke, Text};
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::graphics::geometry::Renderer as _;
+use crate::Renderer;
+
+use std::ops::Range;
+
+pub use crate::style::timeline::{Appearance, StyleSheet};
+
+/// The width of the column of labels.
+const LABEL_WIDTH: f32 = 140.0;
+
+/// The height of the time axis.
+const AXIS_HEIGHT: f32 = 26.0;
+
+/// The height of the header of a [`Group`].
+const GROUP_HEIGHT: f32 = 22.0;
+
+/// The height of a row.
+const ROW_HEIGHT: f32 = 30.0;
+
+/// The vertical space between a bar and the edges of its row.
+const BAR_MARGIN: f32 = 5.0;
+
+/// The distance to the edges of a bar where it can be resized.
+const EDGE_WIDTH: f32 = 6.0;
+
+/// The minimum horizontal space between two ticks of the time axis.
+const TICK_SPACING: f32 = 80.0;
+
+/// The size of the text of the timeline.
+const TEXT_SIZE: f32 = 12.0;
+
+/// A timeline displaying [`Bar`]s on a horizontal time axis.
+///
+/// The user can:
+/// - drag a bar to move it in time and drag its edges to resize it,
+/// - pan the timeline by dragging its background,
+/// - scroll the rows with the mouse wheel,
+/// - pan the time axis with `Shift` and the mouse wheel, and
+/// - zoom the time axis around the cursor with `Ctrl` and the mouse wheel.
+#[allow(missing_debug_implementations)]
+pub struct Timeline<'a, Message, Theme = crate::Theme>
+where
+    Theme: StyleSheet,
+{
+    groups: &'a [Group],
+    bars: &'a [Bar],
+    dependencies: &'a [Dependency],
+    width: Length,
+    height: Length,
+    snap: Option<f32>,
+    format: Box<dyn Fn(f32) -> String + 'a>,
+    on_change: Option<Box<dyn Fn(Change) -> Message + 'a>>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> Timeline<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Timeline`] with the given [`Group`]s of rows and the
+    /// [`Bar`]s displayed in them.
+    pub fn new(groups: &'a [Group], bars: &'a [Bar]) -> Self {
+        Self {
+            groups,
+            bars,
+            dependencies: &[],
+            width: Length::Fill,
+            height: Length::Fill,
+            snap: None,
+            format: Box::new(|time| format!("{time}")),
+            on_change: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Dependency`] arrows drawn between the bars of the
+    /// [`Timeline`].
+    pub fn dependencies(mut self, dependencies: &'a [Dependency]) -> Self {
+        self.dependencies = dependencies;
+        self
+    }
+
+    /// Sets the width of the [`Timeline`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Timeline`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Makes the bars snap to multiples of the given step while dragged.
+    pub fn snap(mut self, step: f32) -> Self {
+        self.snap = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets the function used to format the ticks of the time axis.
+    pub fn format(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the message that should be produced when the user moves or
+    /// resizes a [`Bar`].
+    ///
+    /// The bars cannot be edited if this is not set.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Change) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the style of the [`Timeline`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the vertical offset of every row, relative to the top of the
+    /// rows area and before scrolling, along with the total height.
+    fn rows(&self) -> (Vec<f32>, f32) {
+        let mut offsets = Vec::new();
+        let mut y = 0.0;
+
+        for group in self.groups {
+            y += GROUP_HEIGHT;
+
+            for _ in &group.rows {
+                offsets.push(y);
+                y += ROW_HEIGHT;
+            }
+        }
+
+        (offsets, y)
+    }
+
+    /// Returns the time at the left edge of the plot and the amount of
+    /// pixels per unit of time.
+    fn view(&self, state: &State, plot: Rectangle) -> (f32, f32) {
+        if let Some(view) = state.view {
+            return view;
+        }
+
+        // Fit all the bars by default
+        let (start, end) =
+            self.bars.iter().fold(
+                (f32::INFINITY, f32::NEG_INFINITY),
+                |(start, end), bar| (start.min(bar.start), end.max(bar.end)),
+            );
+
+        if !start.is_finite() || end <= start {
+            return (0.0, 10.0);
+        }
+
+        let margin = (end - start) * 0.05;
+
+        (
+            start - margin,
+            plot.width.max(1.0) / (end - start + margin * 2.0),
+        )
+    }
+
+    /// Returns the bounds of the bar at the given index, relative to the
+    /// widget.
+    fn bar_bounds(
+        &self,
+        bar: &Bar,
+        rows: &[f32],
+        (start, scale): (f32, f32),
+        scroll: f32,
+    ) -> Option<Rectangle> {
+        let y = *rows.get(bar.row)?;
+
+        Some(Rectangle {
+            x: LABEL_WIDTH + (bar.start - start) * scale,
+            y: AXIS_HEIGHT + y - scroll + BAR_MARGIN,
+            width: ((bar.end - bar.start) * scale).max(1.0),
+            height: ROW_HEIGHT - BAR_MARGIN * 2.0,
+        })
+    }
+
+    fn snap(&self, time: f32) -> f32 {
+        match self.snap {
+            Some(step) => (time / step).round() * step,
+            None => time,
+        }
+    }
+}
+
+/// A group of rows of a [`Timeline`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    label: String,
+    rows: Vec<String>,
+}
+
+impl Group {
+    /// Creates a new [`Group`] with the given label and the labels of its
+    /// rows.
+    ///
+    /// The rows of all the groups are numbered in order, starting from 0.
+    pub fn new(
+        label: impl Into<String>,
+        rows: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            rows: rows.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A bar of a [`Timeline`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    /// The index of the row of the [`Bar`].
+    pub row: usize,
+    /// The start time of the [`Bar`].
+    pub start: f32,
+    /// The end time of the [`Bar`].
+    pub end: f32,
+    /// The label of the [`Bar`].
+    pub label: String,
+    /// The [`Color`] of the [`Bar`], if it differs from the style.
+    pub color: Option<Color>,
+}
+
+impl Bar {
+    /// Creates a new [`Bar`] in the given row spanning the given range of
+    /// time.
+    pub fn new(
+        row: usize,
+        range: Range<f32>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            row,
+            start: range.start,
+            end: range.end.max(range.start),
+            label: label.into(),
+            color: None,
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Bar`].
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// An arrow from the end of a [`Bar`] to the start of another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dependency {
+    /// The index of the [`Bar`] the arrow starts from.
+    pub from: usize,
+    /// The index of the [`Bar`] the arrow points to.
+    pub to: usize,
+}
+
+/// A [`Bar`] of a [`Timeline`] moved or resized by the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    /// The index of the [`Bar`].
+    pub bar: usize,
+    /// The new start time of the [`Bar`].
+    pub start: f32,
+    /// The new end time of the [`Bar`].
+    pub end: f32,
+}
+
+/// The local state of a [`Timeline`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    view: Option<(f32, f32)>,
+    scroll: f32,
+    interaction: Interaction,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Interaction {
+    #[default]
+    Idle,
+    Panning {
+        origin: Point,
+        view: (f32, f32),
+        scroll: f32,
+    },
+    Dragging {
+        bar: usize,
+        edge: Edge,
+        origin: f32,
+        start: f32,
+        end: f32,
+    },
+}
+
+/// The part of a bar being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Start,
+    End,
+    Both,
+}
+
+/// Returns a step between ticks of roughly the given size, rounded to 1, 2,
+/// or 5 times a power of ten.
+fn tick_step(size: f32) -> f32 {
+    let magnitude = 10.0_f32.powf(size.log10().floor());
+
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= size)
+        .unwrap_or(magnitude * 10.0)
+}
+
+impl<'a, Message, Theme> Widget<Message, Renderer<Theme>>
+    for Timeline<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer<Theme>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer<Theme>,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let plot = plot(bounds.size());
+
+        let (rows, total_height) = self.rows();
+        let max_scroll = (total_height - plot.height).max(0.0);
+        let view = self.view(state, plot);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if !plot.contains(position) {
+                    return event::Status::Ignored;
+                }
+
+                let hit = self.on_change.as_ref().and_then(|_| {
+                    self.bars.iter().enumerate().rev().find_map(
+                        |(index, bar)| {
+                            let area = self
+                                .bar_bounds(bar, &rows, view, state.scroll)?
+                                .expand(1.0);
+
+                            area.contains(position).then(|| {
+                                let edge = if area.width > EDGE_WIDTH * 3.0
+                                    && position.x < area.x + EDGE_WIDTH
+                                {
+                                    Edge::Start
+                                } else if area.width > EDGE_WIDTH * 3.0
+                                    && position.x
+                                        > area.x + area.width - EDGE_WIDTH
+                                {
+                                    Edge::End
+                                } else {
+                                    Edge::Both
+                                };
+
+                                (index, bar, edge)
+                            })
+                        },
+                    )
+                });
+
+                state.interaction = match hit {
+                    Some((index, bar, edge)) => Interaction::Dragging {
+                        bar: index,
+                        edge,
+                        origin: position.x,
+                        start: bar.start,
+                        end: bar.end,
+                    },
+                    None => Interaction::Panning {
+                        origin: position,
+                        view,
+                        scroll: state.scroll,
+                    },
+                };
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let position = position - Vector::new(bounds.x, bounds.y);
+
+                match state.interaction {
+                    Interaction::Idle => return event::Status::Ignored,
+                    Interaction::Panning {
+                        origin,
+                        view: (start, scale),
+                        scroll,
+                    } => {
+                        state.view = Some((
+                            start - (position.x - origin.x) / scale,
+                            scale,
+                        ));
+                        state.scroll = (scroll - (position.y - origin.y))
+                            .max(0.0)
+                            .min(max_scroll);
+                    }
+                    Interaction::Dragging {
+                        bar,
+                        edge,
+                        origin,
+                        start,
+                        end,
+                    } => {
+                        let delta = (position.x - origin) / view.1;
+
+                        let (start, end) = match edge {
+                            Edge::Both => {
+                                let moved = self.snap(start + delta);
+
+                                (moved, moved + (end - start))
+                            }
+                            Edge::Start => {
+                                (self.snap(start + delta).min(end), end)
+                            }
+                            Edge::End => {
+                                (start, self.snap(end + delta).max(start))
+                            }
+                        };
+
+                        if let Some(on_change) = &self.on_change {
+                            shell.publish(on_change(Change {
+                                bar,
+                                start,
+                                end,
+                            }));
+                        }
+
+                        // Keep the current view while dragging
+                        state.view = Some(view);
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Interaction::Idle =
+                    std::mem::take(&mut state.interaction)
+                {
+                    event::Status::Ignored
+                } else {
+                    event::Status::Captured
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * ROW_HEIGHT, y * ROW_HEIGHT)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                let (start, scale) = view;
+
+                if state.modifiers.command() {
+                    // Zoom around the cursor
+                    let anchor =
+                        start + (position.x.max(plot.x) - plot.x) / scale;
+                    let scale = scale * 1.1_f32.powf(y / ROW_HEIGHT);
+
+                    state.view = Some((
+                        anchor - (position.x.max(plot.x) - plot.x) / scale,
+                        scale,
+                    ));
+                } else if state.modifiers.shift() || x != 0.0 {
+                    let x = if x != 0.0 { x } else { y };
+
+                    state.view = Some((start - x / scale, scale));
+                } else {
+                    state.scroll = (state.scroll - y).max(0.0).min(max_scroll);
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer<Theme>,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        match state.interaction {
+            Interaction::Panning { .. } => mouse::Interaction::Grabbing,
+            Interaction::Dragging {
+                edge: Edge::Both, ..
+            } => mouse::Interaction::Grabbing,
+            Interaction::Dragging { .. } => {
+                mouse::Interaction::ResizingHorizontally
+            }
+            Interaction::Idle => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return mouse::Interaction::default();
+                };
+
+                let plot = plot(bounds.size());
+
+                if !plot.contains(position) || self.on_change.is_none() {
+                    return mouse::Interaction::default();
+                }
+
+                let (rows, _) = self.rows();
+                let view = self.view(state, plot);
+
+                let area = self.bars.iter().rev().find_map(|bar| {
+                    self.bar_bounds(bar, &rows, view, state.scroll)
+                        .filter(|area| area.expand(1.0).contains(position))
+                });
+
+                match area {
+                    Some(area)
+                        if area.width > EDGE_WIDTH * 3.0
+                            && (position.x < area.x + EDGE_WIDTH
+                                || position.x
+                                    > area.x + area.width - EDGE_WIDTH) =>
+                    {
+                        mouse::Interaction::ResizingHorizontally
+                    }
+                    Some(_) => mouse::Interaction::Grab,
+                    None => mouse::Interaction::default(),
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<Theme>,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let plot = plot(bounds.size());
+
+        if plot.width <= 0.0 || plot.height <= 0.0 {
+            return;
+        }
+
+        let (rows, _) = self.rows();
+        let view @ (start, scale) = self.view(state, plot);
+        let scroll = state.scroll;
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        frame.fill_rectangle(
+            Point::ORIGIN,
+            bounds.size(),
+            appearance.background,
+        );
+
+        let label =
+            |frame: &mut Frame,
+             content: String,
+             position: Point,
+             color: Color,
+             horizontal_alignment: alignment::Horizontal| {
+                frame.fill_text(Text {
+                    content,
+                    position,
+                    color,
+                    size: TEXT_SIZE.into(),
+                    horizontal_alignment,
+                    vertical_alignment: alignment::Vertical::Center,
+                    ..Text::default()
+                });
+            };
+
+        let step = tick_step(TICK_SPACING / scale);
+        let first_tick = (start / step).ceil() as i64;
+        let last_tick = ((start + plot.width / scale) / step).floor() as i64;
+
+        // Plot: grid, bars, and dependencies
+        frame.with_clip(plot, |frame| {
+            frame.translate(Vector::new(-plot.x, -plot.y));
+
+            let grid = Stroke::default()
+                .with_color(appearance.grid)
+                .with_width(1.0);
+
+            for tick in first_tick..=last_tick {
+                let x = plot.x + (tick as f32 * step - start) * scale;
+
+                frame.stroke(
+                    &Path::line(
+                        Point::new(x, plot.y),
+                        Point::new(x, plot.y + plot.height),
+                    ),
+                    grid.clone(),
+                );
+            }
+
+            let mut y = AXIS_HEIGHT - scroll;
+
+            for group in self.groups {
+                frame.fill_rectangle(
+                    Point::new(plot.x, y),
+                    Size::new(plot.width, GROUP_HEIGHT),
+                    appearance.group_background,
+                );
+
+                y += GROUP_HEIGHT + ROW_HEIGHT * group.rows.len() as f32;
+
+                frame.stroke(
+                    &Path::line(
+                        Point::new(plot.x, y),
+                        Point::new(plot.x + plot.width, y),
+                    ),
+                    grid.clone(),
+                );
+            }
+
+            for bar in self.bars {
+                let Some(area) = self.bar_bounds(bar, &rows, view, scroll)
+                else {
+                    continue;
+                };
+
+                if area.x > plot.x + plot.width || area.x + area.width < plot.x
+                {
+                    continue;
+                }
+
+                frame.fill_rectangle(
+                    area.position(),
+                    area.size(),
+                    bar.color.unwrap_or(appearance.bar),
+                );
+
+                if !bar.label.is_empty() {
+                    let clip = Rectangle::new(
+                        area.position() - Vector::new(plot.x, plot.y),
+                        area.size(),
+                    );
+
+                    // Clipped regions are placed ignoring the translation
+                    frame.with_clip(clip, |frame| {
+                        label(
+                            frame,
+                            bar.label.clone(),
+                            Point::new(
+                                (plot.x - area.x).max(0.0) + 6.0,
+                                area.height / 2.0,
+                            ),
+                            appearance.bar_text,
+                            alignment::Horizontal::Left,
+                        );
+                    });
+                }
+            }
+
+            let arrow = Stroke::default()
+                .with_color(appearance.dependency)
+                .with_width(1.5);
+
+            for dependency in self.dependencies {
+                let (Some(from), Some(to)) = (
+                    self.bars.get(dependency.from).and_then(|bar| {
+                        self.bar_bounds(bar, &rows, view, scroll)
+                    }),
+                    self.bars.get(dependency.to).and_then(|bar| {
+                        self.bar_bounds(bar, &rows, view, scroll)
+                    }),
+                ) else {
+                    continue;
+                };
+
+                let source = Point::new(from.x + from.width, from.center_y());
+                let target = Point::new(to.x, to.center_y());
+                let elbow = (source.x + 8.0).max(target.x - 8.0);
+
+                frame.stroke(
+                    &Path::new(|builder| {
+                        builder.move_to(source);
+
+                        if elbow <= target.x - 8.0 {
+                            builder.line_to(Point::new(elbow, source.y));
+                            builder.line_to(Point::new(elbow, target.y));
+                        } else {
+                            // Go around when the target starts before the
+                            // source ends
+                            let middle = if target.y > source.y {
+                                to.y - BAR_MARGIN
+                            } else {
+                                to.y + to.height + BAR_MARGIN
+                            };
+
+                            builder
+                                .line_to(Point::new(source.x + 8.0, source.y));
+                            builder.line_to(Point::new(source.x + 8.0, middle));
+                            builder.line_to(Point::new(target.x - 8.0, middle));
+                            builder
+                                .line_to(Point::new(target.x - 8.0, target.y));
+                        }
+
+                        builder.line_to(target);
+                    }),
+                    arrow.clone(),
+                );
+
+                frame.fill(
+                    &Path::new(|builder| {
+                        builder.move_to(target);
+                        builder.line_to(Point::new(
+                            target.x - 6.0,
+                            target.y - 4.0,
+                        ));
+                        builder.line_to(Point::new(
+                            target.x - 6.0,
+                            target.y + 4.0,
+                        ));
+                        builder.close();
+                    }),
+                    appearance.dependency,
+                );
+            }
+        });
+
+        // Time axis
+        frame.fill_rectangle(
+            Point::ORIGIN,
+            Size::new(bounds.width, AXIS_HEIGHT),
+            appearance.header_background,
+        );
+
+        frame.with_clip(
+            Rectangle::new(
+                Point::new(plot.x, 0.0),
+                Size::new(plot.width, AXIS_HEIGHT),
+            ),
+            |frame| {
+                for tick in first_tick..=last_tick {
+                    let time = tick as f32 * step;
+
+                    label(
+                        frame,
+                        (self.format)(time),
+                        Point::new((time - start) * scale, AXIS_HEIGHT / 2.0),
+                        appearance.text,
+                        alignment::Horizontal::Center,
+                    );
+                }
+            },
+        );
+
+        // Labels of the groups and rows
+        frame.with_clip(
+            Rectangle::new(
+                Point::new(0.0, AXIS_HEIGHT),
+                Size::new(LABEL_WIDTH, plot.height),
+            ),
+            |frame| {
+                frame.fill_rectangle(
+                    Point::ORIGIN,
+                    Size::new(LABEL_WIDTH, plot.height),
+                    appearance.header_background,
+                );
+
+                let mut y = -scroll;
+
+                for group in self.groups {
+                    frame.fill_rectangle(
+                        Point::new(0.0, y),
+                        Size::new(LABEL_WIDTH, GROUP_HEIGHT),
+                        appearance.group_background,
+                    );
+
+                    label(
+                        frame,
+                        group.label.clone(),
+                        Point::new(8.0, y + GROUP_HEIGHT / 2.0),
+                        appearance.text,
+                        alignment::Horizontal::Left,
+                    );
+
+                    y += GROUP_HEIGHT;
+
+                    for row in &group.rows {
+                        label(
+                            frame,
+                            row.clone(),
+                            Point::new(16.0, y + ROW_HEIGHT / 2.0),
+                            appearance.text,
+                            alignment::Horizontal::Left,
+                        );
+
+                        y += ROW_HEIGHT;
+                    }
+                }
+            },
+        );
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw(vec![geometry]);
+            },
+        );
+    }
+}
+
+/// Returns the area of the bars, relative to the widget.
+fn plot(size: Size) -> Rectangle {
+    Rectangle {
+        x: LABEL_WIDTH,
+        y: AXIS_HEIGHT,
+        width: (size.width - LABEL_WIDTH).max(0.0),
+        height: (size.height - AXIS_HEIGHT).max(0.0),
+    }
+}
+
+impl<'a, Message, Theme> From<Timeline<'a, Message, Theme>>
+    for Element<'a, Message, Renderer<Theme>>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+{
+    fn from(timeline: Timeline<'a, Message, Theme>) -> Self {
+        Self::new(timeline)
+    }
+}
@@ -1,17 +1,22 @@
 //! Show toggle controls using togglers.
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event;
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::token::{self, Step};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size,
-    Widget,
+    Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shadow,
+    Shell, Size, Widget,
 };
+use crate::focus_ring;
+use crate::style::focus;
 
 pub use crate::style::toggler::{Appearance, StyleSheet};
 
@@ -48,6 +53,7 @@ where
     text_shaping: text::Shaping,
     spacing: f32,
     font: Option<Renderer::Font>,
+    tab_index: i32,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -85,8 +91,9 @@ where
             text_line_height: text::LineHeight::default(),
             text_alignment: alignment::Horizontal::Left,
             text_shaping: text::Shaping::Basic,
-            spacing: Self::DEFAULT_SIZE / 2.0,
+            spacing: token::spacing(Step::Md),
             font: None,
+            tab_index: 0,
             style: Default::default(),
         }
     }
@@ -144,6 +151,16 @@ where
         self
     }
 
+    /// Sets the position of the [`Toggler`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the style of the [`Toggler`].
     pub fn style(
         mut self,
@@ -158,14 +175,14 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Toggler<'a, Message, Renderer>
 where
     Renderer: text::Renderer,
-    Renderer::Theme: StyleSheet + crate::text::StyleSheet,
+    Renderer::Theme: StyleSheet + crate::text::StyleSheet + focus::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn width(&self) -> Length {
@@ -190,12 +207,11 @@ where
             |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -215,9 +231,36 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(
+            &mut widget::operation::focusable::with_tab_index(
+                state,
+                self.tab_index,
+            ),
+            None,
+        );
+
+        let mut node = accessibility::Node::new(accessibility::Role::Switch)
+            .toggled(self.is_toggled);
+
+        if let Some(label) = &self.label {
+            node = node.label(label.clone());
+        }
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -226,9 +269,14 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Pointer interactions hide the focus ring
+                state.is_focused = false;
+
                 let mouse_over = cursor.is_over(layout.bounds());
 
                 if mouse_over {
@@ -239,6 +287,14 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            }) if state.is_focused => {
+                shell.publish((self.on_toggle)(!self.is_toggled));
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -275,6 +331,8 @@ where
         /// between the background Quad and foreground Quad.
         const SPACE_RATIO: f32 = 0.05;
 
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
 
@@ -285,7 +343,7 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance::default(),
             );
         }
@@ -317,6 +375,7 @@ where
                 border_color: style
                     .background_border
                     .unwrap_or(style.background),
+                shadow: Shadow::default(),
             },
             style.background,
         );
@@ -341,9 +400,14 @@ where
                 border_color: style
                     .foreground_border
                     .unwrap_or(style.foreground),
+                shadow: Shadow::default(),
             },
             style.foreground,
         );
+
+        if state.is_focused {
+            focus_ring::draw(renderer, bounds, theme);
+        }
     }
 }
 
@@ -352,7 +416,7 @@ impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
 where
     Message: 'a,
     Renderer: 'a + text::Renderer,
-    Renderer::Theme: StyleSheet + crate::text::StyleSheet,
+    Renderer::Theme: StyleSheet + crate::text::StyleSheet + focus::StyleSheet,
 {
     fn from(
         toggler: Toggler<'a, Message, Renderer>,
@@ -360,3 +424,23 @@ where
         Element::new(toggler)
     }
 }
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+}
+
+impl<P: text::Paragraph> widget::operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
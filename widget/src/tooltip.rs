@@ -5,73 +5,63 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::token::{self, Step};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
-    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
-    Vector,
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Vector,
 };
-use crate::Text;
-
-use std::borrow::Cow;
 
 /// An element to display a widget over another.
 #[allow(missing_debug_implementations)]
 pub struct Tooltip<'a, Message, Renderer = crate::Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + crate::text::StyleSheet,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     content: Element<'a, Message, Renderer>,
-    tooltip: Text<'a, Renderer>,
+    tooltip: Element<'a, Message, Renderer>,
     position: Position,
     gap: f32,
     padding: f32,
     snap_within_viewport: bool,
+    delay: Duration,
+    hide_delay: Duration,
+    arrow: Option<f32>,
     style: <Renderer::Theme as container::StyleSheet>::Style,
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + crate::text::StyleSheet,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
-    /// The default padding of a [`Tooltip`] drawn by this renderer.
-    const DEFAULT_PADDING: f32 = 5.0;
-
     /// Creates a new [`Tooltip`].
     ///
+    /// The tooltip can be any widget; a `&str` will be displayed as text.
+    ///
     /// [`Tooltip`]: struct.Tooltip.html
     pub fn new(
         content: impl Into<Element<'a, Message, Renderer>>,
-        tooltip: impl Into<Cow<'a, str>>,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
         position: Position,
     ) -> Self {
         Tooltip {
             content: content.into(),
-            tooltip: Text::new(tooltip),
+            tooltip: tooltip.into(),
             position,
             gap: 0.0,
-            padding: Self::DEFAULT_PADDING,
+            padding: token::spacing(Step::Sm),
             snap_within_viewport: true,
+            delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            arrow: None,
             style: Default::default(),
         }
     }
 
-    /// Sets the size of the text of the [`Tooltip`].
-    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
-        self.tooltip = self.tooltip.size(size);
-        self
-    }
-
-    /// Sets the font of the [`Tooltip`].
-    ///
-    /// [`Font`]: Renderer::Font
-    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
-        self.tooltip = self.tooltip.font(font);
-        self
-    }
-
     /// Sets the gap between the content and its [`Tooltip`].
     pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
         self.gap = gap.into().0;
@@ -85,11 +75,38 @@ where
     }
 
     /// Sets whether the [`Tooltip`] is snapped within the viewport.
+    ///
+    /// When enabled, the [`Tooltip`] is also flipped to the opposite side of
+    /// its content if it does not fit in the viewport.
     pub fn snap_within_viewport(mut self, snap: bool) -> Self {
         self.snap_within_viewport = snap;
         self
     }
 
+    /// Sets the time the content needs to be hovered before showing the
+    /// [`Tooltip`].
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the time the [`Tooltip`] remains visible after the cursor leaves
+    /// its content.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// Draws an arrow of the given size pointing from the [`Tooltip`] to its
+    /// content.
+    ///
+    /// The arrow is placed in the gap between them and it takes the
+    /// [`Background`] color of the [`Tooltip`].
+    pub fn arrow(mut self, size: impl Into<Pixels>) -> Self {
+        self.arrow = Some(size.into().0);
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     pub fn style(
         mut self,
@@ -98,23 +115,34 @@ where
         self.style = style.into();
         self
     }
+
+    fn is_visible(&self, state: &State, now: Instant) -> bool {
+        match *state {
+            State::Idle => false,
+            State::Hovered { since, .. } => now >= since + self.delay,
+            State::Leaving { until, .. } => now < until,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + crate::text::StyleSheet,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     fn children(&self) -> Vec<widget::Tree> {
         vec![
             widget::Tree::new(&self.content),
-            widget::Tree::new(&self.tooltip as &dyn Widget<Message, _>),
+            widget::Tree::new(&self.tooltip),
         ]
     }
 
     fn diff(&self, tree: &mut widget::Tree) {
-        tree.diff_children(&[self.content.as_widget(), &self.tooltip]);
+        tree.diff_children(&[
+            self.content.as_widget(),
+            self.tooltip.as_widget(),
+        ]);
     }
 
     fn state(&self) -> widget::tree::State {
@@ -155,12 +183,56 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        let state = tree.state.downcast_mut::<State>();
+        let state = *tree.state.downcast_ref::<State>();
+        let now = Instant::now();
+        let is_visible = self.is_visible(&state, now);
 
-        *state = cursor
-            .position_over(layout.bounds())
-            .map(|cursor_position| State::Hovered { cursor_position })
-            .unwrap_or_default();
+        let new_state = match (cursor.position_over(layout.bounds()), state) {
+            (Some(cursor_position), State::Hovered { since, .. }) => {
+                State::Hovered {
+                    cursor_position,
+                    since,
+                }
+            }
+            (Some(cursor_position), State::Leaving { .. }) if is_visible => {
+                // Keep showing the tooltip if the cursor comes back
+                State::Hovered {
+                    cursor_position,
+                    since: now.checked_sub(self.delay).unwrap_or(now),
+                }
+            }
+            (Some(cursor_position), _) => State::Hovered {
+                cursor_position,
+                since: now,
+            },
+            (
+                None,
+                State::Hovered {
+                    cursor_position, ..
+                },
+            ) if is_visible && self.hide_delay > Duration::ZERO => {
+                State::Leaving {
+                    cursor_position,
+                    until: now + self.hide_delay,
+                }
+            }
+            (None, State::Leaving { .. }) if is_visible => state,
+            (None, _) => State::Idle,
+        };
+
+        match new_state {
+            State::Hovered { since, .. } if now < since + self.delay => {
+                shell.request_redraw(window::RedrawRequest::At(
+                    since + self.delay,
+                ));
+            }
+            State::Leaving { until, .. } => {
+                shell.request_redraw(window::RedrawRequest::At(until));
+            }
+            _ => {}
+        }
+
+        *tree.state.downcast_mut::<State>() = new_state;
 
         self.content.as_widget_mut().on_event(
             &mut tree.children[0],
@@ -218,7 +290,8 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        let state = tree.state.downcast_ref::<State>();
+        let state = *tree.state.downcast_ref::<State>();
+        let is_visible = self.is_visible(&state, Instant::now());
 
         let mut children = tree.children.iter_mut();
 
@@ -228,8 +301,13 @@ where
             renderer,
         );
 
-        let tooltip = if let State::Hovered { cursor_position } = *state {
-            Some(overlay::Element::new(
+        let tooltip = match state {
+            State::Hovered {
+                cursor_position, ..
+            }
+            | State::Leaving {
+                cursor_position, ..
+            } if is_visible => Some(overlay::Element::new(
                 layout.position(),
                 Box::new(Overlay {
                     tooltip: &self.tooltip,
@@ -240,11 +318,13 @@ where
                     position: self.position,
                     gap: self.gap,
                     padding: self.padding,
+                    arrow: self.arrow,
+                    anchor: Rectangle::with_size(Size::ZERO),
+                    side: self.position,
                     style: &self.style,
                 }),
-            ))
-        } else {
-            None
+            )),
+            _ => None,
         };
 
         if content.is_some() || tooltip.is_some() {
@@ -264,8 +344,8 @@ impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Message: 'a,
-    Renderer: 'a + text::Renderer,
-    Renderer::Theme: container::StyleSheet + crate::text::StyleSheet,
+    Renderer: 'a + crate::core::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     fn from(
         tooltip: Tooltip<'a, Message, Renderer>,
@@ -289,21 +369,37 @@ pub enum Position {
     Right,
 }
 
+impl Position {
+    fn opposite(self) -> Self {
+        match self {
+            Position::FollowCursor | Position::Top => Position::Bottom,
+            Position::Bottom => Position::Top,
+            Position::Left => Position::Right,
+            Position::Right => Position::Left,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 enum State {
     #[default]
     Idle,
     Hovered {
         cursor_position: Point,
+        since: Instant,
+    },
+    Leaving {
+        cursor_position: Point,
+        until: Instant,
     },
 }
 
-struct Overlay<'a, 'b, Renderer>
+struct Overlay<'a, 'b, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + widget::text::StyleSheet,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
-    tooltip: &'b Text<'a, Renderer>,
+    tooltip: &'b Element<'a, Message, Renderer>,
     state: &'b mut widget::Tree,
     cursor_position: Point,
     content_bounds: Rectangle,
@@ -311,14 +407,17 @@ where
     position: Position,
     gap: f32,
     padding: f32,
+    arrow: Option<f32>,
+    anchor: Rectangle,
+    side: Position,
     style: &'b <Renderer::Theme as container::StyleSheet>::Style,
 }
 
 impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
-    for Overlay<'a, 'b, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + widget::text::StyleSheet,
+    Renderer: crate::core::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     fn layout(
         &mut self,
@@ -328,8 +427,7 @@ where
     ) -> layout::Node {
         let viewport = Rectangle::with_size(bounds);
 
-        let text_layout = Widget::<(), Renderer>::layout(
-            self.tooltip,
+        let tooltip_layout = self.tooltip.as_widget().layout(
             self.state,
             renderer,
             &layout::Limits::new(
@@ -341,55 +439,95 @@ where
             .pad(Padding::new(self.padding)),
         );
 
-        let text_bounds = text_layout.bounds();
-        let x_center =
-            position.x + (self.content_bounds.width - text_bounds.width) / 2.0;
-        let y_center = position.y
-            + (self.content_bounds.height - text_bounds.height) / 2.0;
+        let tooltip_size = tooltip_layout.bounds().size();
+        let gap = self.gap + self.arrow.unwrap_or(0.0);
+        let cursor_position =
+            self.cursor_position + (position - self.content_bounds.position());
 
-        let mut tooltip_bounds = {
-            let offset = match self.position {
+        let place = |side: Position, anchor: Rectangle| {
+            let x_center = anchor.x + (anchor.width - tooltip_size.width) / 2.0;
+            let y_center =
+                anchor.y + (anchor.height - tooltip_size.height) / 2.0;
+
+            let offset = match side {
                 Position::Top => Vector::new(
                     x_center,
-                    position.y - text_bounds.height - self.gap - self.padding,
+                    anchor.y - tooltip_size.height - gap - self.padding,
                 ),
                 Position::Bottom => Vector::new(
                     x_center,
-                    position.y
-                        + self.content_bounds.height
-                        + self.gap
-                        + self.padding,
+                    anchor.y + anchor.height + gap + self.padding,
                 ),
                 Position::Left => Vector::new(
-                    position.x - text_bounds.width - self.gap - self.padding,
+                    anchor.x - tooltip_size.width - gap - self.padding,
                     y_center,
                 ),
                 Position::Right => Vector::new(
-                    position.x
-                        + self.content_bounds.width
-                        + self.gap
-                        + self.padding,
+                    anchor.x + anchor.width + gap + self.padding,
                     y_center,
                 ),
-                Position::FollowCursor => {
-                    let translation = position - self.content_bounds.position();
-
-                    Vector::new(
-                        self.cursor_position.x,
-                        self.cursor_position.y - text_bounds.height,
-                    ) + translation
-                }
+                Position::FollowCursor => Vector::new(
+                    cursor_position.x,
+                    cursor_position.y - tooltip_size.height,
+                ),
             };
 
             Rectangle {
                 x: offset.x - self.padding,
                 y: offset.y - self.padding,
-                width: text_bounds.width + self.padding * 2.0,
-                height: text_bounds.height + self.padding * 2.0,
+                width: tooltip_size.width + self.padding * 2.0,
+                height: tooltip_size.height + self.padding * 2.0,
             }
         };
 
+        let overflows = |side: Position, bounds: Rectangle| match side {
+            Position::Top | Position::FollowCursor => bounds.y < viewport.y,
+            Position::Bottom => {
+                bounds.y + bounds.height > viewport.y + viewport.height
+            }
+            Position::Left => bounds.x < viewport.x,
+            Position::Right => {
+                bounds.x + bounds.width > viewport.x + viewport.width
+            }
+        };
+
+        let (mut side, mut anchor) = match self.position {
+            // An arrow needs the tooltip to be centered on the cursor
+            Position::FollowCursor if self.arrow.is_some() => {
+                (Position::Top, Rectangle::new(cursor_position, Size::ZERO))
+            }
+            Position::FollowCursor => (
+                Position::FollowCursor,
+                Rectangle::new(cursor_position, Size::ZERO),
+            ),
+            side => {
+                (side, Rectangle::new(position, self.content_bounds.size()))
+            }
+        };
+
+        let mut tooltip_bounds = place(side, anchor);
+
         if self.snap_within_viewport {
+            if overflows(side, tooltip_bounds) {
+                let flipped = side.opposite();
+
+                let flipped_anchor = if self.position == Position::FollowCursor
+                {
+                    // Leave room for the cursor icon
+                    Rectangle::new(cursor_position, Size::new(0.0, 16.0))
+                } else {
+                    anchor
+                };
+
+                let flipped_bounds = place(flipped, flipped_anchor);
+
+                if !overflows(flipped, flipped_bounds) {
+                    side = flipped;
+                    anchor = flipped_anchor;
+                    tooltip_bounds = flipped_bounds;
+                }
+            }
+
             if tooltip_bounds.x < viewport.x {
                 tooltip_bounds.x = viewport.x;
             } else if viewport.x + viewport.width
@@ -409,9 +547,13 @@ where
             }
         }
 
+        self.side = side;
+        self.anchor = anchor;
+
         layout::Node::with_children(
             tooltip_bounds.size(),
-            vec![text_layout.translate(Vector::new(self.padding, self.padding))],
+            vec![tooltip_layout
+                .translate(Vector::new(self.padding, self.padding))],
         )
         .translate(Vector::new(tooltip_bounds.x, tooltip_bounds.y))
     }
@@ -428,14 +570,21 @@ where
             theme, self.style,
         );
 
-        container::draw_background(renderer, &style, layout.bounds());
+        let bounds = layout.bounds();
+
+        container::draw_background(renderer, &style, bounds);
+
+        if let (Some(size), Some(Background::Color(color))) =
+            (self.arrow, style.background)
+        {
+            draw_arrow(renderer, bounds, self.anchor, self.side, size, color);
+        }
 
         let defaults = renderer::Style {
             text_color: style.text_color.unwrap_or(inherited_style.text_color),
         };
 
-        Widget::<(), Renderer>::draw(
-            self.tooltip,
+        self.tooltip.as_widget().draw(
             self.state,
             renderer,
             theme,
@@ -455,3 +604,69 @@ where
         false
     }
 }
+
+/// Draws an arrow from the given tooltip bounds towards its anchor, out of
+/// stacked quads of decreasing length.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    anchor: Rectangle,
+    side: Position,
+    size: f32,
+    color: Color,
+) where
+    Renderer: crate::core::Renderer,
+{
+    let x = anchor
+        .center_x()
+        .max(bounds.x + size)
+        .min(bounds.x + bounds.width - size);
+
+    let y = anchor
+        .center_y()
+        .max(bounds.y + size)
+        .min(bounds.y + bounds.height - size);
+
+    for step in 0..size.ceil() as usize {
+        let step = step as f32;
+        let half = size - step;
+
+        let quad = match side {
+            Position::Top | Position::FollowCursor => Rectangle {
+                x: x - half,
+                y: bounds.y + bounds.height + step,
+                width: half * 2.0,
+                height: 1.0,
+            },
+            Position::Bottom => Rectangle {
+                x: x - half,
+                y: bounds.y - step - 1.0,
+                width: half * 2.0,
+                height: 1.0,
+            },
+            Position::Left => Rectangle {
+                x: bounds.x + bounds.width + step,
+                y: y - half,
+                width: 1.0,
+                height: half * 2.0,
+            },
+            Position::Right => Rectangle {
+                x: bounds.x - step - 1.0,
+                y: y - half,
+                width: 1.0,
+                height: half * 2.0,
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: quad,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! Animate widgets as they enter and leave the screen.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Vector, Widget,
+};
+use crate::runtime::animation::{Animation, Easing};
+
+/// A wrapper that animates its content when it appears and disappears.
+///
+/// The content plays the enter [`Effect`] when the [`Transition`] is first
+/// added to the widget tree and whenever it becomes visible. When it is
+/// hidden, the content stays on screen until the exit [`Effect`] finishes,
+/// and then the `on_exit` message is produced so the application can remove
+/// it for good.
+#[allow(missing_debug_implementations)]
+pub struct Transition<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    is_visible: bool,
+    enter: Effect,
+    exit: Effect,
+    duration: Duration,
+    easing: Easing,
+    on_exit: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Transition<'a, Message, Renderer> {
+    /// The default duration of a [`Transition`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Transition`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Transition {
+            content: content.into(),
+            is_visible: true,
+            enter: Effect::default(),
+            exit: Effect::default(),
+            duration: Self::DEFAULT_DURATION,
+            easing: Easing::EaseOut,
+            on_exit: None,
+        }
+    }
+
+    /// Sets whether the content of the [`Transition`] is visible.
+    ///
+    /// Changing it plays the enter or exit [`Effect`].
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = is_visible;
+        self
+    }
+
+    /// Sets the [`Effect`] played when the content appears.
+    ///
+    /// The exit [`Effect`] is set to the same one, unless it is changed
+    /// afterwards with [`Transition::exit`].
+    pub fn enter(mut self, effect: Effect) -> Self {
+        self.enter = effect;
+        self.exit = effect;
+        self
+    }
+
+    /// Sets the [`Effect`] played when the content disappears.
+    pub fn exit(mut self, effect: Effect) -> Self {
+        self.exit = effect;
+        self
+    }
+
+    /// Sets the [`Duration`] of the [`Transition`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] of the [`Transition`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the message produced once the exit [`Effect`] has finished.
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+}
+
+/// The way the content of a [`Transition`] appears and disappears.
+///
+/// It describes how the content looks while hidden; the [`Transition`]
+/// interpolates between that and the content at rest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Effect {
+    /// The offset of the hidden content.
+    pub offset: Vector,
+
+    /// The scale of the visible area of the hidden content, in the
+    /// `0.0..=1.0` range.
+    ///
+    /// The content is revealed from its center as it grows.
+    pub scale: f32,
+}
+
+impl Effect {
+    /// An [`Effect`] that does not change the content at all.
+    pub const NONE: Self = Self {
+        offset: Vector::ZERO,
+        scale: 1.0,
+    };
+
+    /// Creates an [`Effect`] that slides the content in from the given
+    /// offset.
+    pub fn slide(offset: Vector) -> Self {
+        Self {
+            offset,
+            ..Self::NONE
+        }
+    }
+
+    /// Creates an [`Effect`] that grows the content from the given scale.
+    pub fn zoom(scale: f32) -> Self {
+        Self {
+            scale: scale.clamp(0.0, 1.0),
+            ..Self::NONE
+        }
+    }
+
+    /// Sets the offset of the [`Effect`].
+    pub fn offset(self, offset: Vector) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Sets the scale of the [`Effect`].
+    pub fn scale(self, scale: f32) -> Self {
+        Self {
+            scale: scale.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    fn offset_at(&self, progress: f32) -> Vector {
+        self.offset * (1.0 - progress)
+    }
+
+    fn clip_at(&self, bounds: Rectangle, progress: f32) -> Rectangle {
+        let scale = self.scale + (1.0 - self.scale) * progress;
+
+        let width = bounds.width * scale;
+        let height = bounds.height * scale;
+
+        Rectangle {
+            x: bounds.center_x() - width / 2.0,
+            y: bounds.center_y() - height / 2.0,
+            width,
+            height,
+        }
+    }
+}
+
+impl Default for Effect {
+    fn default() -> Self {
+        Self::slide(Vector::new(0.0, 10.0))
+    }
+}
+
+struct State {
+    visibility: Animation<f32>,
+    is_exited: bool,
+}
+
+impl State {
+    fn is_hidden(&self) -> bool {
+        self.is_exited && *self.visibility.target() == 0.0
+    }
+}
+
+impl<'a, Message, Renderer> Transition<'a, Message, Renderer> {
+    fn effect(&self, state: &State) -> Effect {
+        if *state.visibility.target() > 0.0 {
+            self.enter
+        } else {
+            self.exit
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Transition<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Message: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut visibility = Animation::new(0.0)
+            .duration(self.duration)
+            .easing(self.easing);
+
+        if self.is_visible {
+            visibility.go_to(1.0, Instant::now());
+        }
+
+        tree::State::new(State {
+            visibility,
+            is_exited: !self.is_visible,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+
+        let state = tree.state.downcast_mut::<State>();
+        let target = if self.is_visible { 1.0 } else { 0.0 };
+
+        if *state.visibility.target() != target {
+            let now = Instant::now();
+
+            state.visibility = state
+                .visibility
+                .clone()
+                .duration(self.duration)
+                .easing(self.easing);
+            state.visibility.go_to(target, now);
+            state.is_exited = false;
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if tree.state.downcast_ref::<State>().is_hidden() {
+            return;
+        }
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.visibility.request_redraw(now, shell);
+
+            if !state.visibility.is_animating(now)
+                && *state.visibility.target() == 0.0
+                && !state.is_exited
+            {
+                state.is_exited = true;
+
+                if let Some(on_exit) = &self.on_exit {
+                    shell.publish(on_exit.clone());
+                }
+            }
+        }
+
+        // Content on its way out does not take any more input
+        if *state.visibility.target() == 0.0 {
+            return event::Status::Ignored;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if *state.visibility.target() == 0.0 {
+            return mouse::Interaction::default();
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_hidden() {
+            return;
+        }
+
+        let progress = state.visibility.value(Instant::now());
+
+        if progress >= 1.0 {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                renderer_style,
+                layout,
+                cursor,
+                viewport,
+            );
+
+            return;
+        }
+
+        let effect = self.effect(state);
+        let offset = effect.offset_at(progress);
+        let clip = effect.clip_at(layout.bounds(), progress);
+        let clip = Rectangle {
+            x: clip.x + offset.x,
+            y: clip.y + offset.y,
+            ..clip
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_translation(offset, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    renderer_style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    viewport,
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        if *tree.state.downcast_ref::<State>().visibility.target() == 0.0 {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Transition<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        transition: Transition<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(transition)
+    }
+}
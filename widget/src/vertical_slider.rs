@@ -6,16 +6,21 @@ use std::ops::RangeInclusive;
 pub use crate::style::slider::{Appearance, Handle, HandleShape, StyleSheet};
 
 use crate::core;
+use crate::core::accessibility;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, Color, Element, Length, Pixels, Point, Rectangle, Shell, Size,
-    Widget,
+    Clipboard, Color, Element, Length, Pixels, Point, Rectangle, Shadow, Shell,
+    Size, Widget,
 };
+use crate::focus_ring;
+use crate::style::focus;
 
 /// An vertical bar and a handle that selects a single value from a range of
 /// values.
@@ -50,6 +55,7 @@ where
     value: T,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
+    tab_index: i32,
     width: f32,
     height: Length,
     style: <Renderer::Theme as StyleSheet>::Style,
@@ -95,6 +101,7 @@ where
             step: T::from(1),
             on_change: Box::new(on_change),
             on_release: None,
+            tab_index: 0,
             width: Self::DEFAULT_WIDTH,
             height: Length::Fill,
             style: Default::default(),
@@ -133,6 +140,16 @@ where
         self
     }
 
+    /// Sets the position of the [`VerticalSlider`] in the tab order.
+    ///
+    /// See [`Focusable::tab_index`] for the meaning of the index.
+    ///
+    /// [`Focusable::tab_index`]: crate::core::widget::operation::Focusable::tab_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the step size of the [`VerticalSlider`].
     pub fn step(mut self, step: T) -> Self {
         self.step = step;
@@ -146,7 +163,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Renderer: core::Renderer,
-    Renderer::Theme: StyleSheet,
+    Renderer::Theme: StyleSheet + focus::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -176,6 +193,30 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(
+            &mut operation::focusable::with_tab_index(state, self.tab_index),
+            None,
+        );
+
+        let node = accessibility::Node::new(accessibility::Role::Slider)
+            .numeric(
+                self.value.into(),
+                (*self.range.start()).into()..=(*self.range.end()).into(),
+                Some(self.step.into()),
+            );
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -221,6 +262,10 @@ where
             theme,
             &self.style,
         );
+
+        if tree.state.downcast_ref::<State>().is_focused {
+            focus_ring::draw(renderer, layout.bounds(), theme);
+        }
     }
 
     fn mouse_interaction(
@@ -241,7 +286,7 @@ where
     T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
     Message: 'a + Clone,
     Renderer: 'a + core::Renderer,
-    Renderer::Theme: StyleSheet,
+    Renderer::Theme: StyleSheet + focus::StyleSheet,
 {
     fn from(
         slider: VerticalSlider<'a, T, Message, Renderer>,
@@ -306,10 +351,14 @@ where
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            // Pointer interactions hide the focus ring
+            state.is_focused = false;
+
             if let Some(cursor_position) = cursor.position_over(layout.bounds())
             {
                 change(cursor_position);
                 state.is_dragging = true;
+                shell.capture_pointer();
 
                 return event::Status::Captured;
             }
@@ -334,6 +383,41 @@ where
                 return event::Status::Captured;
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused =>
+        {
+            let current = (*value).into();
+            let step = step.into();
+            let start = (*range.start()).into();
+            let end = (*range.end()).into();
+
+            let new_value = match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    current - step
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    current + step
+                }
+                keyboard::KeyCode::Home => start,
+                keyboard::KeyCode::End => end,
+                _ => return event::Status::Ignored,
+            };
+
+            if let Some(new_value) = T::from_f64(new_value.max(start).min(end))
+            {
+                if (current - new_value.into()).abs() > f64::EPSILON {
+                    shell.publish((on_change)(new_value));
+
+                    if let Some(on_release) = on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    *value = new_value;
+                }
+            }
+
+            return event::Status::Captured;
+        }
         _ => {}
     }
 
@@ -404,6 +488,7 @@ pub fn draw<T, R>(
             border_radius: style.rail.border_radius,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.1,
     );
@@ -419,6 +504,7 @@ pub fn draw<T, R>(
             border_radius: style.rail.border_radius,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
         },
         style.rail.colors.0,
     );
@@ -434,6 +520,7 @@ pub fn draw<T, R>(
             border_radius: handle_border_radius,
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            shadow: Shadow::default(),
         },
         style.handle.color,
     );
@@ -461,6 +548,7 @@ pub fn mouse_interaction(
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -468,4 +556,23 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`VerticalSlider`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
@@ -0,0 +1,628 @@
+//! Play videos from decoded frames.
+//!
+//! A [`Video`] does not decode anything by itself. Your application is in
+//! charge of decoding the frames (for instance, in a subscription) and
+//! handing the latest one to the widget as an [`image::Handle`] created with
+//! [`image::Handle::from_frame`]; which avoids hashing the pixels of every
+//! frame and lets the renderer upload them directly as textures.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::Duration;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, ContentFit, Element, Layout, Length, Point, Rectangle,
+    Shadow, Shell, Size, Widget,
+};
+
+pub use iced_style::video::{Appearance, StyleSheet};
+
+/// The height of the playback controls.
+const CONTROLS_HEIGHT: f32 = 36.0;
+
+/// The width reserved for the elapsed and total time.
+const TIME_WIDTH: f32 = 96.0;
+
+/// The size of the play and pause icons.
+const ICON_SIZE: f32 = 14.0;
+
+/// A widget displaying the frames of a video with playback controls.
+///
+/// The playback itself is driven by your application: the [`Video`] only
+/// displays the current frame and reports the interactions of the user
+/// with its controls as messages.
+#[allow(missing_debug_implementations)]
+pub struct Video<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    frame: Option<image::Handle>,
+    position: Duration,
+    duration: Duration,
+    is_playing: bool,
+    controls: bool,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    on_seek: Option<Box<dyn Fn(Duration) -> Message + 'a>>,
+    on_seek_release: Option<Message>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Video<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Video`] without any frame.
+    pub fn new() -> Self {
+        Self {
+            frame: None,
+            position: Duration::ZERO,
+            duration: Duration::ZERO,
+            is_playing: false,
+            controls: true,
+            width: Length::Fill,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            on_toggle: None,
+            on_seek: None,
+            on_seek_release: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the current frame of the [`Video`].
+    pub fn frame(mut self, frame: impl Into<image::Handle>) -> Self {
+        self.frame = Some(frame.into());
+        self
+    }
+
+    /// Sets the current playback position of the [`Video`].
+    pub fn position(mut self, position: Duration) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the total duration of the [`Video`].
+    ///
+    /// The seek bar is disabled while the duration is zero; as it should be
+    /// for live streams.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets whether the [`Video`] is currently playing.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the playback controls of the [`Video`] are displayed.
+    ///
+    /// They are displayed by default, while the [`Video`] is hovered or
+    /// paused.
+    pub fn controls(mut self, controls: bool) -> Self {
+        self.controls = controls;
+        self
+    }
+
+    /// Sets the width of the [`Video`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Video`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the frames of the [`Video`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the message that should be produced when the user plays or
+    /// pauses the [`Video`], given whether it should be playing.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(bool) -> Message + 'a,
+    ) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message that should be produced when the user seeks a new
+    /// position in the [`Video`].
+    ///
+    /// The message is produced continuously while the user drags the seek
+    /// bar.
+    pub fn on_seek(
+        mut self,
+        on_seek: impl Fn(Duration) -> Message + 'a,
+    ) -> Self {
+        self.on_seek = Some(Box::new(on_seek));
+        self
+    }
+
+    /// Sets the message that should be produced when the user stops
+    /// dragging the seek bar of the [`Video`].
+    pub fn on_seek_release(mut self, on_seek_release: Message) -> Self {
+        self.on_seek_release = Some(on_seek_release);
+        self
+    }
+
+    /// Sets the style of the [`Video`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Video<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The local state of a [`Video`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_seeking: bool,
+}
+
+/// The bounds of the playback controls of a [`Video`].
+struct Controls {
+    bar: Rectangle,
+    button: Rectangle,
+    track: Rectangle,
+    time: Rectangle,
+}
+
+impl Controls {
+    fn new(bounds: Rectangle) -> Self {
+        let bar = Rectangle {
+            y: bounds.y + bounds.height - CONTROLS_HEIGHT,
+            height: CONTROLS_HEIGHT,
+            ..bounds
+        };
+
+        let button = Rectangle {
+            width: CONTROLS_HEIGHT,
+            ..bar
+        };
+
+        let time = Rectangle {
+            x: bar.x + bar.width - TIME_WIDTH,
+            width: TIME_WIDTH,
+            ..bar
+        };
+
+        let track = Rectangle {
+            x: button.x + button.width,
+            width: (time.x - button.x - button.width).max(0.0),
+            ..bar
+        };
+
+        Self {
+            bar,
+            button,
+            track,
+            time,
+        }
+    }
+
+    /// Returns the bounds of the visible seek bar.
+    fn seek_bar(&self) -> Rectangle {
+        Rectangle {
+            x: self.track.x + 8.0,
+            y: self.track.center_y() - 2.0,
+            width: (self.track.width - 16.0).max(0.0),
+            height: 4.0,
+        }
+    }
+
+    /// Returns the position in the [`Video`] under the given point of the
+    /// seek bar.
+    fn seek(&self, point: Point, duration: Duration) -> Duration {
+        let track = self.seek_bar();
+        let ratio =
+            ((point.x - track.x) / track.width.max(1.0)).clamp(0.0, 1.0);
+
+        duration.mul_f32(ratio)
+    }
+}
+
+/// Formats a [`Duration`] as `h:mm:ss` or `m:ss`.
+fn format(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) =
+        (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Video<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match &self.frame {
+            Some(frame) => crate::image::layout(
+                renderer,
+                limits,
+                frame,
+                self.width,
+                self.height,
+                self.content_fit,
+                None,
+            ),
+            None => {
+                let size = limits
+                    .width(self.width)
+                    .height(self.height)
+                    .resolve(Size::new(320.0, 180.0));
+
+                layout::Node::new(size)
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let controls = Controls::new(bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if self.controls && controls.track.contains(position) {
+                    if let Some(on_seek) = &self.on_seek {
+                        if !self.duration.is_zero() {
+                            state.is_seeking = true;
+
+                            shell.publish(on_seek(
+                                controls.seek(position, self.duration),
+                            ));
+
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+
+                let is_over_controls =
+                    self.controls && controls.bar.contains(position);
+
+                if !is_over_controls || controls.button.contains(position) {
+                    if let Some(on_toggle) = &self.on_toggle {
+                        shell.publish(on_toggle(!self.is_playing));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if state.is_seeking {
+                    if let Some(on_seek) = &self.on_seek {
+                        shell.publish(on_seek(
+                            controls.seek(position, self.duration),
+                        ));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.is_seeking {
+                    state.is_seeking = false;
+
+                    if let Some(on_seek_release) = self.on_seek_release.clone()
+                    {
+                        shell.publish(on_seek_release);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let controls = Controls::new(bounds);
+
+        let can_seek =
+            self.controls && self.on_seek.is_some() && !self.duration.is_zero();
+
+        if state.is_seeking
+            || (can_seek && cursor.is_over(controls.track))
+            || (self.on_toggle.is_some()
+                && cursor.is_over(bounds)
+                && !(self.controls
+                    && cursor.is_over(controls.bar)
+                    && !cursor.is_over(controls.button)))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        if let Some(frame) = &self.frame {
+            crate::image::draw(
+                renderer,
+                layout,
+                frame,
+                self.content_fit,
+                image::FilterMethod::default(),
+                image::Effects::default(),
+                None,
+            );
+        }
+
+        let show_controls = self.controls
+            && (!self.is_playing || state.is_seeking || cursor.is_over(bounds));
+
+        if !show_controls || bounds.height < CONTROLS_HEIGHT {
+            return;
+        }
+
+        let controls = Controls::new(bounds);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: controls.bar,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                appearance.controls_background,
+            );
+
+            // Play and pause icons
+            let icon = Rectangle {
+                x: controls.button.center_x() - ICON_SIZE / 2.0,
+                y: controls.button.center_y() - ICON_SIZE / 2.0,
+                width: ICON_SIZE,
+                height: ICON_SIZE,
+            };
+
+            let bars: Vec<Rectangle> = if self.is_playing {
+                let width = ICON_SIZE / 3.0;
+
+                vec![
+                    Rectangle { width, ..icon },
+                    Rectangle {
+                        x: icon.x + ICON_SIZE - width,
+                        width,
+                        ..icon
+                    },
+                ]
+            } else {
+                // A triangle made of thin vertical slices
+                const SLICES: usize = 7;
+
+                let width = ICON_SIZE / SLICES as f32;
+
+                (0..SLICES)
+                    .map(|i| {
+                        let height =
+                            ICON_SIZE * (1.0 - i as f32 / SLICES as f32);
+
+                        Rectangle {
+                            x: icon.x + i as f32 * width,
+                            y: icon.center_y() - height / 2.0,
+                            width,
+                            height,
+                        }
+                    })
+                    .collect()
+            };
+
+            for bar in bars {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bar,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    },
+                    appearance.icon,
+                );
+            }
+
+            // Seek bar
+            let track = controls.seek_bar();
+
+            let progress = if self.duration.is_zero() {
+                0.0
+            } else {
+                (self.position.as_secs_f32() / self.duration.as_secs_f32())
+                    .clamp(0.0, 1.0)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: track,
+                    border_radius: 2.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                appearance.track,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: track.width * progress,
+                        ..track
+                    },
+                    border_radius: 2.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                },
+                appearance.progress,
+            );
+
+            if !self.duration.is_zero() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: track.x + track.width * progress - 6.0,
+                            y: track.center_y() - 6.0,
+                            width: 12.0,
+                            height: 12.0,
+                        },
+                        border_radius: 6.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    },
+                    appearance.progress,
+                );
+            }
+
+            // Elapsed and total time
+            let time = if self.duration.is_zero() {
+                format(self.position)
+            } else {
+                format!("{} / {}", format(self.position), format(self.duration))
+            };
+
+            let size = renderer.default_size();
+
+            renderer.fill_text(
+                text::Text {
+                    content: &time,
+                    bounds: controls.time.size(),
+                    size: (size.0 * 0.8).into(),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                },
+                controls.time.center(),
+                appearance.text,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Video<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(video: Video<'a, Message, Renderer>) -> Self {
+        Self::new(video)
+    }
+}
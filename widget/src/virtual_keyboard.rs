@@ -0,0 +1,677 @@
+//! Type into widgets without a physical keyboard.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shadow, Shell, Size, Widget,
+};
+
+pub use iced_style::virtual_keyboard::{Appearance, StyleSheet};
+
+/// An on-screen keyboard displayed under some content.
+///
+/// Pressing a key of a [`VirtualKeyboard`] produces the same keyboard events
+/// a physical keyboard would, which are then handled by the focused widget
+/// of its content.
+///
+/// # Example
+/// ```no_run
+/// # type VirtualKeyboard<'a, Message> =
+/// #     iced_widget::VirtualKeyboard<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type TextInput<'a, Message> =
+/// #     iced_widget::TextInput<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::virtual_keyboard::Layout;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PinChanged(String),
+/// }
+///
+/// let pin = "12";
+///
+/// let keyboard = VirtualKeyboard::new(
+///     TextInput::new("PIN", pin).on_input(Message::PinChanged),
+///     Layout::numeric(),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualKeyboard<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    layout: Layout,
+    is_visible: bool,
+    width: Length,
+    height: Length,
+    key_height: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> VirtualKeyboard<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default height of a key of a [`VirtualKeyboard`].
+    pub const DEFAULT_KEY_HEIGHT: f32 = 40.0;
+
+    /// The default spacing between the keys of a [`VirtualKeyboard`].
+    pub const DEFAULT_SPACING: f32 = 4.0;
+
+    /// Creates a new [`VirtualKeyboard`] typing into the given content with
+    /// the given [`Layout`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        layout: Layout,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            layout,
+            is_visible: true,
+            width: Length::Fill,
+            height: Length::Shrink,
+            key_height: Self::DEFAULT_KEY_HEIGHT,
+            spacing: Self::DEFAULT_SPACING,
+            padding: Padding::new(Self::DEFAULT_SPACING),
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets whether the keyboard of the [`VirtualKeyboard`] is displayed.
+    ///
+    /// A hidden keyboard takes no space.
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = is_visible;
+        self
+    }
+
+    /// Sets the width of the [`VirtualKeyboard`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualKeyboard`], including its content.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of each key of the [`VirtualKeyboard`].
+    pub fn key_height(mut self, key_height: impl Into<Pixels>) -> Self {
+        self.key_height = key_height.into().0;
+        self
+    }
+
+    /// Sets the spacing between the keys of the [`VirtualKeyboard`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the keyboard of the [`VirtualKeyboard`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the keys of the [`VirtualKeyboard`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the keys of the [`VirtualKeyboard`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`VirtualKeyboard`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn keyboard_height(&self) -> f32 {
+        if !self.is_visible {
+            return 0.0;
+        }
+
+        let rows = self.layout.rows.len();
+
+        self.key_height * rows as f32
+            + self.spacing * rows.saturating_sub(1) as f32
+            + self.padding.vertical()
+    }
+
+    /// Returns the bounds and position of every key, given the bounds of the
+    /// keyboard.
+    fn keys(&self, bounds: Rectangle) -> Vec<(Rectangle, (usize, usize))> {
+        let inner_width = bounds.width - self.padding.horizontal();
+
+        // All the rows share the same unit width, and are centered
+        let unit = self
+            .layout
+            .rows
+            .iter()
+            .filter(|row| !row.is_empty())
+            .map(|row| {
+                let units: f32 = row.iter().map(Key::width).sum();
+                let spacing = self.spacing * (row.len() - 1) as f32;
+
+                (inner_width - spacing).max(0.0) / units
+            })
+            .fold(f32::INFINITY, f32::min);
+
+        let mut keys = Vec::new();
+
+        for (i, row) in self.layout.rows.iter().enumerate() {
+            let row_width =
+                row.iter().map(|key| key.width() * unit).sum::<f32>()
+                    + self.spacing * row.len().saturating_sub(1) as f32;
+
+            let mut x =
+                bounds.x + self.padding.left + (inner_width - row_width) / 2.0;
+            let y = bounds.y
+                + self.padding.top
+                + (self.key_height + self.spacing) * i as f32;
+
+            for (j, key) in row.iter().enumerate() {
+                let width = key.width() * unit;
+
+                keys.push((
+                    Rectangle {
+                        x,
+                        y,
+                        width,
+                        height: self.key_height,
+                    },
+                    (i, j),
+                ));
+
+                x += width + self.spacing;
+            }
+        }
+
+        keys
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualKeyboard<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let keyboard_height = self.keyboard_height();
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, keyboard_height)),
+        );
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            content.size().height + keyboard_height,
+        ));
+
+        let mut keyboard =
+            layout::Node::new(Size::new(size.width, keyboard_height));
+        keyboard.move_to(Point::new(0.0, size.height - keyboard_height));
+
+        layout::Node::with_children(size, vec![content, keyboard])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: core::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: core::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let keyboard_bounds = children.next().unwrap().bounds();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.is_visible {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    // Presses on the keyboard never reach the content, so
+                    // its focused widget keeps its focus
+                    if cursor.is_over(keyboard_bounds) {
+                        let pressed = self
+                            .keys(keyboard_bounds)
+                            .into_iter()
+                            .find(|(bounds, _)| cursor.is_over(*bounds));
+
+                        if let Some((_, (row, column))) = pressed {
+                            state.pressed = Some((row, column));
+
+                            let key = self.layout.rows[row][column];
+
+                            for event in state.type_key(&key) {
+                                let _ = self.content.as_widget_mut().on_event(
+                                    &mut tree.children[0],
+                                    event,
+                                    content_layout,
+                                    mouse::Cursor::Unavailable,
+                                    renderer,
+                                    clipboard,
+                                    shell,
+                                    viewport,
+                                );
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.pressed = None;
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: core::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let keyboard_bounds = children.next().unwrap().bounds();
+
+        if self.is_visible && cursor.is_over(keyboard_bounds) {
+            return if self
+                .keys(keyboard_bounds)
+                .iter()
+                .any(|(bounds, _)| cursor.is_over(*bounds))
+            {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: core::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let keyboard_bounds = children.next().unwrap().bounds();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        if !self.is_visible {
+            return;
+        }
+
+        let appearance = theme.appearance(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: keyboard_bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (bounds, (row, column)) in self.keys(keyboard_bounds) {
+            let key = &self.layout.rows[row][column];
+
+            let is_pressed = state.pressed == Some((row, column))
+                || (*key == Key::Shift && state.is_shifted);
+
+            let appearance = if cursor.is_over(bounds) {
+                theme.hovered_key(&self.style, is_pressed)
+            } else {
+                theme.key(&self.style, is_pressed)
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: &key.label(state.is_shifted),
+                    bounds: bounds.size(),
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                bounds.center(),
+                appearance.text_color,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: core::Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualKeyboard<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(keyboard: VirtualKeyboard<'a, Message, Renderer>) -> Self {
+        Element::new(keyboard)
+    }
+}
+
+/// The arrangement of the keys of a [`VirtualKeyboard`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    rows: Vec<Vec<Key>>,
+}
+
+impl Layout {
+    /// Creates a custom [`Layout`] with the given rows of keys.
+    pub fn new(rows: impl IntoIterator<Item = Vec<Key>>) -> Self {
+        Self {
+            rows: rows.into_iter().collect(),
+        }
+    }
+
+    /// A QWERTY [`Layout`], with a row of digits.
+    pub fn qwerty() -> Self {
+        let characters = |row: &str| row.chars().map(Key::Character);
+
+        Self::new([
+            characters("1234567890").collect(),
+            characters("qwertyuiop").collect(),
+            characters("asdfghjkl").collect(),
+            std::iter::once(Key::Shift)
+                .chain(characters("zxcvbnm"))
+                .chain(std::iter::once(Key::Backspace))
+                .collect(),
+            vec![
+                Key::Left,
+                Key::Character(','),
+                Key::Space,
+                Key::Character('.'),
+                Key::Right,
+                Key::Enter,
+            ],
+        ])
+    }
+
+    /// A numeric keypad [`Layout`].
+    pub fn numeric() -> Self {
+        let characters = |row: &str| row.chars().map(Key::Character).collect();
+
+        Self::new([
+            characters("123"),
+            characters("456"),
+            characters("789"),
+            vec![Key::Backspace, Key::Character('0'), Key::Enter],
+        ])
+    }
+
+    /// Returns the rows of keys of the [`Layout`].
+    pub fn rows(&self) -> &[Vec<Key>] {
+        &self.rows
+    }
+}
+
+/// A key of a [`VirtualKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// Types a character.
+    ///
+    /// Letters are typed in uppercase after pressing [`Key::Shift`].
+    Character(char),
+    /// Types a space.
+    Space,
+    /// Deletes the character before the cursor.
+    Backspace,
+    /// Presses Enter, usually submitting the focused widget.
+    Enter,
+    /// Types the next letter in uppercase.
+    Shift,
+    /// Moves the cursor to the left.
+    Left,
+    /// Moves the cursor to the right.
+    Right,
+}
+
+impl Key {
+    /// Returns the width of the [`Key`], relative to a character key.
+    pub fn width(&self) -> f32 {
+        match self {
+            Key::Character(_) | Key::Left | Key::Right => 1.0,
+            Key::Backspace | Key::Enter | Key::Shift => 1.5,
+            Key::Space => 4.0,
+        }
+    }
+
+    /// Returns the label of the [`Key`].
+    pub fn label(&self, is_shifted: bool) -> String {
+        match self {
+            Key::Character(c) if is_shifted => c.to_uppercase().collect(),
+            Key::Character(c) => c.to_string(),
+            Key::Space => String::from("Space"),
+            Key::Backspace => String::from("⌫"),
+            Key::Enter => String::from("⏎"),
+            Key::Shift => String::from("⇧"),
+            Key::Left => String::from("←"),
+            Key::Right => String::from("→"),
+        }
+    }
+
+    fn key_code(&self) -> Option<keyboard::KeyCode> {
+        Some(match self {
+            Key::Backspace => keyboard::KeyCode::Backspace,
+            Key::Enter => keyboard::KeyCode::Enter,
+            Key::Left => keyboard::KeyCode::Left,
+            Key::Right => keyboard::KeyCode::Right,
+            Key::Character(_) | Key::Space | Key::Shift => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    pressed: Option<(usize, usize)>,
+    is_shifted: bool,
+}
+
+impl State {
+    /// Produces the keyboard events of typing the given [`Key`].
+    fn type_key(&mut self, key: &Key) -> Vec<Event> {
+        let character = match key {
+            Key::Character(c) if self.is_shifted => {
+                self.is_shifted = false;
+
+                Some(c.to_uppercase().next().unwrap_or(*c))
+            }
+            Key::Character(c) => Some(*c),
+            Key::Space => Some(' '),
+            Key::Shift => {
+                self.is_shifted = !self.is_shifted;
+
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(c) = character {
+            return vec![Event::Keyboard(keyboard::Event::CharacterReceived(
+                c,
+            ))];
+        }
+
+        key.key_code()
+            .map(|key_code| {
+                let modifiers = keyboard::Modifiers::default();
+
+                vec![
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                        scancode: None,
+                        is_repeat: false,
+                    }),
+                    Event::Keyboard(keyboard::Event::KeyReleased {
+                        key_code,
+                        modifiers,
+                        scancode: None,
+                    }),
+                ]
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,191 @@
+//! Embed web content in your application.
+//!
+//! A [`WebView`] reserves some space in the layout and lets the shell place
+//! a native web surface on top of it. The page can post messages to the
+//! application with `window.ipc.postMessage(message)` and the application
+//! can run scripts in the page with [`evaluate`].
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+use crate::runtime::webview::State;
+
+pub use crate::runtime::webview::{evaluate, navigate, Id};
+
+/// A native web surface laid out like any other widget.
+///
+/// Web views are only displayed by shells that support them; for instance,
+/// `iced_winit` with its `webview` feature enabled. Elsewhere, a [`WebView`]
+/// is just empty space.
+#[allow(missing_debug_implementations)]
+pub struct WebView<'a, Message> {
+    id: widget::Id,
+    url: String,
+    width: Length,
+    height: Length,
+    on_message: Option<Box<dyn Fn(String) -> Message + 'a>>,
+}
+
+impl<'a, Message> WebView<'a, Message> {
+    /// Creates a new [`WebView`] with the given [`Id`] showing the given URL.
+    ///
+    /// The [`Id`] identifies the native surface of the [`WebView`], so it
+    /// must be the same every time the [`WebView`] is built.
+    pub fn new(id: Id, url: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            url: url.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            on_message: None,
+        }
+    }
+
+    /// Sets the width of the [`WebView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`WebView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that should be produced when the page of the
+    /// [`WebView`] posts a message.
+    pub fn on_message(
+        mut self,
+        on_message: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_message = Some(Box::new(on_message));
+        self
+    }
+}
+
+/// The local state of a [`WebView`].
+#[derive(Debug)]
+struct Local {
+    shared: State,
+    url: String,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for WebView<'a, Message>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Local>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Local {
+            shared: State::new(self.url.clone()),
+            url: self.url.clone(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let local = tree.state.downcast_mut::<Local>();
+
+        // Only navigate when the URL of the widget changes, so that
+        // `navigate` commands and links followed by the user are kept
+        if local.url != self.url {
+            local.url = self.url.clone();
+            local.shared.navigate(self.url.clone());
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let local = tree.state.downcast_mut::<Local>();
+
+        operation.custom(&mut local.shared, Some(&self.id));
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let local = tree.state.downcast_mut::<Local>();
+
+            local.shared.set_bounds(layout.bounds());
+
+            let messages = local.shared.take_messages();
+
+            if let Some(on_message) = &self.on_message {
+                if !messages.is_empty() {
+                    for message in messages {
+                        shell.publish(on_message(message));
+                    }
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl<'a, Message, Renderer> From<WebView<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(webview: WebView<'a, Message>) -> Self {
+        Self::new(webview)
+    }
+}
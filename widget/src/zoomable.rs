@@ -0,0 +1,750 @@
+//! Zoom and pan any content with the mouse, trackpad, or touch.
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Vector, Widget,
+};
+
+use std::collections::HashMap;
+
+/// The maximum delay between two taps or clicks to be considered a double
+/// tap.
+const DOUBLE_TAP_DELAY: Duration = Duration::from_millis(300);
+
+/// The maximum distance between two taps or clicks to be considered a double
+/// tap.
+const DOUBLE_TAP_DISTANCE: f32 = 20.0;
+
+/// The amount of pixels a line of a [`mouse::ScrollDelta`] represents.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// A frame that lets its content be zoomed and panned.
+///
+/// The content can be zoomed with a pinch gesture or by scrolling while
+/// holding `Ctrl`, and panned by dragging it or by scrolling. A double tap
+/// (or double click) makes the content fit the [`Zoomable`] again.
+///
+/// Zooming scales the content as it is drawn, without laying it out again.
+/// Content smaller than the [`Zoomable`] is centered.
+#[allow(missing_debug_implementations)]
+pub struct Zoomable<'a, Message, Renderer = crate::Renderer> {
+    width: Length,
+    height: Length,
+    min_zoom: f32,
+    max_zoom: f32,
+    zoom_step: f32,
+    on_zoom: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Zoomable<'a, Message, Renderer> {
+    /// Creates a new [`Zoomable`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Zoomable {
+            width: Length::Fill,
+            height: Length::Fill,
+            min_zoom: 1.0,
+            max_zoom: 10.0,
+            zoom_step: 0.10,
+            on_zoom: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`Zoomable`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Zoomable`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the min zoom of the content of the [`Zoomable`].
+    ///
+    /// A zoom of `1.0` makes the content fit the [`Zoomable`].
+    ///
+    /// Default is `1.0`
+    pub fn min_zoom(mut self, min_zoom: f32) -> Self {
+        self.min_zoom = min_zoom;
+        self
+    }
+
+    /// Sets the max zoom of the content of the [`Zoomable`].
+    ///
+    /// Default is `10.0`
+    pub fn max_zoom(mut self, max_zoom: f32) -> Self {
+        self.max_zoom = max_zoom;
+        self
+    }
+
+    /// Sets the percentage the content of the [`Zoomable`] will be zoomed by
+    /// for every line scrolled while holding `Ctrl`.
+    ///
+    /// Default is `0.10`
+    pub fn zoom_step(mut self, zoom_step: f32) -> Self {
+        self.zoom_step = zoom_step;
+        self
+    }
+
+    /// Sets the message that will be produced when the zoom of the
+    /// [`Zoomable`] changes.
+    pub fn on_zoom(mut self, on_zoom: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_zoom = Some(Box::new(on_zoom));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Zoomable<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.loose(),
+        );
+
+        let size = limits.resolve(content.size());
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.modifiers = modifiers;
+        }
+
+        if !state.is_grabbed() {
+            let cursor = match cursor.position_over(bounds) {
+                Some(cursor_position) => mouse::Cursor::Available(
+                    state.project(cursor_position, bounds, content_bounds),
+                ),
+                None => mouse::Cursor::Unavailable,
+            };
+
+            let event =
+                state.project_event(event.clone(), bounds, content_bounds);
+
+            let status = self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &state.viewport(bounds, content_bounds),
+            );
+
+            if let event::Status::Captured = status {
+                return event::Status::Captured;
+            }
+        }
+
+        let previous_zoom = state.zoom;
+
+        let status = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(cursor_position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        Vector::new(x * LINE_HEIGHT, y * LINE_HEIGHT)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+
+                if state.modifiers.control() {
+                    let zoom = state.zoom
+                        * (1.0 + self.zoom_step).powf(delta.y / LINE_HEIGHT);
+
+                    state.zoom_to(
+                        zoom.clamp(self.min_zoom, self.max_zoom),
+                        cursor_position - bounds.position(),
+                        bounds,
+                        content_bounds,
+                    );
+                } else {
+                    let offset = state.offset(bounds, content_bounds);
+
+                    state.pan(offset - delta, bounds, content_bounds);
+
+                    if state.offset(bounds, content_bounds) == offset {
+                        return event::Status::Ignored;
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(cursor_position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if state.is_double_tap(cursor_position) {
+                    state.fit();
+                } else {
+                    state.grab(cursor_position, bounds, content_bounds);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.grab {
+                    state.pan(
+                        grab.offset - (position - grab.origin),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.grab.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if !bounds.contains(position) {
+                    return event::Status::Ignored;
+                }
+
+                let _ = state.fingers.insert(id, position);
+
+                match state.fingers.len() {
+                    1 => {
+                        if state.is_double_tap(position) {
+                            state.fit();
+                        } else {
+                            state.grab(position, bounds, content_bounds);
+                        }
+                    }
+                    _ => {
+                        // Pinching takes over from panning
+                        state.grab = None;
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let Some(previous) = state.fingers.get(&id).copied() else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(grab) = state.grab {
+                    state.pan(
+                        grab.offset - (position - grab.origin),
+                        bounds,
+                        content_bounds,
+                    );
+                } else if let Some(other) = state
+                    .fingers
+                    .iter()
+                    .find(|(finger, _)| **finger != id)
+                    .map(|(_, position)| *position)
+                {
+                    let previous_center = center(previous, other);
+                    let previous_distance = previous.distance(other);
+
+                    let current_center = center(position, other);
+                    let current_distance = position.distance(other);
+
+                    if previous_distance > 0.0 {
+                        let zoom = (state.zoom * current_distance
+                            / previous_distance)
+                            .clamp(self.min_zoom, self.max_zoom);
+
+                        state.zoom_to(
+                            zoom,
+                            previous_center - bounds.position(),
+                            bounds,
+                            content_bounds,
+                        );
+                    }
+
+                    let offset = state.offset(bounds, content_bounds);
+
+                    state.pan(
+                        offset - (current_center - previous_center),
+                        bounds,
+                        content_bounds,
+                    );
+                }
+
+                let _ = state.fingers.insert(id, position);
+
+                event::Status::Captured
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. }
+                | touch::Event::FingerLost { id, .. },
+            ) => {
+                if state.fingers.remove(&id).is_none() {
+                    return event::Status::Ignored;
+                }
+
+                state.grab = None;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        if state.zoom != previous_zoom {
+            if let Some(on_zoom) = &self.on_zoom {
+                shell.publish(on_zoom(state.zoom));
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        if state.is_grabbed() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(cursor_position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::Idle;
+        };
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            mouse::Cursor::Available(state.project(
+                cursor_position,
+                bounds,
+                content_bounds,
+            )),
+            &state.viewport(bounds, content_bounds),
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::Idle {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        let cursor = match cursor.position_over(bounds) {
+            Some(cursor_position) if !state.is_grabbed() => {
+                mouse::Cursor::Available(state.project(
+                    cursor_position,
+                    bounds,
+                    content_bounds,
+                ))
+            }
+            _ => mouse::Cursor::Unavailable,
+        };
+
+        let translation = state.translation(bounds, content_bounds);
+        let origin = Vector::new(bounds.x, bounds.y);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(origin + translation, |renderer| {
+                renderer.with_scale(state.zoom, |renderer| {
+                    renderer.with_translation(
+                        Vector::new(-origin.x, -origin.y),
+                        |renderer| {
+                            self.content.as_widget().draw(
+                                &tree.children[0],
+                                renderer,
+                                theme,
+                                style,
+                                content_layout,
+                                cursor,
+                                &state.viewport(bounds, content_bounds),
+                            );
+                        },
+                    );
+                });
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let translation = tree
+            .state
+            .downcast_ref::<State>()
+            .translation(bounds, content_layout.bounds());
+
+        // Overlays cannot be scaled, so they only follow the content around
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], content_layout, renderer)
+            .map(|overlay| overlay.translate(translation))
+    }
+}
+
+impl<'a, Message, Renderer> From<Zoomable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(zoomable: Zoomable<'a, Message, Renderer>) -> Self {
+        Self::new(zoomable)
+    }
+}
+
+/// The local state of a [`Zoomable`].
+#[derive(Debug, Clone)]
+struct State {
+    zoom: f32,
+    offset: Vector,
+    grab: Option<Grab>,
+    fingers: HashMap<touch::Finger, Point>,
+    last_tap: Option<(Instant, Point)>,
+    modifiers: keyboard::Modifiers,
+}
+
+/// A drag in progress in a [`Zoomable`].
+#[derive(Debug, Clone, Copy)]
+struct Grab {
+    origin: Point,
+    offset: Vector,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            zoom: 1.0,
+            offset: Vector::new(0.0, 0.0),
+            grab: None,
+            fingers: HashMap::new(),
+            last_tap: None,
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+
+    fn is_grabbed(&self) -> bool {
+        self.grab.is_some() || self.fingers.len() > 1
+    }
+
+    /// Returns the current offset of the zoomed content, given the bounds of
+    /// the [`Zoomable`] and its content.
+    fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+        let max_x = (content_bounds.width * self.zoom - bounds.width).max(0.0);
+        let max_y =
+            (content_bounds.height * self.zoom - bounds.height).max(0.0);
+
+        Vector::new(
+            self.offset.x.clamp(0.0, max_x),
+            self.offset.y.clamp(0.0, max_y),
+        )
+    }
+
+    /// Returns the space around the zoomed content when it is smaller than
+    /// the [`Zoomable`], which centers it.
+    fn center(&self, bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+        Vector::new(
+            ((bounds.width - content_bounds.width * self.zoom) / 2.0).max(0.0),
+            ((bounds.height - content_bounds.height * self.zoom) / 2.0)
+                .max(0.0),
+        )
+    }
+
+    /// Returns the translation of the zoomed content relative to the
+    /// [`Zoomable`].
+    fn translation(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector {
+        self.center(bounds, content_bounds)
+            - self.offset(bounds, content_bounds)
+    }
+
+    /// Maps a position on the screen to its position in the content, before
+    /// it is zoomed.
+    fn project(
+        &self,
+        position: Point,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Point {
+        let translation = self.translation(bounds, content_bounds);
+
+        Point::new(
+            bounds.x + (position.x - bounds.x - translation.x) / self.zoom,
+            bounds.y + (position.y - bounds.y - translation.y) / self.zoom,
+        )
+    }
+
+    /// Maps the positions of the given [`Event`] to the content, before it is
+    /// zoomed.
+    fn project_event(
+        &self,
+        event: Event,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Event {
+        let project =
+            |position: Point| self.project(position, bounds, content_bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: project(position),
+                })
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { .. } => delta,
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        mouse::ScrollDelta::Pixels {
+                            x: x / self.zoom,
+                            y: y / self.zoom,
+                        }
+                    }
+                };
+
+                Event::Mouse(mouse::Event::WheelScrolled { delta })
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                Event::Touch(touch::Event::FingerPressed {
+                    id,
+                    position: project(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                Event::Touch(touch::Event::FingerMoved {
+                    id,
+                    position: project(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position }) => {
+                Event::Touch(touch::Event::FingerLifted {
+                    id,
+                    position: project(position),
+                })
+            }
+            Event::Touch(touch::Event::FingerLost { id, position }) => {
+                Event::Touch(touch::Event::FingerLost {
+                    id,
+                    position: project(position),
+                })
+            }
+            _ => event,
+        }
+    }
+
+    /// Returns the visible area of the content, before it is zoomed.
+    fn viewport(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Rectangle {
+        let position = self.project(bounds.position(), bounds, content_bounds);
+
+        Rectangle {
+            x: position.x,
+            y: position.y,
+            width: bounds.width / self.zoom,
+            height: bounds.height / self.zoom,
+        }
+    }
+
+    fn pan(
+        &mut self,
+        offset: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.offset = offset;
+        self.offset = self.offset(bounds, content_bounds);
+    }
+
+    fn grab(
+        &mut self,
+        origin: Point,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.grab = Some(Grab {
+            origin,
+            offset: self.offset(bounds, content_bounds),
+        });
+    }
+
+    /// Zooms the content, keeping the point at the given `anchor`—relative
+    /// to the [`Zoomable`]—in place.
+    fn zoom_to(
+        &mut self,
+        zoom: f32,
+        anchor: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if zoom == self.zoom {
+            return;
+        }
+
+        // The point of the content under the anchor, before it is zoomed
+        let point = (anchor - self.translation(bounds, content_bounds))
+            * (1.0 / self.zoom);
+
+        self.zoom = zoom;
+
+        self.pan(
+            self.center(bounds, content_bounds) + point * zoom - anchor,
+            bounds,
+            content_bounds,
+        );
+    }
+
+    /// Makes the content fit the [`Zoomable`] again.
+    fn fit(&mut self) {
+        self.zoom = 1.0;
+        self.offset = Vector::new(0.0, 0.0);
+        self.grab = None;
+        self.last_tap = None;
+    }
+
+    /// Records a tap at the given position and returns whether it completes
+    /// a double tap.
+    fn is_double_tap(&mut self, position: Point) -> bool {
+        let now = Instant::now();
+
+        let is_double_tap =
+            self.last_tap.is_some_and(|(instant, last_position)| {
+                now.duration_since(instant) <= DOUBLE_TAP_DELAY
+                    && last_position.distance(position) <= DOUBLE_TAP_DISTANCE
+            });
+
+        self.last_tap = if is_double_tap {
+            None
+        } else {
+            Some((now, position))
+        };
+
+        is_double_tap
+    }
+}
+
+fn center(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
@@ -0,0 +1,380 @@
+//! Expose the user interface to assistive technologies with AccessKit.
+use crate::core::accessibility::{self, Role};
+use crate::core::mouse;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::{Clipboard, Event, Point, Rectangle};
+use crate::runtime::UserInterface;
+
+use std::num::NonZeroU128;
+use std::sync::mpsc;
+
+/// The accessibility tree of a window, published with AccessKit.
+#[allow(missing_debug_implementations)]
+pub struct Accessibility {
+    adapter: accesskit_winit::Adapter,
+    requests: mpsc::Receiver<accesskit::ActionRequest>,
+    entries: Vec<Entry>,
+    classes: accesskit::NodeClassSet,
+}
+
+/// A request of an assistive technology that must be carried out by the
+/// user interface.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Request {
+    /// Click at the given position.
+    Click(Point),
+    /// Focus the widget with the given [`widget::Id`].
+    Focus(widget::Id),
+}
+
+impl Accessibility {
+    /// Creates a new [`Accessibility`] adapter for the given window.
+    ///
+    /// It must be created before the window is visible.
+    pub fn new(window: &winit::window::Window) -> Self {
+        let (sender, requests) = mpsc::channel();
+
+        let adapter = accesskit_winit::Adapter::with_action_handler(
+            window,
+            || accesskit::TreeUpdate {
+                nodes: vec![(
+                    ROOT,
+                    accesskit::NodeBuilder::new(accesskit::Role::Window)
+                        .build(&mut accesskit::NodeClassSet::new()),
+                )],
+                tree: Some(accesskit::Tree::new(ROOT)),
+                focus: None,
+            },
+            Box::new(Handler { sender }),
+        );
+
+        Self {
+            adapter,
+            requests,
+            entries: Vec::new(),
+            classes: accesskit::NodeClassSet::new(),
+        }
+    }
+
+    /// Lets the adapter process a window event.
+    pub fn process_event(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent<'_>,
+    ) {
+        let _ = self.adapter.on_event(window, event);
+    }
+
+    /// Collects the accessibility tree of the given [`UserInterface`] and
+    /// publishes it, if an assistive technology is listening.
+    pub fn update<Message, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &Renderer,
+        scale_factor: f64,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        let mut collector = Collector::default();
+
+        user_interface.operate(renderer, &mut collector);
+
+        let Collector { entries, roots, .. } = collector;
+
+        self.entries = entries;
+
+        let Self {
+            adapter,
+            entries,
+            classes,
+            ..
+        } = self;
+
+        adapter.update_if_active(|| {
+            let mut nodes = Vec::with_capacity(entries.len() + 1);
+
+            let mut root = accesskit::NodeBuilder::new(accesskit::Role::Window);
+            root.set_transform(accesskit::Affine::scale(scale_factor));
+            root.set_children(roots.iter().copied().map(node_id).collect());
+
+            nodes.push((ROOT, root.build(classes)));
+
+            for (index, entry) in entries.iter().enumerate() {
+                nodes.push((node_id(index), entry.build(entries, classes)));
+            }
+
+            let focus = entries
+                .iter()
+                .position(|entry| entry.node.is_focused)
+                .map(node_id)
+                .unwrap_or(ROOT);
+
+            accesskit::TreeUpdate {
+                nodes,
+                tree: Some(accesskit::Tree::new(ROOT)),
+                focus: Some(focus),
+            }
+        });
+    }
+
+    /// Carries out the pending [`Request`]s of assistive technologies on the
+    /// given [`UserInterface`].
+    ///
+    /// Returns whether any [`Request`] was processed.
+    pub fn process_requests<Message, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> bool
+    where
+        Renderer: crate::core::Renderer,
+    {
+        let requests = self.requests();
+
+        for request in &requests {
+            match request {
+                Request::Click(position) => {
+                    let _ = user_interface.update(
+                        &[
+                            Event::Mouse(mouse::Event::ButtonPressed(
+                                mouse::Button::Left,
+                            )),
+                            Event::Mouse(mouse::Event::ButtonReleased(
+                                mouse::Button::Left,
+                            )),
+                        ],
+                        mouse::Cursor::Available(*position),
+                        renderer,
+                        clipboard,
+                        messages,
+                    );
+                }
+                Request::Focus(id) => {
+                    user_interface.operate(
+                        renderer,
+                        &mut operation::focusable::focus(id.clone()),
+                    );
+                }
+            }
+        }
+
+        !requests.is_empty()
+    }
+
+    /// Returns the pending [`Request`]s of assistive technologies.
+    ///
+    /// Requests targeting widgets that are not part of the last published
+    /// tree are discarded.
+    pub fn requests(&mut self) -> Vec<Request> {
+        self.requests
+            .try_iter()
+            .filter_map(|request| {
+                let index = usize::try_from(request.target.0.get())
+                    .ok()?
+                    .checked_sub(FIRST_WIDGET)?;
+
+                let entry = self.entries.get(index)?;
+
+                match request.action {
+                    accesskit::Action::Default if entry.node.is_clickable() => {
+                        Some(Request::Click(entry.bounds.center()))
+                    }
+                    accesskit::Action::Focus => match &entry.id {
+                        Some(id) => Some(Request::Focus(id.clone())),
+                        None if entry.node.role == Role::TextInput => {
+                            Some(Request::Click(entry.bounds.center()))
+                        }
+                        None => None,
+                    },
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+const ROOT: accesskit::NodeId = accesskit::NodeId(NonZeroU128::MIN);
+
+/// The [`accesskit::NodeId`] of the first widget; the root window takes
+/// the first one.
+const FIRST_WIDGET: usize = 2;
+
+fn node_id(index: usize) -> accesskit::NodeId {
+    accesskit::NodeId(
+        NonZeroU128::new((index + FIRST_WIDGET) as u128)
+            .expect("Node id is never zero"),
+    )
+}
+
+struct Handler {
+    sender: mpsc::Sender<accesskit::ActionRequest>,
+}
+
+impl accesskit::ActionHandler for Handler {
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        // Requests are processed the next time the event loop wakes up
+        let _ = self.sender.send(request);
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    id: Option<widget::Id>,
+    bounds: Rectangle,
+    node: accessibility::Node,
+    children: Vec<usize>,
+}
+
+impl Entry {
+    fn build(
+        &self,
+        entries: &[Entry],
+        classes: &mut accesskit::NodeClassSet,
+    ) -> accesskit::Node {
+        let node = &self.node;
+        let mut builder = accesskit::NodeBuilder::new(role(node.role));
+
+        builder.set_bounds(accesskit::Rect {
+            x0: f64::from(self.bounds.x),
+            y0: f64::from(self.bounds.y),
+            x1: f64::from(self.bounds.x + self.bounds.width),
+            y1: f64::from(self.bounds.y + self.bounds.height),
+        });
+
+        builder.set_children(
+            self.children
+                .iter()
+                .copied()
+                .map(node_id)
+                .collect::<Vec<_>>(),
+        );
+
+        match &node.label {
+            Some(label) => builder.set_name(label.clone()),
+            None => {
+                let label = self.label_from_children(entries);
+
+                if !label.is_empty() {
+                    builder.set_name(label);
+                }
+            }
+        }
+
+        if let Some(value) = &node.value {
+            builder.set_value(value.clone());
+        }
+
+        if let Some(numeric) = &node.numeric {
+            builder.set_numeric_value(numeric.value);
+            builder.set_min_numeric_value(*numeric.range.start());
+            builder.set_max_numeric_value(*numeric.range.end());
+
+            if let Some(step) = numeric.step {
+                builder.set_numeric_value_step(step);
+            }
+        }
+
+        if let Some(is_toggled) = node.is_toggled {
+            builder.set_checked_state(if is_toggled {
+                accesskit::CheckedState::True
+            } else {
+                accesskit::CheckedState::False
+            });
+        }
+
+        if node.is_disabled {
+            builder.set_disabled();
+        }
+
+        if node.is_clickable() {
+            builder.add_action(accesskit::Action::Default);
+            builder
+                .set_default_action_verb(accesskit::DefaultActionVerb::Click);
+        }
+
+        if self.id.is_some() || node.role == Role::TextInput {
+            builder.add_action(accesskit::Action::Focus);
+        }
+
+        builder.build(classes)
+    }
+
+    fn label_from_children(&self, entries: &[Entry]) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| {
+                let child = entries.get(*child)?;
+
+                match child.node.role {
+                    Role::Label => child.node.label.clone(),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn role(role: Role) -> accesskit::Role {
+    match role {
+        Role::Button => accesskit::Role::Button,
+        Role::CheckBox => accesskit::Role::CheckBox,
+        Role::ComboBox => accesskit::Role::ComboBox,
+        Role::Group => accesskit::Role::Group,
+        Role::Image => accesskit::Role::Image,
+        Role::Label => accesskit::Role::StaticText,
+        Role::ProgressIndicator => accesskit::Role::ProgressIndicator,
+        Role::RadioButton => accesskit::Role::RadioButton,
+        Role::ScrollView => accesskit::Role::ScrollView,
+        Role::Slider => accesskit::Role::Slider,
+        Role::Switch => accesskit::Role::Switch,
+        Role::TextInput => accesskit::Role::TextInput,
+    }
+}
+
+#[derive(Default)]
+struct Collector {
+    entries: Vec<Entry>,
+    roots: Vec<usize>,
+    parents: Vec<usize>,
+}
+
+impl<T> Operation<T> for Collector {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let index = self.entries.len();
+
+        self.entries.push(Entry {
+            id: id.cloned(),
+            bounds,
+            node: node.clone(),
+            children: Vec::new(),
+        });
+
+        match self.parents.last() {
+            Some(parent) => self.entries[*parent].children.push(index),
+            None => self.roots.push(index),
+        }
+
+        self.parents.push(index);
+        operate_on_children(self);
+        let _ = self.parents.pop();
+    }
+}
@@ -7,20 +7,35 @@ pub use state::State;
 
 use crate::conversion;
 use crate::core;
+use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Event, Size};
+use crate::core::{Element, Event, Size, Vector};
+use crate::fallback::Fallback;
+use crate::file_drop::FileDrop;
 use crate::futures::futures;
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics::compositor::{self, Compositor};
+use crate::hud::Hud;
+use crate::inspector::Inspector;
+use crate::key_repeat::KeyRepeat;
+use crate::placement::Placement;
 use crate::runtime::clipboard;
+use crate::runtime::dnd;
+use crate::runtime::menu::Menu;
+use crate::runtime::notification;
 use crate::runtime::program::Program;
+use crate::runtime::report::{self, Report};
+use crate::runtime::tray::Tray;
 use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window::drag_area;
 use crate::runtime::{Command, Debug};
 use crate::style::application::{Appearance, StyleSheet};
+use crate::zoom;
 use crate::{Clipboard, Error, Proxy, Settings};
 
 use futures::channel::mpsc;
@@ -42,7 +57,9 @@ use tracing::{info_span, instrument::Instrument};
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`, an inspector of its widgets by
+/// pressing `Ctrl+Shift+I`, and a performance overlay by pressing
+/// `Ctrl+Shift+P`.
 pub trait Application: Program
 where
     <Self::Renderer as core::Renderer>::Theme: StyleSheet,
@@ -89,6 +106,40 @@ where
         Subscription::none()
     }
 
+    /// Returns the native menu bar of the [`Application`], if any.
+    ///
+    /// Its top-level items should be submenus. The native menu is kept in
+    /// sync with it, so items can be enabled, disabled, and checked
+    /// dynamically. It is only shown when the `menu` feature is enabled.
+    ///
+    /// By default, it returns `None`.
+    fn menu(&self) -> Option<Menu<Self::Message>> {
+        None
+    }
+
+    /// Returns the [`Tray`] of the [`Application`], if any.
+    ///
+    /// Like the title, it can be dynamic; the icon in the system tray is
+    /// kept in sync with it. It is only shown when the `tray` feature is
+    /// enabled.
+    ///
+    /// By default, it returns `None`.
+    fn tray(&self) -> Option<Tray<Self::Message>> {
+        None
+    }
+
+    /// Reports a panic in the `view`, `update`, or `subscription` of the
+    /// [`Application`], producing the message that handles it.
+    ///
+    /// While the `view` panics, a fallback screen describing the [`Report`]
+    /// is shown instead, so the [`Application`] degrades gracefully instead
+    /// of aborting.
+    ///
+    /// By default, it returns `None` and the panic is not caught.
+    fn report(&self, _report: Report) -> Option<Self::Message> {
+        None
+    }
+
     /// Returns the scale factor of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -133,9 +184,11 @@ where
 
     let runtime = {
         let proxy = Proxy::new(event_loop.create_proxy());
-        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+        let executor = E::with_settings(&settings.executor)
+            .map_err(Error::ExecutorCreationFailed)?;
 
         Runtime::new(executor, proxy)
+            .low_priority_limit(settings.executor.low_priority_limit)
     };
 
     let (application, init_command) = {
@@ -212,6 +265,9 @@ where
             window,
             should_be_visible,
             settings.exit_on_close_request,
+            settings.key_repeat,
+            settings.theme_transition,
+            settings.zoom_shortcuts,
         );
 
         #[cfg(feature = "trace")]
@@ -279,6 +335,9 @@ async fn run_instance<A, E, C>(
     window: winit::window::Window,
     should_be_visible: bool,
     exit_on_close_request: bool,
+    key_repeat: keyboard::Repeat,
+    theme_transition: Duration,
+    zoom_shortcuts: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -289,7 +348,8 @@ async fn run_instance<A, E, C>(
     use winit::event;
     use winit::event_loop::ControlFlow;
 
-    let mut state = State::new(&application, &window);
+    let mut state =
+        State::new(&application, &window).theme_transition(theme_transition);
     let mut viewport_version = state.viewport_version();
     let physical_size = state.physical_size();
 
@@ -302,18 +362,25 @@ async fn run_instance<A, E, C>(
     );
     let mut should_exit = false;
 
+    // The adapter must exist before the window is shown
+    #[cfg(feature = "accessibility")]
+    let mut accessibility = crate::accessibility::Accessibility::new(&window);
+
     if should_be_visible {
         window.set_visible(true);
     }
 
+    let subscription_command =
+        track_subscription(&mut application, &mut runtime);
+
     run_command(
         &application,
         &mut compositor,
         &mut surface,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
-        init_command,
+        Command::batch([init_command, subscription_command]),
         &mut runtime,
         &mut clipboard,
         &mut should_exit,
@@ -321,7 +388,6 @@ async fn run_instance<A, E, C>(
         &mut debug,
         &window,
     );
-    runtime.track(application.subscription().into_recipes());
 
     let mut user_interface = ManuallyDrop::new(build_user_interface(
         &application,
@@ -335,6 +401,35 @@ async fn run_instance<A, E, C>(
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut last_drag_click = None;
+    let mut placement = Placement::new(&window);
+    let mut gestures = touch::gesture::Recognizer::new();
+    let mut key_repeat = KeyRepeat::new(key_repeat);
+    let mut file_drop = FileDrop::new();
+    let mut inspector = Inspector::new();
+    let mut hud = Hud::new();
+
+    #[cfg(feature = "webview")]
+    let mut webviews = crate::webview::WebViews::new();
+
+    #[cfg(any(feature = "menu", feature = "tray"))]
+    let menu_handler = crate::menu::Handler::new(proxy.clone());
+
+    #[cfg(feature = "menu")]
+    let mut menu_bar = {
+        let mut menu_bar = crate::menu::MenuBar::new(menu_handler.clone());
+        menu_bar.synchronize(&window, application.menu());
+
+        menu_bar
+    };
+
+    #[cfg(feature = "tray")]
+    let mut tray = {
+        let mut tray = crate::tray::Tray::new(proxy.clone(), menu_handler);
+        tray.synchronize(application.tray());
+
+        tray
+    };
 
     debug.startup_finished();
 
@@ -349,10 +444,53 @@ async fn run_instance<A, E, C>(
                 );
             }
             event::Event::MainEventsCleared => {
-                if !redraw_pending && events.is_empty() && messages.is_empty() {
+                if let Some(event) = placement.resize_ended(Instant::now()) {
+                    events.push(Event::Window(event));
+                }
+
+                key_repeat.tick(Instant::now(), &mut events);
+
+                if let Some(position) = file_drop.tick(
+                    &window,
+                    Instant::now(),
+                    state.scale_factor(),
+                    &mut events,
+                ) {
+                    state.move_cursor(position);
+                }
+
+                if let Some(gesture) = gestures.tick(Instant::now()) {
+                    events.push(Event::Gesture(gesture));
+                }
+
+                #[cfg(feature = "webview")]
+                let has_webview_messages = webviews.has_messages();
+                #[cfg(not(feature = "webview"))]
+                let has_webview_messages = false;
+
+                #[cfg(feature = "accessibility")]
+                let has_accessibility_requests = accessibility
+                    .process_requests(
+                        &mut user_interface,
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+                #[cfg(not(feature = "accessibility"))]
+                let has_accessibility_requests = false;
+
+                if !redraw_pending
+                    && events.is_empty()
+                    && messages.is_empty()
+                    && !has_webview_messages
+                    && !has_accessibility_requests
+                    && !hud.is_enabled()
+                {
                     continue;
                 }
 
+                state.tick(Instant::now());
+
                 debug.event_processing_started();
 
                 let (interface_state, statuses) = user_interface.update(
@@ -365,9 +503,29 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
+                if user_interface.is_pointer_captured() {
+                    state.capture_pointer();
+                }
+
                 for (event, status) in
                     events.drain(..).zip(statuses.into_iter())
                 {
+                    if let (
+                        Event::Mouse(mouse::Event::ButtonPressed(
+                            mouse::Button::Left,
+                        )),
+                        core::event::Status::Ignored,
+                    ) = (&event, status)
+                    {
+                        drag_window(
+                            &window,
+                            &mut user_interface,
+                            &renderer,
+                            state.cursor(),
+                            &mut last_drag_click,
+                        );
+                    }
+
                     runtime.broadcast(event, status);
                 }
 
@@ -386,7 +544,7 @@ async fn run_instance<A, E, C>(
                         &mut compositor,
                         &mut surface,
                         &mut cache,
-                        &state,
+                        &mut state,
                         &mut renderer,
                         &mut runtime,
                         &mut clipboard,
@@ -400,6 +558,12 @@ async fn run_instance<A, E, C>(
                     // Update window
                     state.synchronize(&application, &window);
 
+                    #[cfg(feature = "menu")]
+                    menu_bar.synchronize(&window, application.menu());
+
+                    #[cfg(feature = "tray")]
+                    tray.synchronize(application.tray());
+
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &application,
                         cache,
@@ -439,9 +603,44 @@ async fn run_instance<A, E, C>(
                     },
                     state.cursor(),
                 );
+
+                inspector.draw(
+                    &mut user_interface,
+                    &mut renderer,
+                    &renderer::Style {
+                        text_color: state.text_color(),
+                    },
+                    state.background_color(),
+                    state.cursor(),
+                );
+
+                hud.draw(
+                    &mut renderer,
+                    compositor.statistics(&renderer),
+                    &debug,
+                    state.logical_size(),
+                );
                 debug.draw_finished();
 
+                // Messages delivered to web views are published on the
+                // next update
+                #[cfg(feature = "webview")]
+                let interface_state = if webviews.synchronize(
+                    &window,
+                    &mut user_interface,
+                    &renderer,
+                ) {
+                    user_interface::State::Updated {
+                        redraw_request: Some(window::RedrawRequest::NextFrame),
+                    }
+                } else {
+                    interface_state
+                };
+
                 if new_mouse_interaction != mouse_interaction {
+                    window.set_cursor_visible(
+                        new_mouse_interaction != mouse::Interaction::Hidden,
+                    );
                     window.set_cursor_icon(conversion::mouse_interaction(
                         new_mouse_interaction,
                     ));
@@ -449,10 +648,17 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                #[cfg(feature = "accessibility")]
+                accessibility.update(
+                    &mut user_interface,
+                    &renderer,
+                    state.scale_factor(),
+                );
+
                 window.request_redraw();
                 runtime.broadcast(redraw_event, core::event::Status::Ignored);
 
-                let _ = control_sender.start_send(match interface_state {
+                let control_flow = match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
                     } => match redraw_request {
@@ -461,8 +667,31 @@ async fn run_instance<A, E, C>(
                             ControlFlow::WaitUntil(at)
                         }
                     },
+                    _ if state.is_changing_theme() => ControlFlow::Poll,
                     _ => ControlFlow::Wait,
-                });
+                };
+
+                // Wake up to notice the end of an ongoing resize or a long
+                // press, to repeat a held key, or to follow dragged files
+                let deadline = placement
+                    .deadline()
+                    .into_iter()
+                    .chain(gestures.deadline())
+                    .chain(key_repeat.deadline())
+                    .chain(file_drop.deadline())
+                    .min();
+
+                let control_flow = match (control_flow, deadline) {
+                    (ControlFlow::Wait, Some(deadline)) => {
+                        ControlFlow::WaitUntil(deadline)
+                    }
+                    (ControlFlow::WaitUntil(at), Some(deadline)) => {
+                        ControlFlow::WaitUntil(at.min(deadline))
+                    }
+                    (control_flow, _) => control_flow,
+                };
+
+                let _ = control_sender.start_send(control_flow);
 
                 redraw_pending = false;
             }
@@ -477,6 +706,14 @@ async fn run_instance<A, E, C>(
             //         )),
             //     ));
             // }
+            event::Event::DeviceEvent {
+                event: winit::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                events.push(Event::Mouse(mouse::Event::RelativeMotion {
+                    delta: Vector::new(delta.0 as f32, delta.1 as f32),
+                }));
+            }
             event::Event::UserEvent(message) => {
                 messages.push(message);
             }
@@ -513,7 +750,27 @@ async fn run_instance<A, E, C>(
                         state.cursor(),
                     );
 
+                    inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        &renderer::Style {
+                            text_color: state.text_color(),
+                        },
+                        state.background_color(),
+                        state.cursor(),
+                    );
+
+                    hud.draw(
+                        &mut renderer,
+                        compositor.statistics(&renderer),
+                        &debug,
+                        state.logical_size(),
+                    );
+
                     if new_mouse_interaction != mouse_interaction {
+                        window.set_cursor_visible(
+                            new_mouse_interaction != mouse::Interaction::Hidden,
+                        );
                         window.set_cursor_icon(conversion::mouse_interaction(
                             new_mouse_interaction,
                         ));
@@ -536,10 +793,16 @@ async fn run_instance<A, E, C>(
                     &mut surface,
                     state.viewport(),
                     state.background_color(),
-                    &debug.overlay(),
+                    &inspector.overlay(debug.overlay()),
                 ) {
                     Ok(()) => {
                         debug.render_finished();
+                        hud.frame_presented();
+
+                        // The HUD measures frames continuously
+                        if hud.is_enabled() {
+                            window.request_redraw();
+                        }
 
                         // TODO: Handle animations!
                         // Maybe we can use `ControlFlow::WaitUntil` for this.
@@ -568,15 +831,89 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
+                #[cfg(feature = "accessibility")]
+                accessibility.process_event(&window, &window_event);
+
+                if let winit::event::WindowEvent::Focused(false) = window_event
+                {
+                    let is_pointer_captured = state.is_pointer_captured();
+                    let pressed_buttons = state.release_pointer();
+
+                    // Releases are not reported to unfocused windows
+                    if is_pointer_captured {
+                        events.extend(pressed_buttons.into_iter().map(
+                            |button| {
+                                Event::Mouse(mouse::Event::ButtonReleased(
+                                    button,
+                                ))
+                            },
+                        ));
+                    }
+                }
+
                 state.update(&window, &window_event, &mut debug);
 
-                if let Some(event) = conversion::window_event(
+                if inspector.update(&window_event, state.modifiers()) {
+                    window.request_redraw();
+                }
+
+                if hud.update(&window_event, state.modifiers()) {
+                    window.request_redraw();
+                }
+
+                if let Some(position) = file_drop.update(
+                    &window,
+                    &window_event,
+                    state.scale_factor(),
+                    &mut events,
+                ) {
+                    state.move_cursor(position);
+                }
+
+                let zoom = if zoom_shortcuts {
+                    zoom::shortcut(
+                        &window_event,
+                        state.modifiers(),
+                        state.zoom(),
+                    )
+                } else {
+                    None
+                };
+
+                // Zoom shortcuts are not seen by the widgets
+                if let Some(percent) = zoom {
+                    if state.set_zoom(&window, percent) {
+                        events.push(Event::Window(window::Event::ZoomChanged(
+                            state.zoom(),
+                        )));
+
+                        window.request_redraw();
+                    }
+                } else if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
-                    events.push(event);
+                    let touch = match &event {
+                        Event::Touch(touch) => Some(*touch),
+                        _ => None,
+                    };
+
+                    key_repeat.process(event, Instant::now(), &mut events);
+
+                    if let Some(touch) = touch {
+                        gestures.update(&touch, Instant::now(), |gesture| {
+                            events.push(Event::Gesture(gesture));
+                        });
+                    }
                 }
+
+                placement.update(
+                    &window,
+                    &window_event,
+                    state.scale_factor(),
+                    &mut events,
+                );
             }
             _ => {}
         }
@@ -586,6 +923,111 @@ async fn run_instance<A, E, C>(
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Moves or resizes the window if the cursor is over a drag area of the
+/// [`UserInterface`].
+///
+/// Double-clicking an area that moves the window toggles whether it is
+/// maximized.
+fn drag_window<Message, Renderer>(
+    window: &winit::window::Window,
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    renderer: &Renderer,
+    cursor: mouse::Cursor,
+    last_click: &mut Option<mouse::Click>,
+) where
+    Renderer: core::Renderer,
+{
+    let Some(position) = cursor.position() else {
+        return;
+    };
+
+    let mut find = drag_area::find(position);
+    user_interface.operate(renderer, &mut find);
+
+    match find.kind() {
+        Some(drag_area::Kind::Move) => {
+            let click = mouse::Click::new(position, *last_click);
+
+            if let mouse::click::Kind::Double = click.kind() {
+                window.set_maximized(!window.is_maximized());
+            } else {
+                let _ = window.drag_window();
+            }
+
+            *last_click = Some(click);
+        }
+        Some(drag_area::Kind::Resize(direction)) => {
+            let _ = window
+                .drag_resize_window(conversion::resize_direction(direction));
+        }
+        None => {}
+    }
+}
+
+/// Moves the window to the given [`Monitor`], centering it.
+///
+/// [`Monitor`]: core::window::Monitor
+fn move_to_monitor(
+    window: &winit::window::Window,
+    monitor: &core::window::Monitor,
+) {
+    // Monitors are identified by their position in the desktop
+    let handle = window.available_monitors().find(|handle| {
+        let position = handle.position();
+
+        (position.x, position.y) == (monitor.x, monitor.y)
+    });
+
+    if let Some(fullscreen) = window.fullscreen() {
+        if let Some(handle) = handle {
+            window.set_fullscreen(conversion::fullscreen(
+                Some(handle),
+                conversion::mode(Some(fullscreen)),
+            ));
+        }
+
+        return;
+    }
+
+    let size = window.outer_size();
+
+    let center = |start: i32, length: u32, window_length: u32| {
+        let offset = (i64::from(length) - i64::from(window_length)) / 2;
+
+        (i64::from(start) + offset.max(0)) as i32
+    };
+
+    window.set_outer_position(winit::dpi::PhysicalPosition::new(
+        center(monitor.x, monitor.size.width, size.width),
+        center(monitor.y, monitor.size.height, size.height),
+    ));
+}
+
+/// Grabs the cursor of the window, falling back to the other way of holding
+/// it when the platform does not support the given one.
+fn grab_cursor(window: &winit::window::Window, grab: core::window::CursorGrab) {
+    use core::window::CursorGrab;
+
+    let fallback = match grab {
+        CursorGrab::None => None,
+        CursorGrab::Confined => Some(CursorGrab::Locked),
+        CursorGrab::Locked => Some(CursorGrab::Confined),
+    };
+
+    let result = window
+        .set_cursor_grab(conversion::cursor_grab(grab))
+        .or_else(|error| match fallback {
+            Some(fallback) => {
+                window.set_cursor_grab(conversion::cursor_grab(fallback))
+            }
+            None => Err(error),
+        });
+
+    if let Err(error) = result {
+        log::warn!("Failed to grab cursor ({grab:?}): {error}");
+    }
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -626,7 +1068,10 @@ where
     let view_span = info_span!("Application", "VIEW").entered();
 
     debug.view_started();
-    let view = application.view();
+    let view = match report::catch(report::Phase::View, || application.view()) {
+        Ok(view) => view,
+        Err(report) => fallback(application, report),
+    };
 
     #[cfg(feature = "trace")]
     let _ = view_span.exit();
@@ -652,7 +1097,7 @@ pub fn update<A: Application, C, E: Executor>(
     compositor: &mut C,
     surface: &mut C::Surface,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<A>,
     renderer: &mut A::Renderer,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
@@ -672,7 +1117,12 @@ pub fn update<A: Application, C, E: Executor>(
         debug.log_message(&message);
 
         debug.update_started();
-        let command = runtime.enter(|| application.update(message));
+        let command = match report::catch(report::Phase::Update, || {
+            runtime.enter(|| application.update(message))
+        }) {
+            Ok(command) => command,
+            Err(report) => recover(application, runtime, report),
+        };
 
         #[cfg(feature = "trace")]
         let _ = update_span.exit();
@@ -695,8 +1145,87 @@ pub fn update<A: Application, C, E: Executor>(
         );
     }
 
-    let subscription = application.subscription();
-    runtime.track(subscription.into_recipes());
+    let command = track_subscription(application, runtime);
+
+    run_command(
+        application,
+        compositor,
+        surface,
+        cache,
+        state,
+        renderer,
+        command,
+        runtime,
+        clipboard,
+        should_exit,
+        proxy,
+        debug,
+        window,
+    );
+}
+
+/// Tracks the [`Subscription`] of the [`Application`], recovering from any
+/// panic producing it.
+fn track_subscription<A: Application, E: Executor>(
+    application: &mut A,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+) -> Command<A::Message>
+where
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    match report::catch(report::Phase::Subscription, || {
+        application.subscription()
+    }) {
+        Ok(subscription) => {
+            runtime.track(subscription.into_recipes());
+
+            Command::none()
+        }
+        // The previous subscriptions are kept alive
+        Err(report) => recover(application, runtime, report),
+    }
+}
+
+/// Reports a panic of the [`Application`] and handles the resulting message.
+///
+/// The panic is resumed if the [`Application`] does not report it, or if
+/// handling the report panics too.
+fn recover<A: Application, E: Executor>(
+    application: &mut A,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    report: Report,
+) -> Command<A::Message>
+where
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    let Some(message) = application.report(report.clone()) else {
+        report::resume(report);
+    };
+
+    match report::catch(report::Phase::Update, || {
+        runtime.enter(|| application.update(message))
+    }) {
+        Ok(command) => command,
+        Err(report) => report::resume(report),
+    }
+}
+
+/// Produces the fallback screen of a panic in the view of the
+/// [`Application`], which reports it.
+///
+/// The panic is resumed if the [`Application`] does not report it.
+fn fallback<A: Application>(
+    application: &A,
+    report: Report,
+) -> Element<'_, A::Message, A::Renderer>
+where
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    let Some(message) = application.report(report.clone()) else {
+        report::resume(report);
+    };
+
+    Element::new(Fallback::new(&report, message))
 }
 
 /// Runs the actions of a [`Command`].
@@ -705,7 +1234,7 @@ pub fn run_command<A, C, E>(
     compositor: &mut C,
     surface: &mut C::Surface,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<A>,
     renderer: &mut A::Renderer,
     command: Command<A::Message>,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
@@ -729,6 +1258,9 @@ pub fn run_command<A, C, E>(
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
+            command::Action::Prioritized { future, priority } => {
+                runtime.spawn_with_priority(future, priority);
+            }
             command::Action::Clipboard(action) => match action {
                 clipboard::Action::Read(tag) => {
                     let message = tag(clipboard.read());
@@ -740,6 +1272,88 @@ pub fn run_command<A, C, E>(
                 clipboard::Action::Write(contents) => {
                     clipboard.write(contents);
                 }
+                clipboard::Action::ReadImage(tag) => {
+                    let message = tag(clipboard.read_image());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::WriteImage(image) => {
+                    clipboard.write_image(image);
+                }
+                clipboard::Action::ReadHtml(tag) => {
+                    let message = tag(clipboard.read_html());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::WriteHtml { html, alt_text } => {
+                    clipboard.write_html(html, alt_text);
+                }
+                clipboard::Action::ReadFiles(tag) => {
+                    let message = tag(clipboard.read_files());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::WriteFiles(files) => {
+                    clipboard.write_files(files);
+                }
+            },
+            command::Action::Dnd(action) => match action {
+                dnd::Action::StartDrag(drag, on_finish) => {
+                    #[cfg(feature = "dnd")]
+                    {
+                        let preview = drag.preview.and_then(|preview| {
+                            render_preview(
+                                preview,
+                                application,
+                                compositor,
+                                surface,
+                                cache,
+                                state,
+                                renderer,
+                                debug,
+                            )
+                        });
+
+                        crate::dnd::start(
+                            window,
+                            drag.payload,
+                            preview,
+                            on_finish,
+                            proxy.clone(),
+                        );
+                    }
+
+                    #[cfg(not(feature = "dnd"))]
+                    {
+                        log::warn!(
+                            "Dragging out of the application requires the \
+                            `dnd` feature: {drag:?}"
+                        );
+
+                        proxy
+                            .send_event(on_finish(dnd::Outcome::Cancelled))
+                            .expect("Send message to event loop");
+                    }
+                }
+            },
+            command::Action::Notification(action) => match action {
+                notification::Action::Show(notification) => {
+                    #[cfg(feature = "notification")]
+                    crate::notification::show(notification, proxy.clone());
+
+                    #[cfg(not(feature = "notification"))]
+                    log::warn!(
+                        "Showing notifications requires the `notification` \
+                        feature: {:?}",
+                        notification.title
+                    );
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Close => {
@@ -748,6 +1362,11 @@ pub fn run_command<A, C, E>(
                 window::Action::Drag => {
                     let _res = window.drag_window();
                 }
+                window::Action::DragResize(direction) => {
+                    let _res = window.drag_resize_window(
+                        conversion::resize_direction(direction),
+                    );
+                }
                 window::Action::Resize(size) => {
                     window.set_inner_size(winit::dpi::LogicalSize {
                         width: size.width,
@@ -776,6 +1395,23 @@ pub fn run_command<A, C, E>(
                         y,
                     });
                 }
+                window::Action::MoveToMonitor(monitor) => {
+                    move_to_monitor(window, &monitor);
+                }
+                window::Action::FetchMonitors(tag) => {
+                    let primary = window.primary_monitor();
+
+                    let monitors = window
+                        .available_monitors()
+                        .map(|monitor| {
+                            conversion::monitor(&monitor, primary.as_ref())
+                        })
+                        .collect();
+
+                    proxy
+                        .send_event(tag(monitors))
+                        .expect("Send message to event loop");
+                }
                 window::Action::ChangeMode(mode) => {
                     window.set_visible(conversion::visible(mode));
                     window.set_fullscreen(conversion::fullscreen(
@@ -814,11 +1450,35 @@ pub fn run_command<A, C, E>(
                 window::Action::ChangeLevel(level) => {
                     window.set_window_level(conversion::window_level(level));
                 }
+                window::Action::SkipTaskbar(_skip) => {
+                    #[cfg(target_os = "windows")]
+                    {
+                        use winit::platform::windows::WindowExtWindows;
+
+                        window.set_skip_taskbar(_skip);
+                    }
+                }
+                window::Action::ChangeProgress(progress) => {
+                    crate::taskbar::change_progress(window, progress);
+                }
                 window::Action::FetchId(tag) => {
                     proxy
                         .send_event(tag(window.id().into()))
                         .expect("Send message to event loop");
                 }
+                window::Action::GrabCursor(grab) => {
+                    grab_cursor(window, grab);
+                }
+                window::Action::ChangeZoom(percent) => {
+                    if state.set_zoom(window, percent) {
+                        window.request_redraw();
+                    }
+                }
+                window::Action::FetchZoom(tag) => {
+                    proxy
+                        .send_event(tag(state.zoom()))
+                        .expect("Send message to event loop");
+                }
                 window::Action::Screenshot(tag) => {
                     let bytes = compositor.screenshot(
                         renderer,
@@ -901,6 +1561,66 @@ pub fn run_command<A, C, E>(
     }
 }
 
+/// Renders the given [`dnd::Preview`] of a drag into RGBA pixels.
+#[cfg(feature = "dnd")]
+#[allow(clippy::too_many_arguments)]
+fn render_preview<A, C>(
+    preview: dnd::Preview,
+    application: &A,
+    compositor: &mut C,
+    surface: &mut C::Surface,
+    cache: &mut user_interface::Cache,
+    state: &State<A>,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+) -> Option<(Vec<u8>, Size<u32>)>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    let id = match preview {
+        dnd::Preview::Image { rgba, size } => return Some((rgba, size)),
+        dnd::Preview::Widget(id) => id,
+    };
+
+    let mut user_interface = build_user_interface(
+        application,
+        std::mem::take(cache),
+        renderer,
+        state.logical_size(),
+        debug,
+    );
+
+    let mut find_bounds = dnd::find_bounds(id);
+    user_interface.operate(renderer, &mut find_bounds);
+
+    *cache = user_interface.into_cache();
+
+    let physical_size = state.physical_size();
+    let bounds = (find_bounds.bounds()? * state.scale_factor() as f32)
+        .intersection(&core::Rectangle::with_size(Size::new(
+            physical_size.width as f32,
+            physical_size.height as f32,
+        )))?;
+
+    let bytes = compositor.screenshot(
+        renderer,
+        surface,
+        state.viewport(),
+        state.background_color(),
+        &debug.overlay(),
+    );
+
+    let screenshot =
+        crate::runtime::window::Screenshot::new(bytes, physical_size)
+            .crop(bounds.snap())
+            .map_err(|error| log::warn!("Invalid drag preview: {error}"))
+            .ok()?;
+
+    Some((screenshot.bytes.to_vec(), screenshot.size))
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub fn run<T, F>(
@@ -2,9 +2,13 @@ use crate::application::{self, StyleSheet as _};
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
+use crate::core::time::{Duration, Instant};
+use crate::core::token;
 use crate::core::{Color, Size};
 use crate::graphics::Viewport;
+use crate::runtime::animation::{Animation, Easing, Interpolate};
 use crate::runtime::Debug;
+use crate::zoom;
 use crate::Application;
 
 use std::marker::PhantomData;
@@ -19,15 +23,30 @@ where
 {
     title: String,
     scale_factor: f64,
+    zoom: u32,
     viewport: Viewport,
     viewport_version: usize,
     cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
+    pressed_buttons: Vec<mouse::Button>,
+    is_pointer_captured: bool,
+    is_cursor_outside: bool,
     modifiers: winit::event::ModifiersState,
     theme: <A::Renderer as core::Renderer>::Theme,
     appearance: application::Appearance,
+    theme_transition: Duration,
+    transition: Option<Transition<<A::Renderer as core::Renderer>::Theme>>,
     application: PhantomData<A>,
 }
 
+/// A fade between the previous theme of an application and its current one.
+struct Transition<Theme> {
+    from: Theme,
+    from_appearance: application::Appearance,
+    current: Theme,
+    current_appearance: application::Appearance,
+    progress: Animation<f32>,
+}
+
 impl<A: Application> State<A>
 where
     <A::Renderer as core::Renderer>::Theme: application::StyleSheet,
@@ -39,6 +58,10 @@ where
         let theme = application.theme();
         let appearance = theme.appearance(&application.style());
 
+        if let Some(tokens) = theme.tokens() {
+            token::set(tokens);
+        }
+
         let viewport = {
             let physical_size = window.inner_size();
 
@@ -51,16 +74,31 @@ where
         Self {
             title,
             scale_factor,
+            zoom: zoom::DEFAULT,
             viewport,
             viewport_version: 0,
             cursor_position: None,
+            pressed_buttons: Vec::new(),
+            is_pointer_captured: false,
+            is_cursor_outside: false,
             modifiers: winit::event::ModifiersState::default(),
             theme,
             appearance,
+            theme_transition: Duration::ZERO,
+            transition: None,
             application: PhantomData,
         }
     }
 
+    /// Sets the [`Duration`] of the fade between themes when the theme of
+    /// the [`Application`] changes.
+    ///
+    /// By default, it is zero and themes are switched immediately.
+    pub fn theme_transition(mut self, duration: Duration) -> Self {
+        self.theme_transition = duration;
+        self
+    }
+
     /// Returns the current [`Viewport`] of the [`State`].
     pub fn viewport(&self) -> &Viewport {
         &self.viewport
@@ -88,6 +126,36 @@ where
         self.viewport.scale_factor()
     }
 
+    /// Returns the current zoom of the [`State`], in percent.
+    pub fn zoom(&self) -> u32 {
+        self.zoom
+    }
+
+    /// Changes the zoom of the [`State`], in percent.
+    ///
+    /// The zoom is applied on top of the scale factor of the window and the
+    /// [`Application`]. Returns true if the zoom changed.
+    pub fn set_zoom(&mut self, window: &Window, percent: u32) -> bool {
+        let percent = zoom::clamp(percent);
+
+        if percent == self.zoom {
+            return false;
+        }
+
+        self.zoom = percent;
+        self.viewport = Viewport::with_physical_size(
+            self.viewport.physical_size(),
+            window.scale_factor() * self.scale_factor * self.zoom_factor(),
+        );
+        self.viewport_version = self.viewport_version.wrapping_add(1);
+
+        true
+    }
+
+    fn zoom_factor(&self) -> f64 {
+        f64::from(self.zoom) / 100.0
+    }
+
     /// Returns the current cursor position of the [`State`].
     pub fn cursor(&self) -> mouse::Cursor {
         self.cursor_position
@@ -101,24 +169,65 @@ where
             .unwrap_or(mouse::Cursor::Unavailable)
     }
 
+    /// Moves the cursor of the [`State`] to the given physical position.
+    ///
+    /// Windows are not told where the cursor is while files are dragged over
+    /// them, so the shell finds out on its own.
+    pub fn move_cursor(&mut self, position: winit::dpi::PhysicalPosition<f64>) {
+        self.cursor_position = Some(position);
+    }
+
+    /// Returns whether the pointer is captured by a widget.
+    pub fn is_pointer_captured(&self) -> bool {
+        self.is_pointer_captured
+    }
+
+    /// Captures the pointer until every mouse button is released.
+    ///
+    /// While captured, the cursor keeps its last position when it leaves the
+    /// window. Nothing happens if no mouse button is pressed.
+    pub fn capture_pointer(&mut self) {
+        self.is_pointer_captured = !self.pressed_buttons.is_empty();
+    }
+
+    /// Releases the pointer, returning the mouse buttons that are still
+    /// pressed.
+    ///
+    /// Windows are not told about the releases that happen while they are
+    /// unfocused, so the shell reports them on its own.
+    pub fn release_pointer(&mut self) -> Vec<mouse::Button> {
+        self.is_pointer_captured = false;
+
+        if self.is_cursor_outside {
+            self.cursor_position = None;
+        }
+
+        std::mem::take(&mut self.pressed_buttons)
+    }
+
     /// Returns the current keyboard modifiers of the [`State`].
     pub fn modifiers(&self) -> winit::event::ModifiersState {
         self.modifiers
     }
 
     /// Returns the current theme of the [`State`].
+    ///
+    /// While the theme of the [`Application`] is changing, it is the one
+    /// found midway.
     pub fn theme(&self) -> &<A::Renderer as core::Renderer>::Theme {
-        &self.theme
+        self.transition
+            .as_ref()
+            .map_or(&self.theme, |transition| &transition.current)
     }
 
     /// Returns the current background [`Color`] of the [`State`].
     pub fn background_color(&self) -> Color {
-        self.appearance.background_color
+        self.current_appearance().background_color
     }
 
     /// Returns the current text [`Color`] of the [`State`].
     pub fn text_color(&self) -> Color {
-        self.appearance.text_color
+        self.current_appearance().text_color
     }
 
     /// Processes the provided window event and updates the [`State`]
@@ -135,7 +244,9 @@ where
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    window.scale_factor() * self.scale_factor,
+                    window.scale_factor()
+                        * self.scale_factor
+                        * self.zoom_factor(),
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
@@ -149,7 +260,7 @@ where
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    new_scale_factor * self.scale_factor,
+                    new_scale_factor * self.scale_factor * self.zoom_factor(),
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
@@ -160,8 +271,34 @@ where
             }) => {
                 self.cursor_position = Some(*position);
             }
+            WindowEvent::CursorEntered { .. } => {
+                self.is_cursor_outside = false;
+            }
             WindowEvent::CursorLeft { .. } => {
-                self.cursor_position = None;
+                self.is_cursor_outside = true;
+
+                // A captured pointer keeps dragging outside of the window
+                if !self.is_pointer_captured {
+                    self.cursor_position = None;
+                }
+            }
+            WindowEvent::MouseInput { button, state, .. } => {
+                let button = conversion::mouse_button(*button);
+
+                match state {
+                    winit::event::ElementState::Pressed => {
+                        if !self.pressed_buttons.contains(&button) {
+                            self.pressed_buttons.push(button);
+                        }
+                    }
+                    winit::event::ElementState::Released => {
+                        self.pressed_buttons.retain(|&other| other != button);
+
+                        if self.pressed_buttons.is_empty() {
+                            let _ = self.release_pointer();
+                        }
+                    }
+                }
             }
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = *new_modifiers;
@@ -206,7 +343,7 @@ where
         {
             self.viewport = Viewport::with_physical_size(
                 Size::new(new_size.width, new_size.height),
-                window.scale_factor() * new_scale_factor,
+                window.scale_factor() * new_scale_factor * self.zoom_factor(),
             );
             self.viewport_version = self.viewport_version.wrapping_add(1);
 
@@ -214,7 +351,97 @@ where
         }
 
         // Update theme and appearance
-        self.theme = application.theme();
-        self.appearance = self.theme.appearance(&application.style());
+        let theme = application.theme();
+        let appearance = theme.appearance(&application.style());
+
+        // The widgets are built right after, so they pick up the new tokens
+        if let Some(tokens) = theme.tokens() {
+            token::set(tokens);
+        }
+
+        // Only a different appearance is noticed as a new theme, so the
+        // transition keeps going when the same theme is produced again
+        if self.theme_transition.is_zero() || appearance == self.appearance {
+            self.theme = theme;
+            self.appearance = appearance;
+
+            return;
+        }
+
+        // The fade starts from whatever is shown right now
+        let previous = std::mem::replace(&mut self.theme, theme);
+        let previous_appearance =
+            std::mem::replace(&mut self.appearance, appearance);
+
+        let (from, from_appearance) = match self.transition.take() {
+            Some(transition) => {
+                (transition.current, transition.current_appearance)
+            }
+            None => (previous, previous_appearance),
+        };
+
+        let Some(current) = from.interpolate(&self.theme, 0.0) else {
+            return;
+        };
+
+        let mut progress = Animation::new(0.0)
+            .duration(self.theme_transition)
+            .easing(Easing::EaseInOut);
+
+        progress.go_to(1.0, Instant::now());
+
+        self.transition = Some(Transition {
+            from,
+            from_appearance,
+            current,
+            current_appearance: from_appearance,
+            progress,
+        });
+    }
+
+    /// Returns whether the [`State`] is fading between themes.
+    pub fn is_changing_theme(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Advances the fade between themes, if any, to `now`.
+    pub fn tick(&mut self, now: Instant) {
+        let Some(transition) = &mut self.transition else {
+            return;
+        };
+
+        if !transition.progress.is_animating(now) {
+            self.transition = None;
+
+            return;
+        }
+
+        let t = transition.progress.value(now);
+
+        if let Some(current) = transition.from.interpolate(&self.theme, t) {
+            transition.current = current;
+        }
+
+        transition.current_appearance =
+            interpolate(transition.from_appearance, self.appearance, t);
+    }
+
+    fn current_appearance(&self) -> application::Appearance {
+        self.transition
+            .as_ref()
+            .map_or(self.appearance, |transition| transition.current_appearance)
+    }
+}
+
+fn interpolate(
+    from: application::Appearance,
+    to: application::Appearance,
+    t: f32,
+) -> application::Appearance {
+    application::Appearance {
+        background_color: from
+            .background_color
+            .interpolate(&to.background_color, t),
+        text_color: from.text_color.interpolate(&to.text_color, t),
     }
 }
@@ -1,4 +1,7 @@
 //! Access the clipboard.
+use crate::core::image;
+
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -8,7 +11,10 @@ pub struct Clipboard {
 }
 
 enum State {
-    Connected(window_clipboard::Clipboard),
+    Connected {
+        text: window_clipboard::Clipboard,
+        rich: Option<Rich>,
+    },
     Unavailable,
 }
 
@@ -17,7 +23,10 @@ impl Clipboard {
     pub fn connect(window: &winit::window::Window) -> Clipboard {
         let state = window_clipboard::Clipboard::connect(window)
             .ok()
-            .map(State::Connected)
+            .map(|text| State::Connected {
+                text,
+                rich: Rich::connect(),
+            })
             .unwrap_or(State::Unavailable);
 
         Clipboard { state }
@@ -34,7 +43,7 @@ impl Clipboard {
     /// Reads the current content of the [`Clipboard`] as text.
     pub fn read(&self) -> Option<String> {
         match &self.state {
-            State::Connected(clipboard) => clipboard.read().ok(),
+            State::Connected { text, .. } => text.read().ok(),
             State::Unavailable => None,
         }
     }
@@ -42,7 +51,7 @@ impl Clipboard {
     /// Writes the given text contents to the [`Clipboard`].
     pub fn write(&mut self, contents: String) {
         match &mut self.state {
-            State::Connected(clipboard) => match clipboard.write(contents) {
+            State::Connected { text, .. } => match text.write(contents) {
                 Ok(()) => {}
                 Err(error) => {
                     log::warn!("error writing to clipboard: {error}");
@@ -51,6 +60,50 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    /// Reads the current image of the [`Clipboard`] as RGBA pixels.
+    pub fn read_image(&mut self) -> Option<image::Handle> {
+        self.rich()?.read_image()
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    pub fn write_image(&mut self, image: image::Handle) {
+        if let Some(rich) = self.rich() {
+            rich.write_image(image);
+        }
+    }
+
+    /// Reads the current HTML content of the [`Clipboard`].
+    pub fn read_html(&mut self) -> Option<String> {
+        self.rich()?.read_html()
+    }
+
+    /// Writes the given HTML to the [`Clipboard`], along with an optional
+    /// plain text alternative.
+    pub fn write_html(&mut self, html: String, alt_text: Option<String>) {
+        if let Some(rich) = self.rich() {
+            rich.write_html(html, alt_text);
+        }
+    }
+
+    /// Reads the current list of files of the [`Clipboard`].
+    pub fn read_files(&mut self) -> Option<Vec<PathBuf>> {
+        self.rich()?.read_files()
+    }
+
+    /// Writes the given list of files to the [`Clipboard`].
+    pub fn write_files(&mut self, files: Vec<PathBuf>) {
+        if let Some(rich) = self.rich() {
+            rich.write_files(files);
+        }
+    }
+
+    fn rich(&mut self) -> Option<&mut Rich> {
+        match &mut self.state {
+            State::Connected { rich, .. } => rich.as_mut(),
+            State::Unavailable => None,
+        }
+    }
 }
 
 impl crate::core::Clipboard for Clipboard {
@@ -62,3 +115,137 @@ impl crate::core::Clipboard for Clipboard {
         self.write(contents);
     }
 }
+
+/// The contents of the clipboard other than plain text, backed by
+/// [`arboard`].
+#[cfg(all(feature = "rich-clipboard", not(target_arch = "wasm32")))]
+struct Rich(arboard::Clipboard);
+
+#[cfg(all(feature = "rich-clipboard", not(target_arch = "wasm32")))]
+impl Rich {
+    fn connect() -> Option<Self> {
+        arboard::Clipboard::new()
+            .map(Self)
+            .map_err(|error| {
+                log::warn!("error connecting to clipboard: {error}")
+            })
+            .ok()
+    }
+
+    fn read_image(&mut self) -> Option<image::Handle> {
+        let image = self.0.get_image().ok()?;
+
+        Some(image::Handle::from_pixels(
+            image.width as u32,
+            image.height as u32,
+            image.bytes.into_owned(),
+        ))
+    }
+
+    fn write_image(&mut self, image: image::Handle) {
+        let Some(rgba) = decode(image.data()) else {
+            return;
+        };
+
+        let result = self.0.set_image(arboard::ImageData {
+            width: rgba.width() as usize,
+            height: rgba.height() as usize,
+            bytes: rgba.into_raw().into(),
+        });
+
+        if let Err(error) = result {
+            log::warn!("error writing image to clipboard: {error}");
+        }
+    }
+
+    fn read_html(&mut self) -> Option<String> {
+        self.0.get().html().ok()
+    }
+
+    fn write_html(&mut self, html: String, alt_text: Option<String>) {
+        if let Err(error) = self.0.set_html(html, alt_text) {
+            log::warn!("error writing HTML to clipboard: {error}");
+        }
+    }
+
+    fn read_files(&mut self) -> Option<Vec<PathBuf>> {
+        self.0.get().file_list().ok()
+    }
+
+    fn write_files(&mut self, files: Vec<PathBuf>) {
+        if let Err(error) = self.0.set().file_list(&files) {
+            log::warn!("error writing files to clipboard: {error}");
+        }
+    }
+}
+
+/// Decodes the given image data into RGBA pixels.
+#[cfg(all(feature = "rich-clipboard", not(target_arch = "wasm32")))]
+fn decode(data: &image::Data) -> Option<::image::RgbaImage> {
+    let decoded = match data {
+        image::Data::Path(path) => ::image::open(path),
+        image::Data::Bytes(bytes) | image::Data::Partial(bytes) => {
+            ::image::load_from_memory(bytes)
+        }
+        image::Data::Rgba {
+            width,
+            height,
+            pixels,
+        } => {
+            return ::image::RgbaImage::from_raw(
+                *width,
+                *height,
+                pixels.to_vec(),
+            );
+        }
+        image::Data::Raw {
+            width,
+            height,
+            format,
+            planes,
+        } => {
+            return ::image::RgbaImage::from_raw(
+                *width,
+                *height,
+                format
+                    .to_rgba(crate::core::Size::new(*width, *height), planes)?,
+            );
+        }
+        image::Data::Texture { .. } => return None,
+    };
+
+    decoded
+        .map(|image| image.into_rgba8())
+        .map_err(|error| log::warn!("error decoding clipboard image: {error}"))
+        .ok()
+}
+
+/// The rich contents of the clipboard are only supported on native
+/// platforms, with the `rich-clipboard` feature.
+#[cfg(not(all(feature = "rich-clipboard", not(target_arch = "wasm32"))))]
+struct Rich;
+
+#[cfg(not(all(feature = "rich-clipboard", not(target_arch = "wasm32"))))]
+impl Rich {
+    fn connect() -> Option<Self> {
+        None
+    }
+
+    fn read_image(&mut self) -> Option<image::Handle> {
+        None
+    }
+
+    fn write_image(&mut self, _image: image::Handle) {}
+
+    fn read_html(&mut self) -> Option<String> {
+        None
+    }
+
+    fn write_html(&mut self, _html: String, _alt_text: Option<String>) {}
+
+    fn read_files(&mut self) -> Option<Vec<PathBuf>> {
+        None
+    }
+
+    fn write_files(&mut self, _files: Vec<PathBuf>) {}
+}
@@ -4,9 +4,10 @@
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.10/runtime
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::pen;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point};
+use crate::core::{Event, Point, Size};
 use crate::Position;
 
 /// Converts a winit window event into an iced event.
@@ -87,6 +88,7 @@ pub fn window_event(
             input:
                 winit::event::KeyboardInput {
                     virtual_keycode: Some(virtual_keycode),
+                    scancode,
                     state,
                     ..
                 },
@@ -94,18 +96,24 @@ pub fn window_event(
         } => Some(Event::Keyboard({
             let key_code = key_code(*virtual_keycode);
             let modifiers = self::modifiers(modifiers);
+            let scancode = Some(*scancode);
 
             match state {
                 winit::event::ElementState::Pressed => {
+                    // Repeats are detected by the shell, since winit does not
+                    // report them
                     keyboard::Event::KeyPressed {
                         key_code,
                         modifiers,
+                        scancode,
+                        is_repeat: false,
                     }
                 }
                 winit::event::ElementState::Released => {
                     keyboard::Event::KeyReleased {
                         key_code,
                         modifiers,
+                        scancode,
                     }
                 }
             }
@@ -127,9 +135,10 @@ pub fn window_event(
         WindowEvent::HoveredFileCancelled => {
             Some(Event::Window(window::Event::FilesHoveredLeft))
         }
-        WindowEvent::Touch(touch) => {
-            Some(Event::Touch(touch_event(*touch, scale_factor)))
-        }
+        WindowEvent::Touch(touch) => Some(match pen_state(touch) {
+            Some(state) => Event::Pen(pen_event(*touch, state, scale_factor)),
+            None => Event::Touch(touch_event(*touch, scale_factor)),
+        }),
         WindowEvent::Moved(position) => {
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
@@ -153,6 +162,42 @@ pub fn window_level(level: window::Level) -> winit::window::WindowLevel {
     }
 }
 
+/// Converts a `MonitorHandle` from [`winit`] to an [`iced`] monitor.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+pub fn monitor(
+    monitor: &winit::monitor::MonitorHandle,
+    primary: Option<&winit::monitor::MonitorHandle>,
+) -> window::Monitor {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    window::Monitor {
+        name: monitor.name(),
+        x: position.x,
+        y: position.y,
+        size: Size::new(size.width, size.height),
+        scale_factor: monitor.scale_factor(),
+        is_primary: primary == Some(monitor),
+        video_modes: monitor
+            .video_modes()
+            .map(|video_mode| self::video_mode(&video_mode))
+            .collect(),
+    }
+}
+
+/// Converts a [`window::CursorGrab`] to a [`winit`] cursor grab mode.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn cursor_grab(grab: window::CursorGrab) -> winit::window::CursorGrabMode {
+    match grab {
+        window::CursorGrab::None => winit::window::CursorGrabMode::None,
+        window::CursorGrab::Confined => winit::window::CursorGrabMode::Confined,
+        window::CursorGrab::Locked => winit::window::CursorGrabMode::Locked,
+    }
+}
+
 /// Converts a [`Position`] to a [`winit`] logical position for a given monitor.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -208,13 +253,26 @@ pub fn fullscreen(
         window::Mode::Fullscreen => {
             Some(winit::window::Fullscreen::Borderless(monitor))
         }
+        window::Mode::ExclusiveFullscreen(video_mode) => {
+            match monitor
+                .as_ref()
+                .and_then(|monitor| closest_video_mode(monitor, video_mode))
+            {
+                Some(video_mode) => {
+                    Some(winit::window::Fullscreen::Exclusive(video_mode))
+                }
+                None => Some(winit::window::Fullscreen::Borderless(monitor)),
+            }
+        }
     }
 }
 
 /// Converts a [`window::Mode`] to a visibility flag.
 pub fn visible(mode: window::Mode) -> bool {
     match mode {
-        window::Mode::Windowed | window::Mode::Fullscreen => true,
+        window::Mode::Windowed
+        | window::Mode::Fullscreen
+        | window::Mode::ExclusiveFullscreen(_) => true,
         window::Mode::Hidden => false,
     }
 }
@@ -225,10 +283,54 @@ pub fn visible(mode: window::Mode) -> bool {
 pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     match mode {
         None => window::Mode::Windowed,
-        Some(_) => window::Mode::Fullscreen,
+        Some(winit::window::Fullscreen::Borderless(_)) => {
+            window::Mode::Fullscreen
+        }
+        Some(winit::window::Fullscreen::Exclusive(video_mode)) => {
+            window::Mode::ExclusiveFullscreen(self::video_mode(&video_mode))
+        }
+    }
+}
+
+/// Converts a `VideoMode` from [`winit`] to an [`iced`] video mode.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+pub fn video_mode(video_mode: &winit::monitor::VideoMode) -> window::VideoMode {
+    let size = video_mode.size();
+
+    window::VideoMode {
+        size: Size::new(size.width, size.height),
+        bit_depth: video_mode.bit_depth(),
+        refresh_rate_millihertz: video_mode.refresh_rate_millihertz(),
     }
 }
 
+/// Finds the `VideoMode` of the given monitor closest to the requested
+/// [`window::VideoMode`].
+///
+/// The size is matched first, then the refresh rate, and finally the bit
+/// depth.
+fn closest_video_mode(
+    monitor: &winit::monitor::MonitorHandle,
+    requested: window::VideoMode,
+) -> Option<winit::monitor::VideoMode> {
+    let area = |size: Size<u32>| u64::from(size.width) * u64::from(size.height);
+
+    monitor.video_modes().min_by_key(|candidate| {
+        let candidate = video_mode(candidate);
+
+        (
+            candidate.size != requested.size,
+            area(candidate.size).abs_diff(area(requested.size)),
+            candidate
+                .refresh_rate_millihertz
+                .abs_diff(requested.refresh_rate_millihertz),
+            candidate.bit_depth != requested.bit_depth,
+        )
+    })
+}
+
 /// Converts a [`mouse::Interaction`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -249,7 +351,15 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::ResizingDiagonallyUp => {
+            winit::window::CursorIcon::NeswResize
+        }
+        Interaction::ResizingDiagonallyDown => {
+            winit::window::CursorIcon::NwseResize
+        }
         Interaction::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        // Hiding the cursor is up to the window
+        Interaction::Hidden => winit::window::CursorIcon::Default,
     }
 }
 
@@ -325,6 +435,77 @@ pub fn touch_event(
     }
 }
 
+/// Converts a `Touch` made with a pen from [`winit`] to an [`iced`] pen
+/// event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.10
+pub fn pen_event(
+    touch: winit::event::Touch,
+    state: pen::State,
+    scale_factor: f64,
+) -> pen::Event {
+    let position = {
+        let location = touch.location.to_logical::<f64>(scale_factor);
+
+        Point::new(location.x as f32, location.y as f32)
+    };
+
+    match touch.phase {
+        winit::event::TouchPhase::Started => {
+            pen::Event::Pressed { position, state }
+        }
+        winit::event::TouchPhase::Moved => {
+            pen::Event::Moved { position, state }
+        }
+        winit::event::TouchPhase::Ended => {
+            pen::Event::Lifted { position, state }
+        }
+        winit::event::TouchPhase::Cancelled => pen::Event::Lost { position },
+    }
+}
+
+/// Returns the [`pen::State`] of a `Touch` from [`winit`], if it was made
+/// with a pen.
+///
+/// [`winit`] does not tell pens and fingers apart. On Windows, the state is
+/// read from the pointer of the `Touch`; on iOS, only pencils report the
+/// altitude of the touch. Other platforms report pens as touches.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+#[cfg(target_os = "windows")]
+pub fn pen_state(touch: &winit::event::Touch) -> Option<pen::State> {
+    windows::pen_state(touch.id)
+}
+
+/// Returns the [`pen::State`] of a `Touch` from [`winit`], if it was made
+/// with a pen.
+///
+/// [`winit`] does not tell pens and fingers apart. On Windows, the state is
+/// read from the pointer of the `Touch`; on iOS, only pencils report the
+/// altitude of the touch. Other platforms report pens as touches.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+#[cfg(not(target_os = "windows"))]
+pub fn pen_state(touch: &winit::event::Touch) -> Option<pen::State> {
+    match touch.force {
+        Some(winit::event::Force::Calibrated {
+            force,
+            max_possible_force,
+            altitude_angle: Some(altitude_angle),
+        }) => Some(pen::State {
+            pressure: (force / max_possible_force).clamp(0.0, 1.0) as f32,
+            // The azimuth of the pencil is unknown
+            tilt: pen::Tilt {
+                x: 90.0 - altitude_angle.to_degrees() as f32,
+                y: 0.0,
+            },
+            ..pen::State::default()
+        }),
+        _ => None,
+    }
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -505,6 +686,30 @@ pub fn key_code(
     }
 }
 
+/// Converts a [`window::Direction`] into its `winit` counterpart.
+pub fn resize_direction(
+    direction: window::Direction,
+) -> winit::window::ResizeDirection {
+    match direction {
+        window::Direction::North => winit::window::ResizeDirection::North,
+        window::Direction::South => winit::window::ResizeDirection::South,
+        window::Direction::East => winit::window::ResizeDirection::East,
+        window::Direction::West => winit::window::ResizeDirection::West,
+        window::Direction::NorthEast => {
+            winit::window::ResizeDirection::NorthEast
+        }
+        window::Direction::NorthWest => {
+            winit::window::ResizeDirection::NorthWest
+        }
+        window::Direction::SouthEast => {
+            winit::window::ResizeDirection::SouthEast
+        }
+        window::Direction::SouthWest => {
+            winit::window::ResizeDirection::SouthWest
+        }
+    }
+}
+
 /// Converts some [`UserAttention`] into it's `winit` counterpart.
 ///
 /// [`UserAttention`]: window::UserAttention
@@ -539,3 +744,54 @@ pub(crate) fn is_private_use_character(c: char) -> bool {
         | '\u{100000}'..='\u{10FFFD}'
     )
 }
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use crate::core::pen;
+
+    use winapi::um::winuser::{
+        GetPointerPenInfo, GetPointerType, PEN_FLAG_BARREL, PEN_FLAG_ERASER,
+        PEN_FLAG_INVERTED, POINTER_INPUT_TYPE, POINTER_PEN_INFO, PT_PEN,
+    };
+
+    /// Reads the state of the pen with the given pointer id, if it is one.
+    #[allow(unsafe_code)]
+    pub fn pen_state(pointer_id: u64) -> Option<pen::State> {
+        let pointer_id = pointer_id as u32;
+
+        let mut pointer_type: POINTER_INPUT_TYPE = 0;
+
+        // SAFETY: both functions only write to the given output
+        let is_pen = unsafe { GetPointerType(pointer_id, &mut pointer_type) }
+            != 0
+            && pointer_type == PT_PEN;
+
+        if !is_pen {
+            return None;
+        }
+
+        let mut info: POINTER_PEN_INFO = unsafe { std::mem::zeroed() };
+
+        if unsafe { GetPointerPenInfo(pointer_id, &mut info) } == 0 {
+            return None;
+        }
+
+        let is_eraser =
+            info.penFlags & (PEN_FLAG_ERASER | PEN_FLAG_INVERTED) != 0;
+
+        Some(pen::State {
+            // Pressure is normalized to a range from 0 to 1024
+            pressure: (info.pressure as f32 / 1024.0).clamp(0.0, 1.0),
+            tilt: pen::Tilt {
+                x: info.tiltX as f32,
+                y: info.tiltY as f32,
+            },
+            tool: if is_eraser {
+                pen::Tool::Eraser
+            } else {
+                pen::Tool::Pen
+            },
+            is_barrel_pressed: info.penFlags & PEN_FLAG_BARREL != 0,
+        })
+    }
+}
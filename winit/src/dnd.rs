@@ -0,0 +1,85 @@
+//! Drag data out of the application with [`drag`].
+//!
+//! Only dragging files is supported natively, on Windows and macOS. Other
+//! payloads and platforms cancel the drag right away.
+use crate::core::Size;
+use crate::runtime::dnd::{Outcome, Payload};
+
+use std::io::Cursor;
+use std::sync::Mutex;
+
+/// Starts a native drag of the given [`Payload`], sending one of the given
+/// messages through the event loop proxy once it finishes.
+pub(crate) fn start<Message>(
+    window: &winit::window::Window,
+    payload: Payload,
+    preview: Option<(Vec<u8>, Size<u32>)>,
+    on_finish: impl Fn(Outcome) -> Message,
+    proxy: winit::event_loop::EventLoopProxy<Message>,
+) where
+    Message: Send + 'static,
+{
+    let Payload::Files(paths) = payload else {
+        log::warn!("Only files can be dragged out of the application");
+
+        // The event loop may be gone already
+        let _ = proxy.send_event(on_finish(Outcome::Cancelled));
+        return;
+    };
+
+    // Without a preview, the drag shows a transparent pixel
+    let image = preview
+        .and_then(|(rgba, size)| encode(rgba, size))
+        .or_else(|| encode(vec![0; 4], Size::new(1, 1)))
+        .unwrap_or_default();
+
+    // The callback may run on another thread, so the messages are produced
+    // beforehand
+    let messages = Mutex::new(Some((
+        on_finish(Outcome::Dropped),
+        on_finish(Outcome::Cancelled),
+    )));
+
+    let cancelled = on_finish(Outcome::Cancelled);
+
+    let result = drag::start_drag(
+        window,
+        drag::DragItem::Files(paths),
+        drag::Image::Raw(image),
+        {
+            let proxy = proxy.clone();
+
+            move |result| {
+                let Some((dropped, cancelled)) =
+                    messages.lock().expect("Lock drag messages").take()
+                else {
+                    return;
+                };
+
+                let _ = proxy.send_event(match result {
+                    drag::DragResult::Dropped => dropped,
+                    drag::DragResult::Cancel => cancelled,
+                });
+            }
+        },
+    );
+
+    if let Err(error) = result {
+        log::warn!("Failed to start drag: {error}");
+
+        let _ = proxy.send_event(cancelled);
+    }
+}
+
+/// Encodes the given RGBA pixels as a PNG image.
+fn encode(rgba: Vec<u8>, size: Size<u32>) -> Option<Vec<u8>> {
+    let image = image::RgbaImage::from_raw(size.width, size.height, rgba)?;
+    let mut png = Cursor::new(Vec::new());
+
+    image
+        .write_to(&mut png, image::ImageOutputFormat::Png)
+        .map_err(|error| log::warn!("Invalid drag preview: {error}"))
+        .ok()?;
+
+    Some(png.into_inner())
+}
@@ -0,0 +1,216 @@
+//! Embed iced in an existing `winit` application.
+//!
+//! An [`Embedded`] program lets an application that already owns a `winit`
+//! window, an event loop, and a renderer—like a game—draw an iced user
+//! interface on top of its own frames:
+//!
+//! 1. Create a renderer, like an `iced_wgpu::Renderer`, with the device of
+//!    the application.
+//! 2. Create an [`Embedded`] program with [`Embedded::new`].
+//! 3. Feed it every `winit` window event with [`Embedded::handle_event`].
+//! 4. Call [`Embedded::update`] once the events have been handled, which
+//!    processes the queued events and draws the user interface.
+//! 5. Render the primitives of the renderer on top of the frame of the
+//!    application, before presenting it.
+//!
+//! With `iced_wgpu`, the last step looks like this:
+//!
+//! ```ignore
+//! renderer.with_primitives(|backend, primitives| {
+//!     backend.present(
+//!         &device,
+//!         &queue,
+//!         &mut encoder,
+//!         None, // Keep what the application has drawn
+//!         &frame_view,
+//!         primitives,
+//!         embedded.viewport(),
+//!         &embedded.debug().overlay(),
+//!     );
+//! });
+//! ```
+//!
+//! The `integration` example overlays some controls on top of a scene drawn
+//! directly with `wgpu`.
+use crate::conversion;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::{Event, Size};
+use crate::graphics::Viewport;
+use crate::runtime::program::{self, Program};
+use crate::runtime::{Command, Debug};
+use crate::Clipboard;
+
+use winit::event::{ModifiersState, WindowEvent};
+use winit::window::Window;
+
+/// A [`Program`] embedded in a `winit` window owned by another application.
+#[allow(missing_debug_implementations)]
+pub struct Embedded<P>
+where
+    P: Program + 'static,
+{
+    state: program::State<P>,
+    viewport: Viewport,
+    cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
+    modifiers: ModifiersState,
+    clipboard: Clipboard,
+    debug: Debug,
+}
+
+impl<P> Embedded<P>
+where
+    P: Program + 'static,
+{
+    /// Creates a new [`Embedded`] program filling the given [`Window`].
+    pub fn new(
+        program: P,
+        window: &Window,
+        renderer: &mut P::Renderer,
+    ) -> Self {
+        let physical_size = window.inner_size();
+
+        let viewport = Viewport::with_physical_size(
+            Size::new(physical_size.width, physical_size.height),
+            window.scale_factor(),
+        );
+
+        let mut debug = Debug::new();
+
+        let state = program::State::new(
+            program,
+            viewport.logical_size(),
+            renderer,
+            &mut debug,
+        );
+
+        Self {
+            state,
+            viewport,
+            cursor_position: None,
+            modifiers: ModifiersState::default(),
+            clipboard: Clipboard::connect(window),
+            debug,
+        }
+    }
+
+    /// Handles an event of the [`Window`] of the [`Embedded`] program.
+    ///
+    /// The event is queued until the next [`Embedded::update`]; and the
+    /// [`Viewport`] follows the size and scale factor of the [`Window`].
+    pub fn handle_event(&mut self, window: &Window, event: &WindowEvent<'_>) {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_position = Some(*position);
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor_position = None;
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = *modifiers;
+            }
+            WindowEvent::Resized(size) => {
+                self.viewport = Viewport::with_physical_size(
+                    Size::new(size.width, size.height),
+                    window.scale_factor(),
+                );
+            }
+            WindowEvent::ScaleFactorChanged {
+                scale_factor,
+                new_inner_size,
+            } => {
+                self.viewport = Viewport::with_physical_size(
+                    Size::new(new_inner_size.width, new_inner_size.height),
+                    *scale_factor,
+                );
+            }
+            _ => {}
+        }
+
+        if let Some(event) = conversion::window_event(
+            event,
+            window.scale_factor(),
+            self.modifiers,
+        ) {
+            self.state.queue_event(event);
+        }
+    }
+
+    /// Queues a message for the [`Program`], which is processed during the
+    /// next [`Embedded::update`].
+    pub fn queue_message(&mut self, message: P::Message) {
+        self.state.queue_message(message);
+    }
+
+    /// Returns true if there are events or messages waiting for the next
+    /// [`Embedded::update`].
+    pub fn has_pending_events(&self) -> bool {
+        !self.state.is_queue_empty()
+    }
+
+    /// Processes the queued events and messages, updating the [`Program`]
+    /// and drawing its user interface with the given renderer.
+    ///
+    /// Returns the events that the user interface has not captured—so the
+    /// application can handle them instead—and the [`Command`] produced by
+    /// the [`Program`], if it was updated. Since the application owns the
+    /// event loop, it is in charge of running the [`Command`].
+    pub fn update(
+        &mut self,
+        renderer: &mut P::Renderer,
+        theme: &<P::Renderer as crate::core::Renderer>::Theme,
+        style: &renderer::Style,
+    ) -> (Vec<Event>, Option<Command<P::Message>>) {
+        let cursor = self
+            .cursor_position
+            .map(|position| {
+                mouse::Cursor::Available(conversion::cursor_position(
+                    position,
+                    self.viewport.scale_factor(),
+                ))
+            })
+            .unwrap_or(mouse::Cursor::Unavailable);
+
+        self.state.update(
+            self.viewport.logical_size(),
+            cursor,
+            renderer,
+            theme,
+            style,
+            &mut self.clipboard,
+            &mut self.debug,
+        )
+    }
+
+    /// Sets the mouse cursor of the [`Window`] to the one requested by the
+    /// user interface.
+    pub fn sync_cursor(&self, window: &Window) {
+        window.set_cursor_icon(conversion::mouse_interaction(
+            self.state.mouse_interaction(),
+        ));
+    }
+
+    /// Returns the current [`mouse::Interaction`] of the user interface.
+    ///
+    /// Any interaction other than [`mouse::Interaction::Idle`] means the
+    /// mouse is over an interactive widget.
+    pub fn mouse_interaction(&self) -> mouse::Interaction {
+        self.state.mouse_interaction()
+    }
+
+    /// Returns the [`Viewport`] the user interface is rendered with.
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Returns a reference to the embedded [`Program`].
+    pub fn program(&self) -> &P {
+        self.state.program()
+    }
+
+    /// Returns the [`Debug`] information of the [`Embedded`] program; like
+    /// its overlay.
+    pub fn debug(&self) -> &Debug {
+        &self.debug
+    }
+}
@@ -0,0 +1,125 @@
+//! Show a fallback screen when the view of an application panics.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::widget::Tree;
+use crate::core::{
+    Clipboard, Color, Layout, Length, Point, Rectangle, Shadow, Shell, Size,
+    Widget,
+};
+use crate::runtime::report::Report;
+
+/// The space between the borders of the window and the description.
+const PADDING: f32 = 20.0;
+
+/// A widget describing a [`Report`], which produces the message reporting
+/// it as soon as it receives any event.
+pub(crate) struct Fallback<Message> {
+    description: String,
+    message: Option<Message>,
+}
+
+impl<Message> Fallback<Message> {
+    /// Creates a new [`Fallback`] for the given [`Report`] and message.
+    pub(crate) fn new(report: &Report, message: Message) -> Self {
+        let mut description = format!(
+            "The application stopped working.\n\n\
+            It panicked during its {}:\n{}",
+            report.phase, report.message
+        );
+
+        if let Some(location) = &report.location {
+            description.push_str(&format!("\n\nat {location}"));
+        }
+
+        Self {
+            description,
+            message: Some(message),
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Fallback<Message>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fill
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.max())
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Some(message) = self.message.take() {
+            shell.publish(message);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: Shadow::default(),
+            },
+            Color::from_rgb8(0x2b, 0x1d, 0x1d),
+        );
+
+        renderer.fill_text(
+            text::Text {
+                content: &self.description,
+                bounds: Size::new(
+                    (bounds.width - PADDING * 2.0).max(0.0),
+                    (bounds.height - PADDING * 2.0).max(0.0),
+                ),
+                size: renderer.default_size(),
+                line_height: LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: Shaping::Basic,
+            },
+            Point::new(bounds.x + PADDING, bounds.y + PADDING),
+            Color::WHITE,
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Track the files dragged over a window, reporting the position winit lacks.
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::Event;
+
+/// How often the position of the dragged files is polled.
+const POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+/// The files dragged over a window.
+#[derive(Debug, Default)]
+pub(crate) struct FileDrop {
+    is_hovering: bool,
+    position: Option<winit::dpi::PhysicalPosition<f64>>,
+    next_poll: Option<Instant>,
+}
+
+impl FileDrop {
+    /// Creates a new [`FileDrop`] with no files hovering the window.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the [`FileDrop`] before the given [`winit`] event is
+    /// processed, producing the position of the files about to be dropped.
+    ///
+    /// Returns the new physical position of the cursor, if it moved.
+    pub(crate) fn update(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent<'_>,
+        scale_factor: f64,
+        events: &mut Vec<Event>,
+    ) -> Option<winit::dpi::PhysicalPosition<f64>> {
+        use winit::event::WindowEvent;
+
+        match event {
+            WindowEvent::HoveredFile(_) if !self.is_hovering => {
+                self.is_hovering = true;
+                self.position = None;
+
+                self.poll(window, Instant::now(), scale_factor, events)
+            }
+            WindowEvent::DroppedFile(_) if self.is_hovering => {
+                let position =
+                    self.poll(window, Instant::now(), scale_factor, events);
+
+                self.is_hovering = false;
+                self.next_poll = None;
+
+                position
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.is_hovering = false;
+                self.next_poll = None;
+
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Produces the position of the hovering files, if it changed.
+    ///
+    /// Returns the new physical position of the cursor, if it moved.
+    pub(crate) fn tick(
+        &mut self,
+        window: &winit::window::Window,
+        now: Instant,
+        scale_factor: f64,
+        events: &mut Vec<Event>,
+    ) -> Option<winit::dpi::PhysicalPosition<f64>> {
+        if self.next_poll.is_some_and(|next_poll| now >= next_poll) {
+            self.poll(window, now, scale_factor, events)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the time when the position of the hovering files should be
+    /// polled next, if any.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.next_poll
+    }
+
+    fn poll(
+        &mut self,
+        window: &winit::window::Window,
+        now: Instant,
+        scale_factor: f64,
+        events: &mut Vec<Event>,
+    ) -> Option<winit::dpi::PhysicalPosition<f64>> {
+        // The position cannot be known in some platforms
+        let position = cursor_position(window)?;
+
+        self.next_poll = Some(now + POLL_INTERVAL);
+
+        if self.position == Some(position) {
+            return None;
+        }
+
+        self.position = Some(position);
+
+        let logical = position.to_logical::<f64>(scale_factor);
+
+        events.push(Event::Window(window::Event::FilesHoveredMoved {
+            x: logical.x.round() as i32,
+            y: logical.y.round() as i32,
+        }));
+
+        Some(position)
+    }
+}
+
+/// Returns the physical position of the cursor relative to the window.
+///
+/// Windows do not receive cursor events while files are dragged over them,
+/// so the position is read from the system.
+#[cfg(target_os = "windows")]
+#[allow(unsafe_code)]
+fn cursor_position(
+    window: &winit::window::Window,
+) -> Option<winit::dpi::PhysicalPosition<f64>> {
+    use winapi::shared::windef::{HWND, POINT};
+    use winapi::um::winuser::{GetCursorPos, ScreenToClient};
+    use winit::platform::windows::WindowExtWindows;
+
+    let mut point = POINT { x: 0, y: 0 };
+
+    // SAFETY: the point is valid for writes and the handle belongs to a live
+    // window
+    let is_known = unsafe {
+        GetCursorPos(&mut point) != 0
+            && ScreenToClient(window.hwnd() as HWND, &mut point) != 0
+    };
+
+    is_known.then_some(winit::dpi::PhysicalPosition::new(
+        f64::from(point.x),
+        f64::from(point.y),
+    ))
+}
+
+/// Returns the physical position of the cursor relative to the window.
+///
+/// Other platforms do not expose the position of the files dragged over a
+/// window.
+#[cfg(not(target_os = "windows"))]
+fn cursor_position(
+    _window: &winit::window::Window,
+) -> Option<winit::dpi::PhysicalPosition<f64>> {
+    None
+}
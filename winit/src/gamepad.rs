@@ -0,0 +1,153 @@
+//! Listen to gamepad input with [`gilrs`].
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::{SinkExt, StreamExt};
+use crate::futures::subscription::{self, Subscription};
+use crate::runtime::gamepad::{Axis, Button, Event, Id};
+
+use std::thread;
+use std::time::Duration;
+
+/// How often the gamepads are polled when idle.
+const POLL_INTERVAL: Duration = Duration::from_millis(8);
+
+/// Subscribes to the [`Event`]s of every gamepad.
+///
+/// Gamepads connected before subscribing are reported with
+/// [`Event::Connected`] first.
+///
+/// Combine it with [`navigate`] to move the focus with the D-pad.
+///
+/// [`navigate`]: crate::runtime::gamepad::navigate
+pub fn events() -> Subscription<Event> {
+    struct Events;
+
+    subscription::channel(
+        std::any::TypeId::of::<Events>(),
+        100,
+        |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            listen(sender);
+
+            loop {
+                match receiver.next().await {
+                    Some(event) => {
+                        let _ = output.send(event).await;
+                    }
+                    None => {
+                        // Gamepads are unavailable
+                        crate::futures::futures::future::pending::<()>().await;
+                    }
+                }
+            }
+        },
+    )
+}
+
+fn listen(sender: mpsc::UnboundedSender<Event>) {
+    let _ = thread::spawn(move || {
+        // Some backends of `gilrs` are not `Send`, so it lives in this thread
+        let mut gilrs = match gilrs::Gilrs::new() {
+            Ok(gilrs) => gilrs,
+            Err(error) => {
+                log::warn!("Gamepads are unavailable: {error}");
+                return;
+            }
+        };
+
+        for (id, gamepad) in gilrs.gamepads() {
+            let event = Event::Connected {
+                id: Id(id.into()),
+                name: gamepad.name().to_owned(),
+            };
+
+            if sender.unbounded_send(event).is_err() {
+                return;
+            }
+        }
+
+        loop {
+            while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event()
+            {
+                let Some(event) = convert(&gilrs, id, event) else {
+                    continue;
+                };
+
+                if sender.unbounded_send(event).is_err() {
+                    return;
+                }
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
+
+fn convert(
+    gilrs: &gilrs::Gilrs,
+    gamepad: gilrs::GamepadId,
+    event: gilrs::EventType,
+) -> Option<Event> {
+    use gilrs::EventType;
+
+    let id = Id(gamepad.into());
+
+    Some(match event {
+        EventType::Connected => Event::Connected {
+            id,
+            name: gilrs.gamepad(gamepad).name().to_owned(),
+        },
+        EventType::Disconnected => Event::Disconnected { id },
+        EventType::ButtonPressed(button, _) => Event::ButtonPressed {
+            id,
+            button: self::button(button),
+        },
+        EventType::ButtonReleased(button, _) => Event::ButtonReleased {
+            id,
+            button: self::button(button),
+        },
+        EventType::AxisChanged(axis, value, _) => Event::AxisChanged {
+            id,
+            axis: self::axis(axis),
+            value,
+        },
+        _ => return None,
+    })
+}
+
+fn button(button: gilrs::Button) -> Button {
+    match button {
+        gilrs::Button::South => Button::South,
+        gilrs::Button::East => Button::East,
+        gilrs::Button::North => Button::North,
+        gilrs::Button::West => Button::West,
+        gilrs::Button::LeftTrigger => Button::LeftBumper,
+        gilrs::Button::RightTrigger => Button::RightBumper,
+        gilrs::Button::LeftTrigger2 => Button::LeftTrigger,
+        gilrs::Button::RightTrigger2 => Button::RightTrigger,
+        gilrs::Button::Select => Button::Select,
+        gilrs::Button::Start => Button::Start,
+        gilrs::Button::Mode => Button::Mode,
+        gilrs::Button::LeftThumb => Button::LeftStick,
+        gilrs::Button::RightThumb => Button::RightStick,
+        gilrs::Button::DPadUp => Button::DPadUp,
+        gilrs::Button::DPadDown => Button::DPadDown,
+        gilrs::Button::DPadLeft => Button::DPadLeft,
+        gilrs::Button::DPadRight => Button::DPadRight,
+        _ => Button::Other,
+    }
+}
+
+fn axis(axis: gilrs::Axis) -> Axis {
+    match axis {
+        gilrs::Axis::LeftStickX => Axis::LeftStickX,
+        gilrs::Axis::LeftStickY => Axis::LeftStickY,
+        gilrs::Axis::RightStickX => Axis::RightStickX,
+        gilrs::Axis::RightStickY => Axis::RightStickY,
+        gilrs::Axis::LeftZ => Axis::LeftTrigger,
+        gilrs::Axis::RightZ => Axis::RightTrigger,
+        gilrs::Axis::DPadX => Axis::DPadX,
+        gilrs::Axis::DPadY => Axis::DPadY,
+        _ => Axis::Other,
+    }
+}
@@ -0,0 +1,264 @@
+//! Show the performance of an application on top of it.
+use crate::core::alignment;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Shaping};
+use crate::core::time::{Duration, Instant};
+use crate::core::{Color, Pixels, Point, Rectangle, Shadow, Size};
+use crate::graphics::compositor::Statistics;
+use crate::runtime::Debug;
+
+use std::collections::VecDeque;
+
+/// The amount of frames shown in the frame time graph.
+const FRAMES: usize = 120;
+
+/// The frame time at the top of the frame time graph.
+const GRAPH_CEILING: Duration = Duration::from_millis(50);
+
+/// The frame time needed to reach 60 FPS, marked in the frame time graph.
+const BUDGET: Duration = Duration::from_micros(16_667);
+
+const WIDTH: f32 = 260.0;
+const PADDING: f32 = 10.0;
+const TEXT_SIZE: f32 = 14.0;
+const GRAPH_HEIGHT: f32 = 60.0;
+
+/// An overlay showing the frame rate, a frame time graph, and some
+/// statistics of the frames of an application.
+///
+/// It is toggled by pressing `Ctrl+Shift+P` when the `debug` feature is
+/// enabled. While enabled, the application is redrawn continuously.
+#[derive(Debug, Default)]
+pub(crate) struct Hud {
+    is_enabled: bool,
+    frames: VecDeque<Duration>,
+    last_present: Option<Instant>,
+}
+
+impl Hud {
+    /// Creates a new disabled [`Hud`].
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Hud`] is shown.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Toggles the [`Hud`] if the given [`winit`] event is its key chord.
+    ///
+    /// Returns true if the [`Hud`] was toggled.
+    pub(crate) fn update(
+        &mut self,
+        event: &winit::event::WindowEvent<'_>,
+        modifiers: winit::event::ModifiersState,
+    ) -> bool {
+        use winit::event::{
+            ElementState, KeyboardInput, VirtualKeyCode, WindowEvent,
+        };
+
+        if !cfg!(feature = "debug") {
+            return false;
+        }
+
+        match event {
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        virtual_keycode: Some(VirtualKeyCode::P),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } if modifiers.ctrl() && modifiers.shift() => {
+                self.is_enabled = !self.is_enabled;
+                self.frames.clear();
+                self.last_present = None;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Records that a frame was presented, measuring the time since the
+    /// previous one.
+    pub(crate) fn frame_presented(&mut self) {
+        if !self.is_enabled {
+            return;
+        }
+
+        let now = Instant::now();
+
+        if let Some(last_present) = self.last_present.replace(now) {
+            self.frames.push_back(now - last_present);
+
+            if self.frames.len() > FRAMES {
+                let _ = self.frames.pop_front();
+            }
+        }
+    }
+
+    /// Draws the [`Hud`] on top of everything else, in the top right corner
+    /// of a viewport of the given [`Size`].
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        statistics: Statistics,
+        debug: &Debug,
+        viewport: Size,
+    ) where
+        Renderer: text::Renderer,
+    {
+        if !self.is_enabled {
+            return;
+        }
+
+        let frame_time = self.frames.iter().sum::<Duration>()
+            / self.frames.len().max(1) as u32;
+
+        let fps = if frame_time.is_zero() {
+            0.0
+        } else {
+            1.0 / frame_time.as_secs_f32()
+        };
+
+        let lines = [
+            format!("{fps:.0} FPS ({:.2} ms)", milliseconds(frame_time)),
+            format!("Layout: {:.2} ms", milliseconds(debug.layout_duration())),
+            format!("Quads: {}  Texts: {}", statistics.quads, statistics.texts),
+            format!(
+                "Meshes: {}  Images: {}",
+                statistics.meshes, statistics.images
+            ),
+            match statistics.atlas_occupancy {
+                Some(occupancy) => format!("Atlas: {:.1}%", occupancy * 100.0),
+                None => String::from("Atlas: none"),
+            },
+            match statistics.image_cache {
+                Some(cache) => format!(
+                    "Image cache: {} ({:.1} MB)",
+                    cache.images,
+                    megabytes(cache.bytes)
+                ),
+                None => String::from("Image cache: none"),
+            },
+        ];
+
+        let line_height =
+            LineHeight::default().to_absolute(Pixels(TEXT_SIZE)).0;
+
+        let text_height = line_height * lines.len() as f32;
+
+        let bounds = Rectangle {
+            x: (viewport.width - WIDTH - PADDING).max(0.0),
+            y: PADDING,
+            width: WIDTH,
+            height: PADDING * 3.0 + text_height + GRAPH_HEIGHT,
+        };
+
+        renderer.with_layer(Rectangle::with_size(viewport), |renderer| {
+            fill(renderer, bounds, Color::from_rgba(0.0, 0.0, 0.0, 0.8));
+
+            for (i, line) in lines.iter().enumerate() {
+                renderer.fill_text(
+                    text::Text {
+                        content: line,
+                        bounds: Size::new(
+                            bounds.width - PADDING * 2.0,
+                            line_height,
+                        ),
+                        size: Pixels(TEXT_SIZE),
+                        line_height: LineHeight::default(),
+                        font: renderer.default_font(),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: Shaping::Basic,
+                    },
+                    Point::new(
+                        bounds.x + PADDING,
+                        bounds.y + PADDING + line_height * i as f32,
+                    ),
+                    Color::WHITE,
+                );
+            }
+
+            let graph = Rectangle {
+                x: bounds.x + PADDING,
+                y: bounds.y + PADDING * 2.0 + text_height,
+                width: bounds.width - PADDING * 2.0,
+                height: GRAPH_HEIGHT,
+            };
+
+            fill(renderer, graph, Color::from_rgba(1.0, 1.0, 1.0, 0.1));
+
+            let bar_width = graph.width / FRAMES as f32;
+
+            // The latest frame is always at the right end of the graph
+            let first = FRAMES - self.frames.len();
+
+            for (i, frame) in self.frames.iter().enumerate() {
+                let height = graph.height * height_ratio(*frame);
+
+                let color = if *frame <= BUDGET {
+                    Color::from_rgb(0.3, 0.9, 0.4)
+                } else if *frame <= BUDGET * 2 {
+                    Color::from_rgb(1.0, 0.8, 0.2)
+                } else {
+                    Color::from_rgb(1.0, 0.3, 0.3)
+                };
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: graph.x + bar_width * (first + i) as f32,
+                        y: graph.y + graph.height - height,
+                        width: bar_width,
+                        height,
+                    },
+                    color,
+                );
+            }
+
+            fill(
+                renderer,
+                Rectangle {
+                    x: graph.x,
+                    y: graph.y + graph.height * (1.0 - height_ratio(BUDGET)),
+                    width: graph.width,
+                    height: 1.0,
+                },
+                Color::from_rgba(1.0, 1.0, 1.0, 0.5),
+            );
+        });
+    }
+}
+
+fn fill<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+        },
+        color,
+    );
+}
+
+fn height_ratio(frame: Duration) -> f32 {
+    (frame.as_secs_f32() / GRAPH_CEILING.as_secs_f32()).min(1.0)
+}
+
+fn milliseconds(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+fn megabytes(bytes: usize) -> f32 {
+    bytes as f32 / (1024.0 * 1024.0)
+}
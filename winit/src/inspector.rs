@@ -0,0 +1,363 @@
+//! Inspect the widgets of an application, like the devtools of a browser.
+use crate::core::accessibility;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::Id;
+use crate::core::{Color, Layout, Point, Rectangle, Shadow, Vector};
+use crate::runtime::UserInterface;
+
+/// The maximum amount of lines of the widget tree shown at once.
+const MAX_TREE_LINES: usize = 30;
+
+/// An overlay showing the widget tree of an application and the details of
+/// the hovered widget.
+///
+/// It is toggled by pressing `Ctrl+Shift+I` when the `debug` feature is
+/// enabled.
+#[derive(Debug, Default)]
+pub(crate) struct Inspector {
+    is_enabled: bool,
+    lines: Vec<String>,
+}
+
+impl Inspector {
+    /// Creates a new disabled [`Inspector`].
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Toggles the [`Inspector`] if the given [`winit`] event is its key
+    /// chord.
+    ///
+    /// Returns true if the [`Inspector`] was toggled.
+    pub(crate) fn update(
+        &mut self,
+        event: &winit::event::WindowEvent<'_>,
+        modifiers: winit::event::ModifiersState,
+    ) -> bool {
+        use winit::event::{
+            ElementState, KeyboardInput, VirtualKeyCode, WindowEvent,
+        };
+
+        if !cfg!(feature = "debug") {
+            return false;
+        }
+
+        match event {
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        virtual_keycode: Some(VirtualKeyCode::I),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } if modifiers.ctrl() && modifiers.shift() => {
+                self.is_enabled = !self.is_enabled;
+                self.lines.clear();
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Draws the bounds and padding of the hovered widget on top of the
+    /// [`UserInterface`], describing it in the lines of the [`Inspector`].
+    pub(crate) fn draw<Message, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        background_color: Color,
+        cursor: mouse::Cursor,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        if !self.is_enabled {
+            return;
+        }
+
+        let mut describe = Describe::default();
+        user_interface.operate(renderer, &mut describe);
+
+        let layout = user_interface.layout();
+        let hovered = cursor.position().and_then(|position| {
+            let mut path = Vec::new();
+
+            hovered_path(layout, position, &mut path).then_some(path)
+        });
+
+        self.lines.clear();
+        self.lines.push(String::from("Inspector (Ctrl+Shift+I)"));
+
+        let mut tree = Vec::new();
+        write_tree(layout, &describe, hovered.as_deref(), 0, &mut tree);
+
+        if tree.len() > MAX_TREE_LINES {
+            tree.truncate(MAX_TREE_LINES);
+            tree.push(String::from("..."));
+        }
+
+        self.lines.extend(tree);
+
+        let Some(hovered) = hovered.and_then(|path| {
+            path.iter()
+                .try_fold(layout, |layout, &index| layout.children().nth(index))
+        }) else {
+            return;
+        };
+
+        let bounds = hovered.bounds();
+
+        self.lines.push(String::new());
+        self.lines
+            .push(format!("Hovered: {}", describe.label(bounds)));
+        self.lines.push(format!(
+            "Bounds: {}, {} ({} x {})",
+            bounds.x, bounds.y, bounds.width, bounds.height
+        ));
+
+        let content =
+            hovered
+                .children()
+                .map(|child| child.bounds())
+                .reduce(|a, b| {
+                    let x = a.x.min(b.x);
+                    let y = a.y.min(b.y);
+
+                    Rectangle {
+                        x,
+                        y,
+                        width: (a.x + a.width).max(b.x + b.width) - x,
+                        height: (a.y + a.height).max(b.y + b.height) - y,
+                    }
+                });
+
+        if let Some(content) = content {
+            self.lines.push(format!(
+                "Padding: {} {} {} {}",
+                content.y - bounds.y,
+                bounds.x + bounds.width - content.x - content.width,
+                bounds.y + bounds.height - content.y - content.height,
+                content.x - bounds.x,
+            ));
+        }
+
+        self.lines.push(format!(
+            "Style: text {}, background {}",
+            hex(style.text_color),
+            hex(background_color)
+        ));
+
+        if let Some(node) = describe.node(bounds) {
+            self.lines.push(format!(
+                "State: focused {}, disabled {}",
+                node.is_focused, node.is_disabled
+            ));
+
+            if let Some(value) = &node.value {
+                self.lines.push(format!("Value: {value}"));
+            }
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            let highlight = Color::from_rgba(0.2, 0.5, 1.0, 0.25);
+
+            // The padding is tinted differently from the content
+            if let Some(content) = content {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    },
+                    Color::from_rgba(0.3, 0.8, 0.4, 0.25),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: content,
+                        border_radius: 0.0.into(),
+                        border_width: 1.0,
+                        border_color: highlight,
+                        shadow: Shadow::default(),
+                    },
+                    highlight,
+                );
+            } else {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 1.0,
+                        border_color: highlight,
+                        shadow: Shadow::default(),
+                    },
+                    highlight,
+                );
+            }
+        });
+    }
+
+    /// Returns the lines of the [`Inspector`] to be shown in the overlay of
+    /// the window, after the given ones.
+    pub(crate) fn overlay(&self, mut lines: Vec<String>) -> Vec<String> {
+        if self.is_enabled {
+            lines.extend(self.lines.iter().cloned());
+        }
+
+        lines
+    }
+}
+
+/// An [`Operation`] that describes the widgets with known bounds.
+#[derive(Default)]
+struct Describe {
+    widgets: Vec<(Rectangle, String, Option<accessibility::Node>)>,
+}
+
+impl Describe {
+    fn label(&self, bounds: Rectangle) -> &str {
+        self.widgets
+            .iter()
+            .find(|(other, _, _)| *other == bounds)
+            .map_or("widget", |(_, label, _)| label)
+    }
+
+    fn node(&self, bounds: Rectangle) -> Option<&accessibility::Node> {
+        self.widgets
+            .iter()
+            .filter(|(other, _, _)| *other == bounds)
+            .find_map(|(_, _, node)| node.as_ref())
+    }
+}
+
+impl<T> Operation<T> for Describe {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.widgets
+            .push((bounds, with_name("container", id), None));
+
+        operate_on_children(self);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let role = format!("{:?}", node.role).to_lowercase();
+
+        let label = match &node.label {
+            Some(label) => format!("{} \"{label}\"", with_name(&role, id)),
+            None => with_name(&role, id),
+        };
+
+        // Accessible widgets are more specific than plain containers
+        self.widgets.insert(0, (bounds, label, Some(node.clone())));
+
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.widgets.insert(
+            0,
+            (
+                bounds,
+                format!(
+                    "{} at {}, {}",
+                    with_name("scrollable", id),
+                    translation.x,
+                    translation.y
+                ),
+                None,
+            ),
+        );
+    }
+}
+
+fn with_name(kind: &str, id: Option<&Id>) -> String {
+    match id.and_then(Id::name) {
+        Some(name) => format!("{kind} #{name}"),
+        None => kind.to_owned(),
+    }
+}
+
+/// Finds the path of child indices leading to the deepest layout node under
+/// the given position.
+fn hovered_path(
+    layout: Layout<'_>,
+    position: Point,
+    path: &mut Vec<usize>,
+) -> bool {
+    if !layout.bounds().contains(position) {
+        return false;
+    }
+
+    for (index, child) in layout.children().enumerate() {
+        path.push(index);
+
+        if hovered_path(child, position, path) {
+            return true;
+        }
+
+        let _ = path.pop();
+    }
+
+    true
+}
+
+fn write_tree(
+    layout: Layout<'_>,
+    describe: &Describe,
+    hovered: Option<&[usize]>,
+    depth: usize,
+    lines: &mut Vec<String>,
+) {
+    let bounds = layout.bounds();
+    let marker = if hovered.is_some_and(<[usize]>::is_empty) {
+        ">"
+    } else {
+        " "
+    };
+
+    lines.push(format!(
+        "{marker}{:indent$}{} ({} x {})",
+        "",
+        describe.label(bounds),
+        bounds.width,
+        bounds.height,
+        indent = depth * 2,
+    ));
+
+    for (index, child) in layout.children().enumerate() {
+        let hovered = hovered
+            .and_then(|path| path.split_first())
+            .filter(|(first, _)| **first == index)
+            .map(|(_, rest)| rest);
+
+        write_tree(child, describe, hovered, depth + 1, lines);
+    }
+}
+
+fn hex(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+}
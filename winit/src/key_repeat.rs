@@ -0,0 +1,159 @@
+//! Detect and configure the repetition of held keys, which winit lacks.
+use crate::core::keyboard::{self, Repeat};
+use crate::core::time::Instant;
+use crate::core::{window, Event};
+
+use std::collections::HashSet;
+
+/// The keys held down and how they repeat.
+#[derive(Debug)]
+pub(crate) struct KeyRepeat {
+    repeat: Repeat,
+    pressed: HashSet<u32>,
+    held: Option<Held>,
+    is_suppressing_characters: bool,
+}
+
+/// The last key pressed, repeated by [`Repeat::Custom`].
+#[derive(Debug)]
+struct Held {
+    scancode: u32,
+    event: keyboard::Event,
+    character: Option<char>,
+    next: Instant,
+}
+
+impl KeyRepeat {
+    /// Creates a new [`KeyRepeat`] with the given [`Repeat`] setting.
+    pub(crate) fn new(repeat: Repeat) -> Self {
+        Self {
+            repeat,
+            pressed: HashSet::new(),
+            held: None,
+            is_suppressing_characters: false,
+        }
+    }
+
+    /// Processes the given [`Event`], marking, dropping, or replacing the
+    /// repeated key presses as configured.
+    pub(crate) fn process(
+        &mut self,
+        event: Event,
+        now: Instant,
+        events: &mut Vec<Event>,
+    ) {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+                scancode: Some(scancode),
+                ..
+            }) => {
+                let is_repeat = !self.pressed.insert(scancode);
+
+                let event = keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                    scancode: Some(scancode),
+                    is_repeat,
+                };
+
+                if is_repeat && self.repeat != Repeat::System {
+                    // The characters of a dropped repeat must go, too
+                    self.is_suppressing_characters = true;
+                    return;
+                }
+
+                self.is_suppressing_characters = false;
+
+                if let Repeat::Custom { delay, .. } = self.repeat {
+                    self.held = Some(Held {
+                        scancode,
+                        event: keyboard::Event::KeyPressed {
+                            key_code,
+                            modifiers,
+                            scancode: Some(scancode),
+                            is_repeat: true,
+                        },
+                        character: None,
+                        next: now + delay,
+                    });
+                }
+
+                events.push(Event::Keyboard(event));
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+                if self.is_suppressing_characters {
+                    return;
+                }
+
+                if let Some(held) = &mut self.held {
+                    if held.character.is_none() {
+                        held.character = Some(c);
+                    }
+                }
+
+                events.push(event);
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                scancode: Some(scancode),
+                ..
+            }) => {
+                let _ = self.pressed.remove(&scancode);
+
+                if self
+                    .held
+                    .as_ref()
+                    .is_some_and(|held| held.scancode == scancode)
+                {
+                    self.held = None;
+                }
+
+                self.is_suppressing_characters = false;
+
+                events.push(event);
+            }
+            Event::Window(window::Event::Unfocused) => {
+                // Releases are not reported to unfocused windows
+                self.pressed.clear();
+                self.held = None;
+
+                events.push(event);
+            }
+            _ => {
+                events.push(event);
+            }
+        }
+    }
+
+    /// Produces the key presses repeated by [`Repeat::Custom`] until the
+    /// given time.
+    pub(crate) fn tick(&mut self, now: Instant, events: &mut Vec<Event>) {
+        let Repeat::Custom { interval, .. } = self.repeat else {
+            return;
+        };
+
+        let Some(held) = &mut self.held else {
+            return;
+        };
+
+        if now < held.next {
+            return;
+        }
+
+        events.push(Event::Keyboard(held.event));
+
+        if let Some(c) = held.character {
+            events.push(Event::Keyboard(keyboard::Event::CharacterReceived(c)));
+        }
+
+        // Repeats missed while the event loop was busy are skipped
+        held.next = now + interval;
+    }
+
+    /// Returns the time of the next key press repeated by
+    /// [`Repeat::Custom`], if any.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.held.as_ref().map(|held| held.next)
+    }
+}
@@ -37,20 +37,44 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod embed;
 pub mod settings;
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
+#[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+pub mod gamepad;
+#[cfg(any(feature = "menu", feature = "tray"))]
+pub mod menu;
 #[cfg(feature = "system")]
 pub mod system;
+#[cfg(feature = "tray")]
+pub mod tray;
+#[cfg(feature = "webview")]
+pub mod webview;
 
+#[cfg(feature = "dnd")]
+mod dnd;
 mod error;
+mod fallback;
+mod file_drop;
+mod hud;
+mod inspector;
+mod key_repeat;
+#[cfg(feature = "notification")]
+mod notification;
+mod placement;
 mod position;
 mod proxy;
+mod taskbar;
+mod zoom;
 
 #[cfg(feature = "application")]
 pub use application::Application;
 #[cfg(feature = "trace")]
 pub use application::Profiler;
 pub use clipboard::Clipboard;
+pub use embed::Embedded;
 pub use error::Error;
 pub use position::Position;
 pub use proxy::Proxy;
@@ -0,0 +1,359 @@
+//! Build native menus with [`muda`].
+//!
+//! The menu bar of an application is supported on macOS, where it becomes
+//! the menu bar of the app, and on Windows, where it is attached to the
+//! window. Other platforms ignore it.
+use crate::runtime::menu::{self, Menu};
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Sends the messages of the activated native menu items to the event loop.
+///
+/// There must be a single [`Handler`] per event loop; it is shared by all
+/// the native menus of the application.
+#[allow(missing_debug_implementations)]
+pub struct Handler<Message: 'static> {
+    menus: Arc<Mutex<Menus<Message>>>,
+}
+
+struct Menus<Message: 'static> {
+    proxy: winit::event_loop::EventLoopProxy<Message>,
+    messages: HashMap<&'static str, Vec<Option<Message>>>,
+}
+
+impl<Message> Handler<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    /// Creates a new [`Handler`] that sends messages through the given
+    /// event loop proxy.
+    pub fn new(proxy: winit::event_loop::EventLoopProxy<Message>) -> Self {
+        let menus = Arc::new(Mutex::new(Menus {
+            proxy,
+            messages: HashMap::new(),
+        }));
+
+        muda::MenuEvent::set_event_handler(Some({
+            let menus = menus.clone();
+
+            move |event: muda::MenuEvent| {
+                let menus = menus.lock().expect("Lock menu messages");
+
+                let message =
+                    event.id.0.split_once('/').and_then(|(source, index)| {
+                        let index = index.parse::<usize>().ok()?;
+
+                        menus.messages.get(source)?.get(index)?.clone()
+                    });
+
+                if let Some(message) = message {
+                    // The event loop may be gone already
+                    let _ = menus.proxy.send_event(message);
+                }
+            }
+        }));
+
+        Self { menus }
+    }
+
+    /// Replaces the messages of the native menu built from the given
+    /// source.
+    pub(crate) fn set(
+        &self,
+        source: &'static str,
+        menu: Option<&Menu<Message>>,
+    ) {
+        let mut menus = self.menus.lock().expect("Lock menu messages");
+
+        match menu {
+            Some(menu) => {
+                let messages = menus.messages.entry(source).or_default();
+
+                messages.clear();
+                collect(menu, messages);
+            }
+            None => {
+                let _ = menus.messages.remove(source);
+            }
+        }
+    }
+}
+
+impl<Message> Clone for Handler<Message> {
+    fn clone(&self) -> Self {
+        Self {
+            menus: self.menus.clone(),
+        }
+    }
+}
+
+/// The native menu bar of an application, kept in sync with the [`Menu`]
+/// it returns.
+///
+/// The top-level items of the [`Menu`] should be submenus. On macOS, the
+/// first one becomes the application menu.
+#[cfg(feature = "menu")]
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message: 'static> {
+    native: Option<Native>,
+    handler: Handler<Message>,
+}
+
+#[cfg(feature = "menu")]
+impl<Message> MenuBar<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    const SOURCE: &'static str = "bar";
+
+    /// Creates a new, empty [`MenuBar`].
+    pub fn new(handler: Handler<Message>) -> Self {
+        Self {
+            native: None,
+            handler,
+        }
+    }
+
+    /// Builds, updates, or removes the native menu bar to match the given
+    /// [`Menu`].
+    pub fn synchronize(
+        &mut self,
+        window: &winit::window::Window,
+        menu: Option<Menu<Message>>,
+    ) {
+        self.handler.set(Self::SOURCE, menu.as_ref());
+
+        if let (Some(native), Some(menu)) = (&self.native, &menu) {
+            if native.update(menu) {
+                return;
+            }
+        }
+
+        if let Some(native) = self.native.take() {
+            detach(window, &native.menu);
+        }
+
+        if let Some(menu) = &menu {
+            let native = Native::build(menu, Self::SOURCE);
+
+            attach(window, &native.menu);
+
+            self.native = Some(native);
+        }
+    }
+}
+
+#[cfg(all(feature = "menu", target_os = "macos"))]
+fn attach(_window: &winit::window::Window, menu: &muda::Menu) {
+    menu.init_for_nsapp();
+}
+
+#[cfg(all(feature = "menu", target_os = "macos"))]
+fn detach(_window: &winit::window::Window, menu: &muda::Menu) {
+    menu.remove_for_nsapp();
+}
+
+#[cfg(all(feature = "menu", target_os = "windows"))]
+fn attach(window: &winit::window::Window, menu: &muda::Menu) {
+    use winit::platform::windows::WindowExtWindows;
+
+    if let Err(error) = menu.init_for_hwnd(window.hwnd()) {
+        log::warn!("Failed to attach menu bar: {error}");
+    }
+}
+
+#[cfg(all(feature = "menu", target_os = "windows"))]
+fn detach(window: &winit::window::Window, menu: &muda::Menu) {
+    use winit::platform::windows::WindowExtWindows;
+
+    if let Err(error) = menu.remove_for_hwnd(window.hwnd()) {
+        log::warn!("Failed to detach menu bar: {error}");
+    }
+}
+
+#[cfg(all(
+    feature = "menu",
+    not(any(target_os = "macos", target_os = "windows"))
+))]
+fn attach(_window: &winit::window::Window, _menu: &muda::Menu) {}
+
+#[cfg(all(
+    feature = "menu",
+    not(any(target_os = "macos", target_os = "windows"))
+))]
+fn detach(_window: &winit::window::Window, _menu: &muda::Menu) {}
+
+/// The native counterpart of a [`Menu`].
+pub(crate) struct Native {
+    pub(crate) menu: muda::Menu,
+    shape: Vec<Entry>,
+    actions: Vec<NativeAction>,
+}
+
+/// The structure of a [`Menu`]; the native menu must be rebuilt when it
+/// changes.
+#[derive(PartialEq)]
+enum Entry {
+    Action { label: String, is_checkable: bool },
+    Separator,
+    Submenu(String, Vec<Entry>),
+}
+
+enum NativeAction {
+    Item(muda::MenuItem),
+    Check(muda::CheckMenuItem),
+}
+
+impl Native {
+    /// Builds the native menu of the given [`Menu`].
+    ///
+    /// The id of every action is its index in the order of [`collect`],
+    /// prefixed by the given source.
+    pub(crate) fn build<Message>(
+        menu: &Menu<Message>,
+        source: &'static str,
+    ) -> Self {
+        let native = muda::Menu::new();
+        let mut actions = Vec::new();
+
+        append(menu, source, &mut actions, &mut |item| {
+            if let Err(error) = native.append(item) {
+                log::warn!("Failed to add menu item: {error}");
+            }
+        });
+
+        Self {
+            menu: native,
+            shape: entries(menu),
+            actions,
+        }
+    }
+
+    /// Updates the state of the actions of the native menu to match the
+    /// given [`Menu`].
+    ///
+    /// Returns false if the structure of the [`Menu`] changed, in which case
+    /// the native menu must be rebuilt.
+    pub(crate) fn update<Message>(&self, menu: &Menu<Message>) -> bool {
+        if self.shape != entries(menu) {
+            return false;
+        }
+
+        let mut actions = Vec::new();
+        flatten(menu, &mut actions);
+
+        for (native, action) in self.actions.iter().zip(actions) {
+            match native {
+                NativeAction::Item(item) => {
+                    item.set_enabled(action.on_activate.is_some());
+                }
+                NativeAction::Check(item) => {
+                    item.set_enabled(action.on_activate.is_some());
+                    item.set_checked(action.is_checked.unwrap_or(false));
+                }
+            }
+        }
+
+        true
+    }
+}
+
+fn entries<Message>(menu: &Menu<Message>) -> Vec<Entry> {
+    menu.items()
+        .iter()
+        .map(|item| match item {
+            menu::Item::Action(action) => Entry::Action {
+                label: action.label.clone(),
+                is_checkable: action.is_checked.is_some(),
+            },
+            menu::Item::Separator => Entry::Separator,
+            menu::Item::Submenu { label, menu } => {
+                Entry::Submenu(label.clone(), entries(menu))
+            }
+        })
+        .collect()
+}
+
+fn flatten<'a, Message>(
+    menu: &'a Menu<Message>,
+    actions: &mut Vec<&'a menu::Action<Message>>,
+) {
+    for item in menu.items() {
+        match item {
+            menu::Item::Action(action) => actions.push(action),
+            menu::Item::Separator => {}
+            menu::Item::Submenu { menu, .. } => flatten(menu, actions),
+        }
+    }
+}
+
+/// Collects the messages of the actions of the given [`Menu`], in the
+/// order of their ids.
+fn collect<Message: Clone>(
+    menu: &Menu<Message>,
+    messages: &mut Vec<Option<Message>>,
+) {
+    let mut actions = Vec::new();
+    flatten(menu, &mut actions);
+
+    messages
+        .extend(actions.into_iter().map(|action| action.on_activate.clone()));
+}
+
+fn append<Message>(
+    menu: &Menu<Message>,
+    source: &'static str,
+    actions: &mut Vec<NativeAction>,
+    push: &mut dyn FnMut(&dyn muda::IsMenuItem),
+) {
+    for item in menu.items() {
+        match item {
+            menu::Item::Action(action) => {
+                let id =
+                    muda::MenuId::new(format!("{source}/{}", actions.len()));
+                let is_enabled = action.on_activate.is_some();
+
+                match action.is_checked {
+                    Some(is_checked) => {
+                        let item = muda::CheckMenuItem::with_id(
+                            id,
+                            &action.label,
+                            is_enabled,
+                            is_checked,
+                            None,
+                        );
+
+                        push(&item);
+                        actions.push(NativeAction::Check(item));
+                    }
+                    None => {
+                        let item = muda::MenuItem::with_id(
+                            id,
+                            &action.label,
+                            is_enabled,
+                            None,
+                        );
+
+                        push(&item);
+                        actions.push(NativeAction::Item(item));
+                    }
+                }
+            }
+            menu::Item::Separator => {
+                push(&muda::PredefinedMenuItem::separator());
+            }
+            menu::Item::Submenu { label, menu } => {
+                let submenu = muda::Submenu::new(label, true);
+
+                append(menu, source, actions, &mut |item| {
+                    if let Err(error) = submenu.append(item) {
+                        log::warn!("Failed to add menu item: {error}");
+                    }
+                });
+
+                push(&submenu);
+            }
+        }
+    }
+}
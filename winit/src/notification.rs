@@ -0,0 +1,54 @@
+//! Show desktop notifications with [`notify_rust`].
+//!
+//! Clicks on notifications are only reported on Linux and BSD, through the
+//! desktop notification specification.
+use crate::runtime::notification::Notification;
+
+/// Shows the given [`Notification`], sending its click message through the
+/// event loop proxy if it is clicked.
+pub(crate) fn show<Message>(
+    notification: Notification<Message>,
+    proxy: winit::event_loop::EventLoopProxy<Message>,
+) where
+    Message: Send + 'static,
+{
+    // Showing a notification may block, and waiting for a click does
+    let _ = std::thread::spawn(move || {
+        let mut native = notify_rust::Notification::new();
+
+        let _ = native.summary(&notification.title).body(&notification.body);
+
+        if let Some(icon) = &notification.icon {
+            let _ = native.icon(icon);
+        }
+
+        if notification.on_click.is_some() {
+            let _ = native.action("default", "");
+        }
+
+        let handle = match native.show() {
+            Ok(handle) => handle,
+            Err(error) => {
+                log::warn!("Failed to show notification: {error}");
+                return;
+            }
+        };
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        if let Some(message) = notification.on_click {
+            let mut message = Some(message);
+
+            handle.wait_for_action(|action| {
+                if action == "default" {
+                    if let Some(message) = message.take() {
+                        // The event loop may be gone already
+                        let _ = proxy.send_event(message);
+                    }
+                }
+            });
+        }
+
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        let _ = (handle, proxy);
+    });
+}
@@ -0,0 +1,123 @@
+//! Track the placement of a window to produce the events winit lacks.
+use crate::conversion;
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+use crate::core::Event;
+
+/// The time without resizes after which a resize is considered finished.
+const RESIZE_END_DELAY: Duration = Duration::from_millis(200);
+
+/// The last known placement of a window.
+#[derive(Debug)]
+pub(crate) struct Placement {
+    is_maximized: bool,
+    is_minimized: bool,
+    mode: window::Mode,
+    monitor: Option<Option<String>>,
+    resize: Option<(Instant, window::Event)>,
+}
+
+impl Placement {
+    /// Creates a new [`Placement`] from the current state of the window.
+    pub(crate) fn new(window: &winit::window::Window) -> Self {
+        Self {
+            is_maximized: window.is_maximized(),
+            is_minimized: window.is_minimized().unwrap_or(false),
+            mode: conversion::mode(window.fullscreen()),
+            monitor: window.current_monitor().map(|monitor| monitor.name()),
+            resize: None,
+        }
+    }
+
+    /// Updates the [`Placement`] after the given [`winit`] event, producing
+    /// the [`Event`]s describing how it changed.
+    pub(crate) fn update(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent<'_>,
+        scale_factor: f64,
+        events: &mut Vec<Event>,
+    ) {
+        use winit::event::WindowEvent;
+
+        match event {
+            WindowEvent::Resized(new_size) => {
+                let logical_size = new_size.to_logical(scale_factor);
+
+                self.resize = Some((
+                    Instant::now(),
+                    window::Event::ResizeEnded {
+                        width: logical_size.width,
+                        height: logical_size.height,
+                    },
+                ));
+            }
+            WindowEvent::Moved(_)
+            | WindowEvent::ScaleFactorChanged { .. }
+            | WindowEvent::Focused(_) => {}
+            _ => return,
+        }
+
+        // Exclusive fullscreen may be left when the window loses focus
+        let mode = conversion::mode(window.fullscreen());
+
+        if mode != self.mode {
+            events.push(Event::Window(window::Event::ModeChanged(mode)));
+
+            self.mode = mode;
+        }
+
+        let is_maximized = window.is_maximized();
+        let is_minimized = window.is_minimized().unwrap_or(self.is_minimized);
+
+        if (is_maximized, is_minimized)
+            != (self.is_maximized, self.is_minimized)
+        {
+            events.push(Event::Window(if is_minimized {
+                window::Event::Minimized
+            } else if is_maximized {
+                window::Event::Maximized
+            } else {
+                window::Event::Restored
+            }));
+
+            self.is_maximized = is_maximized;
+            self.is_minimized = is_minimized;
+        }
+
+        // Minimized windows may report no monitor at all
+        if let Some(monitor) = window.current_monitor() {
+            let name = monitor.name();
+
+            if self.monitor.as_ref() != Some(&name) {
+                if self.monitor.is_some() {
+                    events.push(Event::Window(window::Event::MonitorChanged {
+                        name: name.clone(),
+                    }));
+                }
+
+                self.monitor = Some(name);
+            }
+        }
+    }
+
+    /// Returns the [`window::Event::ResizeEnded`] of the last resize, if it
+    /// finished by the given [`Instant`].
+    pub(crate) fn resize_ended(
+        &mut self,
+        now: Instant,
+    ) -> Option<window::Event> {
+        match &self.resize {
+            Some((at, _)) if now >= *at + RESIZE_END_DELAY => {
+                self.resize.take().map(|(_, event)| event)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Instant`] at which the current resize will be
+    /// considered finished, if the window is being resized.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.resize.as_ref().map(|(at, _)| *at + RESIZE_END_DELAY)
+    }
+}
@@ -27,7 +27,10 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
+use crate::core::keyboard;
+use crate::core::time::Duration;
 use crate::core::window::{Icon, Level};
+use crate::futures::executor;
 use crate::Position;
 
 use winit::monitor::MonitorHandle;
@@ -57,6 +60,29 @@ pub struct Settings<Flags> {
     ///
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
+
+    /// How the keys held down by the user repeat.
+    pub key_repeat: keyboard::Repeat,
+
+    /// How long it takes to fade between themes when the theme of the
+    /// [`Application`] changes.
+    ///
+    /// A zero [`Duration`] switches themes immediately.
+    ///
+    /// [`Application`]: crate::Application
+    pub theme_transition: Duration,
+
+    /// Whether the user can zoom the user interface with `Ctrl` and `+`,
+    /// `-`, `0`, or the mouse wheel.
+    ///
+    /// The zoom is applied on top of the scale factor of the window.
+    pub zoom_shortcuts: bool,
+
+    /// The configuration of the executor running the futures of the
+    /// [`Application`].
+    ///
+    /// [`Application`]: crate::Application
+    pub executor: executor::Settings,
 }
 
 /// The window settings of an application.
@@ -179,7 +205,8 @@ impl Window {
                     .with_parent_window(self.platform_specific.parent);
             }
             window_builder = window_builder
-                .with_drag_and_drop(self.platform_specific.drag_and_drop);
+                .with_drag_and_drop(self.platform_specific.drag_and_drop)
+                .with_skip_taskbar(self.platform_specific.skip_taskbar);
         }
 
         #[cfg(target_os = "macos")]
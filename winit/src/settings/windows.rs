@@ -9,6 +9,9 @@ pub struct PlatformSpecific {
 
     /// Drag and drop support
     pub drag_and_drop: bool,
+
+    /// Whether the window is left out of the taskbar.
+    pub skip_taskbar: bool,
 }
 
 impl Default for PlatformSpecific {
@@ -16,6 +19,7 @@ impl Default for PlatformSpecific {
         Self {
             parent: None,
             drag_and_drop: true,
+            skip_taskbar: false,
         }
     }
 }
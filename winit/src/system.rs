@@ -1,4 +1,10 @@
 //! Access the native system.
+mod appearance;
+mod power;
+
+pub use appearance::appearance;
+pub use power::events;
+
 use crate::graphics::compositor;
 use crate::runtime::command::{self, Command};
 use crate::runtime::system::{Action, Information};
@@ -0,0 +1,330 @@
+//! Follow the appearance preferred by the user.
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::future;
+use crate::futures::futures::{SinkExt, StreamExt};
+use crate::futures::subscription::{self, Subscription};
+use crate::runtime::system::Appearance;
+
+/// Subscribes to the [`Appearance`] preferred by the user.
+///
+/// The current [`Appearance`] is produced right away, and then every time
+/// it changes. On Linux, it is read from the XDG desktop portal; on Windows
+/// and macOS, from the user settings.
+pub fn appearance() -> Subscription<Appearance> {
+    struct Appearances;
+
+    subscription::channel(
+        std::any::TypeId::of::<Appearances>(),
+        10,
+        |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            let forward = async move {
+                loop {
+                    match receiver.next().await {
+                        Some(appearance) => {
+                            let _ = output.send(appearance).await;
+                        }
+                        None => {
+                            // The appearance is unavailable
+                            future::pending::<()>().await;
+                        }
+                    }
+                }
+            };
+
+            let _ = future::join(platform::listen(sender), forward).await;
+
+            unreachable!("appearances are forwarded forever")
+        },
+    )
+}
+
+/// Reads the [`Appearance`] with the given function every few seconds,
+/// sending it whenever it changes, until the subscription is dropped.
+///
+/// The function may return `None` when the settings of the user are known
+/// to be unchanged.
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+fn poll(
+    sender: mpsc::UnboundedSender<Appearance>,
+    mut read: impl FnMut() -> Option<Appearance> + Send + 'static,
+) {
+    use std::time::Duration;
+
+    const INTERVAL: Duration = Duration::from_secs(2);
+
+    let _ = std::thread::spawn(move || {
+        let mut last = None;
+
+        while !sender.is_closed() {
+            if let Some(appearance) = read() {
+                if last != Some(appearance) {
+                    if sender.unbounded_send(appearance).is_err() {
+                        return;
+                    }
+
+                    last = Some(appearance);
+                }
+            }
+
+            std::thread::sleep(INTERVAL);
+        }
+    });
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::Appearance;
+    use crate::core::Color;
+    use crate::futures::futures::channel::mpsc;
+    use crate::futures::futures::StreamExt;
+
+    use zbus::zvariant::Value;
+
+    const NAMESPACE: &str = "org.freedesktop.appearance";
+
+    /// Reads the appearance from the settings of the desktop portal, and
+    /// reads it again every time they change, until the subscription is
+    /// dropped.
+    pub async fn listen(sender: mpsc::UnboundedSender<Appearance>) {
+        let result = async {
+            let settings = settings().await?;
+            let mut last = read(&settings).await;
+
+            if sender.unbounded_send(last).is_err() {
+                return Ok(());
+            }
+
+            let mut changes = settings.receive_signal("SettingChanged").await?;
+
+            while changes.next().await.is_some() {
+                let appearance = read(&settings).await;
+
+                if appearance == last {
+                    continue;
+                }
+
+                if sender.unbounded_send(appearance).is_err() {
+                    break;
+                }
+
+                last = appearance;
+            }
+
+            zbus::Result::Ok(())
+        };
+
+        if let Err(error) = result.await {
+            log::warn!("The system appearance is unavailable: {error}");
+        }
+    }
+
+    async fn settings() -> zbus::Result<zbus::Proxy<'static>> {
+        let connection = zbus::Connection::session().await?;
+
+        zbus::Proxy::new(
+            &connection,
+            "org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.Settings",
+        )
+        .await
+    }
+
+    async fn read(settings: &zbus::Proxy<'_>) -> Appearance {
+        let color_scheme = setting(settings, "color-scheme").await;
+        let accent_color = setting(settings, "accent-color").await;
+
+        let is_dark =
+            matches!(color_scheme.as_deref().map(unwrap), Some(Value::U32(1)));
+
+        let accent = accent_color.as_deref().map(unwrap).and_then(|value| {
+            let Value::Structure(structure) = value else {
+                return None;
+            };
+
+            let [Value::F64(r), Value::F64(g), Value::F64(b)] =
+                structure.fields()
+            else {
+                return None;
+            };
+
+            // Out of range values mean the accent color is not set
+            [r, g, b]
+                .iter()
+                .all(|channel| (0.0..=1.0).contains(*channel))
+                .then(|| Color::from_rgb(*r as f32, *g as f32, *b as f32))
+        });
+
+        Appearance { is_dark, accent }
+    }
+
+    async fn setting(
+        settings: &zbus::Proxy<'_>,
+        key: &str,
+    ) -> Option<zbus::zvariant::OwnedValue> {
+        settings.call("Read", &(NAMESPACE, key)).await.ok()
+    }
+
+    /// Older portals wrap the values of the settings in another variant.
+    fn unwrap<'a>(value: &'a Value<'a>) -> &'a Value<'a> {
+        match value {
+            Value::Value(value) => unwrap(value),
+            value => value,
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::Appearance;
+    use crate::core::Color;
+    use crate::futures::futures::channel::mpsc;
+
+    pub async fn listen(sender: mpsc::UnboundedSender<Appearance>) {
+        super::poll(sender, || Some(read()));
+    }
+
+    fn read() -> Appearance {
+        let uses_light_theme = dword(
+            r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "AppsUseLightTheme",
+        );
+
+        // The accent color is stored as 0xAABBGGRR
+        let accent = dword(r"Software\Microsoft\Windows\DWM", "AccentColor")
+            .map(|color| {
+                Color::from_rgb8(
+                    (color & 0xFF) as u8,
+                    ((color >> 8) & 0xFF) as u8,
+                    ((color >> 16) & 0xFF) as u8,
+                )
+            });
+
+        Appearance {
+            is_dark: uses_light_theme == Some(0),
+            accent,
+        }
+    }
+
+    /// Reads a DWORD value of the registry of the current user.
+    #[allow(unsafe_code)]
+    fn dword(key: &str, value: &str) -> Option<u32> {
+        use std::ffi::OsStr;
+        use std::os::windows::ffi::OsStrExt;
+        use std::ptr;
+        use winapi::shared::minwindef::DWORD;
+        use winapi::shared::winerror::ERROR_SUCCESS;
+        use winapi::um::winreg::{
+            RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD,
+        };
+
+        let wide = |string: &str| -> Vec<u16> {
+            OsStr::new(string).encode_wide().chain(Some(0)).collect()
+        };
+
+        let key = wide(key);
+        let value = wide(value);
+
+        let mut data: DWORD = 0;
+        let mut size = std::mem::size_of::<DWORD>() as DWORD;
+
+        let status = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                key.as_ptr(),
+                value.as_ptr(),
+                RRF_RT_REG_DWORD,
+                ptr::null_mut(),
+                &mut data as *mut DWORD as *mut _,
+                &mut size,
+            )
+        };
+
+        (status == ERROR_SUCCESS as i32).then_some(data)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::Appearance;
+    use crate::core::Color;
+    use crate::futures::futures::channel::mpsc;
+
+    use std::path::PathBuf;
+    use std::time::SystemTime;
+
+    /// Reads the appearance again only when the global preferences of the
+    /// user are modified, instead of spawning `defaults` on every poll.
+    pub async fn listen(sender: mpsc::UnboundedSender<Appearance>) {
+        let preferences = std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join("Library/Preferences/.GlobalPreferences.plist")
+        });
+
+        let mut modified_at: Option<SystemTime> = None;
+        let mut is_read = false;
+
+        super::poll(sender, move || {
+            let modified = preferences
+                .as_ref()
+                .and_then(|path| path.metadata().ok()?.modified().ok());
+
+            if is_read && modified.is_some() && modified == modified_at {
+                return None;
+            }
+
+            is_read = true;
+            modified_at = modified;
+
+            Some(read())
+        });
+    }
+
+    fn read() -> Appearance {
+        let is_dark = default("AppleInterfaceStyle").as_deref() == Some("Dark");
+
+        // Blue is the default, and it is not stored
+        let accent = match default("AppleAccentColor").as_deref() {
+            Some("-1") => Color::from_rgb8(0x8E, 0x8E, 0x93),
+            Some("0") => Color::from_rgb8(0xFF, 0x3B, 0x30),
+            Some("1") => Color::from_rgb8(0xFF, 0x95, 0x00),
+            Some("2") => Color::from_rgb8(0xFF, 0xCC, 0x00),
+            Some("3") => Color::from_rgb8(0x28, 0xCD, 0x41),
+            Some("5") => Color::from_rgb8(0xAF, 0x52, 0xDE),
+            Some("6") => Color::from_rgb8(0xFF, 0x2D, 0x55),
+            _ => Color::from_rgb8(0x00, 0x7A, 0xFF),
+        };
+
+        Appearance {
+            is_dark,
+            accent: Some(accent),
+        }
+    }
+
+    /// Reads a global default of the user.
+    fn default(key: &str) -> Option<String> {
+        let output = std::process::Command::new("defaults")
+            .args(["read", "-g", key])
+            .output()
+            .ok()?;
+
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "windows",
+    target_os = "macos"
+)))]
+mod platform {
+    use super::Appearance;
+    use crate::futures::futures::channel::mpsc;
+
+    pub async fn listen(_sender: mpsc::UnboundedSender<Appearance>) {}
+}
@@ -0,0 +1,296 @@
+//! Listen to the power and session events of the system.
+use crate::futures::futures::channel::mpsc;
+use crate::futures::futures::future;
+use crate::futures::futures::{SinkExt, StreamExt};
+use crate::futures::subscription::{self, Subscription};
+use crate::runtime::system::Event;
+
+use std::thread;
+use std::time::Duration;
+
+/// The charge below which a discharging battery is considered low.
+const LOW_BATTERY: f32 = 15.0;
+
+/// How often the battery is checked.
+const BATTERY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Subscribes to the power and session [`Event`]s of the system.
+///
+/// Every platform reports [`Event::BatteryLow`]. [`Event::Suspended`], lid,
+/// and session events are only reported on Linux, through `logind`.
+///
+/// [`Event::Resumed`] is reported on Linux and on platforms whose monotonic
+/// clock keeps running while the system is suspended, like Windows.
+pub fn events() -> Subscription<Event> {
+    struct Events;
+
+    subscription::channel(
+        std::any::TypeId::of::<Events>(),
+        100,
+        |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            battery(sender.clone());
+
+            let forward = async move {
+                loop {
+                    match receiver.next().await {
+                        Some(event) => {
+                            let _ = output.send(event).await;
+                        }
+                        None => {
+                            // Every listener failed; there is nothing to report
+                            future::pending::<()>().await;
+                        }
+                    }
+                }
+            };
+
+            let _ = future::join(platform::listen(sender), forward).await;
+
+            unreachable!("system events are forwarded forever")
+        },
+    )
+}
+
+/// Reports [`Event::BatteryLow`] once every time the battery discharges
+/// below [`LOW_BATTERY`].
+///
+/// The battery is checked until the subscription is dropped.
+fn battery(sender: mpsc::UnboundedSender<Event>) {
+    let _ = thread::spawn(move || {
+        let manager = match battery::Manager::new() {
+            Ok(manager) => manager,
+            Err(error) => {
+                log::warn!("Battery information is unavailable: {error}");
+                return;
+            }
+        };
+
+        let mut is_low = false;
+
+        while !sender.is_closed() {
+            let batteries = manager
+                .batteries()
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok);
+
+            let mut percentage = None;
+
+            for battery in batteries {
+                if battery.state() != battery::State::Discharging {
+                    continue;
+                }
+
+                let charge = battery
+                    .state_of_charge()
+                    .get::<battery::units::ratio::percent>();
+
+                percentage =
+                    Some(percentage.map_or(charge, |p: f32| p.min(charge)));
+            }
+
+            match percentage {
+                Some(percentage) if percentage <= LOW_BATTERY => {
+                    if !is_low
+                        && sender
+                            .unbounded_send(Event::BatteryLow { percentage })
+                            .is_err()
+                    {
+                        return;
+                    }
+
+                    is_low = true;
+                }
+                _ => {
+                    is_low = false;
+                }
+            }
+
+            thread::sleep(BATTERY_INTERVAL);
+        }
+    });
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::Event;
+    use crate::futures::futures::channel::mpsc;
+    use crate::futures::futures::stream::{self, BoxStream, StreamExt};
+
+    use zbus::zvariant::OwnedObjectPath;
+
+    const LOGIN: &str = "org.freedesktop.login1";
+
+    /// Listens to the signals of `logind` until the subscription is dropped.
+    pub async fn listen(sender: mpsc::UnboundedSender<Event>) {
+        let connection = match zbus::Connection::system().await {
+            Ok(connection) => connection,
+            Err(error) => {
+                log::warn!("Power and session events are unavailable: {error}");
+                return;
+            }
+        };
+
+        let mut events = stream::select_all([
+            sleep(&connection).await,
+            lid(&connection).await,
+            session(&connection).await,
+        ]);
+
+        while let Some(event) = events.next().await {
+            if sender.unbounded_send(event).is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Reports the system being suspended and resumed.
+    async fn sleep(connection: &zbus::Connection) -> BoxStream<'static, Event> {
+        let signals = async {
+            manager(connection)
+                .await?
+                .receive_signal("PrepareForSleep")
+                .await
+        };
+
+        match signals.await {
+            Ok(signals) => signals
+                .filter_map(|message| async move {
+                    let is_suspending: bool = message.body().ok()?;
+
+                    Some(if is_suspending {
+                        Event::Suspended
+                    } else {
+                        Event::Resumed
+                    })
+                })
+                .boxed(),
+            Err(error) => {
+                log::warn!("Sleep events are unavailable: {error}");
+
+                stream::empty().boxed()
+            }
+        }
+    }
+
+    /// Reports the lid of the device being closed and opened.
+    async fn lid(connection: &zbus::Connection) -> BoxStream<'static, Event> {
+        match manager(connection).await {
+            Ok(manager) => manager
+                .receive_property_changed::<bool>("LidClosed")
+                .await
+                .filter_map(|change| async move {
+                    Some(if change.get().await.ok()? {
+                        Event::LidClosed
+                    } else {
+                        Event::LidOpened
+                    })
+                })
+                .boxed(),
+            Err(error) => {
+                log::warn!("Lid events are unavailable: {error}");
+
+                stream::empty().boxed()
+            }
+        }
+    }
+
+    /// Reports the session of the application being locked and unlocked.
+    async fn session(
+        connection: &zbus::Connection,
+    ) -> BoxStream<'static, Event> {
+        let signals = async {
+            let manager = manager(connection).await?;
+
+            let path: OwnedObjectPath = match manager
+                .call("GetSessionByPID", &(std::process::id(),))
+                .await
+            {
+                Ok(path) => path,
+                // Processes outside of any session, like those started by a
+                // service manager, follow the display session of their user
+                Err(_) => manager.call("GetSession", &("auto",)).await?,
+            };
+
+            let session = zbus::Proxy::new(
+                connection,
+                LOGIN,
+                path.into_inner(),
+                "org.freedesktop.login1.Session",
+            )
+            .await?;
+
+            let lock = session.receive_signal("Lock").await?;
+            let unlock = session.receive_signal("Unlock").await?;
+
+            zbus::Result::Ok(stream::select(
+                lock.map(|_| Event::SessionLocked),
+                unlock.map(|_| Event::SessionUnlocked),
+            ))
+        };
+
+        match signals.await {
+            Ok(signals) => signals.boxed(),
+            Err(error) => {
+                log::warn!("Session events are unavailable: {error}");
+
+                stream::empty().boxed()
+            }
+        }
+    }
+
+    async fn manager(
+        connection: &zbus::Connection,
+    ) -> zbus::Result<zbus::Proxy<'static>> {
+        zbus::Proxy::new(
+            connection,
+            LOGIN,
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+        )
+        .await
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod platform {
+    use super::Event;
+    use crate::futures::futures::channel::mpsc;
+
+    use std::thread;
+    use std::time::{Duration, Instant, SystemTime};
+
+    /// How often the clock is checked.
+    const INTERVAL: Duration = Duration::from_secs(2);
+
+    /// The delay in waking up after which the system is considered to have
+    /// been suspended.
+    const SUSPEND_THRESHOLD: Duration = Duration::from_secs(10);
+
+    /// Detects the system being resumed by noticing the clocks jumping
+    /// forward while sleeping, until the subscription is dropped.
+    ///
+    /// Both the monotonic and the system clocks must agree, so that changes
+    /// to the system clock alone are not mistaken for a suspension.
+    pub async fn listen(sender: mpsc::UnboundedSender<Event>) {
+        let _ = thread::spawn(move || {
+            while !sender.is_closed() {
+                let instant = Instant::now();
+                let time = SystemTime::now();
+
+                thread::sleep(INTERVAL);
+
+                let limit = INTERVAL + SUSPEND_THRESHOLD;
+                let is_resumed = instant.elapsed() > limit
+                    && time.elapsed().unwrap_or_default() > limit;
+
+                if is_resumed && sender.unbounded_send(Event::Resumed).is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
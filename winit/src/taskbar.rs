@@ -0,0 +1,78 @@
+//! Show the progress of a window in its taskbar entry.
+use crate::core::window::Progress;
+
+/// Changes the [`Progress`] shown in the taskbar entry of the window.
+#[cfg(target_os = "windows")]
+#[allow(unsafe_code)]
+pub fn change_progress(
+    window: &winit::window::Window,
+    progress: Option<Progress>,
+) {
+    use std::ptr;
+    use winapi::shared::windef::HWND;
+    use winapi::shared::winerror::SUCCEEDED;
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+    use winapi::um::combaseapi::{CoCreateInstance, CoInitializeEx};
+    use winapi::um::objbase::COINIT_APARTMENTTHREADED;
+    use winapi::um::shobjidl_core::{
+        CLSID_TaskbarList, ITaskbarList3, TBPF_ERROR, TBPF_INDETERMINATE,
+        TBPF_NOPROGRESS, TBPF_NORMAL, TBPF_PAUSED,
+    };
+    use winapi::Interface;
+    use winit::platform::windows::WindowExtWindows;
+
+    const TOTAL: u64 = 10_000;
+
+    let hwnd = window.hwnd() as HWND;
+
+    let (state, value) = match progress {
+        None => (TBPF_NOPROGRESS, None),
+        Some(Progress::Indeterminate) => (TBPF_INDETERMINATE, None),
+        Some(Progress::Normal(value)) => (TBPF_NORMAL, Some(value)),
+        Some(Progress::Paused(value)) => (TBPF_PAUSED, Some(value)),
+        Some(Progress::Error(value)) => (TBPF_ERROR, Some(value)),
+    };
+
+    unsafe {
+        // COM may have been initialized already by the windowing system
+        let _ = CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+
+        let mut taskbar: *mut ITaskbarList3 = ptr::null_mut();
+
+        let result = CoCreateInstance(
+            &CLSID_TaskbarList,
+            ptr::null_mut(),
+            CLSCTX_INPROC_SERVER,
+            &ITaskbarList3::uuidof(),
+            &mut taskbar as *mut *mut ITaskbarList3 as *mut _,
+        );
+
+        if !SUCCEEDED(result) || taskbar.is_null() {
+            log::warn!("Failed to access the taskbar: {result:#x}");
+            return;
+        }
+
+        let taskbar = &*taskbar;
+
+        if SUCCEEDED(taskbar.HrInit()) {
+            // Setting a value resets the state, so it must come first
+            if let Some(value) = value {
+                let completed = (value.clamp(0.0, 1.0) * TOTAL as f32) as u64;
+
+                let _ = taskbar.SetProgressValue(hwnd, completed, TOTAL);
+            }
+
+            let _ = taskbar.SetProgressState(hwnd, state);
+        }
+
+        let _ = taskbar.Release();
+    }
+}
+
+/// Changes the [`Progress`] shown in the taskbar entry of the window.
+#[cfg(not(target_os = "windows"))]
+pub fn change_progress(
+    _window: &winit::window::Window,
+    _progress: Option<Progress>,
+) {
+}
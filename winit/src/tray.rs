@@ -0,0 +1,193 @@
+//! Show an icon in the system tray with [`tray_icon`].
+//!
+//! On Linux, the tray is backed by GTK, which must be initialized in the
+//! thread running the event loop.
+use crate::menu::{Handler, Native};
+use crate::runtime::tray;
+
+use std::sync::{Arc, Mutex};
+use tray_icon::{ClickType, TrayIconEvent};
+
+/// The system tray icon of an application, kept in sync with the
+/// [`tray::Tray`] it returns.
+///
+/// The messages of the icon are sent directly to the event loop, so they
+/// are processed even when the window is hidden.
+#[allow(missing_debug_implementations)]
+pub struct Tray<Message: 'static> {
+    raw: Option<tray_icon::TrayIcon>,
+    shape: Option<Shape>,
+    menu: Option<Native>,
+    clicks: Arc<Mutex<Clicks<Message>>>,
+    handler: Handler<Message>,
+}
+
+struct Clicks<Message: 'static> {
+    proxy: winit::event_loop::EventLoopProxy<Message>,
+    on_click: Option<Message>,
+    on_double_click: Option<Message>,
+}
+
+/// The part of a [`tray::Tray`] that requires updating the native icon
+/// when it changes.
+#[derive(PartialEq)]
+struct Shape {
+    icon: (Vec<u8>, crate::core::Size<u32>),
+    tooltip: Option<String>,
+}
+
+impl<Message> Tray<Message>
+where
+    Message: Clone + Send + 'static,
+{
+    const SOURCE: &'static str = "tray";
+
+    /// Creates a new [`Tray`] that sends its messages through the given
+    /// event loop proxy and menu [`Handler`].
+    ///
+    /// No icon is shown until the [`Tray`] is synchronized.
+    pub fn new(
+        proxy: winit::event_loop::EventLoopProxy<Message>,
+        handler: Handler<Message>,
+    ) -> Self {
+        let clicks = Arc::new(Mutex::new(Clicks {
+            proxy,
+            on_click: None,
+            on_double_click: None,
+        }));
+
+        TrayIconEvent::set_event_handler(Some({
+            let clicks = clicks.clone();
+
+            move |event: TrayIconEvent| {
+                let clicks = clicks.lock().expect("Lock tray messages");
+
+                let message = match event.click_type {
+                    ClickType::Left => clicks.on_click.clone(),
+                    ClickType::Double => clicks.on_double_click.clone(),
+                    ClickType::Right => None,
+                };
+
+                if let Some(message) = message {
+                    // The event loop may be gone already
+                    let _ = clicks.proxy.send_event(message);
+                }
+            }
+        }));
+
+        Self {
+            raw: None,
+            shape: None,
+            menu: None,
+            clicks,
+            handler,
+        }
+    }
+
+    /// Creates, updates, or removes the native icon to match the given
+    /// [`tray::Tray`].
+    pub fn synchronize(&mut self, tray: Option<tray::Tray<Message>>) {
+        self.handler.set(
+            Self::SOURCE,
+            tray.as_ref().and_then(|tray| tray.menu.as_ref()),
+        );
+
+        let Some(tray) = tray else {
+            self.raw = None;
+            self.shape = None;
+            self.menu = None;
+
+            return;
+        };
+
+        {
+            let mut clicks = self.clicks.lock().expect("Lock tray messages");
+
+            clicks.on_click = tray.on_click;
+            clicks.on_double_click = tray.on_double_click;
+        }
+
+        let is_menu_outdated = match (&self.menu, &tray.menu) {
+            (Some(native), Some(menu)) => !native.update(menu),
+            (None, None) => false,
+            _ => true,
+        };
+
+        if is_menu_outdated {
+            self.menu = tray
+                .menu
+                .as_ref()
+                .map(|menu| Native::build(menu, Self::SOURCE));
+        }
+
+        let shape = Shape {
+            icon: tray.icon.into_raw(),
+            tooltip: tray.tooltip,
+        };
+
+        if let Some(raw) = &self.raw {
+            if is_menu_outdated {
+                raw.set_menu(context_menu(self.menu.as_ref()));
+            }
+
+            if self.shape.as_ref() == Some(&shape) {
+                return;
+            }
+        }
+
+        let (rgba, size) = shape.icon.clone();
+
+        let icon =
+            match tray_icon::Icon::from_rgba(rgba, size.width, size.height) {
+                Ok(icon) => icon,
+                Err(error) => {
+                    log::warn!("Invalid tray icon: {error}");
+                    return;
+                }
+            };
+
+        match &self.raw {
+            Some(raw) => {
+                if let Err(error) = raw.set_icon(Some(icon)) {
+                    log::warn!("Failed to update tray icon: {error}");
+                }
+
+                if let Err(error) = raw.set_tooltip(shape.tooltip.as_ref()) {
+                    log::warn!("Failed to update tray tooltip: {error}");
+                }
+            }
+            None => {
+                let mut builder =
+                    tray_icon::TrayIconBuilder::new().with_icon(icon);
+
+                if let Some(tooltip) = &shape.tooltip {
+                    builder = builder.with_tooltip(tooltip);
+                }
+
+                if let Some(menu) = context_menu(self.menu.as_ref()) {
+                    builder = builder.with_menu(menu);
+                }
+
+                match builder.build() {
+                    Ok(raw) => {
+                        self.raw = Some(raw);
+                    }
+                    Err(error) => {
+                        log::warn!("Failed to create tray icon: {error}");
+                        return;
+                    }
+                }
+            }
+        }
+
+        self.shape = Some(shape);
+    }
+}
+
+fn context_menu(
+    native: Option<&Native>,
+) -> Option<Box<dyn tray_icon::menu::ContextMenu>> {
+    native.map(|native| {
+        Box::new(native.menu.clone()) as Box<dyn tray_icon::menu::ContextMenu>
+    })
+}
@@ -0,0 +1,164 @@
+//! Display native web views on top of a window.
+use crate::core::widget;
+use crate::core::Rectangle;
+use crate::runtime::user_interface::UserInterface;
+use crate::runtime::webview;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// The native web views of a window, kept in sync with the `WebView`
+/// widgets of its user interface.
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct WebViews {
+    entries: HashMap<widget::Id, Entry>,
+    failed: HashSet<widget::Id>,
+    received: Rc<RefCell<Vec<(widget::Id, String)>>>,
+}
+
+struct Entry {
+    webview: wry::WebView,
+    url: String,
+    bounds: Option<Rectangle>,
+}
+
+impl WebViews {
+    /// Creates an empty set of [`WebViews`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if some page has posted messages that have not been
+    /// delivered to the user interface yet.
+    pub fn has_messages(&self) -> bool {
+        !self.received.borrow().is_empty()
+    }
+
+    /// Creates, moves, navigates, and destroys the native web views of the
+    /// window to match the `WebView` widgets of the [`UserInterface`].
+    ///
+    /// It also runs the scripts queued by the widgets and delivers them the
+    /// messages posted by their pages. Returns true if any message was
+    /// delivered; in which case the [`UserInterface`] should be updated
+    /// again as soon as possible.
+    pub fn synchronize<Message, Renderer>(
+        &mut self,
+        window: &winit::window::Window,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &Renderer,
+    ) -> bool
+    where
+        Renderer: crate::core::Renderer,
+    {
+        let scale_factor = window.scale_factor();
+        let mut received = std::mem::take(&mut *self.received.borrow_mut());
+        let mut visited = HashSet::new();
+        let mut delivered = false;
+
+        let entries = &mut self.entries;
+        let failed = &mut self.failed;
+        let sender = &self.received;
+
+        let mut operation = webview::visit(|id, state, bounds| {
+            let _ = visited.insert(id.clone());
+
+            received.retain(|(target, message)| {
+                if target == id {
+                    state.receive(message.clone());
+                    delivered = true;
+
+                    false
+                } else {
+                    true
+                }
+            });
+
+            let entry = match entries.get_mut(id) {
+                Some(entry) => entry,
+                None if failed.contains(id) => return,
+                None => {
+                    let sender = Rc::clone(sender);
+                    let target = id.clone();
+
+                    let webview = wry::WebViewBuilder::new_as_child(window)
+                        .with_bounds(rect(
+                            bounds.unwrap_or_default(),
+                            scale_factor,
+                        ))
+                        .with_visible(bounds.is_some())
+                        .with_ipc_handler(move |message: String| {
+                            sender.borrow_mut().push((target.clone(), message));
+                        })
+                        .with_url(state.url())
+                        .and_then(wry::WebViewBuilder::build);
+
+                    match webview {
+                        Ok(webview) => {
+                            entries.entry(id.clone()).or_insert(Entry {
+                                webview,
+                                url: state.url().to_owned(),
+                                bounds,
+                            })
+                        }
+                        Err(error) => {
+                            log::error!("Failed to create web view: {error}");
+
+                            let _ = failed.insert(id.clone());
+                            return;
+                        }
+                    }
+                }
+            };
+
+            // Web views scrolled out of view are hidden, since native
+            // surfaces cannot be clipped
+            if entry.bounds != bounds {
+                match bounds {
+                    Some(bounds) => {
+                        entry.webview.set_bounds(rect(bounds, scale_factor));
+
+                        if entry.bounds.is_none() {
+                            entry.webview.set_visible(true);
+                        }
+                    }
+                    None => entry.webview.set_visible(false),
+                }
+
+                entry.bounds = bounds;
+            }
+
+            if entry.url != state.url() {
+                entry.webview.load_url(state.url());
+                entry.url = state.url().to_owned();
+            }
+
+            for script in state.take_scripts() {
+                if let Err(error) = entry.webview.evaluate_script(&script) {
+                    log::warn!("Failed to evaluate script: {error}");
+                }
+            }
+        });
+
+        user_interface.operate(renderer, &mut operation);
+
+        // Destroy the web views whose widgets are gone
+        self.entries.retain(|id, _| visited.contains(id));
+        self.failed.retain(|id| visited.contains(id));
+
+        delivered
+    }
+}
+
+/// Converts logical bounds into the physical bounds of a web view.
+fn rect(bounds: Rectangle, scale_factor: f64) -> wry::Rect {
+    let scale = |value: f32| (f64::from(value) * scale_factor).round();
+
+    wry::Rect {
+        x: scale(bounds.x) as i32,
+        y: scale(bounds.y) as i32,
+        width: scale(bounds.width).max(0.0) as u32,
+        height: scale(bounds.height).max(0.0) as u32,
+    }
+}
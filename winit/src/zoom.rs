@@ -0,0 +1,96 @@
+//! Zoom the user interface with keyboard and mouse shortcuts.
+//!
+//! The zoom is expressed in percent and applied on top of the scale factor
+//! of the window and the [`Application`].
+//!
+//! [`Application`]: crate::Application
+
+/// The zoom of a user interface that has not been zoomed.
+pub(crate) const DEFAULT: u32 = 100;
+
+/// The zoom levels the shortcuts step through, like in a web browser.
+const LEVELS: [u32; 13] =
+    [50, 67, 75, 80, 90, 100, 110, 125, 150, 175, 200, 250, 300];
+
+/// Clamps the given zoom to the range of the zoom levels.
+pub(crate) fn clamp(percent: u32) -> u32 {
+    percent.clamp(LEVELS[0], LEVELS[LEVELS.len() - 1])
+}
+
+/// Returns the zoom requested by the given [`winit`] event with a shortcut,
+/// if any.
+///
+/// `Ctrl` and `+` or `-` step through the zoom levels, just like `Ctrl` and
+/// the mouse wheel, while `Ctrl` and `0` resets the zoom. `Cmd` is used
+/// instead of `Ctrl` on macOS.
+pub(crate) fn shortcut(
+    event: &winit::event::WindowEvent<'_>,
+    modifiers: winit::event::ModifiersState,
+    current: u32,
+) -> Option<u32> {
+    use winit::event::{
+        ElementState, KeyboardInput, MouseScrollDelta, VirtualKeyCode,
+        WindowEvent,
+    };
+
+    let is_pressed = if cfg!(target_os = "macos") {
+        modifiers.logo()
+    } else {
+        modifiers.ctrl()
+    };
+
+    if !is_pressed {
+        return None;
+    }
+
+    match event {
+        WindowEvent::KeyboardInput {
+            input:
+                KeyboardInput {
+                    virtual_keycode: Some(key_code),
+                    state: ElementState::Pressed,
+                    ..
+                },
+            ..
+        } => match key_code {
+            VirtualKeyCode::Equals
+            | VirtualKeyCode::Plus
+            | VirtualKeyCode::NumpadAdd => Some(zoom_in(current)),
+            VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => {
+                Some(zoom_out(current))
+            }
+            VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => Some(DEFAULT),
+            _ => None,
+        },
+        WindowEvent::MouseWheel { delta, .. } => {
+            let y = match delta {
+                MouseScrollDelta::LineDelta(_, y) => *y as f64,
+                MouseScrollDelta::PixelDelta(position) => position.y,
+            };
+
+            if y > 0.0 {
+                Some(zoom_in(current))
+            } else if y < 0.0 {
+                Some(zoom_out(current))
+            } else {
+                Some(current)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn zoom_in(current: u32) -> u32 {
+    LEVELS
+        .into_iter()
+        .find(|level| *level > current)
+        .unwrap_or(LEVELS[LEVELS.len() - 1])
+}
+
+fn zoom_out(current: u32) -> u32 {
+    LEVELS
+        .into_iter()
+        .rev()
+        .find(|level| *level < current)
+        .unwrap_or(LEVELS[0])
+}